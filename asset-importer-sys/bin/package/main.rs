@@ -6,6 +6,11 @@ use std::{
 
 use flate2::{Compression, write::GzEncoder};
 
+// Shared with the build script, which runs the same checks on downloaded archives.
+#[path = "../../build_support/archive.rs"]
+#[allow(dead_code)]
+mod archive;
+
 fn vendored_assimp_version() -> &'static str {
     include_str!("../../assimp-version.txt").trim()
 }
@@ -121,7 +126,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     fs::create_dir_all(&ar_dst_dir)?;
     println!("Packaging at: {}", ar_dst_dir.display());
 
-    validate_from_dir(&from_dir, link_type, &target_os)?;
+    archive::validate_package_dir(&from_dir, link_type, &target_os)?;
 
     let tar_file = fs::File::create(ar_dst_dir.join(&ar_filename))?;
     let mut archive = tar::Builder::new(GzEncoder::new(tar_file, Compression::best()));
@@ -349,119 +354,6 @@ fn append_dir_all_files(
     Ok(())
 }
 
-fn validate_from_dir(
-    from_dir: &Path,
-    link_type: &str,
-    target_os: &str,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let include_version = from_dir.join("include").join("assimp").join("version.h");
-    if !include_version.exists() {
-        return Err(format!(
-            "Assimp headers not found (missing {}); refusing to package an invalid archive",
-            include_version.display()
-        )
-        .into());
-    }
-
-    let lib_dir = from_dir.join("lib");
-    let lib64_dir = from_dir.join("lib64");
-    let bin_dir = from_dir.join("bin");
-
-    let lib_roots = [lib_dir.as_path(), lib64_dir.as_path()];
-
-    let mut has_static = false;
-    let mut has_shared = false;
-    let mut has_windows_lib = false;
-
-    for root in lib_roots {
-        if !root.exists() {
-            continue;
-        }
-        for entry in fs::read_dir(root)?.flatten() {
-            let p = entry.path();
-            let Some(name) = p.file_name().and_then(|s| s.to_str()) else {
-                continue;
-            };
-            let lower = name.to_ascii_lowercase();
-
-            if target_os == "windows" {
-                if lower.starts_with("assimp") && lower.ends_with(".lib") {
-                    has_windows_lib = true;
-                }
-                continue;
-            }
-
-            if lower.starts_with("libassimp") && lower.ends_with(".a") {
-                has_static = true;
-            }
-            if lower.starts_with("libassimp")
-                && (lower.ends_with(".dylib") || lower.contains(".so"))
-            {
-                has_shared = true;
-            }
-        }
-    }
-
-    let has_windows_dll = if target_os == "windows" && bin_dir.exists() {
-        fs::read_dir(&bin_dir)?.flatten().any(|e| {
-            e.path()
-                .extension()
-                .and_then(|s| s.to_str())
-                .is_some_and(|ext| ext.eq_ignore_ascii_case("dll"))
-        })
-    } else {
-        false
-    };
-
-    match (target_os, link_type) {
-        ("windows", "static") => {
-            if !has_windows_lib {
-                return Err(format!(
-                    "Windows static package is missing assimp *.lib under {} (or {}).",
-                    lib_dir.display(),
-                    lib64_dir.display()
-                )
-                .into());
-            }
-        }
-        ("windows", "dylib") => {
-            if !has_windows_lib || !has_windows_dll {
-                return Err(format!(
-                    "Windows dylib package is missing assimp import lib (*.lib) and/or runtime DLLs (bin/*.dll). lib={}, bin={}",
-                    lib_dir.display(),
-                    bin_dir.display()
-                )
-                .into());
-            }
-        }
-        (_, "static") => {
-            if !has_static || has_shared {
-                return Err(format!(
-                    "Static package content mismatch: expected static assimp library only, found static={}, shared={}. from_dir={}",
-                    has_static,
-                    has_shared,
-                    from_dir.display()
-                )
-                .into());
-            }
-        }
-        (_, "dylib") => {
-            if !has_shared || has_static {
-                return Err(format!(
-                    "Dylib package content mismatch: expected shared assimp library only, found shared={}, static={}. from_dir={}",
-                    has_shared,
-                    has_static,
-                    from_dir.display()
-                )
-                .into());
-            }
-        }
-        _ => {}
-    }
-
-    Ok(())
-}
-
 fn locate_build_out_dir(
     workspace_root: &std::path::Path,
     target: &str,
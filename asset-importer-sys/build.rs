@@ -1,6 +1,12 @@
 mod build_support;
 
-use build_support::{bindings, bridge, config::BuildConfig, plan, system_deps};
+use build_support::{
+    bindings, bridge,
+    config::BuildConfig,
+    formats,
+    plan::{self, BuildMethod},
+    system_deps,
+};
 
 fn main() {
     let cfg = BuildConfig::new();
@@ -8,6 +14,8 @@ fn main() {
 
     if cfg.docs_rs {
         bindings::run_docsrs(&cfg);
+        bindings::emit_real_type(&cfg, &[]);
+        write_built_formats(&cfg, false);
         return;
     }
 
@@ -21,7 +29,23 @@ fn main() {
 
     // Generate Rust bindings (always from headers that match the chosen link strategy).
     bindings::run(&cfg, &plan);
+    bindings::emit_real_type(&cfg, &plan.include_dirs);
+
+    // Record which importers/exporters were compiled in (only known for source builds).
+    write_built_formats(&cfg, matches!(plan.method, BuildMethod::Vendored));
 
     // Build our small C++ bridge (progress handler + IOSystem wrappers).
     bridge::build(&cfg, &plan);
 }
+
+fn write_built_formats(cfg: &BuildConfig, source_build: bool) {
+    // The `cmake` crate configures into `OUT_DIR/build`.
+    let assimp_src = cfg.assimp_source_dir();
+    let cmake_build_dir = cfg.out_dir.join("build");
+    formats::write(
+        &cfg.out_dir,
+        source_build.then_some(assimp_src.as_path()),
+        source_build.then_some(cmake_build_dir.as_path()),
+    )
+    .unwrap_or_else(|e| panic!("Failed to write {} to OUT_DIR: {}", formats::OUTPUT_FILE, e));
+}
@@ -0,0 +1,336 @@
+//! Prebuilt archive selection, checksum comparison and package layout validation.
+//!
+//! Std-only so it can be shared by the build script (`prebuilt` feature), the `package`
+//! binary and the crate's unit tests. Computing the digest needs `sha2` and lives in the
+//! build script's `prebuilt` module.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Inputs that decide where a prebuilt archive comes from.
+#[derive(Clone, Debug)]
+pub struct ArchiveSettings {
+    /// `ASSET_IMPORTER_PREBUILT_ARCHIVE`: an explicit archive file.
+    pub explicit_archive: Option<PathBuf>,
+    /// `ASSET_IMPORTER_PACKAGE_DIR`: a directory holding archives under their release names.
+    pub package_dir: Option<PathBuf>,
+    /// Directory downloads are cached in.
+    pub cache_root: PathBuf,
+    /// `ASSET_IMPORTER_OFFLINE` / `CARGO_NET_OFFLINE`: never download.
+    pub offline: bool,
+}
+
+/// Where the archive for this build comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArchiveSource {
+    /// An archive already on disk.
+    Local(PathBuf),
+    /// Nothing local; download into this directory.
+    Download(PathBuf),
+}
+
+/// Pick the archive for `candidates` (release file names, most specific first).
+///
+/// An explicit archive wins, then a package directory, then the download cache. In
+/// offline mode a missing archive is an error instead of a download.
+pub fn select_archive(
+    settings: &ArchiveSettings,
+    candidates: &[String],
+) -> Result<ArchiveSource, String> {
+    if let Some(path) = &settings.explicit_archive {
+        if !path.is_file() {
+            return Err(format!(
+                "ASSET_IMPORTER_PREBUILT_ARCHIVE points to {}, which is not a file.\n\
+                 Hint: pass the path of a .tar.gz produced by the `package` tool.",
+                path.display()
+            ));
+        }
+        return Ok(ArchiveSource::Local(path.clone()));
+    }
+
+    if let Some(dir) = &settings.package_dir {
+        return find_candidate(dir, candidates)
+            .map(ArchiveSource::Local)
+            .ok_or_else(|| {
+                format!(
+                    "ASSET_IMPORTER_PACKAGE_DIR={} contains none of {:?}.\n\
+                     Hint: copy the archive for this target there, or set ASSET_IMPORTER_PREBUILT_ARCHIVE to its path.",
+                    dir.display(),
+                    candidates
+                )
+            });
+    }
+
+    if let Some(path) = find_candidate(&settings.cache_root, candidates) {
+        return Ok(ArchiveSource::Local(path));
+    }
+
+    if settings.offline {
+        return Err(format!(
+            "offline mode (ASSET_IMPORTER_OFFLINE/CARGO_NET_OFFLINE) is set and no prebuilt archive named {:?} is cached in {}; refusing to download.\n\
+             Hint: set ASSET_IMPORTER_PREBUILT_ARCHIVE to a local .tar.gz or ASSET_IMPORTER_PACKAGE_DIR to a directory containing it.",
+            candidates,
+            settings.cache_root.display()
+        ));
+    }
+
+    Ok(ArchiveSource::Download(settings.cache_root.clone()))
+}
+
+fn find_candidate(dir: &Path, candidates: &[String]) -> Option<PathBuf> {
+    candidates
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Compare a computed digest with the expected one from the environment.
+pub fn check_sha256(actual: &str, expected: &str, archive: &Path) -> Result<(), String> {
+    let expected = expected.trim();
+    if actual.eq_ignore_ascii_case(expected) {
+        return Ok(());
+    }
+    Err(format!(
+        "prebuilt archive {} has SHA-256 {}, but ASSET_IMPORTER_PREBUILT_SHA256 expects {}.\n\
+         Hint: the archive may be corrupt or for a different release; fetch it again or update the checksum.",
+        archive.display(),
+        actual,
+        expected
+    ))
+}
+
+/// Directory an archive with `digest` is extracted to: next to `out_dir`, so every
+/// build of the same archive reuses one extraction.
+pub fn extraction_dir(out_dir: &Path, digest: &str) -> PathBuf {
+    let base = out_dir.parent().unwrap_or(out_dir);
+    base.join(format!(
+        "asset-importer-prebuilt-{}",
+        &digest[..digest.len().min(16)]
+    ))
+}
+
+/// Check that an installed or extracted package matches `link_type` (`"static"` or
+/// `"dylib"`) for `target_os`.
+pub fn validate_package_dir(
+    from_dir: &Path,
+    link_type: &str,
+    target_os: &str,
+) -> Result<(), String> {
+    let include_version = from_dir.join("include").join("assimp").join("version.h");
+    if !include_version.exists() {
+        return Err(format!(
+            "Assimp headers not found (missing {}); refusing to use an invalid package",
+            include_version.display()
+        ));
+    }
+
+    let lib_dir = from_dir.join("lib");
+    let lib64_dir = from_dir.join("lib64");
+    let bin_dir = from_dir.join("bin");
+
+    let mut has_static = false;
+    let mut has_shared = false;
+    let mut has_windows_lib = false;
+
+    for root in [lib_dir.as_path(), lib64_dir.as_path()] {
+        if !root.exists() {
+            continue;
+        }
+        let entries = fs::read_dir(root).map_err(|e| format!("{}: {}", root.display(), e))?;
+        for entry in entries.flatten() {
+            let p = entry.path();
+            let Some(name) = p.file_name().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let lower = name.to_ascii_lowercase();
+
+            if target_os == "windows" {
+                if lower.starts_with("assimp") && lower.ends_with(".lib") {
+                    has_windows_lib = true;
+                }
+                continue;
+            }
+
+            if lower.starts_with("libassimp") && lower.ends_with(".a") {
+                has_static = true;
+            }
+            if lower.starts_with("libassimp")
+                && (lower.ends_with(".dylib") || lower.contains(".so"))
+            {
+                has_shared = true;
+            }
+        }
+    }
+
+    let has_windows_dll = target_os == "windows"
+        && bin_dir.exists()
+        && fs::read_dir(&bin_dir)
+            .map_err(|e| format!("{}: {}", bin_dir.display(), e))?
+            .flatten()
+            .any(|e| {
+                e.path()
+                    .extension()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("dll"))
+            });
+
+    match (target_os, link_type) {
+        ("windows", "static") if !has_windows_lib => Err(format!(
+            "Windows static package is missing assimp *.lib under {} (or {}).",
+            lib_dir.display(),
+            lib64_dir.display()
+        )),
+        ("windows", "dylib") if !has_windows_lib || !has_windows_dll => Err(format!(
+            "Windows dylib package is missing assimp import lib (*.lib) and/or runtime DLLs (bin/*.dll). lib={}, bin={}",
+            lib_dir.display(),
+            bin_dir.display()
+        )),
+        ("windows", _) => Ok(()),
+        (_, "static") if !has_static || has_shared => Err(format!(
+            "Static package content mismatch: expected static assimp library only, found static={}, shared={}. from_dir={}",
+            has_static,
+            has_shared,
+            from_dir.display()
+        )),
+        (_, "dylib") if !has_shared || has_static => Err(format!(
+            "Dylib package content mismatch: expected shared assimp library only, found shared={}, static={}. from_dir={}",
+            has_shared,
+            has_static,
+            from_dir.display()
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "asset-importer-sys-archive-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn settings(cache_root: &Path) -> ArchiveSettings {
+        ArchiveSettings {
+            explicit_archive: None,
+            package_dir: None,
+            cache_root: cache_root.to_path_buf(),
+            offline: false,
+        }
+    }
+
+    fn names() -> Vec<String> {
+        vec!["pkg-md.tar.gz".to_string(), "pkg.tar.gz".to_string()]
+    }
+
+    #[test]
+    fn test_explicit_archive_wins() {
+        let dir = scratch_dir("explicit");
+        let archive = dir.join("custom.tar.gz");
+        fs::write(&archive, b"archive").unwrap();
+        fs::write(dir.join("pkg.tar.gz"), b"cached").unwrap();
+
+        let mut s = settings(&dir);
+        s.explicit_archive = Some(archive.clone());
+        s.offline = true;
+        assert_eq!(
+            select_archive(&s, &names()),
+            Ok(ArchiveSource::Local(archive))
+        );
+
+        s.explicit_archive = Some(dir.join("missing.tar.gz"));
+        let err = select_archive(&s, &names()).unwrap_err();
+        assert!(err.contains("ASSET_IMPORTER_PREBUILT_ARCHIVE"), "{err}");
+    }
+
+    #[test]
+    fn test_package_dir_prefers_first_candidate() {
+        let dir = scratch_dir("package-dir");
+        fs::write(dir.join("pkg.tar.gz"), b"generic").unwrap();
+
+        let mut s = settings(&dir.join("cache"));
+        s.package_dir = Some(dir.clone());
+        assert_eq!(
+            select_archive(&s, &names()),
+            Ok(ArchiveSource::Local(dir.join("pkg.tar.gz")))
+        );
+
+        fs::write(dir.join("pkg-md.tar.gz"), b"crt specific").unwrap();
+        assert_eq!(
+            select_archive(&s, &names()),
+            Ok(ArchiveSource::Local(dir.join("pkg-md.tar.gz")))
+        );
+
+        s.package_dir = Some(dir.join("empty"));
+        assert!(select_archive(&s, &names()).is_err());
+    }
+
+    #[test]
+    fn test_offline_never_downloads() {
+        let dir = scratch_dir("offline");
+        let mut s = settings(&dir);
+        assert_eq!(
+            select_archive(&s, &names()),
+            Ok(ArchiveSource::Download(dir.clone()))
+        );
+
+        s.offline = true;
+        let err = select_archive(&s, &names()).unwrap_err();
+        assert!(err.contains("refusing to download"), "{err}");
+
+        // A cached archive is still usable offline.
+        fs::write(dir.join("pkg.tar.gz"), b"cached").unwrap();
+        assert_eq!(
+            select_archive(&s, &names()),
+            Ok(ArchiveSource::Local(dir.join("pkg.tar.gz")))
+        );
+    }
+
+    #[test]
+    fn test_checksum_and_extraction_dir() {
+        let dir = scratch_dir("checksum");
+        let archive = dir.join("pkg.tar.gz");
+        fs::write(&archive, b"abc").unwrap();
+        let digest = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+        assert!(check_sha256(digest, &format!(" {} ", digest.to_uppercase()), &archive).is_ok());
+        let err = check_sha256(digest, "00", &archive).unwrap_err();
+        assert!(err.contains("ASSET_IMPORTER_PREBUILT_SHA256"), "{err}");
+
+        let out_dir = dir
+            .join("build")
+            .join("asset-importer-sys-1234")
+            .join("out");
+        assert_eq!(
+            extraction_dir(&out_dir, digest),
+            dir.join("build")
+                .join("asset-importer-sys-1234")
+                .join("asset-importer-prebuilt-ba7816bf8f01cfea")
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_mismatched_link_type() {
+        let dir = scratch_dir("validate");
+        fs::create_dir_all(dir.join("include").join("assimp")).unwrap();
+        fs::write(dir.join("include").join("assimp").join("version.h"), b"").unwrap();
+        fs::create_dir_all(dir.join("lib")).unwrap();
+        fs::write(dir.join("lib").join("libassimp.a"), b"").unwrap();
+
+        assert!(validate_package_dir(&dir, "static", "linux").is_ok());
+        let err = validate_package_dir(&dir, "dylib", "linux").unwrap_err();
+        assert!(err.contains("Dylib package content mismatch"), "{err}");
+
+        fs::remove_file(dir.join("include").join("assimp").join("version.h")).unwrap();
+        assert!(validate_package_dir(&dir, "static", "linux").is_err());
+    }
+}
@@ -69,6 +69,53 @@ pub fn run(cfg: &BuildConfig, plan: &BuildPlan) {
     }
 }
 
+/// Report whether `ai_real` is `f64` in the bindings written to `OUT_DIR`.
+///
+/// Emits `cfg(f64_real)` for this crate and `DEP_ASSIMP_F64_REAL` (`0`/`1`) for the safe
+/// crate's build script. Headers configured with `ASSIMP_DOUBLE_PRECISION` paired with
+/// single-precision bindings (e.g. the pregenerated ones) are rejected, since every float
+/// field would be read with the wrong width.
+pub fn emit_real_type(cfg: &BuildConfig, include_dirs: &[std::path::PathBuf]) {
+    println!("cargo:rustc-check-cfg=cfg(f64_real)");
+
+    let bindings = std::fs::read_to_string(cfg.out_dir.join("bindings.rs")).unwrap_or_default();
+    let f64_real = bindings_use_f64_real(&bindings);
+
+    if !f64_real
+        && include_dirs
+            .iter()
+            .any(|dir| headers_define_double_precision(dir))
+    {
+        panic!(
+            "Assimp headers define ASSIMP_DOUBLE_PRECISION, but the Rust bindings use f32 for ai_real.\n\
+             Hint: enable `generate-bindings` and set ASSET_IMPORTER_FORCE_GENERATE_BINDINGS=1 so bindings are generated from these headers."
+        );
+    }
+
+    if f64_real {
+        println!("cargo:rustc-cfg=f64_real");
+    }
+    println!("cargo:f64_real={}", u8::from(f64_real));
+}
+
+fn bindings_use_f64_real(bindings: &str) -> bool {
+    bindings.lines().any(|line| {
+        line.trim()
+            .strip_prefix("pub type ai_real")
+            .is_some_and(|rest| rest.contains("f64"))
+    })
+}
+
+fn headers_define_double_precision(include_dir: &std::path::Path) -> bool {
+    let config_h = include_dir.join("assimp").join("config.h");
+    std::fs::read_to_string(config_h).is_ok_and(|contents| {
+        contents.lines().any(|line| {
+            let mut words = line.split_whitespace();
+            words.next() == Some("#define") && words.next() == Some("ASSIMP_DOUBLE_PRECISION")
+        })
+    })
+}
+
 fn copy_pregenerated_bindings(cfg: &BuildConfig) -> bool {
     let pregenerated = cfg
         .manifest_dir
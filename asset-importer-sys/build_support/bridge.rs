@@ -40,6 +40,11 @@ fn configure_cpp_flags(build: &mut cc::Build, cfg: &BuildConfig) {
         return;
     }
 
+    if cfg.is_emscripten() {
+        // Match the Assimp build: C++ exceptions on, no threads.
+        build.flag("-fexceptions");
+    }
+
     if cfg.is_macos() {
         build.flag(format!(
             "-mmacosx-version-min={}",
@@ -4,7 +4,6 @@ use std::{env, path::PathBuf};
 pub struct BuildConfig {
     pub manifest_dir: PathBuf,
     pub out_dir: PathBuf,
-    #[cfg_attr(not(feature = "prebuilt"), allow(dead_code))]
     pub target: String,
     pub target_os: String,
     pub target_env: String,
@@ -57,6 +56,25 @@ impl BuildConfig {
         self.target_env == "msvc"
     }
 
+    pub fn is_emscripten(&self) -> bool {
+        self.target_os == "emscripten"
+    }
+
+    /// `Emscripten.cmake` from the active emsdk: `$EMSCRIPTEN/cmake/...` (set by
+    /// `emsdk_env`) or `$EMSDK/upstream/emscripten/cmake/...`.
+    pub fn emscripten_toolchain_file(&self) -> Option<PathBuf> {
+        let relative = ["cmake", "Modules", "Platform", "Emscripten.cmake"];
+        let from_root = |root: PathBuf| relative.iter().fold(root, |p, c| p.join(c));
+        env::var_os("EMSCRIPTEN")
+            .map(|root| from_root(PathBuf::from(root)))
+            .into_iter()
+            .chain(
+                env::var_os("EMSDK")
+                    .map(|root| from_root(PathBuf::from(root).join("upstream").join("emscripten"))),
+            )
+            .find(|p| p.exists())
+    }
+
     pub fn is_debug(&self) -> bool {
         self.profile == "debug"
     }
@@ -114,6 +132,8 @@ impl BuildConfig {
         println!("cargo:rerun-if-env-changed=ASSIMP_DIR");
         println!("cargo:rerun-if-env-changed=ASSET_IMPORTER_PACKAGE_DIR");
         println!("cargo:rerun-if-env-changed=ASSET_IMPORTER_CACHE_DIR");
+        println!("cargo:rerun-if-env-changed=ASSET_IMPORTER_PREBUILT_ARCHIVE");
+        println!("cargo:rerun-if-env-changed=ASSET_IMPORTER_PREBUILT_SHA256");
         println!("cargo:rerun-if-env-changed=ASSET_IMPORTER_OFFLINE");
         println!("cargo:rerun-if-env-changed=ASSET_IMPORTER_FORCE_BUILD");
         println!("cargo:rerun-if-env-changed=ASSET_IMPORTER_FORCE_GENERATE_BINDINGS");
@@ -134,5 +154,7 @@ impl BuildConfig {
 
         // Toolchain knobs
         println!("cargo:rerun-if-env-changed=MACOSX_DEPLOYMENT_TARGET");
+        println!("cargo:rerun-if-env-changed=EMSCRIPTEN");
+        println!("cargo:rerun-if-env-changed=EMSDK");
     }
 }
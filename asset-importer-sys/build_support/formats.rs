@@ -0,0 +1,230 @@
+//! Which importers/exporters a source build compiled in, written to `OUT_DIR/built_formats.rs`.
+//!
+//! Assimp registers every format with `ADD_ASSIMP_IMPORTER(<NAME> ...)` /
+//! `ADD_ASSIMP_EXPORTER(<NAME> ...)` in `code/CMakeLists.txt` and drops the ones whose
+//! `ASSIMP_BUILD_<NAME>_IMPORTER` option is off. The names come from the former and the
+//! options from the configured `CMakeCache.txt`, applying the same rules as those macros.
+//!
+//! This module is std-only so its unit tests run with the crate's (see `src/lib.rs`).
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+pub const OUTPUT_FILE: &str = "built_formats.rs";
+
+/// Importer and exporter names compiled into Assimp.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BuiltFormats {
+    pub importers: Vec<String>,
+    pub exporters: Vec<String>,
+}
+
+/// Names passed to `macro_name(` in a CMake list file, in order.
+pub fn registered_names(cmake_lists: &str, macro_name: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for line in cmake_lists.lines() {
+        let line = line.trim_start();
+        let Some(prefix) = line.get(..macro_name.len()) else {
+            continue;
+        };
+        if !prefix.eq_ignore_ascii_case(macro_name) {
+            continue;
+        }
+        let Some(args) = line[macro_name.len()..].trim_start().strip_prefix('(') else {
+            continue;
+        };
+        if let Some(name) = args.split_whitespace().next() {
+            let name = name.trim_end_matches(')');
+            if !name.is_empty() && !names.iter().any(|n| n == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// `KEY:TYPE=VALUE` entries of a `CMakeCache.txt`.
+pub fn parse_cmake_cache(cache: &str) -> HashMap<String, String> {
+    cache
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.starts_with("//"))
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            let key = key.split_once(':').map_or(key, |(key, _)| key);
+            Some((key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// CMake's `if(<constant>)` truthiness.
+pub fn cmake_truthy(value: &str) -> bool {
+    let upper = value.trim().to_ascii_uppercase();
+    match upper.as_str() {
+        "" | "0" | "OFF" | "NO" | "FALSE" | "N" | "IGNORE" | "NOTFOUND" => false,
+        "1" | "ON" | "YES" | "TRUE" | "Y" => true,
+        other if other.ends_with("-NOTFOUND") => false,
+        other => other.parse::<f64>().is_ok_and(|n| n != 0.0),
+    }
+}
+
+/// Names enabled by `ASSIMP_BUILD_<NAME>_<KIND>` given `ASSIMP_BUILD_ALL_<KIND>S_BY_DEFAULT`.
+pub fn enabled_names(names: &[String], cache: &HashMap<String, String>, kind: &str) -> Vec<String> {
+    let by_default = cache
+        .get(&format!("ASSIMP_BUILD_ALL_{kind}S_BY_DEFAULT"))
+        .is_none_or(|v| cmake_truthy(v));
+    names
+        .iter()
+        .filter(|name| {
+            let option = cache.get(&format!("ASSIMP_BUILD_{name}_{kind}"));
+            if by_default {
+                option.is_none_or(|v| cmake_truthy(v))
+            } else {
+                option.is_some_and(|v| cmake_truthy(v))
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// Resolve the formats of a configured source build; `None` when the list file is missing
+/// or registers nothing.
+pub fn resolve(cmake_lists: &str, cache: &str) -> Option<BuiltFormats> {
+    let importers = registered_names(cmake_lists, "ADD_ASSIMP_IMPORTER");
+    if importers.is_empty() {
+        return None;
+    }
+    let exporters = registered_names(cmake_lists, "ADD_ASSIMP_EXPORTER");
+    let cache = parse_cmake_cache(cache);
+    let no_export = cache
+        .get("ASSIMP_NO_EXPORT")
+        .is_some_and(|v| cmake_truthy(v));
+    Some(BuiltFormats {
+        importers: enabled_names(&importers, &cache, "IMPORTER"),
+        exporters: if no_export {
+            Vec::new()
+        } else {
+            enabled_names(&exporters, &cache, "EXPORTER")
+        },
+    })
+}
+
+/// Rust source for the generated constants.
+pub fn render(formats: Option<&BuiltFormats>) -> String {
+    let list = |names: Option<&Vec<String>>| {
+        let items: Vec<String> = names
+            .into_iter()
+            .flatten()
+            .map(|name| format!("{name:?}"))
+            .collect();
+        format!("&[{}]", items.join(", "))
+    };
+    format!(
+        "/// Whether [`BUILT_IMPORTERS`] and [`BUILT_EXPORTERS`] describe the linked Assimp.\n\
+         ///\n\
+         /// `true` only when this crate built Assimp from source; system and prebuilt\n\
+         /// libraries have to be queried at runtime.\n\
+         pub const BUILT_FORMATS_KNOWN: bool = {known};\n\n\
+         /// Importers compiled into Assimp by CMake name (`ASSIMP_BUILD_<NAME>_IMPORTER`),\n\
+         /// empty unless [`BUILT_FORMATS_KNOWN`].\n\
+         pub const BUILT_IMPORTERS: &[&str] = {importers};\n\n\
+         /// Exporters compiled into Assimp by CMake name (`ASSIMP_BUILD_<NAME>_EXPORTER`),\n\
+         /// empty unless [`BUILT_FORMATS_KNOWN`].\n\
+         pub const BUILT_EXPORTERS: &[&str] = {exporters};\n",
+        known = formats.is_some(),
+        importers = list(formats.map(|f| &f.importers)),
+        exporters = list(formats.map(|f| &f.exporters)),
+    )
+}
+
+/// Write `built_formats.rs` for a source build configured under `cmake_build_dir`, or the
+/// "unknown" variant when either path is `None` or unreadable.
+pub fn write(
+    out_dir: &Path,
+    assimp_src: Option<&Path>,
+    cmake_build_dir: Option<&Path>,
+) -> io::Result<()> {
+    let formats = assimp_src.zip(cmake_build_dir).and_then(|(src, build)| {
+        let lists = fs::read_to_string(src.join("code").join("CMakeLists.txt")).ok()?;
+        let cache = fs::read_to_string(build.join("CMakeCache.txt")).ok()?;
+        resolve(&lists, &cache)
+    });
+    fs::write(out_dir.join(OUTPUT_FILE), render(formats.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LISTS: &str = "\
+MACRO(ADD_ASSIMP_IMPORTER name)
+  # ...
+ENDMACRO()
+ADD_ASSIMP_IMPORTER( AMF
+  AssetLib/AMF/AMFImporter.hpp
+)
+ADD_ASSIMP_IMPORTER( OBJ
+  AssetLib/Obj/ObjFileImporter.cpp
+)
+ADD_ASSIMP_IMPORTER(FBX AssetLib/FBX/FBXImporter.cpp)
+ADD_ASSIMP_EXPORTER( OBJ
+  AssetLib/Obj/ObjExporter.cpp)
+ADD_ASSIMP_EXPORTER( STL AssetLib/STL/STLExporter.cpp)
+";
+
+    #[test]
+    fn test_registered_names() {
+        assert_eq!(
+            registered_names(LISTS, "ADD_ASSIMP_IMPORTER"),
+            ["AMF", "OBJ", "FBX"]
+        );
+        assert_eq!(
+            registered_names(LISTS, "ADD_ASSIMP_EXPORTER"),
+            ["OBJ", "STL"]
+        );
+    }
+
+    #[test]
+    fn test_defaults_enable_everything() {
+        let formats = resolve(LISTS, "").expect("formats");
+        assert_eq!(formats.importers, ["AMF", "OBJ", "FBX"]);
+        assert_eq!(formats.exporters, ["OBJ", "STL"]);
+    }
+
+    #[test]
+    fn test_cache_overrides() {
+        let cache = "\
+// Build AMF importer
+ASSIMP_BUILD_AMF_IMPORTER:BOOL=OFF
+ASSIMP_BUILD_STL_EXPORTER:BOOL=FALSE
+";
+        let formats = resolve(LISTS, cache).expect("formats");
+        assert_eq!(formats.importers, ["OBJ", "FBX"]);
+        assert_eq!(formats.exporters, ["OBJ"]);
+
+        let cache = "\
+ASSIMP_BUILD_ALL_IMPORTERS_BY_DEFAULT:BOOL=OFF
+ASSIMP_BUILD_FBX_IMPORTER:BOOL=ON
+ASSIMP_NO_EXPORT:BOOL=ON
+";
+        let formats = resolve(LISTS, cache).expect("formats");
+        assert_eq!(formats.importers, ["FBX"]);
+        assert!(formats.exporters.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_without_sources() {
+        assert_eq!(resolve("", ""), None);
+        let rendered = render(None);
+        assert!(rendered.contains("BUILT_FORMATS_KNOWN: bool = false"));
+        assert!(rendered.contains("BUILT_IMPORTERS: &[&str] = &[]"));
+    }
+
+    #[test]
+    fn test_cmake_truthy() {
+        for value in ["ON", "on", "1", "TRUE", "Yes", "2"] {
+            assert!(cmake_truthy(value), "{value}");
+        }
+        for value in ["OFF", "0", "", "FALSE", "no", "ZLIB-NOTFOUND"] {
+            assert!(!cmake_truthy(value), "{value}");
+        }
+    }
+}
@@ -1,10 +1,13 @@
 pub mod bindings;
 pub mod bridge;
 pub mod config;
+pub mod formats;
 pub mod plan;
 pub mod system_deps;
 pub mod util;
 
+#[cfg(feature = "prebuilt")]
+pub mod archive;
 #[cfg(feature = "prebuilt")]
 pub mod prebuilt;
 
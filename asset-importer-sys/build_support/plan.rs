@@ -69,6 +69,17 @@ pub fn resolve(cfg: &BuildConfig) -> BuildPlan {
         LinkKind::Dynamic
     };
 
+    // Emscripten: always a static source build; there are no system or prebuilt libraries.
+    if cfg!(feature = "wasm") || cfg.is_emscripten() {
+        if !cfg.is_emscripten() {
+            panic!(
+                "feature `wasm` requires the wasm32-unknown-emscripten target (building for {}).",
+                cfg.target
+            );
+        }
+        return crate::build_support::vendored::build(cfg, LinkKind::Static);
+    }
+
     if cfg!(feature = "system") {
         #[cfg(not(feature = "system"))]
         {
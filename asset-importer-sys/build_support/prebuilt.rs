@@ -1,16 +1,29 @@
-use std::{env, fs, path::PathBuf};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
 
 use crate::build_support::{
+    archive::{self, ArchiveSettings, ArchiveSource},
     config::BuildConfig,
     plan::{BuildMethod, BuildPlan, LinkKind},
     util,
 };
 
 use flate2::read::GzDecoder;
+use sha2::{Digest, Sha256};
 use tar::Archive;
 
 const PACKAGE_PREFIX: &str = "asset-importer";
 
+/// Lowercase hex SHA-256 of a file.
+fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 fn vendored_assimp_version() -> &'static str {
     include_str!("../assimp-version.txt").trim()
 }
@@ -42,18 +55,45 @@ pub fn prepare(cfg: &BuildConfig, link_kind: LinkKind) -> BuildPlan {
         PACKAGE_PREFIX, crate_version, target, link_type
     ));
 
-    let cache_root = cache_root(cfg);
-    let package_root = env::var("ASSET_IMPORTER_PACKAGE_DIR")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| cache_root.clone());
+    let settings = ArchiveSettings {
+        explicit_archive: env::var_os("ASSET_IMPORTER_PREBUILT_ARCHIVE").map(PathBuf::from),
+        package_dir: env::var_os("ASSET_IMPORTER_PACKAGE_DIR").map(PathBuf::from),
+        cache_root: cache_root(cfg),
+        offline: cfg.offline,
+    };
+
+    let archive_path = match archive::select_archive(&settings, &archive_names) {
+        Ok(ArchiveSource::Local(path)) => path,
+        Ok(ArchiveSource::Download(dest_dir)) => download(cfg, &dest_dir, &archive_names),
+        Err(msg) => panic!("{}", msg),
+    };
 
-    // Ensure archive(s) exist: download into cache_root when not provided locally.
-    if env::var("ASSET_IMPORTER_PACKAGE_DIR").is_err() {
-        download_if_needed(cfg, &cache_root, &archive_names);
+    let digest = sha256_file(&archive_path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to read prebuilt archive {}: {}",
+            archive_path.display(),
+            e
+        )
+    });
+    if let Ok(expected) = env::var("ASSET_IMPORTER_PREBUILT_SHA256") {
+        archive::check_sha256(&digest, &expected, &archive_path)
+            .unwrap_or_else(|msg| panic!("{}", msg));
     }
 
-    let extract_dir = extract_dir(cfg, link_type, crt_suffix);
-    extract_archive(&package_root, &archive_names, &extract_dir);
+    let extract_dir = archive::extraction_dir(&cfg.out_dir, &digest);
+    extract_archive(&archive_path, &extract_dir);
+
+    // Reject a static archive on a dylib build (and vice versa) before anything is linked.
+    if let Err(msg) = archive::validate_package_dir(&extract_dir, link_type, &cfg.target_os) {
+        panic!(
+            "prebuilt archive {} does not match this build: {}\n\
+             Hint: use the `{}` archive for {}, or rebuild it with the `package` tool.",
+            archive_path.display(),
+            msg,
+            link_type,
+            target
+        );
+    }
 
     let include_dir = extract_dir.join("include");
     let lib_dir = if extract_dir.join("lib").exists() {
@@ -356,47 +396,9 @@ fn cache_root(cfg: &BuildConfig) -> PathBuf {
     target_dir.join("asset-importer-prebuilt")
 }
 
-fn extract_dir(cfg: &BuildConfig, link_type: &str, crt_suffix: Option<&str>) -> PathBuf {
-    let root = cache_root(cfg);
-    let crate_version = env::var("CARGO_PKG_VERSION").unwrap();
-    let subdir = if let Some(crt) = crt_suffix {
-        format!("{}-{}", link_type, crt)
-    } else {
-        link_type.to_string()
-    };
-    root.join(crate_version).join(&cfg.target).join(subdir)
-}
-
-fn pick_archive_name(root: &std::path::Path, candidates: &[String]) -> PathBuf {
-    for name in candidates {
-        let p = root.join(name);
-        if p.exists() {
-            return p;
-        }
-    }
-    panic!(
-        "prebuilt package not found in {} with any of {:?}",
-        root.display(),
-        candidates
-    );
-}
-
-fn download_if_needed(cfg: &BuildConfig, cache_root: &std::path::Path, archive_names: &[String]) {
+fn download(cfg: &BuildConfig, cache_root: &std::path::Path, archive_names: &[String]) -> PathBuf {
     fs::create_dir_all(cache_root).expect("Failed to create prebuilt cache directory");
 
-    // Skip download if any candidate archive is already present.
-    if archive_names.iter().any(|n| cache_root.join(n).exists()) {
-        return;
-    }
-
-    if cfg.offline {
-        panic!(
-            "ASSET_IMPORTER_OFFLINE/CARGO_NET_OFFLINE is set but no prebuilt archive exists in {}\n\
-             Hint: set ASSET_IMPORTER_PACKAGE_DIR to a directory containing the prebuilt .tar.gz, or disable offline mode.",
-            cache_root.display()
-        );
-    }
-
     let crate_version = env::var("CARGO_PKG_VERSION").unwrap();
     let tag_formats = [
         format!("asset-importer-sys-v{}", crate_version),
@@ -451,7 +453,7 @@ fn download_if_needed(cfg: &BuildConfig, cache_root: &std::path::Path, archive_n
                     e
                 )
             });
-            return;
+            return dst;
         }
     }
 
@@ -461,24 +463,46 @@ fn download_if_needed(cfg: &BuildConfig, cache_root: &std::path::Path, archive_n
     );
 }
 
-fn extract_archive(root: &std::path::Path, candidates: &[String], dst: &std::path::Path) {
-    let archive_path = pick_archive_name(root, candidates);
-    let include_ok = dst.join("include").exists();
-    let lib_ok = dst.join("lib").exists() || dst.join("lib64").exists();
-    if include_ok && lib_ok {
+fn extract_archive(archive_path: &std::path::Path, dst: &std::path::Path) {
+    // The directory is keyed by the archive digest, so an existing extraction is complete:
+    // it is only ever created by the rename below.
+    if dst.join("include").exists() {
         return;
     }
 
-    if dst.exists() {
-        let _ = fs::remove_dir_all(dst);
+    let tmp = dst.with_extension(format!("tmp-{}", std::process::id()));
+    if tmp.exists() {
+        let _ = fs::remove_dir_all(&tmp);
     }
-    fs::create_dir_all(dst).expect("Failed to create extract directory");
+    fs::create_dir_all(&tmp).expect("Failed to create extract directory");
 
-    let file = fs::File::open(&archive_path).expect("Failed to open prebuilt archive");
-    let mut archive = Archive::new(GzDecoder::new(file));
-    archive
-        .unpack(dst)
-        .expect("Failed to extract prebuilt archive");
+    let file = fs::File::open(archive_path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to open prebuilt archive {}: {}",
+            archive_path.display(),
+            e
+        )
+    });
+    Archive::new(GzDecoder::new(file))
+        .unpack(&tmp)
+        .unwrap_or_else(|e| {
+            panic!(
+                "Failed to extract prebuilt archive {}: {}",
+                archive_path.display(),
+                e
+            )
+        });
+
+    if fs::rename(&tmp, dst).is_err() {
+        // Another build extracted the same archive concurrently; keep theirs.
+        let _ = fs::remove_dir_all(&tmp);
+        if !dst.join("include").exists() {
+            panic!(
+                "Failed to move extracted prebuilt package into {}",
+                dst.display()
+            );
+        }
+    }
 }
 
 fn detect_windows_import_lib(lib_dir: &std::path::Path) -> Option<String> {
@@ -17,6 +17,14 @@ pub fn emit(cfg: &BuildConfig) {
         return;
     }
 
+    if cfg.is_emscripten() {
+        // emcc links libc++ itself; exception support has to be enabled at link time too,
+        // and models routinely outgrow the initial heap.
+        println!("cargo:rustc-link-arg=-fexceptions");
+        println!("cargo:rustc-link-arg=-sALLOW_MEMORY_GROWTH=1");
+        return;
+    }
+
     if cfg.is_macos() {
         println!("cargo:rustc-link-lib=c++");
         // Keep parity with existing behavior (some toolchains use Foundation APIs for file/locale).
@@ -46,17 +46,20 @@ pub fn build(cfg: &BuildConfig, link_kind: LinkKind) -> BuildPlan {
     };
 
     // For unix static builds, link system zlib explicitly when enabled.
-    if !cfg.is_windows() && matches!(link_kind, LinkKind::Static) && !cfg!(feature = "nozlib") {
+    if !cfg.is_windows()
+        && !cfg.is_emscripten()
+        && matches!(link_kind, LinkKind::Static)
+        && !cfg!(feature = "nozlib")
+    {
         println!("cargo:rustc-link-lib=z");
     }
 
-    // For Windows static builds, link bundled zlib if present.
-    if cfg.is_windows()
-        && cfg.is_msvc()
+    // For Windows and Emscripten static builds, link bundled zlib if present.
+    if ((cfg.is_windows() && cfg.is_msvc()) || cfg.is_emscripten())
         && matches!(link_kind, LinkKind::Static)
         && !cfg!(feature = "nozlib")
     {
-        link_windows_zlib(&dst, cfg);
+        link_bundled_zlib(&dst, cfg);
     }
 
     // For Windows shared builds, copy DLLs next to test binaries.
@@ -177,11 +180,11 @@ fn build_assimp_with_cmake(
     cmake_config.define("ASSIMP_NO_EXPORT", "OFF");
 
     // zlib strategy:
-    // - Windows: build bundled zlib (default for Assimp)
+    // - Windows and Emscripten: build bundled zlib (default for Assimp)
     // - Unix: use system zlib (faster and more predictable); link `-lz` for static builds.
     if cfg!(feature = "nozlib") {
         cmake_config.define("ASSIMP_BUILD_ZLIB", "OFF");
-    } else if cfg.is_windows() {
+    } else if cfg.is_windows() || cfg.is_emscripten() {
         cmake_config.define("ASSIMP_BUILD_ZLIB", "ON");
     } else {
         cmake_config.define("ASSIMP_BUILD_ZLIB", "OFF");
@@ -222,6 +225,8 @@ fn build_assimp_with_cmake(
         }
     } else if cfg.is_macos() {
         cmake_config.define("CMAKE_OSX_DEPLOYMENT_TARGET", cfg.macos_deployment_target());
+    } else if cfg.is_emscripten() {
+        configure_emscripten(cfg, &mut cmake_config);
     }
 
     if cfg.verbose {
@@ -234,6 +239,32 @@ fn build_assimp_with_cmake(
     cmake_config.build()
 }
 
+fn configure_emscripten(cfg: &BuildConfig, cmake_config: &mut cmake::Config) {
+    // An explicit CMAKE_TOOLCHAIN_FILE (e.g. from `emcmake`) wins.
+    if env::var_os("CMAKE_TOOLCHAIN_FILE").is_none() {
+        let toolchain = cfg.emscripten_toolchain_file().unwrap_or_else(|| {
+            panic!(
+                "Building Assimp for {} needs the Emscripten SDK.\n\
+                 Hint: run `source <emsdk>/emsdk_env.sh` (sets EMSDK and EMSCRIPTEN) or set \
+                 CMAKE_TOOLCHAIN_FILE to Emscripten.cmake",
+                cfg.target
+            )
+        });
+        cmake_config.define("CMAKE_TOOLCHAIN_FILE", toolchain);
+    }
+
+    // Exporters are dead weight in the browser. The export C API stays compiled in
+    // (ASSIMP_NO_EXPORT=OFF above) because aiCopyScene lives behind it.
+    cmake_config.define("ASSIMP_BUILD_ALL_EXPORTERS_BY_DEFAULT", "OFF");
+
+    // Assimp reports import failures with C++ exceptions, which Emscripten disables by
+    // default. Threads stay off so the module runs without SharedArrayBuffer.
+    for flag in ["-fexceptions", "-sUSE_PTHREADS=0"] {
+        cmake_config.cflag(flag);
+        cmake_config.cxxflag(flag);
+    }
+}
+
 fn detect_windows_assimp_lib(dst: &std::path::Path, cfg: &BuildConfig) -> Option<String> {
     let cmake_dir = cfg.cmake_profile();
     let candidates = [
@@ -315,7 +346,7 @@ fn detect_unix_assimp_lib(dst: &std::path::Path, cfg: &BuildConfig) -> Option<St
     })
 }
 
-fn link_windows_zlib(dst: &std::path::Path, cfg: &BuildConfig) {
+fn link_bundled_zlib(dst: &std::path::Path, cfg: &BuildConfig) {
     let cmake_dir = cfg.cmake_profile();
     let candidates = [
         dst.join("build")
@@ -337,8 +368,17 @@ fn link_windows_zlib(dst: &std::path::Path, cfg: &BuildConfig) {
                 continue;
             };
             let lower = name.to_ascii_lowercase();
-            if lower.ends_with(".lib") && (lower.contains("zlibstatic") || lower == "zlib.lib") {
+            // MSVC: `zlibstatic.lib` / `zlib.lib`; Emscripten: `libzlibstatic.a`.
+            let is_msvc_zlib =
+                lower.ends_with(".lib") && (lower.contains("zlibstatic") || lower == "zlib.lib");
+            let is_unix_zlib = lower.ends_with(".a") && lower.contains("zlibstatic");
+            if is_msvc_zlib || is_unix_zlib {
                 if let Some(stem) = p.file_stem().and_then(|s| s.to_str()) {
+                    let stem = if is_unix_zlib {
+                        stem.strip_prefix("lib").unwrap_or(stem)
+                    } else {
+                        stem
+                    };
                     println!("cargo:rustc-link-search=native={}", dir.display());
                     println!("cargo:rustc-link-lib=static={}", stem);
                     return;
@@ -2333,6 +2333,120 @@ unsafe extern "C" {
         props_count: usize,
     ) -> *const aiExportDataBlob;
 }
+unsafe extern "C" {
+    pub fn aiExportSceneWithProgressRust(
+        scene: *const aiScene,
+        format_id: *const ::std::os::raw::c_char,
+        path: *const ::std::os::raw::c_char,
+        file_io: *const aiFileIO,
+        preprocessing: ::std::os::raw::c_uint,
+        props: *const aiRustProperty,
+        props_count: usize,
+        progress_cb: aiRustProgressCallback,
+        progress_user: *mut ::std::os::raw::c_void,
+    ) -> aiReturn;
+}
+unsafe extern "C" {
+    pub fn aiExportSceneToBlobWithProgressRust(
+        scene: *const aiScene,
+        format_id: *const ::std::os::raw::c_char,
+        preprocessing: ::std::os::raw::c_uint,
+        props: *const aiRustProperty,
+        props_count: usize,
+        progress_cb: aiRustProgressCallback,
+        progress_user: *mut ::std::os::raw::c_void,
+    ) -> *const aiExportDataBlob;
+}
+unsafe extern "C" {
+    pub fn aiCopySceneRust(scene: *const aiScene) -> *mut aiScene;
+}
+unsafe extern "C" {
+    pub fn aiPruneSceneRust(
+        scene: *mut aiScene,
+        root_name: *const ::std::os::raw::c_char,
+        mesh_indices: *const ::std::os::raw::c_uint,
+        mesh_count: usize,
+        filter_meshes: bool,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn aiMaterialSetPropertyRust(
+        material: *mut aiMaterial,
+        key: *const ::std::os::raw::c_char,
+        semantic: ::std::os::raw::c_uint,
+        index: ::std::os::raw::c_uint,
+        type_: aiPropertyTypeInfo,
+        data: *const ::std::os::raw::c_void,
+        length: ::std::os::raw::c_uint,
+    ) -> bool;
+}
+unsafe extern "C" {
+    pub fn aiMaterialRemovePropertyRust(
+        material: *mut aiMaterial,
+        key: *const ::std::os::raw::c_char,
+        semantic: ::std::os::raw::c_uint,
+        index: ::std::os::raw::c_uint,
+    ) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn aiSceneAddMaterialRust(scene: *mut aiScene) -> *mut aiMaterial;
+}
+unsafe extern "C" {
+    pub fn aiCreateSceneRust(root_name: *const ::std::os::raw::c_char) -> *mut aiScene;
+}
+unsafe extern "C" {
+    pub fn aiSceneAddMeshRust(
+        scene: *mut aiScene,
+        name: *const ::std::os::raw::c_char,
+        positions: *const f32,
+        normals: *const f32,
+        uvs: *const f32,
+        num_vertices: ::std::os::raw::c_uint,
+        indices: *const ::std::os::raw::c_uint,
+        num_indices: ::std::os::raw::c_uint,
+        material_index: ::std::os::raw::c_uint,
+    ) -> *mut aiMesh;
+}
+unsafe extern "C" {
+    pub fn aiNodeAddChildRust(
+        parent: *mut aiNode,
+        name: *const ::std::os::raw::c_char,
+        transform: *const aiMatrix4x4,
+        meshes: *const ::std::os::raw::c_uint,
+        num_meshes: ::std::os::raw::c_uint,
+    ) -> *mut aiNode;
+}
+unsafe extern "C" {
+    pub fn aiGetLastImporterIndexRust(importer_index: *mut usize) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn aiDetectImporterFromMemoryRust(
+        data: *const ::std::os::raw::c_char,
+        length: usize,
+        importer_index: *mut usize,
+    ) -> ::std::os::raw::c_int;
+}
 unsafe extern "C" {
     pub fn aiGetLastErrorStringRust() -> *const ::std::os::raw::c_char;
 }
+pub type aiRustLogCallback = ::std::option::Option<
+    unsafe extern "C" fn(severity: ::std::os::raw::c_uint, message: *const ::std::os::raw::c_char),
+>;
+unsafe extern "C" {
+    pub fn aiSetLogForwardRust(callback: aiRustLogCallback, severity_mask: ::std::os::raw::c_uint);
+}
+unsafe extern "C" {
+    pub fn aiBeginLogCaptureRust(severity_mask: ::std::os::raw::c_uint);
+}
+unsafe extern "C" {
+    pub fn aiEndLogCaptureRust();
+}
+unsafe extern "C" {
+    pub fn aiGetCapturedLogCountRust() -> usize;
+}
+unsafe extern "C" {
+    pub fn aiGetCapturedLogRust(
+        index: usize,
+        severity: *mut ::std::os::raw::c_uint,
+    ) -> *const ::std::os::raw::c_char;
+}
@@ -14,10 +14,18 @@
     all(feature = "system", feature = "prebuilt"),
     all(feature = "system", feature = "build-assimp"),
     all(feature = "prebuilt", feature = "build-assimp"),
+    all(feature = "wasm", feature = "system"),
+    all(feature = "wasm", feature = "prebuilt"),
 ))]
 compile_error!(
-    "Build mode features are mutually exclusive. Use at most one of: `system`, `prebuilt`, `build-assimp`.\n\
-     Hint: use `system` to link against a system-installed Assimp, `prebuilt` to download prebuilt binaries, or `build-assimp` to build from source."
+    "Build mode features are mutually exclusive. Use at most one of: `system`, `prebuilt`, `build-assimp`/`wasm`.\n\
+     Hint: use `system` to link against a system-installed Assimp, `prebuilt` to download prebuilt binaries, `build-assimp` to build from source, or `wasm` to build from source for Emscripten."
+);
+
+#[cfg(all(feature = "wasm", not(target_os = "emscripten")))]
+compile_error!(
+    "feature `wasm` only applies to the `wasm32-unknown-emscripten` target.\n\
+     Hint: build with `--target wasm32-unknown-emscripten`, or drop the feature for native builds."
 );
 
 #[cfg(all(feature = "system", not(feature = "generate-bindings")))]
@@ -29,6 +37,9 @@ compile_error!(
 // Include the generated bindings
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+// Importers/exporters compiled into a source build (`BUILT_IMPORTERS`, ...).
+include!(concat!(env!("OUT_DIR"), "/built_formats.rs"));
+
 // Re-export commonly used types for convenience
 pub use aiImporterDesc as ImporterDesc;
 pub use aiScene as Scene;
@@ -43,6 +54,15 @@ pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
 // Include tests
 mod test;
 
+// Build-script archive selection is std-only, so its unit tests run with the crate's.
+#[cfg(test)]
+#[path = "../build_support/archive.rs"]
+mod build_support_archive;
+
+#[cfg(test)]
+#[path = "../build_support/formats.rs"]
+mod build_support_formats;
+
 // Include type extensions (optional convenience implementations)
 #[cfg(feature = "type-extensions")]
 pub mod types;
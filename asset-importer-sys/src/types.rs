@@ -10,7 +10,7 @@ use crate::*;
 impl aiVector3D {
     /// Create a new 3D vector
     #[inline]
-    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(x: ai_real, y: ai_real, z: ai_real) -> Self {
         Self { x, y, z }
     }
 
@@ -42,7 +42,7 @@ impl aiVector3D {
 impl aiVector2D {
     /// Create a new 2D vector
     #[inline]
-    pub const fn new(x: f32, y: f32) -> Self {
+    pub const fn new(x: ai_real, y: ai_real) -> Self {
         Self { x, y }
     }
 
@@ -96,7 +96,7 @@ impl aiColor4D {
 impl aiQuaternion {
     /// Create a new quaternion
     #[inline]
-    pub const fn new(w: f32, x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(w: ai_real, x: ai_real, y: ai_real, z: ai_real) -> Self {
         Self { w, x, y, z }
     }
 
@@ -108,58 +108,58 @@ impl aiQuaternion {
 }
 
 // Conversions from standard Rust types
-impl From<[f32; 3]> for aiVector3D {
+impl From<[ai_real; 3]> for aiVector3D {
     #[inline]
-    fn from(array: [f32; 3]) -> Self {
+    fn from(array: [ai_real; 3]) -> Self {
         Self::new(array[0], array[1], array[2])
     }
 }
 
-impl From<(f32, f32, f32)> for aiVector3D {
+impl From<(ai_real, ai_real, ai_real)> for aiVector3D {
     #[inline]
-    fn from((x, y, z): (f32, f32, f32)) -> Self {
+    fn from((x, y, z): (ai_real, ai_real, ai_real)) -> Self {
         Self::new(x, y, z)
     }
 }
 
-impl From<aiVector3D> for [f32; 3] {
+impl From<aiVector3D> for [ai_real; 3] {
     #[inline]
-    fn from(v: aiVector3D) -> [f32; 3] {
+    fn from(v: aiVector3D) -> [ai_real; 3] {
         [v.x, v.y, v.z]
     }
 }
 
-impl From<aiVector3D> for (f32, f32, f32) {
+impl From<aiVector3D> for (ai_real, ai_real, ai_real) {
     #[inline]
-    fn from(v: aiVector3D) -> (f32, f32, f32) {
+    fn from(v: aiVector3D) -> (ai_real, ai_real, ai_real) {
         (v.x, v.y, v.z)
     }
 }
 
-impl From<[f32; 2]> for aiVector2D {
+impl From<[ai_real; 2]> for aiVector2D {
     #[inline]
-    fn from(array: [f32; 2]) -> Self {
+    fn from(array: [ai_real; 2]) -> Self {
         Self::new(array[0], array[1])
     }
 }
 
-impl From<(f32, f32)> for aiVector2D {
+impl From<(ai_real, ai_real)> for aiVector2D {
     #[inline]
-    fn from((x, y): (f32, f32)) -> Self {
+    fn from((x, y): (ai_real, ai_real)) -> Self {
         Self::new(x, y)
     }
 }
 
-impl From<aiVector2D> for [f32; 2] {
+impl From<aiVector2D> for [ai_real; 2] {
     #[inline]
-    fn from(v: aiVector2D) -> [f32; 2] {
+    fn from(v: aiVector2D) -> [ai_real; 2] {
         [v.x, v.y]
     }
 }
 
-impl From<aiVector2D> for (f32, f32) {
+impl From<aiVector2D> for (ai_real, ai_real) {
     #[inline]
-    fn from(v: aiVector2D) -> (f32, f32) {
+    fn from(v: aiVector2D) -> (ai_real, ai_real) {
         (v.x, v.y)
     }
 }
@@ -183,14 +183,14 @@ impl From<[f32; 4]> for aiColor4D {
 mod mint_integration {
     use super::*;
 
-    impl From<mint::Vector3<f32>> for aiVector3D {
+    impl From<mint::Vector3<ai_real>> for aiVector3D {
         #[inline]
-        fn from(v: mint::Vector3<f32>) -> Self {
+        fn from(v: mint::Vector3<ai_real>) -> Self {
             Self::new(v.x, v.y, v.z)
         }
     }
 
-    impl From<aiVector3D> for mint::Vector3<f32> {
+    impl From<aiVector3D> for mint::Vector3<ai_real> {
         #[inline]
         fn from(v: aiVector3D) -> Self {
             mint::Vector3 {
@@ -201,28 +201,28 @@ mod mint_integration {
         }
     }
 
-    impl From<mint::Vector2<f32>> for aiVector2D {
+    impl From<mint::Vector2<ai_real>> for aiVector2D {
         #[inline]
-        fn from(v: mint::Vector2<f32>) -> Self {
+        fn from(v: mint::Vector2<ai_real>) -> Self {
             Self::new(v.x, v.y)
         }
     }
 
-    impl From<aiVector2D> for mint::Vector2<f32> {
+    impl From<aiVector2D> for mint::Vector2<ai_real> {
         #[inline]
         fn from(v: aiVector2D) -> Self {
             mint::Vector2 { x: v.x, y: v.y }
         }
     }
 
-    impl From<mint::Quaternion<f32>> for aiQuaternion {
+    impl From<mint::Quaternion<ai_real>> for aiQuaternion {
         #[inline]
-        fn from(q: mint::Quaternion<f32>) -> Self {
+        fn from(q: mint::Quaternion<ai_real>) -> Self {
             Self::new(q.s, q.v.x, q.v.y, q.v.z)
         }
     }
 
-    impl From<aiQuaternion> for mint::Quaternion<f32> {
+    impl From<aiQuaternion> for mint::Quaternion<ai_real> {
         #[inline]
         fn from(q: aiQuaternion) -> Self {
             mint::Quaternion {
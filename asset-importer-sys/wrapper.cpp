@@ -2,49 +2,78 @@
  * C++ wrapper implementations for Assimp bindings
  *
  * This file adds bridging for:
- * - Progress callbacks via Assimp::ProgressHandler
+ * - Progress callbacks via Assimp::ProgressHandler (import and export)
  * - Custom IO via Assimp::IOSystem wrapping C aiFileIO
  * - Property passing to Assimp::Importer / Assimp::ExportProperties
+ * - Per-thread capture of Assimp::DefaultLogger output
+ * - In-place pruning of copied scenes (sub-scene extraction)
  */
 
 #include "wrapper.h"
 
 #include <assimp/Importer.hpp>
+#include <assimp/DefaultLogger.hpp>
+#include <assimp/LogStream.hpp>
 #ifndef ASSIMP_BUILD_NO_EXPORT
 #include <assimp/Exporter.hpp>
 #endif
 #include <assimp/IOSystem.hpp>
 #include <assimp/IOStream.hpp>
+#include <assimp/BaseImporter.h>
+#include <assimp/MemoryIOWrapper.h>
 #include <assimp/ProgressHandler.hpp>
 #include <assimp/cexport.h> // aiCopyScene
+#include <atomic>
+#include <cstdint>
 #include <cstdio>
+#include <cstdlib>
 #include <cstring>
 #include <exception>
+#include <memory>
 #include <mutex>
+#include <set>
+#include <stdexcept>
 #include <string>
+#include <utility>
+#include <vector>
 
 // Store a thread-local last-error message for the bridge
 static thread_local std::string g_last_error_msg;
 
+// Log lines captured on this thread between aiBeginLogCaptureRust / aiEndLogCaptureRust
+static thread_local std::vector<std::pair<unsigned int, std::string>> g_captured_log;
+static thread_local unsigned int g_log_capture_mask = 0u;
+static thread_local bool g_log_capturing = false;
+// Index of the importer that read the last successful bridge import on this thread.
+static thread_local long long g_last_importer_index = -1;
+
 namespace {
 
 struct BridgeProgressHandler final : public Assimp::ProgressHandler {
     aiRustProgressCallback cb{nullptr};
     void* user{nullptr};
+    // Assimp ignores the result of the UpdateFile*/UpdatePostProcess hooks, so a
+    // cancellation is remembered here and checked once ReadFile returns.
+    bool cancelled{false};
 
     explicit BridgeProgressHandler(aiRustProgressCallback c, void* u) : cb(c), user(u) {}
 
+    bool forward(float percentage, const char* message) {
+        if (!cb || cancelled) return !cancelled;
+        if (!cb(percentage, message, user)) cancelled = true;
+        return !cancelled;
+    }
+
     bool Update(float percentage = -1.f) override {
-        if (!cb) return true;
         // No message variant
-        return cb(percentage, nullptr, user);
+        return forward(percentage, nullptr);
     }
 
     void UpdateFileRead(int currentStep = 0, int numberOfSteps = 0) override {
         if (!cb) return;
         char buf[64];
         std::snprintf(buf, sizeof(buf), "read %d/%d", currentStep, numberOfSteps);
-        (void)cb(numberOfSteps ? (currentStep / (float)numberOfSteps) * 0.5f : 0.0f, buf, user);
+        (void)forward(numberOfSteps ? (currentStep / (float)numberOfSteps) * 0.5f : 0.0f, buf);
     }
 
     void UpdatePostProcess(int currentStep = 0, int numberOfSteps = 0) override {
@@ -52,7 +81,7 @@ struct BridgeProgressHandler final : public Assimp::ProgressHandler {
         char buf[64];
         std::snprintf(buf, sizeof(buf), "post %d/%d", currentStep, numberOfSteps);
         float f = numberOfSteps ? (currentStep / (float)numberOfSteps) : 1.0f;
-        (void)cb(f * 0.5f + 0.5f, buf, user);
+        (void)forward(f * 0.5f + 0.5f, buf);
     }
 
     void UpdateFileWrite(int currentStep = 0, int numberOfSteps = 0) override {
@@ -60,10 +89,44 @@ struct BridgeProgressHandler final : public Assimp::ProgressHandler {
         char buf[64];
         std::snprintf(buf, sizeof(buf), "write %d/%d", currentStep, numberOfSteps);
         float f = numberOfSteps ? (currentStep / (float)numberOfSteps) : 1.0f;
-        (void)cb(f * 0.5f, buf, user);
+        (void)forward(f * 0.5f, buf);
     }
 };
 
+#ifndef ASSIMP_BUILD_NO_EXPORT
+// Thrown by ExportProgressHandler to stop Assimp::Exporter::Export mid-way.
+struct ExportCancelled final : public std::exception {
+    const char* what() const noexcept override { return "Export cancelled"; }
+};
+
+// Assimp::Exporter reports its stages through UpdateFileWrite and, like the importer,
+// ignores the result. Throwing is the only way to stop it; the exporter's exception
+// region (or the bridge) catches the exception and the export fails.
+struct ExportProgressHandler final : public Assimp::ProgressHandler {
+    aiRustProgressCallback cb{nullptr};
+    void* user{nullptr};
+    bool cancelled{false};
+
+    explicit ExportProgressHandler(aiRustProgressCallback c, void* u) : cb(c), user(u) {}
+
+    bool forward(float percentage, const char* message) {
+        if (!cancelled && !cb(percentage, message, user)) cancelled = true;
+        return !cancelled;
+    }
+
+    bool Update(float percentage = -1.f) override {
+        return forward(percentage, nullptr);
+    }
+
+    void UpdateFileWrite(int currentStep = 0, int numberOfSteps = 0) override {
+        char buf[64];
+        std::snprintf(buf, sizeof(buf), "write %d/%d", currentStep, numberOfSteps);
+        float f = numberOfSteps ? (currentStep / (float)numberOfSteps) : 1.0f;
+        if (!forward(f, buf)) throw ExportCancelled();
+    }
+};
+#endif
+
 // IOStream wrapper
 class RustIOStream final : public Assimp::IOStream {
 public:
@@ -146,6 +209,82 @@ public:
     }
 };
 
+// Process-wide forwarding target set by aiSetLogForwardRust. Read without locking so
+// forwarding never blocks importing threads.
+static std::atomic<aiRustLogCallback> g_log_forward{nullptr};
+static std::atomic<unsigned int> g_log_forward_mask{0u};
+
+// DefaultLogger formats lines as "<Level>,  T<thread>: <message>\n"; keep only the message.
+static std::string strip_log_prefix(const char* message) {
+    std::string line(message);
+    const size_t prefix = line.find(": ");
+    if (prefix != std::string::npos && line.find(",  T") < prefix) {
+        line.erase(0, prefix + 2);
+    }
+    while (!line.empty() && (line.back() == '\n' || line.back() == '\r')) {
+        line.pop_back();
+    }
+    return line;
+}
+
+// Routes DefaultLogger output into the calling thread's capture buffer and to the
+// forwarding callback, if one is set.
+// Assimp logs synchronously on the importing thread, so thread-local routing keeps
+// concurrent imports from seeing each other's messages.
+class CaptureLogStream final : public Assimp::LogStream {
+public:
+    explicit CaptureLogStream(unsigned int severity) : m_severity(severity) {}
+
+    void write(const char* message) override {
+        if (!message) return;
+        const bool capture = g_log_capturing && (g_log_capture_mask & m_severity) != 0u;
+        const aiRustLogCallback forward = g_log_forward.load(std::memory_order_acquire);
+        const bool do_forward =
+            forward && (g_log_forward_mask.load(std::memory_order_relaxed) & m_severity) != 0u;
+        if (!capture && !do_forward) return;
+        try {
+            std::string line = strip_log_prefix(message);
+            if (do_forward) {
+                forward(m_severity, line.c_str());
+            }
+            if (capture) {
+                g_captured_log.emplace_back(m_severity, std::move(line));
+            }
+        } catch (...) {
+            // Never let a logging failure propagate into the importer.
+        }
+    }
+
+private:
+    unsigned int m_severity;
+};
+
+static std::mutex g_capture_logger_mutex;
+static Assimp::Logger* g_capture_logger = nullptr;
+
+// Ensure a DefaultLogger exists and carries our capture streams. The logger is never
+// killed here: other threads may be logging through it concurrently.
+static void install_capture_streams() {
+    std::lock_guard<std::mutex> lock(g_capture_logger_mutex);
+    if (Assimp::DefaultLogger::isNullLogger()) {
+        Assimp::DefaultLogger::create(nullptr, Assimp::Logger::NORMAL, 0u);
+    }
+    Assimp::Logger* logger = Assimp::DefaultLogger::get();
+    if (logger == g_capture_logger) return;
+
+    // The logger owns attached streams and deletes them when it is destroyed.
+    const unsigned int severities[] = {
+        Assimp::Logger::Debugging,
+        Assimp::Logger::Info,
+        Assimp::Logger::Warn,
+        Assimp::Logger::Err,
+    };
+    for (unsigned int severity : severities) {
+        logger->attachStream(new CaptureLogStream(severity), severity);
+    }
+    g_capture_logger = logger;
+}
+
 static void apply_properties(Assimp::Importer& importer, const aiRustProperty* props, size_t count) {
     if (!props) return;
     for (size_t i = 0; i < count; ++i) {
@@ -230,6 +369,257 @@ static void set_unknown_exception_error(const char* context) noexcept {
     }
 }
 
+// === Sub-scene pruning helpers ===
+
+// Build an old-index -> new-index table from a keep mask (-1 = dropped).
+static std::vector<int> build_remap(const std::vector<bool>& keep) {
+    std::vector<int> remap(keep.size(), -1);
+    int next = 0;
+    for (size_t i = 0; i < keep.size(); ++i) {
+        if (keep[i]) remap[i] = next++;
+    }
+    return remap;
+}
+
+// Compact an Assimp-owned pointer array according to `remap`, deleting dropped entries.
+template <typename T>
+static void compact_owned_array(T**& items, unsigned int& count, const std::vector<int>& remap) {
+    unsigned int kept = 0;
+    for (unsigned int i = 0; i < count; ++i) {
+        if (remap[i] >= 0) ++kept;
+    }
+    T** out = kept ? new T*[kept] : nullptr;
+    for (unsigned int i = 0; i < count; ++i) {
+        if (remap[i] >= 0) {
+            out[remap[i]] = items[i];
+        } else {
+            delete items[i];
+        }
+    }
+    delete[] items;
+    items = out;
+    count = kept;
+}
+
+static void collect_node_meshes(const aiNode* node, std::vector<bool>& used) {
+    if (!node) return;
+    for (unsigned int i = 0; i < node->mNumMeshes; ++i) {
+        if (node->mMeshes[i] < used.size()) used[node->mMeshes[i]] = true;
+    }
+    for (unsigned int i = 0; i < node->mNumChildren; ++i) {
+        collect_node_meshes(node->mChildren[i], used);
+    }
+}
+
+static void remap_node_meshes(aiNode* node, const std::vector<int>& remap) {
+    if (!node) return;
+    unsigned int n = 0;
+    for (unsigned int i = 0; i < node->mNumMeshes; ++i) {
+        const unsigned int idx = node->mMeshes[i];
+        if (idx < remap.size() && remap[idx] >= 0) {
+            node->mMeshes[n++] = static_cast<unsigned int>(remap[idx]);
+        }
+    }
+    node->mNumMeshes = n;
+    if (n == 0) {
+        delete[] node->mMeshes;
+        node->mMeshes = nullptr;
+    }
+    for (unsigned int i = 0; i < node->mNumChildren; ++i) {
+        remap_node_meshes(node->mChildren[i], remap);
+    }
+}
+
+static bool is_texture_file_property(const aiMaterialProperty* prop) {
+    return prop && prop->mType == aiPTI_String && prop->mData && prop->mDataLength >= sizeof(uint32_t) &&
+           std::strcmp(prop->mKey.C_Str(), _AI_MATKEY_TEXTURE_BASE) == 0;
+}
+
+// String properties are stored as a 32-bit length followed by the bytes and a terminator.
+static std::string read_string_property(const aiMaterialProperty* prop) {
+    uint32_t len = 0;
+    std::memcpy(&len, prop->mData, sizeof(uint32_t));
+    const size_t max_len = prop->mDataLength - sizeof(uint32_t);
+    return std::string(prop->mData + sizeof(uint32_t), len < max_len ? len : max_len);
+}
+
+static void write_string_property(aiMaterialProperty* prop, const std::string& value) {
+    const uint32_t len = static_cast<uint32_t>(value.size());
+    char* data = new char[sizeof(uint32_t) + value.size() + 1];
+    std::memcpy(data, &len, sizeof(uint32_t));
+    std::memcpy(data + sizeof(uint32_t), value.c_str(), value.size() + 1);
+    delete[] prop->mData;
+    prop->mData = data;
+    prop->mDataLength = static_cast<unsigned int>(sizeof(uint32_t) + value.size() + 1);
+}
+
+static const char* path_file_name(const char* path) {
+    const char* name = path;
+    for (const char* c = path; *c; ++c) {
+        if (*c == '/' || *c == '\\') name = c + 1;
+    }
+    return name;
+}
+
+// Resolve a texture path to an embedded texture index, like aiScene::GetEmbeddedTexture.
+static int embedded_texture_index(const aiScene* scene, const std::string& path) {
+    if (path.empty()) return -1;
+    if (path[0] == '*') {
+        char* end = nullptr;
+        const long idx = std::strtol(path.c_str() + 1, &end, 10);
+        if (end == path.c_str() + 1 || *end != '\0' || idx < 0 || idx >= (long)scene->mNumTextures) return -1;
+        return static_cast<int>(idx);
+    }
+    const char* wanted = path_file_name(path.c_str());
+    for (unsigned int i = 0; i < scene->mNumTextures; ++i) {
+        const aiTexture* tex = scene->mTextures[i];
+        if (tex && tex->mFilename.length > 0 && std::strcmp(path_file_name(tex->mFilename.C_Str()), wanted) == 0) {
+            return static_cast<int>(i);
+        }
+    }
+    return -1;
+}
+
+static void prune_scene(aiScene* scene, const char* root_name, const unsigned int* mesh_indices, size_t mesh_count, bool filter_meshes) {
+    // 1. Re-root at the requested subtree, baking the ancestors' transforms into it.
+    if (root_name) {
+        aiNode* sub = scene->mRootNode->FindNode(root_name);
+        if (!sub) throw std::runtime_error(std::string("Node not found: ") + root_name);
+        if (sub != scene->mRootNode) {
+            aiMatrix4x4 world = sub->mTransformation;
+            for (const aiNode* p = sub->mParent; p; p = p->mParent) {
+                world = p->mTransformation * world;
+            }
+            aiNode* parent = sub->mParent;
+            unsigned int n = 0;
+            for (unsigned int i = 0; i < parent->mNumChildren; ++i) {
+                if (parent->mChildren[i] != sub) parent->mChildren[n++] = parent->mChildren[i];
+            }
+            parent->mNumChildren = n;
+            delete scene->mRootNode;
+            sub->mParent = nullptr;
+            sub->mTransformation = world;
+            scene->mRootNode = sub;
+        }
+    }
+    aiNode* root = scene->mRootNode;
+
+    // 2. Meshes: requested subset, restricted to meshes referenced by the kept nodes.
+    std::vector<bool> keep_mesh(scene->mNumMeshes, !filter_meshes);
+    if (filter_meshes) {
+        for (size_t i = 0; i < mesh_count; ++i) {
+            if (mesh_indices[i] >= scene->mNumMeshes) throw std::runtime_error("Mesh index out of range");
+            keep_mesh[mesh_indices[i]] = true;
+        }
+    }
+    if (root_name) {
+        std::vector<bool> referenced(scene->mNumMeshes, false);
+        collect_node_meshes(root, referenced);
+        for (size_t i = 0; i < keep_mesh.size(); ++i) keep_mesh[i] = keep_mesh[i] && referenced[i];
+    }
+    const std::vector<int> mesh_remap = build_remap(keep_mesh);
+    compact_owned_array(scene->mMeshes, scene->mNumMeshes, mesh_remap);
+    remap_node_meshes(root, mesh_remap);
+
+    // 3. Materials referenced by the kept meshes.
+    std::vector<bool> keep_material(scene->mNumMaterials, false);
+    for (unsigned int i = 0; i < scene->mNumMeshes; ++i) {
+        if (scene->mMeshes[i]->mMaterialIndex < scene->mNumMaterials) keep_material[scene->mMeshes[i]->mMaterialIndex] = true;
+    }
+    const std::vector<int> material_remap = build_remap(keep_material);
+    compact_owned_array(scene->mMaterials, scene->mNumMaterials, material_remap);
+    for (unsigned int i = 0; i < scene->mNumMeshes; ++i) {
+        const unsigned int idx = scene->mMeshes[i]->mMaterialIndex;
+        scene->mMeshes[i]->mMaterialIndex = (idx < material_remap.size() && material_remap[idx] >= 0) ? material_remap[idx] : 0u;
+    }
+
+    // 4. Embedded textures referenced by the kept materials ("*N" paths are rewritten).
+    std::vector<bool> keep_texture(scene->mNumTextures, false);
+    for (unsigned int m = 0; m < scene->mNumMaterials; ++m) {
+        const aiMaterial* mat = scene->mMaterials[m];
+        for (unsigned int p = 0; p < mat->mNumProperties; ++p) {
+            if (!is_texture_file_property(mat->mProperties[p])) continue;
+            const int idx = embedded_texture_index(scene, read_string_property(mat->mProperties[p]));
+            if (idx >= 0) keep_texture[idx] = true;
+        }
+    }
+    const std::vector<int> texture_remap = build_remap(keep_texture);
+    for (unsigned int m = 0; m < scene->mNumMaterials; ++m) {
+        aiMaterial* mat = scene->mMaterials[m];
+        for (unsigned int p = 0; p < mat->mNumProperties; ++p) {
+            aiMaterialProperty* prop = mat->mProperties[p];
+            if (!is_texture_file_property(prop)) continue;
+            const std::string path = read_string_property(prop);
+            if (path.empty() || path[0] != '*') continue;
+            const int idx = embedded_texture_index(scene, path);
+            if (idx >= 0) write_string_property(prop, "*" + std::to_string(texture_remap[idx]));
+        }
+    }
+    compact_owned_array(scene->mTextures, scene->mNumTextures, texture_remap);
+
+    // 5. Lights, cameras and animation channels whose node is gone.
+    std::vector<bool> keep_light(scene->mNumLights);
+    for (unsigned int i = 0; i < scene->mNumLights; ++i) keep_light[i] = root->FindNode(scene->mLights[i]->mName) != nullptr;
+    compact_owned_array(scene->mLights, scene->mNumLights, build_remap(keep_light));
+
+    std::vector<bool> keep_camera(scene->mNumCameras);
+    for (unsigned int i = 0; i < scene->mNumCameras; ++i) keep_camera[i] = root->FindNode(scene->mCameras[i]->mName) != nullptr;
+    compact_owned_array(scene->mCameras, scene->mNumCameras, build_remap(keep_camera));
+
+    std::vector<bool> keep_animation(scene->mNumAnimations);
+    for (unsigned int a = 0; a < scene->mNumAnimations; ++a) {
+        aiAnimation* anim = scene->mAnimations[a];
+        std::vector<bool> keep_channel(anim->mNumChannels);
+        for (unsigned int c = 0; c < anim->mNumChannels; ++c) keep_channel[c] = root->FindNode(anim->mChannels[c]->mNodeName) != nullptr;
+        compact_owned_array(anim->mChannels, anim->mNumChannels, build_remap(keep_channel));
+        keep_animation[a] = anim->mNumChannels + anim->mNumMeshChannels + anim->mNumMorphMeshChannels > 0;
+    }
+    compact_owned_array(scene->mAnimations, scene->mNumAnimations, build_remap(keep_animation));
+
+    // 6. Bone node pointers are copied verbatim by aiCopyScene and may point into a scene
+    // that no longer exists; re-resolve them against the pruned hierarchy.
+    for (unsigned int i = 0; i < scene->mNumMeshes; ++i) {
+        aiMesh* mesh = scene->mMeshes[i];
+        for (unsigned int b = 0; b < mesh->mNumBones; ++b) {
+            mesh->mBones[b]->mNode = root->FindNode(mesh->mBones[b]->mName);
+            mesh->mBones[b]->mArmature = nullptr;
+        }
+    }
+
+    // Skeletons reference meshes and nodes by pointer; they are not carried over.
+    for (unsigned int i = 0; i < scene->mNumSkeletons; ++i) delete scene->mSkeletons[i];
+    delete[] scene->mSkeletons;
+    scene->mSkeletons = nullptr;
+    scene->mNumSkeletons = 0;
+}
+
+// Mirror Assimp::Importer::ReadFile's importer selection: the first importer that
+// lists the file's extension and accepts the file, else the first whose signature
+// check accepts it.
+static long long find_reading_importer(const Assimp::Importer& importer, const std::string& file, Assimp::IOSystem* io)
+{
+    const std::string extension = Assimp::BaseImporter::GetExtension(file);
+    const size_t count = importer.GetImporterCount();
+    for (size_t i = 0; i < count; ++i) {
+        Assimp::BaseImporter* candidate = importer.GetImporter(i);
+        if (!candidate) {
+            continue;
+        }
+        std::set<std::string> extensions;
+        candidate->GetExtensionList(extensions);
+        if (extensions.count(extension) && candidate->CanRead(file, io, false)) {
+            return (long long)i;
+        }
+    }
+    for (size_t i = 0; i < count; ++i) {
+        const Assimp::BaseImporter* candidate = importer.GetImporter(i);
+        if (candidate && candidate->CanRead(file, io, true)) {
+            return (long long)i;
+        }
+    }
+    return -1;
+}
+
 static const aiScene* import_with_bridge(
     const char* path,
     const char* mem,
@@ -242,6 +632,7 @@ static const aiScene* import_with_bridge(
     void* progress_user,
     const char* hint)
 {
+    g_last_importer_index = -1;
     Assimp::Importer importer;
 
     // IO bridge
@@ -251,8 +642,10 @@ static const aiScene* import_with_bridge(
     }
 
     // Progress bridge
+    BridgeProgressHandler* progress = nullptr;
     if (progress_cb) {
-        importer.SetProgressHandler(new BridgeProgressHandler(progress_cb, progress_user));
+        progress = new BridgeProgressHandler(progress_cb, progress_user);
+        importer.SetProgressHandler(progress);
     }
 
     // Properties
@@ -265,20 +658,124 @@ static const aiScene* import_with_bridge(
         scene = importer.ReadFileFromMemory(mem, (size_t)mem_len, flags, hint ? hint : "");
     }
 
+    if (progress && progress->cancelled) {
+        g_last_error_msg = "Import cancelled";
+        return nullptr;
+    }
+
     if (!scene) {
         g_last_error_msg = importer.GetErrorString();
         return nullptr;
     }
 
-    // Deep copy to decouple from Importer lifetime
-    aiScene* out = nullptr;
-    aiCopyScene(scene, &out);
-    if (!out) {
-        g_last_error_msg = "aiCopyScene returned null";
+    if (path) {
+        g_last_importer_index = find_reading_importer(importer, path, importer.GetIOHandler());
+    } else {
+        // Same file name ReadFileFromMemory hands to the importers.
+        Assimp::MemoryIOSystem io(reinterpret_cast<const uint8_t*>(mem), (size_t)mem_len, nullptr);
+        const std::string file = std::string(AI_MEMORYIO_MAGIC_FILENAME) + "." + (hint ? hint : "");
+        g_last_importer_index = find_reading_importer(importer, file, &io);
+    }
+
+    // Take the scene out of the Importer so it survives the Importer's destruction
+    // without a deep copy; the caller frees it with aiFreeScene.
+    return importer.GetOrphanedScene();
+}
+
+#ifndef ASSIMP_BUILD_NO_EXPORT
+static bool file_exists(const char* path) {
+    std::FILE* f = std::fopen(path, "rb");
+    if (!f) return false;
+    std::fclose(f);
+    return true;
+}
+
+// Shared by the export entry points. `progress_cb` is nullable.
+static aiReturn export_file_with_bridge(
+    const aiScene* scene,
+    const char* format_id,
+    const char* path,
+    const aiFileIO* file_io,
+    unsigned int preprocessing,
+    const aiRustProperty* props,
+    size_t props_count,
+    aiRustProgressCallback progress_cb,
+    void* progress_user)
+{
+    Assimp::Exporter exporter;
+    Assimp::ExportProperties export_props;
+
+    if (file_io) {
+        // Assimp::Exporter takes ownership of custom IO/progress handlers and deletes them.
+        exporter.SetIOHandler(new RustIOSystem(file_io));
+    }
+    ExportProgressHandler* progress = nullptr;
+    if (progress_cb) {
+        progress = new ExportProgressHandler(progress_cb, progress_user);
+        exporter.SetProgressHandler(progress);
+    }
+
+    apply_export_properties(export_props, props, props_count);
+
+    // Only a file this export created is removed on cancellation.
+    const bool existed = progress && !file_io && file_exists(path);
+    aiReturn r = aiReturn_FAILURE;
+    try {
+        r = exporter.Export(scene, format_id, path, preprocessing, &export_props);
+    } catch (const ExportCancelled&) {
+    }
+
+    if (progress && progress->cancelled) {
+        if (!file_io && !existed) {
+            std::remove(path);
+        }
+        g_last_error_msg = "Export cancelled";
+        return aiReturn_FAILURE;
+    }
+    if (r != aiReturn_SUCCESS) {
+        g_last_error_msg = exporter.GetErrorString();
+    }
+    return r;
+}
+
+static const aiExportDataBlob* export_blob_with_bridge(
+    const aiScene* scene,
+    const char* format_id,
+    unsigned int preprocessing,
+    const aiRustProperty* props,
+    size_t props_count,
+    aiRustProgressCallback progress_cb,
+    void* progress_user)
+{
+    Assimp::Exporter exporter;
+    Assimp::ExportProperties export_props;
+
+    ExportProgressHandler* progress = nullptr;
+    if (progress_cb) {
+        progress = new ExportProgressHandler(progress_cb, progress_user);
+        exporter.SetProgressHandler(progress);
+    }
+
+    apply_export_properties(export_props, props, props_count);
+
+    const aiExportDataBlob* blob = nullptr;
+    try {
+        blob = exporter.ExportToBlob(scene, format_id, preprocessing, &export_props);
+    } catch (const ExportCancelled&) {
+    }
+
+    if (progress && progress->cancelled) {
+        // A partial blob stays owned by the exporter and is freed with it.
+        g_last_error_msg = "Export cancelled";
         return nullptr;
     }
-    return out;
+    if (!blob) {
+        g_last_error_msg = exporter.GetErrorString();
+        return nullptr;
+    }
+    return exporter.GetOrphanedBlob();
 }
+#endif
 
 } // namespace
 
@@ -344,6 +841,21 @@ enum aiReturn aiExportSceneExWithPropertiesRust(
     unsigned int preprocessing,
     const struct aiRustProperty* props,
     size_t props_count)
+{
+    return aiExportSceneWithProgressRust(
+        scene, format_id, path, file_io, preprocessing, props, props_count, nullptr, nullptr);
+}
+
+enum aiReturn aiExportSceneWithProgressRust(
+    const struct aiScene* scene,
+    const char* format_id,
+    const char* path,
+    const struct aiFileIO* file_io,
+    unsigned int preprocessing,
+    const struct aiRustProperty* props,
+    size_t props_count,
+    aiRustProgressCallback progress_cb,
+    void* progress_user)
 {
     g_last_error_msg.clear();
     try {
@@ -356,6 +868,8 @@ enum aiReturn aiExportSceneExWithPropertiesRust(
     (void)preprocessing;
     (void)props;
     (void)props_count;
+    (void)progress_cb;
+    (void)progress_user;
     g_last_error_msg = "Assimp was built without export support (ASSIMP_BUILD_NO_EXPORT)";
     return aiReturn_FAILURE;
 #else
@@ -367,28 +881,14 @@ enum aiReturn aiExportSceneExWithPropertiesRust(
         g_last_error_msg = "Format ID or path is null";
         return aiReturn_FAILURE;
     }
-
-    Assimp::Exporter exporter;
-    Assimp::ExportProperties export_props;
-
-    if (file_io) {
-        // Assimp::Exporter takes ownership of custom IO handlers via SetIOHandler().
-        exporter.SetIOHandler(new RustIOSystem(file_io));
-    }
-
-    apply_export_properties(export_props, props, props_count);
-
-    const aiReturn r = exporter.Export(scene, format_id, path, preprocessing, &export_props);
-    if (r != aiReturn_SUCCESS) {
-        g_last_error_msg = exporter.GetErrorString();
-    }
-    return r;
+    return export_file_with_bridge(
+        scene, format_id, path, file_io, preprocessing, props, props_count, progress_cb, progress_user);
 #endif
     } catch (const std::exception& e) {
-        set_exception_error("aiExportSceneExWithPropertiesRust", e);
+        set_exception_error("aiExportSceneWithProgressRust", e);
         return aiReturn_FAILURE;
     } catch (...) {
-        set_unknown_exception_error("aiExportSceneExWithPropertiesRust");
+        set_unknown_exception_error("aiExportSceneWithProgressRust");
         return aiReturn_FAILURE;
     }
 }
@@ -399,6 +899,19 @@ const struct aiExportDataBlob* aiExportSceneToBlobWithPropertiesRust(
     unsigned int preprocessing,
     const struct aiRustProperty* props,
     size_t props_count)
+{
+    return aiExportSceneToBlobWithProgressRust(
+        scene, format_id, preprocessing, props, props_count, nullptr, nullptr);
+}
+
+const struct aiExportDataBlob* aiExportSceneToBlobWithProgressRust(
+    const struct aiScene* scene,
+    const char* format_id,
+    unsigned int preprocessing,
+    const struct aiRustProperty* props,
+    size_t props_count,
+    aiRustProgressCallback progress_cb,
+    void* progress_user)
 {
     g_last_error_msg.clear();
     try {
@@ -409,6 +922,8 @@ const struct aiExportDataBlob* aiExportSceneToBlobWithPropertiesRust(
     (void)preprocessing;
     (void)props;
     (void)props_count;
+    (void)progress_cb;
+    (void)progress_user;
     g_last_error_msg = "Assimp was built without export support (ASSIMP_BUILD_NO_EXPORT)";
     return nullptr;
 #else
@@ -420,31 +935,349 @@ const struct aiExportDataBlob* aiExportSceneToBlobWithPropertiesRust(
         g_last_error_msg = "Format ID is null";
         return nullptr;
     }
+    return export_blob_with_bridge(
+        scene, format_id, preprocessing, props, props_count, progress_cb, progress_user);
+#endif
+    } catch (const std::exception& e) {
+        set_exception_error("aiExportSceneToBlobWithProgressRust", e);
+        return nullptr;
+    } catch (...) {
+        set_unknown_exception_error("aiExportSceneToBlobWithProgressRust");
+        return nullptr;
+    }
+}
 
-    Assimp::Exporter exporter;
-    Assimp::ExportProperties export_props;
+struct aiScene* aiCopySceneRust(const struct aiScene* scene) {
+    g_last_error_msg.clear();
+    try {
+        if (!scene) {
+            g_last_error_msg = "Scene is null";
+            return nullptr;
+        }
+        aiScene* out = nullptr;
+        aiCopyScene(scene, &out);
+        if (!out) {
+            g_last_error_msg = "aiCopyScene returned null";
+        }
+        return out;
+    } catch (const std::exception& e) {
+        set_exception_error("aiCopySceneRust", e);
+        return nullptr;
+    } catch (...) {
+        set_unknown_exception_error("aiCopySceneRust");
+        return nullptr;
+    }
+}
 
-    apply_export_properties(export_props, props, props_count);
+bool aiPruneSceneRust(
+    struct aiScene* scene,
+    const char* root_name,
+    const unsigned int* mesh_indices,
+    size_t mesh_count,
+    bool filter_meshes)
+{
+    g_last_error_msg.clear();
+    try {
+        if (!scene || !scene->mRootNode) {
+            g_last_error_msg = "Scene is null or has no root node";
+            return false;
+        }
+        if (filter_meshes && mesh_count > 0 && !mesh_indices) {
+            g_last_error_msg = "Mesh index list is null";
+            return false;
+        }
+        prune_scene(scene, root_name, mesh_indices, mesh_count, filter_meshes);
+        return true;
+    } catch (const std::exception& e) {
+        set_exception_error("aiPruneSceneRust", e);
+        return false;
+    } catch (...) {
+        set_unknown_exception_error("aiPruneSceneRust");
+        return false;
+    }
+}
 
-    const aiExportDataBlob* blob = exporter.ExportToBlob(scene, format_id, preprocessing, &export_props);
-    if (!blob) {
-        g_last_error_msg = exporter.GetErrorString();
+bool aiMaterialSetPropertyRust(
+    struct aiMaterial* material,
+    const char* key,
+    unsigned int semantic,
+    unsigned int index,
+    enum aiPropertyTypeInfo type,
+    const void* data,
+    unsigned int length)
+{
+    g_last_error_msg.clear();
+    try {
+        if (!material || !key || !data || length == 0) {
+            g_last_error_msg = "Material, key or property data is null";
+            return false;
+        }
+        if (std::strlen(key) >= AI_MAXLEN) {
+            g_last_error_msg = "Material property key is too long";
+            return false;
+        }
+        if (material->AddBinaryProperty(data, length, key, semantic, index, type) != aiReturn_SUCCESS) {
+            g_last_error_msg = "aiMaterial::AddBinaryProperty failed";
+            return false;
+        }
+        return true;
+    } catch (const std::exception& e) {
+        set_exception_error("aiMaterialSetPropertyRust", e);
+        return false;
+    } catch (...) {
+        set_unknown_exception_error("aiMaterialSetPropertyRust");
+        return false;
+    }
+}
+
+int aiMaterialRemovePropertyRust(
+    struct aiMaterial* material,
+    const char* key,
+    unsigned int semantic,
+    unsigned int index)
+{
+    g_last_error_msg.clear();
+    try {
+        if (!material || !key) {
+            g_last_error_msg = "Material or key is null";
+            return -1;
+        }
+        return material->RemoveProperty(key, semantic, index) == aiReturn_SUCCESS ? 1 : 0;
+    } catch (const std::exception& e) {
+        set_exception_error("aiMaterialRemovePropertyRust", e);
+        return -1;
+    } catch (...) {
+        set_unknown_exception_error("aiMaterialRemovePropertyRust");
+        return -1;
+    }
+}
+
+struct aiMaterial* aiSceneAddMaterialRust(struct aiScene* scene) {
+    g_last_error_msg.clear();
+    try {
+        if (!scene) {
+            g_last_error_msg = "Scene is null";
+            return nullptr;
+        }
+        const unsigned int count = scene->mMaterials ? scene->mNumMaterials : 0;
+        aiMaterial** materials = new aiMaterial*[count + 1];
+        aiMaterial* material = nullptr;
+        try {
+            material = new aiMaterial();
+        } catch (...) {
+            delete[] materials;
+            throw;
+        }
+        for (unsigned int i = 0; i < count; ++i) {
+            materials[i] = scene->mMaterials[i];
+        }
+        materials[count] = material;
+        delete[] scene->mMaterials;
+        scene->mMaterials = materials;
+        scene->mNumMaterials = count + 1;
+        return material;
+    } catch (const std::exception& e) {
+        set_exception_error("aiSceneAddMaterialRust", e);
+        return nullptr;
+    } catch (...) {
+        set_unknown_exception_error("aiSceneAddMaterialRust");
         return nullptr;
     }
+}
 
-    return exporter.GetOrphanedBlob();
-#endif
+struct aiScene* aiCreateSceneRust(const char* root_name) {
+    g_last_error_msg.clear();
+    try {
+        std::unique_ptr<aiScene> scene(new aiScene());
+        scene->mRootNode = new aiNode(root_name ? root_name : "");
+        scene->mFlags |= AI_SCENE_FLAGS_NON_VERBOSE_FORMAT;
+        return scene.release();
+    } catch (const std::exception& e) {
+        set_exception_error("aiCreateSceneRust", e);
+        return nullptr;
+    } catch (...) {
+        set_unknown_exception_error("aiCreateSceneRust");
+        return nullptr;
+    }
+}
+
+struct aiMesh* aiSceneAddMeshRust(struct aiScene* scene, const char* name,
+                                  const float* positions, const float* normals,
+                                  const float* uvs, unsigned int num_vertices,
+                                  const unsigned int* indices, unsigned int num_indices,
+                                  unsigned int material_index) {
+    g_last_error_msg.clear();
+    try {
+        if (!scene || !positions || (num_indices > 0 && !indices) || num_indices % 3 != 0) {
+            g_last_error_msg = "Invalid arguments";
+            return nullptr;
+        }
+        std::unique_ptr<aiMesh> mesh(new aiMesh());
+        if (name) {
+            mesh->mName.Set(name);
+        }
+        mesh->mMaterialIndex = material_index;
+        mesh->mPrimitiveTypes = aiPrimitiveType_TRIANGLE;
+
+        mesh->mNumVertices = num_vertices;
+        mesh->mVertices = new aiVector3D[num_vertices];
+        for (unsigned int i = 0; i < num_vertices; ++i) {
+            mesh->mVertices[i] = aiVector3D(positions[3 * i], positions[3 * i + 1], positions[3 * i + 2]);
+        }
+        if (normals) {
+            mesh->mNormals = new aiVector3D[num_vertices];
+            for (unsigned int i = 0; i < num_vertices; ++i) {
+                mesh->mNormals[i] = aiVector3D(normals[3 * i], normals[3 * i + 1], normals[3 * i + 2]);
+            }
+        }
+        if (uvs) {
+            mesh->mTextureCoords[0] = new aiVector3D[num_vertices];
+            mesh->mNumUVComponents[0] = 2;
+            for (unsigned int i = 0; i < num_vertices; ++i) {
+                mesh->mTextureCoords[0][i] = aiVector3D(uvs[2 * i], uvs[2 * i + 1], 0.0f);
+            }
+        }
+
+        const unsigned int num_faces = num_indices / 3;
+        mesh->mFaces = new aiFace[num_faces];
+        mesh->mNumFaces = num_faces;
+        for (unsigned int f = 0; f < num_faces; ++f) {
+            aiFace& face = mesh->mFaces[f];
+            face.mIndices = new unsigned int[3];
+            face.mNumIndices = 3;
+            for (unsigned int k = 0; k < 3; ++k) {
+                face.mIndices[k] = indices[3 * f + k];
+            }
+        }
+
+        const unsigned int count = scene->mMeshes ? scene->mNumMeshes : 0;
+        aiMesh** meshes = new aiMesh*[count + 1];
+        for (unsigned int i = 0; i < count; ++i) {
+            meshes[i] = scene->mMeshes[i];
+        }
+        meshes[count] = mesh.release();
+        delete[] scene->mMeshes;
+        scene->mMeshes = meshes;
+        scene->mNumMeshes = count + 1;
+        return meshes[count];
     } catch (const std::exception& e) {
-        set_exception_error("aiExportSceneToBlobWithPropertiesRust", e);
+        set_exception_error("aiSceneAddMeshRust", e);
         return nullptr;
     } catch (...) {
-        set_unknown_exception_error("aiExportSceneToBlobWithPropertiesRust");
+        set_unknown_exception_error("aiSceneAddMeshRust");
         return nullptr;
     }
 }
 
+struct aiNode* aiNodeAddChildRust(struct aiNode* parent, const char* name,
+                                  const struct aiMatrix4x4* transform,
+                                  const unsigned int* meshes, unsigned int num_meshes) {
+    g_last_error_msg.clear();
+    try {
+        if (!parent || (num_meshes > 0 && !meshes)) {
+            g_last_error_msg = "Invalid arguments";
+            return nullptr;
+        }
+        std::unique_ptr<aiNode> node(new aiNode(name ? name : ""));
+        if (transform) {
+            node->mTransformation = *transform;
+        }
+        if (num_meshes > 0) {
+            node->mMeshes = new unsigned int[num_meshes];
+            node->mNumMeshes = num_meshes;
+            for (unsigned int i = 0; i < num_meshes; ++i) {
+                node->mMeshes[i] = meshes[i];
+            }
+        }
+        aiNode* child = node.get();
+        aiNode* children[] = {child};
+        parent->addChildren(1, children);
+        node.release();
+        return child;
+    } catch (const std::exception& e) {
+        set_exception_error("aiNodeAddChildRust", e);
+        return nullptr;
+    } catch (...) {
+        set_unknown_exception_error("aiNodeAddChildRust");
+        return nullptr;
+    }
+}
+
+int aiDetectImporterFromMemoryRust(const char* data, size_t length, size_t* importer_index) {
+    g_last_error_msg.clear();
+    try {
+        if (!data || !importer_index) {
+            g_last_error_msg = "Invalid arguments";
+            return -1;
+        }
+        Assimp::Importer importer;
+        Assimp::MemoryIOSystem io(reinterpret_cast<const uint8_t*>(data), length, nullptr);
+        // Same file name aiImportFileFromMemory uses for an empty hint.
+        const std::string file = std::string(AI_MEMORYIO_MAGIC_FILENAME) + ".";
+        for (size_t i = 0; i < importer.GetImporterCount(); ++i) {
+            const Assimp::BaseImporter* candidate = importer.GetImporter(i);
+            if (candidate && candidate->CanRead(file, &io, true)) {
+                *importer_index = i;
+                return 1;
+            }
+        }
+        return 0;
+    } catch (const std::exception& e) {
+        set_exception_error("aiDetectImporterFromMemoryRust", e);
+        return -1;
+    } catch (...) {
+        set_unknown_exception_error("aiDetectImporterFromMemoryRust");
+        return -1;
+    }
+}
+
+int aiGetLastImporterIndexRust(size_t* importer_index) {
+    if (!importer_index || g_last_importer_index < 0) {
+        return 0;
+    }
+    *importer_index = (size_t)g_last_importer_index;
+    return 1;
+}
+
 const char* aiGetLastErrorStringRust(void) {
     return g_last_error_msg.empty() ? nullptr : g_last_error_msg.c_str();
 }
 
+void aiSetLogForwardRust(aiRustLogCallback callback, unsigned int severity_mask) {
+    try {
+        if (callback) {
+            install_capture_streams();
+        }
+        g_log_forward_mask.store(severity_mask, std::memory_order_relaxed);
+        g_log_forward.store(callback, std::memory_order_release);
+    } catch (...) {
+        g_log_forward.store(nullptr, std::memory_order_release);
+    }
+}
+
+void aiBeginLogCaptureRust(unsigned int severity_mask) {
+    try {
+        g_captured_log.clear();
+        g_log_capture_mask = severity_mask;
+        install_capture_streams();
+        g_log_capturing = true;
+    } catch (...) {
+        g_log_capturing = false;
+    }
+}
+
+void aiEndLogCaptureRust(void) {
+    g_log_capturing = false;
+}
+
+size_t aiGetCapturedLogCountRust(void) {
+    return g_captured_log.size();
+}
+
+const char* aiGetCapturedLogRust(size_t index, unsigned int* severity) {
+    if (index >= g_captured_log.size()) return nullptr;
+    if (severity) *severity = g_captured_log[index].first;
+    return g_captured_log[index].second.c_str();
+}
+
 } // extern "C"
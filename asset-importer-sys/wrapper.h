@@ -55,7 +55,9 @@ extern "C" {
 #endif
 
 // Import a file with optional custom IO, properties and a progress callback.
-// Returns a deep-copied aiScene which can be freed with aiFreeScene.
+// Ownership of the imported aiScene passes to the caller; free it with aiFreeScene.
+// Returns null with the last error set to "Import cancelled" when the progress
+// callback returned false.
 const struct aiScene* aiImportFileExWithProgressRust(
     const char* path,
     unsigned int flags,
@@ -67,6 +69,7 @@ const struct aiScene* aiImportFileExWithProgressRust(
 );
 
 // Import from memory with properties + progress callback support.
+// Same ownership and cancellation behaviour as aiImportFileExWithProgressRust.
 const struct aiScene* aiImportFileFromMemoryWithProgressRust(
     const char* data,
     unsigned int length,
@@ -102,9 +105,153 @@ const struct aiExportDataBlob* aiExportSceneToBlobWithPropertiesRust(
     size_t props_count
 );
 
+// Export a scene to a file like aiExportSceneExWithPropertiesRust, reporting progress
+// through `progress_cb` ("write N/M" messages, percentages 0..1). Assimp reports progress
+// only between export stages (copy, preprocessing, writing), not while an exporter writes.
+// When the callback returns false the export is aborted at the next stage and fails with
+// the last error set to "Export cancelled"; if `file_io` is null and `path` did not exist
+// before, the partially written file is removed (best effort: auxiliary files such as a
+// glTF .bin or an OBJ .mtl are left behind).
+enum aiReturn aiExportSceneWithProgressRust(
+    const struct aiScene* scene,
+    const char* format_id,
+    const char* path,
+    const struct aiFileIO* file_io, // nullable
+    unsigned int preprocessing,
+    const struct aiRustProperty* props,
+    size_t props_count,
+    aiRustProgressCallback progress_cb, // nullable
+    void* progress_user // nullable
+);
+
+// Export a scene to a blob with exporter properties and a progress callback.
+// Same progress and cancellation behaviour as aiExportSceneWithProgressRust; a cancelled
+// export returns null and frees the partial blob.
+const struct aiExportDataBlob* aiExportSceneToBlobWithProgressRust(
+    const struct aiScene* scene,
+    const char* format_id,
+    unsigned int preprocessing,
+    const struct aiRustProperty* props,
+    size_t props_count,
+    aiRustProgressCallback progress_cb, // nullable
+    void* progress_user // nullable
+);
+
+// === Scene copy bridging ===
+
+// Deep-copy a scene like aiCopyScene, but report failures (e.g. std::bad_alloc) through
+// aiGetLastErrorStringRust instead of letting C++ exceptions cross the C ABI.
+// Returns null on failure; free the copy with aiFreeScene.
+struct aiScene* aiCopySceneRust(const struct aiScene* scene);
+
+// === Scene pruning bridging ===
+
+// Prune a modifiable scene (from aiCopyScene) in place.
+// `root_name` (nullable) selects the node subtree that becomes the new root; its ancestors'
+// transforms are baked into it. When `filter_meshes` is true, only meshes listed in
+// `mesh_indices` are kept. Materials, embedded textures, lights, cameras and animation
+// channels that are no longer referenced are removed and the rest are reindexed.
+// Returns false on failure (see aiGetLastErrorStringRust); the scene must then be freed.
+bool aiPruneSceneRust(
+    struct aiScene* scene,
+    const char* root_name, // nullable
+    const unsigned int* mesh_indices, // nullable when mesh_count == 0
+    size_t mesh_count,
+    bool filter_meshes
+);
+
+// === Material editing bridging ===
+
+// Add or replace a property on a material (aiMaterial::AddBinaryProperty). An existing
+// property with the same key, semantic and index is replaced. `key` must be shorter than
+// AI_MAXLEN. Returns false on failure (see aiGetLastErrorStringRust).
+bool aiMaterialSetPropertyRust(
+    struct aiMaterial* material,
+    const char* key,
+    unsigned int semantic,
+    unsigned int index,
+    enum aiPropertyTypeInfo type,
+    const void* data,
+    unsigned int length
+);
+
+// Remove a property from a material (aiMaterial::RemoveProperty).
+// Returns 1 if it was removed, 0 if no such property exists and -1 on failure.
+int aiMaterialRemovePropertyRust(
+    struct aiMaterial* material,
+    const char* key,
+    unsigned int semantic,
+    unsigned int index
+);
+
+// Append an empty material to a modifiable scene (from aiCopyScene).
+// Returns the new material, owned by the scene, or null on failure.
+struct aiMaterial* aiSceneAddMaterialRust(struct aiScene* scene);
+
+// === Scene construction bridging ===
+
+// Allocate an empty scene whose root node is named `root_name` (may be null).
+// The scene is flagged non-verbose (meshes share vertices between faces).
+// Returns null on failure; free with aiFreeScene.
+struct aiScene* aiCreateSceneRust(const char* root_name);
+
+// Append a triangle mesh to a scene from aiCreateSceneRust. `positions` and `normals`
+// hold 3 floats per vertex, `uvs` 2 floats per vertex; `normals` and `uvs` may be null.
+// `indices` holds 3 entries per triangle and is not range-checked here.
+// Returns the new mesh, owned by the scene, or null on failure.
+struct aiMesh* aiSceneAddMeshRust(struct aiScene* scene, const char* name,
+                                  const float* positions, const float* normals,
+                                  const float* uvs, unsigned int num_vertices,
+                                  const unsigned int* indices, unsigned int num_indices,
+                                  unsigned int material_index);
+
+// Append a child node to `parent` with the given local transform and mesh indices.
+// Returns the new node, owned by `parent`, or null on failure.
+struct aiNode* aiNodeAddChildRust(struct aiNode* parent, const char* name,
+                                  const struct aiMatrix4x4* transform,
+                                  const unsigned int* meshes, unsigned int num_meshes);
+
+// Index (for aiGetImportFormatDescription) of the importer that read the last
+// successful aiImportFileExWithProgressRust / aiImportFileFromMemoryWithProgressRust
+// call on this thread. Returns 1 and writes the index, or 0 if none was recorded.
+int aiGetLastImporterIndexRust(size_t* importer_index);
+
+// Find the first importer whose signature check accepts a memory buffer, the way
+// aiImportFileFromMemory probes importers when no hint is given (without importing).
+// Writes the importer index (usable with aiGetImportFormatDescription) and returns 1,
+// returns 0 if no importer claims the buffer and -1 on error.
+int aiDetectImporterFromMemoryRust(const char* data, size_t length, size_t* importer_index);
+
 // Get the last error message produced by the Rust C++ bridge (thread-local).
 const char* aiGetLastErrorStringRust(void);
 
+// === Log capture bridging ===
+//
+// Severity values match Assimp::Logger::ErrorSeverity:
+// 1 = debug, 2 = info, 4 = warning, 8 = error.
+
+// Start capturing Assimp log messages emitted on the calling thread.
+// `severity_mask` is a bitwise OR of the severity values above.
+// Clears any lines captured by a previous capture on this thread.
+void aiBeginLogCaptureRust(unsigned int severity_mask);
+
+// Stop capturing on the calling thread. Captured lines stay readable until the next begin.
+void aiEndLogCaptureRust(void);
+
+// Number of lines captured on the calling thread.
+size_t aiGetCapturedLogCountRust(void);
+
+// Captured line at `index` (null if out of range); writes its severity to `severity` if non-null.
+const char* aiGetCapturedLogRust(size_t index, unsigned int* severity);
+
+// Callback receiving one Assimp log line (without the level/thread prefix).
+typedef void (*aiRustLogCallback)(unsigned int severity, const char* message);
+
+// Forward Assimp log lines whose severity is in `severity_mask` to `callback`, on whatever
+// thread emits them. Replaces any previous callback; pass null to stop forwarding. The
+// callback must not unwind and may still be invoked briefly after being replaced.
+void aiSetLogForwardRust(aiRustLogCallback callback, unsigned int severity_mask);
+
 #ifdef __cplusplus
 }
 #endif
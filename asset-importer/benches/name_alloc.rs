@@ -0,0 +1,88 @@
+//! Compare `Node::name` (`String`) with `Node::name_owned` (inline `Name`) when
+//! collecting the names of a 10k-node scene.
+//!
+//! Run with `cargo bench --bench name_alloc`. Besides the timings, the benchmark prints
+//! how many heap allocations one pass over all names performs with each accessor.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use asset_importer::{Name, Scene, node::Node};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const NODE_COUNT: usize = 10_000;
+
+/// A glTF scene with `NODE_COUNT` named children under one root.
+fn wide_scene() -> Scene {
+    let nodes: Vec<String> = (0..NODE_COUNT)
+        .map(|i| format!(r#"{{ "name": "Armature_Joint_{i:05}" }}"#))
+        .collect();
+    let children: Vec<String> = (1..=NODE_COUNT).map(|i| i.to_string()).collect();
+    let gltf = format!(
+        r#"{{
+  "asset": {{ "version": "2.0" }},
+  "nodes": [{{ "name": "Root", "children": [{}] }}, {}],
+  "scenes": [{{ "nodes": [0] }}],
+  "scene": 0
+}}"#,
+        children.join(","),
+        nodes.join(",")
+    );
+    Scene::from_memory(gltf.as_bytes(), Some("gltf")).expect("import generated glTF")
+}
+
+fn collect_strings(nodes: &[Node]) -> Vec<String> {
+    nodes.iter().map(Node::name).collect()
+}
+
+fn collect_names(nodes: &[Node]) -> Vec<Name> {
+    nodes.iter().map(Node::name_owned).collect()
+}
+
+fn allocations_during<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn bench_names(c: &mut Criterion) {
+    let scene = wide_scene();
+    let nodes: Vec<Node> = scene.nodes_depth_first().collect();
+    assert!(nodes.len() > NODE_COUNT);
+
+    println!(
+        "allocations for {} names: String = {}, Name = {}",
+        nodes.len(),
+        allocations_during(|| collect_strings(&nodes)),
+        allocations_during(|| collect_names(&nodes)),
+    );
+
+    let mut group = c.benchmark_group("node_names");
+    group.bench_function("name", |b| b.iter(|| collect_strings(black_box(&nodes))));
+    group.bench_function("name_owned", |b| {
+        b.iter(|| collect_names(black_box(&nodes)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_names);
+criterion_main!(benches);
@@ -0,0 +1,13 @@
+//! Mirror the precision of the linked Assimp build.
+//!
+//! `asset-importer-sys` reports whether `ai_real` is `f64` through `DEP_ASSIMP_F64_REAL`;
+//! this turns it into `cfg(f64_real)` so type-level choices (raw mirror layouts, glam
+//! interop) follow the bindings.
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(f64_real)");
+    println!("cargo:rerun-if-env-changed=DEP_ASSIMP_F64_REAL");
+    if std::env::var("DEP_ASSIMP_F64_REAL").is_ok_and(|v| v == "1") {
+        println!("cargo:rustc-cfg=f64_real");
+    }
+}
@@ -6,9 +6,7 @@ mod common;
 use std::error::Error;
 
 #[cfg(feature = "export")]
-use asset_importer::{
-    Scene, exporter::ExportBuilder, get_export_formats, postprocess::PostProcessSteps,
-};
+use asset_importer::{ConvertOptions, convert, postprocess::PostProcessSteps};
 
 fn main() -> Result<(), Box<dyn Error>> {
     common::init_logging_from_env();
@@ -16,7 +14,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let args: Vec<String> = std::env::args().collect();
     if args.len() < 3 {
         eprintln!(
-            "Usage: {} <input_model> <output_file>",
+            "Usage: {} <input_model> <output_file> [format_id]",
             args.first().unwrap_or(&"06_convert".to_string())
         );
         std::process::exit(1);
@@ -31,24 +29,32 @@ fn main() -> Result<(), Box<dyn Error>> {
 
         #[cfg(feature = "export")]
         {
-            let input = std::path::Path::new(&args[1]);
-            let output = std::path::Path::new(&args[2]);
-            let scene: Scene = common::import_scene(input, PostProcessSteps::TRIANGULATE)?;
-            let ext = output
-                .extension()
-                .and_then(|s| s.to_str())
-                .unwrap_or("")
-                .to_ascii_lowercase();
-            let id = match map_extension_to_id(&ext) {
-                Some(id) => id,
-                None => {
-                    eprintln!("Unknown export extension '.{}'", ext);
-                    return Err(format!("Unknown export extension '.{}'", ext).into());
+            let mut options = ConvertOptions::from_path(&args[1])
+                .to_path(&args[2])
+                .with_post_process(PostProcessSteps::TRIANGULATE)
+                .with_progress_event_fn(|event| {
+                    println!("  {:>5.1}% {:?}", event.percent * 100.0, event.stage);
+                    true
+                });
+            // The format is chosen from the output extension unless given explicitly.
+            if let Some(format_id) = args.get(3) {
+                options = options.with_format(format_id);
+            }
+
+            match convert(options) {
+                Ok(report) => {
+                    println!(
+                        "Wrote {} as '{}' (import {:?}, export {:?})",
+                        args[2], report.format_id, report.import_duration, report.export_duration
+                    );
+                    println!("{}", report.statistics);
+                    for (level, message) in &report.warnings {
+                        println!("  [{level:?}] {message}");
+                    }
+                    Ok(())
                 }
-            };
-            ExportBuilder::new(id).export_to_file(&scene, output)?;
-            println!("Wrote {}", output.display());
-            Ok(())
+                Err(err) => Err(err.into()),
+            }
         }
     };
 
@@ -61,13 +67,3 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     result
 }
-
-#[cfg(feature = "export")]
-fn map_extension_to_id(ext: &str) -> Option<String> {
-    for f in get_export_formats() {
-        if f.file_extension.eq_ignore_ascii_case(ext) {
-            return Some(f.id);
-        }
-    }
-    None
-}
@@ -35,7 +35,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     };
 
     println!("Loaded: {}", path.display());
-    println!("Nodes: {}", count_nodes(&root));
+    println!("Nodes: {}", scene.node_count());
     println!("Meshes: {}", scene.num_meshes());
     println!("---");
 
@@ -45,10 +45,6 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn count_nodes(node: &Node) -> usize {
-    1 + node.children().map(|c| count_nodes(&c)).sum::<usize>()
-}
-
 fn walk_node(scene: &asset_importer::Scene, node: &Node, parent_world: Matrix4x4, depth: usize) {
     let local = node.transformation();
     let world = mul_mat4(parent_world, local);
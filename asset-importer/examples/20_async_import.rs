@@ -0,0 +1,53 @@
+//! Import models on an async runtime without blocking the executor.
+//!
+//! Each import runs on Tokio's blocking thread pool via `Importer::import_file_async`;
+//! the resulting `Scene`s are `Send + Sync` and come back to the awaiting task.
+//!
+//! Usage:
+//!   cargo run -p asset-importer --example 20_async_import --features tokio -- <model> [model...]
+//!
+//! Examples:
+//!   cargo run -p asset-importer --example 20_async_import --features tokio -- asset-importer/examples/models/box.obj
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::{error::Error, path::PathBuf};
+
+use asset_importer::{Importer, postprocess::PostProcessSteps};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    common::init_logging_from_env();
+
+    let mut paths: Vec<PathBuf> = std::env::args().skip(1).map(PathBuf::from).collect();
+    if paths.is_empty() {
+        paths.push(common::resolve_model_path(
+            common::ModelSource::ArgOrExamplesDir,
+            "box.obj",
+        ));
+    }
+
+    let importer = Importer::new();
+    let post = PostProcessSteps::TRIANGULATE | PostProcessSteps::SORT_BY_PTYPE;
+    let tasks: Vec<_> = paths
+        .iter()
+        .map(|path| tokio::spawn(importer.import_file_async(path, post)))
+        .collect();
+
+    for (path, task) in paths.iter().zip(tasks) {
+        match task.await? {
+            Ok(scene) => println!(
+                "{}: meshes={} materials={} animations={}",
+                path.display(),
+                scene.num_meshes(),
+                scene.num_materials(),
+                scene.num_animations()
+            ),
+            Err(err) => eprintln!("{}: {}", path.display(), err),
+        }
+    }
+
+    common::shutdown_logging();
+    Ok(())
+}
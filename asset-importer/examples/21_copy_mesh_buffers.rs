@@ -0,0 +1,120 @@
+//! Copy vertex attributes into reused `[f32; N]` buffers and compare against the
+//! allocating accessors.
+//!
+//! Usage:
+//!   cargo run --release -p asset-importer --example 21_copy_mesh_buffers -- [model] [iterations]
+//!
+//! Without a model, a generated 256x256 grid with normals and UVs is used.
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::{
+    error::Error,
+    fmt::Write as _,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use asset_importer::{Scene, postprocess::PostProcessSteps};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    common::init_logging_from_env();
+    let args: Vec<String> = std::env::args().collect();
+    let scene = match args.get(1) {
+        Some(path) => common::import_scene(Path::new(path), PostProcessSteps::GEN_NORMALS)?,
+        None => Scene::from_memory(grid_obj(256).as_bytes(), Some("obj"))?,
+    };
+    let iterations: u32 = args.get(2).and_then(|n| n.parse().ok()).unwrap_or(200);
+    let vertices: usize = scene.meshes().map(|mesh| mesh.num_vertices()).sum();
+    println!(
+        "{} meshes, {} vertices, {} iterations",
+        scene.num_meshes(),
+        vertices,
+        iterations
+    );
+
+    // Allocating accessors: one Vec<Vector3D>/Vec<Vector2D> per attribute and mesh,
+    // then a second conversion into the layout a GPU upload wants.
+    let allocating = time(iterations, || {
+        let mut floats = 0;
+        for mesh in scene.meshes() {
+            let positions: Vec<[f32; 3]> =
+                mesh.vertices().iter().map(|v| [v.x, v.y, v.z]).collect();
+            let normals: Vec<[f32; 3]> = mesh
+                .normals()
+                .unwrap_or_default()
+                .iter()
+                .map(|v| [v.x, v.y, v.z])
+                .collect();
+            let uvs: Vec<[f32; 2]> = mesh
+                .texture_coords_2d(0)
+                .unwrap_or_default()
+                .iter()
+                .map(|v| [v.x, v.y])
+                .collect();
+            floats += black_box(&positions).len() * 3
+                + black_box(&normals).len() * 3
+                + black_box(&uvs).len() * 2;
+        }
+        floats
+    });
+
+    // Copy straight into buffers that are reused across meshes and iterations.
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let copying = time(iterations, || {
+        let mut floats = 0;
+        for mesh in scene.meshes() {
+            mesh.copy_positions(&mut positions);
+            mesh.copy_normals(&mut normals);
+            mesh.copy_texture_coords_2d(0, &mut uvs);
+            floats += black_box(&positions).len() * 3
+                + black_box(&normals).len() * 3
+                + black_box(&uvs).len() * 2;
+        }
+        floats
+    });
+
+    println!("allocating accessors: {:?} per pass", allocating);
+    println!("copy_* into buffers:  {:?} per pass", copying);
+    if copying > Duration::ZERO {
+        println!(
+            "speedup: {:.2}x",
+            allocating.as_secs_f64() / copying.as_secs_f64()
+        );
+    }
+    Ok(())
+}
+
+/// Average duration of `pass` over `iterations` runs, after one warm-up run.
+fn time(iterations: u32, mut pass: impl FnMut() -> usize) -> Duration {
+    black_box(pass());
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(pass());
+    }
+    start.elapsed() / iterations.max(1)
+}
+
+/// OBJ text for an `n` x `n` quad grid with normals and UVs.
+fn grid_obj(n: usize) -> String {
+    let mut obj = String::new();
+    for y in 0..=n {
+        for x in 0..=n {
+            let (u, v) = (x as f32 / n as f32, y as f32 / n as f32);
+            writeln!(obj, "v {u} {v} 0\nvt {u} {v}").unwrap();
+        }
+    }
+    obj.push_str("vn 0 0 1\n");
+    for y in 0..n {
+        for x in 0..n {
+            let a = y * (n + 1) + x + 1;
+            let (b, c, d) = (a + 1, a + n + 2, a + n + 1);
+            writeln!(obj, "f {a}/{a}/1 {b}/{b}/1 {c}/{c}/1 {d}/{d}/1").unwrap();
+        }
+    }
+    obj
+}
@@ -122,9 +122,9 @@ struct Vertex {
     tex_coords: [f32; 2],
 }
 
-/// Texture information
+/// A material texture uploaded to OpenGL (not `asset_importer::material::TextureInfo`)
 #[derive(Debug, Clone)]
-struct TextureInfo {
+struct LoadedTexture {
     id: Option<glow::NativeTexture>,
     texture_type: String,
     path: String,
@@ -134,7 +134,7 @@ struct TextureInfo {
 struct Mesh {
     vertices: Vec<Vertex>,
     indices: Vec<u32>,
-    textures: Vec<TextureInfo>,
+    textures: Vec<LoadedTexture>,
     vao: Option<glow::NativeVertexArray>,
     vbo: Option<glow::NativeBuffer>,
     ebo: Option<glow::NativeBuffer>,
@@ -145,7 +145,7 @@ impl Mesh {
         gl: &glow::Context,
         vertices: Vec<Vertex>,
         indices: Vec<u32>,
-        textures: Vec<TextureInfo>,
+        textures: Vec<LoadedTexture>,
     ) -> Result<Self, Box<dyn Error>> {
         let mut mesh = Self {
             vertices,
@@ -253,7 +253,7 @@ impl Mesh {
 /// Model containing multiple meshes
 struct Model {
     meshes: Vec<Mesh>,
-    textures_loaded: Vec<TextureInfo>,
+    textures_loaded: Vec<LoadedTexture>,
     directory: String,
 }
 
@@ -517,7 +517,7 @@ impl Model {
         material: &asset_importer::material::Material,
         tex_type: TextureType,
         type_name: &str,
-    ) -> Result<Vec<TextureInfo>, Box<dyn Error>> {
+    ) -> Result<Vec<LoadedTexture>, Box<dyn Error>> {
         let mut textures = Vec::new();
         let texture_count = material.texture_count(tex_type);
 
@@ -535,7 +535,7 @@ impl Model {
 
                 // Load new texture
                 let texture_id = self.load_texture_from_file(gl, &texture_path)?;
-                let texture = TextureInfo {
+                let texture = LoadedTexture {
                     id: Some(texture_id),
                     texture_type: type_name.to_string(),
                     path: texture_path.clone(),
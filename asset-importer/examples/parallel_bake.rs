@@ -0,0 +1,110 @@
+//! Bake every mesh of a large scene into interleaved vertex buffers, sequentially and
+//! with `Scene::bake_all_meshes` on the rayon thread pool, and report the speedup.
+//!
+//! The scene is generated in memory: `meshes` OBJ grids of `grid` x `grid` quads
+//! (defaults: 100 meshes of 160 x 160, about 2.6 million vertices). Baking is
+//! memory-bound, so the speedup grows with core count until memory bandwidth is
+//! saturated; it is printed together with the rayon thread count.
+//!
+//! Usage:
+//!   cargo run --release -p asset-importer --example parallel_bake --features rayon -- [meshes] [grid]
+
+#[path = "common/mod.rs"]
+mod common;
+
+use std::{error::Error, fmt::Write as _, time::Instant};
+
+use asset_importer::{Importer, mesh::bake::VertexLayout, postprocess::PostProcessSteps};
+
+fn generate_obj(meshes: usize, grid: usize) -> String {
+    let mut obj = String::new();
+    let mut base = 1;
+    for m in 0..meshes {
+        let _ = writeln!(obj, "o grid_{m}");
+        for y in 0..=grid {
+            for x in 0..=grid {
+                let _ = writeln!(obj, "v {x} {y} {m}");
+                let _ = writeln!(
+                    obj,
+                    "vt {} {}",
+                    x as f32 / grid as f32,
+                    y as f32 / grid as f32
+                );
+            }
+        }
+        for y in 0..grid {
+            for x in 0..grid {
+                let a = base + y * (grid + 1) + x;
+                let (b, c, d) = (a + 1, a + grid + 1, a + grid + 2);
+                let _ = writeln!(obj, "f {a}/{a} {b}/{b} {d}/{d} {c}/{c}");
+            }
+        }
+        base += (grid + 1) * (grid + 1);
+    }
+    obj
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    common::init_logging_from_env();
+
+    let args: Vec<String> = std::env::args().collect();
+    let meshes = args.get(1).and_then(|s| s.parse().ok()).unwrap_or(100);
+    let grid = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(160);
+
+    let started = Instant::now();
+    let obj = generate_obj(meshes, grid);
+    let scene = Importer::new()
+        .read_from_memory(obj.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(
+            PostProcessSteps::TRIANGULATE
+                | PostProcessSteps::GEN_NORMALS
+                | PostProcessSteps::CALC_TANGENT_SPACE,
+        )
+        .import()?;
+    let vertices: usize = scene.meshes().map(|m| m.num_vertices()).sum();
+    println!(
+        "Generated {} meshes / {} vertices in {:.2?}",
+        scene.num_meshes(),
+        vertices,
+        started.elapsed()
+    );
+
+    let layout = VertexLayout::new()
+        .with_position()
+        .with_normal()
+        .with_tangent()
+        .with_uv(0);
+
+    let started = Instant::now();
+    let sequential = scene
+        .meshes()
+        .map(|mesh| mesh.bake_vertices(&layout))
+        .collect::<Result<Vec<_>, _>>()?;
+    let sequential_time = started.elapsed();
+
+    let started = Instant::now();
+    let parallel = scene.bake_all_meshes(&layout)?;
+    let parallel_time = started.elapsed();
+
+    assert_eq!(
+        sequential, parallel,
+        "parallel bake must match sequential bake"
+    );
+    let bytes: usize = parallel.iter().map(|b| b.interleaved_bytes().len()).sum();
+    println!(
+        "Baked {:.1} MiB of vertex data",
+        bytes as f64 / (1024.0 * 1024.0)
+    );
+    println!("  sequential: {:.2?}", sequential_time);
+    println!(
+        "  parallel:   {:.2?} ({} threads)",
+        parallel_time,
+        rayon::current_num_threads()
+    );
+    println!(
+        "  speedup:    {:.2}x",
+        sequential_time.as_secs_f64() / parallel_time.as_secs_f64().max(f64::EPSILON)
+    );
+    Ok(())
+}
@@ -215,24 +215,17 @@ impl AABB {
             return *self;
         }
 
-        // Transform all 8 corners of the AABB
-        let corners = [
-            Vector3D::new(self.min.x, self.min.y, self.min.z),
-            Vector3D::new(self.max.x, self.min.y, self.min.z),
-            Vector3D::new(self.min.x, self.max.y, self.min.z),
-            Vector3D::new(self.max.x, self.max.y, self.min.z),
-            Vector3D::new(self.min.x, self.min.y, self.max.z),
-            Vector3D::new(self.max.x, self.min.y, self.max.z),
-            Vector3D::new(self.min.x, self.max.y, self.max.z),
-            Vector3D::new(self.max.x, self.max.y, self.max.z),
-        ];
-
-        let transformed_corners: Vec<Vector3D> = corners
-            .iter()
-            .map(|&corner| matrix.transform_point3(corner))
-            .collect();
+        // Transform all 8 corners of the AABB and re-fit
+        Self::from_points(
+            self.corners()
+                .into_iter()
+                .map(|corner| matrix.transform_point3(corner)),
+        )
+    }
 
-        Self::from_points(transformed_corners)
+    /// The smallest AABB containing both boxes (empty boxes are ignored).
+    pub fn union(&self, other: &AABB) -> Self {
+        self.expanded_to_include_aabb(other)
     }
 
     /// Expand the AABB by a uniform amount in all directions
@@ -289,8 +282,8 @@ impl AABB {
 
 pub(crate) fn from_sys_aabb(aabb: &sys::aiAABB) -> AABB {
     AABB {
-        min: Vector3D::new(aabb.mMin.x, aabb.mMin.y, aabb.mMin.z),
-        max: Vector3D::new(aabb.mMax.x, aabb.mMax.y, aabb.mMax.z),
+        min: crate::types::from_ai_vector3d(aabb.mMin),
+        max: crate::types::from_ai_vector3d(aabb.mMax),
     }
 }
 
@@ -1,12 +1,15 @@
 //! Animation data structures and utilities
 
+pub mod clips;
+pub mod split;
+
 use crate::{
     ffi,
     ptr::SharedPtr,
     raw,
     scene::Scene,
     sys,
-    types::{Quaternion, Vector3D, ai_string_to_string},
+    types::{Name, Quaternion, Vector3D, ai_string_bytes, ai_string_to_name, ai_string_to_string},
 };
 
 /// An animation containing keyframes for various properties
@@ -77,6 +80,11 @@ impl Animation {
         ai_string_to_string(&self.raw().mName)
     }
 
+    /// Bytes of the name up to the first NUL, for binary-safe comparison.
+    pub fn name_bytes(&self) -> &[u8] {
+        ai_string_bytes(&self.raw().mName)
+    }
+
     /// Get the duration of the animation in ticks
     pub fn duration(&self) -> f64 {
         self.raw().mDuration
@@ -93,6 +101,52 @@ impl Animation {
         self.duration() / self.ticks_per_second()
     }
 
+    /// Earliest and latest key time in ticks across all node, mesh and morph channels.
+    ///
+    /// Returns `(0.0, 0.0)` when there are no keys. Unlike [`Animation::duration`], which
+    /// is whatever the file declares, this is where the keys actually are.
+    pub fn channel_time_range(&self) -> (f64, f64) {
+        let mut range: Option<(f64, f64)> = None;
+        let mut extend = |time: f64| {
+            range = Some(match range {
+                Some((first, last)) => (first.min(time), last.max(time)),
+                None => (time, time),
+            });
+        };
+        for channel in self.channels() {
+            let vector_keys = channel
+                .position_keys_raw()
+                .iter()
+                .chain(channel.scaling_keys_raw());
+            vector_keys.for_each(|key| extend(key.mTime));
+            channel
+                .rotation_keys_raw()
+                .iter()
+                .for_each(|key| extend(key.mTime));
+        }
+        for channel in self.mesh_channels() {
+            channel.keys().iter().for_each(|key| extend(key.time));
+        }
+        for channel in self.morph_mesh_channels() {
+            let keys = channel.keys_raw().unwrap_or(&[]);
+            keys.iter().for_each(|key| extend(key.mTime));
+        }
+        range.unwrap_or((0.0, 0.0))
+    }
+
+    /// Whether the animation moves nothing: it has no channels, or no track in it has
+    /// more than one key.
+    pub fn is_empty(&self) -> bool {
+        self.channels().all(|channel| {
+            channel.num_position_keys() <= 1
+                && channel.num_rotation_keys() <= 1
+                && channel.num_scaling_keys() <= 1
+        }) && self.mesh_channels().all(|channel| channel.num_keys() <= 1)
+            && self
+                .morph_mesh_channels()
+                .all(|channel| channel.num_keys() <= 1)
+    }
+
     /// Get the number of node animation channels
     pub fn num_channels(&self) -> usize {
         let anim = self.raw();
@@ -115,10 +169,15 @@ impl Animation {
 
     /// Get an iterator over all node animation channels
     pub fn channels(&self) -> NodeAnimationIterator {
+        let animation = self.raw();
         NodeAnimationIterator {
             scene: self.scene.clone(),
             animation_ptr: self.animation_ptr,
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                animation,
+                animation.mChannels as *const *const sys::aiNodeAnim,
+                animation.mNumChannels as usize,
+            ),
         }
     }
 
@@ -143,10 +202,15 @@ impl Animation {
 
     /// Iterate mesh animation channels
     pub fn mesh_channels(&self) -> MeshAnimationIterator {
+        let animation = self.raw();
         MeshAnimationIterator {
             scene: self.scene.clone(),
             animation_ptr: self.animation_ptr,
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                animation,
+                animation.mMeshChannels as *const *const sys::aiMeshAnim,
+                animation.mNumMeshChannels as usize,
+            ),
         }
     }
 
@@ -171,10 +235,15 @@ impl Animation {
 
     /// Iterate morph mesh animation channels
     pub fn morph_mesh_channels(&self) -> MorphMeshAnimationIterator {
+        let animation = self.raw();
         MorphMeshAnimationIterator {
             scene: self.scene.clone(),
             animation_ptr: self.animation_ptr,
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                animation,
+                animation.mMorphMeshChannels as *const *const sys::aiMeshMorphAnim,
+                animation.mNumMorphMeshChannels as usize,
+            ),
         }
     }
 }
@@ -214,6 +283,11 @@ impl NodeAnimation {
         ai_string_to_string(&self.raw().mNodeName)
     }
 
+    /// Get the name of the affected node as an inline [`Name`].
+    pub fn node_name_owned(&self) -> Name {
+        ai_string_to_name(&self.raw().mNodeName)
+    }
+
     /// Get the number of position keyframes
     pub fn num_position_keys(&self) -> usize {
         let ch = self.raw();
@@ -314,6 +388,104 @@ impl NodeAnimation {
     pub fn post_state(&self) -> AnimBehaviour {
         AnimBehaviour::from_sys(self.raw().mPostState)
     }
+
+    /// Interpolate the position at `ticks`; `None` when there are no position keys.
+    ///
+    /// Between two keys the earlier key's interpolation applies: [`AnimInterpolation::Step`]
+    /// holds its value, anything else blends linearly (spherically for rotations).
+    /// Outside the keys, [`AnimBehaviour::Repeat`] wraps `ticks` into the key range and
+    /// every other behaviour holds the first or last key.
+    pub fn sample_position(&self, ticks: f64) -> Option<Vector3D> {
+        let keys = self.position_keys_raw();
+        let ticks = self.behaviour_ticks(ticks, keys, |key| key.mTime)?;
+        sample_track(keys, ticks, raw_vector_key, Vector3D::lerp).map(|(value, _)| value)
+    }
+
+    /// Interpolate the rotation at `ticks`, as for [`NodeAnimation::sample_position`].
+    pub fn sample_rotation(&self, ticks: f64) -> Option<Quaternion> {
+        let keys = self.rotation_keys_raw();
+        let ticks = self.behaviour_ticks(ticks, keys, |key| key.mTime)?;
+        sample_track(keys, ticks, raw_quat_key, Quaternion::slerp).map(|(value, _)| value)
+    }
+
+    /// Interpolate the scaling at `ticks`, as for [`NodeAnimation::sample_position`].
+    pub fn sample_scaling(&self, ticks: f64) -> Option<Vector3D> {
+        let keys = self.scaling_keys_raw();
+        let ticks = self.behaviour_ticks(ticks, keys, |key| key.mTime)?;
+        sample_track(keys, ticks, raw_vector_key, Vector3D::lerp).map(|(value, _)| value)
+    }
+
+    fn behaviour_ticks<K>(&self, ticks: f64, keys: &[K], time: impl Fn(&K) -> f64) -> Option<f64> {
+        let range = (time(keys.first()?), time(keys.last()?));
+        Some(behaviour_ticks(
+            ticks,
+            range,
+            self.pre_state(),
+            self.post_state(),
+        ))
+    }
+}
+
+fn raw_vector_key(key: &raw::AiVectorKey) -> (f64, Vector3D, AnimInterpolation) {
+    (
+        key.mTime,
+        Vector3D::from(key.mValue),
+        AnimInterpolation::from_raw(key.mInterpolation),
+    )
+}
+
+fn raw_quat_key(key: &raw::AiQuatKey) -> (f64, Quaternion, AnimInterpolation) {
+    (
+        key.mTime,
+        Quaternion::from(key.mValue),
+        AnimInterpolation::from_raw(key.mInterpolation),
+    )
+}
+
+/// Map `ticks` outside the key range `(first, last)` back into it for repeating tracks.
+pub(crate) fn behaviour_ticks(
+    ticks: f64,
+    (first, last): (f64, f64),
+    pre_state: AnimBehaviour,
+    post_state: AnimBehaviour,
+) -> f64 {
+    let span = last - first;
+    let repeat = (ticks < first && pre_state == AnimBehaviour::Repeat)
+        || (ticks > last && post_state == AnimBehaviour::Repeat);
+    if repeat && span > 0.0 {
+        first + (ticks - first).rem_euclid(span)
+    } else {
+        ticks
+    }
+}
+
+/// The value of a key track at `ticks`, held at the first and last key, together with the
+/// interpolation of the key it was taken from.
+pub(crate) fn sample_track<K, V: Copy>(
+    keys: &[K],
+    ticks: f64,
+    key: impl Fn(&K) -> (f64, V, AnimInterpolation),
+    blend: impl Fn(V, V, f32) -> V,
+) -> Option<(V, AnimInterpolation)> {
+    let first = keys.first()?;
+    let next = keys.partition_point(|k| key(k).0 <= ticks);
+    if next == 0 {
+        let (_, value, interpolation) = key(first);
+        return Some((value, interpolation));
+    }
+    let (time, value, interpolation) = key(&keys[next - 1]);
+    let after = match keys.get(next) {
+        Some(after) if interpolation != AnimInterpolation::Step => after,
+        _ => return Some((value, interpolation)),
+    };
+    let (after_time, after_value, _) = key(after);
+    let span = after_time - time;
+    let t = if span > 0.0 {
+        ((ticks - time) / span) as f32
+    } else {
+        0.0
+    };
+    Some((blend(value, after_value, t), interpolation))
 }
 
 /// Interpolation method for animation keys
@@ -373,6 +545,7 @@ impl AnimBehaviour {
 }
 
 /// A keyframe containing a time and a 3D vector value
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct VectorKey {
     /// Time of the keyframe
     pub time: f64,
@@ -386,13 +559,14 @@ impl VectorKey {
     fn from_raw(k: raw::AiVectorKey) -> Self {
         Self {
             time: k.mTime,
-            value: Vector3D::new(k.mValue.x, k.mValue.y, k.mValue.z),
+            value: Vector3D::from(k.mValue),
             interpolation: AnimInterpolation::from_raw(k.mInterpolation),
         }
     }
 }
 
 /// A keyframe containing a time and a quaternion value
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct QuaternionKey {
     /// Time of the keyframe
     pub time: f64,
@@ -406,7 +580,7 @@ impl QuaternionKey {
     fn from_raw(k: raw::AiQuatKey) -> Self {
         Self {
             time: k.mTime,
-            value: Quaternion::from_xyzw(k.mValue.x, k.mValue.y, k.mValue.z, k.mValue.w),
+            value: Quaternion::from(k.mValue),
             interpolation: AnimInterpolation::from_raw(k.mInterpolation),
         }
     }
@@ -416,19 +590,23 @@ impl QuaternionKey {
 pub struct NodeAnimationIterator {
     scene: Scene,
     animation_ptr: SharedPtr<sys::aiAnimation>,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl NodeAnimationIterator {
-    #[inline]
-    fn raw(&self) -> &sys::aiAnimation {
-        self.animation_ptr.as_ref()
-    }
-
-    #[inline]
-    fn channel_array(&self) -> (*mut *mut sys::aiNodeAnim, usize) {
-        let animation = self.raw();
-        (animation.mChannels, animation.mNumChannels as usize)
+    fn channel(
+        scene: &Scene,
+        animation_ptr: SharedPtr<sys::aiAnimation>,
+        index: usize,
+    ) -> Option<NodeAnimation> {
+        let animation = animation_ptr.as_ref();
+        let ptr = ffi::ptr_array_get(
+            animation,
+            animation.mChannels as *const *mut sys::aiNodeAnim,
+            animation.mNumChannels as usize,
+            index,
+        )?;
+        NodeAnimation::from_ptr(scene.clone(), ptr as *const sys::aiNodeAnim)
     }
 }
 
@@ -436,35 +614,34 @@ impl Iterator for NodeAnimationIterator {
     type Item = NodeAnimation;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (base, len) = self.channel_array();
-        let channels: &[*mut sys::aiNodeAnim] =
-            ffi::slice_from_ptr_len_opt(&(), base as *const *mut sys::aiNodeAnim, len)?;
-        while self.index < channels.len() {
-            let channel_ptr = channels[self.index];
-            self.index += 1;
-            if channel_ptr.is_null() {
-                continue;
-            }
-            return NodeAnimation::from_ptr(
-                self.scene.clone(),
-                channel_ptr as *const sys::aiNodeAnim,
-            );
-        }
-        None
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .next(|index| Self::channel(scene, animation_ptr, index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let animation = self.raw();
-        if animation.mChannels.is_null() {
-            (0, Some(0))
-        } else {
-            let remaining = (animation.mNumChannels as usize).saturating_sub(self.index);
-            (0, Some(remaining))
-        }
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .nth(n, |index| Self::channel(scene, animation_ptr, index))
+    }
+}
+
+impl DoubleEndedIterator for NodeAnimationIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .next_back(|index| Self::channel(scene, animation_ptr, index))
     }
 }
 
+impl ExactSizeIterator for NodeAnimationIterator {}
+
 /// Mesh animation key
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(C)]
 pub struct MeshKey {
     /// Time of this key in the animation
@@ -520,49 +697,56 @@ impl MeshAnimation {
 pub struct MeshAnimationIterator {
     scene: Scene,
     animation_ptr: SharedPtr<sys::aiAnimation>,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl MeshAnimationIterator {
-    #[inline]
-    fn raw(&self) -> &sys::aiAnimation {
-        self.animation_ptr.as_ref()
-    }
-
-    #[inline]
-    fn channel_array(&self) -> (*mut *mut sys::aiMeshAnim, usize) {
-        let anim = self.raw();
-        (anim.mMeshChannels, anim.mNumMeshChannels as usize)
+    fn channel(
+        scene: &Scene,
+        animation_ptr: SharedPtr<sys::aiAnimation>,
+        index: usize,
+    ) -> Option<MeshAnimation> {
+        let animation = animation_ptr.as_ref();
+        let ptr = ffi::ptr_array_get(
+            animation,
+            animation.mMeshChannels as *const *mut sys::aiMeshAnim,
+            animation.mNumMeshChannels as usize,
+            index,
+        )?;
+        MeshAnimation::from_ptr(scene.clone(), ptr as *const sys::aiMeshAnim)
     }
 }
 
 impl Iterator for MeshAnimationIterator {
     type Item = MeshAnimation;
+
     fn next(&mut self) -> Option<Self::Item> {
-        let (base, len) = self.channel_array();
-        let chans: &[*mut sys::aiMeshAnim] =
-            ffi::slice_from_ptr_len_opt(&(), base as *const *mut sys::aiMeshAnim, len)?;
-        while self.index < chans.len() {
-            let ptr = chans[self.index];
-            self.index += 1;
-            if ptr.is_null() {
-                continue;
-            }
-            return MeshAnimation::from_ptr(self.scene.clone(), ptr as *const sys::aiMeshAnim);
-        }
-        None
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .next(|index| Self::channel(scene, animation_ptr, index))
     }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let anim = self.raw();
-        if anim.mMeshChannels.is_null() {
-            (0, Some(0))
-        } else {
-            let remaining = (anim.mNumMeshChannels as usize).saturating_sub(self.index);
-            (0, Some(remaining))
-        }
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .nth(n, |index| Self::channel(scene, animation_ptr, index))
     }
 }
 
+impl DoubleEndedIterator for MeshAnimationIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .next_back(|index| Self::channel(scene, animation_ptr, index))
+    }
+}
+
+impl ExactSizeIterator for MeshAnimationIterator {}
+
 /// Morph mesh key (weights for multiple targets)
 #[derive(Clone)]
 pub struct MorphMeshKey {
@@ -665,58 +849,108 @@ impl MorphMeshAnimation {
             key_ptr,
         })
     }
+
+    /// Interpolate the morph target weights at `ticks`.
+    ///
+    /// Weights are linearly interpolated between the keys before and after `ticks`; a
+    /// target missing from one of the two keys counts as weight 0 there. Before the
+    /// first key and after the last one, that key's weights are returned as-is. Targets
+    /// keep the order of the earlier key, followed by those only the later key has.
+    pub fn sample(&self, ticks: f64) -> Vec<(u32, f64)> {
+        let keys = match self.keys_raw() {
+            Some(keys) if !keys.is_empty() => keys,
+            _ => return Vec::new(),
+        };
+        let next = keys.partition_point(|key| key.mTime <= ticks);
+        if next == 0 {
+            return morph_key_weights(&keys[0]).collect();
+        }
+        if next == keys.len() {
+            return morph_key_weights(&keys[next - 1]).collect();
+        }
+
+        let (before, after) = (&keys[next - 1], &keys[next]);
+        let span = after.mTime - before.mTime;
+        let t = if span > 0.0 {
+            (ticks - before.mTime) / span
+        } else {
+            0.0
+        };
+        let mut weights: Vec<(u32, f64)> = morph_key_weights(before)
+            .map(|(target, weight)| (target, weight * (1.0 - t)))
+            .collect();
+        for (target, weight) in morph_key_weights(after) {
+            match weights.iter_mut().find(|(existing, _)| *existing == target) {
+                Some((_, blended)) => *blended += weight * t,
+                None => weights.push((target, weight * t)),
+            }
+        }
+        weights
+    }
+}
+
+/// `(target, weight)` pairs of a morph key; empty when its arrays are missing.
+fn morph_key_weights(key: &sys::aiMeshMorphKey) -> impl Iterator<Item = (u32, f64)> + '_ {
+    let n = key.mNumValuesAndWeights as usize;
+    let values = ffi::slice_from_ptr_len(key, key.mValues as *const u32, n);
+    let weights = ffi::slice_from_ptr_len(key, key.mWeights as *const f64, n);
+    values.iter().copied().zip(weights.iter().copied())
 }
 
 /// Iterator over morph mesh animation channels
 pub struct MorphMeshAnimationIterator {
     scene: Scene,
     animation_ptr: SharedPtr<sys::aiAnimation>,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl MorphMeshAnimationIterator {
-    #[inline]
-    fn raw(&self) -> &sys::aiAnimation {
-        self.animation_ptr.as_ref()
-    }
-
-    #[inline]
-    fn channel_array(&self) -> (*mut *mut sys::aiMeshMorphAnim, usize) {
-        let anim = self.raw();
-        (anim.mMorphMeshChannels, anim.mNumMorphMeshChannels as usize)
+    fn channel(
+        scene: &Scene,
+        animation_ptr: SharedPtr<sys::aiAnimation>,
+        index: usize,
+    ) -> Option<MorphMeshAnimation> {
+        let animation = animation_ptr.as_ref();
+        let ptr = ffi::ptr_array_get(
+            animation,
+            animation.mMorphMeshChannels as *const *mut sys::aiMeshMorphAnim,
+            animation.mNumMorphMeshChannels as usize,
+            index,
+        )?;
+        MorphMeshAnimation::from_ptr(scene.clone(), ptr as *const sys::aiMeshMorphAnim)
     }
 }
 
 impl Iterator for MorphMeshAnimationIterator {
     type Item = MorphMeshAnimation;
+
     fn next(&mut self) -> Option<Self::Item> {
-        let (base, len) = self.channel_array();
-        let chans: &[*mut sys::aiMeshMorphAnim] =
-            ffi::slice_from_ptr_len_opt(&(), base as *const *mut sys::aiMeshMorphAnim, len)?;
-        while self.index < chans.len() {
-            let ptr = chans[self.index];
-            self.index += 1;
-            if ptr.is_null() {
-                continue;
-            }
-            return MorphMeshAnimation::from_ptr(
-                self.scene.clone(),
-                ptr as *const sys::aiMeshMorphAnim,
-            );
-        }
-        None
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .next(|index| Self::channel(scene, animation_ptr, index))
     }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let anim = self.raw();
-        if anim.mMorphMeshChannels.is_null() {
-            (0, Some(0))
-        } else {
-            let remaining = (anim.mNumMorphMeshChannels as usize).saturating_sub(self.index);
-            (0, Some(remaining))
-        }
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .nth(n, |index| Self::channel(scene, animation_ptr, index))
+    }
+}
+
+impl DoubleEndedIterator for MorphMeshAnimationIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, animation_ptr) = (&self.scene, self.animation_ptr);
+        self.cursor
+            .next_back(|index| Self::channel(scene, animation_ptr, index))
     }
 }
 
+impl ExactSizeIterator for MorphMeshAnimationIterator {}
+
 // Auto-traits (Send/Sync) are derived from the contained pointers and lifetimes.
 
 #[cfg(test)]
@@ -0,0 +1,147 @@
+//! Listing and selecting animation clips by exporter-independent names.
+//!
+//! Multi-take files import every take as one [`Animation`], but the names carry whatever
+//! decoration the exporter added: FBX stacks come through as `AnimStack::Take 001`,
+//! Blender prefixes the armature (`Armature|Walk`), and Maya keeps its namespaces
+//! (`rig:Run`). [`clean_clip_name`] strips those so clips can be picked by the name an
+//! artist would use:
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! # fn pick(scene: &Scene) {
+//! for clip in scene.animation_clips() {
+//!     println!("{} ({:.2} s)", clip.clean_name, clip.duration_seconds);
+//! }
+//! let walk = scene.animation_by_clean_name("walk");
+//! # }
+//! ```
+
+use crate::{animation::Animation, scene::Scene};
+
+/// FBX object-class prefixes that can precede a take name, matched case-insensitively.
+const CLASS_PREFIXES: [&str; 2] = ["AnimStack::", "AnimLayer::"];
+
+/// Summary of one animation of a scene, see [`Scene::animation_clips`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationClipInfo {
+    /// Index into [`Scene::animations`]
+    pub index: usize,
+    /// Name as stored by the importer
+    pub raw_name: String,
+    /// Name with exporter decoration removed, see [`clean_clip_name`]
+    pub clean_name: String,
+    /// Declared duration in seconds
+    pub duration_seconds: f64,
+    /// Number of node channels
+    pub channel_count: usize,
+}
+
+/// Strip exporter decoration from an animation name.
+///
+/// Applied in order:
+/// 1. surrounding whitespace is trimmed;
+/// 2. leading FBX class prefixes (`AnimStack::`, `AnimLayer::`, any case, repeated) are
+///    removed;
+/// 3. everything up to the last `|` is removed, so Blender's `Armature|Walk` and nested
+///    `Armature|Armature|Walk` both become `Walk`;
+/// 4. leading Maya namespaces (`rig:` or `rig:arm:`: a run of characters without
+///    whitespace, then a single colon directly followed by the rest) are removed;
+/// 5. whitespace is trimmed again.
+///
+/// If nothing is left, the trimmed input is returned instead.
+pub fn clean_clip_name(name: &str) -> String {
+    let trimmed = name.trim();
+    let mut rest = trimmed;
+    while let Some(stripped) = CLASS_PREFIXES
+        .iter()
+        .find_map(|prefix| strip_prefix_ignore_case(rest, prefix))
+    {
+        rest = stripped.trim_start();
+    }
+    if let Some((_, last)) = rest.rsplit_once('|') {
+        rest = last;
+    }
+    rest = strip_namespaces(rest.trim());
+    let clean = rest.trim();
+    if clean.is_empty() { trimmed } else { clean }.to_string()
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &s[prefix.len()..])
+}
+
+/// Remove leading `namespace:` segments; `::` separators and colons next to whitespace
+/// (`Jump: high`) are left alone.
+fn strip_namespaces(mut s: &str) -> &str {
+    while let Some(colon) = s.find(':') {
+        let (namespace, tail) = (&s[..colon], &s[colon + 1..]);
+        if namespace.is_empty()
+            || namespace.contains(char::is_whitespace)
+            || tail.starts_with([':', ' ', '\t'])
+        {
+            break;
+        }
+        s = tail;
+    }
+    s
+}
+
+impl Scene {
+    /// List every animation with its exporter-independent name.
+    pub fn animation_clips(&self) -> Vec<AnimationClipInfo> {
+        self.animations()
+            .enumerate()
+            .map(|(index, animation)| {
+                let raw_name = animation.name();
+                AnimationClipInfo {
+                    index,
+                    clean_name: clean_clip_name(&raw_name),
+                    raw_name,
+                    duration_seconds: animation.duration_in_seconds(),
+                    channel_count: animation.num_channels(),
+                }
+            })
+            .collect()
+    }
+
+    /// The first animation whose cleaned name matches `name`.
+    ///
+    /// Both sides go through [`clean_clip_name`] and are compared case-insensitively, so
+    /// `"walk"`, `" Walk "` and `"Armature|Walk"` all find a clip stored as
+    /// `AnimStack::Armature|Walk`.
+    pub fn animation_by_clean_name(&self, name: &str) -> Option<Animation> {
+        let wanted = clean_clip_name(name).to_lowercase();
+        self.animations()
+            .find(|animation| clean_clip_name(&animation.name()).to_lowercase() == wanted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clean_clip_name;
+
+    #[test]
+    fn test_clean_clip_name() {
+        let cases = [
+            ("AnimStack::Take 001", "Take 001"),
+            ("animstack::AnimStack::Take 001", "Take 001"),
+            ("AnimStack::AnimLayer::Base", "Base"),
+            ("Armature|Walk", "Walk"),
+            ("Armature|Armature|Walk", "Walk"),
+            ("AnimStack::Armature|Run ", "Run"),
+            ("rig:Idle", "Idle"),
+            ("rig:arm:Wave", "Wave"),
+            ("mixamo.com", "mixamo.com"),
+            ("Jump: high", "Jump: high"),
+            ("Node::Take", "Node::Take"),
+            ("  Take 001  ", "Take 001"),
+            ("Armature|", "Armature|"),
+            ("", ""),
+        ];
+        for (raw, clean) in cases {
+            assert_eq!(clean_clip_name(raw), clean, "{raw:?}");
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Cutting one long timeline into separate clips.
+//!
+//! Source files often store every clip on a single timeline (walk at 0–30, run at 31–60).
+//! [`extract_clip`] copies one range out as an [`OwnedAnimationClip`] that starts at 0
+//! and no longer borrows the scene:
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! use asset_importer::animation::split::extract_clip;
+//!
+//! # fn split(scene: &Scene) {
+//! let timeline = scene.animation(0).unwrap();
+//! let mut walk = extract_clip(&timeline, 0.0, 30.0);
+//! walk.name = "walk".into();
+//! let mut run = extract_clip(&timeline, 31.0, 60.0);
+//! run.name = "run".into();
+//! # }
+//! ```
+
+use crate::{
+    animation::{
+        AnimBehaviour, Animation, MeshAnimation, MeshKey, MorphMeshAnimation, NodeAnimation,
+        QuaternionKey, VectorKey, behaviour_ticks, sample_track,
+    },
+    types::{Quaternion, Vector3D},
+};
+
+/// An owned animation, usually a range of a longer one cut by [`extract_clip`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedAnimationClip {
+    /// Name of the clip; [`extract_clip`] copies the source animation's name
+    pub name: String,
+    /// Length in ticks
+    pub duration: f64,
+    /// Ticks per second, as reported by [`Animation::ticks_per_second`]
+    pub ticks_per_second: f64,
+    /// Node channels
+    pub channels: Vec<OwnedNodeChannel>,
+    /// Mesh (vertex animation) channels
+    pub mesh_channels: Vec<OwnedMeshChannel>,
+    /// Morph target weight channels
+    pub morph_mesh_channels: Vec<OwnedMorphMeshChannel>,
+}
+
+impl OwnedAnimationClip {
+    /// Length in seconds.
+    pub fn duration_in_seconds(&self) -> f64 {
+        self.duration / self.ticks_per_second
+    }
+
+    /// The first channel animating the node named `node_name`.
+    pub fn channel(&self, node_name: &str) -> Option<&OwnedNodeChannel> {
+        self.channels
+            .iter()
+            .find(|channel| channel.node_name == node_name)
+    }
+}
+
+/// Owned keys of one node channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedNodeChannel {
+    /// Name of the animated node
+    pub node_name: String,
+    /// Position keys, by time
+    pub position_keys: Vec<VectorKey>,
+    /// Rotation keys, by time
+    pub rotation_keys: Vec<QuaternionKey>,
+    /// Scaling keys, by time
+    pub scaling_keys: Vec<VectorKey>,
+    /// Behaviour before the first key
+    pub pre_state: AnimBehaviour,
+    /// Behaviour after the last key
+    pub post_state: AnimBehaviour,
+}
+
+impl From<&NodeAnimation> for OwnedNodeChannel {
+    fn from(channel: &NodeAnimation) -> Self {
+        Self {
+            node_name: channel.node_name(),
+            position_keys: channel.position_keys(),
+            rotation_keys: channel.rotation_keys(),
+            scaling_keys: channel.scaling_keys(),
+            pre_state: channel.pre_state(),
+            post_state: channel.post_state(),
+        }
+    }
+}
+
+impl OwnedNodeChannel {
+    /// Interpolate the position at `ticks`, as [`NodeAnimation::sample_position`] does.
+    pub fn sample_position(&self, ticks: f64) -> Option<Vector3D> {
+        self.position_key_at(ticks).map(|key| key.value)
+    }
+
+    /// Interpolate the rotation at `ticks`, as [`NodeAnimation::sample_rotation`] does.
+    pub fn sample_rotation(&self, ticks: f64) -> Option<Quaternion> {
+        self.rotation_key_at(ticks).map(|key| key.value)
+    }
+
+    /// Interpolate the scaling at `ticks`, as [`NodeAnimation::sample_scaling`] does.
+    pub fn sample_scaling(&self, ticks: f64) -> Option<Vector3D> {
+        self.scaling_key_at(ticks).map(|key| key.value)
+    }
+
+    fn position_key_at(&self, ticks: f64) -> Option<VectorKey> {
+        self.vector_key_at(&self.position_keys, ticks)
+    }
+
+    fn scaling_key_at(&self, ticks: f64) -> Option<VectorKey> {
+        self.vector_key_at(&self.scaling_keys, ticks)
+    }
+
+    fn vector_key_at(&self, keys: &[VectorKey], ticks: f64) -> Option<VectorKey> {
+        let range = (keys.first()?.time, keys.last()?.time);
+        let wrapped = behaviour_ticks(ticks, range, self.pre_state, self.post_state);
+        let (value, interpolation) = sample_track(
+            keys,
+            wrapped,
+            |key| (key.time, key.value, key.interpolation),
+            Vector3D::lerp,
+        )?;
+        Some(VectorKey {
+            time: ticks,
+            value,
+            interpolation,
+        })
+    }
+
+    fn rotation_key_at(&self, ticks: f64) -> Option<QuaternionKey> {
+        let keys = &self.rotation_keys;
+        let range = (keys.first()?.time, keys.last()?.time);
+        let wrapped = behaviour_ticks(ticks, range, self.pre_state, self.post_state);
+        let (value, interpolation) = sample_track(
+            keys,
+            wrapped,
+            |key| (key.time, key.value, key.interpolation),
+            Quaternion::slerp,
+        )?;
+        Some(QuaternionKey {
+            time: ticks,
+            value,
+            interpolation,
+        })
+    }
+}
+
+/// Owned keys of one mesh channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedMeshChannel {
+    /// Name of the animated mesh
+    pub name: String,
+    /// Keys selecting an entry of the mesh's anim meshes, by time
+    pub keys: Vec<MeshKey>,
+}
+
+impl From<&MeshAnimation> for OwnedMeshChannel {
+    fn from(channel: &MeshAnimation) -> Self {
+        Self {
+            name: channel.name(),
+            keys: channel.keys().to_vec(),
+        }
+    }
+}
+
+/// One key of an [`OwnedMorphMeshChannel`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedMorphKey {
+    /// Time in ticks
+    pub time: f64,
+    /// `(morph target, weight)` pairs
+    pub weights: Vec<(u32, f64)>,
+}
+
+/// Owned keys of one morph target weight channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedMorphMeshChannel {
+    /// Name of the animated mesh
+    pub name: String,
+    /// Keys, by time
+    pub keys: Vec<OwnedMorphKey>,
+}
+
+impl From<&MorphMeshAnimation> for OwnedMorphMeshChannel {
+    fn from(channel: &MorphMeshAnimation) -> Self {
+        let keys = (0..channel.num_keys())
+            .filter_map(|index| channel.key(index))
+            .map(|key| OwnedMorphKey {
+                time: key.time(),
+                weights: key
+                    .values()
+                    .iter()
+                    .copied()
+                    .zip(key.weights().iter().copied())
+                    .collect(),
+            })
+            .collect();
+        Self {
+            name: channel.name(),
+            keys,
+        }
+    }
+}
+
+/// Key types whose time can be read and rebased.
+trait Timed {
+    fn time(&self) -> f64;
+    fn set_time(&mut self, time: f64);
+}
+
+macro_rules! impl_timed {
+    ($($ty:ty),*) => {$(
+        impl Timed for $ty {
+            fn time(&self) -> f64 {
+                self.time
+            }
+            fn set_time(&mut self, time: f64) {
+                self.time = time;
+            }
+        }
+    )*};
+}
+
+impl_timed!(VectorKey, QuaternionKey, MeshKey, OwnedMorphKey);
+
+/// The keys within `[start, end]` shifted to start at 0. Keys sampled at `start` and
+/// `end` are added unless a key already sits exactly on the cut.
+fn trim<K: Timed + Clone>(
+    keys: &[K],
+    start: f64,
+    end: f64,
+    sample: impl Fn(f64) -> Option<K>,
+) -> Vec<K> {
+    let inside = keys
+        .iter()
+        .filter(|key| (start..=end).contains(&key.time()));
+    let mut out: Vec<K> = Vec::new();
+    if keys.iter().all(|key| key.time() != start) {
+        out.extend(sample(start));
+    }
+    out.extend(inside.cloned());
+    if end > start && keys.iter().all(|key| key.time() != end) {
+        out.extend(sample(end));
+    }
+    for key in &mut out {
+        key.set_time(key.time() - start);
+    }
+    out
+}
+
+/// Copy the keys of `animation` between `start_ticks` and `end_ticks` into a clip that
+/// starts at 0.
+///
+/// Every track that has keys gets a key at both cut points: an existing key when one
+/// lies exactly on the cut, otherwise one sampled there with the same rules as
+/// [`NodeAnimation::sample_position`]. Mesh channels hold the last key at or before the
+/// cut. Sampling the clip at `t` therefore gives the same pose as sampling the source at
+/// `start_ticks + t`. An `end_ticks` before `start_ticks` yields a clip of length 0
+/// holding the pose at `start_ticks`.
+pub fn extract_clip(animation: &Animation, start_ticks: f64, end_ticks: f64) -> OwnedAnimationClip {
+    let (start, end) = (start_ticks, end_ticks.max(start_ticks));
+
+    let channels = animation
+        .channels()
+        .map(|channel| {
+            let full = OwnedNodeChannel::from(&channel);
+            let position_keys = trim(&full.position_keys, start, end, |t| full.position_key_at(t));
+            let rotation_keys = trim(&full.rotation_keys, start, end, |t| full.rotation_key_at(t));
+            let scaling_keys = trim(&full.scaling_keys, start, end, |t| full.scaling_key_at(t));
+            OwnedNodeChannel {
+                position_keys,
+                rotation_keys,
+                scaling_keys,
+                ..full
+            }
+        })
+        .collect();
+
+    let mesh_channels = animation
+        .mesh_channels()
+        .map(|channel| {
+            let full = OwnedMeshChannel::from(&channel);
+            let keys = trim(&full.keys, start, end, |t| {
+                let held = full.keys.iter().rev().find(|key| key.time <= t);
+                let mut key = *held.or(full.keys.first())?;
+                key.time = t;
+                Some(key)
+            });
+            OwnedMeshChannel { keys, ..full }
+        })
+        .collect();
+
+    let morph_mesh_channels = animation
+        .morph_mesh_channels()
+        .map(|channel| {
+            let full = OwnedMorphMeshChannel::from(&channel);
+            let keys = trim(&full.keys, start, end, |t| {
+                (!full.keys.is_empty()).then(|| OwnedMorphKey {
+                    time: t,
+                    weights: channel.sample(t),
+                })
+            });
+            OwnedMorphMeshChannel { keys, ..full }
+        })
+        .collect();
+
+    OwnedAnimationClip {
+        name: animation.name(),
+        duration: end - start,
+        ticks_per_second: animation.ticks_per_second(),
+        channels,
+        mesh_channels,
+        morph_mesh_channels,
+    }
+}
@@ -0,0 +1,206 @@
+//! Scenes as Assimp JSON (`assjson`), parsed (requires the `assjson` feature).
+//!
+//! Assimp's `assjson` exporter writes the whole scene as one JSON document, which is
+//! handy for debugging and diffing. [`Scene::to_assjson_value`] runs it and returns the
+//! parsed document; [`Scene::to_assjson`] further reads the stable top-level parts into
+//! [`AssJsonScene`]. Every typed field defaults when missing, so documents written by
+//! older or newer Assimp versions still parse; anything not modelled here stays
+//! reachable through the untyped value.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! # fn dump(scene: &Scene) -> asset_importer::Result<()> {
+//! let json = scene.to_assjson()?;
+//! for mesh in &json.meshes {
+//!     println!("{}: {} vertices", mesh.name, mesh.positions().count());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    exporter::{ExportBuilder, format_ids, is_format_available},
+    scene::Scene,
+};
+
+/// Top-level parts of an `assjson` document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonScene {
+    /// Root of the node hierarchy (`rootnode`)
+    #[serde(rename = "rootnode")]
+    pub root_node: AssJsonNode,
+    /// Meshes in scene order
+    pub meshes: Vec<AssJsonMesh>,
+    /// Materials in scene order
+    pub materials: Vec<AssJsonMaterial>,
+    /// Animations in scene order
+    pub animations: Vec<AssJsonAnimation>,
+}
+
+/// A node of [`AssJsonScene::root_node`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonNode {
+    /// Node name
+    pub name: String,
+    /// Local transform, 16 values in row-major order like `aiMatrix4x4`
+    pub transformation: Vec<f32>,
+    /// Indices into [`AssJsonScene::meshes`]
+    pub meshes: Vec<u32>,
+    /// Child nodes
+    pub children: Vec<AssJsonNode>,
+}
+
+/// A mesh of [`AssJsonScene::meshes`]. Vertex attributes are flat arrays of floats.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonMesh {
+    /// Mesh name
+    pub name: String,
+    /// Index into [`AssJsonScene::materials`]
+    #[serde(rename = "materialindex")]
+    pub material_index: u32,
+    /// `aiPrimitiveType` bits
+    #[serde(rename = "primitivetypes")]
+    pub primitive_types: u32,
+    /// Positions, three floats per vertex
+    pub vertices: Vec<f32>,
+    /// Normals, three floats per vertex
+    pub normals: Vec<f32>,
+    /// Tangents, three floats per vertex
+    pub tangents: Vec<f32>,
+    /// Bitangents, three floats per vertex
+    pub bitangents: Vec<f32>,
+    /// Components per vertex of each UV channel
+    #[serde(rename = "numuvcomponents")]
+    pub num_uv_components: Vec<u32>,
+    /// UV channels, [`AssJsonMesh::num_uv_components`] floats per vertex each
+    #[serde(rename = "texturecoords")]
+    pub texture_coords: Vec<Vec<f32>>,
+    /// Vertex color channels, four floats per vertex each
+    pub colors: Vec<Vec<f32>>,
+    /// Vertex indices of each face
+    pub faces: Vec<Vec<u32>>,
+    /// Bones influencing the mesh
+    pub bones: Vec<AssJsonBone>,
+}
+
+impl AssJsonMesh {
+    /// Positions as `[x, y, z]`.
+    pub fn positions(&self) -> impl Iterator<Item = [f32; 3]> + '_ {
+        self.vertices.chunks_exact(3).map(|v| [v[0], v[1], v[2]])
+    }
+}
+
+/// A bone of [`AssJsonMesh::bones`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonBone {
+    /// Bone (and node) name
+    pub name: String,
+    /// Mesh-to-bone-space matrix, 16 values in row-major order
+    #[serde(rename = "offsetmatrix")]
+    pub offset_matrix: Vec<f32>,
+    /// `(vertex index, weight)` pairs
+    pub weights: Vec<(u32, f32)>,
+}
+
+/// A material of [`AssJsonScene::materials`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonMaterial {
+    /// Every material property, in the order Assimp stores them
+    pub properties: Vec<AssJsonMaterialProperty>,
+}
+
+impl AssJsonMaterial {
+    /// First property with `key` (e.g. `?mat.name` or `$clr.diffuse`).
+    pub fn property(&self, key: &str) -> Option<&AssJsonMaterialProperty> {
+        self.properties.iter().find(|p| p.key == key)
+    }
+}
+
+/// One entry of [`AssJsonMaterial::properties`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonMaterialProperty {
+    /// Property key
+    pub key: String,
+    /// `aiTextureType` for texture properties, 0 otherwise
+    pub semantic: u32,
+    /// Texture index for texture properties, 0 otherwise
+    pub index: u32,
+    /// `aiPropertyTypeInfo` of the stored value
+    #[serde(rename = "type")]
+    pub type_info: u32,
+    /// The value: a number, string or array depending on the type
+    pub value: serde_json::Value,
+}
+
+/// An animation of [`AssJsonScene::animations`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonAnimation {
+    /// Animation name
+    pub name: String,
+    /// Ticks per second, 0 when the source did not say
+    #[serde(rename = "tickspersecond")]
+    pub ticks_per_second: f64,
+    /// Duration in ticks
+    pub duration: f64,
+    /// Node channels
+    pub channels: Vec<AssJsonChannel>,
+}
+
+/// A node channel of [`AssJsonAnimation::channels`]. Keys are `(time in ticks, value)`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AssJsonChannel {
+    /// Name of the animated node
+    pub name: String,
+    /// `aiAnimBehaviour` before the first key
+    #[serde(rename = "prestate")]
+    pub pre_state: u32,
+    /// `aiAnimBehaviour` after the last key
+    #[serde(rename = "poststate")]
+    pub post_state: u32,
+    /// Position keys
+    #[serde(rename = "positionkeys")]
+    pub position_keys: Vec<(f64, [f32; 3])>,
+    /// Rotation keys, quaternions as `[w, x, y, z]`
+    #[serde(rename = "rotationkeys")]
+    pub rotation_keys: Vec<(f64, [f32; 4])>,
+    /// Scaling keys
+    #[serde(rename = "scalingkeys")]
+    pub scaling_keys: Vec<(f64, [f32; 3])>,
+}
+
+impl Scene {
+    /// Export the scene with Assimp's `assjson` exporter and parse the result.
+    ///
+    /// Fails with [`Error::UnsupportedFormat`] when the linked Assimp was built without
+    /// that exporter.
+    pub fn to_assjson_value(&self) -> Result<serde_json::Value> {
+        if !is_format_available(format_ids::ASSJSON) {
+            return Err(Error::unsupported_format(
+                "assjson (the linked Assimp has no JSON exporter; build it with \
+                 ASSIMP_BUILD_ASSJSON_EXPORTER=ON, the default for source builds, and \
+                 without ASSIMP_NO_EXPORT)",
+            ));
+        }
+        let blob = ExportBuilder::new(format_ids::ASSJSON).export_to_blob(self)?;
+        serde_json::from_slice(blob.data())
+            .map_err(|e| Error::export_failed(format!("assjson output is not valid JSON: {e}")))
+    }
+
+    /// Export the scene as `assjson` and read its top-level parts, see [`AssJsonScene`].
+    pub fn to_assjson(&self) -> Result<AssJsonScene> {
+        serde_json::from_value(self.to_assjson_value()?).map_err(|e| {
+            Error::export_failed(format!("assjson output has an unexpected shape: {e}"))
+        })
+    }
+}
@@ -4,13 +4,19 @@
 //! which are essential for skeletal animation in 3D models.
 
 use crate::{
+    aabb::AABB,
     error::{Error, Result},
     ffi,
+    mesh::Mesh,
+    node::Node,
     ptr::SharedPtr,
     raw,
     scene::Scene,
     sys,
-    types::{Matrix4x4, ai_string_to_str, ai_string_to_string, from_ai_matrix4x4},
+    types::{
+        Matrix4x4, Name, Vector3D, ai_string_bytes, ai_string_to_name, ai_string_to_str,
+        ai_string_to_string, from_ai_matrix4x4, from_real,
+    },
 };
 
 /// A vertex weight that associates a vertex with a bone
@@ -50,7 +56,7 @@ impl From<&sys::aiVertexWeight> for VertexWeight {
     fn from(weight: &sys::aiVertexWeight) -> Self {
         Self {
             vertex_id: weight.mVertexId,
-            weight: weight.mWeight,
+            weight: from_real(weight.mWeight),
         }
     }
 }
@@ -59,7 +65,7 @@ impl From<&raw::AiVertexWeight> for VertexWeight {
     fn from(weight: &raw::AiVertexWeight) -> Self {
         Self {
             vertex_id: weight.mVertexId,
-            weight: weight.mWeight,
+            weight: from_real(weight.mWeight),
         }
     }
 }
@@ -70,7 +76,6 @@ impl From<&raw::AiVertexWeight> for VertexWeight {
 /// Each bone has a name, an offset matrix, and a list of vertex weights.
 #[derive(Debug, Clone)]
 pub struct Bone {
-    #[allow(dead_code)]
     scene: Scene,
     bone_ptr: SharedPtr<sys::aiBone>,
 }
@@ -103,11 +108,41 @@ impl Bone {
         ai_string_to_string(&self.raw().mName)
     }
 
+    /// Get the name of the bone as an inline [`Name`], allocating
+    /// only for names longer than 64 bytes.
+    pub fn name_owned(&self) -> Name {
+        ai_string_to_name(&self.raw().mName)
+    }
+
     /// Get the name of the bone (zero-copy, lossy UTF-8).
     pub fn name_str(&self) -> std::borrow::Cow<'_, str> {
         ai_string_to_str(&self.raw().mName)
     }
 
+    /// Bytes of the name up to the first NUL, for binary-safe comparison.
+    pub fn name_bytes(&self) -> &[u8] {
+        ai_string_bytes(&self.raw().mName)
+    }
+
+    /// The node this bone animates (`aiBone::mNode`).
+    ///
+    /// Only filled in when the scene was imported with
+    /// [`PostProcessSteps::POPULATE_ARMATURE_DATA`](crate::postprocess::PostProcessSteps::POPULATE_ARMATURE_DATA),
+    /// e.g. via [`ImportBuilder::with_armature_population`](crate::ImportBuilder::with_armature_population);
+    /// otherwise `None`, and the node has to be found by name. The returned node
+    /// belongs to the same scene as this bone and keeps it alive.
+    pub fn node(&self) -> Option<Node> {
+        Node::from_sys_ptr(self.scene.clone(), self.raw().mNode)
+    }
+
+    /// The root node of the skeleton this bone belongs to (`aiBone::mArmature`).
+    ///
+    /// Populated under the same conditions as [`Bone::node`], and likewise a node of
+    /// this bone's scene.
+    pub fn armature_node(&self) -> Option<Node> {
+        Node::from_sys_ptr(self.scene.clone(), self.raw().mArmature)
+    }
+
     /// Get the number of vertex weights for this bone
     pub fn num_weights(&self) -> usize {
         let bone = self.raw();
@@ -162,6 +197,26 @@ impl Bone {
         from_ai_matrix4x4(self.raw().mOffsetMatrix)
     }
 
+    /// Bounds of the vertices of `mesh` this bone influences with a weight of at
+    /// least `min_weight`, in bind pose (mesh space).
+    ///
+    /// Transform the result with [`Bone::offset_matrix`] for bounds in bone space.
+    /// Returns `None` when no weight reaches the threshold; weights naming vertices
+    /// outside `mesh` are ignored, so pass the mesh this bone belongs to.
+    pub fn compute_aabb(&self, mesh: &Mesh, min_weight: f32) -> Option<AABB> {
+        let vertices = mesh.vertices_raw();
+        let mut aabb = AABB::empty();
+        for weight in self.weights_raw() {
+            if from_real(weight.mWeight) < min_weight {
+                continue;
+            }
+            if let Some(vertex) = vertices.get(weight.mVertexId as usize) {
+                aabb.expand_to_include_point(Vector3D::from(*vertex));
+            }
+        }
+        (!aabb.is_empty()).then_some(aabb)
+    }
+
     /// Get weights that affect a specific vertex
     pub fn weights_for_vertex(&self, vertex_id: u32) -> Vec<VertexWeight> {
         self.weights_for_vertex_iter(vertex_id).collect()
@@ -213,7 +268,7 @@ impl Bone {
         if ws.is_empty() {
             return 0.0;
         }
-        let sum: f32 = ws.iter().map(|w| w.mWeight).sum();
+        let sum: f32 = ws.iter().map(|w| from_real(w.mWeight)).sum();
         sum / (ws.len() as f32)
     }
 
@@ -259,7 +314,7 @@ pub struct BoneIterator {
     scene: Scene,
     bones: Option<SharedPtr<*const sys::aiBone>>,
     count: usize,
-    index: usize,
+    cursor: crate::ffi::PtrArrayCursor,
 }
 
 impl BoneIterator {
@@ -267,12 +322,28 @@ impl BoneIterator {
     pub(crate) fn new(scene: Scene, bones: *mut *mut sys::aiBone, count: usize) -> Self {
         let bones_ptr = SharedPtr::new(bones as *const *const sys::aiBone);
         let count = if bones_ptr.is_some() { count } else { 0 };
+        let cursor =
+            crate::ffi::PtrArrayCursor::new(&scene, bones as *const *const sys::aiBone, count);
         Self {
             scene,
             bones: bones_ptr,
             count,
-            index: 0,
+            cursor,
+        }
+    }
+
+    fn item(
+        scene: &Scene,
+        bones: Option<SharedPtr<*const sys::aiBone>>,
+        count: usize,
+        index: usize,
+    ) -> Option<Bone> {
+        let slice = crate::ffi::slice_from_ptr_len_opt(&(), bones?.as_ptr(), count)?;
+        let ptr = *slice.get(index)?;
+        if ptr.is_null() {
+            return None;
         }
+        Bone::from_sys_ptr(scene.clone(), ptr as *mut sys::aiBone).ok()
     }
 }
 
@@ -280,27 +351,32 @@ impl Iterator for BoneIterator {
     type Item = Bone;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let bones = self.bones?;
-        let slice = crate::ffi::slice_from_ptr_len_opt(&(), bones.as_ptr(), self.count)?;
-        while self.index < slice.len() {
-            let bone_ptr = slice[self.index];
-            self.index += 1;
-            if bone_ptr.is_null() {
-                continue;
-            }
-            if let Ok(bone) = Bone::from_sys_ptr(self.scene.clone(), bone_ptr as *mut sys::aiBone) {
-                return Some(bone);
-            }
-        }
-        None
+        let (scene, bones, count) = (&self.scene, self.bones, self.count);
+        self.cursor
+            .next(|index| Self::item(scene, bones, count, index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.count.saturating_sub(self.index);
-        (0, Some(remaining))
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, bones, count) = (&self.scene, self.bones, self.count);
+        self.cursor
+            .nth(n, |index| Self::item(scene, bones, count, index))
     }
 }
 
+impl DoubleEndedIterator for BoneIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, bones, count) = (&self.scene, self.bones, self.count);
+        self.cursor
+            .next_back(|index| Self::item(scene, bones, count, index))
+    }
+}
+
+impl ExactSizeIterator for BoneIterator {}
+
 /// Utility functions for working with bones and weights
 pub mod utils {
     use super::*;
@@ -7,84 +7,138 @@ use crate::{
     types::to_ai_matrix4x4,
 };
 
+/// Property list for the `*WithPropertiesRust` / `*WithProgressRust` bridge functions.
+///
+/// `ffi_props` holds raw pointers into the other fields. Those buffers are filled
+/// completely before any pointer is taken and never touched afterwards, so the pointers
+/// stay valid for as long as this value lives. `CString` and the boxed slice keep their
+/// heap data in place even when the struct itself moves.
 pub(crate) struct BridgePropertyBuffers {
-    pub(crate) ffi_props: Vec<sys::aiRustProperty>,
-    _name_bufs: Vec<CString>,
-    _value_str_bufs: Vec<CString>,
-    _matrices: Vec<sys::aiMatrix4x4>,
+    ffi_props: Vec<sys::aiRustProperty>,
+    names: Vec<CString>,
+    strings: Vec<CString>,
+    matrices: Box<[sys::aiMatrix4x4]>,
+}
+
+/// Where a property's value lives while the owned buffers are being collected.
+enum Slot {
+    Integer(i32),
+    Boolean(bool),
+    Float(f32),
+    String(usize),
+    Matrix(usize),
+}
+
+impl BridgePropertyBuffers {
+    /// Pointer to the first property, for the bridge call.
+    pub(crate) fn as_ptr(&self) -> *const sys::aiRustProperty {
+        self.debug_assert_pointers();
+        self.ffi_props.as_ptr()
+    }
+
+    /// Number of properties behind [`Self::as_ptr`].
+    pub(crate) fn len(&self) -> usize {
+        self.ffi_props.len()
+    }
+
+    /// Check that every pointer handed to C points into buffers owned by `self`.
+    fn debug_assert_pointers(&self) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        let matrices = self.matrices.as_ptr_range();
+        for p in &self.ffi_props {
+            debug_assert!(self.names.iter().any(|n| n.as_ptr() == p.name));
+            match p.kind {
+                sys::aiRustPropertyKind::aiRustPropertyKind_String => {
+                    debug_assert!(self.strings.iter().any(|s| s.as_ptr() == p.string_value));
+                }
+                sys::aiRustPropertyKind::aiRustPropertyKind_Matrix4x4 => {
+                    let ptr = p.matrix_value.cast::<sys::aiMatrix4x4>().cast_const();
+                    debug_assert!(matrices.contains(&ptr), "matrix pointer outside buffer");
+                }
+                _ => {}
+            }
+        }
+    }
 }
 
 pub(crate) fn build_rust_properties(
     props: &[(String, PropertyValue)],
 ) -> Result<BridgePropertyBuffers> {
-    let mut ffi_props = Vec::with_capacity(props.len());
-    let mut name_bufs: Vec<CString> = Vec::with_capacity(props.len());
-    let mut value_str_bufs: Vec<CString> = Vec::new();
+    // First pass: own every name, string and matrix. No pointers are taken yet, so the
+    // vectors are free to grow.
+    let mut names: Vec<CString> = Vec::with_capacity(props.len());
+    let mut strings: Vec<CString> = Vec::new();
     let mut matrices: Vec<sys::aiMatrix4x4> = Vec::new();
-    let mut matrix_ptr_fixes: Vec<(usize, usize)> = Vec::new();
-
-    for (prop_index, (name, value)) in props.iter().enumerate() {
-        let c_name = CString::new(name.as_str())
-            .map_err(|_| Error::invalid_parameter("Invalid property name"))?;
-        let mut p = sys::aiRustProperty {
-            name: c_name.as_ptr(),
-            kind: sys::aiRustPropertyKind::aiRustPropertyKind_Integer, // default, will set below
-            int_value: 0,
-            float_value: 0.0,
-            string_value: std::ptr::null(),
-            matrix_value: std::ptr::null_mut(),
-        };
-
-        match value {
-            PropertyValue::Integer(v) => {
-                p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_Integer;
-                p.int_value = *v;
-            }
-            PropertyValue::Boolean(v) => {
-                p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_Boolean;
-                p.int_value = if *v { 1 } else { 0 };
-            }
-            PropertyValue::Float(v) => {
-                p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_Float;
-                p.float_value = *v;
-            }
+    let mut slots = Vec::with_capacity(props.len());
+    for (name, value) in props {
+        names.push(
+            CString::new(name.as_str())
+                .map_err(|_| Error::invalid_parameter("Invalid property name"))?,
+        );
+        slots.push(match value {
+            PropertyValue::Integer(v) => Slot::Integer(*v),
+            PropertyValue::Boolean(v) => Slot::Boolean(*v),
+            PropertyValue::Float(v) => Slot::Float(*v),
             PropertyValue::String(s) => {
-                p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_String;
-                let c_val = CString::new(s.as_str())
-                    .map_err(|_| Error::invalid_parameter("Invalid property string value"))?;
-                p.string_value = c_val.as_ptr();
-                value_str_bufs.push(c_val);
+                strings.push(
+                    CString::new(s.as_str())
+                        .map_err(|_| Error::invalid_parameter("Invalid property string value"))?,
+                );
+                Slot::String(strings.len() - 1)
             }
             PropertyValue::Matrix(m) => {
-                p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_Matrix4x4;
                 matrices.push(to_ai_matrix4x4(*m));
-                let matrix_index = matrices.len() - 1;
-                matrix_ptr_fixes.push((prop_index, matrix_index));
+                Slot::Matrix(matrices.len() - 1)
             }
-        }
-
-        name_bufs.push(c_name);
-        ffi_props.push(p);
+        });
     }
 
-    // Patch matrix pointers after all matrix values are stored, so pointers are stable even if the
-    // matrices Vec had to grow during collection.
-    for (prop_index, matrix_index) in matrix_ptr_fixes {
-        let matrix = matrices
-            .get(matrix_index)
-            .expect("matrix index should be in-bounds");
-        let matrix_ptr = std::ptr::from_ref(matrix);
-        let prop = ffi_props
-            .get_mut(prop_index)
-            .expect("prop index should be in-bounds");
-        prop.matrix_value = matrix_ptr.cast::<std::ffi::c_void>().cast_mut();
-    }
+    // Second pass: the buffers are frozen from here on, so their pointers stay put.
+    let matrices = matrices.into_boxed_slice();
+    let ffi_props = names
+        .iter()
+        .zip(slots)
+        .map(|(name, slot)| {
+            let mut p = sys::aiRustProperty {
+                name: name.as_ptr(),
+                kind: sys::aiRustPropertyKind::aiRustPropertyKind_Integer,
+                int_value: 0,
+                float_value: 0.0,
+                string_value: std::ptr::null(),
+                matrix_value: std::ptr::null_mut(),
+            };
+            match slot {
+                Slot::Integer(v) => p.int_value = v,
+                Slot::Boolean(v) => {
+                    p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_Boolean;
+                    p.int_value = i32::from(v);
+                }
+                Slot::Float(v) => {
+                    p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_Float;
+                    p.float_value = v;
+                }
+                Slot::String(index) => {
+                    p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_String;
+                    p.string_value = strings[index].as_ptr();
+                }
+                Slot::Matrix(index) => {
+                    p.kind = sys::aiRustPropertyKind::aiRustPropertyKind_Matrix4x4;
+                    p.matrix_value = std::ptr::from_ref(&matrices[index])
+                        .cast::<std::ffi::c_void>()
+                        .cast_mut();
+                }
+            }
+            p
+        })
+        .collect();
 
     Ok(BridgePropertyBuffers {
         ffi_props,
-        _name_bufs: name_bufs,
-        _value_str_bufs: value_str_bufs,
-        _matrices: matrices,
+        names,
+        strings,
+        matrices,
     })
 }
 
@@ -162,4 +216,31 @@ mod tests {
         let got1 = unsafe { *(p2.matrix_value as *const sys::aiMatrix4x4) };
         assert_eq!(got1, to_ai_matrix4x4(m1));
     }
+
+    #[test]
+    fn many_matrix_properties_survive_moves() {
+        let props: Vec<_> = (0..8)
+            .map(|i| {
+                let v = i as f32 + 1.0;
+                let m = crate::types::Matrix4x4::from_cols(
+                    crate::types::Vector4D::new(v, 0.0, 0.0, 0.0),
+                    crate::types::Vector4D::new(0.0, v, 0.0, 0.0),
+                    crate::types::Vector4D::new(0.0, 0.0, v, 0.0),
+                    crate::types::Vector4D::new(0.0, 0.0, 0.0, 1.0),
+                );
+                (format!("m{i}"), PropertyValue::Matrix(m))
+            })
+            .chain([("s".to_string(), PropertyValue::String("x".to_string()))])
+            .collect();
+
+        let buffers = build_rust_properties(&props).unwrap();
+        // Moving the owner must not move the buffers the pointers refer to.
+        let moved = Box::new(buffers);
+        assert_eq!(moved.len(), 9);
+        assert!(!moved.as_ptr().is_null());
+        for (i, p) in moved.ffi_props[..8].iter().enumerate() {
+            let got = unsafe { *(p.matrix_value as *const sys::aiMatrix4x4) };
+            assert_eq!(got.a1, i as f32 + 1.0);
+        }
+    }
 }
@@ -4,10 +4,13 @@ use crate::{
     ptr::SharedPtr,
     scene::Scene,
     sys,
-    types::{Vector3D, ai_string_to_string, from_ai_vector3d},
+    types::{Matrix4x4, Vector3D, Vector4D, ai_string_to_string, from_ai_vector3d},
 };
 
 /// A camera in the scene
+///
+/// Camera parameters are relative to the node with the same name; use
+/// [`Scene::camera_node`] to find it.
 #[derive(Clone)]
 pub struct Camera {
     #[allow(dead_code)]
@@ -78,7 +81,73 @@ impl Camera {
     }
 
     /// Get the orthographic width (for orthographic cameras)
+    ///
+    /// This is the *half* horizontal extent of the view volume in scene units, and is
+    /// `0.0` for perspective cameras.
     pub fn orthographic_width(&self) -> f32 {
         self.raw().mOrthographicWidth
     }
+
+    /// Whether this is an orthographic camera (non-zero [`Camera::orthographic_width`]).
+    pub fn is_orthographic(&self) -> bool {
+        self.orthographic_width() > 0.0
+    }
+
+    /// Vertical field of view in radians for the given aspect ratio.
+    ///
+    /// Assimp stores the *horizontal* FOV; `fov_y = 2 * atan(tan(fov_x / 2) / aspect)`.
+    pub fn vertical_fov(&self, aspect: f32) -> f32 {
+        2.0 * ((0.5 * self.horizontal_fov()).tan() / aspect).atan()
+    }
+
+    /// View matrix in the camera node's local space.
+    ///
+    /// Same as Assimp's `aiCamera::GetCameraMatrix`: rows are `up x look_at`, `up` and
+    /// `look_at` (normalized), so the camera looks down +Z in view space. Multiply by the
+    /// inverse of the camera node's world transform (see [`Scene::camera_node`]) to get a
+    /// world-space view matrix.
+    pub fn view_matrix(&self) -> Matrix4x4 {
+        let position = self.position();
+        let z = self.look_at().normalize();
+        let y = self.up().normalize();
+        let x = self.up().cross(self.look_at()).normalize();
+
+        Matrix4x4::from_cols(
+            Vector4D::new(x.x, y.x, z.x, 0.0),
+            Vector4D::new(x.y, y.y, z.y, 0.0),
+            Vector4D::new(x.z, y.z, z.z, 0.0),
+            Vector4D::new(-x.dot(position), -y.dot(position), -z.dot(position), 1.0),
+        )
+    }
+
+    /// Projection matrix matching [`Camera::view_matrix`] (left-handed, NDC z in [0, 1]).
+    ///
+    /// `aspect_override` replaces the stored [`Camera::aspect`]; when neither is set
+    /// (Assimp uses `0.0` for "unknown"), an aspect of `1.0` is assumed. Perspective
+    /// cameras convert the stored horizontal FOV to a vertical one; orthographic cameras
+    /// span `±orthographic_width` horizontally. Both use the stored clip planes.
+    pub fn projection_matrix(&self, aspect_override: Option<f32>) -> Matrix4x4 {
+        let aspect = match aspect_override {
+            Some(aspect) if aspect > 0.0 => aspect,
+            _ if self.aspect() > 0.0 => self.aspect(),
+            _ => 1.0,
+        };
+        let near = self.clip_plane_near();
+        let far = self.clip_plane_far();
+
+        if self.is_orthographic() {
+            let half_width = self.orthographic_width();
+            let half_height = half_width / aspect;
+            Matrix4x4::orthographic_lh(
+                -half_width,
+                half_width,
+                -half_height,
+                half_height,
+                near,
+                far,
+            )
+        } else {
+            Matrix4x4::perspective_lh(self.vertical_fov(aspect), aspect, near, far)
+        }
+    }
 }
@@ -0,0 +1,426 @@
+//! Which formats the linked Assimp build can read and write.
+//!
+//! Assimp describes importers and exporters separately: importers by a display name
+//! (`"glTF2 Importer"`) and a list of extensions, exporters by an id (`"gltf2"`,
+//! `"glb2"`) and one extension. [`capabilities`] merges both into one entry per format,
+//! so optional components such as the OpenGEX or USD importers can be checked the same
+//! way as the core formats:
+//!
+//! ```rust,no_run
+//! use asset_importer::capabilities::{Direction, capabilities};
+//!
+//! let caps = capabilities();
+//! if caps.get("usd").is_some_and(|usd| usd.read) {
+//!     println!("USD import is available");
+//! }
+//! assert!(caps.supports("scene.obj", Direction::Read));
+//! println!("{}", caps.to_markdown_table());
+//! ```
+
+use std::path::Path;
+
+use crate::importer_desc::{ImporterDesc, ImporterFlags, get_all_importer_descs};
+
+/// Known formats: canonical name, a lowercase fragment of the importer name, and the
+/// exporter ids writing the format, preferred id first.
+///
+/// Rows without an importer fragment are write-only formats whose extension would
+/// otherwise be matched to an unrelated importer (STEP to IFC, `assxml` to XML scenes).
+const KNOWN_FORMATS: &[(&str, Option<&str>, &[&str])] = &[
+    ("obj", Some("wavefront object"), &["obj", "objnomtl"]),
+    ("gltf2", Some("gltf2"), &["gltf2", "glb2"]),
+    ("gltf", Some("gltf importer"), &["gltf", "glb"]),
+    ("collada", Some("collada"), &["collada"]),
+    ("fbx", Some("fbx"), &["fbx", "fbxa"]),
+    ("stl", Some("stereolithography"), &["stl", "stlb"]),
+    ("ply", Some("stanford polygon"), &["ply", "plyb"]),
+    ("3ds", Some("3ds importer"), &["3ds"]),
+    ("x", Some("directx"), &["x"]),
+    ("x3d", Some("x3d"), &["x3d"]),
+    ("3mf", Some("3mf"), &["3mf"]),
+    ("m3d", Some("model 3d"), &["m3d", "m3da"]),
+    ("assbin", Some("assimp binary"), &["assbin"]),
+    ("opengex", Some("open game engine exchange"), &["opengex"]),
+    ("usd", Some("usd"), &[]),
+    ("blend", Some("blender"), &[]),
+    ("step", None, &["stp"]),
+    ("assxml", None, &["assxml"]),
+    ("assjson", None, &["assjson"]),
+    ("pbrt", None, &["pbrt"]),
+];
+
+/// Whether a format is checked for reading or writing, see
+/// [`FormatCapabilities::supports`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Importing
+    Read,
+    /// Exporting
+    Write,
+}
+
+/// What the current build can do with one format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatCapability {
+    /// Canonical format name (`"obj"`, `"gltf2"`, `"collada"`, ...)
+    pub name: String,
+    /// An importer for the format is built in
+    pub read: bool,
+    /// An exporter for the format is built in
+    pub write: bool,
+    /// Lowercase extensions without the dot, from both the importer and the exporters
+    pub extensions: Vec<String>,
+    /// Display name of the importer, when readable
+    pub importer_name: Option<String>,
+    /// Importer flags; empty when not readable
+    pub importer_flags: ImporterFlags,
+    /// Ids to pass to the exporter, preferred first; empty when not writable
+    pub exporter_ids: Vec<String>,
+}
+
+impl FormatCapability {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            read: false,
+            write: false,
+            extensions: Vec::new(),
+            importer_name: None,
+            importer_flags: ImporterFlags::empty(),
+            exporter_ids: Vec::new(),
+        }
+    }
+
+    /// The preferred exporter id, when writable.
+    pub fn exporter_id(&self) -> Option<&str> {
+        self.exporter_ids.first().map(String::as_str)
+    }
+
+    /// Whether Assimp marks the importer as experimental.
+    pub fn is_experimental(&self) -> bool {
+        self.importer_flags.contains(ImporterFlags::EXPERIMENTAL)
+    }
+
+    /// Whether the importer only reads a subset of the format.
+    pub fn has_limited_support(&self) -> bool {
+        self.importer_flags.contains(ImporterFlags::LIMITED_SUPPORT)
+    }
+
+    /// Whether `extension` (without the dot) belongs to this format, ignoring ASCII case.
+    pub fn has_extension(&self, extension: &str) -> bool {
+        self.extensions
+            .iter()
+            .any(|ext| ext.eq_ignore_ascii_case(extension))
+    }
+
+    /// Whether the format can be used in `direction`.
+    pub fn supports(&self, direction: Direction) -> bool {
+        match direction {
+            Direction::Read => self.read,
+            Direction::Write => self.write,
+        }
+    }
+
+    fn add_extension(&mut self, extension: &str) {
+        let extension = extension.trim_start_matches("*.").trim_start_matches('.');
+        if !extension.is_empty() && !self.has_extension(extension) {
+            self.extensions.push(extension.to_ascii_lowercase());
+        }
+    }
+}
+
+/// Read and write support of every format known to the current build, from
+/// [`capabilities`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatCapabilities {
+    formats: Vec<FormatCapability>,
+}
+
+impl FormatCapabilities {
+    /// All formats, sorted by name.
+    pub fn formats(&self) -> &[FormatCapability] {
+        &self.formats
+    }
+
+    /// Iterate all formats, sorted by name.
+    pub fn iter(&self) -> std::slice::Iter<'_, FormatCapability> {
+        self.formats.iter()
+    }
+
+    /// The format with canonical name `name`, ignoring ASCII case.
+    pub fn get(&self, name: &str) -> Option<&FormatCapability> {
+        self.formats
+            .iter()
+            .find(|format| format.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Formats claiming the extension of `path_or_ext`.
+    ///
+    /// Accepts a path (`models/scene.glb`), a bare extension (`glb`) or a dotted one
+    /// (`.glb`). Several formats can share an extension: `.gltf` is claimed by both
+    /// glTF 1 and glTF 2.
+    pub fn for_extension<'a>(
+        &'a self,
+        path_or_ext: &str,
+    ) -> impl Iterator<Item = &'a FormatCapability> + 'a {
+        let extension = extension_of(path_or_ext);
+        self.formats
+            .iter()
+            .filter(move |format| format.has_extension(&extension))
+    }
+
+    /// Whether some format with the extension of `path_or_ext` can be used in
+    /// `direction`; see [`for_extension`](Self::for_extension) for the accepted input.
+    pub fn supports(&self, path_or_ext: &str, direction: Direction) -> bool {
+        self.for_extension(path_or_ext)
+            .any(|format| format.supports(direction))
+    }
+
+    /// Render the formats as a Markdown table with one row per format.
+    pub fn to_markdown_table(&self) -> String {
+        let mut out = String::from(
+            "| Format | Read | Write | Extensions | Exporter ids | Notes |\n\
+             |---|---|---|---|---|---|\n",
+        );
+        for format in &self.formats {
+            let mut notes = Vec::new();
+            if format.is_experimental() {
+                notes.push("experimental");
+            }
+            if format.has_limited_support() {
+                notes.push("limited");
+            }
+            let yes_no = |flag: bool| if flag { "yes" } else { "no" };
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} |\n",
+                format.name,
+                yes_no(format.read),
+                yes_no(format.write),
+                format.extensions.join(", "),
+                format.exporter_ids.join(", "),
+                notes.join(", "),
+            ));
+        }
+        out
+    }
+}
+
+impl<'a> IntoIterator for &'a FormatCapabilities {
+    type Item = &'a FormatCapability;
+    type IntoIter = std::slice::Iter<'a, FormatCapability>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Collect the read and write support of every format in the linked Assimp build.
+///
+/// Importers are matched to exporters through a table of known formats; exporters not
+/// in the table join the format whose importer claims their extension, or get an entry
+/// of their own. Without the `export` feature no format is writable.
+pub fn capabilities() -> FormatCapabilities {
+    merge(&get_all_importer_descs(), &exporters())
+}
+
+/// `(id, extensions)` of every exporter.
+#[cfg(feature = "export")]
+fn exporters() -> Vec<(String, Vec<String>)> {
+    crate::get_export_formats()
+        .into_iter()
+        .map(|desc| {
+            let extensions = desc.extensions().map(str::to_string).collect();
+            (desc.id, extensions)
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "export"))]
+fn exporters() -> Vec<(String, Vec<String>)> {
+    Vec::new()
+}
+
+fn merge(importers: &[ImporterDesc], exporters: &[(String, Vec<String>)]) -> FormatCapabilities {
+    let mut formats: Vec<FormatCapability> = Vec::new();
+
+    for importer in importers {
+        let lower = importer.name.to_ascii_lowercase();
+        let name = KNOWN_FORMATS
+            .iter()
+            .find(|(_, fragment, _)| fragment.is_some_and(|f| lower.contains(f)))
+            .map(|(name, _, _)| name.to_string())
+            .or_else(|| {
+                importer
+                    .file_extensions
+                    .first()
+                    .map(|e| e.to_ascii_lowercase())
+            })
+            .unwrap_or(lower);
+        let index = match formats.iter().position(|f| f.name == name) {
+            Some(index) => index,
+            None => {
+                formats.push(FormatCapability::new(&name));
+                formats.len() - 1
+            }
+        };
+        let format = &mut formats[index];
+        format.read = true;
+        format
+            .importer_name
+            .get_or_insert_with(|| importer.name.clone());
+        format.importer_flags |= importer.flags;
+        for extension in &importer.file_extensions {
+            format.add_extension(extension);
+        }
+    }
+
+    for (id, extensions) in exporters {
+        let known = KNOWN_FORMATS
+            .iter()
+            .find(|(_, _, ids)| ids.iter().any(|known| known.eq_ignore_ascii_case(id)));
+        let index = match known {
+            Some((name, _, _)) => formats.iter().position(|f| f.name == *name),
+            None => formats
+                .iter()
+                .position(|f| extensions.iter().any(|ext| f.has_extension(ext))),
+        };
+        let index = index.unwrap_or_else(|| {
+            let name = known.map_or(id.as_str(), |(name, _, _)| name);
+            formats.push(FormatCapability::new(name));
+            formats.len() - 1
+        });
+        let format = &mut formats[index];
+        format.write = true;
+        format.exporter_ids.push(id.clone());
+        for extension in extensions {
+            format.add_extension(extension);
+        }
+    }
+
+    // Keep preferred exporter ids first, in table order.
+    for format in &mut formats {
+        if let Some((_, _, ids)) = KNOWN_FORMATS.iter().find(|(n, _, _)| *n == format.name) {
+            format.exporter_ids.sort_by_key(|id| {
+                ids.iter()
+                    .position(|known| known == id)
+                    .unwrap_or(ids.len())
+            });
+        }
+    }
+    formats.sort_by(|a, b| a.name.cmp(&b.name));
+    FormatCapabilities { formats }
+}
+
+fn extension_of(path_or_ext: &str) -> String {
+    let trimmed = path_or_ext.trim().trim_start_matches('.');
+    Path::new(trimmed)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn importer(name: &str, extensions: &[&str], flags: ImporterFlags) -> ImporterDesc {
+        ImporterDesc {
+            name: name.to_string(),
+            author: String::new(),
+            maintainer: String::new(),
+            comments: String::new(),
+            flags,
+            min_major: 0,
+            max_major: 0,
+            min_minor: 0,
+            max_minor: 0,
+            file_extensions: extensions.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    fn exporter(id: &str, extension: &str) -> (String, Vec<String>) {
+        (id.to_string(), vec![extension.to_string()])
+    }
+
+    #[test]
+    fn test_merge() {
+        let importers = [
+            importer("glTF2 Importer", &["gltf", "glb"], ImporterFlags::empty()),
+            importer("glTF Importer", &["gltf", "glb"], ImporterFlags::empty()),
+            importer(
+                "Industry Foundation Classes (IFC) Importer",
+                &["ifc", "ifczip", "stp"],
+                ImporterFlags::empty(),
+            ),
+            importer(
+                "Irrlicht Scene Reader",
+                &["irr", "xml"],
+                ImporterFlags::empty(),
+            ),
+            importer("Custom Importer", &["cst"], ImporterFlags::EXPERIMENTAL),
+        ];
+        let exporters = [
+            exporter("glb2", "glb"),
+            exporter("gltf2", "gltf"),
+            exporter("stp", "stp"),
+            exporter("assxml", "assxml"),
+            exporter("cstx", "cst"),
+            exporter("newfmt", "new"),
+        ];
+        let caps = merge(&importers, &exporters);
+
+        let gltf2 = caps.get("gltf2").unwrap();
+        assert!(gltf2.read && gltf2.write);
+        assert_eq!(gltf2.exporter_id(), Some("gltf2"));
+        assert_eq!(gltf2.exporter_ids, ["gltf2", "glb2"]);
+        assert!(!caps.get("gltf").unwrap().write);
+
+        // Curated rows keep STEP apart from the IFC importer.
+        assert!(!caps.get("ifc").unwrap().write);
+        let step = caps.get("step").unwrap();
+        assert!(step.write && !step.read);
+
+        // Unknown exporters join the importer with their extension, or stand alone.
+        let custom = caps.get("cst").unwrap();
+        assert!(custom.read && custom.write && custom.is_experimental());
+        assert_eq!(custom.exporter_id(), Some("cstx"));
+        let new = caps.get("newfmt").unwrap();
+        assert!(new.write && !new.read);
+
+        assert!(caps.supports("scene.GLB", Direction::Write));
+        assert!(caps.supports(".irr", Direction::Read));
+        assert!(!caps.supports("irr", Direction::Write));
+        assert!(!caps.supports("model.xyz", Direction::Read));
+
+        let names: Vec<_> = caps.iter().map(|f| f.name.as_str()).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
+
+    #[test]
+    fn test_extension_of() {
+        assert_eq!(extension_of("models/Scene.GLB"), "glb");
+        assert_eq!(extension_of(".obj"), "obj");
+        assert_eq!(extension_of("fbx"), "fbx");
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let caps = merge(
+            &[importer(
+                "Wavefront Object Importer",
+                &["obj"],
+                ImporterFlags::LIMITED_SUPPORT,
+            )],
+            &[exporter("obj", "obj"), exporter("objnomtl", "obj")],
+        );
+        assert_eq!(
+            caps.to_markdown_table(),
+            "| Format | Read | Write | Extensions | Exporter ids | Notes |\n\
+             |---|---|---|---|---|---|\n\
+             | obj | yes | yes | obj | obj, objnomtl | limited |\n"
+        );
+    }
+}
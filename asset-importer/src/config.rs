@@ -0,0 +1,982 @@
+//! Typed Assimp configuration keys.
+//!
+//! Each `AI_CONFIG_*` key from Assimp's `config.h` is exposed as a [`ConfigKey<T>`] whose
+//! type parameter is the value type Assimp reads, so a float key cannot be set with an
+//! integer by accident. Keys with documented limits also validate the value.
+//!
+//! ```rust,no_run
+//! use asset_importer::{Importer, config, postprocess::PostProcessSteps};
+//!
+//! # fn main() -> asset_importer::Result<()> {
+//! let scene = Importer::new()
+//!     .read_file("model.fbx")
+//!     .with_config(config::GLOBAL_SCALE_FACTOR, 0.01)?
+//!     .with_config(config::FBX_READ_ANIMATIONS, false)?
+//!     .with_post_process(PostProcessSteps::GLOBAL_SCALE)
+//!     .import()?;
+//! # let _ = scene;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The string-based `with_property_*` methods remain available for custom keys.
+
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    error::{Error, Result},
+    importer::PropertyValue,
+    types::Matrix4x4,
+};
+
+/// A typed Assimp configuration key.
+///
+/// `T` is one of `i32`, `f32`, `bool`, `String` or [`Matrix4x4`].
+pub struct ConfigKey<T> {
+    name: &'static str,
+    check: Option<fn(&T) -> bool>,
+    requirement: &'static str,
+    _value: PhantomData<fn(T)>,
+}
+
+impl<T> ConfigKey<T> {
+    /// Define a key without value constraints.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            check: None,
+            requirement: "",
+            _value: PhantomData,
+        }
+    }
+
+    /// Define a key whose values must satisfy `check`; `requirement` describes the
+    /// accepted range in error messages.
+    pub const fn checked(
+        name: &'static str,
+        check: fn(&T) -> bool,
+        requirement: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            check: Some(check),
+            requirement,
+            _value: PhantomData,
+        }
+    }
+
+    /// The Assimp property name.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Validate `value` for this key.
+    pub fn validate(&self, value: &T) -> Result<()> {
+        match self.check {
+            Some(check) if !check(value) => Err(Error::invalid_parameter(format!(
+                "Invalid value for config key {}: expected {}",
+                self.name, self.requirement
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: ConfigValue> ConfigKey<T> {
+    /// Validate `value` and convert it into a `(name, value)` property pair.
+    pub fn property(&self, value: T) -> Result<(String, PropertyValue)> {
+        self.validate(&value)?;
+        Ok((self.name.to_string(), value.into_property_value()))
+    }
+}
+
+impl<T> Clone for ConfigKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ConfigKey<T> {}
+
+impl<T> fmt::Debug for ConfigKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConfigKey").field(&self.name).finish()
+    }
+}
+
+/// Value types accepted by [`ConfigKey`].
+pub trait ConfigValue: sealed::Sealed {
+    /// Convert into the untyped property representation.
+    fn into_property_value(self) -> PropertyValue;
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for i32 {}
+    impl Sealed for f32 {}
+    impl Sealed for bool {}
+    impl Sealed for String {}
+    impl Sealed for crate::types::Matrix4x4 {}
+}
+
+impl ConfigValue for i32 {
+    fn into_property_value(self) -> PropertyValue {
+        PropertyValue::Integer(self)
+    }
+}
+
+impl ConfigValue for f32 {
+    fn into_property_value(self) -> PropertyValue {
+        PropertyValue::Float(self)
+    }
+}
+
+impl ConfigValue for bool {
+    fn into_property_value(self) -> PropertyValue {
+        PropertyValue::Boolean(self)
+    }
+}
+
+impl ConfigValue for String {
+    fn into_property_value(self) -> PropertyValue {
+        PropertyValue::String(self)
+    }
+}
+
+impl ConfigValue for Matrix4x4 {
+    fn into_property_value(self) -> PropertyValue {
+        PropertyValue::Matrix(self)
+    }
+}
+
+mod check {
+    pub(super) const POSITIVE: &str = "a value > 0";
+    pub(super) const NON_NEGATIVE: &str = "a value >= 0";
+    pub(super) const SMOOTHING_ANGLE: &str = "an angle in 0..=175 degrees";
+    pub(super) const UV_CHANNEL: &str = "a UV channel in 0..=7";
+    pub(super) const UNIT_INTERVAL: &str = "a value in 0..=1";
+    pub(super) const IFC_SMOOTHING_ANGLE: &str = "an angle in 5..=120 degrees";
+    pub(super) const IFC_TESSELLATION: &str = "a value in 3..=180";
+
+    pub(super) trait Number: Copy + PartialOrd {
+        const ZERO: Self;
+        fn is_valid(self) -> bool;
+    }
+
+    impl Number for i32 {
+        const ZERO: Self = 0;
+        fn is_valid(self) -> bool {
+            true
+        }
+    }
+
+    impl Number for f32 {
+        const ZERO: Self = 0.0;
+        fn is_valid(self) -> bool {
+            self.is_finite()
+        }
+    }
+
+    pub(super) fn positive<T: Number>(v: &T) -> bool {
+        v.is_valid() && *v > T::ZERO
+    }
+
+    pub(super) fn non_negative<T: Number>(v: &T) -> bool {
+        v.is_valid() && *v >= T::ZERO
+    }
+
+    pub(super) fn smoothing_angle(v: &f32) -> bool {
+        (0.0..=175.0).contains(v)
+    }
+
+    pub(super) fn uv_channel(v: &i32) -> bool {
+        (0..=7).contains(v)
+    }
+
+    pub(super) fn unit_interval(v: &f32) -> bool {
+        (0.0..=1.0).contains(v)
+    }
+
+    pub(super) fn ifc_smoothing_angle(v: &f32) -> bool {
+        (5.0..=120.0).contains(v)
+    }
+
+    pub(super) fn ifc_tessellation(v: &i32) -> bool {
+        (3..=180).contains(v)
+    }
+}
+
+// --- Global ---
+
+/// Log the time spent in each import/post-processing step (`AI_CONFIG_GLOB_MEASURE_TIME`)
+pub const GLOB_MEASURE_TIME: ConfigKey<bool> = ConfigKey::new("GLOB_MEASURE_TIME");
+
+/// Prefer speed over quality in importers and steps that support it (`AI_CONFIG_FAVOUR_SPEED`)
+pub const FAVOUR_SPEED: ConfigKey<bool> = ConfigKey::new("FAVOUR_SPEED");
+
+/// Scale applied by `PostProcessSteps::GLOBAL_SCALE` (must be > 0) (`AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY`)
+pub const GLOBAL_SCALE_FACTOR: ConfigKey<f32> =
+    ConfigKey::checked("GLOBAL_SCALE_FACTOR", check::positive, check::POSITIVE);
+
+/// Application scale multiplied into the global scale (must be > 0) (`AI_CONFIG_APP_SCALE_KEY`)
+pub const APP_SCALE_FACTOR: ConfigKey<f32> =
+    ConfigKey::checked("APP_SCALE_FACTOR", check::positive, check::POSITIVE);
+
+/// Epsilon used when checking for identity matrices (must be > 0) (`AI_CONFIG_CHECK_IDENTITY_MATRIX_EPSILON`)
+pub const CHECK_IDENTITY_MATRIX_EPSILON: ConfigKey<f32> = ConfigKey::checked(
+    "CHECK_IDENTITY_MATRIX_EPSILON",
+    check::positive,
+    check::POSITIVE,
+);
+
+/// Do not generate dummy meshes for skeleton-only files (`AI_CONFIG_IMPORT_NO_SKELETON_MESHES`)
+pub const IMPORT_NO_SKELETON_MESHES: ConfigKey<bool> = ConfigKey::new("IMPORT_NO_SKELETON_MESHES");
+
+/// Keyframe to load for formats with per-vertex animation (must be >= 0) (`AI_CONFIG_IMPORT_GLOBAL_KEYFRAME`)
+pub const IMPORT_GLOBAL_KEYFRAME: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_GLOBAL_KEYFRAME",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// Remove bones without any vertex weights (`AI_CONFIG_IMPORT_REMOVE_EMPTY_BONES`)
+pub const IMPORT_REMOVE_EMPTY_BONES: ConfigKey<bool> =
+    ConfigKey::new("AI_CONFIG_IMPORT_REMOVE_EMPTY_BONES");
+
+// --- Post-processing ---
+
+/// Maximum bones per mesh for `SPLIT_BY_BONE_COUNT` (must be > 0) (`AI_CONFIG_PP_SBBC_MAX_BONES`)
+pub const PP_SBBC_MAX_BONES: ConfigKey<i32> =
+    ConfigKey::checked("PP_SBBC_MAX_BONES", check::positive, check::POSITIVE);
+
+/// Maximum smoothing angle in degrees for `CALC_TANGENT_SPACE` (0..=175) (`AI_CONFIG_PP_CT_MAX_SMOOTHING_ANGLE`)
+pub const PP_CT_MAX_SMOOTHING_ANGLE: ConfigKey<f32> = ConfigKey::checked(
+    "PP_CT_MAX_SMOOTHING_ANGLE",
+    check::smoothing_angle,
+    check::SMOOTHING_ANGLE,
+);
+
+/// UV channel used by `CALC_TANGENT_SPACE` (0..=7) (`AI_CONFIG_PP_CT_TEXTURE_CHANNEL_INDEX`)
+pub const PP_CT_TEXTURE_CHANNEL_INDEX: ConfigKey<i32> = ConfigKey::checked(
+    "PP_CT_TEXTURE_CHANNEL_INDEX",
+    check::uv_channel,
+    check::UV_CHANNEL,
+);
+
+/// Maximum smoothing angle in degrees for `GEN_SMOOTH_NORMALS` (0..=175) (`AI_CONFIG_PP_GSN_MAX_SMOOTHING_ANGLE`)
+pub const PP_GSN_MAX_SMOOTHING_ANGLE: ConfigKey<f32> = ConfigKey::checked(
+    "PP_GSN_MAX_SMOOTHING_ANGLE",
+    check::smoothing_angle,
+    check::SMOOTHING_ANGLE,
+);
+
+/// Materials kept by `REMOVE_REDUNDANT_MATERIALS` (space-separated, quoted names) (`AI_CONFIG_PP_RRM_EXCLUDE_LIST`)
+pub const PP_RRM_EXCLUDE_LIST: ConfigKey<String> = ConfigKey::new("PP_RRM_EXCLUDE_LIST");
+
+/// Keep the scene hierarchy in `PRE_TRANSFORM_VERTICES` (`AI_CONFIG_PP_PTV_KEEP_HIERARCHY`)
+pub const PP_PTV_KEEP_HIERARCHY: ConfigKey<bool> = ConfigKey::new("PP_PTV_KEEP_HIERARCHY");
+
+/// Normalize the scene to the unit cube in `PRE_TRANSFORM_VERTICES` (`AI_CONFIG_PP_PTV_NORMALIZE`)
+pub const PP_PTV_NORMALIZE: ConfigKey<bool> = ConfigKey::new("PP_PTV_NORMALIZE");
+
+/// Apply [`PP_PTV_ROOT_TRANSFORMATION`] in `PRE_TRANSFORM_VERTICES` (`AI_CONFIG_PP_PTV_ADD_ROOT_TRANSFORMATION`)
+pub const PP_PTV_ADD_ROOT_TRANSFORMATION: ConfigKey<bool> =
+    ConfigKey::new("PP_PTV_ADD_ROOT_TRANSFORMATION");
+
+/// Root transformation used by `PRE_TRANSFORM_VERTICES` (`AI_CONFIG_PP_PTV_ROOT_TRANSFORMATION`)
+pub const PP_PTV_ROOT_TRANSFORMATION: ConfigKey<Matrix4x4> =
+    ConfigKey::new("PP_PTV_ROOT_TRANSFORMATION");
+
+/// Remove degenerate primitives in `FIND_DEGENERATES` (`AI_CONFIG_PP_FD_REMOVE`)
+pub const PP_FD_REMOVE: ConfigKey<bool> = ConfigKey::new("PP_FD_REMOVE");
+
+/// Treat zero-area faces as degenerate in `FIND_DEGENERATES` (`AI_CONFIG_PP_FD_CHECKAREA`)
+pub const PP_FD_CHECKAREA: ConfigKey<bool> = ConfigKey::new("PP_FD_CHECKAREA");
+
+/// Nodes kept by `OPTIMIZE_GRAPH` (space-separated, quoted names) (`AI_CONFIG_PP_OG_EXCLUDE_LIST`)
+pub const PP_OG_EXCLUDE_LIST: ConfigKey<String> = ConfigKey::new("PP_OG_EXCLUDE_LIST");
+
+/// Maximum triangles per mesh for `SPLIT_LARGE_MESHES` (must be > 0) (`AI_CONFIG_PP_SLM_TRIANGLE_LIMIT`)
+pub const PP_SLM_TRIANGLE_LIMIT: ConfigKey<i32> =
+    ConfigKey::checked("PP_SLM_TRIANGLE_LIMIT", check::positive, check::POSITIVE);
+
+/// Maximum vertices per mesh for `SPLIT_LARGE_MESHES` (must be > 0) (`AI_CONFIG_PP_SLM_VERTEX_LIMIT`)
+pub const PP_SLM_VERTEX_LIMIT: ConfigKey<i32> =
+    ConfigKey::checked("PP_SLM_VERTEX_LIMIT", check::positive, check::POSITIVE);
+
+/// Maximum bone weights per vertex for `LIMIT_BONE_WEIGHTS` (must be > 0) (`AI_CONFIG_PP_LBW_MAX_WEIGHTS`)
+pub const PP_LBW_MAX_WEIGHTS: ConfigKey<i32> =
+    ConfigKey::checked("PP_LBW_MAX_WEIGHTS", check::positive, check::POSITIVE);
+
+/// Threshold for `DEBONE` (0..=1) (`AI_CONFIG_PP_DB_THRESHOLD`)
+pub const PP_DB_THRESHOLD: ConfigKey<f32> = ConfigKey::checked(
+    "PP_DB_THRESHOLD",
+    check::unit_interval,
+    check::UNIT_INTERVAL,
+);
+
+/// Only remove bones in `DEBONE` if all of them qualify (`AI_CONFIG_PP_DB_ALL_OR_NONE`)
+pub const PP_DB_ALL_OR_NONE: ConfigKey<bool> = ConfigKey::new("PP_DB_ALL_OR_NONE");
+
+/// Post-transform cache size for `IMPROVE_CACHE_LOCALITY` (must be > 0) (`AI_CONFIG_PP_ICL_PTCACHE_SIZE`)
+pub const PP_ICL_PTCACHE_SIZE: ConfigKey<i32> =
+    ConfigKey::checked("PP_ICL_PTCACHE_SIZE", check::positive, check::POSITIVE);
+
+/// `aiComponent` flags removed by `REMOVE_COMPONENT` (`AI_CONFIG_PP_RVC_FLAGS`)
+pub const PP_RVC_FLAGS: ConfigKey<i32> = ConfigKey::new("PP_RVC_FLAGS");
+
+/// `aiPrimitiveType` flags removed by `SORT_BY_PTYPE` (`AI_CONFIG_PP_SBP_REMOVE`)
+pub const PP_SBP_REMOVE: ConfigKey<i32> = ConfigKey::new("PP_SBP_REMOVE");
+
+/// Accuracy for animation checks in `FIND_INVALID_DATA` (must be >= 0) (`AI_CONFIG_PP_FID_ANIM_ACCURACY`)
+pub const PP_FID_ANIM_ACCURACY: ConfigKey<f32> = ConfigKey::checked(
+    "PP_FID_ANIM_ACCURACY",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// Skip texture coordinates in `FIND_INVALID_DATA` (`AI_CONFIG_PP_FID_IGNORE_TEXTURECOORDS`)
+pub const PP_FID_IGNORE_TEXTURECOORDS: ConfigKey<bool> =
+    ConfigKey::new("PP_FID_IGNORE_TEXTURECOORDS");
+
+/// UV transform components evaluated by `TRANSFORM_UV_COORDS` (`AI_CONFIG_PP_TUV_EVALUATE`)
+pub const PP_TUV_EVALUATE: ConfigKey<i32> = ConfigKey::new("PP_TUV_EVALUATE");
+
+// --- FBX ---
+
+/// Read all geometry layers (`AI_CONFIG_IMPORT_FBX_READ_ALL_GEOMETRY_LAYERS`)
+pub const FBX_READ_ALL_GEOMETRY_LAYERS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_FBX_READ_ALL_GEOMETRY_LAYERS");
+
+/// Read all materials, including unused ones (`AI_CONFIG_IMPORT_FBX_READ_ALL_MATERIALS`)
+pub const FBX_READ_ALL_MATERIALS: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_READ_ALL_MATERIALS");
+
+/// Read materials (`AI_CONFIG_IMPORT_FBX_READ_MATERIALS`)
+pub const FBX_READ_MATERIALS: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_READ_MATERIALS");
+
+/// Read embedded textures (`AI_CONFIG_IMPORT_FBX_READ_TEXTURES`)
+pub const FBX_READ_TEXTURES: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_READ_TEXTURES");
+
+/// Read cameras (`AI_CONFIG_IMPORT_FBX_READ_CAMERAS`)
+pub const FBX_READ_CAMERAS: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_READ_CAMERAS");
+
+/// Read lights (`AI_CONFIG_IMPORT_FBX_READ_LIGHTS`)
+pub const FBX_READ_LIGHTS: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_READ_LIGHTS");
+
+/// Read animations (`AI_CONFIG_IMPORT_FBX_READ_ANIMATIONS`)
+pub const FBX_READ_ANIMATIONS: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_READ_ANIMATIONS");
+
+/// Read bone weights (`AI_CONFIG_IMPORT_FBX_READ_WEIGHTS`)
+pub const FBX_READ_WEIGHTS: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_READ_WEIGHTS");
+
+/// Reject files that deviate from the FBX spec (`AI_CONFIG_IMPORT_FBX_STRICT_MODE`)
+pub const FBX_STRICT_MODE: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_STRICT_MODE");
+
+/// Keep pivot helper nodes (`$AssimpFbx$`) (`AI_CONFIG_IMPORT_FBX_PRESERVE_PIVOTS`)
+pub const FBX_PRESERVE_PIVOTS: ConfigKey<bool> = ConfigKey::new("IMPORT_FBX_PRESERVE_PIVOTS");
+
+/// Drop animation curves that never change (`AI_CONFIG_IMPORT_FBX_OPTIMIZE_EMPTY_ANIMATION_CURVES`)
+pub const FBX_OPTIMIZE_EMPTY_ANIMATION_CURVES: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_FBX_OPTIMIZE_EMPTY_ANIMATION_CURVES");
+
+/// Use legacy `*N` naming for embedded textures (`AI_CONFIG_IMPORT_FBX_EMBEDDED_TEXTURES_LEGACY_NAMING`)
+pub const FBX_EMBEDDED_TEXTURES_LEGACY_NAMING: ConfigKey<bool> =
+    ConfigKey::new("AI_CONFIG_IMPORT_FBX_EMBEDDED_TEXTURES_LEGACY_NAMING");
+
+/// Ignore the file's up axis (`AI_CONFIG_IMPORT_FBX_IGNORE_UP_DIRECTION`)
+pub const FBX_IGNORE_UP_DIRECTION: ConfigKey<bool> =
+    ConfigKey::new("AI_CONFIG_IMPORT_FBX_IGNORE_UP_DIRECTION");
+
+/// Convert units to meters (`AI_CONFIG_FBX_CONVERT_TO_M`)
+pub const FBX_CONVERT_TO_M: ConfigKey<bool> = ConfigKey::new("AI_CONFIG_FBX_CONVERT_TO_M");
+
+/// Build `aiSkeleton` containers for bones (`AI_CONFIG_FBX_USE_SKELETON_BONE_CONTAINER`)
+pub const FBX_USE_SKELETON_BONE_CONTAINER: ConfigKey<bool> =
+    ConfigKey::new("AI_CONFIG_FBX_USE_SKELETON_BONE_CONTAINER");
+
+// --- Collada ---
+
+/// Ignore the `<up_axis>` element (`AI_CONFIG_IMPORT_COLLADA_IGNORE_UP_DIRECTION`)
+pub const COLLADA_IGNORE_UP_DIRECTION: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_COLLADA_IGNORE_UP_DIRECTION");
+
+/// Ignore the `<unit>` element (`AI_CONFIG_IMPORT_COLLADA_IGNORE_UNIT_SIZE`)
+pub const COLLADA_IGNORE_UNIT_SIZE: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_COLLADA_IGNORE_UNIT_SIZE");
+
+/// Name nodes and meshes from `name` instead of `id` (`AI_CONFIG_IMPORT_COLLADA_USE_COLLADA_NAMES`)
+pub const COLLADA_USE_COLLADA_NAMES: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_COLLADA_USE_COLLADA_NAMES");
+
+// --- IFC ---
+
+/// Skip `IfcSpace` representations (`AI_CONFIG_IMPORT_IFC_SKIP_SPACE_REPRESENTATIONS`)
+pub const IFC_SKIP_SPACE_REPRESENTATIONS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_IFC_SKIP_SPACE_REPRESENTATIONS");
+
+/// Use the IFC loader's own triangulation for walls (`AI_CONFIG_IMPORT_IFC_CUSTOM_TRIANGULATION`)
+pub const IFC_CUSTOM_TRIANGULATION: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_IFC_CUSTOM_TRIANGULATION");
+
+/// Smoothing angle in degrees for tessellated curves (5..=120) (`AI_CONFIG_IMPORT_IFC_SMOOTHING_ANGLE`)
+pub const IFC_SMOOTHING_ANGLE: ConfigKey<f32> = ConfigKey::checked(
+    "IMPORT_IFC_SMOOTHING_ANGLE",
+    check::ifc_smoothing_angle,
+    check::IFC_SMOOTHING_ANGLE,
+);
+
+/// Tessellation of cylindrical shapes (3..=180) (`AI_CONFIG_IMPORT_IFC_CYLINDRICAL_TESSELLATION`)
+pub const IFC_CYLINDRICAL_TESSELLATION: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_IFC_CYLINDRICAL_TESSELLATION",
+    check::ifc_tessellation,
+    check::IFC_TESSELLATION,
+);
+
+// --- Other formats ---
+
+/// MD3 keyframe to load (overrides [`IMPORT_GLOBAL_KEYFRAME`]) (`AI_CONFIG_IMPORT_MD3_KEYFRAME`)
+pub const MD3_KEYFRAME: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_MD3_KEYFRAME",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// MD2 keyframe to load (`AI_CONFIG_IMPORT_MD2_KEYFRAME`)
+pub const MD2_KEYFRAME: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_MD2_KEYFRAME",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// MDL keyframe to load (`AI_CONFIG_IMPORT_MDL_KEYFRAME`)
+pub const MDL_KEYFRAME: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_MDL_KEYFRAME",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// MDC keyframe to load (`AI_CONFIG_IMPORT_MDC_KEYFRAME`)
+pub const MDC_KEYFRAME: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_MDC_KEYFRAME",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// SMD keyframe to load (`AI_CONFIG_IMPORT_SMD_KEYFRAME`)
+pub const SMD_KEYFRAME: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_SMD_KEYFRAME",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// Unreal keyframe to load (`AI_CONFIG_IMPORT_UNREAL_KEYFRAME`)
+pub const UNREAL_KEYFRAME: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_UNREAL_KEYFRAME",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// Palette file for Quake 1 MDL models (`AI_CONFIG_IMPORT_MDL_COLORMAP`)
+pub const MDL_COLORMAP: ConfigKey<String> = ConfigKey::new("IMPORT_MDL_COLORMAP");
+
+/// Half-Life 1 MDL: read animations (`AI_CONFIG_IMPORT_MDL_HL1_READ_ANIMATIONS`)
+pub const MDL_HL1_READ_ANIMATIONS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_MDL_HL1_READ_ANIMATIONS");
+
+/// Half-Life 1 MDL: read animation events (`AI_CONFIG_IMPORT_MDL_HL1_READ_ANIMATION_EVENTS`)
+pub const MDL_HL1_READ_ANIMATION_EVENTS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_MDL_HL1_READ_ANIMATION_EVENTS");
+
+/// Half-Life 1 MDL: read blend controllers (`AI_CONFIG_IMPORT_MDL_HL1_READ_BLEND_CONTROLLERS`)
+pub const MDL_HL1_READ_BLEND_CONTROLLERS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_MDL_HL1_READ_BLEND_CONTROLLERS");
+
+/// Half-Life 1 MDL: read sequence transition graph (`AI_CONFIG_IMPORT_MDL_HL1_READ_SEQUENCE_TRANSITIONS`)
+pub const MDL_HL1_READ_SEQUENCE_TRANSITIONS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_MDL_HL1_READ_SEQUENCE_TRANSITIONS");
+
+/// Half-Life 1 MDL: read attachments (`AI_CONFIG_IMPORT_MDL_HL1_READ_ATTACHMENTS`)
+pub const MDL_HL1_READ_ATTACHMENTS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_MDL_HL1_READ_ATTACHMENTS");
+
+/// Half-Life 1 MDL: read bone controllers (`AI_CONFIG_IMPORT_MDL_HL1_READ_BONE_CONTROLLERS`)
+pub const MDL_HL1_READ_BONE_CONTROLLERS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_MDL_HL1_READ_BONE_CONTROLLERS");
+
+/// Half-Life 1 MDL: read hitboxes (`AI_CONFIG_IMPORT_MDL_HL1_READ_HITBOXES`)
+pub const MDL_HL1_READ_HITBOXES: ConfigKey<bool> = ConfigKey::new("IMPORT_MDL_HL1_READ_HITBOXES");
+
+/// Half-Life 1 MDL: read miscellaneous global info (`AI_CONFIG_IMPORT_MDL_HL1_READ_MISC_GLOBAL_INFO`)
+pub const MDL_HL1_READ_MISC_GLOBAL_INFO: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_MDL_HL1_READ_MISC_GLOBAL_INFO");
+
+/// Half-Life 1 MDL: transform the coordinate system (`AI_CONFIG_IMPORT_MDL_HL1_TRANSFORM_COORD_SYSTEM`)
+pub const MDL_HL1_TRANSFORM_COORD_SYSTEM: ConfigKey<bool> =
+    ConfigKey::new("TRANSFORM COORDSYSTEM FOR HS! MODELS");
+
+/// SMD: load the animation list (`AI_CONFIG_IMPORT_SMD_LOAD_ANIMATION_LIST`)
+pub const SMD_LOAD_ANIMATION_LIST: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_SMD_LOAD_ANIMATION_LIST");
+
+/// AC3D: split meshes by backface culling flag (`AI_CONFIG_IMPORT_AC_SEPARATE_BFCULL`)
+pub const AC_SEPARATE_BFCULL: ConfigKey<bool> = ConfigKey::new("IMPORT_AC_SEPARATE_BFCULL");
+
+/// AC3D: evaluate subdivision surfaces (`AI_CONFIG_IMPORT_AC_EVAL_SUBDIVISION`)
+pub const AC_EVAL_SUBDIVISION: ConfigKey<bool> = ConfigKey::new("IMPORT_AC_EVAL_SUBDIVISION");
+
+/// Unreal: honour per-triangle flags (`AI_CONFIG_IMPORT_UNREAL_HANDLE_FLAGS`)
+pub const UNREAL_HANDLE_FLAGS: ConfigKey<bool> = ConfigKey::new("UNREAL_HANDLE_FLAGS");
+
+/// Terragen: generate UV coordinates (`AI_CONFIG_IMPORT_TER_MAKE_UVS`)
+pub const TER_MAKE_UVS: ConfigKey<bool> = ConfigKey::new("IMPORT_TER_MAKE_UVS");
+
+/// ASE: recompute normals from smoothing groups (`AI_CONFIG_IMPORT_ASE_RECONSTRUCT_NORMALS`)
+pub const ASE_RECONSTRUCT_NORMALS: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_ASE_RECONSTRUCT_NORMALS");
+
+/// MD3: load multi-part player models (`AI_CONFIG_IMPORT_MD3_HANDLE_MULTIPART`)
+pub const MD3_HANDLE_MULTIPART: ConfigKey<bool> = ConfigKey::new("IMPORT_MD3_HANDLE_MULTIPART");
+
+/// MD3: skin to load (`AI_CONFIG_IMPORT_MD3_SKIN_NAME`)
+pub const MD3_SKIN_NAME: ConfigKey<String> = ConfigKey::new("IMPORT_MD3_SKIN_NAME");
+
+/// MD3: load Quake 3 shader files (`AI_CONFIG_IMPORT_MD3_LOAD_SHADERS`)
+pub const MD3_LOAD_SHADERS: ConfigKey<bool> = ConfigKey::new("IMPORT_MD3_LOAD_SHADERS");
+
+/// MD3: shader file or directory (`AI_CONFIG_IMPORT_MD3_SHADER_SRC`)
+pub const MD3_SHADER_SRC: ConfigKey<String> = ConfigKey::new("IMPORT_MD3_SHADER_SRC");
+
+/// LightWave: load only this layer index (`AI_CONFIG_IMPORT_LWO_ONE_LAYER_ONLY`)
+pub const LWO_ONE_LAYER_ONLY: ConfigKey<i32> = ConfigKey::checked(
+    "IMPORT_LWO_ONE_LAYER_ONLY",
+    check::non_negative,
+    check::NON_NEGATIVE,
+);
+
+/// MD5: do not auto-load the matching `.md5anim` (`AI_CONFIG_IMPORT_MD5_NO_ANIM_AUTOLOAD`)
+pub const MD5_NO_ANIM_AUTOLOAD: ConfigKey<bool> = ConfigKey::new("IMPORT_MD5_NO_ANIM_AUTOLOAD");
+
+/// LightWave scene: first animation frame (`AI_CONFIG_IMPORT_LWS_ANIM_START`)
+pub const LWS_ANIM_START: ConfigKey<i32> = ConfigKey::new("IMPORT_LWS_ANIM_START");
+
+/// LightWave scene: last animation frame (`AI_CONFIG_IMPORT_LWS_ANIM_END`)
+pub const LWS_ANIM_END: ConfigKey<i32> = ConfigKey::new("IMPORT_LWS_ANIM_END");
+
+/// Irrlicht: animation sample rate (must be > 0) (`AI_CONFIG_IMPORT_IRR_ANIM_FPS`)
+pub const IRR_ANIM_FPS: ConfigKey<i32> =
+    ConfigKey::checked("IMPORT_IRR_ANIM_FPS", check::positive, check::POSITIVE);
+
+/// Ogre: fallback material file (`AI_CONFIG_IMPORT_OGRE_MATERIAL_FILE`)
+pub const OGRE_MATERIAL_FILE: ConfigKey<String> = ConfigKey::new("IMPORT_OGRE_MATERIAL_FILE");
+
+/// Ogre: derive texture type from the file name (`AI_CONFIG_IMPORT_OGRE_TEXTURETYPE_FROM_FILENAME`)
+pub const OGRE_TEXTURETYPE_FROM_FILENAME: ConfigKey<bool> =
+    ConfigKey::new("IMPORT_OGRE_TEXTURETYPE_FROM_FILENAME");
+
+// --- Export ---
+
+/// X: write 64-bit floats (`AI_CONFIG_EXPORT_XFILE_64BIT`)
+pub const EXPORT_XFILE_64BIT: ConfigKey<bool> = ConfigKey::new("EXPORT_XFILE_64BIT");
+
+/// Export point clouds (meshes without faces) (`AI_CONFIG_EXPORT_POINT_CLOUDS`)
+pub const EXPORT_POINT_CLOUDS: ConfigKey<bool> = ConfigKey::new("EXPORT_POINT_CLOUDS");
+
+/// glTF: write `KHR_materials_pbrSpecularGlossiness` (`AI_CONFIG_USE_GLTF_PBR_SPECULAR_GLOSSINESS`)
+pub const EXPORT_GLTF_PBR_SPECULAR_GLOSSINESS: ConfigKey<bool> =
+    ConfigKey::new("USE_GLTF_PBR_SPECULAR_GLOSSINESS");
+
+/// glTF: write more than 4 bone weights per vertex (`AI_CONFIG_EXPORT_GLTF_UNLIMITED_SKINNING_BONES_PER_VERTEX`)
+pub const EXPORT_GLTF_UNLIMITED_SKINNING_BONES_PER_VERTEX: ConfigKey<bool> =
+    ConfigKey::new("USE_UNLIMITED_BONES_PER VERTEX");
+
+/// FBX: treat `TransparencyFactor` as opacity (`AI_CONFIG_EXPORT_FBX_TRANSPARENCY_FACTOR_REFER_TO_OPACITY`)
+pub const EXPORT_FBX_TRANSPARENCY_FACTOR_REFER_TO_OPACITY: ConfigKey<bool> =
+    ConfigKey::new("EXPORT_FBX_TRANSPARENCY_FACTOR_REFER_TO_OPACITY");
+
+/// Base name for blob exports (`AI_CONFIG_EXPORT_BLOB_NAME`)
+pub const EXPORT_BLOB_NAME: ConfigKey<String> = ConfigKey::new("EXPORT_BLOB_NAME");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sys;
+
+    fn c_key(bytes: &[u8]) -> &str {
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        std::str::from_utf8(&bytes[..end]).expect("Assimp config key should be UTF-8")
+    }
+
+    #[test]
+    fn config_keys_match_assimp_macros() {
+        assert_eq!(
+            GLOB_MEASURE_TIME.name(),
+            c_key(sys::AI_CONFIG_GLOB_MEASURE_TIME)
+        );
+        assert_eq!(FAVOUR_SPEED.name(), c_key(sys::AI_CONFIG_FAVOUR_SPEED));
+        assert_eq!(
+            GLOBAL_SCALE_FACTOR.name(),
+            c_key(sys::AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY)
+        );
+        assert_eq!(APP_SCALE_FACTOR.name(), c_key(sys::AI_CONFIG_APP_SCALE_KEY));
+        assert_eq!(
+            CHECK_IDENTITY_MATRIX_EPSILON.name(),
+            c_key(sys::AI_CONFIG_CHECK_IDENTITY_MATRIX_EPSILON)
+        );
+        assert_eq!(
+            IMPORT_NO_SKELETON_MESHES.name(),
+            c_key(sys::AI_CONFIG_IMPORT_NO_SKELETON_MESHES)
+        );
+        assert_eq!(
+            IMPORT_GLOBAL_KEYFRAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_GLOBAL_KEYFRAME)
+        );
+        assert_eq!(
+            IMPORT_REMOVE_EMPTY_BONES.name(),
+            c_key(sys::AI_CONFIG_IMPORT_REMOVE_EMPTY_BONES)
+        );
+        assert_eq!(
+            PP_SBBC_MAX_BONES.name(),
+            c_key(sys::AI_CONFIG_PP_SBBC_MAX_BONES)
+        );
+        assert_eq!(
+            PP_CT_MAX_SMOOTHING_ANGLE.name(),
+            c_key(sys::AI_CONFIG_PP_CT_MAX_SMOOTHING_ANGLE)
+        );
+        assert_eq!(
+            PP_CT_TEXTURE_CHANNEL_INDEX.name(),
+            c_key(sys::AI_CONFIG_PP_CT_TEXTURE_CHANNEL_INDEX)
+        );
+        assert_eq!(
+            PP_GSN_MAX_SMOOTHING_ANGLE.name(),
+            c_key(sys::AI_CONFIG_PP_GSN_MAX_SMOOTHING_ANGLE)
+        );
+        assert_eq!(
+            PP_RRM_EXCLUDE_LIST.name(),
+            c_key(sys::AI_CONFIG_PP_RRM_EXCLUDE_LIST)
+        );
+        assert_eq!(
+            PP_PTV_KEEP_HIERARCHY.name(),
+            c_key(sys::AI_CONFIG_PP_PTV_KEEP_HIERARCHY)
+        );
+        assert_eq!(
+            PP_PTV_NORMALIZE.name(),
+            c_key(sys::AI_CONFIG_PP_PTV_NORMALIZE)
+        );
+        assert_eq!(
+            PP_PTV_ADD_ROOT_TRANSFORMATION.name(),
+            c_key(sys::AI_CONFIG_PP_PTV_ADD_ROOT_TRANSFORMATION)
+        );
+        assert_eq!(
+            PP_PTV_ROOT_TRANSFORMATION.name(),
+            c_key(sys::AI_CONFIG_PP_PTV_ROOT_TRANSFORMATION)
+        );
+        assert_eq!(PP_FD_REMOVE.name(), c_key(sys::AI_CONFIG_PP_FD_REMOVE));
+        assert_eq!(
+            PP_FD_CHECKAREA.name(),
+            c_key(sys::AI_CONFIG_PP_FD_CHECKAREA)
+        );
+        assert_eq!(
+            PP_OG_EXCLUDE_LIST.name(),
+            c_key(sys::AI_CONFIG_PP_OG_EXCLUDE_LIST)
+        );
+        assert_eq!(
+            PP_SLM_TRIANGLE_LIMIT.name(),
+            c_key(sys::AI_CONFIG_PP_SLM_TRIANGLE_LIMIT)
+        );
+        assert_eq!(
+            PP_SLM_VERTEX_LIMIT.name(),
+            c_key(sys::AI_CONFIG_PP_SLM_VERTEX_LIMIT)
+        );
+        assert_eq!(
+            PP_LBW_MAX_WEIGHTS.name(),
+            c_key(sys::AI_CONFIG_PP_LBW_MAX_WEIGHTS)
+        );
+        assert_eq!(
+            PP_DB_THRESHOLD.name(),
+            c_key(sys::AI_CONFIG_PP_DB_THRESHOLD)
+        );
+        assert_eq!(
+            PP_DB_ALL_OR_NONE.name(),
+            c_key(sys::AI_CONFIG_PP_DB_ALL_OR_NONE)
+        );
+        assert_eq!(
+            PP_ICL_PTCACHE_SIZE.name(),
+            c_key(sys::AI_CONFIG_PP_ICL_PTCACHE_SIZE)
+        );
+        assert_eq!(PP_RVC_FLAGS.name(), c_key(sys::AI_CONFIG_PP_RVC_FLAGS));
+        assert_eq!(PP_SBP_REMOVE.name(), c_key(sys::AI_CONFIG_PP_SBP_REMOVE));
+        assert_eq!(
+            PP_FID_ANIM_ACCURACY.name(),
+            c_key(sys::AI_CONFIG_PP_FID_ANIM_ACCURACY)
+        );
+        assert_eq!(
+            PP_FID_IGNORE_TEXTURECOORDS.name(),
+            c_key(sys::AI_CONFIG_PP_FID_IGNORE_TEXTURECOORDS)
+        );
+        assert_eq!(
+            PP_TUV_EVALUATE.name(),
+            c_key(sys::AI_CONFIG_PP_TUV_EVALUATE)
+        );
+        assert_eq!(
+            FBX_READ_ALL_GEOMETRY_LAYERS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_ALL_GEOMETRY_LAYERS)
+        );
+        assert_eq!(
+            FBX_READ_ALL_MATERIALS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_ALL_MATERIALS)
+        );
+        assert_eq!(
+            FBX_READ_MATERIALS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_MATERIALS)
+        );
+        assert_eq!(
+            FBX_READ_TEXTURES.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_TEXTURES)
+        );
+        assert_eq!(
+            FBX_READ_CAMERAS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_CAMERAS)
+        );
+        assert_eq!(
+            FBX_READ_LIGHTS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_LIGHTS)
+        );
+        assert_eq!(
+            FBX_READ_ANIMATIONS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_ANIMATIONS)
+        );
+        assert_eq!(
+            FBX_READ_WEIGHTS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_READ_WEIGHTS)
+        );
+        assert_eq!(
+            FBX_STRICT_MODE.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_STRICT_MODE)
+        );
+        assert_eq!(
+            FBX_PRESERVE_PIVOTS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_PRESERVE_PIVOTS)
+        );
+        assert_eq!(
+            FBX_OPTIMIZE_EMPTY_ANIMATION_CURVES.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_OPTIMIZE_EMPTY_ANIMATION_CURVES)
+        );
+        assert_eq!(
+            FBX_EMBEDDED_TEXTURES_LEGACY_NAMING.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_EMBEDDED_TEXTURES_LEGACY_NAMING)
+        );
+        assert_eq!(
+            FBX_IGNORE_UP_DIRECTION.name(),
+            c_key(sys::AI_CONFIG_IMPORT_FBX_IGNORE_UP_DIRECTION)
+        );
+        assert_eq!(
+            FBX_CONVERT_TO_M.name(),
+            c_key(sys::AI_CONFIG_FBX_CONVERT_TO_M)
+        );
+        assert_eq!(
+            FBX_USE_SKELETON_BONE_CONTAINER.name(),
+            c_key(sys::AI_CONFIG_FBX_USE_SKELETON_BONE_CONTAINER)
+        );
+        assert_eq!(
+            COLLADA_IGNORE_UP_DIRECTION.name(),
+            c_key(sys::AI_CONFIG_IMPORT_COLLADA_IGNORE_UP_DIRECTION)
+        );
+        assert_eq!(
+            COLLADA_IGNORE_UNIT_SIZE.name(),
+            c_key(sys::AI_CONFIG_IMPORT_COLLADA_IGNORE_UNIT_SIZE)
+        );
+        assert_eq!(
+            COLLADA_USE_COLLADA_NAMES.name(),
+            c_key(sys::AI_CONFIG_IMPORT_COLLADA_USE_COLLADA_NAMES)
+        );
+        assert_eq!(
+            IFC_SKIP_SPACE_REPRESENTATIONS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_IFC_SKIP_SPACE_REPRESENTATIONS)
+        );
+        assert_eq!(
+            IFC_CUSTOM_TRIANGULATION.name(),
+            c_key(sys::AI_CONFIG_IMPORT_IFC_CUSTOM_TRIANGULATION)
+        );
+        assert_eq!(
+            IFC_SMOOTHING_ANGLE.name(),
+            c_key(sys::AI_CONFIG_IMPORT_IFC_SMOOTHING_ANGLE)
+        );
+        assert_eq!(
+            IFC_CYLINDRICAL_TESSELLATION.name(),
+            c_key(sys::AI_CONFIG_IMPORT_IFC_CYLINDRICAL_TESSELLATION)
+        );
+        assert_eq!(
+            MD3_KEYFRAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MD3_KEYFRAME)
+        );
+        assert_eq!(
+            MD2_KEYFRAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MD2_KEYFRAME)
+        );
+        assert_eq!(
+            MDL_KEYFRAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_KEYFRAME)
+        );
+        assert_eq!(
+            MDC_KEYFRAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDC_KEYFRAME)
+        );
+        assert_eq!(
+            SMD_KEYFRAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_SMD_KEYFRAME)
+        );
+        assert_eq!(
+            UNREAL_KEYFRAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_UNREAL_KEYFRAME)
+        );
+        assert_eq!(
+            MDL_COLORMAP.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_COLORMAP)
+        );
+        assert_eq!(
+            MDL_HL1_READ_ANIMATIONS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_ANIMATIONS)
+        );
+        assert_eq!(
+            MDL_HL1_READ_ANIMATION_EVENTS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_ANIMATION_EVENTS)
+        );
+        assert_eq!(
+            MDL_HL1_READ_BLEND_CONTROLLERS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_BLEND_CONTROLLERS)
+        );
+        assert_eq!(
+            MDL_HL1_READ_SEQUENCE_TRANSITIONS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_SEQUENCE_TRANSITIONS)
+        );
+        assert_eq!(
+            MDL_HL1_READ_ATTACHMENTS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_ATTACHMENTS)
+        );
+        assert_eq!(
+            MDL_HL1_READ_BONE_CONTROLLERS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_BONE_CONTROLLERS)
+        );
+        assert_eq!(
+            MDL_HL1_READ_HITBOXES.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_HITBOXES)
+        );
+        assert_eq!(
+            MDL_HL1_READ_MISC_GLOBAL_INFO.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_READ_MISC_GLOBAL_INFO)
+        );
+        assert_eq!(
+            MDL_HL1_TRANSFORM_COORD_SYSTEM.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MDL_HL1_TRANSFORM_COORD_SYSTEM)
+        );
+        assert_eq!(
+            SMD_LOAD_ANIMATION_LIST.name(),
+            c_key(sys::AI_CONFIG_IMPORT_SMD_LOAD_ANIMATION_LIST)
+        );
+        assert_eq!(
+            AC_SEPARATE_BFCULL.name(),
+            c_key(sys::AI_CONFIG_IMPORT_AC_SEPARATE_BFCULL)
+        );
+        assert_eq!(
+            AC_EVAL_SUBDIVISION.name(),
+            c_key(sys::AI_CONFIG_IMPORT_AC_EVAL_SUBDIVISION)
+        );
+        assert_eq!(
+            UNREAL_HANDLE_FLAGS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_UNREAL_HANDLE_FLAGS)
+        );
+        assert_eq!(
+            TER_MAKE_UVS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_TER_MAKE_UVS)
+        );
+        assert_eq!(
+            ASE_RECONSTRUCT_NORMALS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_ASE_RECONSTRUCT_NORMALS)
+        );
+        assert_eq!(
+            MD3_HANDLE_MULTIPART.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MD3_HANDLE_MULTIPART)
+        );
+        assert_eq!(
+            MD3_SKIN_NAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MD3_SKIN_NAME)
+        );
+        assert_eq!(
+            MD3_LOAD_SHADERS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MD3_LOAD_SHADERS)
+        );
+        assert_eq!(
+            MD3_SHADER_SRC.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MD3_SHADER_SRC)
+        );
+        assert_eq!(
+            LWO_ONE_LAYER_ONLY.name(),
+            c_key(sys::AI_CONFIG_IMPORT_LWO_ONE_LAYER_ONLY)
+        );
+        assert_eq!(
+            MD5_NO_ANIM_AUTOLOAD.name(),
+            c_key(sys::AI_CONFIG_IMPORT_MD5_NO_ANIM_AUTOLOAD)
+        );
+        assert_eq!(
+            LWS_ANIM_START.name(),
+            c_key(sys::AI_CONFIG_IMPORT_LWS_ANIM_START)
+        );
+        assert_eq!(
+            LWS_ANIM_END.name(),
+            c_key(sys::AI_CONFIG_IMPORT_LWS_ANIM_END)
+        );
+        assert_eq!(
+            IRR_ANIM_FPS.name(),
+            c_key(sys::AI_CONFIG_IMPORT_IRR_ANIM_FPS)
+        );
+        assert_eq!(
+            OGRE_MATERIAL_FILE.name(),
+            c_key(sys::AI_CONFIG_IMPORT_OGRE_MATERIAL_FILE)
+        );
+        assert_eq!(
+            OGRE_TEXTURETYPE_FROM_FILENAME.name(),
+            c_key(sys::AI_CONFIG_IMPORT_OGRE_TEXTURETYPE_FROM_FILENAME)
+        );
+        assert_eq!(
+            EXPORT_XFILE_64BIT.name(),
+            c_key(sys::AI_CONFIG_EXPORT_XFILE_64BIT)
+        );
+        assert_eq!(
+            EXPORT_POINT_CLOUDS.name(),
+            c_key(sys::AI_CONFIG_EXPORT_POINT_CLOUDS)
+        );
+        assert_eq!(
+            EXPORT_GLTF_PBR_SPECULAR_GLOSSINESS.name(),
+            c_key(sys::AI_CONFIG_USE_GLTF_PBR_SPECULAR_GLOSSINESS)
+        );
+        assert_eq!(
+            EXPORT_GLTF_UNLIMITED_SKINNING_BONES_PER_VERTEX.name(),
+            c_key(sys::AI_CONFIG_EXPORT_GLTF_UNLIMITED_SKINNING_BONES_PER_VERTEX)
+        );
+        assert_eq!(
+            EXPORT_FBX_TRANSPARENCY_FACTOR_REFER_TO_OPACITY.name(),
+            c_key(sys::AI_CONFIG_EXPORT_FBX_TRANSPARENCY_FACTOR_REFER_TO_OPACITY)
+        );
+        assert_eq!(
+            EXPORT_BLOB_NAME.name(),
+            c_key(sys::AI_CONFIG_EXPORT_BLOB_NAME)
+        );
+    }
+
+    #[test]
+    fn config_keys_validate_ranges() {
+        assert!(GLOBAL_SCALE_FACTOR.validate(&0.01).is_ok());
+        assert!(GLOBAL_SCALE_FACTOR.validate(&0.0).is_err());
+        assert!(GLOBAL_SCALE_FACTOR.validate(&f32::NAN).is_err());
+        assert!(PP_CT_MAX_SMOOTHING_ANGLE.validate(&80.0).is_ok());
+        assert!(PP_CT_MAX_SMOOTHING_ANGLE.validate(&180.0).is_err());
+        assert!(PP_LBW_MAX_WEIGHTS.validate(&4).is_ok());
+        assert!(PP_LBW_MAX_WEIGHTS.validate(&0).is_err());
+        assert!(PP_CT_TEXTURE_CHANNEL_INDEX.validate(&8).is_err());
+        assert!(FBX_READ_ANIMATIONS.validate(&false).is_ok());
+
+        let (name, value) = GLOBAL_SCALE_FACTOR.property(2.0).unwrap();
+        assert_eq!(name, "GLOBAL_SCALE_FACTOR");
+        assert!(matches!(value, PropertyValue::Float(v) if v == 2.0));
+    }
+}
@@ -0,0 +1,299 @@
+//! One-shot format conversion: import, post-process and export in a single call.
+//!
+//! ```rust,no_run
+//! use asset_importer::{ConvertOptions, convert, postprocess::PostProcessSteps};
+//!
+//! # fn main() -> asset_importer::Result<()> {
+//! let report = convert(
+//!     ConvertOptions::from_path("model.fbx")
+//!         .to_path("model.gltf")
+//!         .with_post_process(PostProcessSteps::TRIANGULATE),
+//! )?;
+//! println!(
+//!     "{} triangles in {:?} + {:?}",
+//!     report.statistics.triangles, report.import_duration, report.export_duration
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{
+    error::{Error, Result},
+    exporter::{ExportBlob, ExportBuilder},
+    importer::{Importer, PropertyStore},
+    logging::{LogCapture, LogLevel},
+    postprocess::PostProcessSteps,
+    progress::{ClosureProgressEventHandler, ProgressEvent, ProgressEventHandler},
+    scene::stats::SceneStatistics,
+};
+
+/// Where [`convert`] reads the source model from.
+#[derive(Debug, Clone)]
+pub enum ConvertInput {
+    /// A model file; companion files (MTL, .bin, textures) are found next to it
+    Path(PathBuf),
+    /// An in-memory model with an optional format hint (such as `"obj"`)
+    Memory {
+        /// The model bytes
+        data: Vec<u8>,
+        /// File extension hint for formats Assimp cannot detect from content
+        hint: Option<String>,
+    },
+}
+
+/// Where [`convert`] writes the converted model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConvertOutput {
+    /// Write to this file; auxiliary files (such as a glTF `.bin`) go next to it
+    Path(PathBuf),
+    /// Export to memory, returned as [`ConvertReport::blob`]
+    Blob,
+}
+
+type SharedProgress = Arc<Mutex<Box<dyn ProgressEventHandler>>>;
+
+/// Options for [`convert`].
+pub struct ConvertOptions {
+    input: ConvertInput,
+    output: ConvertOutput,
+    format_id: Option<String>,
+    post_process: PostProcessSteps,
+    import_properties: PropertyStore,
+    export_properties: PropertyStore,
+    progress: Option<SharedProgress>,
+}
+
+impl std::fmt::Debug for ConvertOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConvertOptions")
+            .field("input", &self.input)
+            .field("output", &self.output)
+            .field("format_id", &self.format_id)
+            .field("post_process", &self.post_process)
+            .field("import_properties", &self.import_properties.len())
+            .field("export_properties", &self.export_properties.len())
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl ConvertOptions {
+    /// Convert from `input`, exporting to a blob until an output path is set.
+    pub fn new(input: ConvertInput) -> Self {
+        Self {
+            input,
+            output: ConvertOutput::Blob,
+            format_id: None,
+            post_process: PostProcessSteps::empty(),
+            import_properties: PropertyStore::new(),
+            export_properties: PropertyStore::new(),
+            progress: None,
+        }
+    }
+
+    /// Convert the model file at `path`.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(ConvertInput::Path(path.as_ref().to_path_buf()))
+    }
+
+    /// Convert an in-memory model; `hint` is a file extension such as `"obj"`.
+    pub fn from_memory(data: Vec<u8>, hint: Option<&str>) -> Self {
+        Self::new(ConvertInput::Memory {
+            data,
+            hint: hint.map(str::to_string),
+        })
+    }
+
+    /// Write the result to `path`. Without [`ConvertOptions::with_format`], the format
+    /// is chosen from the extension as in [`ExportBuilder::to_path_auto`].
+    pub fn to_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.output = ConvertOutput::Path(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Export to memory instead of a file; requires [`ConvertOptions::with_format`].
+    pub fn to_blob(mut self) -> Self {
+        self.output = ConvertOutput::Blob;
+        self
+    }
+
+    /// Export with this format id (see [`crate::exporter::format_ids`]).
+    pub fn with_format<S: Into<String>>(mut self, format_id: S) -> Self {
+        self.format_id = Some(format_id.into());
+        self
+    }
+
+    /// Post-processing steps applied during the import.
+    pub fn with_post_process(mut self, steps: PostProcessSteps) -> Self {
+        self.post_process = steps;
+        self
+    }
+
+    /// Importer properties.
+    pub fn with_import_properties(mut self, store: PropertyStore) -> Self {
+        self.import_properties = store;
+        self
+    }
+
+    /// Exporter properties.
+    pub fn with_export_properties(mut self, store: PropertyStore) -> Self {
+        self.export_properties = store;
+        self
+    }
+
+    /// Report progress of both phases to `handler`.
+    ///
+    /// The import is mapped to `0.0..=0.5` and the export to `0.5..=1.0`. Assimp does
+    /// not report progress from inside exporters, so the export phase produces one
+    /// [`ProgressStage::Writing`](crate::progress::ProgressStage::Writing) event when it
+    /// starts and one when it finishes. Returning `false` before the export starts
+    /// cancels the conversion with [`Error::Cancelled`].
+    pub fn with_progress_handler(mut self, handler: Box<dyn ProgressEventHandler>) -> Self {
+        self.progress = Some(Arc::new(Mutex::new(handler)));
+        self
+    }
+
+    /// Report progress of both phases to a closure; see
+    /// [`ConvertOptions::with_progress_handler`].
+    pub fn with_progress_event_fn<F>(self, f: F) -> Self
+    where
+        F: FnMut(&ProgressEvent) -> bool + Send + 'static,
+    {
+        self.with_progress_handler(Box::new(ClosureProgressEventHandler::new(f)))
+    }
+}
+
+/// Outcome of a successful [`convert`].
+pub struct ConvertReport {
+    /// Export format id that was used
+    pub format_id: String,
+    /// Output file, for [`ConvertOutput::Path`]
+    pub output_path: Option<PathBuf>,
+    /// Exported data, for [`ConvertOutput::Blob`]
+    pub blob: Option<ExportBlob>,
+    /// Wall-clock time of the import, including post-processing
+    pub import_duration: Duration,
+    /// Wall-clock time of the export
+    pub export_duration: Duration,
+    /// Statistics of the scene that was exported
+    pub statistics: SceneStatistics,
+    /// Warnings and errors Assimp logged during either phase, in emission order
+    pub warnings: Vec<(LogLevel, String)>,
+}
+
+impl std::fmt::Debug for ConvertReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConvertReport")
+            .field("format_id", &self.format_id)
+            .field("output_path", &self.output_path)
+            .field("blob", &self.blob.as_ref().map(ExportBlob::size))
+            .field("import_duration", &self.import_duration)
+            .field("export_duration", &self.export_duration)
+            .field("statistics", &self.statistics)
+            .field("warnings", &self.warnings)
+            .finish()
+    }
+}
+
+impl ConvertReport {
+    /// Whether Assimp logged any warning or error.
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+}
+
+/// Import `options.input`, post-process it and export it to `options.output`.
+///
+/// The format is resolved before anything is read, so an unknown output extension
+/// fails fast. Errors carry the operation and path of the failing phase (see
+/// [`Error::operation`]).
+pub fn convert(options: ConvertOptions) -> Result<ConvertReport> {
+    let ConvertOptions {
+        input,
+        output,
+        format_id,
+        post_process,
+        import_properties,
+        export_properties,
+        progress,
+    } = options;
+
+    let exporter = match (format_id, &output) {
+        (Some(id), _) => ExportBuilder::new(id),
+        (None, ConvertOutput::Path(path)) => ExportBuilder::auto().to_path_auto(path)?,
+        (None, ConvertOutput::Blob) => {
+            return Err(Error::invalid_parameter(
+                "Blob conversion needs an export format (use ConvertOptions::with_format)",
+            ));
+        }
+    };
+    let format_id = exporter.format_id().to_string();
+    let exporter = exporter.with_property_store(export_properties);
+
+    let mut builder = match input {
+        ConvertInput::Path(path) => Importer::new().read_file(path),
+        ConvertInput::Memory { data, hint } => Importer::new()
+            .read_from_memory_owned(data)
+            .with_memory_hint_opt(hint.as_deref()),
+    };
+    builder = builder
+        .with_post_process(post_process)
+        .with_property_store(import_properties);
+    if let Some(progress) = &progress {
+        let progress = progress.clone();
+        builder = builder.with_progress_event_fn(move |event| {
+            let mut scaled = event.clone();
+            if scaled.percent >= 0.0 {
+                scaled.percent *= 0.5;
+            }
+            forward(&progress, &scaled)
+        });
+    }
+    let (scene, import_report) = builder.import_with_report()?;
+    let mut warnings = import_report.messages;
+
+    let report_write = |percent: f32, step: &str| match &progress {
+        Some(progress) => forward(progress, &ProgressEvent::parse(percent, Some(step), None)),
+        None => true,
+    };
+    if !report_write(0.5, "write 0/1") {
+        return Err(Error::cancelled());
+    }
+
+    let capture = LogCapture::begin(LogLevel::Warn);
+    let start = Instant::now();
+    let exported = match &output {
+        ConvertOutput::Path(path) => exporter.export_to_file(&scene, path).map(|()| None),
+        ConvertOutput::Blob => exporter.export_to_blob(&scene).map(Some),
+    };
+    let export_duration = start.elapsed();
+    warnings.extend(capture.finish());
+    let blob = exported?;
+
+    // The output exists at this point, so the completion update cannot cancel.
+    report_write(1.0, "write 1/1");
+
+    Ok(ConvertReport {
+        format_id,
+        output_path: match output {
+            ConvertOutput::Path(path) => Some(path),
+            ConvertOutput::Blob => None,
+        },
+        blob,
+        import_duration: import_report.duration,
+        export_duration,
+        statistics: scene.statistics(),
+        warnings,
+    })
+}
+
+fn forward(progress: &SharedProgress, event: &ProgressEvent) -> bool {
+    match progress.lock() {
+        Ok(mut handler) => handler.on_progress(event),
+        Err(poisoned) => poisoned.into_inner().on_progress(event),
+    }
+}
@@ -0,0 +1,181 @@
+//! Format detection for memory buffers.
+//!
+//! Assimp picks an importer from the file extension, which a memory buffer does not
+//! have. [`detect_format`] recovers a usable extension from the content itself.
+//!
+//! ```rust,no_run
+//! use asset_importer::detect_format;
+//!
+//! let data = std::fs::read("download.bin")?;
+//! if let Some(format) = detect_format(&data) {
+//!     println!("{} ({})", format.extension(), format.importer.name);
+//! }
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{
+    importer_desc::{ImporterDesc, get_importer_desc, importer_desc_at},
+    sys,
+};
+
+/// How much of a text file is scanned for format tokens.
+const TEXT_SCAN_LIMIT: usize = 4096;
+
+/// Format guessed from the content of a buffer, see [`detect_format`].
+#[derive(Debug, Clone)]
+pub struct DetectedFormat {
+    /// Likely file extensions, most specific first, without the leading dot
+    pub extensions: Vec<String>,
+    /// Importer that will read the buffer
+    pub importer: ImporterDesc,
+}
+
+impl DetectedFormat {
+    /// The extension to pass as an import hint.
+    pub fn extension(&self) -> &str {
+        self.extensions.first().map_or("", String::as_str)
+    }
+}
+
+/// Guess the format of an in-memory model file.
+///
+/// Checks magic bytes for binary glTF, binary FBX, Blender, 3DS and binary STL, then
+/// scans the start of text files for glTF JSON, COLLADA, PLY, ASCII STL and OBJ
+/// tokens. If none of those match, each registered importer's signature check is run
+/// against the buffer, the way `aiImportFileFromMemory` probes importers when called
+/// without a hint (but without importing anything).
+///
+/// Returns `None` when no importer in this build claims the buffer.
+pub fn detect_format(data: &[u8]) -> Option<DetectedFormat> {
+    signature_extension(data)
+        .and_then(|extension| {
+            let importer = get_importer_desc(extension).ok().flatten()?;
+            Some(DetectedFormat {
+                extensions: vec![extension.to_string()],
+                importer,
+            })
+        })
+        .or_else(|| probe_importers(data))
+}
+
+fn signature_extension(data: &[u8]) -> Option<&'static str> {
+    binary_signature(data).or_else(|| text_signature(data))
+}
+
+fn binary_signature(data: &[u8]) -> Option<&'static str> {
+    if data.len() >= 12 && data.starts_with(b"glTF") {
+        let version = u32_le(data, 4);
+        if version == 1 || version == 2 {
+            return Some("glb");
+        }
+    }
+    if data.starts_with(b"Kaydara FBX Binary") {
+        return Some("fbx");
+    }
+    if data.starts_with(b"BLENDER") {
+        return Some("blend");
+    }
+    // 3DS: main chunk id 0x4D4D followed by a chunk length that fits the buffer.
+    if data.len() >= 16 && data.starts_with(&[0x4D, 0x4D]) {
+        let length = u32_le(data, 2) as usize;
+        if length >= 6 && length <= data.len() {
+            return Some("3ds");
+        }
+    }
+    // Binary STL: 80-byte header and a triangle count matching the buffer size.
+    if data.len() >= 84 {
+        let triangles = u32_le(data, 80) as usize;
+        let expected = triangles.checked_mul(50).and_then(|n| n.checked_add(84));
+        if expected == Some(data.len()) {
+            return Some("stl");
+        }
+    }
+    None
+}
+
+fn text_signature(data: &[u8]) -> Option<&'static str> {
+    let head = &data[..data.len().min(TEXT_SCAN_LIMIT)];
+    let head = head.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(head);
+    if head.is_empty() || head.contains(&0) {
+        return None;
+    }
+    let trimmed = head.trim_ascii_start();
+
+    if trimmed.starts_with(b"{") && contains(head, b"\"asset\"") && contains(head, b"\"version\"") {
+        return Some("gltf");
+    }
+    if trimmed.starts_with(b"<") && contains(head, b"<COLLADA") {
+        return Some("dae");
+    }
+    if head.starts_with(b"ply\n") || head.starts_with(b"ply\r\n") {
+        return Some("ply");
+    }
+    if trimmed.starts_with(b"solid") && contains(head, b"facet") {
+        return Some("stl");
+    }
+
+    const OBJ_TOKENS: [&[u8]; 8] = [
+        b"v ", b"vn ", b"vt ", b"f ", b"o ", b"g ", b"mtllib ", b"usemtl ",
+    ];
+    let is_obj = head.split(|&b| b == b'\n').any(|line| {
+        let line = line.trim_ascii_start();
+        OBJ_TOKENS.iter().any(|token| line.starts_with(token))
+    });
+    is_obj.then_some("obj")
+}
+
+fn probe_importers(data: &[u8]) -> Option<DetectedFormat> {
+    let mut index = 0usize;
+    let found = unsafe {
+        sys::aiDetectImporterFromMemoryRust(
+            data.as_ptr() as *const std::os::raw::c_char,
+            data.len(),
+            &mut index,
+        )
+    };
+    if found != 1 {
+        return None;
+    }
+
+    let importer = importer_desc_at(index)?;
+    Some(DetectedFormat {
+        extensions: importer.file_extensions.clone(),
+        importer,
+    })
+}
+
+fn u32_le(data: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&data[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_extension() {
+        assert_eq!(
+            signature_extension(b"Kaydara FBX Binary  \0\x1a\0"),
+            Some("fbx")
+        );
+        assert_eq!(signature_extension(b"BLENDER-v300"), Some("blend"));
+
+        let mut chunk = vec![0x4D, 0x4D, 16, 0, 0, 0];
+        chunk.resize(16, 0);
+        assert_eq!(signature_extension(&chunk), Some("3ds"));
+
+        // A binary STL whose triangle count disagrees with the size is not an STL.
+        let mut stl = vec![0u8; 84 + 50];
+        stl[80] = 2;
+        assert_eq!(signature_extension(&stl), None);
+        assert_eq!(signature_extension(b"# comment only\n"), None);
+    }
+}
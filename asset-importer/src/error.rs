@@ -1,88 +1,210 @@
 //! Error handling for asset importer operations
-
+//!
+//! Errors raised while importing a file, post-processing a scene or exporting one carry
+//! an [`ErrorContext`] naming the operation and, when known, the file path:
+//!
+//! ```text
+//! import of 'assets/crate.fbx' failed: Unable to open file "assets/crate.fbx".
+//! ```
+//!
+//! Errors wrapping a [`std::io::Error`] expose it through [`std::error::Error::source`].
+
+use std::borrow::Cow;
 use std::ffi::CStr;
-use thiserror::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Result type alias for asset importer operations
-pub type Result<T> = std::result::Result<T, Error>;
+///
+/// The error type defaults to [`Error`] but can be overridden, so the alias keeps working
+/// after a glob import of the [`prelude`](crate::prelude), e.g. in
+/// `fn main() -> Result<(), Box<dyn std::error::Error>>`.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The operation an error happened in, from [`ErrorContext`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    /// Importing a scene
+    Import,
+    /// Exporting a scene
+    Export,
+    /// Applying post-processing steps to an imported scene
+    PostProcess,
+    /// Reading or writing files outside of Assimp
+    Io,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Import => "import",
+            Self::Export => "export",
+            Self::PostProcess => "post-processing",
+            Self::Io => "file access",
+        })
+    }
+}
+
+/// Where an error happened: the operation and the file it concerned.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorContext {
+    /// The failed operation
+    pub operation: Operation,
+    /// The file being imported, exported or accessed, if any
+    pub path: Option<PathBuf>,
+}
+
+impl ErrorContext {
+    /// Context for `operation` without a path.
+    pub fn new(operation: Operation) -> Self {
+        Self {
+            operation,
+            path: None,
+        }
+    }
+
+    /// Set the path.
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
 
 /// Errors that can occur during asset import/export operations
-#[derive(Error, Debug)]
+///
+/// Every variant has an optional `context` field; see [`Error::context`].
+#[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Import operation failed
-    #[error("Import failed: {message}")]
     ImportFailed {
         /// Error message describing the import failure
         message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// Export operation failed
     #[cfg(feature = "export")]
-    #[error("Export failed: {message}")]
     ExportFailed {
         /// Error message describing the export failure
         message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// Invalid file path or file not found
-    #[error("File error: {message}")]
     FileError {
         /// Error message describing the file error
         message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// Invalid parameters or configuration
-    #[error("Invalid parameter: {message}")]
     InvalidParameter {
         /// Error message describing the invalid parameter
         message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
+    },
+
+    /// The operation was cancelled through a progress handler or a
+    /// [`CancellationToken`](crate::progress::CancellationToken)
+    Cancelled {
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
+    },
+
+    /// The import ran past its
+    /// [`ImportBuilder::with_timeout`](crate::importer::ImportBuilder::with_timeout) limit
+    TimedOut {
+        /// Time from the start of the import until it stopped
+        elapsed: Duration,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
+    },
+
+    /// `aiProcess_ValidateDataStructure` rejected the scene, or found warnings under
+    /// [`ValidationMode::Strict`](crate::validation::ValidationMode::Strict)
+    ValidationFailed {
+        /// The validation findings
+        report: crate::validation::ValidationReport,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// Memory allocation failed
-    #[error("Memory allocation failed")]
-    OutOfMemory,
+    OutOfMemory {
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
+    },
 
     /// Unsupported file format
-    #[error("Unsupported format: {format}")]
     UnsupportedFormat {
         /// The unsupported format name
         format: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
+    },
+
+    /// The operation is not supported for this value, e.g. post-processing a copied
+    /// scene
+    UnsupportedOperation {
+        /// Error message describing what is unsupported and what to do instead
+        message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// I/O operation failed
-    #[error("I/O error: {message}")]
     IoError {
         /// Error message describing the I/O error
         message: String,
+        /// The underlying I/O error, when there is one
+        source: Option<std::io::Error>,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// Logging operation failed
-    #[error("Logging error: {message}")]
     LoggingError {
         /// Error message describing the logging error
         message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// Invalid scene data
-    #[error("Invalid scene: {message}")]
     InvalidScene {
         /// Error message describing the scene validation error
         message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 
     /// String conversion error (UTF-8)
-    #[error("String conversion error: {0}")]
-    StringConversion(#[from] std::str::Utf8Error),
+    StringConversion {
+        /// The conversion error
+        source: std::str::Utf8Error,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
+    },
 
     /// Null pointer error
-    #[error("Null pointer encountered")]
-    NullPointer,
+    NullPointer {
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
+    },
 
     /// Generic error with custom message
-    #[error("{message}")]
     Other {
         /// Custom error message
         message: String,
+        /// Operation and path the error occurred in
+        context: Option<ErrorContext>,
     },
 }
 
@@ -91,6 +213,7 @@ impl Error {
     pub fn import_failed<S: Into<String>>(message: S) -> Self {
         Self::ImportFailed {
             message: message.into(),
+            context: None,
         }
     }
 
@@ -99,6 +222,7 @@ impl Error {
     pub fn export_failed<S: Into<String>>(message: S) -> Self {
         Self::ExportFailed {
             message: message.into(),
+            context: None,
         }
     }
 
@@ -106,6 +230,7 @@ impl Error {
     pub fn file_error<S: Into<String>>(message: S) -> Self {
         Self::FileError {
             message: message.into(),
+            context: None,
         }
     }
 
@@ -113,13 +238,49 @@ impl Error {
     pub fn invalid_parameter<S: Into<String>>(message: S) -> Self {
         Self::InvalidParameter {
             message: message.into(),
+            context: None,
         }
     }
 
+    /// Create a cancellation error
+    pub fn cancelled() -> Self {
+        Self::Cancelled { context: None }
+    }
+
+    /// Create a timeout error
+    pub fn timed_out(elapsed: Duration) -> Self {
+        Self::TimedOut {
+            elapsed,
+            context: None,
+        }
+    }
+
+    /// Create a validation error from its report
+    pub fn validation_failed(report: crate::validation::ValidationReport) -> Self {
+        Self::ValidationFailed {
+            report,
+            context: None,
+        }
+    }
+
+    /// Create an out-of-memory error
+    pub fn out_of_memory() -> Self {
+        Self::OutOfMemory { context: None }
+    }
+
     /// Create a new unsupported format error
     pub fn unsupported_format<S: Into<String>>(format: S) -> Self {
         Self::UnsupportedFormat {
             format: format.into(),
+            context: None,
+        }
+    }
+
+    /// Create a new unsupported operation error
+    pub fn unsupported_operation<S: Into<String>>(message: S) -> Self {
+        Self::UnsupportedOperation {
+            message: message.into(),
+            context: None,
         }
     }
 
@@ -127,6 +288,20 @@ impl Error {
     pub fn io_error<S: Into<String>>(message: S) -> Self {
         Self::IoError {
             message: message.into(),
+            source: None,
+            context: None,
+        }
+    }
+
+    /// Create an I/O error wrapping `source`.
+    ///
+    /// `message` describes what was being done; the I/O failure itself is only reported
+    /// through [`std::error::Error::source`], so chain-walking reporters print it once.
+    pub fn io_error_with_source<S: Into<String>>(message: S, source: std::io::Error) -> Self {
+        Self::IoError {
+            message: message.into(),
+            source: Some(source),
+            context: None,
         }
     }
 
@@ -134,6 +309,7 @@ impl Error {
     pub fn logging_error<S: Into<String>>(message: S) -> Self {
         Self::LoggingError {
             message: message.into(),
+            context: None,
         }
     }
 
@@ -141,43 +317,234 @@ impl Error {
     pub fn invalid_scene<S: Into<String>>(message: S) -> Self {
         Self::InvalidScene {
             message: message.into(),
+            context: None,
         }
     }
 
+    /// Create a null pointer error
+    pub fn null_pointer() -> Self {
+        Self::NullPointer { context: None }
+    }
+
     /// Create a generic error
     pub fn other<S: Into<String>>(message: S) -> Self {
         Self::Other {
             message: message.into(),
+            context: None,
         }
     }
 
     /// Get the last error from Assimp
     pub fn from_assimp() -> Self {
-        unsafe {
-            let error_ptr = crate::sys::aiGetErrorString();
-            if error_ptr.is_null() {
-                Self::Other {
-                    message: "Unknown Assimp error".to_string(),
-                }
-            } else {
-                match CStr::from_ptr(error_ptr).to_str() {
-                    Ok(error_str) => Self::Other {
-                        message: error_str.to_string(),
-                    },
-                    Err(_) => Self::Other {
-                        message: "Invalid UTF-8 in Assimp error message".to_string(),
-                    },
-                }
-            }
+        let error_ptr = unsafe { crate::sys::aiGetErrorString() };
+        if error_ptr.is_null() {
+            return Self::other("Unknown Assimp error");
+        }
+        match unsafe { CStr::from_ptr(error_ptr) }.to_str() {
+            Ok(error_str) => Self::other(error_str),
+            Err(_) => Self::other("Invalid UTF-8 in Assimp error message"),
         }
     }
 
     pub(crate) fn from_bridge_or_assimp() -> Self {
         if let Some(message) = bridge_error_string() {
-            return Self::Other { message };
+            return Self::other(message);
         }
         Self::from_assimp()
     }
+
+    /// The operation and path this error occurred in, if recorded.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::ImportFailed { context, .. }
+            | Self::FileError { context, .. }
+            | Self::InvalidParameter { context, .. }
+            | Self::Cancelled { context }
+            | Self::TimedOut { context, .. }
+            | Self::ValidationFailed { context, .. }
+            | Self::OutOfMemory { context }
+            | Self::UnsupportedFormat { context, .. }
+            | Self::UnsupportedOperation { context, .. }
+            | Self::IoError { context, .. }
+            | Self::LoggingError { context, .. }
+            | Self::InvalidScene { context, .. }
+            | Self::StringConversion { context, .. }
+            | Self::NullPointer { context }
+            | Self::Other { context, .. } => context.as_ref(),
+            #[cfg(feature = "export")]
+            Self::ExportFailed { context, .. } => context.as_ref(),
+        }
+    }
+
+    fn context_mut(&mut self) -> &mut Option<ErrorContext> {
+        match self {
+            Self::ImportFailed { context, .. }
+            | Self::FileError { context, .. }
+            | Self::InvalidParameter { context, .. }
+            | Self::Cancelled { context }
+            | Self::TimedOut { context, .. }
+            | Self::ValidationFailed { context, .. }
+            | Self::OutOfMemory { context }
+            | Self::UnsupportedFormat { context, .. }
+            | Self::UnsupportedOperation { context, .. }
+            | Self::IoError { context, .. }
+            | Self::LoggingError { context, .. }
+            | Self::InvalidScene { context, .. }
+            | Self::StringConversion { context, .. }
+            | Self::NullPointer { context }
+            | Self::Other { context, .. } => context,
+            #[cfg(feature = "export")]
+            Self::ExportFailed { context, .. } => context,
+        }
+    }
+
+    /// The operation this error occurred in, if recorded.
+    pub fn operation(&self) -> Option<Operation> {
+        self.context().map(|context| context.operation)
+    }
+
+    /// The file this error concerns, if recorded.
+    pub fn path(&self) -> Option<&Path> {
+        self.context().and_then(|context| context.path.as_deref())
+    }
+
+    /// Replace the error's context.
+    pub fn with_context(mut self, context: ErrorContext) -> Self {
+        *self.context_mut() = Some(context);
+        self
+    }
+
+    /// Set the operation, keeping a recorded path.
+    pub fn with_operation(mut self, operation: Operation) -> Self {
+        let context = self.context_mut();
+        match context {
+            Some(context) => context.operation = operation,
+            None => *context = Some(ErrorContext::new(operation)),
+        }
+        self
+    }
+
+    /// Set the path, replacing a recorded one.
+    ///
+    /// Without a recorded operation, [`Operation::Export`] is assumed for export
+    /// errors and [`Operation::Import`] otherwise.
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        let operation = self.operation().unwrap_or(match self {
+            #[cfg(feature = "export")]
+            Self::ExportFailed { .. } => Operation::Export,
+            _ => Operation::Import,
+        });
+        *self.context_mut() = Some(ErrorContext::new(operation).with_path(path));
+        self
+    }
+
+    /// The error message without the operation and path prefix.
+    pub fn message(&self) -> Cow<'_, str> {
+        match self {
+            Self::ImportFailed { message, .. }
+            | Self::FileError { message, .. }
+            | Self::InvalidParameter { message, .. }
+            | Self::UnsupportedOperation { message, .. }
+            | Self::IoError { message, .. }
+            | Self::LoggingError { message, .. }
+            | Self::InvalidScene { message, .. }
+            | Self::Other { message, .. } => Cow::Borrowed(message),
+            #[cfg(feature = "export")]
+            Self::ExportFailed { message, .. } => Cow::Borrowed(message),
+            Self::Cancelled { .. } => Cow::Borrowed("cancelled"),
+            Self::TimedOut { elapsed, .. } => Cow::Owned(format!("timed out after {:?}", elapsed)),
+            Self::ValidationFailed { report, .. } => Cow::Owned(report.to_string()),
+            Self::OutOfMemory { .. } => Cow::Borrowed("memory allocation failed"),
+            Self::UnsupportedFormat { format, .. } => {
+                Cow::Owned(format!("unsupported format: {}", format))
+            }
+            Self::StringConversion { source, .. } => Cow::Owned(source.to_string()),
+            Self::NullPointer { .. } => Cow::Borrowed("null pointer encountered"),
+        }
+    }
+
+    /// The variant's own description, used when no context is recorded.
+    fn fmt_plain(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ImportFailed { message, .. } => write!(f, "Import failed: {}", message),
+            #[cfg(feature = "export")]
+            Self::ExportFailed { message, .. } => write!(f, "Export failed: {}", message),
+            Self::FileError { message, .. } => write!(f, "File error: {}", message),
+            Self::InvalidParameter { message, .. } => {
+                write!(f, "Invalid parameter: {}", message)
+            }
+            Self::Cancelled { .. } => f.write_str("Import cancelled"),
+            Self::TimedOut { elapsed, .. } => write!(f, "Import timed out after {:?}", elapsed),
+            Self::ValidationFailed { report, .. } => write!(f, "Validation failed: {}", report),
+            Self::OutOfMemory { .. } => f.write_str("Memory allocation failed"),
+            Self::UnsupportedFormat { format, .. } => write!(f, "Unsupported format: {}", format),
+            Self::UnsupportedOperation { message, .. } => {
+                write!(f, "Unsupported operation: {}", message)
+            }
+            Self::IoError { message, .. } => write!(f, "I/O error: {}", message),
+            Self::LoggingError { message, .. } => write!(f, "Logging error: {}", message),
+            Self::InvalidScene { message, .. } => write!(f, "Invalid scene: {}", message),
+            Self::StringConversion { source, .. } => {
+                write!(f, "String conversion error: {}", source)
+            }
+            Self::NullPointer { .. } => f.write_str("Null pointer encountered"),
+            Self::Other { message, .. } => f.write_str(message),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(context) = self.context() else {
+            return self.fmt_plain(f);
+        };
+        match &context.path {
+            Some(path) => write!(f, "{} of '{}' failed: ", context.operation, path.display())?,
+            None => write!(f, "{} failed: ", context.operation)?,
+        }
+        match self {
+            // The prefix already names the operation.
+            Self::ImportFailed { message, .. } | Self::Other { message, .. } => {
+                f.write_str(message)
+            }
+            #[cfg(feature = "export")]
+            Self::ExportFailed { message, .. } => f.write_str(message),
+            Self::Cancelled { .. } => f.write_str("cancelled"),
+            _ => self.fmt_plain(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::IoError {
+                source: Some(source),
+                ..
+            } => Some(source),
+            Self::StringConversion { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(source: std::io::Error) -> Self {
+        Self::IoError {
+            message: "I/O operation failed".to_string(),
+            source: Some(source),
+            context: None,
+        }
+    }
+}
+
+impl From<std::str::Utf8Error> for Error {
+    fn from(source: std::str::Utf8Error) -> Self {
+        Self::StringConversion {
+            source,
+            context: None,
+        }
+    }
 }
 
 fn bridge_error_string() -> Option<String> {
@@ -212,6 +579,46 @@ mod tests {
         assert_eq!(error.to_string(), "Import failed: Test import error");
     }
 
+    #[test]
+    fn test_context_display() {
+        let error = Error::import_failed("Unable to open file").with_path("foo/bar.fbx");
+        assert_eq!(error.operation(), Some(Operation::Import));
+        assert_eq!(
+            error.to_string(),
+            "import of 'foo/bar.fbx' failed: Unable to open file"
+        );
+
+        let error =
+            Error::invalid_scene("Post-processing failed").with_operation(Operation::PostProcess);
+        assert_eq!(
+            error.to_string(),
+            "post-processing failed: Invalid scene: Post-processing failed"
+        );
+        assert_eq!(error.message(), "Post-processing failed");
+
+        let error = error.with_context(ErrorContext::new(Operation::Export).with_path("a.glb"));
+        assert_eq!(error.path(), Some(Path::new("a.glb")));
+        assert!(error.to_string().starts_with("export of 'a.glb' failed: "));
+    }
+
+    #[test]
+    fn test_io_source_chain() {
+        use std::error::Error as _;
+
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+        let error = Error::io_error_with_source("Failed to open texture", io);
+        assert_eq!(error.to_string(), "I/O error: Failed to open texture");
+        let source = error.source().expect("source");
+        assert_eq!(
+            source.downcast_ref::<std::io::Error>().map(|e| e.kind()),
+            Some(std::io::ErrorKind::NotFound)
+        );
+
+        assert!(Error::io_error("plain").source().is_none());
+        let converted: Error = std::io::Error::other("disk full").into();
+        assert!(converted.source().is_some());
+    }
+
     #[test]
     fn test_c_str_to_string_or_empty() {
         // Test with null pointer
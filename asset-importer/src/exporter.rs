@@ -1,15 +1,18 @@
 //! Scene export functionality
 
 use std::ffi::CString;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::{
     bridge_properties::build_rust_properties,
-    error::{Error, Result},
+    config::{ConfigKey, ConfigValue},
+    error::{Error, ErrorContext, Operation, Result},
     ffi,
-    importer::{PropertyStore, PropertyValue},
+    importer::{ProgressUser, PropertyStore, PropertyValue, progress_cb},
     io::{AssimpFileIO, FileSystem},
+    progress::{ProgressDispatcher, ProgressEventHandler, ProgressHandler},
     ptr::SharedPtr,
     scene::Scene,
     sys,
@@ -51,6 +54,10 @@ pub struct ExportBuilder {
     preprocessing: u32,
     file_system: Option<std::sync::Arc<std::sync::Mutex<dyn FileSystem>>>,
     properties: Vec<(String, PropertyValue)>,
+    output_path: Option<PathBuf>,
+    prefer_latest: bool,
+    binary: bool,
+    progress_handler: Option<Box<dyn ProgressEventHandler>>,
 }
 
 impl std::fmt::Debug for ExportBuilder {
@@ -60,6 +67,10 @@ impl std::fmt::Debug for ExportBuilder {
             .field("preprocessing", &self.preprocessing)
             .field("file_system", &self.file_system.is_some())
             .field("properties", &self.properties.len())
+            .field("output_path", &self.output_path)
+            .field("prefer_latest", &self.prefer_latest)
+            .field("binary", &self.binary)
+            .field("progress_handler", &self.progress_handler.is_some())
             .finish()
     }
 }
@@ -72,9 +83,70 @@ impl ExportBuilder {
             preprocessing: 0,
             file_system: None,
             properties: Vec::new(),
+            output_path: None,
+            prefer_latest: true,
+            binary: false,
+            progress_handler: None,
         }
     }
 
+    /// Create a builder without a format, to be chosen from the output path by
+    /// [`ExportBuilder::to_path_auto`].
+    pub fn auto() -> Self {
+        Self::new("")
+    }
+
+    /// Replace the export format, typically with one of the [`format_ids`] constants.
+    ///
+    /// Exports fail before reaching Assimp when the id is not available in this build,
+    /// with the available ids listed in the error.
+    pub fn format<S: Into<String>>(mut self, format_id: S) -> Self {
+        self.format_id = format_id.into();
+        self
+    }
+
+    /// The export format id currently selected.
+    pub fn format_id(&self) -> &str {
+        &self.format_id
+    }
+
+    /// Whether [`ExportBuilder::to_path_auto`] resolves `.gltf`/`.glb` to the newest
+    /// exporter (`gltf2`/`glb2`). Defaults to `true`; when `false`, extensions served by
+    /// several versions are an error listing the candidates.
+    pub fn prefer_latest(mut self, prefer_latest: bool) -> Self {
+        self.prefer_latest = prefer_latest;
+        self
+    }
+
+    /// Make [`ExportBuilder::to_path_auto`] pick the binary variant of formats that
+    /// have an ASCII and a binary exporter (`stl`/`stlb`, `ply`/`plyb`, `fbxa`/`fbx`).
+    /// Defaults to `false`, i.e. ASCII.
+    pub fn binary(mut self, binary: bool) -> Self {
+        self.binary = binary;
+        self
+    }
+
+    /// Export to `path` with the format chosen from its extension; see
+    /// [`format_for_path`] for the preference table. Call [`ExportBuilder::export`] next.
+    ///
+    /// Fails when the extension has no exporter in this build, or when it is ambiguous
+    /// and [`ExportBuilder::prefer_latest`] is off.
+    pub fn to_path_auto<P: AsRef<Path>>(mut self, path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let desc = resolve_format_for_path(path, self.prefer_latest, self.binary)?;
+        self.format_id = desc.id;
+        self.output_path = Some(path.to_path_buf());
+        Ok(self)
+    }
+
+    /// Export to the path set by [`ExportBuilder::to_path_auto`].
+    pub fn export(mut self, scene: &Scene) -> Result<()> {
+        let path = self.output_path.take().ok_or_else(|| {
+            Error::invalid_parameter("Export path not set (use ExportBuilder::to_path_auto)")
+        })?;
+        self.export_to_file(scene, path)
+    }
+
     /// Set preprocessing steps to apply before export
     pub fn with_preprocessing(mut self, steps: u32) -> Self {
         self.preprocessing = steps;
@@ -87,6 +159,12 @@ impl ExportBuilder {
         self
     }
 
+    /// Set a typed configuration key (see [`crate::config`]).
+    pub fn with_config<T: ConfigValue>(mut self, key: ConfigKey<T>, value: T) -> Result<Self> {
+        self.properties.push(key.property(value)?);
+        Ok(self)
+    }
+
     /// Add properties from a PropertyStore.
     pub fn with_property_store(mut self, store: PropertyStore) -> Self {
         self.properties
@@ -129,7 +207,40 @@ impl ExportBuilder {
         self.with_property(name, PropertyValue::Matrix(value))
     }
 
+    /// Report export progress to `handler`.
+    ///
+    /// Assimp reports progress between export stages (scene copy, preprocessing,
+    /// writing) as `"write N/M"` updates from 0.0 to 1.0, not while an exporter writes,
+    /// so a large export still has long gaps between updates. Returning `false` aborts
+    /// the export at the next stage with [`Error::Cancelled`]. Cleanup is best effort: a
+    /// file export removes the output file if the export created it and no custom file
+    /// system is set, but leaves auxiliary files (e.g. a glTF `.bin`) behind; a blob
+    /// export discards the partial blob.
+    pub fn with_progress_handler(self, handler: Box<dyn ProgressHandler>) -> Self {
+        self.with_progress_event_handler(Box::new(handler))
+    }
+
+    /// Set a handler that receives parsed [`ProgressEvent`](crate::progress::ProgressEvent)s.
+    pub fn with_progress_event_handler(mut self, handler: Box<dyn ProgressEventHandler>) -> Self {
+        self.progress_handler = Some(handler);
+        self
+    }
+
+    /// Set a progress handler from a closure.
+    pub fn with_progress_handler_fn<F>(self, f: F) -> Self
+    where
+        F: FnMut(f32, Option<&str>) -> bool + Send + 'static,
+    {
+        self.with_progress_handler(Box::new(crate::progress::ClosureProgressHandler::new(f)))
+    }
+
     /// Use a custom file system for exporting (uses aiExportSceneEx).
+    ///
+    /// Every file of the export (e.g. the `.bin` buffer next to a `.gltf`) is opened
+    /// through [`FileSystem::open_with_mode`] with a write mode, so the file system
+    /// must support writing; [`MemoryFileSystem`](crate::io::MemoryFileSystem) does.
+    /// A failed open, write, flush or close fails the export with
+    /// [`Error::IoError`] even when Assimp reports success.
     pub fn with_file_system<F>(self, file_system: F) -> Self
     where
         F: FileSystem + 'static,
@@ -147,17 +258,30 @@ impl ExportBuilder {
     }
 
     /// Export the scene to a file
+    ///
+    /// Errors carry the path (see [`Error::path`]).
     pub fn export_to_file<P: AsRef<Path>>(self, scene: &Scene, path: P) -> Result<()> {
-        let path_str = path.as_ref().to_string_lossy();
+        let path = path.as_ref();
+        self.export_file(scene, path)
+            .map_err(|err| err.with_context(ErrorContext::new(Operation::Export).with_path(path)))
+    }
+
+    fn export_file(mut self, scene: &Scene, path: &Path) -> Result<()> {
+        self.check_format()?;
+        let path_str = path.to_string_lossy();
         let c_path = CString::new(path_str.as_ref())
             .map_err(|_| Error::invalid_parameter("Invalid file path"))?;
         let c_format = CString::new(self.format_id.as_str())
             .map_err(|_| Error::invalid_parameter("Invalid format ID"))?;
 
-        let used_bridge = !self.properties.is_empty();
+        let progress = self.progress_user();
+        let used_bridge = !self.properties.is_empty() || progress.is_some();
+        let mut file_io = self
+            .file_system
+            .as_ref()
+            .map(|fs| AssimpFileIO::new(fs.clone()).create_ai_file_io());
         let result = if !used_bridge {
-            if let Some(fs) = &self.file_system {
-                let mut file_io = AssimpFileIO::new(fs.clone()).create_ai_file_io();
+            if let Some(file_io) = file_io.as_mut() {
                 unsafe {
                     sys::aiExportSceneEx(
                         scene.as_raw_sys(),
@@ -179,34 +303,33 @@ impl ExportBuilder {
             }
         } else {
             let buffers = build_rust_properties(&self.properties)?;
-            if let Some(fs) = &self.file_system {
-                let file_io = AssimpFileIO::new(fs.clone()).create_ai_file_io();
-                unsafe {
-                    sys::aiExportSceneExWithPropertiesRust(
-                        scene.as_raw_sys(),
-                        c_format.as_ptr(),
-                        c_path.as_ptr(),
-                        file_io.as_ptr_sys(),
-                        self.preprocessing,
-                        buffers.ffi_props.as_ptr(),
-                        buffers.ffi_props.len(),
-                    )
-                }
-            } else {
-                unsafe {
-                    sys::aiExportSceneExWithPropertiesRust(
-                        scene.as_raw_sys(),
-                        c_format.as_ptr(),
-                        c_path.as_ptr(),
-                        std::ptr::null(),
-                        self.preprocessing,
-                        buffers.ffi_props.as_ptr(),
-                        buffers.ffi_props.len(),
-                    )
-                }
+            unsafe {
+                sys::aiExportSceneWithProgressRust(
+                    scene.as_raw_sys(),
+                    c_format.as_ptr(),
+                    c_path.as_ptr(),
+                    file_io
+                        .as_ref()
+                        .map_or(std::ptr::null(), |io| io.as_ptr_sys()),
+                    self.preprocessing,
+                    buffers.as_ptr(),
+                    buffers.len(),
+                    progress.as_ref().map(|_| progress_cb as _),
+                    progress
+                        .as_ref()
+                        .map_or(std::ptr::null_mut(), ProgressUser::as_void_ptr),
+                )
             }
         };
 
+        if progress.as_ref().is_some_and(ProgressUser::is_cancelled) {
+            return Err(Error::cancelled());
+        }
+        // Exporters do not check every write, so a failing custom file system can leave
+        // a truncated file behind a successful return code.
+        if let Some(message) = file_io.as_ref().and_then(|io| io.take_write_error()) {
+            return Err(Error::io_error(message));
+        }
         if result == sys::aiReturn::aiReturn_SUCCESS {
             Ok(())
         } else if used_bridge {
@@ -218,10 +341,17 @@ impl ExportBuilder {
 
     /// Export the scene to a blob in memory
     pub fn export_to_blob(self, scene: &Scene) -> Result<ExportBlob> {
+        self.export_blob(scene)
+            .map_err(|err| err.with_operation(Operation::Export))
+    }
+
+    fn export_blob(mut self, scene: &Scene) -> Result<ExportBlob> {
+        self.check_format()?;
         let c_format = CString::new(self.format_id.as_str())
             .map_err(|_| Error::invalid_parameter("Invalid format ID"))?;
 
-        let used_bridge = !self.properties.is_empty();
+        let progress = self.progress_user();
+        let used_bridge = !self.properties.is_empty() || progress.is_some();
         let blob_ptr = if !used_bridge {
             unsafe {
                 sys::aiExportSceneToBlob(scene.as_raw_sys(), c_format.as_ptr(), self.preprocessing)
@@ -229,16 +359,23 @@ impl ExportBuilder {
         } else {
             let buffers = build_rust_properties(&self.properties)?;
             unsafe {
-                sys::aiExportSceneToBlobWithPropertiesRust(
+                sys::aiExportSceneToBlobWithProgressRust(
                     scene.as_raw_sys(),
                     c_format.as_ptr(),
                     self.preprocessing,
-                    buffers.ffi_props.as_ptr(),
-                    buffers.ffi_props.len(),
+                    buffers.as_ptr(),
+                    buffers.len(),
+                    progress.as_ref().map(|_| progress_cb as _),
+                    progress
+                        .as_ref()
+                        .map_or(std::ptr::null_mut(), ProgressUser::as_void_ptr),
                 )
             }
         };
 
+        if progress.as_ref().is_some_and(ProgressUser::is_cancelled) {
+            return Err(Error::cancelled());
+        }
         if blob_ptr.is_null() {
             if used_bridge {
                 Err(Error::from_bridge_or_assimp())
@@ -246,10 +383,97 @@ impl ExportBuilder {
                 Err(Error::from_assimp())
             }
         } else {
-            ExportBlob::from_sys_ptr(blob_ptr)
+            let base_name = self
+                .properties
+                .iter()
+                .rev()
+                .find_map(|(name, value)| match value {
+                    PropertyValue::String(s) if name == crate::config::EXPORT_BLOB_NAME.name() => {
+                        Some(s.as_str())
+                    }
+                    _ => None,
+                });
+            let extension = crate::get_export_formats_iter()
+                .find(|desc| desc.id == self.format_id)
+                .map(|desc| desc.file_extension);
+            ExportBlob::from_sys_ptr(blob_ptr, base_name, extension.as_deref())
                 .ok_or_else(|| Error::invalid_scene("Invalid export blob pointer"))
         }
     }
+
+    fn progress_user(&mut self) -> Option<ProgressUser> {
+        self.progress_handler
+            .take()
+            .map(|handler| ProgressUser::new(ProgressDispatcher::new(handler, None, None)))
+    }
+
+    fn check_format(&self) -> Result<()> {
+        if is_format_available(&self.format_id) {
+            return Ok(());
+        }
+        let available: Vec<String> = crate::get_export_formats_iter().map(|d| d.id).collect();
+        Err(Error::invalid_parameter(format!(
+            "Export format '{}' is not available; available formats: {}",
+            self.format_id,
+            available.join(", ")
+        )))
+    }
+}
+
+/// File name Assimp uses for the primary blob when no
+/// [`EXPORT_BLOB_NAME`](crate::config::EXPORT_BLOB_NAME) is set (`AI_BLOBIO_MAGIC`).
+///
+/// Exporters derive auxiliary file references from it, so a glTF exported without a
+/// blob name references its buffer as `$blobfile.bin`.
+pub const EXPORT_BLOB_MAGIC: &str = "$blobfile";
+
+/// One file of a (possibly multi-file) blob export.
+///
+/// `data` borrows the buffer Assimp allocated for the export, without copying, so a
+/// part cannot outlive its [`ExportBlob`]:
+///
+/// ```rust,compile_fail
+/// # use asset_importer::{Scene, exporter::{ExportBuilder, formats}};
+/// # fn export(scene: &Scene) -> asset_importer::Result<()> {
+/// let data = {
+///     let blob = ExportBuilder::new(formats::GLB2).export_to_blob(scene)?;
+///     blob.parts().next().unwrap().data()
+/// }; // error: `blob` does not live long enough
+/// # let _ = data;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlobPart<'a> {
+    /// File name the part should be written under, matching the references in the
+    /// primary file (e.g. `scene.gltf` / `scene.bin`).
+    pub name: &'a str,
+    /// File contents.
+    pub data: &'a [u8],
+}
+
+impl<'a> BlobPart<'a> {
+    /// File contents, borrowed from the blob.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Size of the file in bytes.
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Write the contents to `writer` and return the number of bytes written.
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> Result<u64> {
+        writer
+            .write_all(self.data)
+            .and_then(|()| writer.flush())
+            .map_err(|e| {
+                Error::io_error_with_source(format!("Failed to write {}", self.name), e)
+                    .with_context(ErrorContext::new(Operation::Io))
+            })?;
+        Ok(self.data.len() as u64)
+    }
 }
 
 /// A blob containing exported scene data
@@ -260,10 +484,24 @@ pub struct ExportBlob {
 
 impl ExportBlob {
     /// Create an ExportBlob from a raw Assimp blob pointer
-    fn from_sys_ptr(blob_ptr: *const sys::aiExportDataBlob) -> Option<Self> {
-        let blob_ptr = SharedPtr::new(blob_ptr)?;
+    ///
+    /// `base_name` is the `EXPORT_BLOB_NAME` the export ran with and `extension` the
+    /// export format's file extension; both are used to derive part file names.
+    fn from_sys_ptr(
+        blob_ptr: *const sys::aiExportDataBlob,
+        base_name: Option<&str>,
+        extension: Option<&str>,
+    ) -> Option<Self> {
+        let root = SharedPtr::new(blob_ptr)?;
+        let mut parts = Vec::new();
+        let mut current = Some(root);
+        while let Some(blob) = current {
+            let name = part_file_name(parts.len(), blob.as_ref(), base_name, extension);
+            parts.push((name, blob));
+            current = SharedPtr::new(blob.as_ref().next as *const sys::aiExportDataBlob);
+        }
         Some(Self {
-            inner: Arc::new(ExportBlobInner { root: blob_ptr }),
+            inner: Arc::new(ExportBlobInner { root, parts }),
         })
     }
 
@@ -313,11 +551,133 @@ impl ExportBlob {
             current: Some(self.inner.root),
         }
     }
+
+    /// Number of files in the chain (1 for single-file formats).
+    pub fn num_parts(&self) -> usize {
+        self.inner.parts.len()
+    }
+
+    /// Iterate over every file in the chain with the name it should be saved under.
+    ///
+    /// The primary file comes first. Names follow the references Assimp wrote into
+    /// the primary file, so set [`EXPORT_BLOB_NAME`](crate::config::EXPORT_BLOB_NAME)
+    /// to control them (otherwise they are based on [`EXPORT_BLOB_MAGIC`]).
+    pub fn parts(&self) -> impl Iterator<Item = BlobPart<'_>> {
+        self.inner.parts.iter().map(move |(name, blob)| {
+            let raw = blob.as_ref();
+            BlobPart {
+                name,
+                data: ffi::slice_from_ptr_len(self, raw.data as *const u8, raw.size),
+            }
+        })
+    }
+
+    /// Combined size in bytes of every part.
+    pub fn total_size(&self) -> usize {
+        self.parts().map(|part| part.size()).sum()
+    }
+
+    /// The part named `name` (see [`ExportBlob::parts`]).
+    pub fn part(&self, name: &str) -> Option<BlobPart<'_>> {
+        self.parts().find(|part| part.name == name)
+    }
+
+    /// Stream the part named `name` to `writer` and return the number of bytes written.
+    ///
+    /// The bytes go straight from Assimp's buffer to the writer.
+    pub fn write_part_to<W: Write + ?Sized>(&self, name: &str, writer: &mut W) -> Result<u64> {
+        let part = self.part(name).ok_or_else(|| {
+            Error::invalid_parameter(format!("Export blob has no part named {name}"))
+        })?;
+        part.write_to(writer)
+    }
+
+    /// Stream every part to a writer chosen by `sink` from the part name (a zip entry,
+    /// an object storage upload, ...), and return the total number of bytes written.
+    ///
+    /// Parts are written in [`ExportBlob::parts`] order; each writer is flushed and
+    /// dropped before `sink` is asked for the next one.
+    pub fn write_all_to<'w, F>(&self, mut sink: F) -> Result<u64>
+    where
+        F: FnMut(&str) -> Box<dyn Write + 'w>,
+    {
+        let mut total = 0;
+        for part in self.parts() {
+            let mut writer = sink(part.name);
+            total += part.write_to(&mut *writer)?;
+        }
+        Ok(total)
+    }
+
+    /// Write every part into `dir` (which must exist) and return the written paths.
+    pub fn write_all_to_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<std::path::PathBuf>> {
+        let dir = dir.as_ref();
+        let mut written = Vec::with_capacity(self.num_parts());
+        for part in self.parts() {
+            let relative = Path::new(part.name);
+            if relative.is_absolute()
+                || relative
+                    .components()
+                    .any(|c| !matches!(c, std::path::Component::Normal(_)))
+            {
+                return Err(Error::invalid_parameter(format!(
+                    "Refusing to write export blob part outside the target directory: {}",
+                    part.name
+                )));
+            }
+            let path = dir.join(relative);
+            std::fs::write(&path, part.data).map_err(|e| {
+                Error::io_error_with_source(format!("Failed to write {}", path.display()), e)
+                    .with_context(ErrorContext::new(Operation::Io).with_path(&path))
+            })?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+fn part_file_name(
+    index: usize,
+    blob: &sys::aiExportDataBlob,
+    base_name: Option<&str>,
+    extension: Option<&str>,
+) -> String {
+    let raw = if blob.name.length == 0 {
+        String::new()
+    } else {
+        ai_string_to_string(&blob.name)
+    };
+
+    if index == 0 {
+        let mut name = if raw.is_empty() {
+            base_name.unwrap_or(EXPORT_BLOB_MAGIC).to_string()
+        } else {
+            raw
+        };
+        if let Some(ext) = extension.filter(|e| !e.is_empty()) {
+            if Path::new(&name).extension().is_none() {
+                name = format!("{}.{}", name, ext);
+            }
+        }
+        return name;
+    }
+
+    let base = base_name.unwrap_or(EXPORT_BLOB_MAGIC);
+    if raw.is_empty() {
+        format!("{}.part{}", base, index)
+    } else if base_name.is_some() {
+        // With a blob name Assimp stores the full auxiliary file name.
+        raw
+    } else {
+        // Without one it only stores what follows the first '.', e.g. "bin".
+        format!("{}.{}", base, raw)
+    }
 }
 
 #[derive(Debug)]
 struct ExportBlobInner {
     root: SharedPtr<sys::aiExportDataBlob>,
+    parts: Vec<(String, SharedPtr<sys::aiExportDataBlob>)>,
 }
 
 impl Drop for ExportBlobInner {
@@ -417,6 +777,25 @@ impl ExportFormatDesc {
             file_extension: crate::error::c_str_to_string_or_empty(desc.fileExtension),
         }
     }
+
+    /// The file extensions in [`ExportFormatDesc::file_extension`], without dots.
+    ///
+    /// Assimp's exporters register a single extension, but the field may list several
+    /// separated by spaces, commas or semicolons.
+    pub fn extensions(&self) -> impl Iterator<Item = &str> {
+        self.file_extension
+            .split([' ', ',', ';'])
+            .map(|ext| ext.trim_start_matches("*.").trim_start_matches('.'))
+            .filter(|ext| !ext.is_empty())
+    }
+
+    /// Whether `extension` (with or without a leading dot) is one of this format's
+    /// extensions, ignoring ASCII case.
+    pub fn matches_extension(&self, extension: &str) -> bool {
+        let extension = extension.trim_start_matches('.');
+        self.extensions()
+            .any(|ext| ext.eq_ignore_ascii_case(extension))
+    }
 }
 
 /// Main exporter interface
@@ -465,8 +844,7 @@ impl Exporter {
 
     /// Check if a format is supported for export
     pub fn is_format_supported<S: AsRef<str>>(&self, format_id: S) -> bool {
-        self.get_export_formats_iter()
-            .any(|desc| desc.id == format_id.as_ref())
+        is_format_available(format_id.as_ref())
     }
 }
 
@@ -476,12 +854,248 @@ impl Default for Exporter {
     }
 }
 
+/// Check whether an export format id is available in this build.
+///
+/// The set of exporters depends on how Assimp was compiled, so the [`format_ids`]
+/// constants are not all guaranteed to be present.
+pub fn is_format_available(id: &str) -> bool {
+    crate::get_export_formats_iter().any(|desc| desc.id == id)
+}
+
+/// Look up the description of an export format by id, e.g. to get its file extension.
+///
+/// ```rust,no_run
+/// use asset_importer::exporter::{export_format_desc, format_ids};
+///
+/// if let Some(desc) = export_format_desc(format_ids::GLB2) {
+///     let output = format!("scene.{}", desc.file_extension);
+///     # let _ = output;
+/// }
+/// ```
+pub fn export_format_desc(id: &str) -> Option<ExportFormatDesc> {
+    crate::get_export_formats_iter().find(|desc| desc.id == id)
+}
+
+/// How a file extension maps to export format ids.
+#[derive(Debug, Clone, Copy)]
+enum ExtensionFormats {
+    /// One exporter writes this extension.
+    Single(&'static str),
+    /// Several versions of a format; the newest comes first.
+    Versions(&'static [&'static str]),
+    /// ASCII and binary encodings of the same format.
+    Encodings {
+        ascii: &'static str,
+        binary: &'static str,
+    },
+}
+
+/// Preference table for [`format_for_path`], keyed by lowercase extension.
+const EXTENSION_FORMATS: &[(&str, ExtensionFormats)] = &[
+    ("dae", ExtensionFormats::Single(format_ids::COLLADA)),
+    ("x", ExtensionFormats::Single(format_ids::X)),
+    ("stp", ExtensionFormats::Single(format_ids::STEP)),
+    ("obj", ExtensionFormats::Single(format_ids::OBJ)),
+    (
+        "stl",
+        ExtensionFormats::Encodings {
+            ascii: format_ids::STL,
+            binary: format_ids::STL_BINARY,
+        },
+    ),
+    (
+        "ply",
+        ExtensionFormats::Encodings {
+            ascii: format_ids::PLY,
+            binary: format_ids::PLY_BINARY,
+        },
+    ),
+    ("3ds", ExtensionFormats::Single(format_ids::_3DS)),
+    (
+        "gltf",
+        ExtensionFormats::Versions(&[format_ids::GLTF2, format_ids::GLTF]),
+    ),
+    (
+        "glb",
+        ExtensionFormats::Versions(&[format_ids::GLB2, format_ids::GLB]),
+    ),
+    ("assbin", ExtensionFormats::Single(format_ids::ASSBIN)),
+    ("assxml", ExtensionFormats::Single(format_ids::ASSXML)),
+    ("x3d", ExtensionFormats::Single(format_ids::X3D)),
+    (
+        "fbx",
+        ExtensionFormats::Encodings {
+            ascii: format_ids::FBX_ASCII,
+            binary: format_ids::FBX,
+        },
+    ),
+    ("m3d", ExtensionFormats::Single(format_ids::M3D)),
+    ("a3d", ExtensionFormats::Single(format_ids::M3D_ASCII)),
+    ("3mf", ExtensionFormats::Single(format_ids::_3MF)),
+    ("pbrt", ExtensionFormats::Single(format_ids::PBRT)),
+    ("json", ExtensionFormats::Single(format_ids::ASSJSON)),
+];
+
+/// Pick the export format for an output file from its extension, ignoring case.
+///
+/// | Extension | Format |
+/// |-----------|--------|
+/// | `.glb` | `glb2` (`glb` if it is the only one available) |
+/// | `.gltf` | `gltf2` (`gltf` if it is the only one available) |
+/// | `.stl`, `.ply`, `.fbx` | the ASCII exporter (`stl`, `ply`, `fbxa`) |
+/// | `.obj` | `obj` (with a material library) |
+/// | `.dae` | `collada` |
+/// | `.json` | `assjson` |
+/// | others | the only available exporter registering that extension |
+///
+/// Returns `None` when no available exporter writes the extension, and when
+/// `prefer_latest` is off and several versions are available (`.gltf`, `.glb`). Use
+/// [`ExportBuilder::to_path_auto`] to get an error listing the candidates, or to pick
+/// binary encodings.
+///
+/// ```rust,no_run
+/// use asset_importer::exporter::format_for_path;
+/// use std::path::Path;
+///
+/// let desc = format_for_path(Path::new("scene.GLB"), true).expect("glb exporter");
+/// assert_eq!(desc.id, "glb2");
+/// ```
+pub fn format_for_path(path: &Path, prefer_latest: bool) -> Option<ExportFormatDesc> {
+    resolve_format_for_path(path, prefer_latest, false).ok()
+}
+
+fn resolve_format_for_path(
+    path: &Path,
+    prefer_latest: bool,
+    binary: bool,
+) -> Result<ExportFormatDesc> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .ok_or_else(|| {
+            Error::invalid_parameter(format!(
+                "Cannot choose an export format: '{}' has no file extension",
+                path.display()
+            ))
+        })?;
+    let available: Vec<ExportFormatDesc> = crate::get_export_formats_iter().collect();
+    let find = |id: &str| available.iter().find(|desc| desc.id == id).cloned();
+
+    let candidates: Vec<ExportFormatDesc> = match EXTENSION_FORMATS
+        .iter()
+        .find(|(ext, _)| *ext == extension)
+        .map(|(_, formats)| *formats)
+    {
+        Some(ExtensionFormats::Single(id)) => find(id).into_iter().collect(),
+        Some(ExtensionFormats::Encodings { ascii, binary: bin }) => {
+            let (preferred, other) = if binary { (bin, ascii) } else { (ascii, bin) };
+            // The other encoding still beats failing when the preferred one is missing.
+            find(preferred)
+                .or_else(|| find(other))
+                .into_iter()
+                .collect()
+        }
+        Some(ExtensionFormats::Versions(ids)) => {
+            let versions: Vec<ExportFormatDesc> = ids.iter().filter_map(|id| find(id)).collect();
+            if prefer_latest {
+                versions.into_iter().take(1).collect()
+            } else {
+                versions
+            }
+        }
+        None => available
+            .iter()
+            .filter(|desc| desc.matches_extension(&extension))
+            .cloned()
+            .collect(),
+    };
+
+    match candidates.len() {
+        0 => Err(Error::unsupported_format(format!(
+            "no exporter for '.{}' files is available",
+            extension
+        ))),
+        1 => Ok(candidates.into_iter().next().unwrap()),
+        _ => {
+            let ids: Vec<&str> = candidates.iter().map(|desc| desc.id.as_str()).collect();
+            Err(Error::invalid_parameter(format!(
+                "Export format for '.{}' is ambiguous; candidates: {} (enable prefer_latest or set the format explicitly)",
+                extension,
+                ids.join(", ")
+            )))
+        }
+    }
+}
+
+/// Ids of every exporter Assimp ships, for [`ExportBuilder::format`].
+///
+/// Which of these are usable depends on the Assimp build; see [`is_format_available`].
+pub mod format_ids {
+    /// COLLADA (`.dae`)
+    pub const COLLADA: &str = "collada";
+    /// DirectX text format (`.x`)
+    pub const X: &str = "x";
+    /// STEP file (`.stp`)
+    pub const STEP: &str = "stp";
+    /// Wavefront OBJ with a material library (`.obj` + `.mtl`)
+    pub const OBJ: &str = "obj";
+    /// Wavefront OBJ without a material library
+    pub const OBJ_NO_MTL: &str = "objnomtl";
+    /// ASCII STL (`.stl`)
+    pub const STL: &str = "stl";
+    /// Binary STL (`.stl`)
+    pub const STL_BINARY: &str = "stlb";
+    /// ASCII Stanford PLY (`.ply`)
+    pub const PLY: &str = "ply";
+    /// Binary Stanford PLY (`.ply`)
+    pub const PLY_BINARY: &str = "plyb";
+    /// 3D Studio (`.3ds`)
+    pub const _3DS: &str = "3ds";
+    /// glTF 2.0 JSON (`.gltf` + `.bin`)
+    pub const GLTF2: &str = "gltf2";
+    /// glTF 2.0 binary (`.glb`)
+    pub const GLB2: &str = "glb2";
+    /// glTF 1.0 JSON (`.gltf`)
+    pub const GLTF: &str = "gltf";
+    /// glTF 1.0 binary (`.glb`)
+    pub const GLB: &str = "glb";
+    /// Assimp binary dump (`.assbin`)
+    pub const ASSBIN: &str = "assbin";
+    /// Assimp XML dump (`.assxml`)
+    pub const ASSXML: &str = "assxml";
+    /// X3D (`.x3d`)
+    pub const X3D: &str = "x3d";
+    /// Binary FBX (`.fbx`)
+    pub const FBX: &str = "fbx";
+    /// ASCII FBX (`.fbx`)
+    pub const FBX_ASCII: &str = "fbxa";
+    /// Binary Model 3D (`.m3d`)
+    pub const M3D: &str = "m3d";
+    /// ASCII Model 3D (`.a3d`)
+    pub const M3D_ASCII: &str = "m3da";
+    /// 3D Manufacturing Format (`.3mf`)
+    pub const _3MF: &str = "3mf";
+    /// PBRT scene description (`.pbrt`)
+    pub const PBRT: &str = "pbrt";
+    /// Assimp JSON dump (`.json`)
+    pub const ASSJSON: &str = "assjson";
+
+    /// Every id above, in Assimp's registration order.
+    pub const ALL: &[&str] = &[
+        COLLADA, X, STEP, OBJ, OBJ_NO_MTL, STL, STL_BINARY, PLY, PLY_BINARY, _3DS, GLTF2, GLB2,
+        GLTF, GLB, ASSBIN, ASSXML, X3D, FBX, FBX_ASCII, M3D, M3D_ASCII, _3MF, PBRT, ASSJSON,
+    ];
+}
+
 /// Common export format identifiers
+///
+/// See [`format_ids`] for the complete list of Assimp exporter ids.
 pub mod formats {
     /// Wavefront OBJ format
     pub const OBJ: &str = "obj";
-    /// COLLADA format
-    pub const COLLADA: &str = "dae";
+    /// COLLADA format (`.dae`)
+    pub const COLLADA: &str = "collada";
     /// Stanford PLY format
     pub const PLY: &str = "ply";
     /// STL format
@@ -514,7 +1128,7 @@ mod tests {
         let buf = [0u64; 8];
         let unaligned =
             unsafe { (buf.as_ptr() as *const u8).add(1) } as *const sys::aiExportDataBlob;
-        assert!(ExportBlob::from_sys_ptr(unaligned).is_none());
+        assert!(ExportBlob::from_sys_ptr(unaligned, None, None).is_none());
     }
 
     #[test]
@@ -528,8 +1142,22 @@ mod tests {
     #[test]
     fn test_format_constants() {
         assert_eq!(formats::OBJ, "obj");
-        assert_eq!(formats::COLLADA, "dae");
+        assert_eq!(formats::COLLADA, format_ids::COLLADA);
         assert_eq!(formats::GLTF2, "gltf2");
+        // Every shorthand is a real exporter id.
+        for id in [
+            formats::OBJ,
+            formats::COLLADA,
+            formats::PLY,
+            formats::STL,
+            formats::GLTF2,
+            formats::GLB2,
+            formats::FBX,
+            formats::_3DS,
+            formats::X3D,
+        ] {
+            assert!(format_ids::ALL.contains(&id), "{id}");
+        }
     }
 
     #[cfg(feature = "export")]
@@ -20,16 +20,14 @@ pub(crate) fn slice_from_ptr_len<O: ?Sized, T>(owner: &O, ptr: *const T, len: us
         // `from_raw_parts` requires proper alignment for `T`. Assimp should return aligned
         // pointers for its allocations, but reject unaligned pointers to avoid UB when a
         // corrupted/malicious scene reports bogus addresses.
-        let align = std::mem::align_of::<T>();
-        if align > 1 && (ptr as usize) % align != 0 {
+        if !is_aligned(ptr) {
             return &[];
         }
 
         // `from_raw_parts` requires `len * size_of::<T>() <= isize::MAX`.
         // Assimp scenes should satisfy this, but we defensively clamp to avoid UB
         // if a corrupted/malicious scene ever reports an insane length.
-        let elem_size = std::mem::size_of::<T>();
-        if elem_size != 0 && len > (isize::MAX as usize) / elem_size {
+        if !len_in_bounds::<T>(len) {
             &[]
         } else {
             // SAFETY: The crate assumes `ptr` is valid for `len` elements of `T` when sourced
@@ -74,6 +72,83 @@ pub(crate) fn ptr_array_get<O: ?Sized, T>(
     if ptr.is_null() { None } else { Some(ptr) }
 }
 
+/// Front/back cursor for iterators over a `T**` pointer array that skip null entries.
+///
+/// The holes (null or misaligned entries, the same ones the wrappers' `SharedPtr`
+/// rejects) are counted once when the cursor is created, so the number of items left is
+/// exact and [`nth`](Self::nth) can jump straight to its index when the array has none.
+/// The `get` callbacks must yield `Some` for every other entry.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PtrArrayCursor {
+    front: usize,
+    back: usize,
+    holes: usize,
+}
+
+impl PtrArrayCursor {
+    /// Cursor over `len` entries at `base`; a null `base` yields an empty cursor.
+    pub(crate) fn new<O: ?Sized, T>(owner: &O, base: *const *const T, len: usize) -> Self {
+        let entries = slice_from_ptr_len(owner, base, len);
+        Self {
+            front: 0,
+            back: entries.len(),
+            holes: entries.iter().filter(|&&ptr| is_hole(ptr)).count(),
+        }
+    }
+
+    /// Number of usable entries not yet yielded from either end.
+    pub(crate) fn len(&self) -> usize {
+        (self.back - self.front).saturating_sub(self.holes)
+    }
+
+    /// Yield the first entry for which `get` returns `Some`, from the front.
+    pub(crate) fn next<T>(&mut self, mut get: impl FnMut(usize) -> Option<T>) -> Option<T> {
+        while self.front < self.back {
+            let index = self.front;
+            self.front += 1;
+            match get(index) {
+                Some(item) => return Some(item),
+                None => self.holes = self.holes.saturating_sub(1),
+            }
+        }
+        None
+    }
+
+    /// Yield the first entry for which `get` returns `Some`, from the back.
+    pub(crate) fn next_back<T>(&mut self, mut get: impl FnMut(usize) -> Option<T>) -> Option<T> {
+        while self.front < self.back {
+            self.back -= 1;
+            match get(self.back) {
+                Some(item) => return Some(item),
+                None => self.holes = self.holes.saturating_sub(1),
+            }
+        }
+        None
+    }
+
+    /// Skip `n` items and yield the next one.
+    pub(crate) fn nth<T>(
+        &mut self,
+        n: usize,
+        mut get: impl FnMut(usize) -> Option<T>,
+    ) -> Option<T> {
+        if self.holes == 0 {
+            self.front = self.front.saturating_add(n).min(self.back);
+        } else {
+            for _ in 0..n {
+                self.next(&mut get)?;
+            }
+        }
+        self.next(get)
+    }
+
+    /// `size_hint` for iterators driven by this cursor.
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
 /// Borrow a reference from a raw pointer.
 ///
 /// Returns `None` when `ptr` is null or not properly aligned for `T`.
@@ -87,8 +162,7 @@ pub(crate) fn ref_from_ptr<O: ?Sized, T>(owner: &O, ptr: *const T) -> Option<&T>
         return None;
     }
 
-    let align = std::mem::align_of::<T>();
-    if align > 1 && (ptr as usize) % align != 0 {
+    if !is_aligned(ptr) {
         return None;
     }
 
@@ -115,16 +189,14 @@ pub(crate) unsafe fn slice_from_mut_ptr_len<O: ?Sized, T>(
     } else {
         // `from_raw_parts_mut` requires proper alignment for `T`. Reject unaligned pointers to
         // avoid UB on corrupted/malicious inputs.
-        let align = std::mem::align_of::<T>();
-        if align > 1 && (ptr as usize) % align != 0 {
+        if !is_aligned(ptr.cast_const()) {
             return &mut [];
         }
 
         // `from_raw_parts_mut` requires `len * size_of::<T>() <= isize::MAX`.
         // As with immutable slices, clamp to avoid UB if a corrupted/malicious
         // scene ever reports an insane length.
-        let elem_size = std::mem::size_of::<T>();
-        if elem_size != 0 && len > (isize::MAX as usize) / elem_size {
+        if !len_in_bounds::<T>(len) {
             &mut []
         } else {
             unsafe { std::slice::from_raw_parts_mut(ptr, len) }
@@ -132,10 +204,120 @@ pub(crate) unsafe fn slice_from_mut_ptr_len<O: ?Sized, T>(
     }
 }
 
+/// Whether a pointer array entry is unusable: null or misaligned for `T`. Unlike
+/// [`is_aligned`] this does not report, since the entry is checked again when read.
+fn is_hole<T>(ptr: *const T) -> bool {
+    let align = std::mem::align_of::<T>();
+    ptr.is_null() || (align > 1 && (ptr as usize) % align != 0)
+}
+
+/// Whether `ptr` is aligned for `T`; with the `hardened` feature a misaligned pointer
+/// is also reported as a violation.
+fn is_aligned<T>(ptr: *const T) -> bool {
+    let align = std::mem::align_of::<T>();
+    let aligned = align <= 1 || (ptr as usize) % align == 0;
+    #[cfg(feature = "hardened")]
+    if !aligned {
+        crate::hardened::report(format_args!(
+            "pointer {:p} is not aligned for `{}`",
+            ptr,
+            std::any::type_name::<T>()
+        ));
+    }
+    aligned
+}
+
+/// Whether `len` elements of `T` fit `from_raw_parts`' `isize::MAX` byte limit and,
+/// with the `hardened` feature, the configured per-array cap.
+fn len_in_bounds<T>(len: usize) -> bool {
+    let elem_size = std::mem::size_of::<T>();
+    if elem_size != 0 && len > (isize::MAX as usize) / elem_size {
+        #[cfg(feature = "hardened")]
+        crate::hardened::report(format_args!(
+            "{} elements of `{}` exceed the address space",
+            len,
+            std::any::type_name::<T>()
+        ));
+        return false;
+    }
+    #[cfg(feature = "hardened")]
+    if !crate::hardened::check_len::<T>(len) {
+        return false;
+    }
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn ptr_array_cursor_skips_nulls_with_exact_len() {
+        let values = [10u32, 20, 30];
+        let ptrs: [*const u32; 5] = [
+            &values[0],
+            std::ptr::null(),
+            &values[1],
+            std::ptr::null(),
+            &values[2],
+        ];
+        let get = |index: usize| {
+            let ptr = ptrs[index];
+            (!ptr.is_null()).then(|| unsafe { *ptr })
+        };
+
+        let mut cursor = PtrArrayCursor::new(&(), ptrs.as_ptr(), ptrs.len());
+        assert_eq!(cursor.size_hint(), (3, Some(3)));
+        assert_eq!(cursor.next(get), Some(10));
+        assert_eq!(cursor.len(), 2);
+        assert_eq!(cursor.next_back(get), Some(30));
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(cursor.next_back(get), Some(20));
+        assert_eq!(cursor.len(), 0);
+        assert_eq!(cursor.next(get), None);
+
+        let mut cursor = PtrArrayCursor::new(&(), ptrs.as_ptr(), ptrs.len());
+        assert_eq!(cursor.nth(1, get), Some(20));
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(cursor.nth(5, get), None);
+
+        // Without holes `nth` jumps directly.
+        let dense: [*const u32; 3] = [&values[0], &values[1], &values[2]];
+        let mut cursor = PtrArrayCursor::new(&(), dense.as_ptr(), dense.len());
+        assert_eq!(
+            cursor.nth(2, |index| Some(unsafe { *dense[index] })),
+            Some(30)
+        );
+        assert_eq!(cursor.len(), 0);
+
+        let empty = PtrArrayCursor::new(&(), std::ptr::null::<*const u32>(), 4);
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn ptr_array_cursor_counts_misaligned_entries_as_holes() {
+        let values = [10u32, 20, 30];
+        let misaligned = unsafe { (values.as_ptr() as *const u8).add(1) } as *const u32;
+        let ptrs: [*const u32; 4] = [&values[0], misaligned, &values[1], &values[2]];
+        let get = |index: usize| {
+            crate::ptr::SharedPtr::new(ptrs[index]).map(|ptr| unsafe { *ptr.as_ptr() })
+        };
+
+        let mut cursor = PtrArrayCursor::new(&(), ptrs.as_ptr(), ptrs.len());
+        assert_eq!(cursor.len(), 3);
+        assert_eq!(cursor.nth(1, get), Some(20));
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(cursor.next(get), Some(30));
+        assert_eq!(cursor.len(), 0);
+
+        let mut cursor = PtrArrayCursor::new(&(), ptrs.as_ptr(), ptrs.len());
+        assert_eq!(cursor.next_back(get), Some(30));
+        assert_eq!(cursor.next_back(get), Some(20));
+        assert_eq!(cursor.len(), 1);
+        assert_eq!(cursor.next_back(get), Some(10));
+        assert_eq!(cursor.len(), 0);
+    }
+
     #[test]
     fn slice_helpers_return_empty_on_insane_lengths() {
         let too_large = (isize::MAX as usize) + 1;
@@ -234,4 +416,60 @@ mod tests {
 
         assert!(ref_from_ptr(owner, buf.as_ptr()).is_some());
     }
+
+    /// A mesh whose counts claim far more data than its arrays hold, as a corrupted
+    /// scene would. Only the pure slice helpers run, so this also works under Miri.
+    #[cfg(feature = "hardened")]
+    #[test]
+    fn hardened_rejects_absurd_mesh_counts() {
+        use crate::sys;
+
+        let mut vertices = [sys::aiVector3D {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }; 3];
+        let mut indices = [0u32, 1, 2];
+        let mut faces = [sys::aiFace {
+            mNumIndices: u32::MAX,
+            mIndices: indices.as_mut_ptr(),
+        }];
+        // SAFETY: `aiMesh` is plain C data; all-zero is a valid empty mesh.
+        let mut mesh: sys::aiMesh = unsafe { std::mem::zeroed() };
+        mesh.mNumVertices = u32::MAX;
+        mesh.mVertices = vertices.as_mut_ptr();
+        mesh.mNumFaces = u32::MAX;
+        mesh.mFaces = faces.as_mut_ptr();
+
+        let before = crate::hardened::violation_count();
+        let owner = &();
+        let positions = slice_from_ptr_len(owner, mesh.mVertices, mesh.mNumVertices as usize);
+        assert!(positions.is_empty());
+        let faces_view = slice_from_ptr_len(owner, mesh.mFaces, mesh.mNumFaces as usize);
+        assert!(faces_view.is_empty());
+        assert!(crate::hardened::violation_count() >= before + 2);
+
+        // The face array itself is fine once its count is sane, but the index count is not.
+        mesh.mNumFaces = 1;
+        let faces_view = slice_from_ptr_len(owner, mesh.mFaces, mesh.mNumFaces as usize);
+        assert_eq!(faces_view.len(), 1);
+        let face = &faces_view[0];
+        assert!(slice_from_ptr_len(owner, face.mIndices, face.mNumIndices as usize).is_empty());
+
+        // Counts within the cap still produce views.
+        mesh.mNumVertices = 3;
+        let positions = slice_from_ptr_len(owner, mesh.mVertices, mesh.mNumVertices as usize);
+        assert_eq!(positions.len(), 3);
+    }
+
+    #[cfg(feature = "hardened")]
+    #[test]
+    fn hardened_cap_is_configurable() {
+        let cap = crate::hardened::max_array_len();
+        assert!(crate::hardened::check_len::<u8>(cap));
+        crate::hardened::set_max_array_len(cap * 2);
+        assert!(crate::hardened::check_len::<u8>(cap + 1));
+        crate::hardened::set_max_array_len(cap);
+        assert!(!crate::hardened::check_len::<u8>(cap + 1));
+    }
 }
@@ -0,0 +1,62 @@
+//! Extra sanity checks on array counts read from Assimp scenes (requires the
+//! `hardened` feature).
+//!
+//! Every zero-copy view this crate hands out (`Mesh::vertices_raw`, `Face::indices_raw`,
+//! bone weights, animation keys, texture payloads, ...) is built from a pointer and a
+//! count stored in the scene. Normally those come from Assimp and are trusted. When
+//! loading untrusted files, a corrupted scene or an importer bug could pair a small
+//! allocation with a huge count; with this feature enabled, any count above
+//! [`max_array_len`] is rejected before a slice is formed. The affected accessor then
+//! returns an empty slice (or an error, for texture payloads), the violation is
+//! counted, and a warning is logged through `log`/`tracing` when those features are
+//! enabled.
+//!
+//! A cap cannot prove that an array is as long as its count claims, so this narrows
+//! rather than closes the gap; it turns absurd counts into empty views instead of
+//! reads far outside the allocation. Misaligned pointers are rejected in every build
+//! and are also reported here.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Default for [`max_array_len`]: 2^28 elements, far beyond any mesh Assimp can load
+/// into memory yet well below the counts a corrupted 32-bit field produces.
+pub const DEFAULT_MAX_ARRAY_LEN: usize = 1 << 28;
+
+static MAX_ARRAY_LEN: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_ARRAY_LEN);
+static VIOLATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Set the largest element count accepted for a single array (process-wide).
+pub fn set_max_array_len(len: usize) {
+    MAX_ARRAY_LEN.store(len, Ordering::Relaxed);
+}
+
+/// The largest element count accepted for a single array.
+pub fn max_array_len() -> usize {
+    MAX_ARRAY_LEN.load(Ordering::Relaxed)
+}
+
+/// Number of rejected arrays and pointers since the process started.
+pub fn violation_count() -> usize {
+    VIOLATIONS.load(Ordering::Relaxed)
+}
+
+/// Check `len` against the cap, reporting a violation when it is exceeded.
+pub(crate) fn check_len<T>(len: usize) -> bool {
+    let max = max_array_len();
+    if len <= max {
+        return true;
+    }
+    report(format_args!(
+        "{} elements of `{}` exceed the limit of {}",
+        len,
+        std::any::type_name::<T>(),
+        max
+    ));
+    false
+}
+
+/// Count and log a rejected array or pointer.
+pub(crate) fn report(message: std::fmt::Arguments<'_>) {
+    VIOLATIONS.fetch_add(1, Ordering::Relaxed);
+    crate::logging::warn_message(format_args!("hardened access: {}", message));
+}
@@ -6,31 +6,48 @@ use std::path::Path;
 use std::sync::Arc;
 
 use crate::{
-    error::{Error, Result},
-    io::{AssimpFileIO, FileSystem},
-    postprocess::PostProcessSteps,
-    progress::ProgressHandler,
+    config::{ConfigKey, ConfigValue},
+    error::{Error, ErrorContext, Operation, Result},
+    importer_desc::ImporterDesc,
+    io::{AssimpFileIO, FileSystem, MemorySourceFileSystem, OwnedAiFileIO, ReadGate},
+    logging::{LogCapture, LogLevel, VerboseScope},
+    mesh::PrimitiveTypes,
+    postprocess::{Components, PostProcessSteps},
+    preset::ImportPreset,
+    progress::{
+        CancellationToken, Deadline, ProgressDispatcher, ProgressEventHandler, ProgressHandler,
+    },
     scene::Scene,
     sys,
+    validation::{ValidationEntry, ValidationMode, ValidationReport, ValidationSeverity},
 };
 
 use crate::bridge_properties::build_rust_properties;
 
-type ProgressMutex = std::sync::Mutex<Box<dyn ProgressHandler>>;
+type ProgressMutex = std::sync::Mutex<ProgressDispatcher>;
 
-struct ProgressUser {
+/// Owns the dispatcher behind the `user` pointer handed to the bridge's progress
+/// callback (see [`progress_cb`]); shared with the exporter.
+pub(crate) struct ProgressUser {
     ptr: *mut ProgressMutex,
 }
 
 impl ProgressUser {
-    fn new(handler: Box<dyn ProgressHandler>) -> Self {
-        let ptr = Box::into_raw(Box::new(std::sync::Mutex::new(handler)));
+    pub(crate) fn new(dispatcher: ProgressDispatcher) -> Self {
+        let ptr = Box::into_raw(Box::new(std::sync::Mutex::new(dispatcher)));
         Self { ptr }
     }
 
-    fn as_void_ptr(&self) -> *mut c_void {
+    pub(crate) fn as_void_ptr(&self) -> *mut c_void {
         self.ptr.cast::<c_void>()
     }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        let mutex = unsafe { &*self.ptr };
+        mutex
+            .lock()
+            .map_or(true, |dispatcher| dispatcher.is_cancelled())
+    }
 }
 
 impl Drop for ProgressUser {
@@ -44,7 +61,20 @@ impl Drop for ProgressUser {
     }
 }
 
-extern "C" fn progress_cb(percentage: f32, message: *const c_char, user: *mut c_void) -> bool {
+/// The error for an import that stopped because it timed out or was cancelled.
+fn interruption_error(deadline: Option<&Deadline>, user: Option<&ProgressUser>) -> Option<Error> {
+    if let Some(deadline) = deadline.filter(|deadline| deadline.is_expired()) {
+        return Some(Error::timed_out(deadline.elapsed()));
+    }
+    user.is_some_and(ProgressUser::is_cancelled)
+        .then(Error::cancelled)
+}
+
+pub(crate) extern "C" fn progress_cb(
+    percentage: f32,
+    message: *const c_char,
+    user: *mut c_void,
+) -> bool {
     if user.is_null() {
         return true;
     }
@@ -63,10 +93,10 @@ extern "C" fn progress_cb(percentage: f32, message: *const c_char, user: *mut c_
 
     let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         let mutex = unsafe { &*user_ptr };
-        let Ok(mut handler) = mutex.lock() else {
+        let Ok(mut dispatcher) = mutex.lock() else {
             return false;
         };
-        handler.update(percentage, msg_opt)
+        dispatcher.dispatch(percentage, msg_opt)
     }));
     result.unwrap_or(false)
 }
@@ -148,6 +178,23 @@ impl PropertyStore {
         self
     }
 
+    /// Set a typed configuration key, validating the value.
+    ///
+    /// See [`crate::config`] for the available keys.
+    pub fn set<T: ConfigValue>(&mut self, key: ConfigKey<T>, value: T) -> Result<&mut Self> {
+        self.properties.push(key.property(value)?);
+        Ok(self)
+    }
+
+    /// Merge another store into this one. Keys present in `other` replace existing
+    /// entries with the same name (last wins).
+    pub fn merge(&mut self, other: &PropertyStore) -> &mut Self {
+        self.properties
+            .retain(|(name, _)| !other.properties.iter().any(|(n, _)| n == name));
+        self.properties.extend(other.properties.iter().cloned());
+        self
+    }
+
     /// Get all properties as a slice
     pub fn properties(&self) -> &[(String, PropertyValue)] {
         &self.properties
@@ -190,10 +237,14 @@ impl From<PropertyStore> for Vec<(String, PropertyValue)> {
 /// Common import property keys
 ///
 /// These constants provide convenient access to commonly used Assimp import properties.
+/// Prefer the typed keys in [`crate::config`], which cover every `AI_CONFIG_*` key.
 pub mod import_properties {
     /// Remove vertex components (AI_CONFIG_PP_RVC_FLAGS)
     pub const REMOVE_VERTEX_COMPONENTS: &str = "PP_RVC_FLAGS";
 
+    /// Primitive types removed by sorting by primitive type (AI_CONFIG_PP_SBP_REMOVE)
+    pub const SORT_BY_PTYPE_REMOVE: &str = "PP_SBP_REMOVE";
+
     /// Maximum smoothing angle for normal generation (AI_CONFIG_PP_CT_MAX_SMOOTHING_ANGLE)
     pub const MAX_SMOOTHING_ANGLE: &str = "PP_CT_MAX_SMOOTHING_ANGLE";
 
@@ -280,6 +331,10 @@ mod import_properties_tests {
             import_properties::REMOVE_VERTEX_COMPONENTS,
             c_key(crate::sys::AI_CONFIG_PP_RVC_FLAGS)
         );
+        assert_eq!(
+            import_properties::SORT_BY_PTYPE_REMOVE,
+            c_key(crate::sys::AI_CONFIG_PP_SBP_REMOVE)
+        );
         assert_eq!(
             import_properties::MAX_SMOOTHING_ANGLE,
             c_key(crate::sys::AI_CONFIG_PP_CT_MAX_SMOOTHING_ANGLE)
@@ -371,7 +426,41 @@ mod import_properties_tests {
     }
 }
 
+/// Name stem used for an in-memory source imported together with a custom [`FileSystem`].
+///
+/// The buffer is served to Assimp as `"{MEMORY_SOURCE_NAME}.{hint}"` (for example
+/// `$$$___magic___$$$.obj`), the same name Assimp uses for its own memory imports.
+/// Relative companion paths (MTL, .bin, textures) are resolved against the file
+/// system's root.
+pub const MEMORY_SOURCE_NAME: &str = "$$$___magic___$$$";
+
+/// Windows `MAX_PATH`; longer paths fail in Assimp's `fopen`-based default IO.
+#[cfg(not(target_family = "wasm"))]
+const WINDOWS_MAX_PATH: usize = 260;
+
+/// Whether `path` has to be opened from Rust instead of by Assimp's default IO.
+#[cfg(not(target_family = "wasm"))]
+fn needs_native_path_io(path: &Path) -> bool {
+    match path.to_str() {
+        None => true,
+        Some(s) => cfg!(windows) && (!s.is_ascii() || s.len() >= WINDOWS_MAX_PATH),
+    }
+}
+
 /// Builder for configuring and executing scene imports
+///
+/// ```rust,no_run
+/// use asset_importer::{Importer, postprocess::PostProcessSteps};
+///
+/// # fn load() -> asset_importer::Result<()> {
+/// let scene = Importer::new()
+///     .read_file("model.fbx")
+///     .with_post_process(PostProcessSteps::TRIANGULATE)
+///     .import()?;
+/// # let _ = scene;
+/// # Ok(())
+/// # }
+/// ```
 pub struct ImportBuilder {
     source_path: Option<std::path::PathBuf>,
     source_memory: Option<Arc<[u8]>>,
@@ -379,7 +468,122 @@ pub struct ImportBuilder {
     post_process: PostProcessSteps,
     properties: Vec<(String, PropertyValue)>,
     file_system: Option<std::sync::Arc<std::sync::Mutex<dyn FileSystem>>>,
-    progress_handler: Option<Box<dyn ProgressHandler>>,
+    progress_handler: Option<Box<dyn ProgressEventHandler>>,
+    progress_throttle: Option<std::time::Duration>,
+    cancellation: Option<CancellationToken>,
+    timeout: Option<std::time::Duration>,
+    log_capture: Option<LogLevel>,
+    validation: Option<ValidationMode>,
+    global_scale: bool,
+    armature_population: bool,
+    remove_components: bool,
+    remove_primitives: bool,
+    warn_on_limited_support: bool,
+    verbose_logging: bool,
+}
+
+/// Diagnostics collected by the `*_with_report` import methods.
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    /// Assimp log messages emitted during the import, in emission order, preceded by
+    /// the [`ImportBuilder::warn_on_limited_support`] warning when there is one.
+    pub messages: Vec<(LogLevel, String)>,
+    /// Wall-clock time spent in the import call.
+    pub duration: std::time::Duration,
+}
+
+impl ImportReport {
+    /// Messages at exactly the given level.
+    pub fn messages_at(&self, level: LogLevel) -> impl Iterator<Item = &str> {
+        self.messages
+            .iter()
+            .filter(move |(l, _)| *l == level)
+            .map(|(_, m)| m.as_str())
+    }
+
+    /// Whether any warning or error was logged.
+    pub fn has_warnings(&self) -> bool {
+        self.messages.iter().any(|(l, _)| *l >= LogLevel::Warn)
+    }
+}
+
+/// Turn the outcome of a validated import into the result for `mode`, using the
+/// validation lines among the captured `messages`.
+fn finish_validation(
+    mode: ValidationMode,
+    result: Result<Scene>,
+    messages: &[(LogLevel, String)],
+) -> Result<Scene> {
+    let mut report = ValidationReport::from_log(messages);
+    match result {
+        Ok(scene) => {
+            if mode == ValidationMode::Strict && !report.is_empty() {
+                return Err(Error::validation_failed(report));
+            }
+            scene.set_validation_report(report);
+            Ok(scene)
+        }
+        Err(err) => {
+            if !report.has_errors() {
+                // The failure may not have been logged; Assimp's error string still says
+                // whether validation caused it.
+                match ValidationEntry::parse(&err.message()) {
+                    Some(entry) if entry.severity == ValidationSeverity::Error => {
+                        report.entries.push(entry)
+                    }
+                    _ => return Err(err),
+                }
+            }
+            Err(Error::validation_failed(report))
+        }
+    }
+}
+
+/// Memory imports have no path; drop the internal name a file system overlay serves
+/// the buffer under.
+fn memory_import_error(err: Error) -> Error {
+    err.with_context(ErrorContext::new(Operation::Import))
+}
+
+/// The warning [`ImportBuilder::warn_on_limited_support`] emits for `desc`, if any.
+fn limited_support_warning(desc: &ImporterDesc) -> Option<String> {
+    let kind = match (desc.is_experimental(), desc.has_limited_support()) {
+        (true, true) => "is experimental and has limited support",
+        (true, false) => "is experimental",
+        (false, true) => "has limited support",
+        (false, false) => return None,
+    };
+    Some(format!(
+        "{} {} in Assimp; parts of the file may be ignored or imported incorrectly",
+        desc.name, kind
+    ))
+}
+
+fn warn_if_limited_support(desc: Option<ImporterDesc>) {
+    if let Some(warning) = desc.as_ref().and_then(limited_support_warning) {
+        crate::logging::warn_message(format_args!("{warning}"));
+    }
+}
+
+/// Remember which importer read `scene`: the one the bridge recorded for this thread's
+/// last import, else the one `fallback` finds from the file extension or hint.
+fn record_source_importer(
+    scene: &Scene,
+    used_bridge: bool,
+    fallback: impl FnOnce() -> Option<ImporterDesc>,
+) {
+    if used_bridge {
+        let mut index = 0usize;
+        if unsafe { sys::aiGetLastImporterIndexRust(&mut index) } == 1 {
+            if let Some(desc) = crate::importer_desc::importer_desc_at(index) {
+                scene.set_source_importer(desc, true);
+                return;
+            }
+        }
+    }
+    if let Some(desc) = fallback() {
+        scene.set_source_importer(desc, false);
+    }
 }
 
 /// Property values that can be set for import configuration
@@ -408,6 +612,17 @@ impl ImportBuilder {
             properties: Vec::new(),
             file_system: None,
             progress_handler: None,
+            progress_throttle: None,
+            cancellation: None,
+            timeout: None,
+            log_capture: None,
+            validation: None,
+            global_scale: false,
+            armature_population: false,
+            remove_components: false,
+            remove_primitives: false,
+            warn_on_limited_support: true,
+            verbose_logging: false,
         }
     }
 
@@ -458,8 +673,26 @@ impl ImportBuilder {
     }
 
     /// Set the post-processing steps to apply
+    ///
+    /// Keeps [`PostProcessSteps::GLOBAL_SCALE`] if [`ImportBuilder::with_global_scale`]
+    /// was called earlier, [`PostProcessSteps::POPULATE_ARMATURE_DATA`] if
+    /// [`ImportBuilder::with_armature_population`] enabled it,
+    /// [`PostProcessSteps::REMOVE_COMPONENT`] if [`ImportBuilder::remove_components`] did,
+    /// and [`PostProcessSteps::SORT_BY_PTYPE`] if [`ImportBuilder::remove_primitives`] did.
     pub fn with_post_process(mut self, steps: PostProcessSteps) -> Self {
         self.post_process = steps;
+        if self.global_scale {
+            self.post_process |= PostProcessSteps::GLOBAL_SCALE;
+        }
+        if self.armature_population {
+            self.post_process |= PostProcessSteps::POPULATE_ARMATURE_DATA;
+        }
+        if self.remove_components {
+            self.post_process |= PostProcessSteps::REMOVE_COMPONENT;
+        }
+        if self.remove_primitives {
+            self.post_process |= PostProcessSteps::SORT_BY_PTYPE;
+        }
         self
     }
 
@@ -512,6 +745,97 @@ impl ImportBuilder {
         self
     }
 
+    /// Uniformly scale the imported scene by `factor` (e.g. `0.01` to turn centimetres
+    /// into metres).
+    ///
+    /// Sets `AI_CONFIG_GLOBAL_SCALE_FACTOR_KEY` and enables
+    /// [`PostProcessSteps::GLOBAL_SCALE`], which Assimp needs before it reads the key.
+    /// The step stays enabled across later [`ImportBuilder::with_post_process`] calls.
+    /// `factor` should be positive; see [`crate::config::GLOBAL_SCALE_FACTOR`] for a
+    /// checked alternative.
+    pub fn with_global_scale(mut self, factor: f32) -> Self {
+        self.properties.push((
+            import_properties::GLOBAL_SCALE_FACTOR.to_string(),
+            PropertyValue::Float(factor),
+        ));
+        self.post_process |= PostProcessSteps::GLOBAL_SCALE;
+        self.global_scale = true;
+        self
+    }
+
+    /// Fill in [`Bone::node`](crate::bone::Bone::node) and
+    /// [`Bone::armature_node`](crate::bone::Bone::armature_node) during import.
+    ///
+    /// Enables [`PostProcessSteps::POPULATE_ARMATURE_DATA`], which stays enabled across
+    /// later [`ImportBuilder::with_post_process`] calls, and makes sure the FBX importer
+    /// reads skin weights ([`crate::config::FBX_READ_WEIGHTS`]), without which there
+    /// are no bones to populate. `false` removes the step again.
+    pub fn with_armature_population(mut self, enable: bool) -> Self {
+        self.armature_population = enable;
+        if enable {
+            self.post_process |= PostProcessSteps::POPULATE_ARMATURE_DATA;
+            self.properties.push((
+                import_properties::FBX_READ_WEIGHTS.to_string(),
+                PropertyValue::Boolean(true),
+            ));
+        } else {
+            self.post_process -= PostProcessSteps::POPULATE_ARMATURE_DATA;
+        }
+        self
+    }
+
+    /// Strip `components` from the imported scene.
+    ///
+    /// Sets [`crate::config::PP_RVC_FLAGS`] and enables
+    /// [`PostProcessSteps::REMOVE_COMPONENT`], without which Assimp ignores the flags.
+    /// The step stays enabled across later [`ImportBuilder::with_post_process`] calls.
+    /// An empty set removes the step again.
+    pub fn remove_components(mut self, components: Components) -> Self {
+        self.remove_components = !components.is_empty();
+        if self.remove_components {
+            self.properties.push((
+                import_properties::REMOVE_VERTEX_COMPONENTS.to_string(),
+                PropertyValue::Integer(components.bits() as i32),
+            ));
+            self.post_process |= PostProcessSteps::REMOVE_COMPONENT;
+        } else {
+            self.post_process -= PostProcessSteps::REMOVE_COMPONENT;
+        }
+        self
+    }
+
+    /// Drop meshes made of the given primitive types.
+    ///
+    /// Sets [`crate::config::PP_SBP_REMOVE`] and enables
+    /// [`PostProcessSteps::SORT_BY_PTYPE`], which splits mixed meshes by primitive type
+    /// and then removes the unwanted ones. The step stays enabled across later
+    /// [`ImportBuilder::with_post_process`] calls. An empty set clears the removal but
+    /// leaves the step mask alone, since sorting is useful on its own.
+    ///
+    /// `SORT_BY_PTYPE` runs after [`PostProcessSteps::TRIANGULATE`], so polygons are
+    /// already triangles by then: removing [`PrimitiveTypes::POLYGON`] has no effect
+    /// when triangulating, and removing [`PrimitiveTypes::TRIANGLE`] drops the
+    /// triangulated polygons too.
+    pub fn remove_primitives(mut self, types: PrimitiveTypes) -> Self {
+        self.remove_primitives = !types.is_empty();
+        self.properties.push((
+            import_properties::SORT_BY_PTYPE_REMOVE.to_string(),
+            PropertyValue::Integer(types.bits() as i32),
+        ));
+        if self.remove_primitives {
+            self.post_process |= PostProcessSteps::SORT_BY_PTYPE;
+        }
+        self
+    }
+
+    /// Set a typed configuration key (see [`crate::config`]).
+    ///
+    /// Returns an error if the value is outside the key's documented range.
+    pub fn with_config<T: ConfigValue>(mut self, key: ConfigKey<T>, value: T) -> Result<Self> {
+        self.properties.push(key.property(value)?);
+        Ok(self)
+    }
+
     /// Set properties from a PropertyStore
     pub fn with_property_store(mut self, store: PropertyStore) -> Self {
         self.properties.extend(store.properties);
@@ -524,6 +848,19 @@ impl ImportBuilder {
         self
     }
 
+    /// Apply an [`ImportPreset`]: its post-processing steps and import properties.
+    ///
+    /// The steps replace the current set like [`ImportBuilder::with_post_process`], so
+    /// adjust them with later `with_post_process`/`add_post_process` calls. The
+    /// preset's properties are placed before all properties set on the builder, so
+    /// user-specified values win regardless of call order.
+    pub fn with_preset(self, preset: ImportPreset) -> Self {
+        let (steps, store) = preset.settings();
+        let mut builder = self.with_post_process(steps);
+        builder.properties.splice(0..0, Vec::from(store));
+        builder
+    }
+
     /// Set a custom file system (ergonomic wrapper).
     ///
     /// Prefer this over [`ImportBuilder::with_file_system_shared`] unless you need to share a
@@ -545,11 +882,59 @@ impl ImportBuilder {
     }
 
     /// Set a progress handler
-    pub fn with_progress_handler(mut self, handler: Box<dyn ProgressHandler>) -> Self {
+    pub fn with_progress_handler(self, handler: Box<dyn ProgressHandler>) -> Self {
+        self.with_progress_event_handler(Box::new(handler))
+    }
+
+    /// Set a handler that receives parsed [`ProgressEvent`](crate::progress::ProgressEvent)s.
+    pub fn with_progress_event_handler(mut self, handler: Box<dyn ProgressEventHandler>) -> Self {
         self.progress_handler = Some(handler);
         self
     }
 
+    /// Set a progress event handler from a closure.
+    pub fn with_progress_event_fn<F>(self, f: F) -> Self
+    where
+        F: FnMut(&crate::progress::ProgressEvent) -> bool + Send + 'static,
+    {
+        self.with_progress_event_handler(Box::new(
+            crate::progress::ClosureProgressEventHandler::new(f),
+        ))
+    }
+
+    /// Forward progress updates at most once per `interval`.
+    ///
+    /// Updates arriving sooner are dropped (and do not cancel); the completion update
+    /// (`percent >= 1.0`) is always forwarded.
+    pub fn with_progress_throttle(mut self, interval: std::time::Duration) -> Self {
+        self.progress_throttle = Some(interval);
+        self
+    }
+
+    /// Fail the import with [`Error::Cancelled`] once `token` is cancelled.
+    ///
+    /// Lighter than a progress handler that returns `false`: no handler is called, the
+    /// token is checked at Assimp's progress checkpoints. A token cancelled before the
+    /// import starts fails it right away.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Fail the import with [`Error::TimedOut`] once it has run for `limit`.
+    ///
+    /// The clock is compared at every progress update, and a watchdog thread trips the
+    /// timeout for importers that report no progress for a long stretch. Assimp cannot
+    /// be stopped safely mid-parse, so the watchdog only raises a flag: the import
+    /// stops at the next progress update or, with
+    /// [`ImportBuilder::with_file_system`], the next file read (reads return nothing
+    /// once the flag is set). A parse that neither reports progress nor reads through
+    /// a custom file system runs to completion, and its scene is returned.
+    pub fn with_timeout(mut self, limit: std::time::Duration) -> Self {
+        self.timeout = Some(limit);
+        self
+    }
+
     /// Set a progress handler from a closure.
     pub fn with_progress_handler_fn<F>(self, f: F) -> Self
     where
@@ -558,11 +943,144 @@ impl ImportBuilder {
         self.with_progress_handler(Box::new(crate::progress::ClosureProgressHandler::new(f)))
     }
 
+    /// Capture Assimp log messages at `min_level` or above.
+    ///
+    /// Only the `*_with_report` methods return captured messages; defaults to
+    /// [`LogLevel::Warn`] when unset. Debug messages additionally require
+    /// [`ImportBuilder::with_verbose_logging`] or
+    /// [`enable_verbose_logging`](crate::enable_verbose_logging).
+    pub fn capture_log(mut self, min_level: LogLevel) -> Self {
+        self.log_capture = Some(min_level);
+        self
+    }
+
+    /// Make Assimp log debug messages during this import (default: off, which leaves the
+    /// process-wide [`enable_verbose_logging`](crate::enable_verbose_logging) setting in
+    /// charge).
+    ///
+    /// Assimp has one log severity for the whole process on both import paths, so it is
+    /// raised for the duration of the call and then restored. Overlapping verbose imports
+    /// are counted and the setting is restored when the last one finishes. Imports
+    /// running on other threads at the same time also log at debug level, but log
+    /// capture is per thread, so their reports only contain debug messages if they ask
+    /// for them with [`ImportBuilder::capture_log`].
+    pub fn with_verbose_logging(mut self, enable: bool) -> Self {
+        self.verbose_logging = enable;
+        self
+    }
+
+    /// Validate the imported data and report problems as a [`ValidationReport`].
+    ///
+    /// Sets or clears [`PostProcessSteps::VALIDATE_DATA_STRUCTURE`] at import time, so it
+    /// overrides the flag passed to [`ImportBuilder::with_post_process`]. In
+    /// [`ValidationMode::Warn`] the report is available from
+    /// [`Scene::validation_report`]; in [`ValidationMode::Strict`] any warning fails the
+    /// import. Validation errors always fail it with [`Error::ValidationFailed`].
+    pub fn with_validation(mut self, mode: ValidationMode) -> Self {
+        self.validation = Some(mode);
+        self
+    }
+
+    /// Warn when the importer for this file is flagged
+    /// [`LIMITED_SUPPORT`](crate::importer_desc::ImporterFlags::LIMITED_SUPPORT) or
+    /// [`EXPERIMENTAL`](crate::importer_desc::ImporterFlags::EXPERIMENTAL) (default: on).
+    ///
+    /// The importer is looked up from the file extension or memory hint before the
+    /// import, and the warning goes to `log` / `tracing` (target `"asset_importer"`).
+    /// The `*_with_report` methods also add it to [`ImportReport::messages`], naming the
+    /// importer recorded on the scene (see [`Scene::source_format`]).
+    pub fn warn_on_limited_support(mut self, enabled: bool) -> Self {
+        self.warn_on_limited_support = enabled;
+        self
+    }
+
+    /// Like [`ImportBuilder::import`], but also returns an [`ImportReport`].
+    pub fn import_with_report(self) -> Result<(Scene, ImportReport)> {
+        self.run_with_report(|builder| builder.import())
+    }
+
+    /// Like [`ImportBuilder::import_file`], but also returns an [`ImportReport`].
+    ///
+    /// Assimp's logger is global, but capture is routed per thread, so concurrent
+    /// imports on other threads do not leak messages into this report.
+    pub fn import_file_with_report<P: AsRef<Path>>(self, path: P) -> Result<(Scene, ImportReport)> {
+        self.run_with_report(|builder| builder.import_file(path))
+    }
+
+    /// Like [`ImportBuilder::import_from_memory`], but also returns an [`ImportReport`].
+    pub fn import_from_memory_with_report(
+        self,
+        data: &[u8],
+        hint: Option<&str>,
+    ) -> Result<(Scene, ImportReport)> {
+        self.run_with_report(|builder| builder.import_from_memory(data, hint))
+    }
+
+    fn run_with_report<F>(mut self, import: F) -> Result<(Scene, ImportReport)>
+    where
+        F: FnOnce(Self) -> Result<Scene>,
+    {
+        // Captures cannot nest, so validation shares this one and may widen its level.
+        let validation = self.take_validation();
+        let min_level = self.log_capture.unwrap_or(LogLevel::Warn);
+        let capture_level = match validation {
+            Some(_) => min_level.min(LogLevel::Warn),
+            None => min_level,
+        };
+        let warn_on_limited_support = self.warn_on_limited_support;
+        let capture = LogCapture::begin(capture_level);
+        let start = std::time::Instant::now();
+        let result = import(self);
+        let duration = start.elapsed();
+        let mut messages = capture.finish();
+        let result = match validation {
+            Some(mode) => finish_validation(mode, result, &messages),
+            None => result,
+        };
+        let scene = result?;
+        if warn_on_limited_support {
+            // Logged before Assimp ran, so it comes first.
+            if let Some(warning) = scene
+                .source_format()
+                .and_then(|info| limited_support_warning(&info.importer))
+            {
+                messages.insert(0, (LogLevel::Warn, warning));
+            }
+        }
+        messages.retain(|(level, _)| *level >= min_level);
+        Ok((scene, ImportReport { messages, duration }))
+    }
+
+    /// Take the validation mode and apply its post-process flag. Returns `None` when
+    /// validation is unset or off, i.e. when no log capture is needed.
+    fn take_validation(&mut self) -> Option<ValidationMode> {
+        let mode = self.validation.take()?;
+        self.post_process = mode.apply_to(self.post_process);
+        (mode != ValidationMode::Off).then_some(mode)
+    }
+
+    /// Run `import` with the configured validation; nested entry points see it unset.
+    fn run_validated<F>(mut self, import: F) -> Result<Scene>
+    where
+        F: FnOnce(Self) -> Result<Scene>,
+    {
+        let Some(mode) = self.take_validation() else {
+            return import(self);
+        };
+        let capture = LogCapture::begin(LogLevel::Warn);
+        let result = import(self);
+        let messages = capture.finish();
+        finish_validation(mode, result, &messages)
+    }
+
     /// Import using the configured source.
     ///
     /// This is the preferred ergonomic entry point when the source was set via
     /// [`Importer::read_file`], [`Importer::read_from_memory`], or the `with_source_*` methods.
     pub fn import(mut self) -> Result<Scene> {
+        if self.validation.is_some() {
+            return self.run_validated(|builder| builder.import());
+        }
         if self.source_path.is_some() && self.source_memory.is_some() {
             return Err(Error::invalid_parameter(
                 "Both file and memory sources are set; choose exactly one",
@@ -575,6 +1093,11 @@ impl ImportBuilder {
 
         if let Some(data) = self.source_memory.take() {
             let hint = self.source_memory_hint.take();
+            if self.file_system.is_some() {
+                return self
+                    .import_memory_with_file_system(data, hint.as_deref())
+                    .map_err(memory_import_error);
+            }
             return self.import_from_memory(data.as_ref(), hint.as_deref());
         }
 
@@ -584,13 +1107,56 @@ impl ImportBuilder {
     }
 
     /// Import a scene from a file path
+    ///
+    /// Prefer [`ImportBuilder::import`] after [`Importer::read_file`], which does not
+    /// need the path twice. If a different source path was stored, `path` wins (debug
+    /// builds assert that they match).
+    ///
+    /// Paths Assimp cannot open by name (not valid UTF-8, or on Windows non-ASCII or
+    /// longer than `MAX_PATH`) are opened through `std::fs` and fed to Assimp via the
+    /// custom IO callbacks, unless a custom file system was set.
+    ///
+    /// Errors carry the path (see [`Error::path`]).
     pub fn import_file<P: AsRef<Path>>(self, path: P) -> Result<Scene> {
-        let path_str = path.as_ref().to_string_lossy();
-        let c_path = CString::new(path_str.as_ref())
-            .map_err(|_| Error::invalid_parameter("Invalid file path"))?;
+        let path = path.as_ref();
+        self.import_path(path).map_err(|err| err.with_path(path))
+    }
+
+    fn import_path(mut self, path: &Path) -> Result<Scene> {
+        if self.validation.is_some() {
+            return self.run_validated(|builder| builder.import_path(path));
+        }
+        if let Some(stored) = self.source_path.take() {
+            debug_assert!(
+                stored == path,
+                "import_file({}) differs from the path passed to read_file ({})",
+                path.display(),
+                stored.display()
+            );
+        }
+        #[cfg(not(target_family = "wasm"))]
+        if self.file_system.is_none() && needs_native_path_io(path) {
+            return self.import_native_path(path);
+        }
 
+        let path_str = path.to_str().ok_or_else(|| {
+            Error::invalid_parameter(format!(
+                "File path is not valid UTF-8 and cannot be passed to a custom file system: {}",
+                path.display()
+            ))
+        })?;
+        let c_path =
+            CString::new(path_str).map_err(|_| Error::invalid_parameter("Invalid file path"))?;
+
+        if self.warn_on_limited_support {
+            warn_if_limited_support(crate::importer_desc::find_for_path(path));
+        }
+        let _verbose = self.verbose_logging.then(VerboseScope::begin);
+        self.check_cancelled()?;
+        let deadline = self.timeout.map(Deadline::start);
         // Determine if we will use the C++ bridge
-        let use_bridge = self.progress_handler.is_some();
+        let use_bridge =
+            self.progress_handler.is_some() || self.cancellation.is_some() || deadline.is_some();
 
         // Create property store only for the pure C API path
         let property_store = if use_bridge || self.properties.is_empty() {
@@ -600,11 +1166,18 @@ impl ImportBuilder {
         };
         let _property_store_guard = PropertyStoreGuard::new(property_store);
 
-        // Create custom file I/O if specified
+        // Create custom file I/O if specified; reads stop once the import is cancelled.
+        let read_gate = ReadGate::new(
+            self.cancellation
+                .iter()
+                .chain(deadline.as_ref().map(Deadline::token))
+                .cloned()
+                .collect(),
+        );
         let mut file_io = self
             .file_system
             .as_ref()
-            .map(|fs| AssimpFileIO::new(fs.clone()).create_ai_file_io());
+            .map(|fs| AssimpFileIO::new(fs.clone()).create_gated_ai_file_io(read_gate.clone()));
         let file_io_ptr_mut: *mut sys::aiFileIO = file_io
             .as_mut()
             .map_or(std::ptr::null_mut(), |io| io.as_mut_ptr_sys());
@@ -612,22 +1185,24 @@ impl ImportBuilder {
             .as_ref()
             .map_or(std::ptr::null(), |io| io.as_ptr_sys());
 
-        // If a progress handler is provided, use the C++ bridge to set it.
-        let scene_ptr = if use_bridge {
-            let handler = self
-                .progress_handler
-                .ok_or_else(|| Error::invalid_parameter("progress handler missing"))?;
+        // If a progress handler or cancellation token is provided, use the C++ bridge.
+        let progress_user = use_bridge.then(|| {
+            ProgressUser::new(
+                self.progress_dispatcher(Some(path_str.to_string()), deadline.clone()),
+            )
+        });
+        let watchdog = deadline.as_ref().map(Deadline::watchdog);
+        let scene_ptr = if let Some(user) = &progress_user {
             // Prepare property list for the bridge
             let buffers = build_rust_properties(&self.properties)?;
-            let user = ProgressUser::new(handler);
 
             unsafe {
                 sys::aiImportFileExWithProgressRust(
                     c_path.as_ptr(),
                     self.post_process.as_raw(),
                     file_io_ptr_const,
-                    buffers.ffi_props.as_ptr(),
-                    buffers.ffi_props.len(),
+                    buffers.as_ptr(),
+                    buffers.len(),
                     Some(progress_cb),
                     user.as_void_ptr(),
                 )
@@ -661,24 +1236,118 @@ impl ImportBuilder {
             }
         };
 
+        drop(watchdog);
+        // Assimp treats a file that fails to open like a missing one.
+        let open_error = file_io.as_ref().and_then(OwnedAiFileIO::take_open_error);
+
         // Check if import was successful
         if scene_ptr.is_null() {
+            if let Some(err) = interruption_error(deadline.as_ref(), progress_user.as_ref()) {
+                return Err(err);
+            }
+            if let Some(message) = open_error {
+                return Err(Error::io_error(message));
+            }
             if use_bridge {
                 return Err(Error::from_bridge_or_assimp());
             }
             return Err(Error::from_assimp());
         }
 
-        // Create safe wrapper (bridge import is deep-copied -> FreeScene; C API -> ReleaseImport)
-        if use_bridge {
+        // Create safe wrapper (bridge import hands over its scene -> FreeScene; C API -> ReleaseImport)
+        let scene = if use_bridge {
             unsafe { Scene::from_raw_copied_sys(scene_ptr) }
         } else {
             unsafe { Scene::from_raw_import_sys(scene_ptr) }
+        }?;
+        // A refused read may have left the parser with a truncated file.
+        if read_gate.refused() {
+            drop(scene);
+            return Err(
+                interruption_error(deadline.as_ref(), progress_user.as_ref())
+                    .unwrap_or_else(Error::cancelled),
+            );
+        }
+        if let Some(message) = open_error {
+            return Err(Error::io_error(message));
+        }
+        record_source_importer(&scene, use_bridge, || {
+            crate::importer_desc::find_for_path(path)
+        });
+        Ok(scene)
+    }
+
+    /// Import a scene from an [`std::io::Read`] stream (e.g. a network download).
+    ///
+    /// `hint` is the file extension (`"obj"`, `"glb"`, ...) and is required since there
+    /// is no file name. The stream is read to the end into a single buffer which is then
+    /// imported in place: Assimp's importers query the file size and read or seek over
+    /// the whole file up front, so pulling from the reader lazily would still buffer it
+    /// all. Use [`ImportBuilder::import_from_reader_sized`] when the length is known.
+    pub fn import_from_reader<R: std::io::Read>(self, reader: R, hint: &str) -> Result<Scene> {
+        self.import_from_reader_sized(reader, hint, 0)
+    }
+
+    /// Like [`ImportBuilder::import_from_reader`], pre-allocating `size_hint` bytes so a
+    /// stream of known length is read without reallocating.
+    pub fn import_from_reader_sized<R: std::io::Read>(
+        self,
+        mut reader: R,
+        hint: &str,
+        size_hint: usize,
+    ) -> Result<Scene> {
+        let hint = hint.trim_start_matches('.');
+        if hint.is_empty() {
+            return Err(Error::invalid_parameter(
+                "A file extension hint is required when importing from a reader",
+            ));
         }
+
+        let mut data = Vec::with_capacity(size_hint);
+        reader.read_to_end(&mut data).map_err(|e| {
+            Error::io_error_with_source("Failed to read import stream", e)
+                .with_operation(Operation::Import)
+        })?;
+        self.import_from_memory(&data, Some(hint))
     }
 
     /// Import a scene from memory buffer
+    ///
+    /// Prefer [`ImportBuilder::import`] after [`Importer::read_from_memory`]. If a
+    /// buffer was stored there, `data` wins (debug builds assert that they match).
+    ///
+    /// When a file system was set via [`ImportBuilder::with_file_system`], the buffer is
+    /// served under [`MEMORY_SOURCE_NAME`] and every other file Assimp opens (MTL,
+    /// glTF buffers, textures) goes through that file system. The served name needs an
+    /// extension, so the import fails if there is no `hint` and detection fails.
+    ///
+    /// With `hint: None` the format is guessed via [`detect_format`](crate::detect_format)
+    /// and its extension used as the hint. If detection finds nothing the buffer is
+    /// still handed to Assimp without a hint.
     pub fn import_from_memory(self, data: &[u8], hint: Option<&str>) -> Result<Scene> {
+        self.import_memory(data, hint).map_err(memory_import_error)
+    }
+
+    fn import_memory(mut self, data: &[u8], hint: Option<&str>) -> Result<Scene> {
+        if self.validation.is_some() {
+            return self.run_validated(|builder| builder.import_memory(data, hint));
+        }
+        if let Some(stored) = self.source_memory.take() {
+            debug_assert!(
+                *stored == *data,
+                "import_from_memory received a different buffer than read_from_memory"
+            );
+        }
+        if self.file_system.is_some() {
+            return self.import_memory_with_file_system(Arc::from(data), hint);
+        }
+
+        let detected = match hint {
+            Some(_) => None,
+            None => crate::detect::detect_format(data),
+        };
+        let hint = hint.or_else(|| detected.as_ref().map(|format| format.extension()));
+
         if data.len() > u32::MAX as usize {
             return Err(Error::invalid_parameter(
                 "Memory buffer is too large (assimp C API takes u32 length)".to_string(),
@@ -693,8 +1362,19 @@ impl ImportBuilder {
 
         let hint_ptr = hint_cstr.as_ref().map_or(std::ptr::null(), |s| s.as_ptr());
 
+        if self.warn_on_limited_support {
+            warn_if_limited_support(hint.and_then(|h| {
+                crate::importer_desc::get_importer_desc(&h.to_ascii_lowercase())
+                    .ok()
+                    .flatten()
+            }));
+        }
+        let _verbose = self.verbose_logging.then(VerboseScope::begin);
+        self.check_cancelled()?;
+        let deadline = self.timeout.map(Deadline::start);
         // Determine if we will use the C++ bridge
-        let use_bridge = self.progress_handler.is_some();
+        let use_bridge =
+            self.progress_handler.is_some() || self.cancellation.is_some() || deadline.is_some();
 
         // Create property store only for the pure C API path
         let property_store = if use_bridge || self.properties.is_empty() {
@@ -704,14 +1384,13 @@ impl ImportBuilder {
         };
         let _property_store_guard = PropertyStoreGuard::new(property_store);
 
-        // Import from memory (bridge if progress specified)
-        let scene_ptr = if use_bridge {
-            let handler = self
-                .progress_handler
-                .ok_or_else(|| Error::invalid_parameter("progress handler missing"))?;
+        // Import from memory (bridge if progress or cancellation specified)
+        let progress_user =
+            use_bridge.then(|| ProgressUser::new(self.progress_dispatcher(None, deadline.clone())));
+        let watchdog = deadline.as_ref().map(Deadline::watchdog);
+        let scene_ptr = if let Some(user) = &progress_user {
             // Prepare properties
             let buffers = build_rust_properties(&self.properties)?;
-            let user = ProgressUser::new(handler);
 
             unsafe {
                 sys::aiImportFileFromMemoryWithProgressRust(
@@ -719,8 +1398,8 @@ impl ImportBuilder {
                     data.len() as u32,
                     self.post_process.as_raw(),
                     hint_ptr,
-                    buffers.ffi_props.as_ptr(),
-                    buffers.ffi_props.len(),
+                    buffers.as_ptr(),
+                    buffers.len(),
                     Some(progress_cb),
                     user.as_void_ptr(),
                 )
@@ -746,21 +1425,100 @@ impl ImportBuilder {
             }
         };
 
+        drop(watchdog);
+
         // Check if import was successful
         if scene_ptr.is_null() {
+            if let Some(err) = interruption_error(deadline.as_ref(), progress_user.as_ref()) {
+                return Err(err);
+            }
             if use_bridge {
                 return Err(Error::from_bridge_or_assimp());
             }
             return Err(Error::from_assimp());
         }
 
-        if use_bridge {
+        let scene = if use_bridge {
             unsafe { Scene::from_raw_copied_sys(scene_ptr) }
         } else {
             unsafe { Scene::from_raw_import_sys(scene_ptr) }
+        }?;
+        record_source_importer(&scene, use_bridge, || {
+            hint.and_then(|h| {
+                crate::importer_desc::get_importer_desc(&h.to_ascii_lowercase())
+                    .ok()
+                    .flatten()
+            })
+        });
+        Ok(scene)
+    }
+
+    fn import_memory_with_file_system(
+        mut self,
+        data: Arc<[u8]>,
+        hint: Option<&str>,
+    ) -> Result<Scene> {
+        let Some(file_system) = self.file_system.take() else {
+            return Err(Error::invalid_parameter("file system missing"));
+        };
+        let detected = match hint {
+            Some(_) => None,
+            None => crate::detect::detect_format(&data),
+        };
+        let hint = hint
+            .or_else(|| detected.as_ref().map(|format| format.extension()))
+            .map(|h| h.trim_start_matches('.'))
+            .unwrap_or_default();
+        if hint.is_empty() || hint.contains(['/', '\\']) {
+            return Err(Error::invalid_parameter(
+                "A file extension hint is required when importing memory with a custom file system and the format cannot be detected",
+            ));
+        }
+
+        let name = format!("{}.{}", MEMORY_SOURCE_NAME, hint);
+        let overlay = MemorySourceFileSystem::new(name.clone(), data, file_system);
+        self.with_file_system(overlay).import_file(name)
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn import_native_path(self, path: &Path) -> Result<Scene> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .filter(|ext| ext.is_ascii());
+        let name = match (path.file_name().and_then(|n| n.to_str()), extension) {
+            (Some(file_name), _) if file_name.is_ascii() => file_name.to_string(),
+            (_, Some(ext)) => format!("{}.{}", MEMORY_SOURCE_NAME, ext),
+            (_, None) => MEMORY_SOURCE_NAME.to_string(),
+        };
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let file_system =
+            crate::io::NativePathFileSystem::new(name.clone(), path.to_path_buf(), dir);
+        self.with_file_system(file_system).import_file(name)
+    }
+
+    fn check_cancelled(&self) -> Result<()> {
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => Err(Error::cancelled()),
+            _ => Ok(()),
         }
     }
 
+    /// Dispatcher for the bridge's progress callback, taking the progress handler.
+    fn progress_dispatcher(
+        &mut self,
+        file: Option<String>,
+        deadline: Option<Deadline>,
+    ) -> ProgressDispatcher {
+        let dispatcher = match self.progress_handler.take() {
+            Some(handler) => ProgressDispatcher::new(handler, self.progress_throttle, file),
+            None => ProgressDispatcher::without_handler(),
+        };
+        dispatcher
+            .with_cancellation(self.cancellation.clone())
+            .with_deadline(deadline)
+    }
+
     /// Create a property store with the configured properties
     fn create_property_store(&self) -> *mut sys::aiPropertyStore {
         let store = unsafe { sys::aiCreatePropertyStore() };
@@ -780,7 +1538,11 @@ impl ImportBuilder {
                         sys::aiSetImportPropertyInteger(store, c_name.as_ptr(), *v);
                     }
                     PropertyValue::Float(v) => {
-                        sys::aiSetImportPropertyFloat(store, c_name.as_ptr(), *v);
+                        sys::aiSetImportPropertyFloat(
+                            store,
+                            c_name.as_ptr(),
+                            crate::types::to_real(*v),
+                        );
                     }
                     PropertyValue::String(v) => {
                         if let Ok(c_value) = CString::new(v.as_str()) {
@@ -812,25 +1574,7 @@ impl ImportBuilder {
                         );
                     }
                     PropertyValue::Matrix(v) => {
-                        // Convert glam Mat4 to aiMatrix4x4
-                        let ai_matrix = sys::aiMatrix4x4 {
-                            a1: v.x_axis.x,
-                            a2: v.y_axis.x,
-                            a3: v.z_axis.x,
-                            a4: v.w_axis.x,
-                            b1: v.x_axis.y,
-                            b2: v.y_axis.y,
-                            b3: v.z_axis.y,
-                            b4: v.w_axis.y,
-                            c1: v.x_axis.z,
-                            c2: v.y_axis.z,
-                            c3: v.z_axis.z,
-                            c4: v.w_axis.z,
-                            d1: v.x_axis.w,
-                            d2: v.y_axis.w,
-                            d3: v.z_axis.w,
-                            d4: v.w_axis.w,
-                        };
+                        let ai_matrix = crate::types::to_ai_matrix4x4(*v);
                         sys::aiSetImportPropertyMatrix(store, c_name.as_ptr(), &ai_matrix);
                     }
                 }
@@ -857,12 +1601,12 @@ impl Importer {
         Self
     }
 
-    /// Start building an import operation
+    /// Start building an import of `path`; finish with [`ImportBuilder::import`].
     pub fn read_file<P: AsRef<Path>>(&self, path: P) -> ImportBuilder {
         ImportBuilder::new().with_source_file(path)
     }
 
-    /// Start building an import operation from memory
+    /// Start building an import operation from memory; finish with [`ImportBuilder::import`].
     ///
     /// Note: this copies `data` into an owned buffer so the builder can be `'static`.
     pub fn read_from_memory(&self, data: &[u8]) -> ImportBuilder {
@@ -898,6 +1642,11 @@ impl Importer {
             .import()
     }
 
+    /// Quick import from an [`std::io::Read`] stream; see [`ImportBuilder::import_from_reader`].
+    pub fn import_from_reader<R: std::io::Read>(&self, reader: R, hint: &str) -> Result<Scene> {
+        ImportBuilder::new().import_from_reader(reader, hint)
+    }
+
     /// Import a file with a builder configuration closure.
     ///
     /// This avoids repeating the path and keeps call sites compact.
@@ -951,10 +1700,121 @@ impl Default for Importer {
     }
 }
 
+/// Async entry points that run the (blocking) Assimp import on Tokio's blocking pool.
+///
+/// These must be awaited inside a Tokio runtime. The builder only captures `Send` state
+/// (progress handlers and file systems are `Send` trait objects) and [`Scene`] is
+/// `Send + Sync`, so the future can be spawned and the scene handed back to any task.
+#[cfg(feature = "tokio")]
+impl ImportBuilder {
+    /// Run [`ImportBuilder::import`] via `tokio::task::spawn_blocking`.
+    ///
+    /// A panic inside the import is resumed on the awaiting task.
+    pub async fn import_async(self) -> Result<Scene> {
+        match tokio::task::spawn_blocking(move || self.import()).await {
+            Ok(result) => result,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(err) => Err(Error::other(format!("Import task failed: {}", err))),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Importer {
+    /// Import a file without blocking the async executor.
+    ///
+    /// ```rust,no_run
+    /// use asset_importer::{Importer, postprocess::PostProcessSteps};
+    ///
+    /// # async fn load() -> asset_importer::Result<()> {
+    /// let scene = Importer::new()
+    ///     .import_file_async("model.glb", PostProcessSteps::TRIANGULATE)
+    ///     .await?;
+    /// # let _ = scene;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn import_file_async<P: AsRef<Path>>(
+        &self,
+        path: P,
+        post_process: PostProcessSteps,
+    ) -> impl std::future::Future<Output = Result<Scene>> + Send + 'static {
+        self.read_file(path)
+            .with_post_process(post_process)
+            .import_async()
+    }
+
+    /// Import a memory buffer without blocking the async executor.
+    ///
+    /// Accepts `Vec<u8>`, `Box<[u8]>` or `Arc<[u8]>` without copying the bytes again.
+    /// A missing `hint` triggers format detection, as in [`ImportBuilder::import_from_memory`].
+    pub fn import_from_memory_async<D: Into<Arc<[u8]>>>(
+        &self,
+        data: D,
+        hint: Option<&str>,
+        post_process: PostProcessSteps,
+    ) -> impl std::future::Future<Output = Result<Scene>> + Send + 'static {
+        self.read_from_memory_shared(data.into())
+            .with_memory_hint_opt(hint)
+            .with_post_process(post_process)
+            .import_async()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn read_file_stores_the_source_for_import() {
+        let builder = Importer::new().read_file("tests/models/box.obj");
+        assert_eq!(
+            builder.source_path.as_deref(),
+            Some(Path::new("tests/models/box.obj"))
+        );
+        assert!(builder.source_memory.is_none());
+
+        let scene = builder.import().expect("import stored path");
+        assert_eq!(scene.num_meshes(), 1);
+    }
+
+    #[test]
+    fn read_from_memory_stores_the_source_for_import() {
+        let obj = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+        let builder = Importer::new()
+            .read_from_memory(obj)
+            .with_memory_hint("obj");
+        assert!(builder.source_path.is_none());
+        assert_eq!(builder.source_memory.as_deref(), Some(&obj[..]));
+
+        let scene = builder.import().expect("import stored buffer");
+        assert_eq!(scene.num_meshes(), 1);
+    }
+
+    #[test]
+    fn import_without_source_is_an_error() {
+        let err = ImportBuilder::new().import().expect_err("no source");
+        assert!(matches!(err, Error::InvalidParameter { .. }));
+    }
+
+    #[test]
+    fn explicit_path_matching_the_stored_one_is_accepted() {
+        let scene = Importer::new()
+            .read_file("tests/models/box.obj")
+            .import_file("tests/models/box.obj")
+            .expect("import");
+        assert_eq!(scene.num_meshes(), 1);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "differs from the path passed to read_file")]
+    fn explicit_path_differing_from_the_stored_one_asserts() {
+        let _ = Importer::new()
+            .read_file("tests/models/box.obj")
+            .import_file("tests/models/textured.obj");
+    }
+
     #[test]
     fn progress_cb_rejects_unaligned_user_pointers() {
         let buf = [0u64; 8];
@@ -6,7 +6,6 @@
 #![allow(clippy::unnecessary_cast)]
 
 use crate::{error::c_str_to_string_or_empty, ffi, sys};
-use std::ffi::CString;
 
 /// Flags indicating features common to many importers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -118,6 +117,32 @@ pub struct ImporterDesc {
 }
 
 impl ImporterDesc {
+    /// Whether the importer reads a text encoding of the format.
+    pub fn supports_text(&self) -> bool {
+        self.flags.contains(ImporterFlags::SUPPORT_TEXT_FLAVOUR)
+    }
+
+    /// Whether the importer reads a binary encoding of the format.
+    pub fn supports_binary(&self) -> bool {
+        self.flags.contains(ImporterFlags::SUPPORT_BINARY_FLAVOUR)
+    }
+
+    /// Whether the importer reads a compressed encoding of the format.
+    pub fn supports_compressed(&self) -> bool {
+        self.flags
+            .contains(ImporterFlags::SUPPORT_COMPRESSED_FLAVOUR)
+    }
+
+    /// Whether the importer only reads a subset of the format.
+    pub fn has_limited_support(&self) -> bool {
+        self.flags.contains(ImporterFlags::LIMITED_SUPPORT)
+    }
+
+    /// Whether Assimp marks the importer as experimental.
+    pub fn is_experimental(&self) -> bool {
+        self.flags.contains(ImporterFlags::EXPERIMENTAL)
+    }
+
     /// Create from raw Assimp importer description
     pub(crate) fn from_raw(desc: &sys::aiImporterDesc) -> Self {
         let name = c_str_to_string_or_empty(desc.mName);
@@ -148,6 +173,10 @@ impl ImporterDesc {
 
 /// Get importer description for a given file extension
 ///
+/// Several importers can claim the same extension; this returns the first entry of
+/// [`all_for_extension`], the one Assimp registered (and tries) first. The extension is
+/// matched the same way.
+///
 /// # Arguments
 /// * `extension` - File extension to look for (e.g., "obj", "fbx")
 ///
@@ -167,22 +196,21 @@ impl ImporterDesc {
 /// # Ok::<(), asset_importer::Error>(())
 /// ```
 pub fn get_importer_desc(extension: &str) -> crate::Result<Option<ImporterDesc>> {
-    let c_extension = CString::new(extension).map_err(|_| {
-        crate::Error::invalid_parameter("file extension contains NUL byte".to_string())
-    })?;
-
-    unsafe {
-        let desc_ptr = sys::aiGetImporterDesc(c_extension.as_ptr());
-        Ok(ffi::ref_from_ptr(&c_extension, desc_ptr).map(ImporterDesc::from_raw))
+    if extension.contains('\0') {
+        return Err(crate::Error::invalid_parameter(
+            "file extension contains NUL byte".to_string(),
+        ));
     }
+    Ok(all_for_extension(extension).into_iter().next())
 }
 
-/// Get importer description for a given file extension (zero allocation).
+/// Get importer description for a file extension given as a C string.
+///
+/// Same lookup as [`get_importer_desc`]; returns `None` for non-UTF-8 extensions.
 pub fn get_importer_desc_cstr(extension: &std::ffi::CStr) -> Option<ImporterDesc> {
-    unsafe {
-        let desc_ptr = sys::aiGetImporterDesc(extension.as_ptr());
-        ffi::ref_from_ptr(extension, desc_ptr).map(ImporterDesc::from_raw)
-    }
+    all_for_extension(extension.to_str().ok()?)
+        .into_iter()
+        .next()
 }
 
 /// Get descriptions of all available importers
@@ -216,6 +244,69 @@ pub fn get_all_importer_descs_iter() -> ImporterDescIterator {
     }
 }
 
+/// Every importer claiming `extension`, in Assimp's registration order.
+///
+/// Assimp tries importers in that order and uses the first that accepts the file, so
+/// the first entry is the one [`get_importer_desc`] returns.
+/// The extension may be given with or without a leading dot and is matched
+/// case-insensitively; an invalid extension (empty, or containing wildcards or
+/// whitespace) matches nothing.
+///
+/// # Example
+/// ```rust,no_run
+/// use asset_importer::importer_desc::all_for_extension;
+///
+/// for (i, desc) in all_for_extension("gltf").iter().enumerate() {
+///     println!("{i}: {}", desc.name);
+/// }
+/// ```
+pub fn all_for_extension(extension: &str) -> Vec<ImporterDesc> {
+    let Some(wanted) = crate::normalize_extension(extension) else {
+        return Vec::new();
+    };
+    get_all_importer_descs_iter()
+        .filter(|desc| {
+            desc.file_extensions
+                .iter()
+                .filter_map(|ext| crate::normalize_extension(ext))
+                .any(|ext| ext == wanted)
+        })
+        .collect()
+}
+
+/// Get the importer registered for the extension of `path`.
+///
+/// Only the extension is looked at (case-insensitively); the file is not opened. Returns
+/// `None` for paths without an extension or with an unsupported one.
+pub fn find_for_path<P: AsRef<std::path::Path>>(path: P) -> Option<ImporterDesc> {
+    let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+    get_importer_desc(&extension).ok().flatten()
+}
+
+/// Description of the importer at `index` in Assimp's importer list.
+pub(crate) fn importer_desc_at(index: usize) -> Option<ImporterDesc> {
+    let ptr = unsafe { sys::aiGetImportFormatDescription(index) };
+    ffi::ref_from_ptr(&index, ptr).map(ImporterDesc::from_raw)
+}
+
+/// Which importer read a scene, from [`Scene::source_format`](crate::Scene::source_format).
+#[derive(Debug, Clone)]
+pub struct SourceFormatInfo {
+    /// The importer that read the file
+    pub importer: ImporterDesc,
+    /// Format version recorded by the importer (`SourceAsset_FormatVersion`), if any
+    pub format_version: Option<String>,
+    /// Whether `importer` came from repeating Assimp's importer selection on the file.
+    ///
+    /// Imports that go through the C++ bridge (e.g. with a progress handler) re-run the
+    /// `CanRead` checks `ReadFile` uses once the import has finished. Assimp does not
+    /// report the importer it used, so this is the importer it would pick, not a record
+    /// of the one that ran; the two only differ if an importer's checks depend on state
+    /// that changed during the import. When `false`, `importer` is the one registered
+    /// for the file extension or memory hint.
+    pub exact: bool,
+}
+
 /// Iterator over importer descriptions.
 pub struct ImporterDescIterator {
     index: usize,
@@ -229,11 +320,8 @@ impl Iterator for ImporterDescIterator {
         while self.index < self.count {
             let i = self.index;
             self.index += 1;
-            unsafe {
-                let ptr = sys::aiGetImportFormatDescription(i);
-                if let Some(desc) = ffi::ref_from_ptr(self, ptr).map(ImporterDesc::from_raw) {
-                    return Some(desc);
-                }
+            if let Some(desc) = importer_desc_at(i) {
+                return Some(desc);
             }
         }
         None
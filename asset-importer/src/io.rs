@@ -7,14 +7,83 @@
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use std::ptr;
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicBool, Ordering},
+};
 
-use crate::{error::Result, ffi, sys};
+use crate::{error::Result, ffi, progress::CancellationToken, sys};
 
 type FileSystemHandle = Arc<Mutex<dyn FileSystem>>;
 
+/// First failure of one kind through an `aiFileIO`, shared by all of its open files.
+///
+/// Assimp exporters mostly ignore the byte counts returned by write callbacks, so a
+/// failed write would otherwise only show up as a truncated file; likewise a file that
+/// exists but fails to open on first read only surfaces as a vague parse error, or
+/// not at all for optional companion files.
+type ErrorSlot = Arc<Mutex<Option<String>>>;
+
+/// Tokens consulted before every open-for-read and read through an `aiFileIO`.
+///
+/// Once any token is cancelled, reads return nothing so an IO-bound parse fails
+/// quickly; the gate remembers that it refused one, as the parse may still have
+/// produced a (truncated) scene.
+#[derive(Clone, Default)]
+pub(crate) struct ReadGate {
+    tokens: Vec<CancellationToken>,
+    refused: Arc<AtomicBool>,
+}
+
+impl ReadGate {
+    pub(crate) fn new(tokens: Vec<CancellationToken>) -> Self {
+        Self {
+            tokens,
+            refused: Arc::default(),
+        }
+    }
+
+    fn allow(&self) -> bool {
+        if self.tokens.iter().any(CancellationToken::is_cancelled) {
+            self.refused.store(true, Ordering::Release);
+            return false;
+        }
+        true
+    }
+
+    /// Whether a read was refused.
+    pub(crate) fn refused(&self) -> bool {
+        self.refused.load(Ordering::Acquire)
+    }
+}
+
+/// What `aiFileIO::UserData` points to.
+struct FileIOState {
+    file_system: FileSystemHandle,
+    write_error: ErrorSlot,
+    open_error: ErrorSlot,
+    read_gate: ReadGate,
+}
+
+fn record_error(slot: &ErrorSlot, message: impl FnOnce() -> String) {
+    if let Ok(mut first) = slot.lock() {
+        if first.is_none() {
+            *first = Some(message());
+        }
+    }
+}
+
+fn record_write_error(slot: &ErrorSlot, path: &str, error: impl std::fmt::Display) {
+    record_error(slot, || format!("Failed to write '{}': {}", path, error));
+}
+
 #[inline]
-unsafe fn file_system_ptr(file_io: *mut sys::aiFileIO) -> Option<*const FileSystemHandle> {
+fn is_write_mode(mode: &str) -> bool {
+    !mode.starts_with('r') || mode.contains('+')
+}
+
+#[inline]
+unsafe fn file_io_state_ptr(file_io: *mut sys::aiFileIO) -> Option<*const FileIOState> {
     if file_io.is_null() {
         return None;
     }
@@ -22,12 +91,12 @@ unsafe fn file_system_ptr(file_io: *mut sys::aiFileIO) -> Option<*const FileSyst
     if align > 1 && (file_io as usize) % align != 0 {
         return None;
     }
-    let ptr = unsafe { (*file_io).UserData as *mut FileSystemHandle };
+    let ptr = unsafe { (*file_io).UserData as *mut FileIOState };
     if ptr.is_null() {
         return None;
     }
 
-    let align = std::mem::align_of::<FileSystemHandle>();
+    let align = std::mem::align_of::<FileIOState>();
     if align > 1 && (ptr as usize) % align != 0 {
         return None;
     }
@@ -41,9 +110,30 @@ fn catch_unwind_or<R: Copy>(default: R, f: impl FnOnce() -> R) -> R {
 }
 
 /// Trait for custom file I/O implementations
+///
+/// Assimp probes for companion files (textures, `.mtl`, `.bin`) by opening them. When
+/// it opens a file for reading, [`exists`](FileSystem::exists) is asked first and
+/// [`open_with_mode`](FileSystem::open_with_mode) is only called once Assimp reads or
+/// seeks (a size query is answered by [`size_hint`](FileSystem::size_hint)), so a
+/// probe that opens and closes a file costs one `exists` call. Files opened for
+/// writing are opened right away. A file that exists but then fails to open fails the
+/// import with that error.
 pub trait FileSystem: std::fmt::Debug + Send + Sync {
     /// Check if a file exists
-    fn exists(&self, path: &str) -> bool;
+    ///
+    /// The default implementation opens the file; override it when that is expensive.
+    fn exists(&self, path: &str) -> bool {
+        self.open(path).is_ok()
+    }
+
+    /// Size of a file in bytes without reading it, or `None` if it does not exist.
+    ///
+    /// Answers Assimp's size query for a read-mode file that has not been read yet, so
+    /// the query alone does not open it. The default implementation opens the file and
+    /// asks the stream; override it when a cheaper lookup exists.
+    fn size_hint(&self, path: &str) -> Option<u64> {
+        self.open(path).ok()?.size().ok()
+    }
 
     /// Open a file for reading
     fn open(&self, path: &str) -> Result<Box<dyn FileStream>>;
@@ -94,20 +184,43 @@ pub trait FileStream: Send {
     fn flush(&mut self) -> Result<()> {
         Ok(())
     }
+
+    /// Finish the stream; called once when Assimp closes the file.
+    ///
+    /// Errors from streams opened for writing fail the export that wrote them. The
+    /// default implementation flushes.
+    fn close(&mut self) -> Result<()> {
+        self.flush()
+    }
 }
 
 /// Default file system implementation using std::fs
+///
+/// Not available on `wasm` targets; use [`MemoryFileSystem`] there.
+#[cfg(not(target_family = "wasm"))]
 #[derive(Debug)]
 pub struct DefaultFileSystem;
 
+/// Wrap a failure to open `path`, keeping the I/O error as the source.
+#[cfg(not(target_family = "wasm"))]
+fn open_failed(path: impl AsRef<std::path::Path>, source: std::io::Error) -> crate::error::Error {
+    use crate::error::{Error, ErrorContext, Operation};
+    Error::io_error_with_source("Failed to open file", source)
+        .with_context(ErrorContext::new(Operation::Io).with_path(path.as_ref()))
+}
+
+#[cfg(not(target_family = "wasm"))]
 impl FileSystem for DefaultFileSystem {
     fn exists(&self, path: &str) -> bool {
         std::path::Path::new(path).exists()
     }
 
+    fn size_hint(&self, path: &str) -> Option<u64> {
+        std::fs::metadata(path).ok().map(|metadata| metadata.len())
+    }
+
     fn open(&self, path: &str) -> Result<Box<dyn FileStream>> {
-        let file =
-            std::fs::File::open(path).map_err(|e| crate::error::Error::io_error(e.to_string()))?;
+        let file = std::fs::File::open(path).map_err(|e| open_failed(path, e))?;
         Ok(Box::new(StdFileStream::new(file)))
     }
 
@@ -139,37 +252,43 @@ impl FileSystem for DefaultFileSystem {
             .truncate(truncate)
             .create(write || append);
 
-        let file = options
-            .open(path)
-            .map_err(|e| crate::error::Error::io_error(e.to_string()))?;
+        let file = options.open(path).map_err(|e| open_failed(path, e))?;
         Ok(Box::new(StdFileStream::new(file)))
     }
 }
 
 /// File stream implementation using std::fs::File
+#[cfg(not(target_family = "wasm"))]
 pub struct StdFileStream {
     file: std::fs::File,
 }
 
+#[cfg(not(target_family = "wasm"))]
 impl StdFileStream {
     fn new(file: std::fs::File) -> Self {
         Self { file }
     }
 }
 
+#[cfg(not(target_family = "wasm"))]
 impl FileStream for StdFileStream {
     fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
         use std::io::Read;
+        self.file.read(buffer).map_err(crate::error::Error::from)
+    }
+
+    fn write(&mut self, buffer: &[u8]) -> Result<usize> {
+        use std::io::Write;
         self.file
-            .read(buffer)
-            .map_err(|e| crate::error::Error::io_error(e.to_string()))
+            .write_all(buffer)
+            .map(|()| buffer.len())
+            .map_err(crate::error::Error::from)
     }
 
     fn tell(&self) -> Result<u64> {
         use std::io::Seek;
         let mut file = &self.file;
-        file.stream_position()
-            .map_err(|e| crate::error::Error::io_error(e.to_string()))
+        file.stream_position().map_err(crate::error::Error::from)
     }
 
     fn seek(&mut self, position: u64) -> Result<()> {
@@ -177,44 +296,73 @@ impl FileStream for StdFileStream {
         self.file
             .seek(SeekFrom::Start(position))
             .map(|_| ())
-            .map_err(|e| crate::error::Error::io_error(e.to_string()))
+            .map_err(crate::error::Error::from)
     }
 
     fn size(&self) -> Result<u64> {
         self.file
             .metadata()
             .map(|m| m.len())
-            .map_err(|e| crate::error::Error::io_error(e.to_string()))
+            .map_err(crate::error::Error::from)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        use std::io::Write;
+        self.file.flush().map_err(crate::error::Error::from)
     }
 }
 
+type MemoryFiles = Arc<Mutex<std::collections::HashMap<String, Arc<[u8]>>>>;
+
 /// Memory-based file system for testing or embedded resources
+///
+/// Files opened for writing (e.g. by [`ExportBuilder::with_file_system`]) are stored
+/// when they are flushed or closed, replacing any file with the same path.
+///
+/// [`ExportBuilder::with_file_system`]: crate::exporter::ExportBuilder::with_file_system
 #[derive(Debug)]
 pub struct MemoryFileSystem {
-    files: std::collections::HashMap<String, Arc<[u8]>>,
+    files: MemoryFiles,
 }
 
 impl MemoryFileSystem {
     /// Create a new memory file system
     pub fn new() -> Self {
         Self {
-            files: std::collections::HashMap::new(),
+            files: Arc::new(Mutex::new(std::collections::HashMap::new())),
         }
     }
 
+    fn files(&self) -> std::sync::MutexGuard<'_, std::collections::HashMap<String, Arc<[u8]>>> {
+        // Writers only insert whole buffers, so a poisoned map is still consistent.
+        self.files.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Add a file to the memory file system
     pub fn add_file<S: Into<String>>(&mut self, path: S, data: Vec<u8>) {
-        self.files.insert(path.into(), Arc::from(data));
+        self.files().insert(path.into(), Arc::from(data));
     }
 
     /// Add a file from a shared byte buffer.
     pub fn add_file_shared<S: Into<String>>(&mut self, path: S, data: Arc<[u8]>) {
-        self.files.insert(path.into(), data);
+        self.files().insert(path.into(), data);
+    }
+
+    /// Get the contents of a file, including files written through [`FileSystem::open_with_mode`].
+    pub fn file(&self, path: &str) -> Option<Arc<[u8]>> {
+        self.files().get(path).cloned()
+    }
+
+    /// Get the paths of all files, sorted.
+    pub fn file_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.files().keys().cloned().collect();
+        names.sort();
+        names
     }
 
     /// Get the number of files in the memory file system
     pub fn file_count(&self) -> usize {
-        self.files.len()
+        self.files().len()
     }
 }
 
@@ -226,12 +374,16 @@ impl Default for MemoryFileSystem {
 
 impl FileSystem for MemoryFileSystem {
     fn exists(&self, path: &str) -> bool {
-        self.files.contains_key(path)
+        self.files().contains_key(path)
+    }
+
+    fn size_hint(&self, path: &str) -> Option<u64> {
+        self.files().get(path).map(|data| data.len() as u64)
     }
 
     fn open(&self, path: &str) -> Result<Box<dyn FileStream>> {
-        if let Some(data) = self.files.get(path) {
-            Ok(Box::new(ReadOnlyMemoryFileStream::new(data.clone())))
+        if let Some(data) = self.file(path) {
+            Ok(Box::new(ReadOnlyMemoryFileStream::new(data)))
         } else {
             Err(crate::error::Error::file_error(format!(
                 "File not found: {}",
@@ -239,6 +391,196 @@ impl FileSystem for MemoryFileSystem {
             )))
         }
     }
+
+    fn open_with_mode(&self, path: &str, mode: &str) -> Result<Box<dyn FileStream>> {
+        if !is_write_mode(mode) {
+            return self.open(path);
+        }
+        let existing = self.file(path);
+        let stream = if mode.starts_with('w') {
+            MemoryFileStream::new_writable(0)
+        } else if mode.starts_with('a') {
+            let data = existing.map_or_else(Vec::new, |data| data.to_vec());
+            let position = data.len();
+            MemoryFileStream { data, position }
+        } else {
+            // "r+": the file must already exist.
+            let data = existing.ok_or_else(|| {
+                crate::error::Error::file_error(format!("File not found: {}", path))
+            })?;
+            MemoryFileStream::new(data.to_vec())
+        };
+        Ok(Box::new(MemoryWriteStream {
+            path: path.to_string(),
+            stream,
+            files: self.files.clone(),
+        }))
+    }
+}
+
+/// Writable stream of a [`MemoryFileSystem`] which stores its data on flush/close.
+struct MemoryWriteStream {
+    path: String,
+    stream: MemoryFileStream,
+    files: MemoryFiles,
+}
+
+impl FileStream for MemoryWriteStream {
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        self.stream.read(buffer)
+    }
+
+    fn write(&mut self, buffer: &[u8]) -> Result<usize> {
+        self.stream.write(buffer)
+    }
+
+    fn tell(&self) -> Result<u64> {
+        self.stream.tell()
+    }
+
+    fn seek(&mut self, position: u64) -> Result<()> {
+        self.stream.seek(position)
+    }
+
+    fn size(&self) -> Result<u64> {
+        self.stream.size()
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        let mut files = self
+            .files
+            .lock()
+            .map_err(|_| crate::error::Error::io_error("Memory file system lock poisoned"))?;
+        files.insert(self.path.clone(), Arc::from(self.stream.data()));
+        Ok(())
+    }
+}
+
+/// File system that serves one in-memory file under a fixed name and forwards
+/// every other request to an inner file system.
+///
+/// Used to import a memory buffer whose companion files (MTL, .bin, textures)
+/// live in a user-provided [`FileSystem`].
+#[derive(Debug)]
+pub(crate) struct MemorySourceFileSystem {
+    name: String,
+    data: Arc<[u8]>,
+    inner: FileSystemHandle,
+}
+
+impl MemorySourceFileSystem {
+    pub(crate) fn new(name: String, data: Arc<[u8]>, inner: FileSystemHandle) -> Self {
+        Self { name, data, inner }
+    }
+
+    fn is_source(&self, path: &str) -> bool {
+        let path = path
+            .strip_prefix("./")
+            .or_else(|| path.strip_prefix(".\\"))
+            .unwrap_or(path);
+        path == self.name
+    }
+
+    fn inner(&self) -> Result<std::sync::MutexGuard<'_, dyn FileSystem + 'static>> {
+        self.inner
+            .lock()
+            .map_err(|_| crate::error::Error::io_error("File system lock poisoned"))
+    }
+}
+
+impl FileSystem for MemorySourceFileSystem {
+    fn exists(&self, path: &str) -> bool {
+        self.is_source(path) || self.inner().is_ok_and(|fs| fs.exists(path))
+    }
+
+    fn size_hint(&self, path: &str) -> Option<u64> {
+        if self.is_source(path) {
+            return Some(self.data.len() as u64);
+        }
+        self.inner().ok()?.size_hint(path)
+    }
+
+    fn open(&self, path: &str) -> Result<Box<dyn FileStream>> {
+        self.open_with_mode(path, "rb")
+    }
+
+    fn open_with_mode(&self, path: &str, mode: &str) -> Result<Box<dyn FileStream>> {
+        if self.is_source(path) {
+            if is_write_mode(mode) {
+                return Err(crate::error::Error::io_error(
+                    "In-memory import source is read-only",
+                ));
+            }
+            return Ok(Box::new(ReadOnlyMemoryFileStream::new(self.data.clone())));
+        }
+        self.inner()?.open_with_mode(path, mode)
+    }
+
+    fn separator(&self) -> char {
+        self.inner()
+            .map_or(std::path::MAIN_SEPARATOR, |fs| fs.separator())
+    }
+}
+
+/// File system that opens files through `std::fs` relative to a native directory.
+///
+/// Used when a model path cannot be handed to Assimp as a string (not valid UTF-8,
+/// non-ASCII on Windows, or longer than `MAX_PATH`): Assimp is given `name`, which
+/// maps to `source`, and every companion file is resolved against `dir` with the
+/// original `OsStr` components intact.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub(crate) struct NativePathFileSystem {
+    name: String,
+    source: std::path::PathBuf,
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl NativePathFileSystem {
+    pub(crate) fn new(name: String, source: std::path::PathBuf, dir: std::path::PathBuf) -> Self {
+        Self { name, source, dir }
+    }
+
+    fn resolve(&self, path: &str) -> std::path::PathBuf {
+        let relative = path
+            .strip_prefix("./")
+            .or_else(|| path.strip_prefix(".\\"))
+            .unwrap_or(path);
+        if relative == self.name {
+            self.source.clone()
+        } else {
+            self.dir.join(relative)
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl FileSystem for NativePathFileSystem {
+    fn exists(&self, path: &str) -> bool {
+        self.resolve(path).exists()
+    }
+
+    fn size_hint(&self, path: &str) -> Option<u64> {
+        std::fs::metadata(self.resolve(path))
+            .ok()
+            .map(|metadata| metadata.len())
+    }
+
+    fn open(&self, path: &str) -> Result<Box<dyn FileStream>> {
+        self.open_with_mode(path, "rb")
+    }
+
+    fn open_with_mode(&self, path: &str, mode: &str) -> Result<Box<dyn FileStream>> {
+        if is_write_mode(mode) {
+            return Err(crate::error::Error::io_error(
+                "Native path file system is read-only",
+            ));
+        }
+        let resolved = self.resolve(path);
+        let file = std::fs::File::open(&resolved).map_err(|e| open_failed(&resolved, e))?;
+        Ok(Box::new(StdFileStream::new(file)))
+    }
 }
 
 /// Read-only memory file stream backed by a shared byte buffer.
@@ -376,20 +718,46 @@ pub struct AssimpFileIO {
 /// ensure it is released after the import/export call completes.
 pub struct OwnedAiFileIO {
     file_io: sys::aiFileIO,
+    #[cfg_attr(not(feature = "export"), allow(dead_code))]
+    write_error: ErrorSlot,
+    open_error: ErrorSlot,
 }
 
 impl OwnedAiFileIO {
-    fn new(file_system: Arc<Mutex<dyn FileSystem>>) -> Self {
-        let user_data = Box::into_raw(Box::new(file_system)) as *mut c_char;
+    fn new(file_system: Arc<Mutex<dyn FileSystem>>, read_gate: ReadGate) -> Self {
+        let write_error = ErrorSlot::default();
+        let open_error = ErrorSlot::default();
+        let state = FileIOState {
+            file_system,
+            write_error: write_error.clone(),
+            open_error: open_error.clone(),
+            read_gate,
+        };
+        let user_data = Box::into_raw(Box::new(state)) as *mut c_char;
         Self {
             file_io: sys::aiFileIO {
                 OpenProc: Some(file_open_proc),
                 CloseProc: Some(file_close_proc),
                 UserData: user_data,
             },
+            write_error,
+            open_error,
         }
     }
 
+    /// Take the first failure to open a read-mode file that [`FileSystem::exists`]
+    /// reported, which Assimp treats like a missing file.
+    pub(crate) fn take_open_error(&self) -> Option<String> {
+        self.open_error.lock().ok().and_then(|mut e| e.take())
+    }
+
+    /// Take the first error from opening, writing, flushing or closing a file for
+    /// writing, which Assimp itself may have ignored.
+    #[cfg(feature = "export")]
+    pub(crate) fn take_write_error(&self) -> Option<String> {
+        self.write_error.lock().ok().and_then(|mut e| e.take())
+    }
+
     /// Get a const pointer to the underlying `aiFileIO`.
     pub(crate) fn as_ptr_sys(&self) -> *const sys::aiFileIO {
         &self.file_io as *const _
@@ -416,9 +784,9 @@ impl OwnedAiFileIO {
 impl Drop for OwnedAiFileIO {
     fn drop(&mut self) {
         unsafe {
-            let ptr = self.file_io.UserData as *mut Arc<Mutex<dyn FileSystem>>;
+            let ptr = self.file_io.UserData as *mut FileIOState;
             if !ptr.is_null() {
-                let align = std::mem::align_of::<Arc<Mutex<dyn FileSystem>>>();
+                let align = std::mem::align_of::<FileIOState>();
                 if align <= 1 || (ptr as usize) % align == 0 {
                     drop(Box::from_raw(ptr));
                 }
@@ -436,13 +804,65 @@ impl AssimpFileIO {
 
     /// Create the aiFileIO structure for use with Assimp
     pub fn create_ai_file_io(&self) -> OwnedAiFileIO {
-        OwnedAiFileIO::new(self.file_system.clone())
+        OwnedAiFileIO::new(self.file_system.clone(), ReadGate::default())
+    }
+
+    /// Like [`AssimpFileIO::create_ai_file_io`], refusing reads once `read_gate` closes.
+    pub(crate) fn create_gated_ai_file_io(&self, read_gate: ReadGate) -> OwnedAiFileIO {
+        OwnedAiFileIO::new(self.file_system.clone(), read_gate)
     }
 }
 
 /// Internal structure to hold file stream data
 struct FileWrapper {
-    stream: Mutex<Box<dyn FileStream>>,
+    /// `None` until a read-mode file is first used, see [`FileWrapper::pending`].
+    stream: Mutex<Option<Box<dyn FileStream>>>,
+    /// File system and mode to open `path` with on first use.
+    pending: Option<(FileSystemHandle, String)>,
+    path: String,
+    /// Set for files opened for writing; failures are recorded here.
+    write_error: Option<ErrorSlot>,
+    /// Where a failure to open a deferred read-mode file is recorded.
+    open_error: ErrorSlot,
+    read_gate: ReadGate,
+}
+
+impl FileWrapper {
+    /// Open a deferred read-mode file; `None` (with the error recorded) if it cannot
+    /// be opened.
+    fn open_pending(&self) -> Option<Box<dyn FileStream>> {
+        let (file_system, mode) = self.pending.as_ref()?;
+        if !self.read_gate.allow() {
+            return None;
+        }
+        let opened = match file_system.lock() {
+            Ok(fs) => fs.open_with_mode(&self.path, mode),
+            Err(_) => Err(crate::error::Error::io_error("file system lock poisoned")),
+        };
+        opened
+            .map_err(|e| {
+                record_error(&self.open_error, || {
+                    format!("Failed to open '{}': {}", self.path, e)
+                })
+            })
+            .ok()
+    }
+
+    /// Size of a deferred read-mode file that is not open yet, from
+    /// [`FileSystem::size_hint`].
+    fn pending_size(&self) -> Option<u64> {
+        let (file_system, _) = self.pending.as_ref()?;
+        if self.stream.lock().ok()?.is_some() || !self.read_gate.allow() {
+            return None;
+        }
+        file_system.lock().ok()?.size_hint(&self.path)
+    }
+
+    fn record_write_error(&self, error: impl std::fmt::Display) {
+        if let Some(slot) = &self.write_error {
+            record_write_error(slot, &self.path, error);
+        }
+    }
 }
 
 #[inline]
@@ -468,10 +888,10 @@ unsafe fn file_wrapper_ptr(file: *mut sys::aiFile) -> Option<*const FileWrapper>
 }
 
 #[inline]
-fn with_stream<R: Copy>(
+fn with_wrapper<R: Copy>(
     file: *mut sys::aiFile,
     default: R,
-    f: impl FnOnce(&mut dyn FileStream) -> R,
+    f: impl FnOnce(&FileWrapper, &mut dyn FileStream) -> R,
 ) -> R {
     catch_unwind_or(default, || unsafe {
         let wrapper_ptr = file_wrapper_ptr(file).unwrap_or(std::ptr::null());
@@ -482,10 +902,25 @@ fn with_stream<R: Copy>(
         let Ok(mut stream) = wrapper.stream.lock() else {
             return default;
         };
-        f(&mut **stream)
+        if stream.is_none() {
+            *stream = wrapper.open_pending();
+        }
+        match stream.as_mut() {
+            Some(stream) => f(wrapper, &mut **stream),
+            None => default,
+        }
     })
 }
 
+#[inline]
+fn with_stream<R: Copy>(
+    file: *mut sys::aiFile,
+    default: R,
+    f: impl FnOnce(&mut dyn FileStream) -> R,
+) -> R {
+    with_wrapper(file, default, |_, stream| f(stream))
+}
+
 /// C callback for opening files
 extern "C" fn file_open_proc(
     file_io: *mut sys::aiFileIO,
@@ -497,11 +932,11 @@ extern "C" fn file_open_proc(
     }
 
     catch_unwind_or(ptr::null_mut(), || unsafe {
-        let file_system_ptr = file_system_ptr(file_io).unwrap_or(std::ptr::null());
-        if file_system_ptr.is_null() {
+        let state_ptr = file_io_state_ptr(file_io).unwrap_or(std::ptr::null());
+        if state_ptr.is_null() {
             return ptr::null_mut();
         }
-        let file_system = &*file_system_ptr;
+        let state = &*state_ptr;
 
         let filename_str = match CStr::from_ptr(filename).to_str() {
             Ok(s) => s,
@@ -512,16 +947,42 @@ extern "C" fn file_open_proc(
             Err(_) => return ptr::null_mut(),
         };
 
-        let stream = match file_system.lock() {
-            Ok(fs) => match fs.open_with_mode(filename_str, mode_str) {
-                Ok(stream) => stream,
-                Err(_) => return ptr::null_mut(),
-            },
-            Err(_) => return ptr::null_mut(),
+        // Failing to open a file for reading is routine (existence probes), so only
+        // write-mode failures are recorded.
+        let writable = is_write_mode(mode_str);
+        if !writable && !state.read_gate.allow() {
+            return ptr::null_mut();
+        }
+        let Ok(fs) = state.file_system.lock() else {
+            return ptr::null_mut();
         };
+        // Read-mode files are only checked for now: Assimp tests for existence by
+        // opening and closing, and the real open happens on first use.
+        let (stream, pending) = if writable {
+            match fs.open_with_mode(filename_str, mode_str) {
+                Ok(stream) => (Some(stream), None),
+                Err(e) => {
+                    record_write_error(&state.write_error, filename_str, e);
+                    return ptr::null_mut();
+                }
+            }
+        } else if fs.exists(filename_str) {
+            (
+                None,
+                Some((state.file_system.clone(), mode_str.to_string())),
+            )
+        } else {
+            return ptr::null_mut();
+        };
+        drop(fs);
 
         let wrapper = Box::new(FileWrapper {
             stream: Mutex::new(stream),
+            pending,
+            path: filename_str.to_string(),
+            write_error: writable.then(|| state.write_error.clone()),
+            open_error: state.open_error.clone(),
+            read_gate: state.read_gate.clone(),
         });
 
         let ai_file = Box::new(sys::aiFile {
@@ -552,7 +1013,12 @@ extern "C" fn file_close_proc(_file_io: *mut sys::aiFileIO, file: *mut sys::aiFi
     let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
         // Clean up the file wrapper
         if let Some(wrapper_ptr) = file_wrapper_ptr(file) {
-            let _ = Box::from_raw(wrapper_ptr as *mut FileWrapper);
+            let wrapper = Box::from_raw(wrapper_ptr as *mut FileWrapper);
+            if let Ok(mut stream) = wrapper.stream.lock() {
+                if let Some(Err(e)) = stream.as_mut().map(|stream| stream.close()) {
+                    wrapper.record_write_error(e);
+                }
+            }
         }
 
         // Clean up the aiFile
@@ -571,7 +1037,7 @@ extern "C" fn file_read_proc(
         return 0;
     }
 
-    with_stream(file, 0, |stream| {
+    with_wrapper(file, 0, |wrapper, stream| {
         let Some(total_bytes) = size.checked_mul(count) else {
             return 0;
         };
@@ -579,10 +1045,16 @@ extern "C" fn file_read_proc(
         let rust_buffer =
             unsafe { ffi::slice_from_mut_ptr_len(&mut owner, buffer as *mut u8, total_bytes) };
 
-        match stream.read(rust_buffer) {
-            Ok(bytes_read) => bytes_read.min(total_bytes) / size,
-            Err(_) => 0,
+        // Assimp reads with `fread` semantics, where a short count means end of file, so
+        // keep reading until the buffer is full. The gate is checked before every chunk.
+        let mut filled = 0;
+        while filled < total_bytes && wrapper.read_gate.allow() {
+            match stream.read(&mut rust_buffer[filled..]) {
+                Ok(0) | Err(_) => break,
+                Ok(bytes_read) => filled += bytes_read.min(total_bytes - filled),
+            }
         }
+        filled / size
     })
 }
 
@@ -597,8 +1069,9 @@ extern "C" fn file_write_proc(
         return 0;
     }
 
-    with_stream(file, 0, |stream| {
+    with_wrapper(file, 0, |wrapper, stream| {
         let Some(total_bytes) = size.checked_mul(count) else {
+            wrapper.record_write_error("write size overflow");
             return 0;
         };
 
@@ -610,8 +1083,19 @@ extern "C" fn file_write_proc(
         let data_slice = ffi::slice_from_ptr_len(owner, buffer as *const u8, total_bytes);
 
         match stream.write(data_slice) {
-            Ok(bytes_written) => bytes_written.min(total_bytes) / size,
-            Err(_) => 0,
+            Ok(bytes_written) => {
+                if bytes_written < total_bytes {
+                    wrapper.record_write_error(format!(
+                        "short write ({} of {} bytes)",
+                        bytes_written, total_bytes
+                    ));
+                }
+                bytes_written.min(total_bytes) / size
+            }
+            Err(e) => {
+                wrapper.record_write_error(e);
+                0
+            }
         }
     })
 }
@@ -634,6 +1118,14 @@ extern "C" fn file_size_proc(file: *mut sys::aiFile) -> usize {
         return 0;
     }
 
+    // Ask the file system while the file is unopened, so a size query does not open it.
+    let hinted = catch_unwind_or(None, || unsafe {
+        file_wrapper_ptr(file).and_then(|wrapper| (*wrapper).pending_size())
+    });
+    if let Some(size) = hinted {
+        return usize::try_from(size).unwrap_or(0);
+    }
+
     with_stream(file, 0, |stream| match stream.size() {
         Ok(size) => usize::try_from(size).unwrap_or(0),
         Err(_) => 0,
@@ -686,13 +1178,15 @@ extern "C" fn file_seek_proc(
 }
 
 /// C callback for flushing files (no-op for read-only streams)
-extern "C" fn file_flush_proc(_file: *mut sys::aiFile) {
-    if _file.is_null() {
+extern "C" fn file_flush_proc(file: *mut sys::aiFile) {
+    if file.is_null() {
         return;
     }
 
-    with_stream(_file, (), |stream| {
-        let _ = stream.flush();
+    with_wrapper(file, (), |wrapper, stream| {
+        if let Err(e) = stream.flush() {
+            wrapper.record_write_error(e);
+        }
     })
 }
 
@@ -704,7 +1198,7 @@ mod tests {
     fn io_callbacks_reject_unaligned_pointers() {
         let buf = [0u64; 8];
         let unaligned_io = unsafe { (buf.as_ptr() as *const u8).add(1) } as *mut sys::aiFileIO;
-        assert!(unsafe { file_system_ptr(unaligned_io) }.is_none());
+        assert!(unsafe { file_io_state_ptr(unaligned_io) }.is_none());
 
         let unaligned_file = unsafe { (buf.as_ptr() as *const u8).add(1) } as *mut sys::aiFile;
         assert!(unsafe { file_wrapper_ptr(unaligned_file) }.is_none());
@@ -714,13 +1208,14 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(target_family = "wasm"))]
     fn owned_file_io_drop_rejects_unaligned_userdata_pointers() {
         let fs: Arc<Mutex<dyn FileSystem>> = Arc::new(Mutex::new(DefaultFileSystem));
-        let mut owned = OwnedAiFileIO::new(fs);
+        let mut owned = OwnedAiFileIO::new(fs, ReadGate::default());
 
         // Free the real userdata first to avoid a leak, then poison it with an unaligned pointer
         // and ensure Drop does not attempt to `Box::from_raw` it.
-        let ptr = owned.file_io.UserData as *mut Arc<Mutex<dyn FileSystem>>;
+        let ptr = owned.file_io.UserData as *mut FileIOState;
         unsafe {
             drop(Box::from_raw(ptr));
         }
@@ -749,6 +1244,29 @@ mod tests {
         assert_eq!(buffer, test_data);
     }
 
+    #[test]
+    fn test_memory_file_system_write_modes() {
+        let fs = MemoryFileSystem::new();
+        assert!(fs.open_with_mode("out.bin", "r+b").is_err());
+
+        let mut stream = fs.open_with_mode("out.bin", "wb").unwrap();
+        stream.write(b"abc").unwrap();
+        assert!(!fs.exists("out.bin"), "data is stored on flush/close");
+        stream.close().unwrap();
+        assert_eq!(fs.file("out.bin").as_deref(), Some(&b"abc"[..]));
+
+        let mut stream = fs.open_with_mode("out.bin", "ab").unwrap();
+        stream.write(b"de").unwrap();
+        stream.flush().unwrap();
+        assert_eq!(fs.file("out.bin").as_deref(), Some(&b"abcde"[..]));
+
+        let mut stream = fs.open_with_mode("out.bin", "wt").unwrap();
+        stream.write(b"x").unwrap();
+        stream.close().unwrap();
+        assert_eq!(fs.file("out.bin").as_deref(), Some(&b"x"[..]));
+        assert_eq!(fs.file_names(), vec!["out.bin".to_string()]);
+    }
+
     #[test]
     fn memory_file_stream_write_rejects_position_overflow() {
         let mut stream = MemoryFileStream {
@@ -16,7 +16,7 @@
 //! ## Quick Start
 //!
 //! ```rust,no_run
-//! use asset_importer::{Importer, postprocess::PostProcessSteps};
+//! use asset_importer::prelude::*;
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let scene = Importer::new().import_file_with("model.fbx", |b| {
@@ -28,6 +28,10 @@
 //! # }
 //! ```
 //!
+//! The [`prelude`] brings in the importer, scene, mesh, material and node types and,
+//! with the `export` feature, the export builder. The same types are also re-exported
+//! at the crate root; more specialised ones live in their modules.
+//!
 //! ## Architecture
 //!
 //! This crate is built on top of `asset-importer-sys`, which provides the raw
@@ -45,8 +49,35 @@
 //! - `prebuilt`: download/use prebuilt Assimp binaries via `asset-importer-sys`
 //! - `build-assimp`: explicitly build Assimp from source via CMake
 //! - `system`: link against a system-installed Assimp (requires libclang/bindgen)
+//! - `wasm`: build bundled Assimp for `wasm32-unknown-emscripten` (see below)
 //!
 //! For the default source build, `asset-importer = "0.7"` is enough.
+//!
+//! ## WebAssembly
+//!
+//! `cargo build --target wasm32-unknown-emscripten --features wasm` builds Assimp with the
+//! Emscripten toolchain (activate the emsdk first so `EMSDK`/`EMSCRIPTEN` are set). That
+//! build has no exporters and no threads. On `wasm` targets there is no native file
+//! system, so the `std::fs`-backed helpers ([`io::DefaultFileSystem`],
+//! [`Scene::extract_textures_to_dir`], the file log stream) are not compiled. Import from
+//! bytes instead, with a [`io::MemoryFileSystem`] for companion files such as MTL
+//! libraries and textures:
+//!
+//! ```rust,no_run
+//! use asset_importer::{Importer, io::MemoryFileSystem};
+//!
+//! # fn load(obj: &[u8], mtl: &[u8]) -> asset_importer::Result<()> {
+//! let mut files = MemoryFileSystem::new();
+//! files.add_file("model.mtl", mtl.to_vec());
+//! let scene = Importer::new()
+//!     .read_from_memory(obj)
+//!     .with_memory_hint("obj")
+//!     .with_file_system(files)
+//!     .import()?;
+//! # let _ = scene;
+//! # Ok(())
+//! # }
+//! ```
 
 #![deny(unsafe_op_in_unsafe_fn)]
 #![warn(missing_docs)]
@@ -55,9 +86,11 @@
     all(feature = "prebuilt", feature = "build-assimp"),
     all(feature = "prebuilt", feature = "system"),
     all(feature = "build-assimp", feature = "system"),
+    all(feature = "wasm", feature = "prebuilt"),
+    all(feature = "wasm", feature = "system"),
 ))]
 compile_error!(
-    "Build mode features are mutually exclusive. Use at most one of: `prebuilt`, `build-assimp`, or `system`.\n\
+    "Build mode features are mutually exclusive. Use at most one of: `prebuilt`, `build-assimp`/`wasm`, or `system`.\n\
      Hint: default builds bundled Assimp from source; enable `prebuilt` only when you want matching release artifacts."
 );
 
@@ -70,16 +103,29 @@ pub(crate) use asset_importer_sys as sys;
 // Re-export common types for convenience
 pub use crate::{
     error::{Error, Result},
-    importer::{ImportBuilder, Importer, PropertyStore, PropertyValue, import_properties},
-    scene::{MemoryInfo, Scene},
+    importer::{
+        ImportBuilder, ImportReport, Importer, PropertyStore, PropertyValue, import_properties,
+    },
+    mesh::Mesh,
+    node::Node,
+    postprocess::PostProcessSteps,
+    preset::ImportPreset,
+    scene::{MemoryInfo, MeshInstance, OwnedScene, Scene, SceneFlags, extract::SubSceneExtractor},
     types::*,
+    validation::{ValidationEntry, ValidationMode, ValidationReport, ValidationSeverity},
 };
 
 /// Zero-copy raw view types for Assimp-owned data.
 pub mod raw;
 
 #[cfg(feature = "export")]
-pub use crate::exporter::{ExportBlob, ExportBuilder, ExportFormatDesc, export_properties};
+pub use crate::convert::{ConvertOptions, ConvertReport, convert};
+
+#[cfg(feature = "export")]
+pub use crate::exporter::{
+    BlobPart, ExportBlob, ExportBuilder, ExportFormatDesc, export_properties, format_for_path,
+    format_ids, is_format_available,
+};
 
 // Re-export logging functionality
 #[allow(deprecated)]
@@ -90,12 +136,16 @@ pub use crate::metadata::{Metadata, MetadataEntry, MetadataType};
 
 // Re-export material functionality
 pub use crate::material::{
-    Material, MaterialPropertyInfo, MaterialPropertyIterator, MaterialPropertyRef,
-    MaterialStringRef, PropertyTypeInfo, TextureInfo, TextureInfoRef, TextureType, material_keys,
+    Material, MaterialBuilder, MaterialPropertyInfo, MaterialPropertyIterator, MaterialPropertyRef,
+    MaterialStringRef, MaterialSummary, PathResolveOptions, PropertyTypeInfo, ResolvedPath,
+    TextureInfo, TextureInfoRef, TextureSlot, TextureType, material_keys,
 };
 
 // Re-export texture functionality
-pub use crate::texture::{Texel, Texture, TextureData, TextureIterator};
+pub use crate::texture::{
+    ExtractedTexture, Texel, TexelLayout, Texture, TextureData, TextureFormatHint, TextureIterator,
+    TextureNaming, TextureReference,
+};
 
 // Re-export AABB functionality
 pub use crate::aabb::AABB;
@@ -106,14 +156,26 @@ pub use crate::bone::{Bone, BoneIterator, VertexWeight};
 // Re-export animation type for convenience (used by examples)
 pub use crate::animation::Animation;
 
+// Re-export camera and light types
+pub use crate::{camera::Camera, light::Light};
+
 // Re-export importer description functionality
 pub use crate::importer_desc::{
-    ImporterDesc, ImporterDescIterator, ImporterFlags, get_all_importer_descs,
-    get_all_importer_descs_iter, get_importer_desc, get_importer_desc_cstr,
+    ImporterDesc, ImporterDescIterator, ImporterFlags, SourceFormatInfo, find_for_path,
+    get_all_importer_descs, get_all_importer_descs_iter, get_importer_desc, get_importer_desc_cstr,
 };
 
+// Re-export the format capability matrix
+pub use crate::capabilities::{FormatCapabilities, capabilities};
+
+// Re-export memory buffer format detection
+pub use crate::detect::{DetectedFormat, detect_format};
+
 // Core modules
 mod bridge_properties;
+pub mod capabilities;
+pub mod config;
+pub mod detect;
 pub mod error;
 pub(crate) mod ffi;
 pub mod importer;
@@ -135,17 +197,30 @@ pub mod bone;
 pub mod texture;
 
 // Advanced features
+#[cfg(feature = "assjson")]
+pub mod assjson;
+#[cfg(feature = "export")]
+pub mod convert;
 #[cfg(feature = "export")]
 pub mod exporter;
+#[cfg(feature = "hardened")]
+pub mod hardened;
 pub mod io;
 pub mod logging;
 pub mod metadata;
 pub mod progress;
+#[cfg(feature = "serde")]
+pub mod snapshot;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 
 // Utility modules
 pub mod math;
 pub mod postprocess;
+pub mod prelude;
+pub mod preset;
 pub mod utils;
+pub mod validation;
 
 mod ptr;
 
@@ -203,86 +278,264 @@ pub mod version {
     pub fn assimp_legal_string() -> String {
         unsafe { crate::error::c_str_to_string_or_empty(crate::sys::aiGetLegalString()) }
     }
+
+    bitflags::bitflags! {
+        /// Compile flags reported by `aiGetCompileFlags` (the `ASSIMP_CFLAGS_*` defines
+        /// in `assimp/version.h`).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct CompileFlags: u32 {
+            /// Assimp was built as a shared library.
+            const SHARED = 0x1;
+            /// Assimp was built against STLport.
+            const STLPORT = 0x2;
+            /// Assimp was built in debug mode.
+            const DEBUG = 0x4;
+            /// Assimp was built without Boost.
+            const NOBOOST = 0x8;
+            /// Assimp was built without threading support.
+            const SINGLETHREADED = 0x10;
+            /// Assimp was built with `ASSIMP_DOUBLE_PRECISION`, so `ai_real` is `f64`.
+            const DOUBLE_PRECISION = 0x20;
+        }
+    }
+
+    /// How the linked Assimp runtime was built, from [`assimp_build_info`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BuildInfo {
+        /// Major version.
+        pub major: u32,
+        /// Minor version.
+        pub minor: u32,
+        /// Patch version.
+        pub patch: u32,
+        /// Git revision (short commit hash as a number), or 0 when unknown.
+        pub revision: u32,
+        /// Branch the runtime was built from, if Assimp recorded one.
+        pub branch: Option<String>,
+        /// Raw compile flags.
+        pub flags: CompileFlags,
+        /// `ai_real` is `f64` rather than `f32` in this build.
+        pub double_precision: bool,
+        /// Assimp is a shared library rather than linked statically.
+        pub shared: bool,
+        /// Assimp is a debug build.
+        pub debug: bool,
+        /// Assimp was built without threading support.
+        pub single_threaded: bool,
+    }
+
+    impl BuildInfo {
+        /// `"major.minor.patch"`, as returned by [`assimp_version`].
+        pub fn version(&self) -> String {
+            format!("{}.{}.{}", self.major, self.minor, self.patch)
+        }
+    }
+
+    /// Collect version, branch and compile flags of the linked Assimp runtime.
+    ///
+    /// Flags the runtime reports that this crate does not know are kept in
+    /// [`BuildInfo::flags`] unchanged.
+    pub fn assimp_build_info() -> BuildInfo {
+        let flags = CompileFlags::from_bits_retain(assimp_compile_flags());
+        let branch = assimp_branch_name();
+        BuildInfo {
+            major: assimp_version_major(),
+            minor: assimp_version_minor(),
+            patch: assimp_version_patch(),
+            revision: assimp_version_revision(),
+            branch: (!branch.is_empty()).then_some(branch),
+            flags,
+            double_precision: flags.contains(CompileFlags::DOUBLE_PRECISION),
+            shared: flags.contains(CompileFlags::SHARED),
+            debug: flags.contains(CompileFlags::DEBUG),
+            single_threaded: flags.contains(CompileFlags::SINGLETHREADED),
+        }
+    }
 }
 
 /// Check if a file extension is supported for import.
+///
+/// Accepts `"obj"`, `".obj"`, `"*.obj"` and any capitalization; the extension is
+/// normalized before it is passed to Assimp. An empty extension is not supported.
 pub fn is_extension_supported(extension: &str) -> crate::Result<bool> {
-    let c_extension = std::ffi::CString::new(extension).map_err(|_| {
+    let Some(extension) = normalize_extension(extension) else {
+        return Ok(false);
+    };
+    let c_extension = std::ffi::CString::new(extension.as_bytes()).map_err(|_| {
         crate::Error::invalid_parameter("file extension contains NUL byte".to_string())
     })?;
     Ok(unsafe { crate::sys::aiIsExtensionSupported(c_extension.as_ptr()) != 0 })
 }
 
+/// Whether an importer compiled into this Assimp build handles `extension`.
+///
+/// Unlike [`is_extension_supported`] this walks the runtime importer descriptors, so it
+/// reflects importers that were compiled out (for example a build without IFC). If the
+/// runtime reports no importers at all, the well-known extensions of
+/// [`built_importers`] are used instead. The extension is normalized the same way;
+/// invalid input simply returns `false`.
+pub fn has_importer_for(extension: &str) -> bool {
+    let Some(wanted) = normalize_extension(extension) else {
+        return false;
+    };
+    let mut descs = crate::importer_desc::get_all_importer_descs_iter().peekable();
+    if descs.peek().is_none() {
+        return built_importers().is_some_and(|names| {
+            BUILT_IMPORTER_EXTENSIONS
+                .iter()
+                .filter(|(name, _)| names.contains(name))
+                .any(|(_, extensions)| extensions.contains(&wanted.as_ref()))
+        });
+    }
+    descs.any(|desc| {
+        desc.file_extensions
+            .iter()
+            .filter_map(|ext| normalize_extension(ext))
+            .any(|ext| ext == wanted)
+    })
+}
+
+/// Importers compiled into Assimp, by CMake name (`"OBJ"`, `"FBX"`, `"GLTF"`, ...).
+///
+/// Only known when `asset-importer-sys` built Assimp from source; it reads the
+/// `ASSIMP_BUILD_<NAME>_IMPORTER` options of that build. Returns `None` for system and
+/// prebuilt libraries, whose importers have to be queried at runtime with
+/// [`importer_desc::get_all_importer_descs`].
+pub fn built_importers() -> Option<&'static [&'static str]> {
+    crate::sys::BUILT_FORMATS_KNOWN.then_some(crate::sys::BUILT_IMPORTERS)
+}
+
+/// Exporters compiled into Assimp, by CMake name; `None` unless Assimp was built from
+/// source (see [`built_importers`]).
+pub fn built_exporters() -> Option<&'static [&'static str]> {
+    crate::sys::BUILT_FORMATS_KNOWN.then_some(crate::sys::BUILT_EXPORTERS)
+}
+
+/// Extensions of common importers by CMake name, for [`has_importer_for`] when the runtime
+/// reports nothing.
+const BUILT_IMPORTER_EXTENSIONS: [(&str, &[&str]); 20] = [
+    ("OBJ", &[".obj"]),
+    ("FBX", &[".fbx"]),
+    ("COLLADA", &[".dae", ".zae"]),
+    ("GLTF", &[".gltf", ".glb"]),
+    ("3DS", &[".3ds", ".prj"]),
+    ("BLEND", &[".blend"]),
+    ("X", &[".x"]),
+    ("PLY", &[".ply"]),
+    ("STL", &[".stl"]),
+    ("MD2", &[".md2"]),
+    ("MD3", &[".md3"]),
+    ("MD5", &[".md5mesh", ".md5anim", ".md5camera"]),
+    ("ASE", &[".ase", ".ask"]),
+    ("IFC", &[".ifc", ".ifczip"]),
+    ("OFF", &[".off"]),
+    ("AMF", &[".amf"]),
+    ("3MF", &[".3mf"]),
+    ("X3D", &[".x3d", ".x3db"]),
+    ("LWO", &[".lwo", ".lxo"]),
+    ("USD", &[".usd", ".usda", ".usdc", ".usdz"]),
+];
+
+/// Whether this Assimp build can export to `format_id` (for example `"obj"` or
+/// `"gltf2"`), compared case-insensitively against the runtime exporter list.
+#[cfg(feature = "export")]
+pub fn has_exporter_for(format_id: &str) -> bool {
+    let format_id = format_id.trim();
+    !format_id.is_empty()
+        && get_export_formats_iter().any(|desc| desc.id.eq_ignore_ascii_case(format_id))
+}
+
 const FALLBACK_IMPORT_EXTENSIONS: [&str; 15] = [
     ".obj", ".fbx", ".dae", ".gltf", ".glb", ".3ds", ".blend", ".x", ".ply", ".stl", ".md2",
     ".md3", ".md5", ".ase", ".ifc",
 ];
 
+/// Normalize one extension-list entry to `".ext"` form.
+///
+/// Surrounding whitespace and a leading `*` and dots are removed and the rest is
+/// lowercased, keeping inner dots (`"*.MESH.XML"` becomes `".mesh.xml"`). Returns `None`
+/// for empty entries and entries that are still not a plain extension, such as `"*.*"`.
+fn normalize_extension(entry: &str) -> Option<std::borrow::Cow<'_, str>> {
+    let trimmed = entry.trim();
+    let trimmed = trimmed.strip_prefix('*').unwrap_or(trimmed);
+    let body = trimmed.trim_start_matches('.');
+    if body.is_empty()
+        || body.ends_with('.')
+        || body.contains("..")
+        || body.contains(|c: char| c == '*' || c == '?' || c.is_whitespace())
+    {
+        return None;
+    }
+    let has_dot = trimmed.len() > body.len();
+    if has_dot && !body.contains(|c: char| c.is_ascii_uppercase()) {
+        Some(std::borrow::Cow::Borrowed(
+            &trimmed[trimmed.len() - body.len() - 1..],
+        ))
+    } else {
+        Some(std::borrow::Cow::Owned(format!(
+            ".{}",
+            body.to_ascii_lowercase()
+        )))
+    }
+}
+
 /// An allocation-minimized import extension list.
 ///
-/// This keeps the raw Assimp extension list string and provides an iterator over `&str` views
-/// (e.g. `".obj"`), avoiding per-extension allocations.
+/// This keeps the raw Assimp extension list string and provides an iterator over
+/// normalized `".ext"` entries, borrowing from the raw string whenever the entry is
+/// already in that form.
 #[derive(Debug, Clone)]
 pub struct ImportExtensions {
     raw: Option<String>,
 }
 
-#[derive(Debug)]
-enum ImportExtensionsIterInner<'a> {
-    Assimp(std::str::Split<'a, char>),
-    Fallback(std::slice::Iter<'a, &'static str>),
-}
-
 /// Iterator over supported import extensions.
 #[derive(Debug)]
 pub struct ImportExtensionsIter<'a> {
-    inner: ImportExtensionsIterInner<'a>,
+    split: Option<std::str::Split<'a, char>>,
 }
 
 impl<'a> Iterator for ImportExtensionsIter<'a> {
-    type Item = &'a str;
+    type Item = std::borrow::Cow<'a, str>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            match &mut self.inner {
-                ImportExtensionsIterInner::Assimp(split) => {
-                    let ext = split.next()?;
-                    let trimmed = ext.trim();
-                    if trimmed.starts_with("*.") && trimmed.len() > 1 {
-                        return Some(&trimmed[1..]);
-                    }
-                }
-                ImportExtensionsIterInner::Fallback(iter) => {
-                    let s: &'static str = iter.next()?;
-                    return Some(s);
-                }
-            }
-        }
+        let split = self.split.as_mut()?;
+        split.find_map(normalize_extension)
     }
 }
 
 impl ImportExtensions {
+    /// Wrap an extension list in Assimp's `"*.3ds;*.obj;*.dae"` format.
+    pub fn from_assimp_list<S: Into<String>>(raw: S) -> Self {
+        let raw = raw.into();
+        Self {
+            raw: (!raw.trim().is_empty()).then_some(raw),
+        }
+    }
+
     /// Raw Assimp extension list, if available (e.g. `"*.3ds;*.obj;*.dae"`).
     pub fn raw_assimp_list(&self) -> Option<&str> {
         self.raw.as_deref()
     }
 
-    /// Iterate extensions as `".ext"` strings (without allocation).
+    /// Whether Assimp reported no extensions.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Iterate extensions as lowercase `".ext"` strings.
+    ///
+    /// Entries are split on `;` and normalized; empty and malformed entries are
+    /// skipped, and multi-dot extensions such as `".mesh.xml"` are kept whole.
     pub fn iter(&self) -> ImportExtensionsIter<'_> {
-        if let Some(s) = self.raw.as_deref() {
-            ImportExtensionsIter {
-                inner: ImportExtensionsIterInner::Assimp(s.split(';')),
-            }
-        } else {
-            ImportExtensionsIter {
-                inner: ImportExtensionsIterInner::Fallback(FALLBACK_IMPORT_EXTENSIONS.iter()),
-            }
+        ImportExtensionsIter {
+            split: self.raw.as_deref().map(|s| s.split(';')),
         }
     }
 
     /// Collect into owned `String`s.
     pub fn to_vec(&self) -> Vec<String> {
-        self.iter().map(str::to_string).collect()
+        self.iter().map(std::borrow::Cow::into_owned).collect()
     }
 }
 
@@ -298,19 +551,38 @@ pub fn get_import_extensions_list() -> ImportExtensions {
     }
 
     if ai_string.length > 0 {
-        ImportExtensions {
-            raw: Some(crate::types::ai_string_to_string(&ai_string)),
-        }
+        ImportExtensions::from_assimp_list(crate::types::ai_string_to_string(&ai_string))
     } else {
         ImportExtensions { raw: None }
     }
 }
 
 /// Get a list of all supported import file extensions (allocates).
+///
+/// Returns exactly what Assimp reports, as lowercase `".ext"` strings. An empty list
+/// usually means Assimp was built without importers or is not linked correctly; see
+/// [`get_import_extensions_or_default`] for a variant that never returns empty.
 pub fn get_import_extensions() -> Vec<String> {
     get_import_extensions_list().to_vec()
 }
 
+/// Like [`get_import_extensions`], but falls back to a fixed list of common formats
+/// (`.obj`, `.fbx`, `.gltf`, ...) when Assimp reports no extensions.
+///
+/// The fallback says nothing about what this build can actually import; use it only
+/// for UI hints such as file dialog filters.
+pub fn get_import_extensions_or_default() -> Vec<String> {
+    let extensions = get_import_extensions();
+    if extensions.is_empty() {
+        FALLBACK_IMPORT_EXTENSIONS
+            .iter()
+            .map(|ext| ext.to_string())
+            .collect()
+    } else {
+        extensions
+    }
+}
+
 /// Get a list of all supported export formats
 #[cfg(feature = "export")]
 pub fn get_export_formats() -> Vec<crate::exporter::ExportFormatDesc> {
@@ -365,11 +637,12 @@ impl Iterator for ExportFormatDescIterator {
     }
 }
 
-/// Enable verbose logging for debugging
-pub fn enable_verbose_logging(enable: bool) {
-    unsafe {
-        crate::sys::aiEnableVerboseLogging(if enable { 1 } else { 0 });
-    }
+/// Enable verbose logging for debugging, process-wide, returning the previous setting.
+///
+/// See [`logging::enable_verbose_logging`]; prefer
+/// [`ImportBuilder::with_verbose_logging`] to make a single import verbose.
+pub fn enable_verbose_logging(enable: bool) -> bool {
+    crate::logging::enable_verbose_logging(enable)
 }
 
 #[cfg(test)]
@@ -394,6 +667,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_info() {
+        let info = version::assimp_build_info();
+        assert_eq!(info.version(), version::assimp_version());
+        assert_eq!(
+            info.double_precision,
+            std::mem::size_of::<crate::sys::ai_real>() == 8
+        );
+        assert_eq!(
+            info.flags.bits(),
+            version::assimp_compile_flags(),
+            "unknown flags must be kept"
+        );
+        assert_eq!(
+            info.shared,
+            info.flags.contains(version::CompileFlags::SHARED)
+        );
+        assert_ne!(info.branch.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_real_matches_sys() {
+        assert_eq!(
+            std::mem::size_of::<crate::sys::ai_real>(),
+            std::mem::size_of::<crate::types::Real>()
+        );
+        assert_eq!(
+            cfg!(f64_real),
+            std::mem::size_of::<crate::types::Real>() == 8
+        );
+        assert_eq!(
+            cfg!(f64_real),
+            version::assimp_build_info().double_precision
+        );
+    }
+
+    #[test]
+    fn test_has_importer_for() {
+        for input in ["obj", ".OBJ", "*.gltf", "glb"] {
+            assert!(has_importer_for(input), "{input}");
+        }
+        assert!(!has_importer_for("xyz"));
+        assert!(!has_importer_for(""));
+        assert!(!has_importer_for("*.*"));
+    }
+
+    #[test]
+    fn test_built_importers() {
+        if cfg!(feature = "build-assimp") {
+            let importers = built_importers().expect("source builds record their importers");
+            assert!(importers.contains(&"OBJ"), "{importers:?}");
+            assert!(built_exporters().is_some_and(|exporters| !exporters.is_empty()));
+        }
+        if let Some(importers) = built_importers() {
+            assert!(!importers.is_empty());
+        } else {
+            assert!(built_exporters().is_none());
+        }
+    }
+
+    #[cfg(feature = "export")]
+    #[test]
+    fn test_has_exporter_for() {
+        assert!(has_exporter_for("obj"));
+        assert!(has_exporter_for("OBJ"));
+        assert!(!has_exporter_for("not-a-format"));
+        assert!(!has_exporter_for(""));
+        let first = get_export_formats_iter()
+            .next()
+            .expect("at least one exporter");
+        assert!(has_exporter_for(&first.id));
+    }
+
     #[test]
     fn test_extension_support() {
         // These formats should definitely be supported
@@ -406,6 +752,65 @@ mod tests {
         assert!(!is_extension_supported("xyz").unwrap());
     }
 
+    /// Extension list reported by Assimp 5.4 with the default importer set.
+    const ASSIMP_5_4_EXTENSIONS: &str = "*.3d;*.3ds;*.3mf;*.ac;*.ac3d;*.acc;*.amf;*.ase;*.ask;*.assbin;*.b3d;*.blend;*.bsp;*.bvh;*.cob;*.csm;*.dae;*.dxf;*.enff;*.fbx;*.glb;*.gltf;*.hmp;*.ifc;*.ifczip;*.iqm;*.irr;*.irrmesh;*.lwo;*.lws;*.lxo;*.m3d;*.md2;*.md3;*.md5anim;*.md5camera;*.md5mesh;*.mdc;*.mdl;*.mesh;*.mesh.xml;*.mot;*.ms3d;*.ndo;*.nff;*.obj;*.off;*.ogex;*.pk3;*.ply;*.pmx;*.prj;*.q3o;*.q3s;*.raw;*.scn;*.sib;*.smd;*.stl;*.stp;*.ter;*.uc;*.vta;*.x;*.x3d;*.x3db;*.xgl;*.xml;*.zae;*.zgl";
+
+    #[test]
+    fn test_parse_assimp_extension_list() {
+        let list = ImportExtensions::from_assimp_list(ASSIMP_5_4_EXTENSIONS);
+        let extensions = list.to_vec();
+        assert_eq!(extensions.len(), ASSIMP_5_4_EXTENSIONS.split(';').count());
+        assert_eq!(extensions.first().map(String::as_str), Some(".3d"));
+        assert!(extensions.iter().any(|e| e == ".mesh.xml"));
+        assert!(extensions.iter().all(|e| e.starts_with('.')));
+        // Well-formed entries are borrowed from the raw list.
+        assert!(
+            list.iter()
+                .all(|e| matches!(e, std::borrow::Cow::Borrowed(_)))
+        );
+    }
+
+    #[test]
+    fn test_parse_odd_extension_entries() {
+        let list = ImportExtensions::from_assimp_list(
+            " *.OBJ ;;*.blend.gz; .fbx;glb;*.*;*;  ;*..;*.a b;*.Mesh.XML;",
+        );
+        assert_eq!(
+            list.to_vec(),
+            vec![".obj", ".blend.gz", ".fbx", ".glb", ".mesh.xml"]
+        );
+
+        assert!(ImportExtensions::from_assimp_list("").is_empty());
+        assert!(ImportExtensions::from_assimp_list(" ; ;").is_empty());
+        assert!(
+            ImportExtensions::from_assimp_list(" ; ;")
+                .raw_assimp_list()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_normalize_extension() {
+        for input in ["obj", ".obj", "*.obj", "OBJ", " *.Obj "] {
+            assert_eq!(
+                normalize_extension(input).as_deref(),
+                Some(".obj"),
+                "{input}"
+            );
+        }
+        assert_eq!(normalize_extension("").as_deref(), None);
+        assert_eq!(normalize_extension("*.").as_deref(), None);
+    }
+
+    #[test]
+    fn test_extension_support_normalization() {
+        for input in ["obj", ".obj", "*.obj", "OBJ", ".Obj"] {
+            assert!(is_extension_supported(input).unwrap(), "{input}");
+        }
+        assert!(!is_extension_supported("").unwrap());
+        assert!(!is_extension_supported(".").unwrap());
+    }
+
     #[test]
     fn test_get_extensions() {
         let extensions = get_import_extensions();
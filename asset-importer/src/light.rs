@@ -11,6 +11,9 @@ use crate::{
 };
 
 /// A light source in the scene
+///
+/// Position and direction are relative to the node with the same name; use
+/// [`Scene::light_node`] to find it.
 #[derive(Clone)]
 pub struct Light {
     #[allow(dead_code)]
@@ -50,6 +53,37 @@ impl Light {
         LightType::from_raw(self.raw().mType)
     }
 
+    /// The light with the fields that apply to its type, or `None` for an undefined type.
+    ///
+    /// Cone angles are the clamped [`Light::angle_inner_cone`] and
+    /// [`Light::angle_outer_cone`].
+    pub fn kind(&self) -> Option<LightKind> {
+        Some(match self.light_type() {
+            LightType::Undefined => return None,
+            LightType::Directional => LightKind::Directional {
+                direction: self.direction(),
+            },
+            LightType::Point => LightKind::Point {
+                position: self.position(),
+                attenuation: self.attenuation(),
+            },
+            LightType::Spot => LightKind::Spot {
+                position: self.position(),
+                direction: self.direction(),
+                attenuation: self.attenuation(),
+                inner_cone: self.angle_inner_cone(),
+                outer_cone: self.angle_outer_cone(),
+            },
+            LightType::Ambient => LightKind::Ambient,
+            LightType::Area => LightKind::Area {
+                position: self.position(),
+                direction: self.direction(),
+                up: self.up(),
+                size: self.size(),
+            },
+        })
+    }
+
     /// Get the position of the light
     pub fn position(&self) -> Vector3D {
         from_ai_vector3d(self.raw().mPosition)
@@ -95,24 +129,132 @@ impl Light {
         self.raw().mAttenuationQuadratic
     }
 
-    /// Get the inner cone angle for spot lights (in radians)
+    /// Get the constant, linear and quadratic attenuation factors
+    pub fn attenuation(&self) -> Attenuation {
+        Attenuation {
+            constant: self.attenuation_constant(),
+            linear: self.attenuation_linear(),
+            quadratic: self.attenuation_quadratic(),
+        }
+    }
+
+    /// Get the inner cone angle for spot lights (full angle, in radians)
+    ///
+    /// Clamped to `[0, 2π]`.
     pub fn angle_inner_cone(&self) -> f32 {
-        self.raw().mAngleInnerCone
+        clamp_cone_angle(self.raw().mAngleInnerCone)
     }
 
-    /// Get the outer cone angle for spot lights (in radians)
+    /// Get the outer cone angle for spot lights (full angle, in radians)
+    ///
+    /// Some exporters write a zero (or otherwise smaller-than-inner) outer angle; the
+    /// result is clamped to `[angle_inner_cone, 2π]` so the cone is never inverted.
+    /// Use [`Light::angle_outer_cone_raw`] for the stored value.
     pub fn angle_outer_cone(&self) -> f32 {
+        clamp_cone_angle(self.raw().mAngleOuterCone).max(self.angle_inner_cone())
+    }
+
+    /// Get the outer cone angle exactly as stored by Assimp (in radians)
+    pub fn angle_outer_cone_raw(&self) -> f32 {
         self.raw().mAngleOuterCone
     }
 
-    /// Get the size of the area light
+    /// Light intensity factor at `distance` from the light:
+    /// `1 / (constant + linear * d + quadratic * d²)`.
+    ///
+    /// Directional and ambient lights are not attenuated and always return `1.0`, as
+    /// does a light whose attenuation factors are all zero.
+    pub fn intensity_at(&self, distance: f32) -> f32 {
+        if matches!(
+            self.light_type(),
+            LightType::Directional | LightType::Ambient
+        ) {
+            return 1.0;
+        }
+        self.attenuation().intensity_at(distance)
+    }
+
+    /// Get the size of the area light (width, height in the light's local space)
     pub fn size(&self) -> Vector2D {
         from_ai_vector2d(self.raw().mSize)
     }
 }
 
+fn clamp_cone_angle(angle: f32) -> f32 {
+    if angle.is_nan() {
+        return std::f32::consts::TAU;
+    }
+    angle.clamp(0.0, std::f32::consts::TAU)
+}
+
+/// Distance attenuation factors of a point, spot or area light
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attenuation {
+    /// Constant factor
+    pub constant: f32,
+    /// Factor multiplied by the distance
+    pub linear: f32,
+    /// Factor multiplied by the squared distance
+    pub quadratic: f32,
+}
+
+impl Attenuation {
+    /// Intensity factor at `distance`: `1 / (constant + linear * d + quadratic * d²)`,
+    /// or `1.0` when the denominator is not positive.
+    pub fn intensity_at(&self, distance: f32) -> f32 {
+        let d = distance.abs();
+        let denom = self.constant + self.linear * d + self.quadratic * d * d;
+        if denom > 0.0 { 1.0 / denom } else { 1.0 }
+    }
+}
+
+/// A light source with the data that applies to its type, from [`Light::kind`]
+///
+/// Positions and directions are relative to the light's node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LightKind {
+    /// Light from a direction, at infinite distance
+    Directional {
+        /// Direction the light travels in
+        direction: Vector3D,
+    },
+    /// Omnidirectional light at a position
+    Point {
+        /// Position of the light
+        position: Vector3D,
+        /// Distance attenuation
+        attenuation: Attenuation,
+    },
+    /// Cone of light from a position
+    Spot {
+        /// Position of the light
+        position: Vector3D,
+        /// Direction of the cone's axis
+        direction: Vector3D,
+        /// Distance attenuation
+        attenuation: Attenuation,
+        /// Full angle of full intensity, in radians
+        inner_cone: f32,
+        /// Full angle where the light falls off to zero, in radians
+        outer_cone: f32,
+    },
+    /// Light without position or direction
+    Ambient,
+    /// Rectangular light emitting from a surface
+    Area {
+        /// Position of the light
+        position: Vector3D,
+        /// Direction the surface faces
+        direction: Vector3D,
+        /// Up vector of the surface
+        up: Vector3D,
+        /// Width and height of the surface
+        size: Vector2D,
+    },
+}
+
 /// Types of light sources
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum LightType {
     /// Undefined light type
     Undefined,
@@ -25,13 +25,27 @@
 //! For applications that need detailed logging, consider:
 //! 1. Using verbose logging with `enable_verbose_logging()`
 //! 2. Checking error messages with `get_last_error_message()`
-//! 3. Implementing application-level logging around import operations
+//! 3. Capturing the log lines of a single import with
+//!    [`ImportBuilder::import_file_with_report`](crate::importer::ImportBuilder::import_file_with_report)
+//!
+//! Per-import capture buffers messages on the C++ side and hands them back after the
+//! import returns, so no Rust code is called from inside Assimp's logger.
+//!
+//! ## `log` / `tracing` bridges
+//!
+//! With the `log` or `tracing` feature, [`install_log_bridge`] / [`install_tracing_bridge`]
+//! forward every Assimp message to that ecosystem under the target `"assimp"`. Unlike the
+//! removed callback streams, the bridge's stream is owned by the C++ side and calls a
+//! plain Rust function that only reads atomics, so it holds no Rust locks while Assimp
+//! is importing.
 
 use crate::{error::Result, sys};
 use std::ffi::CStr;
 
 /// Log levels supported by Assimp
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Levels are ordered by severity, so `LogLevel::Warn < LogLevel::Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum LogLevel {
     /// Verbose debug information
     Debug,
@@ -43,6 +57,74 @@ pub enum LogLevel {
     Error,
 }
 
+impl LogLevel {
+    const ALL: [LogLevel; 4] = [Self::Debug, Self::Info, Self::Warn, Self::Error];
+
+    /// Severity bit used by `Assimp::Logger::ErrorSeverity`.
+    fn severity_bit(self) -> u32 {
+        match self {
+            Self::Debug => 1,
+            Self::Info => 2,
+            Self::Warn => 4,
+            Self::Error => 8,
+        }
+    }
+
+    fn from_severity_bit(bit: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|l| l.severity_bit() == bit)
+    }
+}
+
+/// Thread-local capture of Assimp log output, backed by the C++ bridge.
+///
+/// Capture stops when the guard is finished or dropped.
+pub(crate) struct LogCapture {
+    // Capture state lives in C++ thread-locals; keep the guard on its thread.
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl LogCapture {
+    /// Start capturing messages at `min_level` or above on the current thread.
+    pub(crate) fn begin(min_level: LogLevel) -> Self {
+        let mask = LogLevel::ALL
+            .into_iter()
+            .filter(|&l| l >= min_level)
+            .fold(0, |mask, l| mask | l.severity_bit());
+        unsafe { sys::aiBeginLogCaptureRust(mask) };
+        Self {
+            _not_send: std::marker::PhantomData,
+        }
+    }
+
+    /// Stop capturing and return the captured messages in emission order.
+    pub(crate) fn finish(self) -> Vec<(LogLevel, String)> {
+        unsafe { sys::aiEndLogCaptureRust() };
+        let count = unsafe { sys::aiGetCapturedLogCountRust() };
+        let mut messages = Vec::with_capacity(count);
+        for index in 0..count {
+            let mut severity = 0u32;
+            let ptr = unsafe { sys::aiGetCapturedLogRust(index, &mut severity) };
+            if ptr.is_null() {
+                continue;
+            }
+            let Some(level) = LogLevel::from_severity_bit(severity) else {
+                continue;
+            };
+            let text = unsafe { CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned();
+            messages.push((level, text));
+        }
+        messages
+    }
+}
+
+impl Drop for LogCapture {
+    fn drop(&mut self) {
+        unsafe { sys::aiEndLogCaptureRust() };
+    }
+}
+
 // Note: Custom log streams have been removed due to FFI callback safety issues.
 // The following types are kept for API compatibility but are no longer functional:
 
@@ -141,11 +223,11 @@ impl Logger {
     }
 
     /// Enable or disable verbose logging
+    ///
+    /// This changes Assimp's process-wide log severity, see [`enable_verbose_logging`].
     pub fn enable_verbose_logging(&mut self, enable: bool) {
         self.verbose_enabled = enable;
-        unsafe {
-            sys::aiEnableVerboseLogging(if enable { 1 } else { 0 });
-        }
+        set_verbose_requested(enable);
     }
 
     /// Check if verbose logging is enabled
@@ -218,6 +300,7 @@ pub fn attach_stderr_stream() -> Result<()> {
 #[deprecated(
     note = "Custom log streams removed due to FFI safety issues. Use enable_verbose_logging instead."
 )]
+#[cfg(not(target_family = "wasm"))]
 pub fn attach_file_stream<P: AsRef<std::path::Path>>(_path: P) -> Result<()> {
     eprintln!("Warning: Custom log streams have been disabled due to FFI safety issues.");
     eprintln!("Use enable_verbose_logging() instead for safe logging.");
@@ -226,19 +309,84 @@ pub fn attach_file_stream<P: AsRef<std::path::Path>>(_path: P) -> Result<()> {
     ))
 }
 
-/// Convenience function to enable verbose logging
-pub fn enable_verbose_logging(enable: bool) {
+/// Enable or disable verbose (debug) logging for every import in the process, and
+/// return the previous setting so it can be restored.
+///
+/// Assimp keeps a single log severity for the whole process. To make one import verbose
+/// without affecting the others, use
+/// [`ImportBuilder::with_verbose_logging`](crate::ImportBuilder::with_verbose_logging).
+pub fn enable_verbose_logging(enable: bool) -> bool {
     if let Ok(mut logger) = global_logger().lock() {
-        logger.enable_verbose_logging(enable);
+        logger.verbose_enabled = enable;
     }
+    set_verbose_requested(enable)
 }
 
-/// Check if verbose logging is enabled
+/// Check if verbose logging is enabled process-wide (ignoring imports that enabled it
+/// for themselves).
 pub fn is_verbose_logging_enabled() -> bool {
-    global_logger()
+    verbose_state().requested
+}
+
+/// Process-wide verbosity: what the caller asked for, and how many imports are currently
+/// running with [`VerboseScope`].
+struct VerboseState {
+    requested: bool,
+    scopes: usize,
+}
+
+static VERBOSE: std::sync::Mutex<VerboseState> = std::sync::Mutex::new(VerboseState {
+    requested: false,
+    scopes: 0,
+});
+
+fn verbose_state() -> std::sync::MutexGuard<'static, VerboseState> {
+    VERBOSE
         .lock()
-        .map(|l| l.is_verbose_enabled())
-        .unwrap_or(false)
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn apply_verbose(enable: bool) {
+    unsafe { sys::aiEnableVerboseLogging(if enable { 1 } else { 0 }) };
+}
+
+/// Record the caller's setting; while verbose imports run, Assimp stays verbose and the
+/// setting takes effect when the last one finishes.
+fn set_verbose_requested(enable: bool) -> bool {
+    let mut state = verbose_state();
+    let previous = std::mem::replace(&mut state.requested, enable);
+    if state.scopes == 0 {
+        apply_verbose(enable);
+    }
+    previous
+}
+
+/// Keeps Assimp's logger verbose while alive.
+///
+/// Scopes are counted, so overlapping verbose imports on different threads keep the
+/// logger verbose until the last one ends, which then restores the caller's setting.
+pub(crate) struct VerboseScope {
+    _private: (),
+}
+
+impl VerboseScope {
+    pub(crate) fn begin() -> Self {
+        let mut state = verbose_state();
+        state.scopes += 1;
+        // Applied every time: a logger created after the last toggle starts out quiet.
+        apply_verbose(true);
+        Self { _private: () }
+    }
+}
+
+impl Drop for VerboseScope {
+    fn drop(&mut self) {
+        let mut state = verbose_state();
+        state.scopes -= 1;
+        if state.scopes == 0 {
+            apply_verbose(state.requested);
+        }
+    }
 }
 
 /// Get the last error message from Assimp
@@ -254,6 +402,188 @@ pub fn detach_all_streams() {
     // No-op: no streams to detach
 }
 
+/// Emit a debug message from this crate (not from Assimp) through `log` / `tracing`
+/// when those features are enabled (target `"asset_importer"`).
+pub(crate) fn debug_message(message: std::fmt::Arguments<'_>) {
+    #[cfg(feature = "log")]
+    log::log!(target: "asset_importer", log::Level::Debug, "{}", message);
+    #[cfg(feature = "tracing")]
+    tracing::event!(target: "asset_importer", tracing::Level::DEBUG, "{}", message);
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    let _ = message;
+}
+
+/// Emit a warning from this crate (not from Assimp) through `log` / `tracing` when
+/// those features are enabled (target `"asset_importer"`).
+pub(crate) fn warn_message(message: std::fmt::Arguments<'_>) {
+    #[cfg(feature = "log")]
+    log::log!(target: "asset_importer", log::Level::Warn, "{}", message);
+    #[cfg(feature = "tracing")]
+    tracing::event!(target: "asset_importer", tracing::Level::WARN, "{}", message);
+    #[cfg(not(any(feature = "log", feature = "tracing")))]
+    let _ = message;
+}
+
+/// Singleton forwarding of Assimp log lines to `log` / `tracing`.
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod bridge {
+    use super::LogLevel;
+    use crate::sys;
+    use std::ffi::{CStr, c_char, c_uint};
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    pub(super) const SINK_NONE: u8 = 0;
+    #[cfg(feature = "log")]
+    pub(super) const SINK_LOG: u8 = 1;
+    #[cfg(feature = "tracing")]
+    pub(super) const SINK_TRACING: u8 = 2;
+
+    /// Read by the trampoline; only ever stored while `INSTALL` is held.
+    static BRIDGE_SINK: AtomicU8 = AtomicU8::new(SINK_NONE);
+
+    /// Install generation. Serializes install/uninstall and lets stale guards detect
+    /// that a newer install replaced them. Never touched by the trampoline.
+    static INSTALL: Mutex<u64> = Mutex::new(0);
+
+    pub(super) fn severity_mask(min_level: LogLevel) -> c_uint {
+        LogLevel::ALL
+            .into_iter()
+            .filter(|&l| l >= min_level)
+            .fold(0, |mask, l| mask | l.severity_bit())
+    }
+
+    /// Attach (or re-target) the singleton forwarding stream. Returns the generation.
+    pub(super) fn install(sink: u8, mask: c_uint) -> u64 {
+        let mut generation = INSTALL.lock().unwrap_or_else(|e| e.into_inner());
+        *generation += 1;
+        BRIDGE_SINK.store(sink, Ordering::Release);
+        let callback = (mask != 0).then_some(trampoline as unsafe extern "C" fn(_, _));
+        unsafe { sys::aiSetLogForwardRust(callback, mask) };
+        *generation
+    }
+
+    /// Detach the stream if `generation` is still the active install.
+    pub(super) fn uninstall(generation: u64) {
+        let current = INSTALL.lock().unwrap_or_else(|e| e.into_inner());
+        if *current == generation {
+            unsafe { sys::aiSetLogForwardRust(None, 0) };
+            BRIDGE_SINK.store(SINK_NONE, Ordering::Release);
+        }
+    }
+
+    unsafe extern "C" fn trampoline(severity: c_uint, message: *const c_char) {
+        if message.is_null() {
+            return;
+        }
+        let Some(level) = LogLevel::from_severity_bit(severity) else {
+            return;
+        };
+        // Unwinding into C++ is undefined behavior; drop the message instead.
+        let _ = std::panic::catch_unwind(|| {
+            let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+            match BRIDGE_SINK.load(Ordering::Acquire) {
+                #[cfg(feature = "log")]
+                SINK_LOG => forward_log(level, &message),
+                #[cfg(feature = "tracing")]
+                SINK_TRACING => forward_tracing(level, &message),
+                _ => {}
+            }
+        });
+    }
+
+    #[cfg(feature = "log")]
+    fn forward_log(level: LogLevel, message: &str) {
+        let level = match level {
+            LogLevel::Debug => log::Level::Debug,
+            LogLevel::Info => log::Level::Info,
+            LogLevel::Warn => log::Level::Warn,
+            LogLevel::Error => log::Level::Error,
+        };
+        log::log!(target: "assimp", level, "{}", message);
+    }
+
+    #[cfg(feature = "tracing")]
+    fn forward_tracing(level: LogLevel, message: &str) {
+        match level {
+            LogLevel::Debug => {
+                tracing::event!(target: "assimp", tracing::Level::DEBUG, "{}", message)
+            }
+            LogLevel::Info => {
+                tracing::event!(target: "assimp", tracing::Level::INFO, "{}", message)
+            }
+            LogLevel::Warn => {
+                tracing::event!(target: "assimp", tracing::Level::WARN, "{}", message)
+            }
+            LogLevel::Error => {
+                tracing::event!(target: "assimp", tracing::Level::ERROR, "{}", message)
+            }
+        }
+    }
+}
+
+/// Keeps the `log`/`tracing` bridge attached; detaches it when dropped.
+///
+/// Installing again replaces the active bridge (and its level filter) without attaching
+/// a second stream; guards from earlier installs then become no-ops. Use
+/// [`std::mem::forget`] to keep the bridge for the rest of the process.
+#[cfg(any(feature = "log", feature = "tracing"))]
+#[must_use = "the log bridge is detached when the guard is dropped"]
+#[derive(Debug)]
+pub struct LogBridgeGuard {
+    generation: u64,
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl LogBridgeGuard {
+    /// Detach the bridge now (same as dropping the guard).
+    pub fn uninstall(self) {}
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl Drop for LogBridgeGuard {
+    fn drop(&mut self) {
+        bridge::uninstall(self.generation);
+    }
+}
+
+/// Forward Assimp messages at `min_level` or above to the `log` crate (target `"assimp"`).
+///
+/// Messages are emitted synchronously on the thread that produced them, usually the
+/// importing thread. Assimp only produces debug messages while verbose logging is
+/// enabled (see [`enable_verbose_logging`]).
+#[cfg(feature = "log")]
+pub fn install_log_bridge(min_level: LogLevel) -> LogBridgeGuard {
+    let mask = bridge::severity_mask(min_level);
+    LogBridgeGuard {
+        generation: bridge::install(bridge::SINK_LOG, mask),
+    }
+}
+
+/// Forward Assimp messages allowed by `filter` to `tracing` events (target `"assimp"`).
+///
+/// Assimp has no trace level, so `TRACE` behaves like `DEBUG`; `OFF` forwards nothing.
+/// See [`install_log_bridge`] for threading and verbosity notes.
+#[cfg(feature = "tracing")]
+pub fn install_tracing_bridge(filter: tracing::level_filters::LevelFilter) -> LogBridgeGuard {
+    use tracing::level_filters::LevelFilter;
+
+    let mask = if filter == LevelFilter::OFF {
+        0
+    } else if filter >= LevelFilter::DEBUG {
+        bridge::severity_mask(LogLevel::Debug)
+    } else if filter >= LevelFilter::INFO {
+        bridge::severity_mask(LogLevel::Info)
+    } else if filter >= LevelFilter::WARN {
+        bridge::severity_mask(LogLevel::Warn)
+    } else {
+        bridge::severity_mask(LogLevel::Error)
+    };
+    LogBridgeGuard {
+        generation: bridge::install(bridge::SINK_TRACING, mask),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,6 +616,19 @@ mod tests {
         assert!(!is_verbose_logging_enabled());
     }
 
+    #[test]
+    fn test_log_level_severity_bits_roundtrip() {
+        for level in LogLevel::ALL {
+            assert_eq!(
+                LogLevel::from_severity_bit(level.severity_bit()),
+                Some(level)
+            );
+        }
+        assert_eq!(LogLevel::from_severity_bit(3), None);
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Warn < LogLevel::Error);
+    }
+
     #[test]
     #[allow(deprecated)]
     fn test_deprecated_functions_return_errors() {
@@ -296,7 +639,10 @@ mod tests {
         let result = attach_stderr_stream();
         assert!(result.is_err());
 
-        let result = attach_file_stream("test.log");
-        assert!(result.is_err());
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let result = attach_file_stream("test.log");
+            assert!(result.is_err());
+        }
     }
 }
@@ -9,13 +9,28 @@ use crate::{
     scene::Scene,
     sys,
     types::{
-        Color3D, Color4D, Vector2D, Vector3D, Vector4D, ai_string_to_str, ai_string_to_string,
+        Color3D, Color4D, Matrix3x3, Name, Real, Vector2D, Vector3D, Vector4D, ai_string_bytes,
+        ai_string_raw_bytes, ai_string_to_str, ai_string_to_string, from_ai_vector2d, from_real,
+        to_real,
     },
 };
 use std::borrow::Cow;
 use std::ffi::CStr;
 use std::ffi::CString;
 
+mod builder;
+mod dedup;
+#[cfg(feature = "image")]
+mod load;
+mod path;
+mod summary;
+
+pub use builder::{MaterialBuilder, MaterialMut, MaterialTexture, OwnedMaterial};
+#[cfg(feature = "image")]
+pub use load::{ImageSource, LoadedImage, TextureLoadContext};
+pub use path::{PathResolveOptions, ResolvedPath, ResolvedTexture};
+pub use summary::{MaterialSummary, TextureCounts};
+
 /// Standard material property keys as defined by Assimp
 pub mod material_keys {
     use std::ffi::CStr;
@@ -64,6 +79,12 @@ pub mod material_keys {
     pub const TEXTURE_SCALE: &CStr = cstr!("$tex.scale");
     /// glTF texture strength key, used by occlusion textures.
     pub const TEXTURE_STRENGTH: &CStr = cstr!("$tex.strength");
+    /// Texture file path (`_AI_MATKEY_TEXTURE_BASE`)
+    pub const TEXTURE_FILE: &CStr = cstr!("$tex.file");
+    /// Texture UV transform (`_AI_MATKEY_UVTRANSFORM_BASE`)
+    pub const TEXTURE_UVTRANSFORM: &CStr = cstr!("$tex.uvtrafo");
+    /// Texture mapping axis (`_AI_MATKEY_TEXMAP_AXIS_BASE`)
+    pub const TEXTURE_MAPAXIS: &CStr = cstr!("$tex.mapaxis");
 
     // PBR-related keys (from material.h)
     /// Base color factor (RGBA)
@@ -98,6 +119,19 @@ pub mod material_keys {
     pub const ANISOTROPY_FACTOR: &CStr = cstr!("$mat.anisotropyFactor");
     /// Anisotropy rotation
     pub const ANISOTROPY_ROTATION: &CStr = cstr!("$mat.anisotropyRotation");
+
+    // glTF-specific keys (from GltfMaterial.h)
+    /// glTF alpha mode string (`OPAQUE`, `MASK` or `BLEND`)
+    pub const GLTF_ALPHAMODE: &CStr = cstr!("$mat.gltf.alphaMode");
+    /// glTF alpha cutoff, used by the `MASK` alpha mode
+    pub const GLTF_ALPHACUTOFF: &CStr = cstr!("$mat.gltf.alphaCutoff");
+    /// glTF material `extras` as a JSON string. Not written by Assimp's glTF 2 importer
+    /// (up to 6.0.5); read by [`Material::extras_json`](super::Material::extras_json) for
+    /// materials that tools or patched Assimp builds annotate this way
+    pub const GLTF_EXTRAS: &CStr = cstr!("$mat.gltf.extras");
+    /// Emissive strength (`KHR_materials_emissive_strength`); Assimp stores it under the
+    /// generic emissive intensity key
+    pub const EMISSIVE_STRENGTH: &CStr = EMISSIVE_INTENSITY;
 }
 
 /// A material containing properties like colors, textures, and shading parameters
@@ -119,10 +153,14 @@ impl MaterialStringRef {
         ai_string_to_str(&self.value)
     }
 
-    /// Raw bytes (without assuming NUL-termination).
+    /// Bytes up to the first NUL (length clamped to the buffer).
     pub fn as_bytes(&self) -> &[u8] {
-        let len = (self.value.length as usize).min(self.value.data.len());
-        ffi::slice_from_ptr_len(self, self.value.data.as_ptr() as *const u8, len)
+        ai_string_bytes(&self.value)
+    }
+
+    /// All `length` bytes (clamped to the buffer), including any embedded NULs.
+    pub fn as_raw_bytes(&self) -> &[u8] {
+        ai_string_raw_bytes(&self.value)
     }
 
     /// Borrow the underlying Assimp `aiString`.
@@ -173,6 +211,14 @@ impl Material {
         self.name_ref().map(|s| s.to_string()).unwrap_or_default()
     }
 
+    /// Get the material name as an inline [`Name`]; empty when the
+    /// material has no name.
+    pub fn name_owned(&self) -> Name {
+        self.name_ref()
+            .map(|s| Name::from_utf8_lossy(s.as_bytes()))
+            .unwrap_or_default()
+    }
+
     /// Get the material name (no heap allocation).
     pub fn name_ref(&self) -> Option<MaterialStringRef> {
         self.get_string_property_ref(material_keys::NAME)
@@ -213,7 +259,7 @@ impl Material {
 
     /// Get a float property from the material
     pub fn get_float_property(&self, key: &CStr) -> Option<f32> {
-        let mut value = 0.0f32;
+        let mut value: Real = 0.0;
         let mut max = 1u32;
 
         let result = unsafe {
@@ -228,7 +274,7 @@ impl Material {
         };
 
         if result == sys::aiReturn::aiReturn_SUCCESS && max > 0 {
-            Some(value)
+            Some(from_real(value))
         } else {
             None
         }
@@ -642,7 +688,7 @@ impl Material {
                     _ => return None,
                 };
                 let count = (info.data_length as usize) / elem_size;
-                let mut out = vec![0f32; count];
+                let mut out: Vec<Real> = vec![0.0; count];
                 let mut max = count as u32;
                 let result = unsafe {
                     sys::aiGetMaterialFloatArray(
@@ -656,7 +702,7 @@ impl Material {
                 };
                 if result == sys::aiReturn::aiReturn_SUCCESS {
                     out.truncate(max as usize);
-                    return Some(out);
+                    return Some(out.into_iter().map(from_real).collect());
                 }
                 // Fallback: manual conversion from raw data
                 self.get_property_f64_array(key, semantic, index)
@@ -755,11 +801,13 @@ impl Material {
     /// Iterate all material properties (zero allocation for keys and raw data).
     pub fn properties(&self) -> MaterialPropertyIterator {
         let m = self.raw();
+        let props = m.mProperties as *const *const sys::aiMaterialProperty;
+        let count = m.mNumProperties as usize;
         MaterialPropertyIterator {
             scene: self.scene.clone(),
-            props: SharedPtr::new(m.mProperties as *const *const sys::aiMaterialProperty),
-            count: m.mNumProperties as usize,
-            index: 0,
+            props: SharedPtr::new(props),
+            count,
+            cursor: ffi::PtrArrayCursor::new(m, props, count),
         }
     }
 
@@ -781,6 +829,54 @@ impl Material {
             .map(|v| BlendMode::from_raw(v as u32))
     }
 
+    /// glTF alpha mode (`$mat.gltf.alphaMode`).
+    ///
+    /// Assimp's glTF importer always sets this key (`Opaque` when the file omits it);
+    /// other formats usually return `None`.
+    pub fn alpha_mode(&self) -> Option<AlphaMode> {
+        self.get_string_property_ref(material_keys::GLTF_ALPHAMODE)
+            .map(|mode| AlphaMode::from_name(&mode.as_str()))
+    }
+
+    /// glTF alpha cutoff (`$mat.gltf.alphaCutoff`).
+    ///
+    /// Only meaningful for [`AlphaMode::Mask`]; glTF's default is `0.5`.
+    pub fn alpha_cutoff(&self) -> Option<f32> {
+        self.get_float_property(material_keys::GLTF_ALPHACUTOFF)
+    }
+
+    /// The glTF `extras` of this material as a JSON string.
+    ///
+    /// Assimp's glTF 2 importer drops material extras (checked up to 6.0.5, the bundled
+    /// version), and `aiMaterial` has no metadata slot, so for imported files this is
+    /// `None`. The only route is a [`material_keys::GLTF_EXTRAS`] string property, which
+    /// some pipelines add to materials themselves; Assimp strings hold at most 1023 bytes.
+    pub fn extras_json(&self) -> Option<String> {
+        self.get_string_property(material_keys::GLTF_EXTRAS)
+            .filter(|json| !json.trim().is_empty())
+    }
+
+    /// [`Material::extras_json`] parsed (requires the `assjson` feature); `None` when
+    /// absent or not valid JSON.
+    #[cfg(feature = "assjson")]
+    pub fn extras_value(&self) -> Option<serde_json::Value> {
+        serde_json::from_str(&self.extras_json()?).ok()
+    }
+
+    /// Emissive strength from `KHR_materials_emissive_strength`.
+    ///
+    /// Same value as [`Material::emissive_intensity`]; `None` means a strength of `1.0`.
+    pub fn emissive_strength(&self) -> Option<f32> {
+        self.get_float_property(material_keys::EMISSIVE_STRENGTH)
+    }
+
+    /// glTF `doubleSided`, which Assimp maps to `$mat.twosided`.
+    ///
+    /// Same as [`Material::is_two_sided`]; `false` when the key is missing.
+    pub fn double_sided(&self) -> bool {
+        self.is_two_sided()
+    }
+
     /// Get a texture-scoped float property from the material.
     ///
     /// This is useful for texture extension metadata such as glTF normal texture scale and
@@ -792,7 +888,7 @@ impl Material {
         index: usize,
     ) -> Option<f32> {
         let index = u32::try_from(index).ok()?;
-        let mut value = 0.0f32;
+        let mut value: Real = 0.0;
         let mut max = 1u32;
 
         let result = unsafe {
@@ -807,7 +903,7 @@ impl Material {
         };
 
         if result == sys::aiReturn::aiReturn_SUCCESS && max > 0 {
-            Some(value)
+            Some(from_real(value))
         } else {
             None
         }
@@ -843,113 +939,188 @@ impl Material {
 
     /// Get the number of textures for a specific type
     pub fn texture_count(&self, texture_type: TextureType) -> usize {
-        unsafe { sys::aiGetMaterialTextureCount(self.as_raw_sys(), texture_type.to_sys()) as usize }
+        match texture_type.to_sys() {
+            Some(ty) => unsafe { sys::aiGetMaterialTextureCount(self.as_raw_sys(), ty) as usize },
+            // Same rule as aiGetMaterialTextureCount: highest `$tex.file` index plus one.
+            None => self
+                .properties()
+                .filter(|prop| {
+                    prop.key_bytes() == b"$tex.file"
+                        && prop.raw().mSemantic == texture_type.to_u32()
+                })
+                .map(|prop| prop.index() as usize + 1)
+                .max()
+                .unwrap_or(0),
+        }
     }
 
     /// Get texture information for a specific type and index (no heap allocation).
+    ///
+    /// The UV transform and mapping axis come from [`Material::uv_transform`] and
+    /// [`Material::texture_map_axis`]. If `aiGetMaterialTexture` rejects the slot while a
+    /// `$tex.file` property exists (e.g. stored with a non-string type), the path is
+    /// decoded from that property and the remaining fields are read one by one.
     pub fn texture_ref(&self, texture_type: TextureType, index: usize) -> Option<TextureInfoRef> {
         if index >= self.texture_count(texture_type) {
             return None;
         }
+        let slot = u32::try_from(index).ok()?;
+
+        let mut path = sys::aiString::default();
+        let mut mapping = sys::aiTextureMapping::aiTextureMapping_UV;
+        // aiGetMaterialTexture only writes these when the property exists.
+        let mut uv_index: u32 = 0;
+        let mut blend: Real = 1.0;
+        let mut op = sys::aiTextureOp::aiTextureOp_Multiply;
+        // Use the exact sys enum type to avoid platform-dependent
+        // signedness mismatches across compilers.
+        let mut map_mode: [sys::aiTextureMapMode; 3] =
+            [sys::aiTextureMapMode::aiTextureMapMode_Wrap; 3];
+        let mut tex_flags: u32 = 0;
+
+        let result = match texture_type.to_sys() {
+            Some(ty) => unsafe {
+                sys::aiGetMaterialTexture(
+                    self.as_raw_sys(),
+                    ty,
+                    slot,
+                    &mut path,
+                    &mut mapping,
+                    &mut uv_index,
+                    &mut blend,
+                    &mut op,
+                    map_mode.as_mut_ptr() as *mut _,
+                    &mut tex_flags as *mut u32,
+                )
+            },
+            // Slots with an unnamed semantic are read property by property below.
+            None => sys::aiReturn::aiReturn_FAILURE,
+        };
 
-        unsafe {
-            let mut path = sys::aiString::default();
-            let mut mapping = std::mem::MaybeUninit::<sys::aiTextureMapping>::uninit();
-            let mut uv_index = std::mem::MaybeUninit::<u32>::uninit();
-            let mut blend = std::mem::MaybeUninit::<f32>::uninit();
-            let mut op = std::mem::MaybeUninit::<sys::aiTextureOp>::uninit();
-            // Use the exact sys enum type to avoid platform-dependent
-            // signedness mismatches across compilers.
-            let mut map_mode: [sys::aiTextureMapMode; 3] =
-                [sys::aiTextureMapMode::aiTextureMapMode_Wrap; 3];
-            let mut tex_flags: u32 = 0;
-
-            let result = sys::aiGetMaterialTexture(
-                self.as_raw_sys(),
-                texture_type.to_sys(),
-                index as u32,
-                &mut path,
-                mapping.as_mut_ptr(),
-                uv_index.as_mut_ptr(),
-                blend.as_mut_ptr(),
-                op.as_mut_ptr(),
-                map_mode.as_mut_ptr() as *mut _,
-                &mut tex_flags as *mut u32,
-            );
-
-            if result != sys::aiReturn::aiReturn_SUCCESS {
-                return None;
+        let (mapping, operation, map_modes) = if result == sys::aiReturn::aiReturn_SUCCESS {
+            (
+                TextureMapping::from_raw(mapping),
+                TextureOperation::from_raw(op),
+                map_mode.map(TextureMapMode::from_raw),
+            )
+        } else {
+            path = self.texture_file_property(texture_type, slot)?;
+            let int = |key: &CStr| self.texture_int(key, texture_type, slot);
+            let mapping = int(c"$tex.mapping")
+                .map_or(TextureMapping::UV, |v| TextureMapping::from_u32(v as u32));
+            if mapping == TextureMapping::UV {
+                uv_index = int(c"$tex.uvwsrc").map_or(0, |v| v as u32);
             }
+            blend = self
+                .get_texture_float_property(c"$tex.blend", texture_type, index)
+                .map_or(1.0, to_real);
+            tex_flags = int(c"$tex.flags").map_or(0, |v| v as u32);
+            let mode = |key: &CStr| {
+                int(key).map_or(TextureMapMode::Wrap, |v| TextureMapMode::from_u32(v as u32))
+            };
+            (
+                mapping,
+                int(c"$tex.op").map_or(TextureOperation::Multiply, |v| {
+                    TextureOperation::from_u32(v as u32)
+                }),
+                [
+                    mode(c"$tex.mapmodeu"),
+                    mode(c"$tex.mapmodev"),
+                    mode(c"$tex.mapmodew"),
+                ],
+            )
+        };
 
-            let mapping_val = mapping.assume_init();
-            let uv_index_val = uv_index.assume_init();
-            let blend_val = blend.assume_init();
-            let op_val = op.assume_init();
+        Some(TextureInfoRef {
+            path,
+            mapping,
+            uv_index,
+            blend_factor: from_real(blend),
+            operation,
+            map_modes,
+            flags: TextureFlags::from_bits_truncate(tex_flags),
+            uv_transform: self.uv_transform(texture_type, index),
+            axis: self.texture_map_axis(texture_type, index),
+        })
+    }
 
-            // Try read UV transform
-            let mut uv_transform = std::mem::MaybeUninit::<sys::aiUVTransform>::uninit();
-            let uv_key: &CStr = c"$tex.uvtrafo";
-            let uv_ok = sys::aiGetMaterialUVTransform(
+    /// Get the UV transform (`$tex.uvtrafo`) of a texture slot.
+    ///
+    /// See [`UVTransform`] for how to apply it.
+    pub fn uv_transform(&self, texture_type: TextureType, index: usize) -> Option<UVTransform> {
+        let index = u32::try_from(index).ok()?;
+        let mut transform = sys::aiUVTransform::default();
+        let result = unsafe {
+            sys::aiGetMaterialUVTransform(
                 self.as_raw_sys(),
-                uv_key.as_ptr(),
+                material_keys::TEXTURE_UVTRANSFORM.as_ptr(),
                 texture_type.to_semantic(),
-                index as u32,
-                uv_transform.as_mut_ptr(),
-            ) == sys::aiReturn::aiReturn_SUCCESS;
-
-            let uv_transform = if uv_ok {
-                let t = uv_transform.assume_init();
-                Some(UVTransform {
-                    translation: Vector2D::new(t.mTranslation.x, t.mTranslation.y),
-                    scaling: Vector2D::new(t.mScaling.x, t.mScaling.y),
-                    rotation: t.mRotation,
-                })
-            } else {
-                None
-            };
+                index,
+                &mut transform,
+            )
+        };
+        (result == sys::aiReturn::aiReturn_SUCCESS).then(|| UVTransform {
+            translation: from_ai_vector2d(transform.mTranslation),
+            scaling: from_ai_vector2d(transform.mScaling),
+            rotation: from_real(transform.mRotation),
+        })
+    }
 
-            // Try read TEXMAP_AXIS via property API ("$tex.mapaxis")
-            let axis = {
-                let key: &CStr = c"$tex.mapaxis";
-                let mut prop_ptr: *const sys::aiMaterialProperty = std::ptr::null();
-                let ok = sys::aiGetMaterialProperty(
-                    self.as_raw_sys(),
-                    key.as_ptr(),
-                    texture_type.to_semantic(),
-                    index as u32,
-                    &mut prop_ptr,
-                ) == sys::aiReturn::aiReturn_SUCCESS;
-                if ok {
-                    if let Some(prop) = ffi::ref_from_ptr(self, prop_ptr) {
-                        MaterialPropertyData::from_sys(prop)
-                            .and_then(|d| d.read_ne_f32_array::<3>(0))
-                            .map(|[x, y, z]| Vector3D::new(x, y, z))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
-            };
+    /// Get the mapping axis (`$tex.mapaxis`) of a texture slot, used by non-UV mappings.
+    pub fn texture_map_axis(&self, texture_type: TextureType, index: usize) -> Option<Vector3D> {
+        let index = u32::try_from(index).ok()?;
+        let prop_ptr =
+            self.property_ptr(material_keys::TEXTURE_MAPAXIS, Some(texture_type), index)?;
+        let prop = ffi::ref_from_ptr(self, prop_ptr)?;
+        unsafe { MaterialPropertyData::from_sys(prop) }
+            .and_then(|d| d.read_ne_f32_array::<3>(0))
+            .map(|[x, y, z]| Vector3D::new(x, y, z))
+    }
 
-            Some(TextureInfoRef {
-                path,
-                mapping: TextureMapping::from_raw(mapping_val),
-                uv_index: uv_index_val,
-                blend_factor: blend_val,
-                operation: TextureOperation::from_raw(op_val),
-                map_modes: [
-                    TextureMapMode::from_raw(map_mode[0]),
-                    TextureMapMode::from_raw(map_mode[1]),
-                    TextureMapMode::from_raw(map_mode[2]),
-                ],
-                flags: TextureFlags::from_bits_truncate(tex_flags),
-                uv_transform,
-                axis,
-            })
+    /// Decode a `$tex.file` property directly, accepting string and raw buffer payloads.
+    fn texture_file_property(
+        &self,
+        texture_type: TextureType,
+        index: u32,
+    ) -> Option<sys::aiString> {
+        let prop_ptr = self.property_ptr(material_keys::TEXTURE_FILE, Some(texture_type), index)?;
+        let prop = ffi::ref_from_ptr(self, prop_ptr)?;
+        let data = unsafe { MaterialPropertyData::from_sys(prop) }?;
+        match PropertyTypeInfo::from_sys(prop.mType) {
+            PropertyTypeInfo::String => data.decode_ai_string(),
+            PropertyTypeInfo::Buffer => {
+                let bytes = data.bytes;
+                let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                let len = len.min(sys::AI_MAXLEN as usize - 1);
+                let mut value = sys::aiString {
+                    length: len as u32,
+                    data: [0; sys::AI_MAXLEN as usize],
+                };
+                for (dst, src) in value.data[..len].iter_mut().zip(&bytes[..len]) {
+                    *dst = *src as std::os::raw::c_char;
+                }
+                (len > 0).then_some(value)
+            }
+            _ => None,
         }
     }
 
+    fn texture_int(&self, key: &CStr, texture_type: TextureType, index: u32) -> Option<i32> {
+        let mut value = 0i32;
+        let mut max = 1u32;
+        let result = unsafe {
+            sys::aiGetMaterialIntegerArray(
+                self.as_raw_sys(),
+                key.as_ptr(),
+                texture_type.to_semantic(),
+                index,
+                &mut value,
+                &mut max,
+            )
+        };
+        (result == sys::aiReturn::aiReturn_SUCCESS && max > 0).then_some(value)
+    }
+
     /// Iterate textures of a given type (no heap allocation).
     pub fn texture_refs(
         &self,
@@ -964,66 +1135,117 @@ impl Material {
         self.texture_ref(texture_type, index)
             .map(TextureInfoRef::into_owned)
     }
+
+    /// Group every `$tex.*` property by its texture slot (semantic and index).
+    ///
+    /// Unlike [`Material::texture`], this keeps keys the crate has no getter for, such
+    /// as importer-specific sampler or transform metadata. Slots are ordered by texture
+    /// type, then index; properties keep their order within the material.
+    pub fn texture_slots(&self) -> Vec<TextureSlot> {
+        let mut slots: Vec<TextureSlot> = Vec::new();
+        for prop in self.properties() {
+            if !prop.key_bytes().starts_with(b"$tex.") {
+                continue;
+            }
+            let Some(texture_type) = prop.semantic() else {
+                continue;
+            };
+            let index = prop.index();
+            let info = prop.into_info();
+            match slots
+                .iter_mut()
+                .find(|slot| slot.texture_type == texture_type && slot.index == index)
+            {
+                Some(slot) => slot.properties.push(info),
+                None => slots.push(TextureSlot {
+                    texture_type,
+                    index,
+                    properties: vec![info],
+                }),
+            }
+        }
+        slots.sort_by_key(|slot| (slot.texture_type.to_u32(), slot.index));
+        slots
+    }
+}
+
+/// All `$tex.*` properties of one texture slot, from [`Material::texture_slots`].
+#[derive(Debug, Clone)]
+pub struct TextureSlot {
+    /// Texture type (the properties' semantic)
+    pub texture_type: TextureType,
+    /// Texture index within the type
+    pub index: u32,
+    /// The slot's properties, e.g. `$tex.file`, `$tex.mapmodeu` and non-standard keys
+    pub properties: Vec<MaterialPropertyInfo>,
+}
+
+impl TextureSlot {
+    /// The slot's property with `key`, if present.
+    pub fn property(&self, key: &str) -> Option<&MaterialPropertyInfo> {
+        self.properties.iter().find(|p| p.key == key)
+    }
 }
 
 /// Types of textures that can be applied to materials
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TextureType {
     /// Diffuse texture (base color)
-    Diffuse = sys::aiTextureType::aiTextureType_DIFFUSE as u32,
+    Diffuse,
     /// Specular texture (reflectivity)
-    Specular = sys::aiTextureType::aiTextureType_SPECULAR as u32,
+    Specular,
     /// Ambient texture (ambient lighting)
-    Ambient = sys::aiTextureType::aiTextureType_AMBIENT as u32,
+    Ambient,
     /// Emissive texture (self-illumination)
-    Emissive = sys::aiTextureType::aiTextureType_EMISSIVE as u32,
+    Emissive,
     /// Height texture (displacement mapping)
-    Height = sys::aiTextureType::aiTextureType_HEIGHT as u32,
+    Height,
     /// Normal texture (surface detail)
-    Normals = sys::aiTextureType::aiTextureType_NORMALS as u32,
+    Normals,
     /// Shininess texture (specular power)
-    Shininess = sys::aiTextureType::aiTextureType_SHININESS as u32,
+    Shininess,
     /// Opacity texture (transparency)
-    Opacity = sys::aiTextureType::aiTextureType_OPACITY as u32,
+    Opacity,
     /// Displacement texture (geometry displacement)
-    Displacement = sys::aiTextureType::aiTextureType_DISPLACEMENT as u32,
+    Displacement,
     /// Lightmap texture (pre-computed lighting)
-    Lightmap = sys::aiTextureType::aiTextureType_LIGHTMAP as u32,
+    Lightmap,
     /// Reflection texture (environment mapping)
-    Reflection = sys::aiTextureType::aiTextureType_REFLECTION as u32,
+    Reflection,
     /// Base color texture (PBR albedo)
-    BaseColor = sys::aiTextureType::aiTextureType_BASE_COLOR as u32,
+    BaseColor,
     /// Normal camera texture (camera-space normals)
-    NormalCamera = sys::aiTextureType::aiTextureType_NORMAL_CAMERA as u32,
+    NormalCamera,
     /// Emission color texture (PBR emission)
-    EmissionColor = sys::aiTextureType::aiTextureType_EMISSION_COLOR as u32,
+    EmissionColor,
     /// Metalness texture (PBR metallic)
-    Metalness = sys::aiTextureType::aiTextureType_METALNESS as u32,
+    Metalness,
     /// Diffuse roughness texture (PBR roughness)
-    DiffuseRoughness = sys::aiTextureType::aiTextureType_DIFFUSE_ROUGHNESS as u32,
+    DiffuseRoughness,
     /// Ambient occlusion texture (shadowing)
-    AmbientOcclusion = sys::aiTextureType::aiTextureType_AMBIENT_OCCLUSION as u32,
+    AmbientOcclusion,
     /// Unknown texture type
-    Unknown = sys::aiTextureType::aiTextureType_UNKNOWN as u32,
+    Unknown,
     /// Sheen layer (PBR)
-    Sheen = sys::aiTextureType::aiTextureType_SHEEN as u32,
+    Sheen,
     /// Clearcoat layer (PBR)
-    Clearcoat = sys::aiTextureType::aiTextureType_CLEARCOAT as u32,
+    Clearcoat,
     /// Transmission layer (PBR)
-    Transmission = sys::aiTextureType::aiTextureType_TRANSMISSION as u32,
+    Transmission,
     /// Maya base color (compat)
-    MayaBase = sys::aiTextureType::aiTextureType_MAYA_BASE as u32,
+    MayaBase,
     /// Maya specular (compat)
-    MayaSpecular = sys::aiTextureType::aiTextureType_MAYA_SPECULAR as u32,
+    MayaSpecular,
     /// Maya specular color (compat)
-    MayaSpecularColor = sys::aiTextureType::aiTextureType_MAYA_SPECULAR_COLOR as u32,
+    MayaSpecularColor,
     /// Maya specular roughness (compat)
-    MayaSpecularRoughness = sys::aiTextureType::aiTextureType_MAYA_SPECULAR_ROUGHNESS as u32,
+    MayaSpecularRoughness,
     /// Anisotropy (PBR)
-    Anisotropy = sys::aiTextureType::aiTextureType_ANISOTROPY as u32,
+    Anisotropy,
     /// glTF metallic-roughness packed
-    GltfMetallicRoughness = sys::aiTextureType::aiTextureType_GLTF_METALLIC_ROUGHNESS as u32,
+    GltfMetallicRoughness,
+    /// A semantic this crate has no variant for, e.g. one added by a newer Assimp
+    Other(u32),
 }
 
 /// High-level shading model
@@ -1240,6 +1462,19 @@ impl<'a> MaterialPropertyData<'a> {
         value.data[copy_len] = 0;
         Some(value)
     }
+
+    /// The payload of an `aiString`-encoded property, without the length prefix and
+    /// cut at the first NUL.
+    fn ai_string_bytes(&self) -> Option<&'a [u8]> {
+        let declared_len = self.read_ne_u32(0)? as usize;
+        let payload = self.bytes.get(4..)?;
+        let payload = &payload[..declared_len.min(payload.len())];
+        let end = payload
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(payload.len());
+        Some(&payload[..end])
+    }
 }
 
 impl MaterialPropertyRef {
@@ -1258,11 +1493,9 @@ impl MaterialPropertyRef {
         ai_string_to_str(&self.raw().mKey)
     }
 
-    /// Raw bytes of the key (without assuming NUL-termination).
+    /// Bytes of the key up to the first NUL (length clamped to the buffer).
     pub fn key_bytes(&self) -> &[u8] {
-        let s = &self.raw().mKey;
-        let len = (s.length as usize).min(s.data.len());
-        ffi::slice_from_ptr_len(self, s.data.as_ptr() as *const u8, len)
+        ai_string_bytes(&self.raw().mKey)
     }
 
     /// Property key as owned `String` (allocates).
@@ -1271,8 +1504,13 @@ impl MaterialPropertyRef {
     }
 
     /// Semantic (texture type) if texture-related.
+    ///
+    /// Semantics without a named variant are returned as [`TextureType::Other`];
+    /// `None` means the property is not texture-related (`aiTextureType_NONE`).
     pub fn semantic(&self) -> Option<TextureType> {
-        TextureType::from_u32(self.raw().mSemantic)
+        let raw = self.raw().mSemantic;
+        (raw != sys::aiTextureType::aiTextureType_NONE as u32)
+            .then(|| TextureType::from_u32_lossy(raw))
     }
 
     /// Texture index (0 for non-texture properties).
@@ -1337,6 +1575,19 @@ impl MaterialPropertyRef {
         Some(MaterialStringRef { value })
     }
 
+    /// Decode the value as an owned `String` when stored as `String` (lossy UTF-8).
+    ///
+    /// Reads the 32-bit length prefix and the bytes after it; unlike
+    /// [`MaterialPropertyRef::string_ref`], the value is not limited to `AI_MAXLEN`.
+    pub fn as_string(&self) -> Option<String> {
+        if self.type_info() != PropertyTypeInfo::String {
+            return None;
+        }
+        let d = unsafe { MaterialPropertyData::from_sys(self.raw()) }?;
+        let bytes = d.ai_string_bytes()?;
+        Some(String::from_utf8_lossy(bytes).into_owned())
+    }
+
     /// Read the first element as `i32` when stored as `Integer`.
     pub fn as_i32(&self) -> Option<i32> {
         if self.type_info() != PropertyTypeInfo::Integer {
@@ -1458,33 +1709,94 @@ pub struct MaterialPropertyIterator {
     scene: Scene,
     props: Option<SharedPtr<*const sys::aiMaterialProperty>>,
     count: usize,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
+}
+
+impl MaterialPropertyIterator {
+    fn property(
+        scene: &Scene,
+        props: Option<SharedPtr<*const sys::aiMaterialProperty>>,
+        count: usize,
+        index: usize,
+    ) -> Option<MaterialPropertyRef> {
+        let slice = ffi::slice_from_ptr_len_opt(&(), props?.as_ptr(), count)?;
+        let ptr = *slice.get(index)?;
+        if ptr.is_null() {
+            return None;
+        }
+        MaterialPropertyRef::from_ptr(scene.clone(), ptr)
+    }
 }
 
 impl Iterator for MaterialPropertyIterator {
     type Item = MaterialPropertyRef;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let props = self.props?;
-        let slice = crate::ffi::slice_from_ptr_len_opt(&(), props.as_ptr(), self.count)?;
-        while self.index < slice.len() {
-            let ptr = slice[self.index];
-            self.index += 1;
-            if ptr.is_null() {
-                continue;
-            }
-            if let Some(prop) = MaterialPropertyRef::from_ptr(self.scene.clone(), ptr) {
-                return Some(prop);
-            }
-        }
-        None
+        let (scene, props, count) = (&self.scene, self.props, self.count);
+        self.cursor
+            .next(|index| Self::property(scene, props, count, index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, props, count) = (&self.scene, self.props, self.count);
+        self.cursor
+            .nth(n, |index| Self::property(scene, props, count, index))
+    }
+}
+
+impl DoubleEndedIterator for MaterialPropertyIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, props, count) = (&self.scene, self.props, self.count);
+        self.cursor
+            .next_back(|index| Self::property(scene, props, count, index))
     }
 }
 
+impl ExactSizeIterator for MaterialPropertyIterator {}
+
 impl TextureType {
+    /// Every named texture type, in `aiTextureType` order (without
+    /// [`TextureType::Other`]).
+    pub const ALL: [Self; 27] = [
+        Self::Diffuse,
+        Self::Specular,
+        Self::Ambient,
+        Self::Emissive,
+        Self::Height,
+        Self::Normals,
+        Self::Shininess,
+        Self::Opacity,
+        Self::Displacement,
+        Self::Lightmap,
+        Self::Reflection,
+        Self::BaseColor,
+        Self::NormalCamera,
+        Self::EmissionColor,
+        Self::Metalness,
+        Self::DiffuseRoughness,
+        Self::AmbientOcclusion,
+        Self::Unknown,
+        Self::Sheen,
+        Self::Clearcoat,
+        Self::Transmission,
+        Self::MayaBase,
+        Self::MayaSpecular,
+        Self::MayaSpecularColor,
+        Self::MayaSpecularRoughness,
+        Self::Anisotropy,
+        Self::GltfMetallicRoughness,
+    ];
+
+    /// The `aiTextureType` for the named variants; `None` for [`TextureType::Other`],
+    /// whose value may not be a valid `aiTextureType` discriminant and so must not be
+    /// passed to functions taking the enum. Those slots are read from the properties.
     #[inline]
-    fn to_sys(self) -> sys::aiTextureType {
-        match self {
+    const fn to_sys(self) -> Option<sys::aiTextureType> {
+        Some(match self {
             Self::Diffuse => sys::aiTextureType::aiTextureType_DIFFUSE,
             Self::Specular => sys::aiTextureType::aiTextureType_SPECULAR,
             Self::Ambient => sys::aiTextureType::aiTextureType_AMBIENT,
@@ -1516,58 +1828,38 @@ impl TextureType {
             Self::GltfMetallicRoughness => {
                 sys::aiTextureType::aiTextureType_GLTF_METALLIC_ROUGHNESS
             }
+            Self::Other(_) => return None,
+        })
+    }
+
+    /// Raw `aiTextureType` value (the property semantic); [`TextureType::Other`] passes
+    /// its value through, so `TextureType::from_u32_lossy(v).to_u32() == v`.
+    pub const fn to_u32(self) -> u32 {
+        match self.to_sys() {
+            Some(ty) => ty as u32,
+            None => match self {
+                Self::Other(raw) => raw,
+                _ => 0,
+            },
         }
     }
 
     #[inline]
     fn to_semantic(self) -> u32 {
-        self.to_sys() as u32
+        self.to_u32()
     }
 
-    /// Try convert a raw u32 (aiTextureType) into TextureType safely
+    /// Try convert a raw u32 (aiTextureType) into TextureType safely.
+    ///
+    /// Returns `None` for values without a named variant; see
+    /// [`TextureType::from_u32_lossy`] to keep them.
     pub fn from_u32(v: u32) -> Option<Self> {
-        Some(match v {
-            x if x == sys::aiTextureType::aiTextureType_DIFFUSE as u32 => Self::Diffuse,
-            x if x == sys::aiTextureType::aiTextureType_SPECULAR as u32 => Self::Specular,
-            x if x == sys::aiTextureType::aiTextureType_AMBIENT as u32 => Self::Ambient,
-            x if x == sys::aiTextureType::aiTextureType_EMISSIVE as u32 => Self::Emissive,
-            x if x == sys::aiTextureType::aiTextureType_HEIGHT as u32 => Self::Height,
-            x if x == sys::aiTextureType::aiTextureType_NORMALS as u32 => Self::Normals,
-            x if x == sys::aiTextureType::aiTextureType_SHININESS as u32 => Self::Shininess,
-            x if x == sys::aiTextureType::aiTextureType_OPACITY as u32 => Self::Opacity,
-            x if x == sys::aiTextureType::aiTextureType_DISPLACEMENT as u32 => Self::Displacement,
-            x if x == sys::aiTextureType::aiTextureType_LIGHTMAP as u32 => Self::Lightmap,
-            x if x == sys::aiTextureType::aiTextureType_REFLECTION as u32 => Self::Reflection,
-            x if x == sys::aiTextureType::aiTextureType_BASE_COLOR as u32 => Self::BaseColor,
-            x if x == sys::aiTextureType::aiTextureType_NORMAL_CAMERA as u32 => Self::NormalCamera,
-            x if x == sys::aiTextureType::aiTextureType_EMISSION_COLOR as u32 => {
-                Self::EmissionColor
-            }
-            x if x == sys::aiTextureType::aiTextureType_METALNESS as u32 => Self::Metalness,
-            x if x == sys::aiTextureType::aiTextureType_DIFFUSE_ROUGHNESS as u32 => {
-                Self::DiffuseRoughness
-            }
-            x if x == sys::aiTextureType::aiTextureType_AMBIENT_OCCLUSION as u32 => {
-                Self::AmbientOcclusion
-            }
-            x if x == sys::aiTextureType::aiTextureType_UNKNOWN as u32 => Self::Unknown,
-            x if x == sys::aiTextureType::aiTextureType_SHEEN as u32 => Self::Sheen,
-            x if x == sys::aiTextureType::aiTextureType_CLEARCOAT as u32 => Self::Clearcoat,
-            x if x == sys::aiTextureType::aiTextureType_TRANSMISSION as u32 => Self::Transmission,
-            x if x == sys::aiTextureType::aiTextureType_MAYA_BASE as u32 => Self::MayaBase,
-            x if x == sys::aiTextureType::aiTextureType_MAYA_SPECULAR as u32 => Self::MayaSpecular,
-            x if x == sys::aiTextureType::aiTextureType_MAYA_SPECULAR_COLOR as u32 => {
-                Self::MayaSpecularColor
-            }
-            x if x == sys::aiTextureType::aiTextureType_MAYA_SPECULAR_ROUGHNESS as u32 => {
-                Self::MayaSpecularRoughness
-            }
-            x if x == sys::aiTextureType::aiTextureType_ANISOTROPY as u32 => Self::Anisotropy,
-            x if x == sys::aiTextureType::aiTextureType_GLTF_METALLIC_ROUGHNESS as u32 => {
-                Self::GltfMetallicRoughness
-            }
-            _ => return None,
-        })
+        Self::ALL.into_iter().find(|ty| ty.to_u32() == v)
+    }
+
+    /// Like [`TextureType::from_u32`], but maps unknown values to [`TextureType::Other`].
+    pub fn from_u32_lossy(v: u32) -> Self {
+        Self::from_u32(v).unwrap_or(Self::Other(v))
     }
 }
 
@@ -1713,6 +2005,30 @@ impl BlendMode {
     }
 }
 
+/// glTF alpha mode, from [`Material::alpha_mode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlphaMode {
+    /// Alpha is ignored (`OPAQUE`)
+    Opaque,
+    /// Alpha is compared against the alpha cutoff (`MASK`)
+    Mask,
+    /// Alpha blending (`BLEND`)
+    Blend,
+    /// Any other value, as stored in the file
+    Unknown(String),
+}
+
+impl AlphaMode {
+    fn from_name(name: &str) -> Self {
+        match name {
+            "OPAQUE" => Self::Opaque,
+            "MASK" => Self::Mask,
+            "BLEND" => Self::Blend,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
 /// Which PBR workflow this material uses (heuristic from material.h docs)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PbrWorkflow {
@@ -1888,8 +2204,11 @@ pub enum TextureMapping {
 
 impl TextureMapping {
     fn from_raw(value: sys::aiTextureMapping) -> Self {
-        let value_u32 = value as u32;
-        match value_u32 {
+        Self::from_u32(value as u32)
+    }
+
+    fn from_u32(value: u32) -> Self {
+        match value {
             v if v == sys::aiTextureMapping::aiTextureMapping_UV as u32 => Self::UV,
             v if v == sys::aiTextureMapping::aiTextureMapping_SPHERE as u32 => Self::Sphere,
             v if v == sys::aiTextureMapping::aiTextureMapping_CYLINDER as u32 => Self::Cylinder,
@@ -1898,6 +2217,17 @@ impl TextureMapping {
             other => Self::Other(other),
         }
     }
+
+    fn to_raw(self) -> u32 {
+        match self {
+            Self::UV => sys::aiTextureMapping::aiTextureMapping_UV as u32,
+            Self::Sphere => sys::aiTextureMapping::aiTextureMapping_SPHERE as u32,
+            Self::Cylinder => sys::aiTextureMapping::aiTextureMapping_CYLINDER as u32,
+            Self::Box => sys::aiTextureMapping::aiTextureMapping_BOX as u32,
+            Self::Plane => sys::aiTextureMapping::aiTextureMapping_PLANE as u32,
+            Self::Other(value) => value,
+        }
+    }
 }
 
 /// Texture operations
@@ -1921,8 +2251,11 @@ pub enum TextureOperation {
 
 impl TextureOperation {
     fn from_raw(value: sys::aiTextureOp) -> Self {
-        let value_u32 = value as u32;
-        match value_u32 {
+        Self::from_u32(value as u32)
+    }
+
+    fn from_u32(value: u32) -> Self {
+        match value {
             v if v == sys::aiTextureOp::aiTextureOp_Multiply as u32 => Self::Multiply,
             v if v == sys::aiTextureOp::aiTextureOp_Add as u32 => Self::Add,
             v if v == sys::aiTextureOp::aiTextureOp_Subtract as u32 => Self::Subtract,
@@ -1951,8 +2284,11 @@ pub enum TextureMapMode {
 
 impl TextureMapMode {
     fn from_raw(value: sys::aiTextureMapMode) -> Self {
-        let value_u32 = value as u32;
-        match value_u32 {
+        Self::from_u32(value as u32)
+    }
+
+    fn from_u32(value: u32) -> Self {
+        match value {
             v if v == sys::aiTextureMapMode::aiTextureMapMode_Wrap as u32 => Self::Wrap,
             v if v == sys::aiTextureMapMode::aiTextureMapMode_Clamp as u32 => Self::Clamp,
             v if v == sys::aiTextureMapMode::aiTextureMapMode_Mirror as u32 => Self::Mirror,
@@ -1960,6 +2296,16 @@ impl TextureMapMode {
             other => Self::Other(other),
         }
     }
+
+    fn to_raw(self) -> u32 {
+        match self {
+            Self::Wrap => sys::aiTextureMapMode::aiTextureMapMode_Wrap as u32,
+            Self::Clamp => sys::aiTextureMapMode::aiTextureMapMode_Clamp as u32,
+            Self::Mirror => sys::aiTextureMapMode::aiTextureMapMode_Mirror as u32,
+            Self::Decal => sys::aiTextureMapMode::aiTextureMapMode_Decal as u32,
+            Self::Other(value) => value,
+        }
+    }
 }
 
 /// Information about a texture applied to a material
@@ -1990,10 +2336,15 @@ impl TextureInfoRef {
         ai_string_to_str(&self.path)
     }
 
-    /// Raw bytes of the path (without assuming NUL-termination).
+    /// Bytes of the path up to the first NUL (length clamped to the buffer).
     pub fn path_bytes(&self) -> &[u8] {
-        let len = (self.path.length as usize).min(self.path.data.len());
-        ffi::slice_from_ptr_len(self, self.path.data.as_ptr() as *const u8, len)
+        ai_string_bytes(&self.path)
+    }
+
+    /// All `length` bytes of the path (clamped to the buffer), including any embedded
+    /// NULs.
+    pub fn path_raw_bytes(&self) -> &[u8] {
+        ai_string_raw_bytes(&self.path)
     }
 
     /// Borrow the underlying Assimp `aiString`.
@@ -2046,7 +2397,23 @@ pub struct TextureInfo {
 }
 
 /// UV transform information
-#[derive(Debug, Clone, Copy)]
+///
+/// The transform follows Assimp's convention: scale, then translate, then rotate about the
+/// pivot `(0.5, 0.5)` by the negated angle, i.e. clockwise for positive `rotation` with U
+/// right and V up:
+///
+/// ```text
+/// (x, y) = (sx * u + tx - 0.5, sy * v + ty - 0.5)
+/// u' = x * cos(r) + y * sin(r) + 0.5
+/// v' = -x * sin(r) + y * cos(r) + 0.5
+/// ```
+///
+/// Assimp's glTF importer converts `KHR_texture_transform` into this form (storing the
+/// negated rotation and folding the pivot into `translation`), so
+/// [`UVTransform::apply`], [`UVTransform::to_matrix3`] and
+/// [`Mesh::texture_coords_transformed`](crate::mesh::Mesh::texture_coords_transformed)
+/// reproduce the glTF transform on the imported, V-flipped coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct UVTransform {
     /// Translation offset for UV coordinates
     pub translation: Vector2D,
@@ -2056,6 +2423,44 @@ pub struct UVTransform {
     pub rotation: f32,
 }
 
+impl UVTransform {
+    /// The transform that leaves coordinates unchanged.
+    pub const IDENTITY: Self = Self {
+        translation: Vector2D::new(0.0, 0.0),
+        scaling: Vector2D::new(1.0, 1.0),
+        rotation: 0.0,
+    };
+
+    /// Matrix form of the transform, for UV row vectors: `[u', v', 1] = [u, v, 1] * m`.
+    ///
+    /// The translation sits in the third row. Shaders that multiply column vectors
+    /// (`m * uv`) need the transpose.
+    pub fn to_matrix3(&self) -> Matrix3x3 {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (sx, sy) = (self.scaling.x, self.scaling.y);
+        let (x, y) = (self.translation.x - 0.5, self.translation.y - 0.5);
+        Matrix3x3::from_cols(
+            Vector3D::new(sx * cos, sy * sin, x * cos + y * sin + 0.5),
+            Vector3D::new(-sx * sin, sy * cos, -x * sin + y * cos + 0.5),
+            Vector3D::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Transform a single UV coordinate.
+    pub fn apply(&self, uv: Vector2D) -> Vector2D {
+        let (sin, cos) = self.rotation.sin_cos();
+        let x = uv.x * self.scaling.x + self.translation.x - 0.5;
+        let y = uv.y * self.scaling.y + self.translation.y - 0.5;
+        Vector2D::new(x * cos + y * sin + 0.5, -x * sin + y * cos + 0.5)
+    }
+}
+
+impl Default for UVTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
 bitflags::bitflags! {
     /// Texture flags (material.h: aiTextureFlags)
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -0,0 +1,448 @@
+//! Authoring and editing material properties.
+//!
+//! [`MaterialBuilder`] collects typed properties into an [`OwnedMaterial`], which can be
+//! added to an [`OwnedScene`] or applied over an existing material through
+//! [`MaterialMut`]:
+//!
+//! ```rust,no_run
+//! # use asset_importer::{Scene, material::{MaterialBuilder, material_keys}, scene::OwnedScene};
+//! # use asset_importer::types::Color4D;
+//! # fn run(scene: &Scene) -> asset_importer::Result<()> {
+//! let overrides = MaterialBuilder::new()
+//!     .with_color(material_keys::BASE_COLOR, Color4D::new(0.8, 0.1, 0.1, 1.0))
+//!     .with_float(material_keys::METALLIC_FACTOR, 0.0)
+//!     .build()?;
+//!
+//! let mut owned = OwnedScene::copy_of(scene)?;
+//! owned.material_mut(0)?.apply(&overrides)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{ffi::CStr, ffi::CString, marker::PhantomData};
+
+use crate::{
+    error::{Error, Result},
+    material::{TextureMapMode, TextureMapping, TextureType},
+    scene::OwnedScene,
+    sys,
+    types::{Color3D, Color4D},
+};
+
+/// Longest string payload Assimp can read back into an `aiString` (excluding the NUL).
+const MAX_STRING_LEN: usize = sys::AI_MAXLEN as usize - 1;
+
+#[derive(Debug, Clone, PartialEq)]
+enum PropertyValue {
+    Floats(Vec<f32>),
+    Ints(Vec<i32>),
+    String(String),
+}
+
+/// One property as stored by `aiMaterial`: key, texture semantic/index and payload.
+#[derive(Debug, Clone, PartialEq)]
+struct Property {
+    key: CString,
+    semantic: u32,
+    index: u32,
+    value: PropertyValue,
+}
+
+impl Property {
+    fn new(key: &CStr, value: PropertyValue) -> Self {
+        Self {
+            key: key.to_owned(),
+            semantic: 0,
+            index: 0,
+            value,
+        }
+    }
+
+    fn texture(key: &CStr, texture_type: TextureType, index: u32, value: PropertyValue) -> Self {
+        Self {
+            key: key.to_owned(),
+            semantic: texture_type.to_u32(),
+            index,
+            value,
+        }
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.key.as_bytes().len() > MAX_STRING_LEN {
+            return Err(Error::invalid_parameter(format!(
+                "Material property key is longer than {} bytes",
+                MAX_STRING_LEN
+            )));
+        }
+        match &self.value {
+            PropertyValue::Floats(values) if values.is_empty() => Err(Error::invalid_parameter(
+                "Material property array must not be empty",
+            )),
+            PropertyValue::String(value) if value.len() > MAX_STRING_LEN => {
+                Err(Error::invalid_parameter(format!(
+                    "Material string property is longer than {} bytes",
+                    MAX_STRING_LEN
+                )))
+            }
+            PropertyValue::String(value) if value.contains('\0') => Err(Error::invalid_parameter(
+                "Material string property contains NUL byte",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Encode the payload in the layout `aiMaterial` uses for the property type.
+    fn encode(&self) -> (sys::aiPropertyTypeInfo, Vec<u8>) {
+        match &self.value {
+            PropertyValue::Floats(values) => (
+                sys::aiPropertyTypeInfo::aiPTI_Float,
+                values.iter().flat_map(|v| v.to_ne_bytes()).collect(),
+            ),
+            PropertyValue::Ints(values) => (
+                sys::aiPropertyTypeInfo::aiPTI_Integer,
+                values.iter().flat_map(|v| v.to_ne_bytes()).collect(),
+            ),
+            PropertyValue::String(value) => {
+                // aiString layout: u32 length, the bytes, then a terminating NUL.
+                let mut data = Vec::with_capacity(value.len() + 5);
+                data.extend_from_slice(&(value.len() as u32).to_ne_bytes());
+                data.extend_from_slice(value.as_bytes());
+                data.push(0);
+                (sys::aiPropertyTypeInfo::aiPTI_String, data)
+            }
+        }
+    }
+
+    fn same_slot(&self, other: &Property) -> bool {
+        self.key == other.key && self.semantic == other.semantic && self.index == other.index
+    }
+
+    /// # Safety
+    /// `material` must point to a live `aiMaterial` that nothing else is reading.
+    unsafe fn write_to(&self, material: *mut sys::aiMaterial) -> Result<()> {
+        let (ty, data) = self.encode();
+        let ok = unsafe {
+            sys::aiMaterialSetPropertyRust(
+                material,
+                self.key.as_ptr(),
+                self.semantic,
+                self.index,
+                ty,
+                data.as_ptr().cast(),
+                data.len() as u32,
+            )
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(Error::from_bridge_or_assimp())
+        }
+    }
+}
+
+/// A texture reference for [`MaterialBuilder::with_texture`] and
+/// [`MaterialMut::set_texture`].
+///
+/// Only the path is required; the other settings are written only when set, so
+/// Assimp's defaults (UV mapping, channel 0, wrap) apply otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialTexture {
+    path: String,
+    mapping: Option<TextureMapping>,
+    uv_index: Option<u32>,
+    map_modes: Option<[TextureMapMode; 2]>,
+    blend_factor: Option<f32>,
+}
+
+impl MaterialTexture {
+    /// Reference a texture file (or an embedded texture as `*N`).
+    pub fn new<S: Into<String>>(path: S) -> Self {
+        Self {
+            path: path.into(),
+            mapping: None,
+            uv_index: None,
+            map_modes: None,
+            blend_factor: None,
+        }
+    }
+
+    /// Set the mapping mode (`$tex.mapping`).
+    pub fn with_mapping(mut self, mapping: TextureMapping) -> Self {
+        self.mapping = Some(mapping);
+        self
+    }
+
+    /// Set the UV channel the texture samples (`$tex.uvwsrc`).
+    pub fn with_uv_index(mut self, uv_index: u32) -> Self {
+        self.uv_index = Some(uv_index);
+        self
+    }
+
+    /// Set the wrap modes for U and V (`$tex.mapmodeu` / `$tex.mapmodev`).
+    pub fn with_map_modes(mut self, u: TextureMapMode, v: TextureMapMode) -> Self {
+        self.map_modes = Some([u, v]);
+        self
+    }
+
+    /// Set the blend factor (`$tex.blend`).
+    pub fn with_blend_factor(mut self, blend_factor: f32) -> Self {
+        self.blend_factor = Some(blend_factor);
+        self
+    }
+
+    fn properties(&self, texture_type: TextureType, index: u32) -> Vec<Property> {
+        let slot = |key: &CStr, value| Property::texture(key, texture_type, index, value);
+        let mut properties = vec![slot(c"$tex.file", PropertyValue::String(self.path.clone()))];
+        if let Some(mapping) = self.mapping {
+            properties.push(slot(
+                c"$tex.mapping",
+                PropertyValue::Ints(vec![mapping.to_raw() as i32]),
+            ));
+        }
+        if let Some(uv_index) = self.uv_index {
+            properties.push(slot(
+                c"$tex.uvwsrc",
+                PropertyValue::Ints(vec![uv_index as i32]),
+            ));
+        }
+        if let Some([u, v]) = self.map_modes {
+            properties.push(slot(
+                c"$tex.mapmodeu",
+                PropertyValue::Ints(vec![u.to_raw() as i32]),
+            ));
+            properties.push(slot(
+                c"$tex.mapmodev",
+                PropertyValue::Ints(vec![v.to_raw() as i32]),
+            ));
+        }
+        if let Some(blend_factor) = self.blend_factor {
+            properties.push(slot(
+                c"$tex.blend",
+                PropertyValue::Floats(vec![blend_factor]),
+            ));
+        }
+        properties
+    }
+}
+
+/// Accumulates typed material properties; see the [module docs](self).
+///
+/// Keys are usually the [`material_keys`](crate::material::material_keys) constants.
+/// Setting a key that is already present replaces the earlier value.
+#[derive(Debug, Clone, Default)]
+pub struct MaterialBuilder {
+    properties: Vec<Property>,
+}
+
+impl MaterialBuilder {
+    /// Start an empty material.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn set(mut self, property: Property) -> Self {
+        match self.properties.iter_mut().find(|p| p.same_slot(&property)) {
+            Some(existing) => *existing = property,
+            None => self.properties.push(property),
+        }
+        self
+    }
+
+    /// Set the material name (`?mat.name`).
+    pub fn with_name<S: Into<String>>(self, name: S) -> Self {
+        self.with_string(crate::material::material_keys::NAME, name)
+    }
+
+    /// Set an RGBA color property.
+    pub fn with_color(self, key: &CStr, color: Color4D) -> Self {
+        self.with_floats(key, &[color.x, color.y, color.z, color.w])
+    }
+
+    /// Set an RGB color property.
+    pub fn with_color3(self, key: &CStr, color: Color3D) -> Self {
+        self.with_floats(key, &[color.x, color.y, color.z])
+    }
+
+    /// Set a float property.
+    pub fn with_float(self, key: &CStr, value: f32) -> Self {
+        self.with_floats(key, &[value])
+    }
+
+    /// Set a float array property.
+    pub fn with_floats(self, key: &CStr, values: &[f32]) -> Self {
+        self.set(Property::new(key, PropertyValue::Floats(values.to_vec())))
+    }
+
+    /// Set an integer property (also used for booleans such as `$mat.twosided`).
+    pub fn with_int(self, key: &CStr, value: i32) -> Self {
+        self.set(Property::new(key, PropertyValue::Ints(vec![value])))
+    }
+
+    /// Set a string property.
+    pub fn with_string<S: Into<String>>(self, key: &CStr, value: S) -> Self {
+        self.set(Property::new(key, PropertyValue::String(value.into())))
+    }
+
+    /// Set the texture in slot `index` of `texture_type`.
+    pub fn with_texture(
+        self,
+        texture_type: TextureType,
+        index: u32,
+        texture: MaterialTexture,
+    ) -> Self {
+        texture
+            .properties(texture_type, index)
+            .into_iter()
+            .fold(self, Self::set)
+    }
+
+    /// Validate the properties and finish the material.
+    ///
+    /// Fails when a key or string value does not fit in an `aiString`, a string contains
+    /// a NUL byte, or a float array is empty.
+    pub fn build(self) -> Result<OwnedMaterial> {
+        for property in &self.properties {
+            property.check()?;
+        }
+        Ok(OwnedMaterial {
+            properties: self.properties,
+        })
+    }
+}
+
+/// A validated set of material properties produced by [`MaterialBuilder`].
+///
+/// Add it to a scene with [`OwnedScene::add_material`] or apply it over an existing
+/// material with [`MaterialMut::apply`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedMaterial {
+    properties: Vec<Property>,
+}
+
+impl OwnedMaterial {
+    /// Number of `aiMaterial` properties this material writes.
+    pub fn len(&self) -> usize {
+        self.properties.len()
+    }
+
+    /// Whether the material has no properties.
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    /// # Safety
+    /// `material` must point to a live `aiMaterial` that nothing else is reading.
+    pub(crate) unsafe fn write_to(&self, material: *mut sys::aiMaterial) -> Result<()> {
+        for property in &self.properties {
+            unsafe { property.write_to(material) }?;
+        }
+        Ok(())
+    }
+}
+
+/// Mutable access to one material of an [`OwnedScene`], from
+/// [`OwnedScene::material_mut`].
+///
+/// Every setter replaces an existing property with the same key (and texture slot).
+#[derive(Debug)]
+pub struct MaterialMut<'a> {
+    material: *mut sys::aiMaterial,
+    _scene: PhantomData<&'a mut OwnedScene>,
+}
+
+impl<'a> MaterialMut<'a> {
+    /// # Safety
+    /// `material` must belong to the scene uniquely borrowed for `'a`.
+    pub(crate) unsafe fn new(material: *mut sys::aiMaterial) -> Self {
+        Self {
+            material,
+            _scene: PhantomData,
+        }
+    }
+
+    fn set(&mut self, property: Property) -> Result<&mut Self> {
+        property.check()?;
+        unsafe { property.write_to(self.material) }?;
+        Ok(self)
+    }
+
+    /// Set the material name (`?mat.name`).
+    pub fn set_name(&mut self, name: &str) -> Result<&mut Self> {
+        self.set_string(crate::material::material_keys::NAME, name)
+    }
+
+    /// Set an RGBA color property.
+    pub fn set_color(&mut self, key: &CStr, color: Color4D) -> Result<&mut Self> {
+        self.set_floats(key, &[color.x, color.y, color.z, color.w])
+    }
+
+    /// Set an RGB color property.
+    pub fn set_color3(&mut self, key: &CStr, color: Color3D) -> Result<&mut Self> {
+        self.set_floats(key, &[color.x, color.y, color.z])
+    }
+
+    /// Set a float property.
+    pub fn set_float(&mut self, key: &CStr, value: f32) -> Result<&mut Self> {
+        self.set_floats(key, &[value])
+    }
+
+    /// Set a float array property.
+    pub fn set_floats(&mut self, key: &CStr, values: &[f32]) -> Result<&mut Self> {
+        self.set(Property::new(key, PropertyValue::Floats(values.to_vec())))
+    }
+
+    /// Set an integer property.
+    pub fn set_int(&mut self, key: &CStr, value: i32) -> Result<&mut Self> {
+        self.set(Property::new(key, PropertyValue::Ints(vec![value])))
+    }
+
+    /// Set a string property.
+    pub fn set_string(&mut self, key: &CStr, value: &str) -> Result<&mut Self> {
+        self.set(Property::new(key, PropertyValue::String(value.to_owned())))
+    }
+
+    /// Set the texture in slot `index` of `texture_type`.
+    pub fn set_texture(
+        &mut self,
+        texture_type: TextureType,
+        index: u32,
+        texture: &MaterialTexture,
+    ) -> Result<&mut Self> {
+        for property in texture.properties(texture_type, index) {
+            self.set(property)?;
+        }
+        Ok(self)
+    }
+
+    /// Write every property of `material` over this one.
+    pub fn apply(&mut self, material: &OwnedMaterial) -> Result<&mut Self> {
+        unsafe { material.write_to(self.material) }?;
+        Ok(self)
+    }
+
+    /// Remove a non-texture property. Returns whether it existed.
+    pub fn remove(&mut self, key: &CStr) -> Result<bool> {
+        self.remove_raw(key, 0, 0)
+    }
+
+    /// Remove a property of texture slot `index` of `texture_type` (e.g. `$tex.file`).
+    /// Returns whether it existed.
+    pub fn remove_texture_property(
+        &mut self,
+        key: &CStr,
+        texture_type: TextureType,
+        index: u32,
+    ) -> Result<bool> {
+        self.remove_raw(key, texture_type.to_u32(), index)
+    }
+
+    fn remove_raw(&mut self, key: &CStr, semantic: u32, index: u32) -> Result<bool> {
+        match unsafe {
+            sys::aiMaterialRemovePropertyRust(self.material, key.as_ptr(), semantic, index)
+        } {
+            1 => Ok(true),
+            0 => Ok(false),
+            _ => Err(Error::from_bridge_or_assimp()),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Grouping of materials that differ only in name or by float noise.
+
+use std::collections::BTreeSet;
+
+use super::{Material, PropertyTypeInfo, TextureType, material_keys};
+use crate::scene::Scene;
+
+/// A material's properties in comparable form.
+struct Signature {
+    /// `(key, semantic, index, value)` for every property except the name and texture
+    /// files, sorted by slot
+    properties: Vec<(Vec<u8>, u32, u32, Value)>,
+    /// `(semantic, path)` of every `$tex.file` property
+    textures: BTreeSet<(u32, Vec<u8>)>,
+}
+
+enum Value {
+    Floats(Vec<f64>),
+    Integers(Vec<i32>),
+    Bytes(Vec<u8>),
+}
+
+impl Value {
+    fn matches(&self, other: &Value, tolerance: f32) -> bool {
+        match (self, other) {
+            (Value::Floats(a), Value::Floats(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b)
+                        .all(|(a, b)| a == b || (a - b).abs() <= f64::from(tolerance))
+            }
+            (Value::Integers(a), Value::Integers(b)) => a == b,
+            (Value::Bytes(a), Value::Bytes(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// Decode a payload of `N`-byte native-endian values; the data may be unaligned.
+fn decode<const N: usize, T>(bytes: &[u8], from_ne: fn([u8; N]) -> T) -> Vec<T> {
+    bytes
+        .chunks_exact(N)
+        .map(|chunk| from_ne(chunk.try_into().expect("chunk of N bytes")))
+        .collect()
+}
+
+impl Signature {
+    fn of(material: &Material) -> Self {
+        let name = material_keys::NAME.to_bytes();
+        let texture_file = material_keys::TEXTURE_FILE.to_bytes();
+        let mut properties = Vec::new();
+        let mut textures = BTreeSet::new();
+        for prop in material.properties() {
+            let key = prop.key_bytes();
+            let semantic = prop.semantic().map_or(0, TextureType::to_u32);
+            if key == name {
+                continue;
+            }
+            if key == texture_file {
+                let path = prop.string_ref().map(|s| s.as_bytes().to_vec());
+                textures.insert((semantic, path.unwrap_or_else(|| prop.data().to_vec())));
+                continue;
+            }
+            let data = prop.data();
+            let value = match prop.type_info() {
+                PropertyTypeInfo::Float => {
+                    Value::Floats(decode(data, |b| f32::from_ne_bytes(b).into()))
+                }
+                PropertyTypeInfo::Double => Value::Floats(decode(data, f64::from_ne_bytes)),
+                PropertyTypeInfo::Integer => Value::Integers(decode(data, i32::from_ne_bytes)),
+                _ => Value::Bytes(data.to_vec()),
+            };
+            properties.push((key.to_vec(), semantic, prop.index(), value));
+        }
+        properties.sort_by(|a, b| (&a.0, a.1, a.2).cmp(&(&b.0, b.1, b.2)));
+        Self {
+            properties,
+            textures,
+        }
+    }
+
+    fn matches(&self, other: &Signature, tolerance: f32) -> bool {
+        self.textures == other.textures
+            && self.properties.len() == other.properties.len()
+            && self
+                .properties
+                .iter()
+                .zip(&other.properties)
+                .all(|(a, b)| (&a.0, a.1, a.2) == (&b.0, b.1, b.2) && a.3.matches(&b.3, tolerance))
+    }
+}
+
+impl Scene {
+    /// Group materials that are equal apart from their name.
+    ///
+    /// Two materials match when they have the same set of properties (ignoring
+    /// `?mat.name`), the same texture paths per texture type, float and double values
+    /// within `tolerance` of each other and every other value byte-for-byte equal. This
+    /// catches near-duplicates that
+    /// [`PostProcessSteps::REMOVE_REDUNDANT_MATERIALS`](crate::postprocess::PostProcessSteps::REMOVE_REDUNDANT_MATERIALS)
+    /// keeps because it compares exactly.
+    ///
+    /// Returns the groups with more than one material, each listing material indices in
+    /// ascending order; the first index of a group is the one every other member was
+    /// compared against. Remap mesh material indices from these as needed; the scene is
+    /// not changed.
+    pub fn material_duplicates(&self, tolerance: f32) -> Vec<Vec<usize>> {
+        let signatures: Vec<Signature> = self.materials().map(|m| Signature::of(&m)).collect();
+        let mut grouped = vec![false; signatures.len()];
+        let mut groups = Vec::new();
+        for (first, signature) in signatures.iter().enumerate() {
+            if grouped[first] {
+                continue;
+            }
+            let mut group = vec![first];
+            for (other, candidate) in signatures.iter().enumerate().skip(first + 1) {
+                if !grouped[other] && signature.matches(candidate, tolerance) {
+                    grouped[other] = true;
+                    group.push(other);
+                }
+            }
+            if group.len() > 1 {
+                groups.push(group);
+            }
+        }
+        groups
+    }
+}
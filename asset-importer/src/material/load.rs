@@ -0,0 +1,262 @@
+//! Decoding material textures into RGBA8 images with the `image` crate.
+
+use std::path::{Path, PathBuf};
+
+use super::{Material, PathResolveOptions, ResolvedPath, TextureType, path::resolve_texture_path};
+use crate::{
+    error::{Error, ErrorContext, Operation, Result},
+    scene::Scene,
+    texture::{Texture, TextureDataRef, texels_to_rgba8},
+};
+
+/// Where [`Material::load_texture_image`] looks for texture files.
+///
+/// Relative paths are tried against the model directory first, then against each search
+/// path in the order they were added. Paths are cleaned up with the
+/// [`PathResolveOptions`] first, so absolute paths from the exporting machine are looked
+/// up by file name.
+#[derive(Clone, Default)]
+pub struct TextureLoadContext {
+    model_dir: PathBuf,
+    search_paths: Vec<PathBuf>,
+    scene: Option<Scene>,
+    fallback: bool,
+    options: PathResolveOptions,
+}
+
+impl std::fmt::Debug for TextureLoadContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextureLoadContext")
+            .field("model_dir", &self.model_dir)
+            .field("search_paths", &self.search_paths)
+            .field("scene", &self.scene.is_some())
+            .field("fallback", &self.fallback)
+            .field("options", &self.options)
+            .finish()
+    }
+}
+
+impl TextureLoadContext {
+    /// Context for a model stored in `model_dir`.
+    pub fn new(model_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            model_dir: model_dir.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Context for the model file at `model_path`, using its parent directory.
+    pub fn for_model_file(model_path: impl AsRef<Path>) -> Self {
+        Self::new(
+            model_path
+                .as_ref()
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Add a directory to search after the model directory.
+    pub fn with_search_path(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.search_paths.push(dir.into());
+        self
+    }
+
+    /// Scene whose embedded textures `*N` paths refer to. Defaults to the scene the
+    /// material belongs to.
+    pub fn with_scene(mut self, scene: &Scene) -> Self {
+        self.scene = Some(scene.clone());
+        self
+    }
+
+    /// Return a 1x1 placeholder instead of an error when a texture is missing.
+    ///
+    /// Normal maps get a flat normal (`128, 128, 255, 255`), everything else opaque white.
+    pub fn with_fallback(mut self, enable: bool) -> Self {
+        self.fallback = enable;
+        self
+    }
+
+    /// Path clean-up applied before searching, see [`PathResolveOptions`].
+    pub fn with_path_options(mut self, options: PathResolveOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// The model directory.
+    pub fn model_dir(&self) -> &Path {
+        &self.model_dir
+    }
+
+    /// Additional search directories, in search order.
+    pub fn search_paths(&self) -> &[PathBuf] {
+        &self.search_paths
+    }
+}
+
+/// Where a [`LoadedImage`] came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageSource {
+    /// Decoded from a file on disk
+    External(PathBuf),
+    /// Decoded from [`Scene::texture`] with this index
+    Embedded(usize),
+    /// The 1x1 placeholder for a missing texture
+    Fallback,
+}
+
+/// A texture decoded to tightly packed RGBA8.
+///
+/// Pixel values are stored as encoded; whether they are sRGB or linear depends on the
+/// [`texture_type`](Self::texture_type) and is left to the caller.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedImage {
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+    /// `width * height * 4` bytes, rows top to bottom
+    pub rgba: Vec<u8>,
+    /// Texture slot the image was loaded for
+    pub texture_type: TextureType,
+    /// Where the pixels came from
+    pub source: ImageSource,
+}
+
+impl LoadedImage {
+    fn fallback(texture_type: TextureType) -> Self {
+        let rgba = match texture_type {
+            TextureType::Normals | TextureType::NormalCamera => vec![128, 128, 255, 255],
+            _ => vec![255; 4],
+        };
+        Self {
+            width: 1,
+            height: 1,
+            rgba,
+            texture_type,
+            source: ImageSource::Fallback,
+        }
+    }
+}
+
+impl Material {
+    /// Load texture `index` of type `ty` and decode it to RGBA8 (requires the `image`
+    /// feature).
+    ///
+    /// Embedded textures (`*N`, or a file name matching an embedded texture) are decoded
+    /// from the scene; other paths are searched for as described on
+    /// [`TextureLoadContext`]. Formats other than PNG need the matching `image` crate
+    /// features enabled in your own `Cargo.toml`.
+    ///
+    /// # Errors
+    /// Fails when the material has no such texture or the file cannot be found, unless
+    /// [`TextureLoadContext::with_fallback`] is set, and whenever a texture that was found
+    /// cannot be read or decoded.
+    pub fn load_texture_image(
+        &self,
+        ty: TextureType,
+        index: usize,
+        ctx: &TextureLoadContext,
+    ) -> Result<LoadedImage> {
+        let Some(info) = self.texture_ref(ty, index) else {
+            return missing(ctx, ty, || {
+                Error::invalid_parameter(format!("material has no {ty:?} texture {index}"))
+            });
+        };
+        let raw = info.path_str();
+        let scene = ctx.scene.as_ref().unwrap_or(&self.scene);
+
+        let embedded = match resolve_texture_path(&raw, &ctx.model_dir, ctx.options) {
+            ResolvedPath::Empty => {
+                return missing(ctx, ty, || {
+                    Error::invalid_parameter(format!("{ty:?} texture {index} has an empty path"))
+                });
+            }
+            ResolvedPath::Embedded(i) => Some(i),
+            _ => embedded_index_by_name(scene, &raw),
+        };
+        if let Some(i) = embedded {
+            let texture = scene.texture(i).ok_or_else(|| {
+                Error::invalid_parameter(format!("embedded texture {i} does not exist"))
+            })?;
+            let (width, height, rgba) = decode_embedded(&texture)?;
+            return Ok(LoadedImage {
+                width,
+                height,
+                rgba,
+                texture_type: ty,
+                source: ImageSource::Embedded(i),
+            });
+        }
+
+        let found = std::iter::once(&ctx.model_dir)
+            .chain(&ctx.search_paths)
+            .find_map(|dir| match resolve_texture_path(&raw, dir, ctx.options) {
+                ResolvedPath::Found(path) => Some(path),
+                _ => None,
+            });
+        let Some(path) = found else {
+            return missing(ctx, ty, || {
+                Error::file_error(format!("texture not found: {raw}"))
+            });
+        };
+        let (width, height, rgba) = decode_file(&path)
+            .map_err(|err| err.with_context(ErrorContext::new(Operation::Io).with_path(&path)))?;
+        Ok(LoadedImage {
+            width,
+            height,
+            rgba,
+            texture_type: ty,
+            source: ImageSource::External(path),
+        })
+    }
+}
+
+fn missing(
+    ctx: &TextureLoadContext,
+    ty: TextureType,
+    error: impl FnOnce() -> Error,
+) -> Result<LoadedImage> {
+    if ctx.fallback {
+        Ok(LoadedImage::fallback(ty))
+    } else {
+        Err(error())
+    }
+}
+
+/// Index of the embedded texture whose file name matches `raw`, compared by file name so
+/// `textures/wood.png` finds an FBX-embedded `C:\art\wood.png`.
+fn embedded_index_by_name(scene: &Scene, raw: &str) -> Option<usize> {
+    let file_name = |path: &str| path.rsplit(['/', '\\']).next().unwrap_or("").to_string();
+    let wanted = file_name(raw);
+    if wanted.is_empty() {
+        return None;
+    }
+    scene.textures().position(|texture| {
+        texture
+            .filename_str()
+            .is_some_and(|name| file_name(&name).eq_ignore_ascii_case(&wanted))
+    })
+}
+
+fn decode_embedded(texture: &Texture) -> Result<(u32, u32, Vec<u8>)> {
+    match texture.data_ref()? {
+        TextureDataRef::Compressed(bytes) => decode_bytes(bytes),
+        TextureDataRef::Texels(texels) => {
+            Ok((texture.width(), texture.height(), texels_to_rgba8(texels)))
+        }
+    }
+}
+
+fn decode_file(path: &Path) -> Result<(u32, u32, Vec<u8>)> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| Error::file_error(format!("Failed to read texture: {e}")))?;
+    decode_bytes(&bytes)
+}
+
+fn decode_bytes(bytes: &[u8]) -> Result<(u32, u32, Vec<u8>)> {
+    let image = image::load_from_memory(bytes)
+        .map_err(|e| Error::file_error(format!("Failed to decode texture: {e}")))?
+        .to_rgba8();
+    Ok((image.width(), image.height(), image.into_raw()))
+}
@@ -0,0 +1,302 @@
+//! Resolving texture paths stored in materials against the model's directory.
+
+use std::path::{Path, PathBuf};
+
+use super::{Material, TextureInfo, TextureInfoRef, TextureType};
+
+/// How [`TextureInfo::resolve_path`] cleans up a texture path before joining it to the
+/// model directory.
+///
+/// The default enables every clean-up step and rejects `..` that escapes the model
+/// directory, which suits files exported on another machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathResolveOptions {
+    /// Replace absolute paths (`C:\Users\...\wood.png`, `/home/.../wood.png`) with their
+    /// file name, looked up in the model directory.
+    pub strip_absolute: bool,
+    /// Decode `%XX` escapes (`my%20texture.png`) and drop a leading `file://`.
+    pub percent_decode: bool,
+    /// Treat `\` as a path separator on every platform.
+    pub normalize_separators: bool,
+    /// Allow `..` components to leave the model directory. When `false`, a path that
+    /// would escape is reduced to its file name inside the model directory.
+    pub allow_parent_dirs: bool,
+}
+
+impl Default for PathResolveOptions {
+    fn default() -> Self {
+        Self {
+            strip_absolute: true,
+            percent_decode: true,
+            normalize_separators: true,
+            allow_parent_dirs: false,
+        }
+    }
+}
+
+impl PathResolveOptions {
+    /// Options that keep the path as stored, only joining relative paths to the model
+    /// directory.
+    pub fn verbatim() -> Self {
+        Self {
+            strip_absolute: false,
+            percent_decode: false,
+            normalize_separators: false,
+            allow_parent_dirs: true,
+        }
+    }
+
+    /// Set [`strip_absolute`](Self::strip_absolute).
+    pub fn with_strip_absolute(mut self, enable: bool) -> Self {
+        self.strip_absolute = enable;
+        self
+    }
+
+    /// Set [`percent_decode`](Self::percent_decode).
+    pub fn with_percent_decode(mut self, enable: bool) -> Self {
+        self.percent_decode = enable;
+        self
+    }
+
+    /// Set [`normalize_separators`](Self::normalize_separators).
+    pub fn with_normalize_separators(mut self, enable: bool) -> Self {
+        self.normalize_separators = enable;
+        self
+    }
+
+    /// Set [`allow_parent_dirs`](Self::allow_parent_dirs).
+    pub fn with_allow_parent_dirs(mut self, enable: bool) -> Self {
+        self.allow_parent_dirs = enable;
+        self
+    }
+}
+
+/// Result of resolving a texture path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedPath {
+    /// The cleaned-up path points at an existing file.
+    Found(PathBuf),
+    /// The best guess for the file, which does not exist on disk.
+    Candidate(PathBuf),
+    /// An embedded texture reference (`*N`), indexing [`Scene::textures`](crate::Scene::textures).
+    Embedded(usize),
+    /// The material stores an empty path.
+    Empty,
+}
+
+impl ResolvedPath {
+    /// The file path, for [`Found`](Self::Found) and [`Candidate`](Self::Candidate).
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Found(path) | Self::Candidate(path) => Some(path),
+            Self::Embedded(_) | Self::Empty => None,
+        }
+    }
+
+    /// Whether the texture is available: an existing file or an embedded texture.
+    pub fn is_available(&self) -> bool {
+        matches!(self, Self::Found(_) | Self::Embedded(_))
+    }
+}
+
+/// One texture of a material with its resolved path, from
+/// [`Material::resolved_texture_paths`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedTexture {
+    /// Texture type
+    pub texture_type: TextureType,
+    /// Texture index within the type
+    pub index: usize,
+    /// Path as stored in the material
+    pub path: String,
+    /// Resolution result
+    pub resolved: ResolvedPath,
+}
+
+impl TextureInfo {
+    /// Resolve [`path`](Self::path) against the directory of the model file.
+    ///
+    /// See [`PathResolveOptions`] for the clean-up applied. When the cleaned-up path
+    /// does not exist but a file with the same name sits directly in `model_dir`,
+    /// that file is returned as [`ResolvedPath::Found`]; exporters often record
+    /// texture folders that are not shipped alongside the model.
+    pub fn resolve_path(&self, model_dir: &Path, options: PathResolveOptions) -> ResolvedPath {
+        resolve_texture_path(&self.path, model_dir, options)
+    }
+}
+
+impl TextureInfoRef {
+    /// Resolve the texture path against the directory of the model file; see
+    /// [`TextureInfo::resolve_path`].
+    pub fn resolve_path(&self, model_dir: &Path, options: PathResolveOptions) -> ResolvedPath {
+        resolve_texture_path(&self.path_str(), model_dir, options)
+    }
+}
+
+impl Material {
+    /// Resolve the path of every texture in this material, ordered by texture type,
+    /// then index. See [`TextureInfo::resolve_path`].
+    pub fn resolved_texture_paths(
+        &self,
+        model_dir: &Path,
+        options: PathResolveOptions,
+    ) -> Vec<ResolvedTexture> {
+        self.texture_slots()
+            .into_iter()
+            .filter_map(|slot| {
+                let index = slot.index as usize;
+                let info = self.texture_ref(slot.texture_type, index)?;
+                Some(ResolvedTexture {
+                    texture_type: slot.texture_type,
+                    index,
+                    resolved: info.resolve_path(model_dir, options),
+                    path: info.path_str().into_owned(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Resolve a raw texture path; shared by [`TextureInfo`] and [`TextureInfoRef`].
+pub(crate) fn resolve_texture_path(
+    raw: &str,
+    model_dir: &Path,
+    options: PathResolveOptions,
+) -> ResolvedPath {
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return ResolvedPath::Empty;
+    }
+    if let Some(index) = raw.strip_prefix('*').and_then(|i| i.parse().ok()) {
+        return ResolvedPath::Embedded(index);
+    }
+
+    let mut path = raw.to_string();
+    if options.percent_decode {
+        if let Some(rest) = path.strip_prefix("file://") {
+            path = rest.to_string();
+        }
+        path = percent_decode(&path);
+    }
+    if options.normalize_separators {
+        path = path.replace('\\', "/");
+    }
+
+    let file_name = path.rsplit(['/', '\\']).next().unwrap_or("").to_string();
+    let full = if is_absolute(&path) {
+        if options.strip_absolute {
+            model_dir.join(&file_name)
+        } else {
+            PathBuf::from(&path)
+        }
+    } else {
+        match relative_components(&path, options.allow_parent_dirs) {
+            Some(components) => components
+                .iter()
+                .fold(model_dir.to_path_buf(), |dir, c| dir.join(c)),
+            None => model_dir.join(&file_name),
+        }
+    };
+
+    if full.is_file() {
+        return ResolvedPath::Found(full);
+    }
+    if !file_name.is_empty() {
+        let flat = model_dir.join(&file_name);
+        if flat != full && flat.is_file() {
+            return ResolvedPath::Found(flat);
+        }
+    }
+    ResolvedPath::Candidate(full)
+}
+
+/// Unix roots, UNC shares and Windows drive paths count as absolute on every platform.
+fn is_absolute(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    path.starts_with('/')
+        || path.starts_with('\\')
+        || (bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+}
+
+/// Lexically normalize a relative path. Returns `None` when it escapes the model
+/// directory and `allow_parent` is `false`.
+fn relative_components(path: &str, allow_parent: bool) -> Option<Vec<&str>> {
+    let mut components: Vec<&str> = Vec::new();
+    for component in path.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => match components.last() {
+                Some(&last) if last != ".." => {
+                    components.pop();
+                }
+                _ if allow_parent => components.push(".."),
+                _ => return None,
+            },
+            other => components.push(other),
+        }
+    }
+    Some(components)
+}
+
+/// Decode `%XX` escapes; malformed escapes are kept as-is.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    if !bytes.contains(&b'%') {
+        return input.to_string();
+    }
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                out.push(hi << 4 | lo);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("my%20tex.png"), "my tex.png");
+        assert_eq!(percent_decode("100%.png"), "100%.png");
+        assert_eq!(percent_decode("a%2"), "a%2");
+        assert_eq!(percent_decode("%C3%A9.png"), "é.png");
+    }
+
+    #[test]
+    fn test_embedded_and_empty() {
+        let dir = Path::new("models");
+        let options = PathResolveOptions::default();
+        assert_eq!(
+            resolve_texture_path("*3", dir, options),
+            ResolvedPath::Embedded(3)
+        );
+        assert_eq!(
+            resolve_texture_path("  ", dir, options),
+            ResolvedPath::Empty
+        );
+    }
+
+    #[test]
+    fn test_relative_components() {
+        assert_eq!(
+            relative_components("a/./b/../c.png", false),
+            Some(vec!["a", "c.png"])
+        );
+        assert_eq!(relative_components("../c.png", false), None);
+        assert_eq!(
+            relative_components("../c.png", true),
+            Some(vec!["..", "c.png"])
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Per-material texture counts and a cacheable summary of common properties.
+
+use super::{AlphaMode, Material, PbrWorkflow, ShadingModel, TextureType};
+use crate::types::{Color3D, Color4D, Name};
+
+/// Number of `aiTextureType` values covered by [`TextureCounts`].
+const SLOTS: usize = {
+    let mut max = 0;
+    let mut i = 0;
+    while i < TextureType::ALL.len() {
+        let value = TextureType::ALL[i].to_u32() as usize;
+        if value > max {
+            max = value;
+        }
+        i += 1;
+    }
+    max + 1
+};
+
+/// Texture count per [`TextureType`], from [`Material::texture_counts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextureCounts {
+    counts: [u32; SLOTS],
+    other: u32,
+}
+
+impl Default for TextureCounts {
+    fn default() -> Self {
+        Self {
+            counts: [0; SLOTS],
+            other: 0,
+        }
+    }
+}
+
+impl TextureCounts {
+    /// Number of textures of `texture_type`; same as [`Material::texture_count`].
+    ///
+    /// Always 0 for [`TextureType::Other`]; those are summed in [`TextureCounts::other`].
+    pub fn get(&self, texture_type: TextureType) -> usize {
+        self[texture_type] as usize
+    }
+
+    /// Textures whose semantic has no named [`TextureType`], summed over those semantics.
+    /// See [`Material::texture_coverage`] for the per-semantic breakdown.
+    pub fn other(&self) -> usize {
+        self.other as usize
+    }
+
+    /// Sum over all texture types, including [`TextureCounts::other`].
+    pub fn total(&self) -> usize {
+        self.counts.iter().map(|&c| c as usize).sum::<usize>() + self.other()
+    }
+
+    /// Whether the material has no textures.
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+
+    /// Texture types with at least one texture, with their counts.
+    pub fn iter(&self) -> impl Iterator<Item = (TextureType, usize)> + '_ {
+        TextureType::ALL
+            .into_iter()
+            .map(|t| (t, self.get(t)))
+            .filter(|&(_, count)| count > 0)
+    }
+
+    fn record(&mut self, texture_type: TextureType, index: u32) {
+        let slot = &mut self.counts[texture_type.to_u32() as usize];
+        *slot = (*slot).max(index.saturating_add(1));
+    }
+}
+
+impl std::ops::Index<TextureType> for TextureCounts {
+    type Output = u32;
+
+    fn index(&self, texture_type: TextureType) -> &u32 {
+        match texture_type {
+            TextureType::Other(_) => &0,
+            _ => &self.counts[texture_type.to_u32() as usize],
+        }
+    }
+}
+
+/// Commonly used material values gathered in one call, from [`Material::summary`].
+///
+/// Owns all its data, so engines can cache it per material without holding the scene.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialSummary {
+    /// Material name (empty when unnamed)
+    pub name: Name,
+    /// Shading model, if stored
+    pub shading_model: Option<ShadingModel>,
+    /// Detected PBR workflow
+    pub pbr_workflow: PbrWorkflow,
+    /// Texture count per type
+    pub texture_counts: TextureCounts,
+    /// PBR base color factor
+    pub base_color: Option<Color4D>,
+    /// Diffuse color
+    pub diffuse_color: Option<Color3D>,
+    /// Emissive color
+    pub emissive_color: Option<Color3D>,
+    /// Metallic factor
+    pub metallic_factor: Option<f32>,
+    /// Roughness factor
+    pub roughness_factor: Option<f32>,
+    /// Opacity
+    pub opacity: Option<f32>,
+    /// glTF alpha mode
+    pub alpha_mode: Option<AlphaMode>,
+    /// glTF alpha cutoff
+    pub alpha_cutoff: Option<f32>,
+    /// Whether back-face culling should be disabled
+    pub two_sided: bool,
+    /// Whether the material is unlit
+    pub unlit: bool,
+}
+
+impl Material {
+    /// Texture counts for every [`TextureType`] at once.
+    ///
+    /// Scans the property array once for `$tex.file` entries instead of calling
+    /// `aiGetMaterialTextureCount` per type; the counts are identical (the highest
+    /// texture index plus one).
+    pub fn texture_counts(&self) -> TextureCounts {
+        let mut counts = TextureCounts::default();
+        for (texture_type, count) in self.texture_coverage() {
+            match texture_type {
+                TextureType::Other(_) => counts.other += count as u32,
+                _ => counts.record(texture_type, count as u32 - 1),
+            }
+        }
+        counts
+    }
+
+    /// Every texture type the material has textures for, with its texture count, ordered
+    /// by `aiTextureType` value.
+    ///
+    /// Unlike [`TextureCounts::iter`] this includes semantics without a named variant as
+    /// [`TextureType::Other`], e.g. ones added by a newer Assimp, so a material's
+    /// textures can be audited without dropping any.
+    pub fn texture_coverage(&self) -> Vec<(TextureType, usize)> {
+        let mut coverage: Vec<(TextureType, usize)> = Vec::new();
+        for prop in self.properties() {
+            if prop.key_bytes() != b"$tex.file" {
+                continue;
+            }
+            let Some(texture_type) = prop.semantic() else {
+                continue;
+            };
+            let count = prop.index() as usize + 1;
+            match coverage.iter_mut().find(|(t, _)| *t == texture_type) {
+                Some((_, max)) => *max = (*max).max(count),
+                None => coverage.push((texture_type, count)),
+            }
+        }
+        coverage.sort_by_key(|&(t, _)| t.to_u32());
+        coverage
+    }
+
+    /// Name, shading model, PBR workflow, texture counts and the common factors in
+    /// one owned struct.
+    pub fn summary(&self) -> MaterialSummary {
+        MaterialSummary {
+            name: self.name_owned(),
+            shading_model: self.shading_model_enum(),
+            pbr_workflow: self.pbr_workflow(),
+            texture_counts: self.texture_counts(),
+            base_color: self.base_color(),
+            diffuse_color: self.diffuse_color(),
+            emissive_color: self.emissive_color(),
+            metallic_factor: self.metallic_factor(),
+            roughness_factor: self.roughness_factor(),
+            opacity: self.opacity(),
+            alpha_mode: self.alpha_mode(),
+            alpha_cutoff: self.alpha_cutoff(),
+            two_sided: self.is_two_sided(),
+            unlit: self.is_unlit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_texture_type_slots_are_distinct() {
+        let mut seen = [false; SLOTS];
+        for t in TextureType::ALL {
+            assert!(!seen[t.to_u32() as usize], "{t:?}");
+            seen[t.to_u32() as usize] = true;
+            assert_eq!(TextureType::from_u32(t.to_u32()), Some(t));
+        }
+    }
+
+    #[test]
+    fn test_record_keeps_highest_index() {
+        let mut counts = TextureCounts::default();
+        assert!(counts.is_empty());
+        counts.record(TextureType::Diffuse, 2);
+        counts.record(TextureType::Diffuse, 0);
+        counts.record(TextureType::Normals, 0);
+        assert_eq!(counts.get(TextureType::Diffuse), 3);
+        assert_eq!(counts[TextureType::Normals], 1);
+        assert_eq!(counts.total(), 4);
+        assert_eq!(counts.get(TextureType::Other(1000)), 0);
+        counts.other = 2;
+        assert_eq!(counts.total(), 6);
+        assert_eq!(
+            counts.iter().collect::<Vec<_>>(),
+            vec![(TextureType::Diffuse, 3), (TextureType::Normals, 1)]
+        );
+    }
+}
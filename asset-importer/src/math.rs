@@ -8,7 +8,7 @@ use crate::{
     types::{
         Matrix3x3, Matrix4x4, Quaternion, Vector2D, Vector3D, from_ai_matrix3x3, from_ai_matrix4x4,
         from_ai_quaternion, from_ai_vector2d, from_ai_vector3d, to_ai_matrix3x3, to_ai_matrix4x4,
-        to_ai_vector2d, to_ai_vector3d,
+        to_ai_quaternion, to_ai_vector2d, to_ai_vector3d,
     },
 };
 
@@ -124,30 +124,15 @@ pub fn quaternion_from_matrix4(m: Matrix4x4) -> Quaternion {
 
 /// Normalize quaternion via Assimp
 pub fn quaternion_normalize(q: Quaternion) -> Quaternion {
-    let mut aq = sys::aiQuaternion {
-        w: q.w,
-        x: q.x,
-        y: q.y,
-        z: q.z,
-    };
+    let mut aq = to_ai_quaternion(q);
     unsafe { sys::aiQuaternionNormalize(&mut aq) };
     from_ai_quaternion(aq)
 }
 
 /// Interpolate two quaternions via Assimp (slerp)
 pub fn quaternion_interpolate(a: Quaternion, b: Quaternion, d: f32) -> Quaternion {
-    let aq = sys::aiQuaternion {
-        w: a.w,
-        x: a.x,
-        y: a.y,
-        z: a.z,
-    };
-    let bq = sys::aiQuaternion {
-        w: b.w,
-        x: b.x,
-        y: b.y,
-        z: b.z,
-    };
+    let aq = to_ai_quaternion(a);
+    let bq = to_ai_quaternion(b);
     let mut out = sys::aiQuaternion {
         w: 1.0,
         x: 0.0,
@@ -162,15 +147,15 @@ pub fn quaternion_interpolate(a: Quaternion, b: Quaternion, d: f32) -> Quaternio
 
 /// Check if two 2D vectors are equal
 pub fn vector2_equal(a: Vector2D, b: Vector2D) -> bool {
-    let aa = sys::aiVector2D { x: a.x, y: a.y };
-    let bb = sys::aiVector2D { x: b.x, y: b.y };
+    let aa = to_ai_vector2d(a);
+    let bb = to_ai_vector2d(b);
     unsafe { sys::aiVector2AreEqual(&aa, &bb) != 0 }
 }
 
 /// Check if two 2D vectors are equal within epsilon tolerance
 pub fn vector2_equal_epsilon(a: Vector2D, b: Vector2D, eps: f32) -> bool {
-    let aa = sys::aiVector2D { x: a.x, y: a.y };
-    let bb = sys::aiVector2D { x: b.x, y: b.y };
+    let aa = to_ai_vector2d(a);
+    let bb = to_ai_vector2d(b);
     unsafe { sys::aiVector2AreEqualEpsilon(&aa, &bb, eps) != 0 }
 }
 
@@ -371,12 +356,7 @@ pub fn vector3_normalize_safe(v: Vector3D) -> Vector3D {
 /// Rotate a 3D vector by a quaternion
 pub fn vector3_rotate_by_quaternion(v: Vector3D, q: Quaternion) -> Vector3D {
     let mut vv = to_ai_vector3d(v);
-    let qq = sys::aiQuaternion {
-        w: q.w,
-        x: q.x,
-        y: q.y,
-        z: q.z,
-    };
+    let qq = to_ai_quaternion(q);
     unsafe { sys::aiVector3RotateByQuaternion(&mut vv, &qq) };
     from_ai_vector3d(vv)
 }
@@ -394,12 +374,7 @@ pub fn matrix3_from_matrix4(m: Matrix4x4) -> Matrix3x3 {
 /// Create a 3x3 rotation matrix from a quaternion
 pub fn matrix3_from_quaternion(q: Quaternion) -> Matrix3x3 {
     let mut out = sys::aiMatrix3x3::default();
-    let qq = sys::aiQuaternion {
-        w: q.w,
-        x: q.x,
-        y: q.y,
-        z: q.z,
-    };
+    let qq = to_ai_quaternion(q);
     unsafe { sys::aiMatrix3FromQuaternion(&mut out, &qq) };
     from_ai_matrix3x3(out)
 }
@@ -477,12 +452,7 @@ pub fn matrix4_from_matrix3(m: Matrix3x3) -> Matrix4x4 {
 pub fn matrix4_from_s_q_t(scale: Vector3D, rot: Quaternion, pos: Vector3D) -> Matrix4x4 {
     let mut out = sys::aiMatrix4x4::default();
     let s = to_ai_vector3d(scale);
-    let q = sys::aiQuaternion {
-        w: rot.w,
-        x: rot.x,
-        y: rot.y,
-        z: rot.z,
-    };
+    let q = to_ai_quaternion(rot);
     let p = to_ai_vector3d(pos);
     unsafe { sys::aiMatrix4FromScalingQuaternionPosition(&mut out, &s, &q, &p) };
     from_ai_matrix4x4(out)
@@ -708,64 +678,29 @@ pub fn quaternion_from_normalized_vector3(v: Vector3D) -> Quaternion {
 
 /// Check if two quaternions are equal
 pub fn quaternion_equal(a: Quaternion, b: Quaternion) -> bool {
-    let aa = sys::aiQuaternion {
-        w: a.w,
-        x: a.x,
-        y: a.y,
-        z: a.z,
-    };
-    let bb = sys::aiQuaternion {
-        w: b.w,
-        x: b.x,
-        y: b.y,
-        z: b.z,
-    };
+    let aa = to_ai_quaternion(a);
+    let bb = to_ai_quaternion(b);
     unsafe { sys::aiQuaternionAreEqual(&aa, &bb) != 0 }
 }
 
 /// Check if two quaternions are equal within epsilon tolerance
 pub fn quaternion_equal_epsilon(a: Quaternion, b: Quaternion, eps: f32) -> bool {
-    let aa = sys::aiQuaternion {
-        w: a.w,
-        x: a.x,
-        y: a.y,
-        z: a.z,
-    };
-    let bb = sys::aiQuaternion {
-        w: b.w,
-        x: b.x,
-        y: b.y,
-        z: b.z,
-    };
+    let aa = to_ai_quaternion(a);
+    let bb = to_ai_quaternion(b);
     unsafe { sys::aiQuaternionAreEqualEpsilon(&aa, &bb, eps) != 0 }
 }
 
 /// Calculate the conjugate of a quaternion
 pub fn quaternion_conjugate(q: Quaternion) -> Quaternion {
-    let mut qq = sys::aiQuaternion {
-        w: q.w,
-        x: q.x,
-        y: q.y,
-        z: q.z,
-    };
+    let mut qq = to_ai_quaternion(q);
     unsafe { sys::aiQuaternionConjugate(&mut qq) };
     from_ai_quaternion(qq)
 }
 
 /// Multiply two quaternions
 pub fn quaternion_multiply(a: Quaternion, b: Quaternion) -> Quaternion {
-    let mut dst = sys::aiQuaternion {
-        w: a.w,
-        x: a.x,
-        y: a.y,
-        z: a.z,
-    };
-    let qb = sys::aiQuaternion {
-        w: b.w,
-        x: b.x,
-        y: b.y,
-        z: b.z,
-    };
+    let mut dst = to_ai_quaternion(a);
+    let qb = to_ai_quaternion(b);
     unsafe { sys::aiQuaternionMultiply(&mut dst, &qb) };
     from_ai_quaternion(dst)
 }
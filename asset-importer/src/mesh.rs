@@ -2,17 +2,45 @@
 
 #![allow(clippy::unnecessary_cast)]
 
+pub mod bake;
+pub mod compute;
+pub mod measure;
+pub mod morph;
+pub mod skinning;
+pub mod topology;
+
 use crate::{
     aabb::AABB,
     bone::{Bone, BoneIterator},
     ffi,
+    material::{Material, UVTransform},
     ptr::SharedPtr,
     raw,
     scene::Scene,
     sys,
-    types::{Color4D, Vector2D, Vector3D, ai_string_to_str, ai_string_to_string},
+    types::{
+        Color4D, Name, Vector2D, Vector3D, ai_string_bytes, ai_string_to_name, ai_string_to_str,
+        ai_string_to_string, from_real,
+    },
 };
 
+bitflags::bitflags! {
+    /// Primitive kinds stored in a mesh (mesh.h: aiPrimitiveType)
+    ///
+    /// A mesh may mix several kinds until `PostProcessSteps::SORT_BY_PTYPE` splits it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct PrimitiveTypes: u32 {
+        /// Single-index faces
+        const POINT = sys::aiPrimitiveType::aiPrimitiveType_POINT as u32;
+        /// Two-index faces
+        const LINE = sys::aiPrimitiveType::aiPrimitiveType_LINE as u32;
+        /// Three-index faces
+        const TRIANGLE = sys::aiPrimitiveType::aiPrimitiveType_TRIANGLE as u32;
+        /// Faces with more than three indices
+        const POLYGON = sys::aiPrimitiveType::aiPrimitiveType_POLYGON as u32;
+    }
+}
+
 /// A mesh containing vertices, faces, and other geometric data
 #[derive(Clone)]
 pub struct Mesh {
@@ -47,11 +75,22 @@ impl Mesh {
         ai_string_to_string(&self.raw().mName)
     }
 
+    /// Get the name of the mesh as an inline [`Name`], allocating
+    /// only for names longer than 64 bytes.
+    pub fn name_owned(&self) -> Name {
+        ai_string_to_name(&self.raw().mName)
+    }
+
     /// Get the name of the mesh (zero-copy, lossy UTF-8).
     pub fn name_str(&self) -> std::borrow::Cow<'_, str> {
         ai_string_to_str(&self.raw().mName)
     }
 
+    /// Bytes of the name up to the first NUL, for binary-safe comparison.
+    pub fn name_bytes(&self) -> &[u8] {
+        ai_string_bytes(&self.raw().mName)
+    }
+
     /// Get the number of vertices in the mesh
     pub fn num_vertices(&self) -> usize {
         self.raw().mNumVertices as usize
@@ -119,11 +158,20 @@ impl Mesh {
     }
 
     /// Get the raw vertex buffer as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn vertices_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.vertices_raw())
     }
 
+    /// Get the vertex positions as `[f32; 3]` arrays (zero-copy).
+    ///
+    /// This matches the `Float32x3` vertex format of wgpu and similar APIs, so the slice
+    /// can be uploaded as-is without depending on `bytemuck`.
+    #[cfg(not(f64_real))]
+    pub fn vertices_f32x3(&self) -> &[[f32; 3]] {
+        vec3_arrays(self, self.vertices_raw())
+    }
+
     /// Get the raw vertex buffer (zero-copy), returning `None` when absent.
     pub fn vertices_raw_opt(&self) -> Option<&[raw::AiVector3D]> {
         let mesh = self.raw();
@@ -138,15 +186,13 @@ impl Mesh {
 
     /// Iterate vertices without allocation.
     pub fn vertices_iter(&self) -> impl Iterator<Item = Vector3D> + '_ {
-        self.vertices_raw()
-            .iter()
-            .map(|v| Vector3D::new(v.x, v.y, v.z))
+        self.vertices_raw().iter().map(|v| Vector3D::from(*v))
     }
 
     /// Get the normals of the mesh
     pub fn normals(&self) -> Option<Vec<Vector3D>> {
         self.normals_raw_opt()
-            .map(|ns| ns.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|ns| ns.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Get the raw normal buffer (zero-copy).
@@ -166,11 +212,17 @@ impl Mesh {
     }
 
     /// Get the raw normal buffer as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn normals_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.normals_raw())
     }
 
+    /// Get the raw normal buffer as `[f32; 3]` arrays (zero-copy).
+    #[cfg(not(f64_real))]
+    pub fn normals_f32x3(&self) -> &[[f32; 3]] {
+        vec3_arrays(self, self.normals_raw())
+    }
+
     /// Get the raw normal buffer (zero-copy), returning `None` when absent.
     pub fn normals_raw_opt(&self) -> Option<&[raw::AiVector3D]> {
         let mesh = self.raw();
@@ -188,15 +240,13 @@ impl Mesh {
 
     /// Iterate normals without allocation.
     pub fn normals_iter(&self) -> impl Iterator<Item = Vector3D> + '_ {
-        self.normals_raw()
-            .iter()
-            .map(|v| Vector3D::new(v.x, v.y, v.z))
+        self.normals_raw().iter().map(|v| Vector3D::from(*v))
     }
 
     /// Get the tangents of the mesh
     pub fn tangents(&self) -> Option<Vec<Vector3D>> {
         self.tangents_raw_opt()
-            .map(|ts| ts.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|ts| ts.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Get the raw tangent buffer (zero-copy).
@@ -216,11 +266,17 @@ impl Mesh {
     }
 
     /// Get the raw tangent buffer as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn tangents_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.tangents_raw())
     }
 
+    /// Get the raw tangent buffer as `[f32; 3]` arrays (zero-copy).
+    #[cfg(not(f64_real))]
+    pub fn tangents_f32x3(&self) -> &[[f32; 3]] {
+        vec3_arrays(self, self.tangents_raw())
+    }
+
     /// Get the raw tangent buffer (zero-copy), returning `None` when absent.
     pub fn tangents_raw_opt(&self) -> Option<&[raw::AiVector3D]> {
         let mesh = self.raw();
@@ -238,15 +294,13 @@ impl Mesh {
 
     /// Iterate tangents without allocation.
     pub fn tangents_iter(&self) -> impl Iterator<Item = Vector3D> + '_ {
-        self.tangents_raw()
-            .iter()
-            .map(|v| Vector3D::new(v.x, v.y, v.z))
+        self.tangents_raw().iter().map(|v| Vector3D::from(*v))
     }
 
     /// Get the bitangents of the mesh
     pub fn bitangents(&self) -> Option<Vec<Vector3D>> {
         self.bitangents_raw_opt()
-            .map(|bs| bs.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|bs| bs.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Get the raw bitangent buffer (zero-copy).
@@ -266,11 +320,17 @@ impl Mesh {
     }
 
     /// Get the raw bitangent buffer as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn bitangents_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.bitangents_raw())
     }
 
+    /// Get the raw bitangent buffer as `[f32; 3]` arrays (zero-copy).
+    #[cfg(not(f64_real))]
+    pub fn bitangents_f32x3(&self) -> &[[f32; 3]] {
+        vec3_arrays(self, self.bitangents_raw())
+    }
+
     /// Get the raw bitangent buffer (zero-copy), returning `None` when absent.
     pub fn bitangents_raw_opt(&self) -> Option<&[raw::AiVector3D]> {
         let mesh = self.raw();
@@ -288,23 +348,126 @@ impl Mesh {
 
     /// Iterate bitangents without allocation.
     pub fn bitangents_iter(&self) -> impl Iterator<Item = Vector3D> + '_ {
-        self.bitangents_raw()
+        self.bitangents_raw().iter().map(|v| Vector3D::from(*v))
+    }
+
+    /// Tangents as `[x, y, z, w]` with the handedness in `w` (glTF layout).
+    ///
+    /// `w` is `1.0` when `cross(normal, tangent)` points the same way as Assimp's
+    /// bitangent and `-1.0` otherwise, so `bitangent = cross(normal, tangent) * w`.
+    /// Returns `None` unless the mesh has tangents, bitangents and normals (i.e. it
+    /// was imported with `CALC_TANGENT_SPACE`).
+    pub fn tangents_with_sign(&self) -> Option<Vec<[f32; 4]>> {
+        let tangents = self.tangents_raw_opt()?;
+        let bitangents = self.bitangents_raw_opt()?;
+        let normals = self.normals_raw_opt()?;
+        let v = |v: &raw::AiVector3D| Vector3D::from(*v);
+        Some(
+            tangents
+                .iter()
+                .zip(bitangents)
+                .zip(normals)
+                .map(|((t, b), n)| {
+                    let (t, b, n) = (v(t), v(b), v(n));
+                    let w = if n.cross(t).dot(b) < 0.0 { -1.0 } else { 1.0 };
+                    [t.x, t.y, t.z, w]
+                })
+                .collect(),
+        )
+    }
+
+    /// Number of populated texture coordinate channels (non-null slots).
+    ///
+    /// Assimp allows gaps, so channels are not necessarily `0..num_uv_channels()`.
+    pub fn num_uv_channels(&self) -> usize {
+        self.raw()
+            .mTextureCoords
             .iter()
-            .map(|v| Vector3D::new(v.x, v.y, v.z))
+            .filter(|ptr| !ptr.is_null())
+            .count()
+    }
+
+    /// Number of populated vertex color channels (non-null slots).
+    pub fn num_color_channels(&self) -> usize {
+        self.raw()
+            .mColors
+            .iter()
+            .filter(|ptr| !ptr.is_null())
+            .count()
+    }
+
+    /// Number of meaningful components (1, 2 or 3) in a texture coordinate channel.
+    ///
+    /// Returns `None` when the channel is out of range or absent.
+    pub fn num_uv_components(&self, channel: usize) -> Option<u32> {
+        if channel >= sys::AI_MAX_NUMBER_OF_TEXTURECOORDS as usize {
+            return None;
+        }
+        let mesh = self.raw();
+        if mesh.mTextureCoords[channel].is_null() {
+            return None;
+        }
+        Some(mesh.mNumUVComponents[channel])
+    }
+
+    /// Get the name of a texture coordinate channel (zero-copy, lossy UTF-8).
+    ///
+    /// Only some importers (e.g. FBX) record UV set names.
+    pub fn uv_channel_name_str(&self, channel: usize) -> Option<std::borrow::Cow<'_, str>> {
+        let mesh = self.raw();
+        let name_ptr = ffi::ptr_array_get(
+            self,
+            mesh.mTextureCoordsNames as *const *mut sys::aiString,
+            sys::AI_MAX_NUMBER_OF_TEXTURECOORDS as usize,
+            channel,
+        )?;
+        let name = ffi::ref_from_ptr(self, name_ptr as *const sys::aiString)?;
+        (name.length != 0).then(|| ai_string_to_str(name))
+    }
+
+    /// Get the name of a texture coordinate channel.
+    pub fn uv_channel_name(&self, channel: usize) -> Option<String> {
+        self.uv_channel_name_str(channel).map(|s| s.into_owned())
     }
 
     /// Get texture coordinates for a specific channel
     pub fn texture_coords(&self, channel: usize) -> Option<Vec<Vector3D>> {
         self.texture_coords_raw_opt(channel)
-            .map(|uvs| uvs.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|uvs| uvs.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Get texture coordinates (Vec2) for a specific channel.
     ///
     /// This is a convenience for the common case where UVs are 2D; it discards the third component.
     pub fn texture_coords2(&self, channel: usize) -> Option<Vec<Vector2D>> {
-        self.texture_coords_raw_opt(channel)
-            .map(|uvs| uvs.iter().map(|v| Vector2D::new(v.x, v.y)).collect())
+        self.texture_coords_raw_opt(channel).map(|uvs| {
+            uvs.iter()
+                .map(|v| Vector2D::new(from_real(v.x), from_real(v.y)))
+                .collect()
+        })
+    }
+
+    /// Get 2D texture coordinates for a channel, honoring its component count.
+    ///
+    /// One-component channels yield `(u, 0.0)`. Returns `None` when the channel is absent
+    /// or stores 3D coordinates (use [`Mesh::texture_coords`] for those).
+    pub fn texture_coords_2d(&self, channel: usize) -> Option<Vec<Vector2D>> {
+        let components = self.num_uv_components(channel)?;
+        let uvs = self.texture_coords_raw_opt(channel)?;
+        match components {
+            1 => Some(
+                uvs.iter()
+                    .map(|v| Vector2D::new(from_real(v.x), 0.0))
+                    .collect(),
+            ),
+            // Some importers leave the count at 0; treat that as the common 2D case.
+            0 | 2 => Some(
+                uvs.iter()
+                    .map(|v| Vector2D::new(from_real(v.x), from_real(v.y)))
+                    .collect(),
+            ),
+            _ => None,
+        }
     }
 
     /// Get raw texture coordinates for a specific channel (zero-copy).
@@ -329,11 +492,17 @@ impl Mesh {
     }
 
     /// Get raw texture coordinates for a specific channel as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn texture_coords_f32(&self, channel: usize) -> &[f32] {
         bytemuck::cast_slice(self.texture_coords_raw(channel))
     }
 
+    /// Get raw texture coordinates for a specific channel as `[f32; 3]` arrays (zero-copy).
+    #[cfg(not(f64_real))]
+    pub fn texture_coords_f32x3(&self, channel: usize) -> &[[f32; 3]] {
+        vec3_arrays(self, self.texture_coords_raw(channel))
+    }
+
     /// Get raw texture coordinates for a specific channel (zero-copy), returning `None` when absent.
     pub fn texture_coords_raw_opt(&self, channel: usize) -> Option<&[raw::AiVector3D]> {
         if channel >= sys::AI_MAX_NUMBER_OF_TEXTURECOORDS as usize {
@@ -356,7 +525,7 @@ impl Mesh {
     pub fn texture_coords_iter(&self, channel: usize) -> impl Iterator<Item = Vector3D> + '_ {
         self.texture_coords_raw(channel)
             .iter()
-            .map(|v| Vector3D::new(v.x, v.y, v.z))
+            .map(|v| Vector3D::from(*v))
     }
 
     /// Iterate texture coordinates (Vec2) without allocation.
@@ -365,7 +534,76 @@ impl Mesh {
     pub fn texture_coords_iter2(&self, channel: usize) -> impl Iterator<Item = Vector2D> + '_ {
         self.texture_coords_raw(channel)
             .iter()
-            .map(|v| Vector2D::new(v.x, v.y))
+            .map(|v| Vector2D::new(from_real(v.x), from_real(v.y)))
+    }
+
+    /// Texture coordinates of a channel with a UV transform baked in.
+    ///
+    /// For renderers without per-texture transforms: pass the
+    /// [`TextureInfo::uv_transform`](crate::material::TextureInfo::uv_transform) of the
+    /// texture sampled with this channel. The third component is discarded. Returns
+    /// `None` when the channel is absent.
+    pub fn texture_coords_transformed(
+        &self,
+        channel: usize,
+        transform: &UVTransform,
+    ) -> Option<Vec<Vector2D>> {
+        self.texture_coords_raw_opt(channel).map(|uvs| {
+            uvs.iter()
+                .map(|v| transform.apply(Vector2D::new(from_real(v.x), from_real(v.y))))
+                .collect()
+        })
+    }
+
+    /// Copy the positions into `out` as `[f32; 3]`, replacing its contents.
+    ///
+    /// Reusing one buffer across meshes avoids the intermediate `Vec<Vector3D>` of
+    /// [`Mesh::vertices`]; with single-precision Assimp the copy is a single `memcpy`.
+    /// Returns `false`, leaving `out` empty, when the mesh has no positions.
+    pub fn copy_positions(&self, out: &mut Vec<[f32; 3]>) -> bool {
+        copy_vec3(self.vertices_raw_opt(), out)
+    }
+
+    /// Copy the normals into `out` as `[f32; 3]`, replacing its contents.
+    ///
+    /// Returns `false`, leaving `out` empty, when the mesh has no normals. See
+    /// [`Mesh::copy_positions`].
+    pub fn copy_normals(&self, out: &mut Vec<[f32; 3]>) -> bool {
+        copy_vec3(self.normals_raw_opt(), out)
+    }
+
+    /// Copy the 2D texture coordinates of a channel into `out`, replacing its contents.
+    ///
+    /// Follows [`Mesh::texture_coords_2d`]: one-component channels yield `[u, 0.0]`.
+    /// Returns `false`, leaving `out` empty, when the channel is absent or stores 3D
+    /// coordinates.
+    pub fn copy_texture_coords_2d(&self, channel: usize, out: &mut Vec<[f32; 2]>) -> bool {
+        out.clear();
+        let (Some(components), Some(uvs)) = (
+            self.num_uv_components(channel),
+            self.texture_coords_raw_opt(channel),
+        ) else {
+            return false;
+        };
+        match components {
+            1 => out.extend(uvs.iter().map(|v| [from_real(v.x), 0.0])),
+            0 | 2 => out.extend(uvs.iter().map(|v| [from_real(v.x), from_real(v.y)])),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Copy the vertex colors of a channel into `out` as RGBA `[f32; 4]`, replacing its
+    /// contents with a single `memcpy`.
+    ///
+    /// Returns `false`, leaving `out` empty, when the channel is absent.
+    pub fn copy_vertex_colors(&self, channel: usize, out: &mut Vec<[f32; 4]>) -> bool {
+        out.clear();
+        if self.vertex_colors_raw_opt(channel).is_none() {
+            return false;
+        }
+        out.extend_from_slice(self.vertex_colors_f32x4(channel));
+        true
     }
 
     /// Get vertex colors for a specific channel
@@ -404,6 +642,12 @@ impl Mesh {
         bytemuck::cast_slice(self.vertex_colors_raw(channel))
     }
 
+    /// Get raw vertex colors for a specific channel as `[f32; 4]` arrays (zero-copy).
+    pub fn vertex_colors_f32x4(&self, channel: usize) -> &[[f32; 4]] {
+        let colors = self.vertex_colors_raw(channel);
+        ffi::slice_from_ptr_len(self, colors.as_ptr() as *const [f32; 4], colors.len())
+    }
+
     /// Get raw vertex colors for a specific channel (zero-copy), returning `None` when absent.
     pub fn vertex_colors_raw_opt(&self, channel: usize) -> Option<&[raw::AiColor4D]> {
         if channel >= sys::AI_MAX_NUMBER_OF_COLOR_SETS as usize {
@@ -459,6 +703,59 @@ impl Mesh {
         self.triangles_iter().flatten()
     }
 
+    /// Iterate triangles of a pure-triangle mesh straight from [`Mesh::faces_raw`].
+    ///
+    /// Returns `None` if the mesh holds any other primitive. Unlike
+    /// [`Mesh::triangles_iter`], which silently skips non-triangles, this tells the
+    /// caller whether the result covers every face. The check reads
+    /// [`Mesh::primitive_types_typed`] and only scans the faces when Assimp left the
+    /// primitive types unset.
+    pub fn triangles_fast(&self) -> Option<impl Iterator<Item = [u32; 3]> + '_> {
+        let faces = self.faces_raw();
+        let types = self.primitive_types_typed();
+        let pure = if types.is_empty() {
+            faces.iter().all(|face| face.num_indices() == 3)
+        } else {
+            types == PrimitiveTypes::TRIANGLE
+        };
+        if !pure {
+            return None;
+        }
+        Some(
+            faces
+                .iter()
+                .filter_map(|face| <[u32; 3]>::try_from(self.face_indices(face)).ok()),
+        )
+    }
+
+    /// Largest vertex index referenced by any face, or `None` without faces.
+    pub fn max_index(&self) -> Option<u32> {
+        self.faces_raw()
+            .iter()
+            .filter_map(|face| self.face_indices(face).iter().copied().max())
+            .max()
+    }
+
+    /// Index slice of a face from [`Mesh::faces_raw`].
+    fn face_indices<'a>(&'a self, face: &'a raw::AiFace) -> &'a [u32] {
+        ffi::slice_from_ptr_len(self, face.mIndices as *const u32, face.num_indices())
+    }
+
+    /// Collect a `u16` index buffer for a pure-triangle mesh.
+    ///
+    /// Returns `None` when the mesh is not pure triangles (see [`Mesh::triangles_fast`])
+    /// or any index exceeds `u16::MAX`.
+    pub fn triangle_indices_u16(&self) -> Option<Vec<u16>> {
+        let triangles = self.triangles_fast()?;
+        let mut indices = Vec::with_capacity(self.num_faces() * 3);
+        for triangle in triangles {
+            for index in triangle {
+                indices.push(u16::try_from(index).ok()?);
+            }
+        }
+        Some(indices)
+    }
+
     /// Get the faces of the mesh
     pub fn faces(&self) -> FaceIterator {
         FaceIterator {
@@ -498,36 +795,83 @@ impl Mesh {
         self.raw().mMaterialIndex as usize
     }
 
-    /// Get the primitive types present in this mesh
+    /// Get the material this mesh uses, looked up in the owning scene
+    ///
+    /// `None` when [`Mesh::material_index`] is out of range.
+    pub fn material(&self) -> Option<Material> {
+        self.scene.material(self.material_index())
+    }
+
+    /// Get the primitive types present in this mesh as raw `aiPrimitiveType` bits
+    ///
+    /// See [`Mesh::primitive_types_typed`] for the typed flags.
     pub fn primitive_types(&self) -> u32 {
         self.raw().mPrimitiveTypes
     }
 
+    /// Get the primitive types present in this mesh
+    ///
+    /// Bits other than the four primitive kinds (such as
+    /// `aiPrimitiveType_NGONEncodingFlag`) are dropped.
+    pub fn primitive_types_typed(&self) -> PrimitiveTypes {
+        PrimitiveTypes::from_bits_truncate(self.primitive_types())
+    }
+
     /// Check if the mesh contains points
     pub fn has_points(&self) -> bool {
-        self.primitive_types() & (sys::aiPrimitiveType::aiPrimitiveType_POINT as u32) != 0
+        self.primitive_types_typed().contains(PrimitiveTypes::POINT)
     }
 
     /// Check if the mesh contains lines
     pub fn has_lines(&self) -> bool {
-        self.primitive_types() & (sys::aiPrimitiveType::aiPrimitiveType_LINE as u32) != 0
+        self.primitive_types_typed().contains(PrimitiveTypes::LINE)
     }
 
     /// Check if the mesh contains triangles
     pub fn has_triangles(&self) -> bool {
-        self.primitive_types() & (sys::aiPrimitiveType::aiPrimitiveType_TRIANGLE as u32) != 0
+        self.primitive_types_typed()
+            .contains(PrimitiveTypes::TRIANGLE)
     }
 
     /// Check if the mesh contains polygons
     pub fn has_polygons(&self) -> bool {
-        self.primitive_types() & (sys::aiPrimitiveType::aiPrimitiveType_POLYGON as u32) != 0
+        self.primitive_types_typed()
+            .contains(PrimitiveTypes::POLYGON)
     }
 
     /// Get the axis-aligned bounding box of the mesh
+    ///
+    /// Assimp only fills this in when `PostProcessSteps::GEN_BOUNDING_BOXES` was requested;
+    /// otherwise it is all zeros. See [`Mesh::compute_aabb`] and [`Mesh::bounds`].
     pub fn aabb(&self) -> AABB {
         crate::aabb::from_sys_aabb(&self.raw().mAABB)
     }
 
+    /// Compute the bounding box from the vertex positions (`None` without vertices).
+    pub fn compute_aabb(&self) -> Option<AABB> {
+        let vertices = self.vertices_raw();
+        if vertices.is_empty() {
+            return None;
+        }
+        Some(AABB::from_points(
+            vertices.iter().map(|v| Vector3D::from(*v)),
+        ))
+    }
+
+    /// Local-space bounds: Assimp's [`Mesh::aabb`] when it was generated, otherwise
+    /// [`Mesh::compute_aabb`]. `None` for meshes without vertices.
+    pub fn bounds(&self) -> Option<AABB> {
+        if self.num_vertices() == 0 {
+            return None;
+        }
+        let aabb = self.aabb();
+        if aabb.min == Vector3D::ZERO && aabb.max == Vector3D::ZERO {
+            self.compute_aabb()
+        } else {
+            Some(aabb)
+        }
+    }
+
     /// Get the number of animation meshes (morph targets)
     pub fn num_anim_meshes(&self) -> usize {
         let mesh = self.raw();
@@ -554,10 +898,15 @@ impl Mesh {
 
     /// Iterate over animation meshes
     pub fn anim_meshes(&self) -> AnimMeshIterator {
+        let mesh = self.raw();
         AnimMeshIterator {
             scene: self.scene.clone(),
             mesh_ptr: self.mesh_ptr,
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                mesh,
+                mesh.mAnimMeshes as *const *const sys::aiAnimMesh,
+                mesh.mNumAnimMeshes as usize,
+            ),
         }
     }
 
@@ -614,6 +963,33 @@ impl Mesh {
     }
 }
 
+/// Reinterpret a raw vector buffer as float arrays; `raw.rs` asserts the layouts match.
+#[cfg(not(f64_real))]
+fn vec3_arrays<'a>(owner: &'a Mesh, vectors: &'a [raw::AiVector3D]) -> &'a [[f32; 3]] {
+    ffi::slice_from_ptr_len(owner, vectors.as_ptr() as *const [f32; 3], vectors.len())
+}
+
+/// Replace the contents of `out` with `vectors`; `false` when there are none.
+fn copy_vec3(vectors: Option<&[raw::AiVector3D]>, out: &mut Vec<[f32; 3]>) -> bool {
+    out.clear();
+    let Some(vectors) = vectors else {
+        return false;
+    };
+    #[cfg(not(f64_real))]
+    out.extend_from_slice(ffi::slice_from_ptr_len(
+        vectors,
+        vectors.as_ptr() as *const [f32; 3],
+        vectors.len(),
+    ));
+    #[cfg(f64_real)]
+    out.extend(
+        vectors
+            .iter()
+            .map(|v| [from_real(v.x), from_real(v.y), from_real(v.z)]),
+    );
+    true
+}
+
 /// A face in a mesh
 #[derive(Clone)]
 pub struct Face {
@@ -703,6 +1079,11 @@ impl Iterator for FaceIterator {
             (remaining, Some(remaining))
         }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n);
+        self.next()
+    }
 }
 
 impl ExactSizeIterator for FaceIterator {}
@@ -775,7 +1156,7 @@ impl AnimMesh {
     /// Replacement positions (if present)
     pub fn vertices(&self) -> Option<Vec<Vector3D>> {
         self.vertices_raw_opt()
-            .map(|vs| vs.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|vs| vs.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Raw replacement positions (zero-copy).
@@ -795,7 +1176,7 @@ impl AnimMesh {
     }
 
     /// Raw replacement positions as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn vertices_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.vertices_raw())
     }
@@ -814,7 +1195,7 @@ impl AnimMesh {
     /// Replacement normals (if present)
     pub fn normals(&self) -> Option<Vec<Vector3D>> {
         self.normals_raw_opt()
-            .map(|ns| ns.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|ns| ns.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Raw replacement normals (zero-copy).
@@ -834,7 +1215,7 @@ impl AnimMesh {
     }
 
     /// Raw replacement normals as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn normals_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.normals_raw())
     }
@@ -853,7 +1234,7 @@ impl AnimMesh {
     /// Replacement tangents (if present)
     pub fn tangents(&self) -> Option<Vec<Vector3D>> {
         self.tangents_raw_opt()
-            .map(|ts| ts.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|ts| ts.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Raw replacement tangents (zero-copy).
@@ -873,7 +1254,7 @@ impl AnimMesh {
     }
 
     /// Raw replacement tangents as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn tangents_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.tangents_raw())
     }
@@ -892,7 +1273,7 @@ impl AnimMesh {
     /// Replacement bitangents (if present)
     pub fn bitangents(&self) -> Option<Vec<Vector3D>> {
         self.bitangents_raw_opt()
-            .map(|bs| bs.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|bs| bs.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Raw replacement bitangents (zero-copy).
@@ -912,7 +1293,7 @@ impl AnimMesh {
     }
 
     /// Raw replacement bitangents as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn bitangents_f32(&self) -> &[f32] {
         bytemuck::cast_slice(self.bitangents_raw())
     }
@@ -964,15 +1345,18 @@ impl AnimMesh {
     /// Replacement texture coordinates for a specific channel
     pub fn texture_coords(&self, channel: usize) -> Option<Vec<Vector3D>> {
         self.texture_coords_raw_opt(channel)
-            .map(|uvs| uvs.iter().map(|v| Vector3D::new(v.x, v.y, v.z)).collect())
+            .map(|uvs| uvs.iter().map(|v| Vector3D::from(*v)).collect())
     }
 
     /// Replacement texture coordinates (Vec2) for a specific channel.
     ///
     /// This is a convenience for the common case where UVs are 2D; it discards the third component.
     pub fn texture_coords2(&self, channel: usize) -> Option<Vec<Vector2D>> {
-        self.texture_coords_raw_opt(channel)
-            .map(|uvs| uvs.iter().map(|v| Vector2D::new(v.x, v.y)).collect())
+        self.texture_coords_raw_opt(channel).map(|uvs| {
+            uvs.iter()
+                .map(|v| Vector2D::new(from_real(v.x), from_real(v.y)))
+                .collect()
+        })
     }
 
     /// Raw replacement texture coordinates for a specific channel (zero-copy).
@@ -992,7 +1376,7 @@ impl AnimMesh {
     }
 
     /// Raw replacement texture coordinates for a specific channel as a flat `f32` slice (x,y,z interleaved).
-    #[cfg(feature = "bytemuck")]
+    #[cfg(all(feature = "bytemuck", not(f64_real)))]
     pub fn texture_coords_f32(&self, channel: usize) -> &[f32] {
         bytemuck::cast_slice(self.texture_coords_raw(channel))
     }
@@ -1017,7 +1401,7 @@ impl AnimMesh {
     pub fn texture_coords_iter2(&self, channel: usize) -> impl Iterator<Item = Vector2D> + '_ {
         self.texture_coords_raw(channel)
             .iter()
-            .map(|v| Vector2D::new(v.x, v.y))
+            .map(|v| Vector2D::new(from_real(v.x), from_real(v.y)))
     }
 
     /// Weight of this anim mesh
@@ -1030,13 +1414,26 @@ impl AnimMesh {
 pub struct AnimMeshIterator {
     scene: Scene,
     mesh_ptr: SharedPtr<sys::aiMesh>,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl AnimMeshIterator {
-    #[inline]
-    fn mesh_ptr(&self) -> SharedPtr<sys::aiMesh> {
-        self.mesh_ptr
+    fn anim_mesh(
+        scene: &Scene,
+        mesh_ptr: SharedPtr<sys::aiMesh>,
+        index: usize,
+    ) -> Option<AnimMesh> {
+        let mesh = mesh_ptr.as_ref();
+        let ptr = ffi::ptr_array_get(
+            mesh,
+            mesh.mAnimMeshes as *const *mut sys::aiAnimMesh,
+            mesh.mNumAnimMeshes as usize,
+            index,
+        )?;
+        Some(AnimMesh {
+            scene: scene.clone(),
+            anim_ptr: SharedPtr::new(ptr as *const sys::aiAnimMesh)?,
+        })
     }
 }
 
@@ -1044,41 +1441,32 @@ impl Iterator for AnimMeshIterator {
     type Item = AnimMesh;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mesh_ptr = self.mesh_ptr();
-        let mesh = mesh_ptr.as_ref();
-        let meshes: &[*mut sys::aiAnimMesh] = ffi::slice_from_ptr_len_opt(
-            mesh,
-            mesh.mAnimMeshes as *const *mut sys::aiAnimMesh,
-            mesh.mNumAnimMeshes as usize,
-        )?;
-        while self.index < meshes.len() {
-            let index = self.index;
-            self.index = index + 1;
-            let ptr = meshes[index];
-            if ptr.is_null() {
-                continue;
-            }
-            let anim_ptr = SharedPtr::new(ptr as *const sys::aiAnimMesh)?;
-            return Some(AnimMesh {
-                scene: self.scene.clone(),
-                anim_ptr,
-            });
-        }
-        None
+        let (scene, mesh_ptr) = (&self.scene, self.mesh_ptr);
+        self.cursor
+            .next(|index| Self::anim_mesh(scene, mesh_ptr, index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let mesh_ptr = self.mesh_ptr();
-        let mesh = mesh_ptr.as_ref();
-        if mesh.mAnimMeshes.is_null() {
-            (0, Some(0))
-        } else {
-            let remaining = (mesh.mNumAnimMeshes as usize).saturating_sub(self.index);
-            (0, Some(remaining))
-        }
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, mesh_ptr) = (&self.scene, self.mesh_ptr);
+        self.cursor
+            .nth(n, |index| Self::anim_mesh(scene, mesh_ptr, index))
     }
 }
 
+impl DoubleEndedIterator for AnimMeshIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, mesh_ptr) = (&self.scene, self.mesh_ptr);
+        self.cursor
+            .next_back(|index| Self::anim_mesh(scene, mesh_ptr, index))
+    }
+}
+
+impl ExactSizeIterator for AnimMeshIterator {}
+
 /// Methods of mesh morphing supported by Assimp
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MorphingMethod {
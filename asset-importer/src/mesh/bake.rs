@@ -0,0 +1,363 @@
+//! Bake mesh attributes into an interleaved vertex buffer for GPU upload.
+//!
+//! ```rust,no_run
+//! # use asset_importer::mesh::{Mesh, bake::VertexLayout};
+//! # fn upload(mesh: &Mesh) -> asset_importer::Result<()> {
+//! let layout = VertexLayout::new().with_position().with_normal().with_uv(0);
+//! let baked = mesh.bake_vertices(&layout)?;
+//! let stride = baked.stride();
+//! let bytes = baked.interleaved_bytes();
+//! # let _ = (stride, bytes);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    mesh::Mesh,
+    raw,
+    scene::Scene,
+    types::from_real,
+};
+
+/// A vertex attribute that can be baked into an interleaved buffer.
+///
+/// All components are written as `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VertexAttribute {
+    /// Position (3 components). Missing: `(0, 0, 0)`.
+    Position,
+    /// Normal (3 components). Missing: `(0, 1, 0)` (+Y).
+    Normal,
+    /// Tangent (3 components). Missing: `(1, 0, 0)` (+X).
+    Tangent,
+    /// Bitangent (3 components). Missing: `(0, 0, 1)` (+Z).
+    Bitangent,
+    /// Texture coordinates from a UV channel (2 or 3 components). Missing: zeros.
+    TexCoord {
+        /// UV channel index
+        channel: usize,
+        /// Number of components to write (2 or 3)
+        components: usize,
+    },
+    /// Vertex color from a color channel (4 components, RGBA). Missing: opaque white.
+    Color {
+        /// Color channel index
+        channel: usize,
+    },
+}
+
+impl VertexAttribute {
+    /// Number of `f32` components written for this attribute.
+    pub fn num_components(&self) -> usize {
+        match self {
+            Self::Position | Self::Normal | Self::Tangent | Self::Bitangent => 3,
+            Self::TexCoord { components, .. } => *components,
+            Self::Color { .. } => 4,
+        }
+    }
+
+    /// Size of this attribute in bytes.
+    pub fn size_in_bytes(&self) -> usize {
+        self.num_components() * std::mem::size_of::<f32>()
+    }
+
+    fn default_value(&self) -> [f32; 4] {
+        match self {
+            Self::Position | Self::TexCoord { .. } => [0.0, 0.0, 0.0, 0.0],
+            Self::Normal => [0.0, 1.0, 0.0, 0.0],
+            Self::Tangent => [1.0, 0.0, 0.0, 0.0],
+            Self::Bitangent => [0.0, 0.0, 1.0, 0.0],
+            Self::Color { .. } => [1.0, 1.0, 1.0, 1.0],
+        }
+    }
+}
+
+/// Selects which attributes to bake, in order.
+///
+/// Attributes are packed tightly in the order they were added.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VertexLayout {
+    attributes: Vec<VertexAttribute>,
+    strict: bool,
+}
+
+impl VertexLayout {
+    /// Create an empty layout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an attribute.
+    pub fn with(mut self, attribute: VertexAttribute) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Append the position attribute.
+    pub fn with_position(self) -> Self {
+        self.with(VertexAttribute::Position)
+    }
+
+    /// Append the normal attribute.
+    pub fn with_normal(self) -> Self {
+        self.with(VertexAttribute::Normal)
+    }
+
+    /// Append the tangent attribute.
+    pub fn with_tangent(self) -> Self {
+        self.with(VertexAttribute::Tangent)
+    }
+
+    /// Append the bitangent attribute.
+    pub fn with_bitangent(self) -> Self {
+        self.with(VertexAttribute::Bitangent)
+    }
+
+    /// Append 2-component texture coordinates from `channel`.
+    pub fn with_uv(self, channel: usize) -> Self {
+        self.with(VertexAttribute::TexCoord {
+            channel,
+            components: 2,
+        })
+    }
+
+    /// Append 3-component texture coordinates from `channel`.
+    pub fn with_uv3(self, channel: usize) -> Self {
+        self.with(VertexAttribute::TexCoord {
+            channel,
+            components: 3,
+        })
+    }
+
+    /// Append RGBA vertex colors from `channel`.
+    pub fn with_color(self, channel: usize) -> Self {
+        self.with(VertexAttribute::Color { channel })
+    }
+
+    /// When `true`, baking fails if the mesh lacks a requested attribute instead of
+    /// filling in the documented default.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Whether missing attributes are an error.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Attributes in buffer order.
+    pub fn attributes(&self) -> &[VertexAttribute] {
+        &self.attributes
+    }
+
+    /// Size of one vertex in bytes.
+    pub fn stride(&self) -> usize {
+        self.attributes.iter().map(|a| a.size_in_bytes()).sum()
+    }
+
+    /// Byte offset of the first occurrence of `attribute` within a vertex.
+    pub fn offset_of(&self, attribute: VertexAttribute) -> Option<usize> {
+        let mut offset = 0;
+        for a in &self.attributes {
+            if *a == attribute {
+                return Some(offset);
+            }
+            offset += a.size_in_bytes();
+        }
+        None
+    }
+}
+
+/// An attribute placed at a byte offset within a baked vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BakedAttribute {
+    /// The attribute stored at `offset`.
+    pub attribute: VertexAttribute,
+    /// Byte offset within a vertex.
+    pub offset: usize,
+    /// `false` when the mesh lacked this attribute and defaults were written.
+    pub present: bool,
+}
+
+/// Interleaved vertex data plus a flat index buffer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BakedMesh {
+    data: Vec<f32>,
+    stride: usize,
+    vertex_count: usize,
+    attributes: Vec<BakedAttribute>,
+    /// Indices of all faces, concatenated in face order.
+    ///
+    /// This is a triangle list when the scene was imported with
+    /// `PostProcessSteps::TRIANGULATE` (and, ideally, `SORT_BY_PTYPE`).
+    pub indices: Vec<u32>,
+}
+
+impl BakedMesh {
+    /// Size of one vertex in bytes.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Number of vertices.
+    pub fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    /// Attributes with their byte offsets, in buffer order.
+    pub fn attributes(&self) -> &[BakedAttribute] {
+        &self.attributes
+    }
+
+    /// Byte offset of the first occurrence of `attribute`.
+    pub fn offset_of(&self, attribute: VertexAttribute) -> Option<usize> {
+        self.attributes
+            .iter()
+            .find(|a| a.attribute == attribute)
+            .map(|a| a.offset)
+    }
+
+    /// Interleaved vertex data as `f32` components.
+    pub fn interleaved(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// Interleaved vertex data as native-endian bytes, ready for upload.
+    pub fn interleaved_bytes(&self) -> &[u8] {
+        let len = std::mem::size_of_val(self.data.as_slice());
+        // SAFETY: `f32` has no padding or invalid bit patterns, `u8` has alignment 1, and
+        // the returned slice borrows `self.data` for exactly its byte length.
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr().cast::<u8>(), len) }
+    }
+
+    /// Consume the bake and return the raw `f32` data and indices.
+    pub fn into_parts(self) -> (Vec<f32>, Vec<u32>) {
+        (self.data, self.indices)
+    }
+}
+
+impl Mesh {
+    /// Bake the selected attributes into an interleaved `f32` vertex buffer.
+    ///
+    /// Reads directly from the mesh's raw attribute slices. Missing attributes are
+    /// filled with the defaults documented on [`VertexAttribute`], or produce an
+    /// error when the layout is [`VertexLayout::strict`].
+    pub fn bake_vertices(&self, layout: &VertexLayout) -> Result<BakedMesh> {
+        let vertex_count = self.num_vertices();
+        let stride_floats = layout.stride() / std::mem::size_of::<f32>();
+        let len = vertex_count
+            .checked_mul(stride_floats)
+            .ok_or_else(|| Error::invalid_scene("Baked vertex buffer size overflows"))?;
+        let mut data = vec![0.0f32; len];
+        let mut attributes = Vec::with_capacity(layout.attributes.len());
+
+        let mut offset = 0;
+        for &attribute in &layout.attributes {
+            if matches!(attribute, VertexAttribute::TexCoord { components, .. } if !(2..=3).contains(&components))
+            {
+                return Err(Error::invalid_parameter(
+                    "Texture coordinates must be baked with 2 or 3 components",
+                ));
+            }
+
+            let source = self.attribute_source(attribute);
+            if source.is_none() && layout.strict {
+                return Err(Error::invalid_scene(format!(
+                    "Mesh is missing vertex attribute {:?}",
+                    attribute
+                )));
+            }
+
+            let width = attribute.num_components();
+            let column = offset / std::mem::size_of::<f32>();
+            let vertices = data.chunks_exact_mut(stride_floats.max(1));
+            match source {
+                Some(AttributeSource::Vectors(values)) => {
+                    for (vertex, v) in vertices.zip(values) {
+                        vertex[column..column + width].copy_from_slice(
+                            &[from_real(v.x), from_real(v.y), from_real(v.z)][..width],
+                        );
+                    }
+                }
+                Some(AttributeSource::Colors(values)) => {
+                    for (vertex, c) in vertices.zip(values) {
+                        vertex[column..column + width].copy_from_slice(&[c.r, c.g, c.b, c.a]);
+                    }
+                }
+                None => {
+                    let default = attribute.default_value();
+                    for vertex in vertices {
+                        vertex[column..column + width].copy_from_slice(&default[..width]);
+                    }
+                }
+            }
+
+            attributes.push(BakedAttribute {
+                attribute,
+                offset,
+                present: source.is_some(),
+            });
+            offset += attribute.size_in_bytes();
+        }
+
+        let mut indices = Vec::with_capacity(self.num_faces().saturating_mul(3));
+        for face in self.faces_iter() {
+            indices.extend_from_slice(face.indices_raw());
+        }
+
+        Ok(BakedMesh {
+            data,
+            stride: layout.stride(),
+            vertex_count,
+            attributes,
+            indices,
+        })
+    }
+
+    fn attribute_source(&self, attribute: VertexAttribute) -> Option<AttributeSource<'_>> {
+        let vectors = match attribute {
+            VertexAttribute::Position => self.vertices_raw_opt(),
+            VertexAttribute::Normal => self.normals_raw_opt(),
+            VertexAttribute::Tangent => self.tangents_raw_opt(),
+            VertexAttribute::Bitangent => self.bitangents_raw_opt(),
+            VertexAttribute::TexCoord { channel, .. } => self.texture_coords_raw_opt(channel),
+            VertexAttribute::Color { channel } => {
+                return self
+                    .vertex_colors_raw_opt(channel)
+                    .map(AttributeSource::Colors);
+            }
+        };
+        vectors.map(AttributeSource::Vectors)
+    }
+}
+
+impl Scene {
+    /// Bake every mesh with the same layout, in [`Scene::meshes`] order.
+    ///
+    /// With the `rayon` feature the meshes are baked in parallel; the output is
+    /// identical to baking them one by one. Fails with the first error
+    /// [`Mesh::bake_vertices`] reports.
+    pub fn bake_all_meshes(&self, layout: &VertexLayout) -> Result<Vec<BakedMesh>> {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::iter::ParallelIterator;
+            self.par_meshes()
+                .map(|mesh| mesh.bake_vertices(layout))
+                .collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.meshes()
+                .map(|mesh| mesh.bake_vertices(layout))
+                .collect()
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum AttributeSource<'a> {
+    Vectors(&'a [raw::AiVector3D]),
+    Colors(&'a [raw::AiColor4D]),
+}
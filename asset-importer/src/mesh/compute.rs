@@ -0,0 +1,447 @@
+//! Pure-Rust geometry helpers for data Assimp did not generate at import time
+//! (normals and tangents) and for welding vertices after the fact.
+//!
+//! ```rust,no_run
+//! # use asset_importer::mesh::{Mesh, compute::NormalMode};
+//! # fn normals(mesh: &Mesh) -> asset_importer::Result<()> {
+//! let normals = match mesh.normals() {
+//!     Some(normals) => normals,
+//!     None => mesh.computed_normals(NormalMode::SmoothAngle(80.0))?,
+//! };
+//! # let _ = normals;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{
+    error::{Error, Result},
+    mesh::Mesh,
+    types::{Vector2D, Vector3D},
+};
+
+/// How [`generate_normals`] derives per-vertex normals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormalMode {
+    /// Each vertex takes the normal of the (last) triangle that references it.
+    ///
+    /// Gives faceted shading when vertices are not shared between faces, which is how
+    /// most importers emit meshes unless `JOIN_IDENTICAL_VERTICES` ran.
+    Flat,
+    /// Smooth normals with a crease angle in degrees.
+    ///
+    /// Each vertex first accumulates the area-weighted normals of the triangles that
+    /// reference it. Vertices at the same position are then averaged together when
+    /// their normals differ by at most the given angle, mirroring Assimp's
+    /// `GEN_SMOOTH_NORMALS` (whose default limit is 175°).
+    SmoothAngle(f32),
+}
+
+/// Compute per-vertex normals for a triangle list.
+///
+/// `indices` must hold whole triangles referencing `positions`. Vertices that are
+/// only used by degenerate (zero-area) triangles, or by none at all, get a zero
+/// vector rather than NaN.
+pub fn generate_normals(
+    positions: &[Vector3D],
+    indices: &[u32],
+    mode: NormalMode,
+) -> Result<Vec<Vector3D>> {
+    check_triangles(indices, positions.len())?;
+
+    let mut normals = vec![Vector3D::ZERO; positions.len()];
+    let triangles = indices.chunks_exact(3);
+    match mode {
+        NormalMode::Flat => {
+            for triangle in triangles {
+                let normal = face_normal(positions, triangle).normalize();
+                if normal != Vector3D::ZERO {
+                    for &i in triangle {
+                        normals[i as usize] = normal;
+                    }
+                }
+            }
+        }
+        NormalMode::SmoothAngle(max_degrees) => {
+            // The unnormalized cross product is twice the triangle area, so summing it
+            // weights each face by area; degenerate faces add nothing.
+            for triangle in triangles {
+                let normal = face_normal(positions, triangle);
+                for &i in triangle {
+                    normals[i as usize] = normals[i as usize] + normal;
+                }
+            }
+            for normal in &mut normals {
+                *normal = normal.normalize();
+            }
+            smooth_coincident(positions, &mut normals, max_degrees);
+        }
+    }
+    Ok(normals)
+}
+
+fn check_triangles(indices: &[u32], vertex_count: usize) -> Result<()> {
+    if indices.len() % 3 != 0 {
+        return Err(Error::invalid_parameter(format!(
+            "Index count {} is not a multiple of 3",
+            indices.len()
+        )));
+    }
+    check_indices(indices, vertex_count)
+}
+
+fn face_normal(positions: &[Vector3D], triangle: &[u32]) -> Vector3D {
+    let a = positions[triangle[0] as usize];
+    let b = positions[triangle[1] as usize];
+    let c = positions[triangle[2] as usize];
+    (b - a).cross(c - a)
+}
+
+/// Compute per-vertex tangents for a triangle list, with handedness in `w`.
+///
+/// Uses per-triangle accumulation in the style of MikkTSpace (Lengyel's method):
+/// each triangle's UV-aligned tangent and bitangent are summed into its vertices,
+/// then the tangent is Gram-Schmidt orthogonalized against the vertex normal and
+/// normalized. `w` is `1.0` when `cross(normal, tangent)` points along the
+/// accumulated bitangent and `-1.0` when it points away (mirrored UVs), so the
+/// bitangent is `cross(normal, tangent) * w` as in glTF. The result is deterministic
+/// but not bit-identical to MikkTSpace.
+///
+/// Triangles with degenerate UVs contribute nothing; vertices left without a
+/// usable tangent get an arbitrary unit tangent orthogonal to their normal and
+/// `w = 1.0`.
+pub fn generate_tangents(
+    positions: &[Vector3D],
+    normals: &[Vector3D],
+    uvs: &[Vector2D],
+    indices: &[u32],
+) -> Result<Vec<[f32; 4]>> {
+    if normals.len() != positions.len() || uvs.len() != positions.len() {
+        return Err(Error::invalid_parameter(format!(
+            "Attribute lengths differ: {} positions, {} normals, {} UVs",
+            positions.len(),
+            normals.len(),
+            uvs.len()
+        )));
+    }
+    check_triangles(indices, positions.len())?;
+
+    let mut tangents = vec![Vector3D::ZERO; positions.len()];
+    let mut bitangents = vec![Vector3D::ZERO; positions.len()];
+    for triangle in indices.chunks_exact(3) {
+        let [i0, i1, i2] = [0, 1, 2].map(|k| triangle[k] as usize);
+        let e1 = positions[i1] - positions[i0];
+        let e2 = positions[i2] - positions[i0];
+        let (du1, dv1) = (uvs[i1].x - uvs[i0].x, uvs[i1].y - uvs[i0].y);
+        let (du2, dv2) = (uvs[i2].x - uvs[i0].x, uvs[i2].y - uvs[i0].y);
+
+        let det = du1 * dv2 - du2 * dv1;
+        if det.abs() <= f32::EPSILON || !det.is_finite() {
+            continue;
+        }
+        let r = 1.0 / det;
+        let tangent = (e1 * dv2 - e2 * dv1) * r;
+        let bitangent = (e2 * du1 - e1 * du2) * r;
+        for i in [i0, i1, i2] {
+            tangents[i] = tangents[i] + tangent;
+            bitangents[i] = bitangents[i] + bitangent;
+        }
+    }
+
+    Ok(tangents
+        .iter()
+        .zip(&bitangents)
+        .zip(normals)
+        .map(|((&t, &b), &n)| {
+            let n = n.normalize();
+            let mut tangent = (t - n * n.dot(t)).normalize();
+            if tangent == Vector3D::ZERO || !tangent.x.is_finite() {
+                tangent = any_orthonormal(n);
+            }
+            let w = if n.cross(tangent).dot(b) < 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            [tangent.x, tangent.y, tangent.z, w]
+        })
+        .collect())
+}
+
+/// A unit vector orthogonal to `n` (or +X when `n` is zero).
+fn any_orthonormal(n: Vector3D) -> Vector3D {
+    if n == Vector3D::ZERO {
+        return Vector3D::new(1.0, 0.0, 0.0);
+    }
+    // Cross with the axis least aligned with `n` to stay well conditioned.
+    let axis = if n.x.abs() <= n.y.abs() && n.x.abs() <= n.z.abs() {
+        Vector3D::new(1.0, 0.0, 0.0)
+    } else if n.y.abs() <= n.z.abs() {
+        Vector3D::new(0.0, 1.0, 0.0)
+    } else {
+        Vector3D::new(0.0, 0.0, 1.0)
+    };
+    n.cross(axis).normalize()
+}
+
+/// Average normals of vertices that share a position and lie within `max_degrees`.
+fn smooth_coincident(positions: &[Vector3D], normals: &mut [Vector3D], max_degrees: f32) {
+    // `+ 0.0` folds -0.0 into 0.0 so both hash to the same key.
+    let key = |p: &Vector3D| {
+        [
+            (p.x + 0.0).to_bits(),
+            (p.y + 0.0).to_bits(),
+            (p.z + 0.0).to_bits(),
+        ]
+    };
+    let mut groups: HashMap<[u32; 3], Vec<usize>> = HashMap::new();
+    for (i, p) in positions.iter().enumerate() {
+        groups.entry(key(p)).or_default().push(i);
+    }
+
+    let cos_limit = max_degrees.clamp(0.0, 180.0).to_radians().cos();
+    let own = normals.to_vec();
+    for members in groups.values().filter(|m| m.len() > 1) {
+        for &i in members {
+            let reference = own[i];
+            if reference == Vector3D::ZERO {
+                continue;
+            }
+            let sum = members
+                .iter()
+                .map(|&j| own[j])
+                .filter(|n| *n != Vector3D::ZERO && n.dot(reference) >= cos_limit)
+                .fold(Vector3D::ZERO, |acc, n| acc + n);
+            normals[i] = sum.normalize();
+        }
+    }
+}
+
+/// Extra per-vertex data that must also match for [`weld_vertices`] to merge two vertices.
+///
+/// Leave both fields `None` for position-only welding (e.g. collision meshes); set them
+/// to keep seams that `JOIN_IDENTICAL_VERTICES` would also keep.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeldAttributes<'a> {
+    /// Per-vertex normals, with the largest angle in degrees between merged normals.
+    pub normals: Option<(&'a [Vector3D], f32)>,
+    /// Per-vertex UVs, with the largest per-component difference between merged UVs.
+    pub uvs: Option<(&'a [Vector2D], f32)>,
+}
+
+/// Result of [`weld_vertices`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WeldedMesh {
+    /// Welded positions; each is the first original vertex of its group.
+    pub positions: Vec<Vector3D>,
+    /// The input indices rewritten to refer to `positions`.
+    pub indices: Vec<u32>,
+    /// For every original vertex, the index of the welded vertex it became.
+    pub remap: Vec<u32>,
+}
+
+impl WeldedMesh {
+    /// Number of vertices after welding.
+    pub fn num_vertices(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Reorder a per-vertex attribute of the original mesh to match the welded vertices,
+    /// keeping the value of the first original vertex in each group.
+    ///
+    /// `values` must have one entry per original vertex.
+    pub fn remap_attribute<T: Copy>(&self, values: &[T]) -> Result<Vec<T>> {
+        if values.len() != self.remap.len() {
+            return Err(Error::invalid_parameter(format!(
+                "Attribute has {} values, expected {}",
+                values.len(),
+                self.remap.len()
+            )));
+        }
+        let mut out: Vec<Option<T>> = vec![None; self.positions.len()];
+        for (&new, &value) in self.remap.iter().zip(values) {
+            out[new as usize].get_or_insert(value);
+        }
+        Ok(out.into_iter().flatten().collect())
+    }
+}
+
+/// Merge vertices that lie within `tolerance` of each other, and whose optional
+/// `attributes` also match, then rewrite `indices` accordingly.
+///
+/// Works on any index list (triangles, lines or points); every position is kept or
+/// merged, including ones no index references. Vertices are visited in order and each
+/// joins the lowest-numbered earlier group it matches, so the result does not depend
+/// on hashing and is identical across runs. A `tolerance` of `0.0` merges only exactly
+/// equal positions, like Assimp's `JOIN_IDENTICAL_VERTICES`.
+pub fn weld_vertices(
+    positions: &[Vector3D],
+    indices: &[u32],
+    tolerance: f32,
+    attributes: Option<&WeldAttributes<'_>>,
+) -> Result<WeldedMesh> {
+    check_indices(indices, positions.len())?;
+    let attributes = attributes.copied().unwrap_or_default();
+    for (name, len) in [
+        ("normals", attributes.normals.map(|(n, _)| n.len())),
+        ("UVs", attributes.uvs.map(|(uv, _)| uv.len())),
+    ] {
+        match len {
+            Some(len) if len != positions.len() => {
+                return Err(Error::invalid_parameter(format!(
+                    "Attribute lengths differ: {} positions, {} {}",
+                    positions.len(),
+                    len,
+                    name
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    let tolerance = if tolerance.is_finite() {
+        tolerance.max(0.0)
+    } else {
+        0.0
+    };
+    let cos_limit = attributes
+        .normals
+        .map(|(_, degrees)| degrees.clamp(0.0, 180.0).to_radians().cos());
+    let matches = |a: usize, b: usize| {
+        if (positions[a] - positions[b]).length() > tolerance {
+            return false;
+        }
+        let normals_match = match (attributes.normals, cos_limit) {
+            (Some((normals, _)), Some(cos_limit)) => {
+                let (na, nb) = (normals[a].normalize(), normals[b].normalize());
+                if na == Vector3D::ZERO || nb == Vector3D::ZERO {
+                    na == nb
+                } else {
+                    na.dot(nb) >= cos_limit
+                }
+            }
+            _ => true,
+        };
+        let uvs_match = attributes.uvs.is_none_or(|(uvs, eps)| {
+            (uvs[a].x - uvs[b].x).abs() <= eps && (uvs[a].y - uvs[b].y).abs() <= eps
+        });
+        normals_match && uvs_match
+    };
+
+    // Cells are `tolerance` wide, so any match lies in the same or an adjacent cell.
+    let cell_of = |p: Vector3D| -> [i64; 3] {
+        if tolerance > 0.0 {
+            [p.x, p.y, p.z].map(|c| (c / tolerance).floor() as i64)
+        } else {
+            // `+ 0.0` folds -0.0 into 0.0 so both land in the same cell.
+            [p.x, p.y, p.z].map(|c| i64::from((c + 0.0).to_bits()))
+        }
+    };
+    let reach: i64 = if tolerance > 0.0 { 1 } else { 0 };
+
+    // Each cell lists the original vertex that founded each group in it.
+    let mut cells: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+    let mut welded = WeldedMesh {
+        positions: Vec::new(),
+        indices: Vec::with_capacity(indices.len()),
+        remap: Vec::with_capacity(positions.len()),
+    };
+    for (i, &p) in positions.iter().enumerate() {
+        let cell = cell_of(p);
+        let mut best: Option<u32> = None;
+        for dx in -reach..=reach {
+            for dy in -reach..=reach {
+                for dz in -reach..=reach {
+                    let key = [
+                        cell[0].saturating_add(dx),
+                        cell[1].saturating_add(dy),
+                        cell[2].saturating_add(dz),
+                    ];
+                    let Some(founders) = cells.get(&key) else {
+                        continue;
+                    };
+                    for &founder in founders {
+                        let group = welded.remap[founder];
+                        if best.is_none_or(|b| group < b) && matches(i, founder) {
+                            best = Some(group);
+                        }
+                    }
+                }
+            }
+        }
+        let group = match best {
+            Some(group) => group,
+            None => {
+                cells.entry(cell).or_default().push(i);
+                welded.positions.push(p);
+                (welded.positions.len() - 1) as u32
+            }
+        };
+        welded.remap.push(group);
+    }
+    welded.indices = indices.iter().map(|&i| welded.remap[i as usize]).collect();
+    Ok(welded)
+}
+
+fn check_indices(indices: &[u32], vertex_count: usize) -> Result<()> {
+    if let Some(&index) = indices.iter().find(|&&i| i as usize >= vertex_count) {
+        return Err(Error::invalid_parameter(format!(
+            "Index {} out of range for {} positions",
+            index, vertex_count
+        )));
+    }
+    Ok(())
+}
+
+impl Mesh {
+    /// Compute normals from this mesh's positions and faces.
+    ///
+    /// Useful when the scene was imported without `GEN_NORMALS`/`GEN_SMOOTH_NORMALS`.
+    /// Fails unless every face is a triangle (import with `TRIANGULATE`, and
+    /// `SORT_BY_PTYPE` to split off points and lines).
+    pub fn computed_normals(&self, mode: NormalMode) -> Result<Vec<Vector3D>> {
+        let indices = self.triangle_index_list()?;
+        generate_normals(&self.vertices(), &indices, mode)
+    }
+
+    /// Compute tangents (with handedness in `w`) from this mesh's positions, normals,
+    /// faces and the UV channel `uv_channel`; see [`generate_tangents`].
+    ///
+    /// Fails when the mesh has no normals or no such UV channel, or is not triangulated.
+    pub fn computed_tangents(&self, uv_channel: usize) -> Result<Vec<[f32; 4]>> {
+        let normals = self
+            .normals()
+            .ok_or_else(|| Error::invalid_scene("Tangent generation requires normals"))?;
+        let uvs = self.texture_coords_2d(uv_channel).ok_or_else(|| {
+            Error::invalid_scene(format!("Mesh has no UV channel {}", uv_channel))
+        })?;
+        let indices = self.triangle_index_list()?;
+        generate_tangents(&self.vertices(), &normals, &uvs, &indices)
+    }
+
+    /// Weld this mesh's vertices by position only; see [`weld_vertices`].
+    ///
+    /// Fails unless every face is a triangle, like [`Mesh::computed_normals`]. Use
+    /// [`WeldedMesh::remap_attribute`] to carry other vertex data over.
+    pub fn welded(&self, tolerance: f32) -> Result<WeldedMesh> {
+        let indices = self.triangle_index_list()?;
+        weld_vertices(&self.vertices(), &indices, tolerance, None)
+    }
+
+    /// Flat triangle index list, or an error if any face is not a triangle.
+    fn triangle_index_list(&self) -> Result<Vec<u32>> {
+        let mut indices = Vec::with_capacity(self.num_faces().saturating_mul(3));
+        for face in self.faces_iter() {
+            let face_indices = face.indices_raw();
+            if face_indices.len() != 3 {
+                return Err(Error::invalid_scene(format!(
+                    "Expected a triangulated mesh, found a face with {} indices",
+                    face_indices.len()
+                )));
+            }
+            indices.extend_from_slice(face_indices);
+        }
+        Ok(indices)
+    }
+}
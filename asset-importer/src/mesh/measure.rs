@@ -0,0 +1,171 @@
+//! Surface area and enclosed volume of meshes, in local or world space.
+//!
+//! Sums are accumulated in `f64` straight from the raw position and face buffers.
+//! Only triangle faces are measured; run `TRIANGULATE` first to include polygons.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! # fn check(scene: &Scene) {
+//! for mesh in scene.meshes() {
+//!     let measures = mesh.measures();
+//!     if measures.skipped_non_finite > 0 {
+//!         eprintln!("{}: {} triangles with NaN positions", mesh.name(), measures.skipped_non_finite);
+//!     }
+//!     if mesh.is_closed() {
+//!         println!("{}: volume {}", mesh.name(), measures.volume());
+//!     }
+//! }
+//! println!("world area {}", scene.world_surface_area());
+//! # }
+//! ```
+
+use std::ops::AddAssign;
+
+use crate::{
+    mesh::Mesh,
+    scene::Scene,
+    types::{Matrix4x4, Vector4D},
+};
+
+type Point = [f64; 3];
+
+/// Area and volume of one or more meshes, from [`Mesh::measures`] or
+/// [`Scene::world_measures`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MeshMeasures {
+    /// Sum of the triangle areas
+    pub surface_area: f64,
+    /// Volume enclosed by the triangles (divergence theorem), positive when they wind
+    /// counter-clockwise seen from outside. Only meaningful for closed meshes, see
+    /// [`Mesh::is_closed`].
+    pub signed_volume: f64,
+    /// Triangles that were measured (degenerate ones included; they add zero)
+    pub triangles: usize,
+    /// Point, line and polygon faces, which are not measured
+    pub skipped_faces: usize,
+    /// Triangles left out because a corner is NaN or infinite, or its index is out of
+    /// range
+    pub skipped_non_finite: usize,
+}
+
+impl MeshMeasures {
+    /// Unsigned enclosed volume.
+    pub fn volume(&self) -> f64 {
+        self.signed_volume.abs()
+    }
+}
+
+impl AddAssign for MeshMeasures {
+    fn add_assign(&mut self, other: Self) {
+        self.surface_area += other.surface_area;
+        self.signed_volume += other.signed_volume;
+        self.triangles += other.triangles;
+        self.skipped_faces += other.skipped_faces;
+        self.skipped_non_finite += other.skipped_non_finite;
+    }
+}
+
+impl Mesh {
+    /// Surface area, signed volume and the skipped face counts in one pass.
+    pub fn measures(&self) -> MeshMeasures {
+        self.measure_with(|p| p)
+    }
+
+    /// [`Mesh::measures`] with every vertex transformed by `transform` first.
+    ///
+    /// Vertices are transformed rather than the results scaled, so non-uniform scale and
+    /// shear are handled exactly. A mirroring transform (negative determinant) flips the
+    /// winding and with it the sign of the volume.
+    pub fn measures_transformed(&self, transform: &Matrix4x4) -> MeshMeasures {
+        let column = |c: Vector4D| [f64::from(c.x), f64::from(c.y), f64::from(c.z)];
+        let [x, y, z, w] = [
+            column(transform.x_axis),
+            column(transform.y_axis),
+            column(transform.z_axis),
+            column(transform.w_axis),
+        ];
+        self.measure_with(|p| {
+            std::array::from_fn(|i| x[i] * p[0] + y[i] * p[1] + z[i] * p[2] + w[i])
+        })
+    }
+
+    /// Sum of the triangle areas. Point, line and polygon faces are skipped, as are
+    /// triangles with non-finite positions; see [`Mesh::measures`] for the counts.
+    pub fn surface_area(&self) -> f64 {
+        self.measures().surface_area
+    }
+
+    /// Volume enclosed by the triangles, positive for outward-facing (counter-clockwise)
+    /// winding.
+    ///
+    /// Computed with the divergence theorem, so the result is only meaningful when the
+    /// mesh is closed ([`Mesh::is_closed`]); for open meshes it depends on where the
+    /// origin is.
+    pub fn signed_volume(&self) -> f64 {
+        self.measures().signed_volume
+    }
+
+    fn measure_with(&self, transform: impl Fn(Point) -> Point) -> MeshMeasures {
+        let positions = self.vertices_raw();
+        let corner = |index: u32| {
+            let v = positions.get(index as usize)?;
+            let p = transform([f64::from(v.x), f64::from(v.y), f64::from(v.z)]);
+            p.iter().all(|c| c.is_finite()).then_some(p)
+        };
+
+        let mut measures = MeshMeasures::default();
+        for face in self.faces_raw() {
+            // SAFETY: the face comes from this mesh's Assimp-owned face array.
+            let &[a, b, c] = (unsafe { face.indices_unchecked() }) else {
+                measures.skipped_faces += 1;
+                continue;
+            };
+            let (Some(a), Some(b), Some(c)) = (corner(a), corner(b), corner(c)) else {
+                measures.skipped_non_finite += 1;
+                continue;
+            };
+            let normal = cross(sub(b, a), sub(c, a));
+            measures.surface_area += 0.5 * dot(normal, normal).sqrt();
+            measures.signed_volume += dot(a, cross(b, c)) / 6.0;
+            measures.triangles += 1;
+        }
+        measures
+    }
+}
+
+impl Scene {
+    /// [`Mesh::measures_transformed`] summed over every mesh instance in world space.
+    ///
+    /// A mesh referenced by several nodes counts once per node; meshes no node
+    /// references are not counted.
+    pub fn world_measures(&self) -> MeshMeasures {
+        let mut total = MeshMeasures::default();
+        for item in self.renderables() {
+            if let Some(mesh) = self.mesh(item.mesh_index) {
+                total += mesh.measures_transformed(&item.world_transform);
+            }
+        }
+        total
+    }
+
+    /// Total world-space surface area, see [`Scene::world_measures`].
+    pub fn world_surface_area(&self) -> f64 {
+        self.world_measures().surface_area
+    }
+}
+
+fn sub(a: Point, b: Point) -> Point {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Point, b: Point) -> Point {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: Point, b: Point) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
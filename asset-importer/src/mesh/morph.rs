@@ -0,0 +1,125 @@
+//! Morph target (anim mesh) deltas and CPU blending.
+//!
+//! Assimp stores every [`AnimMesh`] buffer as a full replacement of the base mesh
+//! stream (importers such as glTF and FBX add their target deltas onto a copy of the
+//! base positions), whatever [`MorphingMethod`] the mesh reports. The delta helpers
+//! therefore always subtract the base, and the morphing method only decides how the
+//! weights are combined in [`Mesh::evaluate_morphs`].
+//!
+//! ```rust,no_run
+//! # use asset_importer::{animation::Animation, mesh::Mesh};
+//! # fn pose(mesh: &Mesh, animation: &Animation, ticks: f64) {
+//! if let Some(channel) = animation.morph_mesh_channels().next() {
+//!     let weights: Vec<(usize, f32)> = channel
+//!         .sample(ticks)
+//!         .into_iter()
+//!         .map(|(target, weight)| (target as usize, weight as f32))
+//!         .collect();
+//!     let positions = mesh.evaluate_morphs(&weights);
+//!     # let _ = positions;
+//! }
+//! # }
+//! ```
+
+use crate::{
+    logging::debug_message,
+    mesh::{AnimMesh, Mesh, MorphingMethod},
+    raw,
+    types::Vector3D,
+};
+
+fn to_vector(v: &raw::AiVector3D) -> Vector3D {
+    Vector3D::from(*v)
+}
+
+/// `replacement - base` per vertex; zero where the anim mesh has no data.
+fn deltas(replacement: &[raw::AiVector3D], base: &[raw::AiVector3D]) -> Vec<Vector3D> {
+    let mut deltas = vec![Vector3D::ZERO; base.len()];
+    for ((delta, target), base) in deltas.iter_mut().zip(replacement).zip(base) {
+        *delta = to_vector(target) - to_vector(base);
+    }
+    deltas
+}
+
+impl AnimMesh {
+    /// Per-vertex offsets of this morph target's positions from `base`.
+    ///
+    /// The anim mesh buffer holds replacement positions, so this is `replacement - base`
+    /// for every [`MorphingMethod`]; for [`MorphingMethod::MorphRelative`] meshes (the
+    /// glTF case) it recovers the delta the source file stored. The result has one
+    /// entry per base vertex; vertices the target does not cover (no replacement
+    /// positions, or a shorter buffer) get a zero delta.
+    pub fn position_deltas(&self, base: &Mesh) -> Vec<Vector3D> {
+        deltas(self.vertices_raw(), base.vertices_raw())
+    }
+
+    /// Per-vertex offsets of this morph target's normals from `base`, like
+    /// [`AnimMesh::position_deltas`]. Empty when `base` has no normals.
+    pub fn normal_deltas(&self, base: &Mesh) -> Vec<Vector3D> {
+        deltas(self.normals_raw(), base.normals_raw())
+    }
+}
+
+impl Mesh {
+    /// Blend the morph targets into a new position buffer.
+    ///
+    /// `weights` pairs anim mesh indices with weights, e.g. from
+    /// [`MorphMeshAnimation::sample`](crate::animation::MorphMeshAnimation::sample) or
+    /// [`AnimMesh::weight`]. Targets are applied as `base + Σ w · (target - base)`; for
+    /// [`MorphingMethod::MorphNormalized`] the weights are first scaled down so they sum
+    /// to at most 1. Out-of-range target indices are skipped (and reported at debug
+    /// level through `log`/`tracing`).
+    pub fn evaluate_morphs(&self, weights: &[(usize, f32)]) -> Vec<Vector3D> {
+        let mut positions: Vec<Vector3D> = self.vertices_iter().collect();
+        self.accumulate_morphs(weights, &mut positions, AnimMesh::position_deltas);
+        positions
+    }
+
+    /// Blend the morph targets' normals like [`Mesh::evaluate_morphs`] and renormalize.
+    ///
+    /// Returns `None` when the mesh has no normals.
+    pub fn evaluate_morph_normals(&self, weights: &[(usize, f32)]) -> Option<Vec<Vector3D>> {
+        let mut normals = self.normals()?;
+        self.accumulate_morphs(weights, &mut normals, AnimMesh::normal_deltas);
+        for normal in &mut normals {
+            let length = normal.length();
+            if length > 0.0 {
+                *normal = *normal / length;
+            }
+        }
+        Some(normals)
+    }
+
+    fn accumulate_morphs(
+        &self,
+        weights: &[(usize, f32)],
+        values: &mut [Vector3D],
+        deltas: impl Fn(&AnimMesh, &Mesh) -> Vec<Vector3D>,
+    ) {
+        let scale = match self.morphing_method() {
+            MorphingMethod::MorphNormalized => {
+                let total: f32 = weights.iter().map(|&(_, weight)| weight).sum();
+                if total > 1.0 { 1.0 / total } else { 1.0 }
+            }
+            _ => 1.0,
+        };
+        for &(target, weight) in weights {
+            let Some(anim_mesh) = self.anim_mesh(target) else {
+                debug_message(format_args!(
+                    "Skipping morph target {} of mesh '{}' ({} anim meshes)",
+                    target,
+                    self.name(),
+                    self.num_anim_meshes()
+                ));
+                continue;
+            };
+            let weight = weight * scale;
+            if weight == 0.0 {
+                continue;
+            }
+            for (value, delta) in values.iter_mut().zip(deltas(&anim_mesh, self)) {
+                *value = *value + delta * weight;
+            }
+        }
+    }
+}
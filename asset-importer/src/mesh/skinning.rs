@@ -0,0 +1,147 @@
+//! Per-vertex bone influences, skinning validation and weight normalization.
+//!
+//! Assimp stores skinning per bone (each bone lists the vertices it moves). Renderers
+//! want the transpose: a few `(bone, weight)` pairs per vertex whose weights sum to one.
+//!
+//! ```rust,no_run
+//! # use asset_importer::mesh::Mesh;
+//! # fn skin(mesh: &Mesh) {
+//! let report = mesh.validate_skinning();
+//! if !report.is_normalized() {
+//!     eprintln!("{}: {report}", mesh.name());
+//! }
+//! let influences = mesh.normalized_vertex_weights(4);
+//! # let _ = influences;
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::mesh::Mesh;
+use crate::types::from_real;
+
+/// Weight sums within this distance of 1.0 count as normalized.
+const WEIGHT_SUM_TOLERANCE: f32 = 0.01;
+
+/// One bone's influence on a vertex.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VertexInfluence {
+    /// Index of the bone in [`Mesh::bones`].
+    pub bone_index: u32,
+    /// Weight of the bone for this vertex.
+    pub weight: f32,
+}
+
+/// Summary of a mesh's skinning data, from [`Mesh::validate_skinning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SkinningReport {
+    /// Number of vertices in the mesh.
+    pub num_vertices: usize,
+    /// Number of bones in the mesh.
+    pub num_bones: usize,
+    /// Vertices whose weights sum to zero (no bone moves them). For meshes without
+    /// bones this is every vertex.
+    pub unweighted_vertices: usize,
+    /// Vertices with a non-zero weight sum outside `[0.99, 1.01]`.
+    pub unnormalized_vertices: usize,
+    /// Largest number of bones influencing a single vertex.
+    pub max_influences: usize,
+}
+
+impl SkinningReport {
+    /// Whether every vertex is weighted and its weights sum to one.
+    pub fn is_normalized(&self) -> bool {
+        self.unweighted_vertices == 0 && self.unnormalized_vertices == 0
+    }
+}
+
+impl fmt::Display for SkinningReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} vertices, {} bones: {} unweighted, {} unnormalized, max {} influences",
+            self.num_vertices,
+            self.num_bones,
+            self.unweighted_vertices,
+            self.unnormalized_vertices,
+            self.max_influences
+        )
+    }
+}
+
+impl Mesh {
+    /// Collect bone influences per vertex (indexed by vertex).
+    ///
+    /// Influences appear in bone order. Weights referencing vertices outside the mesh
+    /// are ignored.
+    pub fn vertex_influences(&self) -> Vec<Vec<VertexInfluence>> {
+        let mut influences = vec![Vec::new(); self.num_vertices()];
+        for (bone_index, bone) in self.bones().enumerate() {
+            for weight in bone.weights_raw() {
+                if let Some(vertex) = influences.get_mut(weight.mVertexId as usize) {
+                    vertex.push(VertexInfluence {
+                        bone_index: bone_index as u32,
+                        weight: from_real(weight.mWeight),
+                    });
+                }
+            }
+        }
+        influences
+    }
+
+    /// Check the skinning data for unweighted and unnormalized vertices.
+    ///
+    /// Importing with `LIMIT_BONE_WEIGHTS` caps the influence count, but Assimp only
+    /// renormalizes the vertices it had to truncate, so unnormalized input weights can
+    /// still show up here.
+    pub fn validate_skinning(&self) -> SkinningReport {
+        self.skinning_report(&self.vertex_influences())
+    }
+
+    /// [`Mesh::validate_skinning`] for influences the caller already collected.
+    pub(crate) fn skinning_report(
+        &self,
+        vertex_influences: &[Vec<VertexInfluence>],
+    ) -> SkinningReport {
+        let mut report = SkinningReport {
+            num_vertices: self.num_vertices(),
+            num_bones: self.num_bones(),
+            ..SkinningReport::default()
+        };
+        for influences in vertex_influences {
+            report.max_influences = report.max_influences.max(influences.len());
+            let sum: f32 = influences.iter().map(|i| i.weight).sum();
+            if sum == 0.0 {
+                report.unweighted_vertices += 1;
+            } else if (sum - 1.0).abs() > WEIGHT_SUM_TOLERANCE {
+                report.unnormalized_vertices += 1;
+            }
+        }
+        report
+    }
+
+    /// Per-vertex influences limited to the `max_influences` strongest bones, with
+    /// weights rescaled to sum to one.
+    ///
+    /// Influences are ordered by descending weight, ties broken by ascending bone index,
+    /// so the result is deterministic. Vertices without any weight keep an empty list;
+    /// `max_influences == 0` empties every list.
+    pub fn normalized_vertex_weights(&self, max_influences: usize) -> Vec<Vec<VertexInfluence>> {
+        let mut influences = self.vertex_influences();
+        for vertex in &mut influences {
+            vertex.sort_by(|a, b| {
+                b.weight
+                    .total_cmp(&a.weight)
+                    .then_with(|| a.bone_index.cmp(&b.bone_index))
+            });
+            vertex.truncate(max_influences);
+            let sum: f32 = vertex.iter().map(|i| i.weight).sum();
+            if sum > 0.0 {
+                for influence in vertex.iter_mut() {
+                    influence.weight /= sum;
+                }
+            }
+        }
+        influences
+    }
+}
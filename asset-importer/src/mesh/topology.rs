@@ -0,0 +1,200 @@
+//! Edge and vertex adjacency built straight from the raw face array.
+//!
+//! Both structures use flat, CSR-style storage (one offsets array plus one index array)
+//! so looking up the faces around an edge or vertex is two slice reads.
+//!
+//! ```rust,no_run
+//! # use asset_importer::mesh::{Mesh, topology::EdgeList};
+//! # fn silhouette(mesh: &Mesh) {
+//! let edges = EdgeList::build(mesh);
+//! for edge in edges.boundary_edges() {
+//!     println!("open edge {:?} of face {}", edge.vertices, edge.faces[0]);
+//! }
+//! let around_first = mesh.adjacency().faces(0).len();
+//! # let _ = around_first;
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::mesh::Mesh;
+
+/// One undirected edge and the faces that use it, from [`EdgeList::iter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge<'a> {
+    /// Vertex indices, smaller index first.
+    pub vertices: [u32; 2],
+    /// Indices of the faces containing this edge, in face order.
+    pub faces: &'a [u32],
+}
+
+impl Edge<'_> {
+    /// Whether only one face uses this edge (an open border).
+    pub fn is_boundary(&self) -> bool {
+        self.faces.len() == 1
+    }
+
+    /// Whether more than two faces share this edge.
+    pub fn is_non_manifold(&self) -> bool {
+        self.faces.len() > 2
+    }
+}
+
+/// Unique undirected edges of a mesh with their adjacent faces.
+///
+/// Edges are numbered in order of first appearance while walking the faces. Polygon
+/// faces contribute their closed outline, line faces their single segment and point
+/// faces nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdgeList {
+    vertices: Vec<[u32; 2]>,
+    face_offsets: Vec<usize>,
+    faces: Vec<u32>,
+}
+
+impl EdgeList {
+    /// Collect the edges of `mesh` in a single pass over its faces.
+    pub fn build(mesh: &Mesh) -> Self {
+        let mut index: HashMap<(u32, u32), u32> = HashMap::new();
+        let mut vertices: Vec<[u32; 2]> = Vec::new();
+        // (edge, face) incidences, grouped by edge below.
+        let mut incidences: Vec<(u32, u32)> = Vec::new();
+
+        for (face_index, face) in mesh.faces_raw().iter().enumerate() {
+            // SAFETY: the face comes from the mesh's Assimp-owned face array.
+            let indices = unsafe { face.indices_unchecked() };
+            let segments = match indices.len() {
+                0 | 1 => 0,
+                2 => 1,
+                n => n,
+            };
+            let face_start = incidences.len();
+            for i in 0..segments {
+                let (a, b) = (indices[i], indices[(i + 1) % indices.len()]);
+                if a == b {
+                    continue;
+                }
+                let key = (a.min(b), a.max(b));
+                let edge = *index.entry(key).or_insert_with(|| {
+                    vertices.push([key.0, key.1]);
+                    (vertices.len() - 1) as u32
+                });
+                let face_index = face_index as u32;
+                // A degenerate polygon can revisit the same edge; record the face once.
+                if !incidences[face_start..].contains(&(edge, face_index)) {
+                    incidences.push((edge, face_index));
+                }
+            }
+        }
+
+        let (face_offsets, faces) = group_by_key(vertices.len(), &incidences);
+        Self {
+            vertices,
+            face_offsets,
+            faces,
+        }
+    }
+
+    /// Number of unique edges.
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Whether the mesh has no edges.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Edge by index.
+    pub fn get(&self, index: usize) -> Option<Edge<'_>> {
+        let vertices = *self.vertices.get(index)?;
+        let faces = &self.faces[self.face_offsets[index]..self.face_offsets[index + 1]];
+        Some(Edge { vertices, faces })
+    }
+
+    /// Iterate all edges in index order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Edge<'_>> + '_ {
+        (0..self.len()).map(|i| self.get(i).expect("edge index in range"))
+    }
+
+    /// Edges used by a single face.
+    pub fn boundary_edges(&self) -> impl Iterator<Item = Edge<'_>> + '_ {
+        self.iter().filter(Edge::is_boundary)
+    }
+
+    /// Edges shared by more than two faces.
+    pub fn non_manifold_edges(&self) -> impl Iterator<Item = Edge<'_>> + '_ {
+        self.iter().filter(Edge::is_non_manifold)
+    }
+}
+
+/// Faces around each vertex, from [`Mesh::adjacency`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VertexAdjacency {
+    offsets: Vec<usize>,
+    faces: Vec<u32>,
+}
+
+impl VertexAdjacency {
+    /// Number of vertices covered (the mesh's vertex count).
+    pub fn num_vertices(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Indices of the faces that reference `vertex`, in face order. Empty for unused or
+    /// out-of-range vertices.
+    pub fn faces(&self, vertex: usize) -> &[u32] {
+        match (self.offsets.get(vertex), self.offsets.get(vertex + 1)) {
+            (Some(&start), Some(&end)) => &self.faces[start..end],
+            _ => &[],
+        }
+    }
+}
+
+impl Mesh {
+    /// Build the vertex → face adjacency in one pass over the faces.
+    ///
+    /// A face is listed once per vertex even if it references the vertex twice. Indices
+    /// past the vertex count are ignored.
+    pub fn adjacency(&self) -> VertexAdjacency {
+        let num_vertices = self.num_vertices();
+        let mut incidences: Vec<(u32, u32)> = Vec::new();
+        for (face_index, face) in self.faces_raw().iter().enumerate() {
+            // SAFETY: the face comes from this mesh's Assimp-owned face array.
+            let indices = unsafe { face.indices_unchecked() };
+            for (i, &vertex) in indices.iter().enumerate() {
+                if (vertex as usize) < num_vertices && !indices[..i].contains(&vertex) {
+                    incidences.push((vertex, face_index as u32));
+                }
+            }
+        }
+        let (offsets, faces) = group_by_key(num_vertices, &incidences);
+        VertexAdjacency { offsets, faces }
+    }
+
+    /// Whether the surface is closed: it has edges and every edge is shared by at least
+    /// two faces. Non-manifold edges do not make a mesh open.
+    pub fn is_closed(&self) -> bool {
+        let edges = EdgeList::build(self);
+        !edges.is_empty() && edges.boundary_edges().next().is_none()
+    }
+}
+
+/// Counting sort of `(key, value)` pairs into CSR offsets and values, keeping the
+/// original order within each key.
+fn group_by_key(num_keys: usize, pairs: &[(u32, u32)]) -> (Vec<usize>, Vec<u32>) {
+    let mut offsets = vec![0usize; num_keys + 1];
+    for &(key, _) in pairs {
+        offsets[key as usize + 1] += 1;
+    }
+    for i in 0..num_keys {
+        offsets[i + 1] += offsets[i];
+    }
+    let mut cursor = offsets.clone();
+    let mut values = vec![0u32; pairs.len()];
+    for &(key, value) in pairs {
+        values[cursor[key as usize]] = value;
+        cursor[key as usize] += 1;
+    }
+    (offsets, values)
+}
@@ -5,7 +5,9 @@
 
 use crate::{
     error::Result,
-    ffi, sys,
+    ffi,
+    scene::dump::write_json_string,
+    sys,
     types::{Vector3D, ai_string_to_string},
 };
 
@@ -124,6 +126,13 @@ pub mod common_metadata {
     /// Scene metadata holding the source asset copyright statement, if available.
     /// Not all formats add this metadata.
     pub const SOURCE_COPYRIGHT: &str = "SourceAsset_Copyright";
+
+    /// Scene metadata holding the length unit relative to centimetres (FBX: 1.0 for
+    /// centimetres, 100.0 for metres), after any unit conversion done by the importer.
+    pub const UNIT_SCALE_FACTOR: &str = "UnitScaleFactor";
+
+    /// FBX scene metadata holding the unit scale factor as written in the file.
+    pub const ORIGINAL_UNIT_SCALE_FACTOR: &str = "OriginalUnitScaleFactor";
 }
 
 /// Collada-specific metadata keys
@@ -494,6 +503,113 @@ impl Metadata {
     }
 }
 
+/// Render an `aiMetadata` as compact JSON, keeping Assimp's key order.
+///
+/// Nested metadata becomes an object, except that nested metadata whose keys all repeat
+/// its own key becomes an array: that is how Assimp's glTF 2 importer stores arrays.
+/// Top-level keys listed in `skip` are left out. Returns `None` for a null pointer or
+/// when nothing is left to write.
+pub(crate) fn metadata_to_json(
+    metadata_ptr: *const sys::aiMetadata,
+    skip: &[&str],
+) -> Option<String> {
+    let entries: Vec<_> = raw_entries(metadata_ptr)
+        .into_iter()
+        .filter(|(key, _)| !skip.contains(&key.as_str()))
+        .collect();
+    if entries.is_empty() {
+        return None;
+    }
+    let mut out = String::new();
+    write_json_object(&mut out, &entries);
+    Some(out)
+}
+
+/// Keys and entries of an `aiMetadata`, in order, with the checks of [`Metadata::from_raw_sys`].
+fn raw_entries<'a>(
+    metadata_ptr: *const sys::aiMetadata,
+) -> Vec<(String, &'a sys::aiMetadataEntry)> {
+    let align = std::mem::align_of::<sys::aiMetadata>();
+    if metadata_ptr.is_null() || (metadata_ptr as usize) % align != 0 {
+        return Vec::new();
+    }
+    // SAFETY: The crate only calls this with pointers coming from an Assimp-owned scene.
+    let metadata = unsafe { &*metadata_ptr };
+    if metadata.mNumProperties == 0 || metadata.mKeys.is_null() || metadata.mValues.is_null() {
+        return Vec::new();
+    }
+    let n = metadata.mNumProperties as usize;
+    let keys = ffi::slice_from_ptr_len(metadata, metadata.mKeys, n);
+    let values = ffi::slice_from_ptr_len(metadata, metadata.mValues, n);
+    keys.iter()
+        .map(ai_string_to_string)
+        .zip(values.iter())
+        .collect()
+}
+
+fn write_json_object(out: &mut String, entries: &[(String, &sys::aiMetadataEntry)]) {
+    out.push('{');
+    for (i, (key, entry)) in entries.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_json_string(out, key);
+        out.push(':');
+        write_json_value(out, key, entry);
+    }
+    out.push('}');
+}
+
+fn write_json_value(out: &mut String, key: &str, entry: &sys::aiMetadataEntry) {
+    if entry.mType == sys::aiMetadataType::AI_AIMETADATA {
+        let entries = raw_entries(entry.mData as *const sys::aiMetadata);
+        if !entries.is_empty() && entries.iter().all(|(item, _)| item == key) {
+            out.push('[');
+            for (i, (item, entry)) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json_value(out, item, entry);
+            }
+            out.push(']');
+        } else {
+            write_json_object(out, &entries);
+        }
+        return;
+    }
+    // SAFETY: As for `raw_entries`, the entry comes from an Assimp-owned scene.
+    match unsafe { Metadata::parse_metadata_entry(entry) } {
+        Ok(MetadataEntry::Bool(b)) => out.push_str(if b { "true" } else { "false" }),
+        Ok(MetadataEntry::Int32(v)) => out.push_str(&v.to_string()),
+        Ok(MetadataEntry::UInt32(v)) => out.push_str(&v.to_string()),
+        Ok(MetadataEntry::Int64(v)) => out.push_str(&v.to_string()),
+        Ok(MetadataEntry::UInt64(v)) => out.push_str(&v.to_string()),
+        Ok(MetadataEntry::Float(v)) => write_json_number(out, v.is_finite(), v),
+        Ok(MetadataEntry::Double(v)) => write_json_number(out, v.is_finite(), v),
+        Ok(MetadataEntry::String(s)) => write_json_string(out, &s),
+        Ok(MetadataEntry::Vector3D(v)) => {
+            out.push('[');
+            for (i, c) in [v.x, v.y, v.z].into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json_number(out, c.is_finite(), c);
+            }
+            out.push(']');
+        }
+        Ok(MetadataEntry::Metadata(_)) | Err(_) => out.push_str("null"),
+    }
+}
+
+/// Shortest round-trip form; JSON has no NaN or infinities, so those become `null`.
+fn write_json_number(out: &mut String, finite: bool, value: impl std::fmt::Display) {
+    if finite {
+        out.push_str(&value.to_string());
+    } else {
+        out.push_str("null");
+    }
+}
+
 impl Default for Metadata {
     fn default() -> Self {
         Self::new()
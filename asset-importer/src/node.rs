@@ -3,12 +3,29 @@
 use crate::{
     error::Result,
     ffi,
-    metadata::Metadata,
+    metadata::{Metadata, MetadataEntry},
     ptr::SharedPtr,
     scene::Scene,
     sys,
-    types::{Matrix4x4, ai_string_to_str, ai_string_to_string, from_ai_matrix4x4},
+    types::{
+        Matrix4x4, Name, Quaternion, Vector3D, ai_string_bytes, ai_string_to_name,
+        ai_string_to_str, ai_string_to_string, from_ai_matrix4x4,
+    },
 };
+use std::collections::VecDeque;
+
+/// Marker in the names of the helper nodes Assimp's FBX importer creates for pivots
+/// and offsets when `FBX_PRESERVE_PIVOTS` is enabled (the default), e.g.
+/// `Arm_$AssimpFbx$_RotationPivot`.
+pub const FBX_PIVOT_MARKER: &str = "_$AssimpFbx$";
+
+/// Name of the node a pivot helper belongs to, or `None` for ordinary nodes.
+fn pivot_base_name(name: &[u8]) -> Option<&[u8]> {
+    let marker = FBX_PIVOT_MARKER.as_bytes();
+    name.windows(marker.len())
+        .position(|window| window == marker)
+        .map(|index| &name[..index])
+}
 
 /// A node in the scene hierarchy
 #[derive(Clone)]
@@ -44,50 +61,156 @@ impl Node {
         ai_string_to_string(&self.raw().mName)
     }
 
+    /// Get the name of the node as an inline [`Name`], allocating
+    /// only for names longer than 64 bytes.
+    pub fn name_owned(&self) -> Name {
+        ai_string_to_name(&self.raw().mName)
+    }
+
     /// Get the name of the node (zero-copy, lossy UTF-8).
     pub fn name_str(&self) -> std::borrow::Cow<'_, str> {
         ai_string_to_str(&self.raw().mName)
     }
 
+    /// Bytes of the name up to the first NUL, for binary-safe comparison.
+    pub fn name_bytes(&self) -> &[u8] {
+        ai_string_bytes(&self.raw().mName)
+    }
+
     /// Get the transformation matrix of the node
     pub fn transformation(&self) -> Matrix4x4 {
         from_ai_matrix4x4(self.raw().mTransformation)
     }
 
+    /// Split the local transformation into `(translation, rotation, scaling)`
+    /// using `aiDecomposeMatrix`.
+    pub fn decomposed_transform(&self) -> (Vector3D, Quaternion, Vector3D) {
+        crate::math::decompose_matrix(self.transformation())
+    }
+
+    /// Whether this is one of the pivot helper nodes of an FBX import, i.e. its name
+    /// contains [`FBX_PIVOT_MARKER`].
+    pub fn is_fbx_pivot_helper(&self) -> bool {
+        pivot_base_name(ai_string_bytes(&self.raw().mName)).is_some()
+    }
+
+    /// Fold the FBX pivot helper chain this node belongs to into one transform.
+    ///
+    /// Works from any node of the chain, helper or real node: returns the logical node
+    /// name (without the `$AssimpFbx$` suffix) and the product of the transforms from
+    /// the first helper down to and including the real node. This equals the node
+    /// transform of an import with `FBX_PRESERVE_PIVOTS` disabled. Geometric helpers
+    /// below the real node are not included. For nodes outside a chain this is the
+    /// node name and [`Node::transformation`].
+    pub fn collapse_pivot_chain(&self) -> (String, Matrix4x4) {
+        let own_name = ai_string_bytes(&self.raw().mName);
+        let base = pivot_base_name(own_name).unwrap_or(own_name);
+
+        let mut node = self.clone();
+        while let Some(parent) = node.parent() {
+            if pivot_base_name(ai_string_bytes(&parent.raw().mName)) != Some(base) {
+                break;
+            }
+            node = parent;
+        }
+
+        // Each helper has the next chain element as its only child; the chain ends at
+        // the real node, whose name is the base name itself.
+        let mut transform = Matrix4x4::IDENTITY;
+        loop {
+            transform = transform * node.transformation();
+            if pivot_base_name(ai_string_bytes(&node.raw().mName)) != Some(base) {
+                break;
+            }
+            let child = {
+                let mut children = child_ptrs(node.raw());
+                match (children.next(), children.next()) {
+                    (Some(child), None) => child,
+                    _ => break,
+                }
+            };
+            node = Node {
+                scene: self.scene.clone(),
+                node_ptr: child,
+            };
+        }
+        (String::from_utf8_lossy(base).into_owned(), transform)
+    }
+
     /// Get the parent node
     pub fn parent(&self) -> Option<Node> {
         let node = self.raw();
         Node::from_sys_ptr(self.scene.clone(), node.mParent)
     }
 
+    /// Child indices leading from the root node to this node; empty for the root.
+    ///
+    /// Each index is this node's [`Node::index_in_parent`], so the path stays valid for
+    /// [`Scene::node_by_path`] on this scene and on a re-import of the same file, where
+    /// node pointers differ.
+    pub fn path(&self) -> Vec<usize> {
+        let mut path = Vec::new();
+        let mut current = self.node_ptr;
+        while let Some(parent) = SharedPtr::new(current.as_ref().mParent as *const sys::aiNode) {
+            let Some(index) = child_position(parent.as_ref(), current) else {
+                // Parent link without a matching child entry; stop at the broken link.
+                break;
+            };
+            path.push(index);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
     /// Get the number of child nodes
+    ///
+    /// Null entries in Assimp's child array are not counted. [`Node::child`],
+    /// [`Node::index_in_parent`] and [`Node::path`] number children the same way, so
+    /// index `i` always means the `i`-th element of [`Node::children`].
     pub fn num_children(&self) -> usize {
-        let node = self.raw();
-        if node.mChildren.is_null() {
-            0
-        } else {
-            node.mNumChildren as usize
-        }
+        child_ptrs(self.raw()).count()
     }
 
-    /// Get a child node by index
+    /// Get a child node by index, counting as [`Node::num_children`] does.
+    ///
+    /// Scans the child array up to `index`.
     pub fn child(&self, index: usize) -> Option<Node> {
-        if index >= self.num_children() {
-            return None;
-        }
+        let child = child_ptrs(self.raw()).nth(index)?;
+        Some(Node {
+            scene: self.scene.clone(),
+            node_ptr: child,
+        })
+    }
 
-        let node = self.raw();
-        let child_ptr =
-            ffi::ptr_array_get(self, node.mChildren, node.mNumChildren as usize, index)?;
-        Node::from_sys_ptr(self.scene.clone(), child_ptr)
+    /// Position of this node among its parent's children; `None` for the root.
+    ///
+    /// O(n) in the number of siblings: the parent's child array is scanned for this node.
+    pub fn index_in_parent(&self) -> Option<usize> {
+        let parent = SharedPtr::new(self.raw().mParent as *const sys::aiNode)?;
+        child_position(parent.as_ref(), self.node_ptr)
+    }
+
+    /// The other children of this node's parent, in order; empty for the root.
+    pub fn siblings(&self) -> impl Iterator<Item = Node> {
+        let own = self.node_ptr;
+        self.parent()
+            .into_iter()
+            .flat_map(|parent| parent.children())
+            .filter(move |sibling| sibling.node_ptr != own)
     }
 
     /// Get an iterator over all child nodes
     pub fn children(&self) -> NodeIterator {
+        let node = self.raw();
         NodeIterator {
             scene: self.scene.clone(),
             node_ptr: self.node_ptr,
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                node,
+                node.mChildren as *const *const sys::aiNode,
+                node.mNumChildren as usize,
+            ),
         }
     }
 
@@ -133,71 +256,214 @@ impl Node {
         self.mesh_indices_raw().iter().map(|&x| x as usize)
     }
 
-    /// Find a child node by name (recursive search)
+    /// Find this node or a descendant by name (depth-first search).
+    ///
+    /// Equivalent to [`Node::find_by_name`].
     pub fn find_node(&self, name: &str) -> Option<Node> {
-        if self.name_str().as_ref() == name {
-            return Some(self.clone());
-        }
+        self.find_by_name(name)
+    }
 
-        for child in self.children() {
-            if let Some(found) = child.find_node(name) {
-                return Some(found);
+    /// Find this node or a descendant by name (depth-first, pre-order).
+    ///
+    /// Names are compared as raw bytes without allocating, and the search uses an
+    /// explicit stack so arbitrarily deep hierarchies cannot overflow the call stack.
+    pub fn find_by_name(&self, name: &str) -> Option<Node> {
+        let needle = name.as_bytes();
+        let mut stack = vec![self.node_ptr];
+        while let Some(node_ptr) = stack.pop() {
+            let node = node_ptr.as_ref();
+            if ai_string_bytes(&node.mName) == needle {
+                return Some(Node {
+                    scene: self.scene.clone(),
+                    node_ptr,
+                });
             }
+            stack.extend(child_ptrs(node).rev());
         }
-
         None
     }
+
+    /// Iterate all descendants of this node (excluding itself) in depth-first pre-order.
+    pub fn descendants(&self) -> NodeTraversal {
+        let mut traversal =
+            NodeTraversal::new(self.scene.clone(), None, TraversalOrder::DepthFirst);
+        traversal.push_children_of(self.node_ptr, 0);
+        traversal
+    }
 }
 
-/// Iterator over child nodes
-pub struct NodeIterator {
+/// Non-null children of `node`, in declaration order.
+fn child_ptrs(node: &sys::aiNode) -> impl DoubleEndedIterator<Item = SharedPtr<sys::aiNode>> + '_ {
+    let children: &[*mut sys::aiNode] = ffi::slice_from_ptr_len(
+        node,
+        node.mChildren as *const *mut sys::aiNode,
+        node.mNumChildren as usize,
+    );
+    children
+        .iter()
+        .filter_map(|&child| SharedPtr::new(child as *const sys::aiNode))
+}
+
+/// Index of `child` among the non-null children of `parent`.
+fn child_position(parent: &sys::aiNode, child: SharedPtr<sys::aiNode>) -> Option<usize> {
+    child_ptrs(parent).position(|ptr| ptr == child)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TraversalOrder {
+    DepthFirst,
+    BreadthFirst,
+}
+
+/// Iterator over a node subtree in depth-first or breadth-first order.
+///
+/// Created by [`Scene::nodes_depth_first`], [`Scene::nodes_breadth_first`] and
+/// [`Node::descendants`]. Uses an explicit stack/queue, and null child pointers
+/// are skipped.
+pub struct NodeTraversal {
     scene: Scene,
-    node_ptr: SharedPtr<sys::aiNode>,
-    index: usize,
+    pending: VecDeque<(SharedPtr<sys::aiNode>, usize)>,
+    order: TraversalOrder,
 }
 
-impl NodeIterator {
-    #[inline]
-    fn node_ptr(&self) -> SharedPtr<sys::aiNode> {
-        self.node_ptr
+impl NodeTraversal {
+    fn new(scene: Scene, root: Option<SharedPtr<sys::aiNode>>, order: TraversalOrder) -> Self {
+        let mut pending = VecDeque::new();
+        if let Some(root) = root {
+            pending.push_back((root, 0));
+        }
+        Self {
+            scene,
+            pending,
+            order,
+        }
+    }
+
+    pub(crate) fn depth_first(scene: Scene, root: *mut sys::aiNode) -> Self {
+        let root = SharedPtr::new(root as *const sys::aiNode);
+        Self::new(scene, root, TraversalOrder::DepthFirst)
+    }
+
+    pub(crate) fn breadth_first(scene: Scene, root: *mut sys::aiNode) -> Self {
+        let root = SharedPtr::new(root as *const sys::aiNode);
+        Self::new(scene, root, TraversalOrder::BreadthFirst)
+    }
+
+    fn push_children_of(&mut self, node_ptr: SharedPtr<sys::aiNode>, depth: usize) {
+        let children = child_ptrs(node_ptr.as_ref()).map(|child| (child, depth + 1));
+        match self.order {
+            // Reversed so popping from the back visits children left to right.
+            TraversalOrder::DepthFirst => self.pending.extend(children.rev()),
+            TraversalOrder::BreadthFirst => self.pending.extend(children),
+        }
+    }
+
+    fn next_entry(&mut self) -> Option<(SharedPtr<sys::aiNode>, usize)> {
+        let (node_ptr, depth) = match self.order {
+            TraversalOrder::DepthFirst => self.pending.pop_back()?,
+            TraversalOrder::BreadthFirst => self.pending.pop_front()?,
+        };
+        self.push_children_of(node_ptr, depth);
+        Some((node_ptr, depth))
+    }
+
+    /// Yield `(node, depth)` pairs instead of bare nodes.
+    ///
+    /// Depth is measured from the traversal root: the scene root node has depth `0`,
+    /// and the direct children of the node passed to [`Node::descendants`] have depth `1`.
+    pub fn with_depth(self) -> NodeTraversalWithDepth {
+        NodeTraversalWithDepth { inner: self }
     }
 }
 
-impl Iterator for NodeIterator {
+impl Iterator for NodeTraversal {
     type Item = Node;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let node_ptr = self.node_ptr();
+        let (node_ptr, _) = self.next_entry()?;
+        Some(Node {
+            scene: self.scene.clone(),
+            node_ptr,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pending.len(), None)
+    }
+}
+
+/// Iterator adapter yielding `(Node, depth)` pairs; see [`NodeTraversal::with_depth`].
+pub struct NodeTraversalWithDepth {
+    inner: NodeTraversal,
+}
+
+impl Iterator for NodeTraversalWithDepth {
+    type Item = (Node, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node_ptr, depth) = self.inner.next_entry()?;
+        let node = Node {
+            scene: self.inner.scene.clone(),
+            node_ptr,
+        };
+        Some((node, depth))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Iterator over child nodes
+pub struct NodeIterator {
+    scene: Scene,
+    node_ptr: SharedPtr<sys::aiNode>,
+    cursor: ffi::PtrArrayCursor,
+}
+
+impl NodeIterator {
+    fn child(scene: &Scene, node_ptr: SharedPtr<sys::aiNode>, index: usize) -> Option<Node> {
         let node = node_ptr.as_ref();
-        let children: &[*mut sys::aiNode] = ffi::slice_from_ptr_len_opt(
+        let child_ptr = ffi::ptr_array_get(
             node,
             node.mChildren as *const *mut sys::aiNode,
             node.mNumChildren as usize,
+            index,
         )?;
-        while self.index < children.len() {
-            let index = self.index;
-            self.index = index + 1;
-            let child_ptr = children[index];
-            if child_ptr.is_null() {
-                continue;
-            }
-            return Node::from_sys_ptr(self.scene.clone(), child_ptr);
-        }
-        None
+        Node::from_sys_ptr(scene.clone(), child_ptr)
+    }
+}
+
+impl Iterator for NodeIterator {
+    type Item = Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (scene, node_ptr) = (&self.scene, self.node_ptr);
+        self.cursor
+            .next(|index| Self::child(scene, node_ptr, index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let node_ptr = self.node_ptr();
-        let node = node_ptr.as_ref();
-        if node.mChildren.is_null() {
-            (0, Some(0))
-        } else {
-            let remaining = (node.mNumChildren as usize).saturating_sub(self.index);
-            (0, Some(remaining))
-        }
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, node_ptr) = (&self.scene, self.node_ptr);
+        self.cursor
+            .nth(n, |index| Self::child(scene, node_ptr, index))
+    }
+}
+
+impl DoubleEndedIterator for NodeIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, node_ptr) = (&self.scene, self.node_ptr);
+        self.cursor
+            .next_back(|index| Self::child(scene, node_ptr, index))
     }
 }
 
+impl ExactSizeIterator for NodeIterator {}
+
 /// Iterator over mesh indices in a node
 pub struct MeshIndexIterator {
     #[allow(dead_code)]
@@ -248,7 +514,64 @@ impl ExactSizeIterator for MeshIndexIterator {}
 
 impl Node {
     /// Get node metadata
+    ///
+    /// Nodes without metadata (`mMetaData` is null) yield an empty [`Metadata`].
     pub fn metadata(&self) -> Result<Metadata> {
         Metadata::from_sys_ptr(self.raw().mMetaData)
     }
+
+    /// A glTF extension object on this node, such as `"KHR_lights_punctual"`.
+    ///
+    /// Assimp's glTF 2 importer copies node `extensions` into the node metadata as
+    /// nested entries; this returns the entry for `name`, or `None` when the node has
+    /// no such extension or the metadata cannot be read.
+    pub fn gltf_extension(&self, name: &str) -> Option<Metadata> {
+        match self.metadata().ok()?.remove(name)? {
+            MetadataEntry::Metadata(extension) => Some(extension),
+            _ => None,
+        }
+    }
+
+    /// The glTF `extras` of this node as a compact JSON object string.
+    ///
+    /// Assimp's glTF 2 importer (5.1 and later, including the bundled 6.0.5) unpacks
+    /// node extras into the node metadata: each member becomes a top-level entry, objects
+    /// become nested metadata and arrays become nested metadata with the array's name
+    /// repeated as every key. This reassembles that into JSON in the original member
+    /// order, leaving out the importer's own `extensions` and `PBR_LightRange` entries.
+    /// Integers come back as integers and all other numbers as doubles; an empty array
+    /// comes back as `{}`.
+    ///
+    /// Returns `None` for scenes not imported from glTF, nodes without extras and older
+    /// Assimp versions that do not keep them.
+    pub fn extras_json(&self) -> Option<String> {
+        let importer = self
+            .scene
+            .source_format()?
+            .importer
+            .name
+            .to_ascii_lowercase();
+        if !importer.contains("gltf") {
+            return None;
+        }
+        crate::metadata::metadata_to_json(self.raw().mMetaData, &["extensions", "PBR_LightRange"])
+    }
+
+    /// [`Node::extras_json`] parsed (requires the `assjson` feature).
+    #[cfg(feature = "assjson")]
+    pub fn extras_value(&self) -> Option<serde_json::Value> {
+        serde_json::from_str(&self.extras_json()?).ok()
+    }
+
+    /// Index of the light referenced by this node's `KHR_lights_punctual` extension.
+    pub fn gltf_light_index(&self) -> Option<usize> {
+        let extension = self.gltf_extension("KHR_lights_punctual")?;
+        match *extension.get("light")? {
+            MetadataEntry::Int32(v) => usize::try_from(v).ok(),
+            MetadataEntry::UInt32(v) => usize::try_from(v).ok(),
+            MetadataEntry::Int64(v) => usize::try_from(v).ok(),
+            MetadataEntry::UInt64(v) => usize::try_from(v).ok(),
+            _ => None,
+        }
+    }
 }
@@ -1,8 +1,21 @@
 //! Post-processing steps for imported scenes
+//!
+//! [`PostProcessSteps`] prints as Assimp's own step names and parses from them or from
+//! the Rust constant names, which suits config files and command-line flags:
+//!
+//! ```
+//! use asset_importer::postprocess::PostProcessSteps;
+//!
+//! let steps: PostProcessSteps = "triangulate | aiProcess_FlipUVs".parse().unwrap();
+//! assert_eq!(steps, PostProcessSteps::TRIANGULATE | PostProcessSteps::FLIP_UVS);
+//! assert_eq!(steps.to_string(), "aiProcess_Triangulate | aiProcess_FlipUVs");
+//! ```
+//!
+//! `PostProcessSteps::all().iter_names()` lists every step under its Rust name.
 
 #![allow(clippy::unnecessary_cast)]
 
-use crate::sys;
+use crate::{error::Error, sys};
 use bitflags::bitflags;
 
 bitflags! {
@@ -114,8 +127,28 @@ impl PostProcessSteps {
     }
 
     /// Create from raw value
+    ///
+    /// Every bit is kept (as with `from_bits_retain`), so the value survives a round
+    /// trip through [`PostProcessSteps::as_raw`]. All 32 bits currently name a step;
+    /// this keeps a bit that a future Assimp reassigns from being dropped.
     pub fn from_raw(value: u32) -> Self {
-        Self::from_bits_truncate(value)
+        Self::from_bits_retain(value)
+    }
+
+    /// Assimp's name (such as `"aiProcess_Triangulate"`) of a single step.
+    pub fn assimp_name(self) -> Option<&'static str> {
+        let bits = self.bits();
+        (bits.count_ones() == 1).then(|| ASSIMP_NAMES[bits.trailing_zeros() as usize])
+    }
+
+    /// Iterate the steps in `self` as `(Assimp name, step)`, in bit order.
+    ///
+    /// The Assimp-name counterpart of `iter_names`, which yields the Rust names.
+    pub fn iter_assimp_names(&self) -> impl Iterator<Item = (&'static str, Self)> + '_ {
+        let bits = self.bits();
+        (0..32)
+            .filter(move |bit| bits & (1 << bit) != 0)
+            .map(|bit| (ASSIMP_NAMES[bit], Self::from_bits_retain(1 << bit)))
     }
 
     /// Validate that the post-processing flags are compatible
@@ -145,6 +178,99 @@ impl PostProcessSteps {
     }
 }
 
+/// Assimp's name of each step, indexed by bit.
+const ASSIMP_NAMES: [&str; 32] = [
+    "aiProcess_CalcTangentSpace",
+    "aiProcess_JoinIdenticalVertices",
+    "aiProcess_MakeLeftHanded",
+    "aiProcess_Triangulate",
+    "aiProcess_RemoveComponent",
+    "aiProcess_GenNormals",
+    "aiProcess_GenSmoothNormals",
+    "aiProcess_SplitLargeMeshes",
+    "aiProcess_PreTransformVertices",
+    "aiProcess_LimitBoneWeights",
+    "aiProcess_ValidateDataStructure",
+    "aiProcess_ImproveCacheLocality",
+    "aiProcess_RemoveRedundantMaterials",
+    "aiProcess_FixInfacingNormals",
+    "aiProcess_PopulateArmatureData",
+    "aiProcess_SortByPType",
+    "aiProcess_FindDegenerates",
+    "aiProcess_FindInvalidData",
+    "aiProcess_GenUVCoords",
+    "aiProcess_TransformUVCoords",
+    "aiProcess_FindInstances",
+    "aiProcess_OptimizeMeshes",
+    "aiProcess_OptimizeGraph",
+    "aiProcess_FlipUVs",
+    "aiProcess_FlipWindingOrder",
+    "aiProcess_SplitByBoneCount",
+    "aiProcess_Debone",
+    "aiProcess_GlobalScale",
+    "aiProcess_EmbedTextures",
+    "aiProcess_ForceGenNormals",
+    "aiProcess_DropNormals",
+    "aiProcess_GenBoundingBoxes",
+];
+
+/// Pipe-separated Assimp step names (`aiProcess_Triangulate | aiProcess_FlipUVs`), in bit
+/// order; no steps print as `0`.
+impl std::fmt::Display for PostProcessSteps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("0");
+        }
+        for (i, (name, _)) in self.iter_assimp_names().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses `|`- or `,`-separated steps, each an Assimp name (`aiProcess_FlipUVs`) or a
+/// Rust constant name (`FLIP_UVS`), compared case-insensitively. Numbers (`0x800000`,
+/// `0`) are taken as raw bits. An empty string is no steps.
+impl std::str::FromStr for PostProcessSteps {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut steps = Self::empty();
+        for token in s.split(['|', ',']).map(str::trim).filter(|t| !t.is_empty()) {
+            steps |= parse_step(token).ok_or_else(|| {
+                Error::invalid_parameter(format!("Unknown post-processing step '{}'", token))
+            })?;
+        }
+        Ok(steps)
+    }
+}
+
+fn parse_step(token: &str) -> Option<PostProcessSteps> {
+    if let Some(bit) = ASSIMP_NAMES
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(token))
+    {
+        return Some(PostProcessSteps::from_bits_retain(1 << bit));
+    }
+    if let Some((_, step)) = PostProcessSteps::all()
+        .iter_names()
+        .find(|(name, _)| name.eq_ignore_ascii_case(token))
+    {
+        return Some(step);
+    }
+    let bits = match token
+        .strip_prefix("0x")
+        .or_else(|| token.strip_prefix("0X"))
+    {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => token.parse().ok()?,
+    };
+    Some(PostProcessSteps::from_raw(bits))
+}
+
 impl Default for PostProcessSteps {
     fn default() -> Self {
         // Common default post-processing steps
@@ -152,6 +278,69 @@ impl Default for PostProcessSteps {
     }
 }
 
+bitflags! {
+    /// Scene components removed by [`PostProcessSteps::REMOVE_COMPONENT`] (`aiComponent`).
+    ///
+    /// Set them with [`ImportBuilder::remove_components`](crate::ImportBuilder::remove_components),
+    /// which also enables the step. Use [`Components::colors_channel`] and
+    /// [`Components::texcoords_channel`] to remove single channels.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Components: u32 {
+        /// Normal vectors
+        const NORMALS = sys::aiComponent::aiComponent_NORMALS as u32;
+        /// Tangents and bitangents
+        const TANGENTS_AND_BITANGENTS = sys::aiComponent::aiComponent_TANGENTS_AND_BITANGENTS as u32;
+        /// All vertex color channels
+        const COLORS = sys::aiComponent::aiComponent_COLORS as u32;
+        /// All texture coordinate channels
+        const TEXCOORDS = sys::aiComponent::aiComponent_TEXCOORDS as u32;
+        /// Bone weights (and the bones themselves)
+        const BONEWEIGHTS = sys::aiComponent::aiComponent_BONEWEIGHTS as u32;
+        /// Node animations (meshes stay in their bind pose)
+        const ANIMATIONS = sys::aiComponent::aiComponent_ANIMATIONS as u32;
+        /// Embedded textures
+        const TEXTURES = sys::aiComponent::aiComponent_TEXTURES as u32;
+        /// Light sources
+        const LIGHTS = sys::aiComponent::aiComponent_LIGHTS as u32;
+        /// Cameras
+        const CAMERAS = sys::aiComponent::aiComponent_CAMERAS as u32;
+        /// All meshes
+        const MESHES = sys::aiComponent::aiComponent_MESHES as u32;
+        /// All materials except one default material
+        const MATERIALS = sys::aiComponent::aiComponent_MATERIALS as u32;
+
+        // Per-channel bits (`aiComponent_COLORSn` / `aiComponent_TEXCOORDSn`).
+        const _ = !0;
+    }
+}
+
+impl Components {
+    /// Vertex color channel `n` (`aiComponent_COLORSn(n)`), for `n < 8`.
+    ///
+    /// Assimp packs these bits above [`Components::MATERIALS`]; color channels 5 to 7
+    /// share their bits with texture coordinate channels 0 to 2, so removing one removes
+    /// the other.
+    pub const fn colors_channel(n: u32) -> Option<Self> {
+        if n < 8 {
+            Some(Self::from_bits_retain(1 << (n + 20)))
+        } else {
+            None
+        }
+    }
+
+    /// Texture coordinate channel `n` (`aiComponent_TEXCOORDSn(n)`), for `n < 7`.
+    ///
+    /// Channel 7 has no bit in the 32-bit flag word. See
+    /// [`colors_channel`](Self::colors_channel) for the bits shared with color channels.
+    pub const fn texcoords_channel(n: u32) -> Option<Self> {
+        if n < 7 {
+            Some(Self::from_bits_retain(1 << (n + 25)))
+        } else {
+            None
+        }
+    }
+}
+
 /// Preset combinations of post-processing steps for common use cases
 impl PostProcessSteps {
     /// Fast preset with basic optimizations
@@ -229,6 +418,19 @@ mod tests {
         assert!(max_quality.contains(PostProcessSteps::CALC_TANGENT_SPACE));
     }
 
+    #[test]
+    fn test_component_channel_bits() {
+        assert_eq!(Components::NORMALS.bits(), 2);
+        assert_eq!(Components::colors_channel(0).unwrap().bits(), 1 << 20);
+        assert_eq!(Components::texcoords_channel(1).unwrap().bits(), 1 << 26);
+        assert_eq!(Components::colors_channel(8), None);
+        assert_eq!(Components::texcoords_channel(7), None);
+
+        let both = Components::NORMALS | Components::texcoords_channel(0).unwrap();
+        assert_eq!(both.bits(), 2 | (1 << 25));
+        assert!(both.contains(Components::NORMALS));
+    }
+
     #[test]
     fn test_raw_conversion() {
         let steps = PostProcessSteps::TRIANGULATE;
@@ -237,6 +439,63 @@ mod tests {
         assert_eq!(steps, converted_back);
     }
 
+    #[test]
+    fn test_raw_round_trip_keeps_every_bit() {
+        for raw in [
+            0,
+            u32::MAX,
+            1 << 31,
+            PostProcessSteps::TRIANGULATE.bits() | (1 << 31),
+        ] {
+            assert_eq!(PostProcessSteps::from_raw(raw).as_raw(), raw);
+        }
+        assert_eq!(
+            PostProcessSteps::from_raw(u32::MAX),
+            PostProcessSteps::all()
+        );
+    }
+
+    #[test]
+    fn test_display_from_str_round_trip() {
+        for (rust_name, step) in PostProcessSteps::all().iter_names() {
+            let assimp_name = step.assimp_name().expect("every step has an Assimp name");
+            assert_eq!(step.to_string(), assimp_name);
+            assert_eq!(assimp_name.parse::<PostProcessSteps>().unwrap(), step);
+            assert_eq!(rust_name.parse::<PostProcessSteps>().unwrap(), step);
+            let lower = rust_name.to_ascii_lowercase();
+            assert_eq!(lower.parse::<PostProcessSteps>().unwrap(), step);
+        }
+
+        let steps = PostProcessSteps::TRIANGULATE | PostProcessSteps::FLIP_UVS;
+        assert_eq!(
+            steps.to_string(),
+            "aiProcess_Triangulate | aiProcess_FlipUVs"
+        );
+        for steps in [
+            steps,
+            PostProcessSteps::MAX_QUALITY,
+            PostProcessSteps::empty(),
+            PostProcessSteps::all(),
+        ] {
+            assert_eq!(
+                steps.to_string().parse::<PostProcessSteps>().unwrap(),
+                steps
+            );
+        }
+        assert_eq!(PostProcessSteps::empty().to_string(), "0");
+        assert_eq!(
+            "FLIP_UVS, aiprocess_triangulate"
+                .parse::<PostProcessSteps>()
+                .unwrap(),
+            steps
+        );
+        assert_eq!(
+            "0x8".parse::<PostProcessSteps>().unwrap(),
+            PostProcessSteps::TRIANGULATE
+        );
+        assert!("aiProcess_Bogus".parse::<PostProcessSteps>().is_err());
+    }
+
     #[test]
     fn test_flag_validation() {
         // Valid combinations
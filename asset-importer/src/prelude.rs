@@ -0,0 +1,78 @@
+//! The types most programs need, in one import.
+//!
+//! ```rust
+//! use asset_importer::prelude::*;
+//! ```
+//!
+//! The prelude covers the read path (importing, post-processing and walking a scene),
+//! the common math types and, with the `export` feature, the export builder. Everything
+//! else stays in its module, e.g. [`crate::material::TextureInfo`] or
+//! [`crate::mesh::topology`].
+//!
+//! ## Import and post-process
+//!
+//! ```rust,no_run
+//! use asset_importer::prelude::*;
+//!
+//! fn load(path: &str) -> Result<Scene> {
+//!     Importer::new()
+//!         .read_file(path)
+//!         .with_post_process(PostProcessSteps::TRIANGULATE | PostProcessSteps::GEN_NORMALS)
+//!         .import()
+//! }
+//! ```
+//!
+//! ## Inspect meshes, materials and nodes
+//!
+//! ```rust,no_run
+//! use asset_importer::prelude::*;
+//!
+//! fn summarize(scene: &Scene) {
+//!     for mesh in scene.meshes() {
+//!         let positions: Vec<Vector3D> = mesh.vertices();
+//!         println!("{}: {} vertices", mesh.name(), positions.len());
+//!     }
+//!     for material in scene.materials() {
+//!         let maps = material.texture_count(TextureType::Diffuse);
+//!         println!("{}: {maps} diffuse maps", material.name());
+//!     }
+//!     if let Some(root) = scene.root_node() {
+//!         for node in root.descendants() {
+//!             let transform: Matrix4x4 = node.transformation();
+//!             println!("{} at {:?}", node.name(), transform.w_axis);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Convert to another format
+//!
+//! ```rust,no_run
+//! use asset_importer::prelude::*;
+//!
+//! # #[cfg(feature = "export")]
+//! fn to_glb(input: &str, output: &str) -> Result<()> {
+//!     let scene = Importer::new().import_file(input)?;
+//!     ExportBuilder::new("glb2").export_to_file(&scene, output)
+//! }
+//! ```
+
+pub use crate::{
+    animation::Animation,
+    bone::Bone,
+    camera::Camera,
+    error::{Error, Result},
+    importer::{ImportBuilder, Importer, PropertyStore},
+    light::Light,
+    material::{Material, TextureType},
+    mesh::Mesh,
+    node::{Node, NodeIterator},
+    postprocess::PostProcessSteps,
+    preset::ImportPreset,
+    scene::{AnimationIterator, MaterialIterator, MeshIterator, OwnedScene, Scene},
+    texture::{Texture, TextureIterator},
+    types::{Color4D, Matrix4x4, Quaternion, Vector2D, Vector3D},
+};
+
+#[cfg(feature = "export")]
+pub use crate::exporter::{ExportBlob, ExportBuilder};
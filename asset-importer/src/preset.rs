@@ -0,0 +1,215 @@
+//! Import presets: documented bundles of post-processing steps and properties.
+//!
+//! A preset is plain data. [`ImportPreset::settings`] returns the steps and the
+//! [`PropertyStore`] it applies, so they can be inspected or tweaked and passed to the
+//! builder by hand instead of through [`ImportBuilder::with_preset`](crate::ImportBuilder::with_preset).
+//!
+//! ```rust,no_run
+//! use asset_importer::{Importer, preset::ImportPreset, postprocess::PostProcessSteps};
+//!
+//! for line in ImportPreset::CadArchitecture.describe() {
+//!     println!("{line}");
+//! }
+//!
+//! let scene = Importer::new()
+//!     .read_file("building.ifc")
+//!     .with_preset(ImportPreset::CadArchitecture)
+//!     .add_post_process(PostProcessSteps::GEN_BOUNDING_BOXES)
+//!     .import()?;
+//! # Ok::<(), asset_importer::Error>(())
+//! ```
+
+use crate::{
+    config,
+    importer::{PropertyStore, PropertyValue},
+    postprocess::PostProcessSteps,
+};
+
+/// A named bundle of post-processing steps and import properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportPreset {
+    /// Architectural and CAD models (IFC and similar).
+    ///
+    /// Triangulates, welds vertices and generates smooth normals with a tight 30° angle
+    /// so hard edges stay hard. No tangents, UV flipping or graph optimisation: the
+    /// node hierarchy (storeys, elements) is kept as authored. Sets
+    /// [`IFC_SKIP_SPACE_REPRESENTATIONS`](config::IFC_SKIP_SPACE_REPRESENTATIONS),
+    /// [`IFC_CUSTOM_TRIANGULATION`](config::IFC_CUSTOM_TRIANGULATION), a 10° IFC
+    /// smoothing angle, 32-segment cylinder tessellation, and removes degenerate faces.
+    CadArchitecture,
+    /// Game-ready assets rendered with a PBR shader.
+    ///
+    /// Triangulated, welded, with smooth normals, tangents, generated UVs, at most
+    /// four bone weights per vertex, optimised meshes and cache-friendly index order.
+    /// Removes degenerate faces and redundant materials and validates the result.
+    GameAssetPbr,
+    /// Point clouds (PLY, XYZ-like OBJ and similar) whose vertices must survive as-is.
+    ///
+    /// Avoids every step that merges, removes or invents vertices (no welding,
+    /// triangulation or normal generation); only sorts primitives by type, drops
+    /// invalid attribute data and computes bounding boxes. Keeps point primitives
+    /// when sorting and disables degenerate-face removal.
+    PointCloudPreserve,
+}
+
+impl ImportPreset {
+    /// All presets, for UI pickers.
+    pub const ALL: [Self; 3] = [
+        Self::CadArchitecture,
+        Self::GameAssetPbr,
+        Self::PointCloudPreserve,
+    ];
+
+    /// Short display name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::CadArchitecture => "CAD / architecture",
+            Self::GameAssetPbr => "Game asset (PBR)",
+            Self::PointCloudPreserve => "Point cloud (preserve)",
+        }
+    }
+
+    /// Post-processing steps of this preset.
+    pub fn post_process(self) -> PostProcessSteps {
+        match self {
+            Self::CadArchitecture => {
+                PostProcessSteps::TRIANGULATE
+                    | PostProcessSteps::JOIN_IDENTICAL_VERTICES
+                    | PostProcessSteps::GEN_SMOOTH_NORMALS
+                    | PostProcessSteps::SORT_BY_PTYPE
+                    | PostProcessSteps::FIND_DEGENERATES
+                    | PostProcessSteps::FIND_INVALID_DATA
+                    | PostProcessSteps::REMOVE_REDUNDANT_MATERIALS
+            }
+            Self::GameAssetPbr => {
+                PostProcessSteps::TRIANGULATE
+                    | PostProcessSteps::JOIN_IDENTICAL_VERTICES
+                    | PostProcessSteps::GEN_SMOOTH_NORMALS
+                    | PostProcessSteps::CALC_TANGENT_SPACE
+                    | PostProcessSteps::GEN_UV_COORDS
+                    | PostProcessSteps::LIMIT_BONE_WEIGHTS
+                    | PostProcessSteps::IMPROVE_CACHE_LOCALITY
+                    | PostProcessSteps::OPTIMIZE_MESHES
+                    | PostProcessSteps::SORT_BY_PTYPE
+                    | PostProcessSteps::FIND_DEGENERATES
+                    | PostProcessSteps::FIND_INVALID_DATA
+                    | PostProcessSteps::REMOVE_REDUNDANT_MATERIALS
+                    | PostProcessSteps::VALIDATE_DATA_STRUCTURE
+            }
+            Self::PointCloudPreserve => {
+                PostProcessSteps::SORT_BY_PTYPE
+                    | PostProcessSteps::FIND_INVALID_DATA
+                    | PostProcessSteps::GEN_BOUNDING_BOXES
+            }
+        }
+    }
+
+    /// Import properties of this preset.
+    pub fn properties(self) -> PropertyStore {
+        let mut store = PropertyStore::new();
+        match self {
+            Self::CadArchitecture => {
+                store
+                    .set_bool(config::IFC_SKIP_SPACE_REPRESENTATIONS.name(), true)
+                    .set_bool(config::IFC_CUSTOM_TRIANGULATION.name(), true)
+                    .set_float(config::IFC_SMOOTHING_ANGLE.name(), 10.0)
+                    .set_int(config::IFC_CYLINDRICAL_TESSELLATION.name(), 32)
+                    .set_float(config::PP_GSN_MAX_SMOOTHING_ANGLE.name(), 30.0)
+                    .set_bool(config::PP_FD_REMOVE.name(), true);
+            }
+            Self::GameAssetPbr => {
+                store
+                    .set_int(config::PP_LBW_MAX_WEIGHTS.name(), 4)
+                    .set_float(config::PP_GSN_MAX_SMOOTHING_ANGLE.name(), 66.0)
+                    .set_bool(config::PP_FD_REMOVE.name(), true);
+            }
+            Self::PointCloudPreserve => {
+                store
+                    .set_int(config::PP_SBP_REMOVE.name(), 0)
+                    .set_bool(config::PP_FD_REMOVE.name(), false);
+            }
+        }
+        store
+    }
+
+    /// Steps and properties together; equivalent to calling
+    /// [`post_process`](Self::post_process) and [`properties`](Self::properties).
+    pub fn settings(self) -> (PostProcessSteps, PropertyStore) {
+        (self.post_process(), self.properties())
+    }
+
+    /// One human-readable line per step and property the preset sets, for UI display.
+    ///
+    /// Steps come first (`"post-process: TRIANGULATE"`), then properties
+    /// (`"IMPORT_IFC_SKIP_SPACE_REPRESENTATIONS = true"`).
+    pub fn describe(self) -> Vec<String> {
+        let (steps, properties) = self.settings();
+        let steps = steps
+            .iter_names()
+            .map(|(name, _)| format!("post-process: {}", name));
+        let properties = properties
+            .properties()
+            .iter()
+            .map(|(name, value)| format!("{} = {}", name, describe_value(value)));
+        steps.chain(properties).collect()
+    }
+}
+
+impl std::fmt::Display for ImportPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+fn describe_value(value: &PropertyValue) -> String {
+    match value {
+        PropertyValue::Integer(v) => v.to_string(),
+        PropertyValue::Float(v) => v.to_string(),
+        PropertyValue::String(v) => format!("{:?}", v),
+        PropertyValue::Boolean(v) => v.to_string(),
+        PropertyValue::Matrix(_) => "<matrix>".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cad_preset_contents() {
+        let (steps, properties) = ImportPreset::CadArchitecture.settings();
+        assert!(!steps.contains(PostProcessSteps::FLIP_UVS));
+        assert!(!steps.contains(PostProcessSteps::CALC_TANGENT_SPACE));
+        assert!(!steps.contains(PostProcessSteps::OPTIMIZE_GRAPH));
+        assert!(properties.properties().iter().any(|(name, value)| {
+            name == config::IFC_SKIP_SPACE_REPRESENTATIONS.name()
+                && matches!(value, PropertyValue::Boolean(true))
+        }));
+    }
+
+    #[test]
+    fn test_presets_are_valid() {
+        for preset in ImportPreset::ALL {
+            let steps = preset.post_process();
+            assert!(steps.is_valid(), "{preset}: {steps:?}");
+            assert!(!steps.contains(PostProcessSteps::FLIP_UVS), "{preset}");
+        }
+        let cloud = ImportPreset::PointCloudPreserve.post_process();
+        assert!(
+            !cloud.intersects(
+                PostProcessSteps::JOIN_IDENTICAL_VERTICES | PostProcessSteps::TRIANGULATE
+            )
+        );
+    }
+
+    #[test]
+    fn test_describe_lists_steps_then_properties() {
+        let lines = ImportPreset::CadArchitecture.describe();
+        let (steps, properties) = ImportPreset::CadArchitecture.settings();
+        assert_eq!(lines.len(), steps.iter().count() + properties.len());
+        assert!(lines.contains(&"post-process: TRIANGULATE".to_string()));
+        assert!(lines.contains(&"IMPORT_IFC_SKIP_SPACE_REPRESENTATIONS = true".to_string()));
+        let first_property = lines.iter().position(|l| !l.starts_with("post-process:"));
+        assert_eq!(first_property, Some(steps.iter().count()));
+    }
+}
@@ -1,4 +1,17 @@
 //! Progress reporting for import/export operations
+//!
+//! Handlers come in two flavours: [`ProgressHandler`] receives the raw percentage and
+//! message, while [`ProgressEventHandler`] receives a parsed [`ProgressEvent`]. Every
+//! `ProgressHandler` is also a `ProgressEventHandler`, so either can be passed to
+//! [`crate::importer::ImportBuilder::with_progress_event_handler`].
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+    mpsc,
+};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 /// Trait for receiving progress updates during import/export operations
 pub trait ProgressHandler: Send {
@@ -13,6 +26,339 @@ pub trait ProgressHandler: Send {
     fn update(&mut self, percentage: f32, message: Option<&str>) -> bool;
 }
 
+/// Phase of an operation a progress update belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProgressStage {
+    /// The importer is reading the source file (reported as 0.0..=0.5).
+    Reading,
+    /// Post-processing steps are running (reported as 0.5..=1.0).
+    PostProcessing,
+    /// An exporter is writing output.
+    Writing,
+    /// The update carried no stage information.
+    Unknown,
+}
+
+/// A progress update with the stage and step counters parsed out of the raw message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressEvent {
+    /// Stage of the operation.
+    pub stage: ProgressStage,
+    /// Overall progress between 0.0 and 1.0, or negative when Assimp did not report one.
+    pub percent: f32,
+    /// `(current, total)` step counters within the stage, when reported.
+    pub step: Option<(u32, u32)>,
+    /// Message as produced by the bridge, e.g. `"post 3/7"`.
+    pub raw_message: Option<String>,
+    /// Source file being imported, when importing from a path.
+    pub file: Option<String>,
+}
+
+impl ProgressEvent {
+    /// Build an event from a raw percentage and bridge message.
+    ///
+    /// Messages of the form `"read N/M"`, `"post N/M"` and `"write N/M"` are mapped to
+    /// their stage; anything else (including no message) is [`ProgressStage::Unknown`].
+    pub fn parse(percent: f32, message: Option<&str>, file: Option<&str>) -> Self {
+        let (stage, step) = message.map_or((ProgressStage::Unknown, None), parse_message);
+        Self {
+            stage,
+            percent,
+            step,
+            raw_message: message.map(str::to_owned),
+            file: file.map(str::to_owned),
+        }
+    }
+
+    /// Whether this event reports completion (`percent >= 1.0`).
+    pub fn is_complete(&self) -> bool {
+        self.percent >= 1.0
+    }
+}
+
+fn parse_message(message: &str) -> (ProgressStage, Option<(u32, u32)>) {
+    let Some((prefix, counters)) = message.split_once(' ') else {
+        return (ProgressStage::Unknown, None);
+    };
+    let stage = match prefix {
+        "read" => ProgressStage::Reading,
+        "post" => ProgressStage::PostProcessing,
+        "write" => ProgressStage::Writing,
+        _ => return (ProgressStage::Unknown, None),
+    };
+    let step = counters
+        .split_once('/')
+        .and_then(|(current, total)| Some((current.parse().ok()?, total.parse().ok()?)));
+    (stage, step)
+}
+
+/// Trait for receiving parsed progress events.
+///
+/// Implemented for every [`ProgressHandler`], which receives
+/// `(event.percent, event.raw_message)`.
+pub trait ProgressEventHandler: Send {
+    /// Called for each forwarded progress event.
+    ///
+    /// # Returns
+    /// Return `true` to continue the operation, `false` to cancel
+    fn on_progress(&mut self, event: &ProgressEvent) -> bool;
+}
+
+impl<T: ProgressHandler + ?Sized> ProgressEventHandler for T {
+    fn on_progress(&mut self, event: &ProgressEvent) -> bool {
+        self.update(event.percent, event.raw_message.as_deref())
+    }
+}
+
+impl<T: ProgressHandler + ?Sized> ProgressHandler for Box<T> {
+    fn update(&mut self, percentage: f32, message: Option<&str>) -> bool {
+        (**self).update(percentage, message)
+    }
+}
+
+/// A progress event handler that calls a closure
+pub struct ClosureProgressEventHandler<F>
+where
+    F: FnMut(&ProgressEvent) -> bool + Send,
+{
+    closure: F,
+}
+
+impl<F> ClosureProgressEventHandler<F>
+where
+    F: FnMut(&ProgressEvent) -> bool + Send,
+{
+    /// Create a new closure-based progress event handler
+    pub fn new(closure: F) -> Self {
+        Self { closure }
+    }
+}
+
+impl<F> ProgressEventHandler for ClosureProgressEventHandler<F>
+where
+    F: FnMut(&ProgressEvent) -> bool + Send,
+{
+    fn on_progress(&mut self, event: &ProgressEvent) -> bool {
+        (self.closure)(event)
+    }
+}
+
+/// Cheap, cloneable handle for cancelling an import from another thread.
+///
+/// Pass a clone to [`crate::importer::ImportBuilder::with_cancellation_token`] and call
+/// [`CancellationToken::cancel`] from anywhere; the import then fails with
+/// [`crate::Error::Cancelled`]. Assimp only checks for cancellation at its progress
+/// checkpoints, so an import stops at the next one rather than immediately.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation. Every clone of this token observes it.
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Release);
+    }
+
+    /// Whether [`CancellationToken::cancel`] was called on this token or a clone.
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Acquire)
+    }
+}
+
+/// Wall-clock limit of one import, see
+/// [`crate::importer::ImportBuilder::with_timeout`].
+///
+/// `expired` is tripped by [`Deadline::check`] or by the [`Watchdog`]; custom-IO reads
+/// watch it too.
+#[derive(Debug, Clone)]
+pub(crate) struct Deadline {
+    start: Instant,
+    limit: Duration,
+    expired: CancellationToken,
+}
+
+impl Deadline {
+    /// Start the clock now.
+    pub(crate) fn start(limit: Duration) -> Self {
+        Self {
+            start: Instant::now(),
+            limit,
+            expired: CancellationToken::new(),
+        }
+    }
+
+    /// Whether the limit has passed, tripping the token if it just did.
+    pub(crate) fn check(&self) -> bool {
+        if self.expired.is_cancelled() {
+            return true;
+        }
+        if self.start.elapsed() >= self.limit {
+            self.expired.cancel();
+            return true;
+        }
+        false
+    }
+
+    /// Whether the deadline was tripped, without consulting the clock.
+    pub(crate) fn is_expired(&self) -> bool {
+        self.expired.is_cancelled()
+    }
+
+    /// Time since [`Deadline::start`].
+    pub(crate) fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Token tripped once the deadline expires.
+    pub(crate) fn token(&self) -> &CancellationToken {
+        &self.expired
+    }
+
+    /// Trip the deadline from a background thread if the import is still running when
+    /// the limit passes.
+    ///
+    /// Assimp cannot be interrupted safely mid-parse, so this only sets the token; the
+    /// import stops at the next progress update or custom-IO read that observes it.
+    /// If the thread cannot be spawned, only the progress checkpoints compare against
+    /// the clock.
+    pub(crate) fn watchdog(&self) -> Watchdog {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let remaining = self.limit.saturating_sub(self.start.elapsed());
+        let expired = self.expired.clone();
+        let thread = std::thread::Builder::new()
+            .name("asset-importer-watchdog".to_string())
+            .spawn(move || {
+                if let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(remaining) {
+                    expired.cancel();
+                }
+            })
+            .ok();
+        Watchdog {
+            stop: Some(stop),
+            thread,
+        }
+    }
+}
+
+/// Background thread started by [`Deadline::watchdog`]; dropping it stops the thread.
+pub(crate) struct Watchdog {
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        // Disconnecting the channel wakes the thread before its timeout.
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Parses raw bridge callbacks, applies throttling and remembers cancellation.
+pub(crate) struct ProgressDispatcher {
+    handler: Option<Box<dyn ProgressEventHandler>>,
+    throttle: Option<Duration>,
+    file: Option<String>,
+    last_forwarded: Option<Instant>,
+    cancellation: Option<CancellationToken>,
+    deadline: Option<Deadline>,
+    cancelled: bool,
+}
+
+impl ProgressDispatcher {
+    pub(crate) fn new(
+        handler: Box<dyn ProgressEventHandler>,
+        throttle: Option<Duration>,
+        file: Option<String>,
+    ) -> Self {
+        Self {
+            handler: Some(handler),
+            throttle,
+            file,
+            last_forwarded: None,
+            cancellation: None,
+            deadline: None,
+            cancelled: false,
+        }
+    }
+
+    /// A dispatcher that only watches a cancellation token.
+    pub(crate) fn without_handler() -> Self {
+        Self {
+            handler: None,
+            throttle: None,
+            file: None,
+            last_forwarded: None,
+            cancellation: None,
+            deadline: None,
+            cancelled: false,
+        }
+    }
+
+    /// Also report cancellation once `token` is cancelled.
+    pub(crate) fn with_cancellation(mut self, token: Option<CancellationToken>) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Also report cancellation once `deadline` expires.
+    pub(crate) fn with_deadline(mut self, deadline: Option<Deadline>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Whether the handler, the token or the deadline cancelled the operation.
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    /// Forward an update unless it is throttled. Returns `false` once cancelled.
+    pub(crate) fn dispatch(&mut self, percent: f32, message: Option<&str>) -> bool {
+        if self.cancelled {
+            return false;
+        }
+        if self
+            .cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            self.cancelled = true;
+            return false;
+        }
+        if self.deadline.as_ref().is_some_and(Deadline::check) {
+            self.cancelled = true;
+            return false;
+        }
+        let Some(handler) = self.handler.as_mut() else {
+            return true;
+        };
+
+        let now = Instant::now();
+        let due = match (self.throttle, self.last_forwarded) {
+            (Some(interval), Some(last)) => now.duration_since(last) >= interval,
+            _ => true,
+        };
+        if !due && percent < 1.0 {
+            return true;
+        }
+
+        self.last_forwarded = Some(now);
+        let event = ProgressEvent::parse(percent, message, self.file.as_deref());
+        if !handler.on_progress(&event) {
+            self.cancelled = true;
+        }
+        !self.cancelled
+    }
+}
+
 /// A simple progress handler that prints to stdout
 pub struct PrintProgressHandler {
     last_percentage: i32,
@@ -173,4 +519,69 @@ mod tests {
         assert_eq!(call_count, 2);
         assert_eq!(last_percentage, 0.7);
     }
+
+    #[test]
+    fn test_progress_event_parse() {
+        let event = ProgressEvent::parse(0.75, Some("post 3/6"), Some("model.obj"));
+        assert_eq!(event.stage, ProgressStage::PostProcessing);
+        assert_eq!(event.step, Some((3, 6)));
+        assert_eq!(event.raw_message.as_deref(), Some("post 3/6"));
+        assert_eq!(event.file.as_deref(), Some("model.obj"));
+
+        let event = ProgressEvent::parse(0.25, Some("read 1/2"), None);
+        assert_eq!(event.stage, ProgressStage::Reading);
+        assert_eq!(event.step, Some((1, 2)));
+
+        let event = ProgressEvent::parse(-1.0, None, None);
+        assert_eq!(event.stage, ProgressStage::Unknown);
+        assert!(event.step.is_none());
+
+        let event = ProgressEvent::parse(0.5, Some("loading textures"), None);
+        assert_eq!(event.stage, ProgressStage::Unknown);
+        assert!(event.step.is_none());
+    }
+
+    #[test]
+    fn test_dispatcher_throttles_but_forwards_completion() {
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let handler = ClosureProgressEventHandler::new(move |event: &ProgressEvent| {
+            sink.lock().unwrap().push(event.percent);
+            true
+        });
+        let mut dispatcher =
+            ProgressDispatcher::new(Box::new(handler), Some(Duration::from_secs(3600)), None);
+
+        assert!(dispatcher.dispatch(0.1, Some("read 0/1")));
+        assert!(dispatcher.dispatch(0.4, Some("read 1/1")));
+        assert!(dispatcher.dispatch(1.0, Some("post 2/2")));
+
+        assert_eq!(*seen.lock().unwrap(), [0.1, 1.0]);
+    }
+
+    #[test]
+    fn test_dispatcher_cancellation_is_sticky() {
+        let mut calls = 0;
+        let handler = ClosureProgressHandler::new(move |_, _| {
+            calls += 1;
+            calls < 2
+        });
+        let mut dispatcher = ProgressDispatcher::new(Box::new(handler), None, None);
+
+        assert!(dispatcher.dispatch(0.1, None));
+        assert!(!dispatcher.dispatch(0.2, None));
+        assert!(!dispatcher.dispatch(1.0, None));
+    }
+
+    #[test]
+    fn test_dispatcher_observes_cancellation_token() {
+        let token = CancellationToken::new();
+        let mut dispatcher =
+            ProgressDispatcher::without_handler().with_cancellation(Some(token.clone()));
+
+        assert!(dispatcher.dispatch(0.1, None));
+        token.cancel();
+        assert!(!dispatcher.dispatch(0.2, None));
+        assert!(dispatcher.is_cancelled());
+    }
 }
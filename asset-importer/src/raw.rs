@@ -5,17 +5,25 @@
 
 #![allow(non_snake_case)]
 
+use crate::types::{Quaternion, Real, Vector3D, from_real};
+
 /// Mirror of Assimp `aiVector3D`.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[repr(C)]
 pub struct AiVector3D {
     /// X component
-    pub x: f32,
+    pub x: Real,
     /// Y component
-    pub y: f32,
+    pub y: Real,
     /// Z component
-    pub z: f32,
+    pub z: Real,
+}
+
+impl From<AiVector3D> for Vector3D {
+    fn from(v: AiVector3D) -> Self {
+        Vector3D::new(from_real(v.x), from_real(v.y), from_real(v.z))
+    }
 }
 
 /// Mirror of Assimp `aiColor4D`.
@@ -33,6 +41,14 @@ pub struct AiColor4D {
     pub a: f32,
 }
 
+// The `Mesh::*_f32x3`/`*_f32x4` accessors rely on these layouts.
+const _: () = {
+    assert!(std::mem::size_of::<AiVector3D>() == std::mem::size_of::<[Real; 3]>());
+    assert!(std::mem::align_of::<AiVector3D>() == std::mem::align_of::<[Real; 3]>());
+    assert!(std::mem::size_of::<AiColor4D>() == std::mem::size_of::<[f32; 4]>());
+    assert!(std::mem::align_of::<AiColor4D>() == std::mem::align_of::<[f32; 4]>());
+};
+
 /// Mirror of Assimp `aiTexel` (ARGB8888).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
@@ -81,13 +97,24 @@ impl AiFace {
 #[repr(C)]
 pub struct AiQuaternion {
     /// W component
-    pub w: f32,
+    pub w: Real,
     /// X component
-    pub x: f32,
+    pub x: Real,
     /// Y component
-    pub y: f32,
+    pub y: Real,
     /// Z component
-    pub z: f32,
+    pub z: Real,
+}
+
+impl From<AiQuaternion> for Quaternion {
+    fn from(q: AiQuaternion) -> Self {
+        Quaternion::from_xyzw(
+            from_real(q.x),
+            from_real(q.y),
+            from_real(q.z),
+            from_real(q.w),
+        )
+    }
 }
 
 /// Mirror of Assimp `aiVectorKey`.
@@ -115,14 +142,19 @@ pub struct AiQuatKey {
 }
 
 /// Mirror of Assimp `aiVertexWeight`.
+///
+/// Not `Pod` in double-precision builds: the `u32` id is followed by padding there.
 #[derive(Debug, Clone, Copy, PartialEq)]
-#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+    all(feature = "bytemuck", not(f64_real)),
+    derive(bytemuck::Pod, bytemuck::Zeroable)
+)]
 #[repr(C)]
 pub struct AiVertexWeight {
     /// Vertex index.
     pub mVertexId: u32,
     /// Weight value.
-    pub mWeight: f32,
+    pub mWeight: Real,
 }
 
 #[cfg(test)]
@@ -1,22 +1,41 @@
 //! Scene representation and management
 
-use std::sync::Arc;
+pub mod bindings;
+pub mod build;
+pub mod conventions;
+pub mod convert;
+pub mod diff;
+pub mod dump;
+pub mod extract;
+pub mod names;
+pub mod placement;
+pub mod skinning;
+pub mod stats;
+
+use std::{
+    ops::Deref,
+    sync::{Arc, OnceLock},
+};
 
 use crate::{
+    aabb::AABB,
     animation::Animation,
     camera::Camera,
     error::{Error, Result},
     ffi,
     importer::{Importer, PropertyStore},
+    importer_desc::{ImporterDesc, SourceFormatInfo},
     light::Light,
-    material::Material,
-    mesh::Mesh,
+    material::{Material, MaterialMut, OwnedMaterial},
+    mesh::{Mesh, PrimitiveTypes},
     metadata::Metadata,
-    node::Node,
+    node::{Node, NodeTraversal},
     postprocess::PostProcessSteps,
     ptr::SharedPtr,
     sys,
     texture::{Texture, TextureIterator},
+    types::Matrix4x4,
+    validation::ValidationReport,
 };
 
 /// Memory usage information for a scene
@@ -104,6 +123,28 @@ impl Default for MemoryInfo {
 /// `'static` (as long as you own the view value) and avoids borrow-checker friction in
 /// async and multithreaded code.
 ///
+/// ## Ownership
+/// Cloning a `Scene` only bumps a reference count. Every view type (and the iterators that
+/// yield them) owns such a clone, so views can be stored in structs, returned from
+/// functions or sent to other threads after the original `Scene` is dropped.
+///
+/// Zero-copy accessors are the exception: slices such as [`Mesh::vertices_raw`] borrow
+/// from the view they were obtained from, so they cannot outlive it.
+///
+/// ```rust,compile_fail
+/// # use asset_importer::Scene;
+/// # fn positions(scene: &Scene) {
+/// let positions = {
+///     let mesh = scene.meshes().next().unwrap();
+///     mesh.vertices_raw()
+/// }; // error: `mesh` does not live long enough
+/// # let _ = positions;
+/// # }
+/// ```
+///
+/// Mutation goes through [`OwnedScene`], whose `&mut` borrows
+/// ([`MaterialMut`](crate::material::MaterialMut)) are lifetime-bound as usual.
+///
 /// This guarantee relies on the safe API treating the imported Assimp scene as immutable.
 /// If you call into raw Assimp bindings (`asset_importer::sys` with feature `raw-sys`, or the
 /// `asset-importer-sys` crate) and mutate internal pointers yourself, you can
@@ -117,13 +158,49 @@ pub struct Scene {
 pub(crate) struct SceneInner {
     scene_ptr: SharedPtr<sys::aiScene>,
     release_kind: SceneRelease,
+    /// Lazily built by [`Scene::instanced_meshes`]; reset whenever `scene_ptr` changes.
+    mesh_instances: OnceLock<Vec<Vec<MeshInstance>>>,
+    /// Set once by the importer, see [`Scene::source_format`]; `bool` is exactness.
+    source_importer: OnceLock<(ImporterDesc, bool)>,
+    /// Set by imports with validation enabled, see [`Scene::validation_report`].
+    validation_report: OnceLock<ValidationReport>,
+}
+
+/// One placement of a mesh in the node hierarchy, see [`Scene::mesh_instances`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshInstance {
+    /// Name of the node that references the mesh.
+    pub node_name: String,
+    /// The node's transform accumulated from the root node.
+    pub world_transform: Matrix4x4,
+}
+
+bitflags::bitflags! {
+    /// Scene flags (`aiScene::mFlags`, the `AI_SCENE_FLAGS_*` defines), see
+    /// [`Scene::scene_flags`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct SceneFlags: u32 {
+        /// The import failed partially or the scene is an animation skeleton only
+        const INCOMPLETE = sys::AI_SCENE_FLAGS_INCOMPLETE;
+        /// `aiProcess_ValidateDataStructure` passed without warnings
+        const VALIDATED = sys::AI_SCENE_FLAGS_VALIDATED;
+        /// `aiProcess_ValidateDataStructure` passed with warnings
+        const VALIDATION_WARNING = sys::AI_SCENE_FLAGS_VALIDATION_WARNING;
+        /// Vertices are shared between faces (set by `aiProcess_JoinIdenticalVertices`)
+        const NON_VERBOSE_FORMAT = sys::AI_SCENE_FLAGS_NON_VERBOSE_FORMAT;
+        /// The scene holds terrain patches (height maps)
+        const TERRAIN = sys::AI_SCENE_FLAGS_TERRAIN;
+        /// Data may be referenced from several places, e.g. one vertex by several faces,
+        /// independently of post-processing
+        const ALLOW_SHARED = sys::AI_SCENE_FLAGS_ALLOW_SHARED;
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum SceneRelease {
     /// Scene returned by aiImportFile* family, free with aiReleaseImport
     ReleaseImport,
-    /// Scene created via aiCopyScene, free with aiFreeScene
+    /// Scene owned outright (aiCopyScene copies, bridge imports), free with aiFreeScene
     FreeScene,
 }
 
@@ -137,12 +214,15 @@ impl Scene {
     /// - The scene was allocated by Assimp and should be freed with aiReleaseImport
     /// - The scene pointer remains valid for the lifetime of this Scene
     pub(crate) unsafe fn from_raw_import_sys(scene_ptr: *const sys::aiScene) -> Result<Self> {
-        let scene_ptr = SharedPtr::new(scene_ptr).ok_or(Error::NullPointer)?;
+        let scene_ptr = SharedPtr::new(scene_ptr).ok_or_else(Error::null_pointer)?;
 
         Ok(Self {
             inner: Arc::new(SceneInner {
                 scene_ptr,
                 release_kind: SceneRelease::ReleaseImport,
+                mesh_instances: OnceLock::new(),
+                source_importer: OnceLock::new(),
+                validation_report: OnceLock::new(),
             }),
         })
     }
@@ -159,14 +239,21 @@ impl Scene {
     /// Create a Scene from a deep-copied Assimp scene pointer (aiCopyScene)
     /// The scene will be freed with aiFreeScene.
     ///
+    /// Also used for scenes the C++ bridge takes out of its `Assimp::Importer`
+    /// (`GetOrphanedScene`), which are owned the same way.
+    ///
     /// # Safety
-    /// Caller must ensure `scene_ptr` is valid and was allocated by aiCopyScene.
+    /// Caller must ensure `scene_ptr` is valid and owned by the caller, i.e. allocated
+    /// by aiCopyScene or released by the bridge.
     pub(crate) unsafe fn from_raw_copied_sys(scene_ptr: *const sys::aiScene) -> Result<Self> {
-        let scene_ptr = SharedPtr::new(scene_ptr).ok_or(Error::NullPointer)?;
+        let scene_ptr = SharedPtr::new(scene_ptr).ok_or_else(Error::null_pointer)?;
         Ok(Self {
             inner: Arc::new(SceneInner {
                 scene_ptr,
                 release_kind: SceneRelease::FreeScene,
+                mesh_instances: OnceLock::new(),
+                source_importer: OnceLock::new(),
+                validation_report: OnceLock::new(),
             }),
         })
     }
@@ -196,31 +283,74 @@ impl Scene {
         self.inner.scene_ptr.as_ref()
     }
 
+    /// Create a fully independent deep copy of this scene (`aiCopyScene`).
+    ///
+    /// [`Scene::clone`] only shares the underlying data; the copy returned here owns its
+    /// own Assimp allocation, so it can outlive the original and is unaffected by
+    /// operations that consume and modify a scene, such as [`Scene::apply_postprocess`].
+    /// Works for imported scenes and for scenes that are themselves copies. Allocation
+    /// failures inside Assimp are returned as errors.
+    pub fn deep_copy(&self) -> Result<Scene> {
+        let scene_ptr = unsafe { copy_scene_sys(self.as_raw_sys()) }?;
+        Ok(Self {
+            inner: Arc::new(SceneInner {
+                scene_ptr,
+                release_kind: SceneRelease::FreeScene,
+                mesh_instances: OnceLock::new(),
+                source_importer: self.inner.source_importer.clone(),
+                validation_report: self.inner.validation_report.clone(),
+            }),
+        })
+    }
+
+    /// Whether the scene came straight from an `aiImportFile*` call and is still owned by
+    /// Assimp's importer.
+    ///
+    /// Copies ([`Scene::deep_copy`]), scenes built or extracted by this crate, and imports
+    /// that went through the C++ bridge (e.g. with a progress handler) are owned outright
+    /// instead; Assimp cannot post-process those, see [`Scene::apply_postprocess`].
+    pub fn is_importer_owned(&self) -> bool {
+        self.inner.release_kind == SceneRelease::ReleaseImport
+    }
+
     /// Apply Assimp post-processing to this scene.
     ///
     /// This consumes the scene and returns the updated scene on success:
     /// `scene = scene.apply_postprocess(flags)?;`.
     ///
-    /// If the scene is shared (i.e. cloned), this function will post-process a deep copy
-    /// to avoid mutating shared scene memory.
+    /// Assimp runs post-processing through the importer that produced the scene, so only
+    /// [importer-owned](Scene::is_importer_owned) scenes qualify; other scenes fail with
+    /// [`Error::UnsupportedOperation`] without calling into Assimp. Pass the steps to the
+    /// import instead, or use the [`mesh::compute`](crate::mesh::compute) and
+    /// [`convert`](crate::scene::convert) utilities. The scene must also not be shared:
+    /// clones and views (meshes, nodes, ...) of it have to be dropped first.
     ///
     /// Assimp documents that post-processing is in-place but may return `NULL` on failure
     /// (notably for `aiProcess_ValidateDataStructure`), potentially invalidating the input
     /// scene pointer. To avoid double-free or use-after-free in safe Rust, this API takes
     /// ownership of the scene and will not drop the original pointer on failure.
+    ///
+    /// Errors are tagged with [`Operation::PostProcess`](crate::error::Operation::PostProcess).
     pub fn apply_postprocess(self, flags: crate::postprocess::PostProcessSteps) -> Result<Self> {
-        let inner = match Arc::try_unwrap(self.inner) {
-            Ok(inner) => inner,
-            Err(shared) => {
-                // If the scene is shared, avoid mutating shared memory by post-processing a deep
-                // copy instead. This makes `apply_postprocess` deterministic and thread-friendly.
-                let copied = unsafe { copy_scene_sys(shared.scene_ptr.as_ptr()) }?;
-                SceneInner {
-                    scene_ptr: copied,
-                    release_kind: SceneRelease::FreeScene,
-                }
-            }
-        };
+        self.apply_postprocess_inner(flags)
+            .map_err(|err| err.with_operation(crate::error::Operation::PostProcess))
+    }
+
+    fn apply_postprocess_inner(self, flags: crate::postprocess::PostProcessSteps) -> Result<Self> {
+        // Copies have no importer attached; Assimp would only log "scene not found".
+        if !self.is_importer_owned() {
+            return Err(Error::unsupported_operation(
+                "post-processing requires an importer-owned scene; re-import with the desired \
+                 steps or use scene::convert utilities",
+            ));
+        }
+        // Post-processing works in place, which other handles must not observe.
+        let inner = Arc::try_unwrap(self.inner).map_err(|_| {
+            Error::unsupported_operation(
+                "post-processing requires the only handle to the scene; drop its clones and \
+                 views first",
+            )
+        })?;
 
         // Assimp may invalidate the input pointer on failure. Prefer leaking over UB.
         let inner = std::mem::ManuallyDrop::new(inner);
@@ -233,7 +363,8 @@ impl Scene {
 
         // Assimp promises this is the same scene pointer on success, but treat it as an update anyway.
         let mut inner = std::mem::ManuallyDrop::into_inner(inner);
-        inner.scene_ptr = SharedPtr::new(new_ptr).ok_or(Error::NullPointer)?;
+        inner.scene_ptr = SharedPtr::new(new_ptr).ok_or_else(Error::null_pointer)?;
+        inner.mesh_instances = OnceLock::new();
         Ok(Self {
             inner: Arc::new(inner),
         })
@@ -390,16 +521,123 @@ impl Scene {
         self.flags() & sys::AI_SCENE_FLAGS_NON_VERBOSE_FORMAT != 0
     }
 
+    /// Get the scene flags as typed [`SceneFlags`]; unknown bits are kept.
+    pub fn scene_flags(&self) -> SceneFlags {
+        SceneFlags::from_bits_retain(self.flags())
+    }
+
+    /// Check if the scene may reference the same data from several places
+    /// ([`SceneFlags::ALLOW_SHARED`]).
+    pub fn allows_shared_data(&self) -> bool {
+        self.scene_flags().contains(SceneFlags::ALLOW_SHARED)
+    }
+
     /// Check if terrain patches are present
     pub fn has_terrain(&self) -> bool {
         self.flags() & sys::AI_SCENE_FLAGS_TERRAIN != 0
     }
 
+    /// Get the name of the scene (`aiScene::mName`), empty for most formats.
+    pub fn name(&self) -> String {
+        crate::types::ai_string_to_string(&self.raw().mName)
+    }
+
+    /// Get the name of the scene (zero-copy, lossy UTF-8).
+    pub fn name_str(&self) -> std::borrow::Cow<'_, str> {
+        crate::types::ai_string_to_str(&self.raw().mName)
+    }
+
+    /// Bytes of the scene name up to the first NUL, for binary-safe comparison.
+    pub fn name_bytes(&self) -> &[u8] {
+        crate::types::ai_string_bytes(&self.raw().mName)
+    }
+
     /// Get the root node of the scene
     pub fn root_node(&self) -> Option<Node> {
         Node::from_sys_ptr(self.clone(), self.raw().mRootNode)
     }
 
+    /// Follow child indices from the root node, as returned by [`Node::path`].
+    ///
+    /// An empty path yields the root node; `None` when an index is out of range.
+    pub fn node_by_path(&self, path: &[usize]) -> Option<Node> {
+        path.iter()
+            .try_fold(self.root_node()?, |node, &index| node.child(index))
+    }
+
+    /// Iterate all nodes in depth-first pre-order, starting at the root node.
+    ///
+    /// Use [`NodeTraversal::with_depth`] to also get each node's depth.
+    pub fn nodes_depth_first(&self) -> NodeTraversal {
+        NodeTraversal::depth_first(self.clone(), self.raw().mRootNode)
+    }
+
+    /// Iterate all nodes in breadth-first (level) order, starting at the root node.
+    pub fn nodes_breadth_first(&self) -> NodeTraversal {
+        NodeTraversal::breadth_first(self.clone(), self.raw().mRootNode)
+    }
+
+    /// Count the nodes in the scene graph (walks the hierarchy).
+    pub fn node_count(&self) -> usize {
+        self.nodes_depth_first().count()
+    }
+
+    /// Compute the world-space bounding box of all meshes referenced by the node hierarchy.
+    ///
+    /// Node transforms are accumulated from the root, and each mesh's [`Mesh::bounds`]
+    /// (Assimp's box, or one computed from the vertices when it was not generated) is
+    /// transformed and merged. Meshes without vertices are skipped. Returns `None` when no
+    /// node references a non-empty mesh.
+    pub fn compute_world_aabb(&self) -> Option<AABB> {
+        let mut stack = vec![(self.root_node()?, Matrix4x4::IDENTITY)];
+        let mut bounds = AABB::empty();
+        while let Some((node, parent)) = stack.pop() {
+            let world = parent * node.transformation();
+            for index in node.mesh_indices_iter() {
+                if let Some(local) = self.mesh(index).and_then(|m| m.bounds()) {
+                    bounds.expand_to_include_aabb(&local.transformed(&world));
+                }
+            }
+            stack.extend(node.children().map(|child| (child, world)));
+        }
+        (!bounds.is_empty()).then_some(bounds)
+    }
+
+    /// List every node that references each mesh, with its world transform.
+    ///
+    /// The outer vector is indexed by mesh index, so a mesh drawn under several nodes
+    /// (instancing) has several entries and an unreferenced mesh has none. Instances
+    /// of one mesh appear in depth-first node order. Built with a single hierarchy walk;
+    /// see [`Scene::instanced_meshes`] for a borrowing view of the same data.
+    pub fn mesh_instances(&self) -> Vec<Vec<MeshInstance>> {
+        self.cached_mesh_instances().clone()
+    }
+
+    /// Iterate meshes together with the nodes that instance them.
+    ///
+    /// The instance lists are computed on first use and cached on the scene (shared
+    /// by clones), so repeated calls do not walk the hierarchy again.
+    pub fn instanced_meshes(&self) -> impl Iterator<Item = (Mesh, &[MeshInstance])> + '_ {
+        self.meshes()
+            .zip(self.cached_mesh_instances())
+            .map(|(mesh, instances)| (mesh, instances.as_slice()))
+    }
+
+    fn cached_mesh_instances(&self) -> &Vec<Vec<MeshInstance>> {
+        self.inner.mesh_instances.get_or_init(|| {
+            let mut instances = vec![Vec::new(); self.num_meshes()];
+            for item in self.renderables() {
+                if let Some(slot) = instances.get_mut(item.mesh_index) {
+                    slot.push(MeshInstance {
+                        node_name: item.node_name.into(),
+                        world_transform: item.world_transform,
+                    });
+                }
+            }
+            instances
+        })
+    }
+
     /// Get the number of meshes in the scene
     pub fn num_meshes(&self) -> usize {
         let scene = self.raw();
@@ -425,10 +663,35 @@ impl Scene {
     pub fn meshes(&self) -> MeshIterator {
         MeshIterator {
             scene: self.clone(),
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                self,
+                self.raw().mMeshes as *const *const sys::aiMesh,
+                self.num_meshes(),
+            ),
         }
     }
 
+    /// Iterate over meshes containing any of the given primitive types.
+    ///
+    /// Import with `PostProcessSteps::SORT_BY_PTYPE` so every mesh holds a single
+    /// primitive kind; otherwise a mixed mesh matches if it holds any of `types`.
+    pub fn meshes_with_primitives(&self, types: PrimitiveTypes) -> impl Iterator<Item = Mesh> + '_ {
+        self.meshes()
+            .filter(move |mesh| mesh.primitive_types_typed().intersects(types))
+    }
+
+    /// Parallel iterator over all meshes (requires the `rayon` feature).
+    ///
+    /// Meshes are read-only views and [`Scene`] is `Sync`, so each mesh can be
+    /// processed on a different thread. `collect` keeps the order of [`Scene::meshes`].
+    #[cfg(feature = "rayon")]
+    pub fn par_meshes(&self) -> impl rayon::iter::ParallelIterator<Item = Mesh> + '_ {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+        (0..self.num_meshes())
+            .into_par_iter()
+            .filter_map(move |index| self.mesh(index))
+    }
+
     /// Get the number of materials in the scene
     pub fn num_materials(&self) -> usize {
         let scene = self.raw();
@@ -455,7 +718,11 @@ impl Scene {
     pub fn materials(&self) -> MaterialIterator {
         MaterialIterator {
             scene: self.clone(),
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                self,
+                self.raw().mMaterials as *const *const sys::aiMaterial,
+                self.num_materials(),
+            ),
         }
     }
 
@@ -489,7 +756,11 @@ impl Scene {
     pub fn animations(&self) -> AnimationIterator {
         AnimationIterator {
             scene: self.clone(),
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                self,
+                self.raw().mAnimations as *const *const sys::aiAnimation,
+                self.num_animations(),
+            ),
         }
     }
 
@@ -519,10 +790,19 @@ impl Scene {
     pub fn cameras(&self) -> CameraIterator {
         CameraIterator {
             scene: self.clone(),
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                self,
+                self.raw().mCameras as *const *const sys::aiCamera,
+                self.num_cameras(),
+            ),
         }
     }
 
+    /// Find the node a camera is attached to (the node with the camera's name).
+    pub fn camera_node(&self, camera: &Camera) -> Option<Node> {
+        self.root_node()?.find_by_name(&camera.name())
+    }
+
     /// Get the number of lights in the scene
     pub fn num_lights(&self) -> usize {
         let scene = self.raw();
@@ -544,12 +824,120 @@ impl Scene {
         Light::from_sys_ptr(self.clone(), light_ptr)
     }
 
+    /// Find the node a light is attached to (the node with the light's name).
+    pub fn light_node(&self, light: &Light) -> Option<Node> {
+        self.root_node()?.find_by_name(&light.name())
+    }
+
     /// Get an iterator over all lights
     pub fn lights(&self) -> LightIterator {
         LightIterator {
             scene: self.clone(),
-            index: 0,
+            cursor: ffi::PtrArrayCursor::new(
+                self,
+                self.raw().mLights as *const *const sys::aiLight,
+                self.num_lights(),
+            ),
+        }
+    }
+}
+
+/// A scene produced by this crate from a private deep copy, e.g. by
+/// [`extract::SubSceneExtractor`], or assembled from Rust data by
+/// [`build::SceneBuilder`].
+///
+/// Derefs to [`Scene`], so it can be inspected and passed to the exporter like any
+/// imported scene.
+#[derive(Debug)]
+pub struct OwnedScene {
+    scene: Scene,
+}
+
+impl OwnedScene {
+    pub(crate) fn from_scene(scene: Scene) -> Self {
+        Self { scene }
+    }
+
+    /// Deep-copy `scene` into a new owned scene that can be edited before export.
+    pub fn copy_of(scene: &Scene) -> Result<Self> {
+        scene.deep_copy().map(Self::from_scene)
+    }
+
+    /// Get a writable pointer to the scene, deep-copying it first if views or clones
+    /// still share the current allocation (they keep seeing the unmodified data).
+    fn raw_mut(&mut self) -> Result<*mut sys::aiScene> {
+        if Arc::get_mut(&mut self.scene.inner).is_none() {
+            self.scene = self.scene.deep_copy()?;
+        }
+        Ok(self.scene.as_raw_sys() as *mut sys::aiScene)
+    }
+
+    /// [`OwnedScene::raw_mut`] for edits that move nodes, which also drops the cached
+    /// [`Scene::mesh_instances`].
+    fn raw_mut_uncached(&mut self) -> Result<*mut sys::aiScene> {
+        let raw = self.raw_mut()?;
+        if let Some(inner) = Arc::get_mut(&mut self.scene.inner) {
+            inner.mesh_instances = OnceLock::new();
+        }
+        Ok(raw)
+    }
+
+    /// Edit the material at `index` in place.
+    ///
+    /// Material views obtained earlier (and clones of this scene) are not affected: the
+    /// scene is copied first if anything else still references it.
+    pub fn material_mut(&mut self, index: usize) -> Result<MaterialMut<'_>> {
+        let count = self.num_materials();
+        if index >= count {
+            return Err(Error::invalid_parameter(format!(
+                "Material index {} out of range (scene has {} materials)",
+                index, count
+            )));
         }
+        let scene = unsafe { &*self.raw_mut()? };
+        let material = unsafe { *scene.mMaterials.add(index) };
+        if material.is_null() {
+            return Err(Error::invalid_scene(format!("Material {} is null", index)));
+        }
+        Ok(unsafe { MaterialMut::new(material) })
+    }
+
+    /// Append `material` to the scene and return its index, for use as a mesh's
+    /// material index.
+    pub fn add_material(&mut self, material: &OwnedMaterial) -> Result<usize> {
+        let index = self.num_materials();
+        let scene = self.raw_mut()?;
+        let new_material = unsafe { sys::aiSceneAddMaterialRust(scene) };
+        if new_material.is_null() {
+            return Err(Error::from_bridge_or_assimp());
+        }
+        unsafe { material.write_to(new_material) }?;
+        Ok(index)
+    }
+
+    /// Convert into a plain shared [`Scene`].
+    pub fn into_scene(self) -> Scene {
+        self.scene
+    }
+}
+
+impl Deref for OwnedScene {
+    type Target = Scene;
+
+    fn deref(&self) -> &Scene {
+        &self.scene
+    }
+}
+
+impl AsRef<Scene> for OwnedScene {
+    fn as_ref(&self) -> &Scene {
+        &self.scene
+    }
+}
+
+impl From<OwnedScene> for Scene {
+    fn from(owned: OwnedScene) -> Self {
+        owned.scene
     }
 }
 
@@ -557,10 +945,9 @@ impl Scene {
 /// `scene_ptr` must point to a valid `aiScene`.
 unsafe fn copy_scene_sys(scene_ptr: *const sys::aiScene) -> Result<SharedPtr<sys::aiScene>> {
     debug_assert!(!scene_ptr.is_null());
-    let mut out: *mut sys::aiScene = std::ptr::null_mut();
-    unsafe { sys::aiCopyScene(scene_ptr, &mut out) };
-    let out = SharedPtr::new(out).ok_or(Error::invalid_scene("aiCopyScene returned null"))?;
-    Ok(out)
+    // The bridge catches C++ exceptions (e.g. std::bad_alloc) so they surface as errors.
+    let out = unsafe { sys::aiCopySceneRust(scene_ptr) };
+    SharedPtr::new(out as *const sys::aiScene).ok_or_else(Error::from_bridge_or_assimp)
 }
 
 impl Drop for SceneInner {
@@ -577,139 +964,246 @@ impl Drop for SceneInner {
 /// Iterator over meshes in a scene
 pub struct MeshIterator {
     scene: Scene,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl Iterator for MeshIterator {
     type Item = Mesh;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.scene.num_meshes() {
-            let idx = self.index;
-            self.index += 1;
-            if let Some(mesh) = self.scene.mesh(idx) {
-                return Some(mesh);
-            }
-        }
-        None
+        let scene = &self.scene;
+        self.cursor.next(|index| scene.mesh(index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.scene.num_meshes().saturating_sub(self.index);
-        (0, Some(remaining))
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.nth(n, |index| scene.mesh(index))
     }
 }
 
+impl DoubleEndedIterator for MeshIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.next_back(|index| scene.mesh(index))
+    }
+}
+
+impl ExactSizeIterator for MeshIterator {}
+
 /// Iterator over materials in a scene
 pub struct MaterialIterator {
     scene: Scene,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl Iterator for MaterialIterator {
     type Item = Material;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.scene.num_materials() {
-            let idx = self.index;
-            self.index += 1;
-            if let Some(material) = self.scene.material(idx) {
-                return Some(material);
-            }
-        }
-        None
+        let scene = &self.scene;
+        self.cursor.next(|index| scene.material(index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.scene.num_materials().saturating_sub(self.index);
-        (0, Some(remaining))
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.nth(n, |index| scene.material(index))
     }
 }
 
+impl DoubleEndedIterator for MaterialIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.next_back(|index| scene.material(index))
+    }
+}
+
+impl ExactSizeIterator for MaterialIterator {}
+
 /// Iterator over animations in a scene
 pub struct AnimationIterator {
     scene: Scene,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl Iterator for AnimationIterator {
     type Item = Animation;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.scene.num_animations() {
-            let idx = self.index;
-            self.index += 1;
-            if let Some(animation) = self.scene.animation(idx) {
-                return Some(animation);
-            }
-        }
-        None
+        let scene = &self.scene;
+        self.cursor.next(|index| scene.animation(index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.scene.num_animations().saturating_sub(self.index);
-        (0, Some(remaining))
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.nth(n, |index| scene.animation(index))
     }
 }
 
+impl DoubleEndedIterator for AnimationIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.next_back(|index| scene.animation(index))
+    }
+}
+
+impl ExactSizeIterator for AnimationIterator {}
+
 /// Iterator over cameras in a scene
 pub struct CameraIterator {
     scene: Scene,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl Iterator for CameraIterator {
     type Item = Camera;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.scene.num_cameras() {
-            let idx = self.index;
-            self.index += 1;
-            if let Some(camera) = self.scene.camera(idx) {
-                return Some(camera);
-            }
-        }
-        None
+        let scene = &self.scene;
+        self.cursor.next(|index| scene.camera(index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.scene.num_cameras().saturating_sub(self.index);
-        (0, Some(remaining))
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.nth(n, |index| scene.camera(index))
     }
 }
 
+impl DoubleEndedIterator for CameraIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.next_back(|index| scene.camera(index))
+    }
+}
+
+impl ExactSizeIterator for CameraIterator {}
+
 /// Iterator over lights in a scene
 pub struct LightIterator {
     scene: Scene,
-    index: usize,
+    cursor: ffi::PtrArrayCursor,
 }
 
 impl Iterator for LightIterator {
     type Item = Light;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.scene.num_lights() {
-            let idx = self.index;
-            self.index += 1;
-            if let Some(light) = self.scene.light(idx) {
-                return Some(light);
-            }
-        }
-        None
+        let scene = &self.scene;
+        self.cursor.next(|index| scene.light(index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.scene.num_lights().saturating_sub(self.index);
-        (0, Some(remaining))
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.nth(n, |index| scene.light(index))
+    }
+}
+
+impl DoubleEndedIterator for LightIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let scene = &self.scene;
+        self.cursor.next_back(|index| scene.light(index))
     }
 }
 
+impl ExactSizeIterator for LightIterator {}
+
 impl Scene {
     /// Get scene metadata
+    ///
+    /// A scene without metadata yields an empty [`Metadata`]; errors are reserved for
+    /// metadata that is present but cannot be converted. Use [`Scene::metadata_opt`] to
+    /// tell the two cases apart.
     pub fn metadata(&self) -> Result<Metadata> {
         Metadata::from_sys_ptr(self.raw().mMetaData)
     }
 
+    /// Scene metadata, or `None` when the importer attached none.
+    pub fn metadata_opt(&self) -> Result<Option<Metadata>> {
+        let ptr = self.raw().mMetaData;
+        if ptr.is_null() {
+            return Ok(None);
+        }
+        Metadata::from_sys_ptr(ptr).map(Some)
+    }
+
+    /// Which importer read this scene, and the format version it recorded.
+    ///
+    /// Imports through the C++ bridge (with a progress handler) report the importer
+    /// Assimp's selection picks for the file; other imports report the importer registered for the file
+    /// extension or memory hint (see [`SourceFormatInfo::exact`]). Returns `None` for
+    /// scenes that were not imported, e.g. from [`build::SceneBuilder`], or when no
+    /// importer is registered for the extension.
+    pub fn source_format(&self) -> Option<SourceFormatInfo> {
+        let (importer, exact) = self.inner.source_importer.get()?.clone();
+        let format_version = self.metadata().ok().and_then(|metadata| {
+            metadata
+                .get_string(crate::metadata::common_metadata::SOURCE_FORMAT_VERSION)
+                .map(str::to_string)
+        });
+        Some(SourceFormatInfo {
+            importer,
+            format_version,
+            exact,
+        })
+    }
+
+    pub(crate) fn set_source_importer(&self, importer: ImporterDesc, exact: bool) {
+        let _ = self.inner.source_importer.set((importer, exact));
+    }
+
+    /// Validation problems found while importing this scene.
+    ///
+    /// `Some` (possibly empty) only for scenes imported with
+    /// [`ImportBuilder::with_validation`](crate::ImportBuilder::with_validation) in
+    /// [`ValidationMode::Warn`](crate::validation::ValidationMode::Warn); Strict imports
+    /// with problems fail instead.
+    pub fn validation_report(&self) -> Option<ValidationReport> {
+        self.inner.validation_report.get().cloned()
+    }
+
+    pub(crate) fn set_validation_report(&self, report: ValidationReport) {
+        let _ = self.inner.validation_report.set(report);
+    }
+
+    /// Length unit of the scene relative to centimetres, if the source format records one.
+    ///
+    /// Reads the `UnitScaleFactor` metadata entry, falling back to the FBX
+    /// `OriginalUnitScaleFactor`. An FBX file authored in metres reports `100.0`; one in
+    /// centimetres reports `1.0`. Formats without unit information return `None`.
+    pub fn unit_scale_factor(&self) -> Option<f32> {
+        use crate::metadata::common_metadata::{ORIGINAL_UNIT_SCALE_FACTOR, UNIT_SCALE_FACTOR};
+
+        let metadata = self.metadata().ok()?;
+        [UNIT_SCALE_FACTOR, ORIGINAL_UNIT_SCALE_FACTOR]
+            .into_iter()
+            .filter_map(|key| metadata.get(key))
+            .find_map(|entry| {
+                entry
+                    .as_f32()
+                    .or_else(|| entry.as_f64().map(|v| v as f32))
+                    .or_else(|| entry.as_i32().map(|v| v as f32))
+            })
+    }
+
     /// Get the number of textures in the scene
     pub fn num_textures(&self) -> usize {
         let scene = self.raw();
@@ -0,0 +1,170 @@
+//! Resolve the nodes cameras and lights are attached to.
+//!
+//! Assimp ties a camera or light to a node only through equal names, so a lookup per
+//! object ([`Scene::camera_node`], [`Scene::light_node`]) silently picks one node when
+//! names collide and finds nothing for unnamed objects. [`Scene::bind_cameras_and_lights`]
+//! resolves every camera and light in one traversal and reports those cases.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! # fn report(scene: &Scene) {
+//! let bindings = scene.bind_cameras_and_lights();
+//! for collision in &bindings.collisions {
+//!     eprintln!("{collision:?}");
+//! }
+//! if let Some(node) = bindings.camera_path(0).and_then(|path| scene.node_by_path(path)) {
+//!     println!("camera 0 is attached to {}", node.name());
+//! }
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{node::Node, scene::Scene};
+
+/// Whether a binding refers to a camera or a light.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BindingKind {
+    /// An entry of `aiScene::mCameras`
+    Camera,
+    /// An entry of `aiScene::mLights`
+    Light,
+}
+
+/// A camera or light whose name matches more than one node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingCollision {
+    /// Camera or light
+    pub kind: BindingKind,
+    /// Index into the scene's cameras or lights
+    pub index: usize,
+    /// The shared name
+    pub name: String,
+    /// Paths of all nodes with that name in depth-first order; the first one is bound.
+    pub candidates: Vec<Vec<usize>>,
+}
+
+/// Camera and light to node assignments, from [`Scene::bind_cameras_and_lights`].
+///
+/// Nodes are identified by their [`Node::path`]; resolve them with
+/// [`Scene::node_by_path`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SceneBindings {
+    /// Camera index to node path
+    pub cameras: BTreeMap<usize, Vec<usize>>,
+    /// Light index to node path
+    pub lights: BTreeMap<usize, Vec<usize>>,
+    /// Cameras and lights bound to the first of several nodes with the same name
+    pub collisions: Vec<BindingCollision>,
+    /// Cameras and lights without a node, including those with empty names
+    pub unbound: Vec<(BindingKind, usize)>,
+}
+
+impl SceneBindings {
+    /// Path of the node camera `index` is attached to.
+    pub fn camera_path(&self, index: usize) -> Option<&[usize]> {
+        self.cameras.get(&index).map(Vec::as_slice)
+    }
+
+    /// Path of the node light `index` is attached to.
+    pub fn light_path(&self, index: usize) -> Option<&[usize]> {
+        self.lights.get(&index).map(Vec::as_slice)
+    }
+
+    /// Whether any camera or light name matched more than one node.
+    pub fn has_collisions(&self) -> bool {
+        !self.collisions.is_empty()
+    }
+
+    fn bind(
+        &mut self,
+        kind: BindingKind,
+        index: usize,
+        name: String,
+        nodes: &HashMap<String, Vec<Vec<usize>>>,
+    ) {
+        let candidates = match nodes.get(&name) {
+            Some(candidates) if !candidates.is_empty() => candidates,
+            _ => {
+                self.unbound.push((kind, index));
+                return;
+            }
+        };
+        let map = match kind {
+            BindingKind::Camera => &mut self.cameras,
+            BindingKind::Light => &mut self.lights,
+        };
+        map.insert(index, candidates[0].clone());
+        if candidates.len() > 1 {
+            self.collisions.push(BindingCollision {
+                kind,
+                index,
+                name,
+                candidates: candidates.clone(),
+            });
+        }
+    }
+}
+
+impl Scene {
+    /// Find the node of every camera and light in one depth-first traversal.
+    ///
+    /// Objects are matched to nodes by name. When several nodes share the name, the
+    /// first in depth-first pre-order wins and the ambiguity is recorded in
+    /// [`SceneBindings::collisions`]. A light referenced from a node's glTF
+    /// `KHR_lights_punctual` extension (see [`Node::gltf_light_index`]) is bound to
+    /// that node without consulting names.
+    pub fn bind_cameras_and_lights(&self) -> SceneBindings {
+        // Null entries get an empty name, keeping positions aligned with the indices.
+        let camera_names: Vec<String> = (0..self.num_cameras())
+            .map(|index| self.camera(index).map(|c| c.name()).unwrap_or_default())
+            .collect();
+        let light_names: Vec<String> = (0..self.num_lights())
+            .map(|index| self.light(index).map(|l| l.name()).unwrap_or_default())
+            .collect();
+
+        let mut nodes: HashMap<String, Vec<Vec<usize>>> = camera_names
+            .iter()
+            .chain(&light_names)
+            .filter(|name| !name.is_empty())
+            .map(|name| (name.clone(), Vec::new()))
+            .collect();
+        let mut lights_by_extension: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+
+        let mut stack: Vec<(Node, Vec<usize>)> = self
+            .root_node()
+            .map(|root| (root, Vec::new()))
+            .into_iter()
+            .collect();
+        while let Some((node, path)) = stack.pop() {
+            if let Some(paths) = nodes.get_mut(&*node.name_str()) {
+                paths.push(path.clone());
+            }
+            if let Some(light) = node.gltf_light_index() {
+                lights_by_extension
+                    .entry(light)
+                    .or_insert_with(|| path.clone());
+            }
+            // Reversed so children are visited left to right.
+            for (index, child) in node.children().enumerate().rev() {
+                let mut child_path = path.clone();
+                child_path.push(index);
+                stack.push((child, child_path));
+            }
+        }
+
+        let mut bindings = SceneBindings::default();
+        for (index, name) in camera_names.into_iter().enumerate() {
+            bindings.bind(BindingKind::Camera, index, name, &nodes);
+        }
+        for (index, name) in light_names.into_iter().enumerate() {
+            match lights_by_extension.remove(&index) {
+                Some(path) => {
+                    bindings.lights.insert(index, path);
+                }
+                None => bindings.bind(BindingKind::Light, index, name, &nodes),
+            }
+        }
+        bindings
+    }
+}
@@ -0,0 +1,341 @@
+//! Build a scene from Rust data, e.g. procedural geometry, for export.
+//!
+//! ```rust,no_run
+//! # use asset_importer::{material::MaterialBuilder, types::{Vector2D, Vector3D}};
+//! use asset_importer::scene::build::{MeshData, SceneBuilder, node};
+//!
+//! # fn run() -> asset_importer::Result<()> {
+//! let mut builder = SceneBuilder::new();
+//! let material = builder.add_material(MaterialBuilder::new().with_name("checker"));
+//! let quad = builder.add_mesh(MeshData {
+//!     name: "quad".into(),
+//!     positions: vec![
+//!         Vector3D::new(0.0, 0.0, 0.0),
+//!         Vector3D::new(1.0, 0.0, 0.0),
+//!         Vector3D::new(1.0, 1.0, 0.0),
+//!         Vector3D::new(0.0, 1.0, 0.0),
+//!     ],
+//!     uvs: Some(vec![
+//!         Vector2D::new(0.0, 0.0),
+//!         Vector2D::new(1.0, 0.0),
+//!         Vector2D::new(1.0, 1.0),
+//!         Vector2D::new(0.0, 1.0),
+//!     ]),
+//!     indices: vec![0, 1, 2, 0, 2, 3],
+//!     material_index: material,
+//!     ..Default::default()
+//! });
+//! builder.add_node(node("quad").with_meshes([quad]));
+//!
+//! let scene = builder.build()?;
+//! assert_eq!(scene.num_meshes(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::CString;
+
+use crate::{
+    error::{Error, Result},
+    material::MaterialBuilder,
+    scene::{OwnedScene, Scene},
+    sys,
+    types::{Matrix4x4, Vector2D, Vector3D, to_ai_matrix4x4},
+};
+
+/// Name given to the root node of built scenes.
+pub const ROOT_NODE_NAME: &str = "Root";
+
+/// Name of the material added when a scene has meshes but no materials, matching
+/// Assimp's `AI_DEFAULT_MATERIAL_NAME`.
+pub const DEFAULT_MATERIAL_NAME: &str = "DefaultMaterial";
+
+/// Vertex and index data of one triangle mesh, for [`SceneBuilder::add_mesh`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeshData {
+    /// Mesh name (may be empty)
+    pub name: String,
+    /// Vertex positions
+    pub positions: Vec<Vector3D>,
+    /// Per-vertex normals, same length as `positions`
+    pub normals: Option<Vec<Vector3D>>,
+    /// Per-vertex texture coordinates for UV channel 0, same length as `positions`
+    pub uvs: Option<Vec<Vector2D>>,
+    /// Triangle list: three vertex indices per triangle
+    pub indices: Vec<u32>,
+    /// Index of a material added with [`SceneBuilder::add_material`]
+    pub material_index: usize,
+}
+
+/// A node to place under the root node, see [`node`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeBuilder {
+    name: String,
+    transform: Matrix4x4,
+    meshes: Vec<usize>,
+    children: Vec<NodeBuilder>,
+}
+
+/// Start a node named `name` with an identity transform, no meshes and no children.
+pub fn node<S: Into<String>>(name: S) -> NodeBuilder {
+    NodeBuilder::new(name)
+}
+
+impl NodeBuilder {
+    /// Same as [`node`].
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            transform: Matrix4x4::IDENTITY,
+            meshes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the transform relative to the parent node.
+    pub fn with_transform(mut self, transform: Matrix4x4) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Reference meshes by the indices returned from [`SceneBuilder::add_mesh`].
+    pub fn with_meshes<I: IntoIterator<Item = usize>>(mut self, meshes: I) -> Self {
+        self.meshes.extend(meshes);
+        self
+    }
+
+    /// Add a child node.
+    pub fn with_child(mut self, child: NodeBuilder) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+/// Collects meshes, materials and nodes and turns them into an [`OwnedScene`].
+///
+/// Nothing is checked until [`SceneBuilder::build`], which reports the first invalid
+/// index or mismatched attribute length it finds.
+#[derive(Debug, Clone, Default)]
+pub struct SceneBuilder {
+    meshes: Vec<MeshData>,
+    materials: Vec<MaterialBuilder>,
+    nodes: Vec<NodeBuilder>,
+}
+
+impl SceneBuilder {
+    /// Start an empty scene.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a mesh and return its index, for use in [`NodeBuilder::with_meshes`].
+    pub fn add_mesh(&mut self, mesh: MeshData) -> usize {
+        self.meshes.push(mesh);
+        self.meshes.len() - 1
+    }
+
+    /// Add a material and return its index, for use as [`MeshData::material_index`].
+    pub fn add_material(&mut self, material: MaterialBuilder) -> usize {
+        self.materials.push(material);
+        self.materials.len() - 1
+    }
+
+    /// Add a node (with its children) under the root node.
+    pub fn add_node(&mut self, node: NodeBuilder) -> &mut Self {
+        self.nodes.push(node);
+        self
+    }
+
+    /// Validate the data and allocate the Assimp scene.
+    ///
+    /// Meshes are written as triangle lists. If meshes were added without any material,
+    /// a [`DEFAULT_MATERIAL_NAME`] material is added so the scene stays exportable.
+    ///
+    /// Fails when a mesh has no vertices or triangles, an index list is not a multiple
+    /// of three, a vertex, material or mesh index is out of range, normals or UVs do
+    /// not match the vertex count, a name contains a NUL byte, or a material fails
+    /// [`MaterialBuilder::build`].
+    pub fn build(self) -> Result<OwnedScene> {
+        let mut materials = self
+            .materials
+            .into_iter()
+            .map(MaterialBuilder::build)
+            .collect::<Result<Vec<_>>>()?;
+        if materials.is_empty() && !self.meshes.is_empty() {
+            materials.push(
+                MaterialBuilder::new()
+                    .with_name(DEFAULT_MATERIAL_NAME)
+                    .build()?,
+            );
+        }
+        for (index, mesh) in self.meshes.iter().enumerate() {
+            check_mesh(index, mesh, materials.len())?;
+        }
+        for node in &self.nodes {
+            check_node(node, self.meshes.len())?;
+        }
+
+        let root_name = CString::new(ROOT_NODE_NAME).expect("root name has no NUL");
+        let scene_ptr = unsafe { sys::aiCreateSceneRust(root_name.as_ptr()) };
+        if scene_ptr.is_null() {
+            return Err(Error::from_bridge_or_assimp());
+        }
+        // Take ownership first so the scene is freed with aiFreeScene on every error path.
+        let mut owned = OwnedScene::from_scene(unsafe { Scene::from_raw_copied_sys(scene_ptr) }?);
+
+        for material in &materials {
+            owned.add_material(material)?;
+        }
+        let scene = owned.raw_mut()?;
+        for mesh in &self.meshes {
+            unsafe { add_mesh_sys(scene, mesh) }?;
+        }
+        let root = unsafe { (*scene).mRootNode };
+        for node in &self.nodes {
+            unsafe { add_node_sys(root, node) }?;
+        }
+        Ok(owned)
+    }
+}
+
+fn to_u32(value: usize, what: &str) -> Result<u32> {
+    u32::try_from(value)
+        .map_err(|_| Error::invalid_parameter(format!("{what} ({value}) does not fit in u32")))
+}
+
+fn check_mesh(index: usize, mesh: &MeshData, num_materials: usize) -> Result<()> {
+    let invalid = |message: String| {
+        Error::invalid_parameter(format!("Mesh {index} ('{}'): {message}", mesh.name))
+    };
+    let num_vertices = mesh.positions.len();
+    if num_vertices == 0 {
+        return Err(invalid("no vertex positions".to_string()));
+    }
+    to_u32(num_vertices, "vertex count")?;
+    if mesh.indices.is_empty() || mesh.indices.len() % 3 != 0 {
+        return Err(invalid(format!(
+            "{} indices do not form a triangle list",
+            mesh.indices.len()
+        )));
+    }
+    to_u32(mesh.indices.len(), "index count")?;
+    if let Some((position, vertex)) = mesh
+        .indices
+        .iter()
+        .enumerate()
+        .find(|&(_, &vertex)| vertex as usize >= num_vertices)
+    {
+        return Err(invalid(format!(
+            "index {vertex} at position {position} is out of range ({num_vertices} vertices)"
+        )));
+    }
+    if let Some(normals) = &mesh.normals {
+        if normals.len() != num_vertices {
+            return Err(invalid(format!(
+                "{} normals for {num_vertices} vertices",
+                normals.len()
+            )));
+        }
+    }
+    if let Some(uvs) = &mesh.uvs {
+        if uvs.len() != num_vertices {
+            return Err(invalid(format!(
+                "{} texture coordinates for {num_vertices} vertices",
+                uvs.len()
+            )));
+        }
+    }
+    if mesh.material_index >= num_materials {
+        return Err(invalid(format!(
+            "material index {} is out of range ({num_materials} materials)",
+            mesh.material_index
+        )));
+    }
+    if mesh.name.contains('\0') {
+        return Err(invalid("name contains a NUL byte".to_string()));
+    }
+    Ok(())
+}
+
+fn check_node(node: &NodeBuilder, num_meshes: usize) -> Result<()> {
+    if node.name.contains('\0') {
+        return Err(Error::invalid_parameter(format!(
+            "Node '{}': name contains a NUL byte",
+            node.name
+        )));
+    }
+    if let Some(&mesh) = node.meshes.iter().find(|&&mesh| mesh >= num_meshes) {
+        return Err(Error::invalid_parameter(format!(
+            "Node '{}': mesh index {mesh} is out of range ({num_meshes} meshes)",
+            node.name
+        )));
+    }
+    node.children
+        .iter()
+        .try_for_each(|child| check_node(child, num_meshes))
+}
+
+/// # Safety
+/// `scene` must come from `aiCreateSceneRust` and not be shared; `mesh` must have passed
+/// [`check_mesh`].
+unsafe fn add_mesh_sys(scene: *mut sys::aiScene, mesh: &MeshData) -> Result<()> {
+    let name = CString::new(mesh.name.as_str())
+        .map_err(|_| Error::invalid_parameter("Mesh name contains a NUL byte"))?;
+    let normals = mesh
+        .normals
+        .as_ref()
+        .map_or(std::ptr::null(), |n| n.as_ptr() as *const f32);
+    let uvs = mesh
+        .uvs
+        .as_ref()
+        .map_or(std::ptr::null(), |uv| uv.as_ptr() as *const f32);
+    // Vector3D and Vector2D are #[repr(C)] structs of f32, so the slices are flat float
+    // arrays of 3 and 2 components per vertex.
+    let added = unsafe {
+        sys::aiSceneAddMeshRust(
+            scene,
+            name.as_ptr(),
+            mesh.positions.as_ptr() as *const f32,
+            normals,
+            uvs,
+            mesh.positions.len() as u32,
+            mesh.indices.as_ptr(),
+            mesh.indices.len() as u32,
+            mesh.material_index as u32,
+        )
+    };
+    if added.is_null() {
+        return Err(Error::from_bridge_or_assimp());
+    }
+    Ok(())
+}
+
+/// # Safety
+/// `parent` must be a node of a scene from `aiCreateSceneRust` that is not shared;
+/// `node` must have passed [`check_node`].
+unsafe fn add_node_sys(parent: *mut sys::aiNode, node: &NodeBuilder) -> Result<()> {
+    let name = CString::new(node.name.as_str())
+        .map_err(|_| Error::invalid_parameter("Node name contains a NUL byte"))?;
+    let transform = to_ai_matrix4x4(node.transform);
+    let meshes = node
+        .meshes
+        .iter()
+        .map(|&mesh| to_u32(mesh, "mesh index"))
+        .collect::<Result<Vec<u32>>>()?;
+    let added = unsafe {
+        sys::aiNodeAddChildRust(
+            parent,
+            name.as_ptr(),
+            &transform,
+            meshes.as_ptr(),
+            meshes.len() as u32,
+        )
+    };
+    if added.is_null() {
+        return Err(Error::from_bridge_or_assimp());
+    }
+    for child in &node.children {
+        unsafe { add_node_sys(added, child) }?;
+    }
+    Ok(())
+}
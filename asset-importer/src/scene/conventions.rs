@@ -0,0 +1,185 @@
+//! Axis conventions and provenance that importers record in scene metadata.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! use asset_importer::scene::conventions::AxisDirection;
+//!
+//! # fn check(scene: &Scene) {
+//! if scene.up_axis() == Some(AxisDirection::PositiveZ) {
+//!     // rotate into a Y-up engine
+//! }
+//! println!("made with {:?}", scene.authoring_tool());
+//! # }
+//! ```
+
+use crate::{
+    metadata::{Metadata, MetadataEntry, common_metadata},
+    scene::Scene,
+    types::Vector3D,
+};
+
+/// A coordinate axis and the direction along it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AxisDirection {
+    /// +X
+    PositiveX,
+    /// -X
+    NegativeX,
+    /// +Y
+    PositiveY,
+    /// -Y
+    NegativeY,
+    /// +Z
+    PositiveZ,
+    /// -Z
+    NegativeZ,
+}
+
+impl AxisDirection {
+    /// All six directions, positive before negative, X before Y before Z.
+    pub const ALL: [Self; 6] = [
+        Self::PositiveX,
+        Self::NegativeX,
+        Self::PositiveY,
+        Self::NegativeY,
+        Self::PositiveZ,
+        Self::NegativeZ,
+    ];
+
+    /// Direction from an FBX-style axis index (0 = X, 1 = Y, 2 = Z) and sign.
+    pub fn from_index(axis: i64, sign: i64) -> Option<Self> {
+        let negative = sign < 0;
+        match (axis, negative) {
+            (0, false) => Some(Self::PositiveX),
+            (0, true) => Some(Self::NegativeX),
+            (1, false) => Some(Self::PositiveY),
+            (1, true) => Some(Self::NegativeY),
+            (2, false) => Some(Self::PositiveZ),
+            (2, true) => Some(Self::NegativeZ),
+            _ => None,
+        }
+    }
+
+    /// Unit vector pointing this way.
+    pub fn vector(self) -> Vector3D {
+        match self {
+            Self::PositiveX => Vector3D::new(1.0, 0.0, 0.0),
+            Self::NegativeX => Vector3D::new(-1.0, 0.0, 0.0),
+            Self::PositiveY => Vector3D::new(0.0, 1.0, 0.0),
+            Self::NegativeY => Vector3D::new(0.0, -1.0, 0.0),
+            Self::PositiveZ => Vector3D::new(0.0, 0.0, 1.0),
+            Self::NegativeZ => Vector3D::new(0.0, 0.0, -1.0),
+        }
+    }
+}
+
+/// Where the source asset came from, from [`Scene::source_format_hints`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceFormatHints {
+    /// `SourceAsset_Format`: the importer or format name
+    pub format: Option<String>,
+    /// `SourceAsset_FormatVersion`, such as an FBX or glTF version
+    pub format_version: Option<String>,
+    /// `SourceAsset_Generator`: the tool that wrote the file
+    pub generator: Option<String>,
+    /// `SourceAsset_Copyright`
+    pub copyright: Option<String>,
+}
+
+impl SourceFormatHints {
+    /// Whether no hint was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.format.is_none()
+            && self.format_version.is_none()
+            && self.generator.is_none()
+            && self.copyright.is_none()
+    }
+}
+
+/// Integer metadata value, whichever integer type the importer stored.
+fn integer(metadata: &Metadata, key: &str) -> Option<i64> {
+    match metadata.get(key)? {
+        MetadataEntry::Int32(v) => Some(*v as i64),
+        MetadataEntry::UInt32(v) => Some(*v as i64),
+        MetadataEntry::Int64(v) => Some(*v),
+        MetadataEntry::UInt64(v) => i64::try_from(*v).ok(),
+        _ => None,
+    }
+}
+
+/// An FBX-style `<name>` / `<name>Sign` axis pair; a missing sign counts as positive.
+fn fbx_axis(metadata: &Metadata, axis_key: &str, sign_key: &str) -> Option<AxisDirection> {
+    let axis = integer(metadata, axis_key)?;
+    AxisDirection::from_index(axis, integer(metadata, sign_key).unwrap_or(1))
+}
+
+impl Scene {
+    /// Format, version, generator and copyright recorded by the importer.
+    pub fn source_format_hints(&self) -> SourceFormatHints {
+        let Ok(Some(metadata)) = self.metadata_opt() else {
+            return SourceFormatHints::default();
+        };
+        let get = |key: &str| metadata.get_string(key).map(str::to_string);
+        SourceFormatHints {
+            format: get(common_metadata::SOURCE_FORMAT),
+            format_version: get(common_metadata::SOURCE_FORMAT_VERSION),
+            generator: get(common_metadata::SOURCE_GENERATOR),
+            copyright: get(common_metadata::SOURCE_COPYRIGHT),
+        }
+    }
+
+    /// The application that wrote the source file (`SourceAsset_Generator`), such as a
+    /// Collada `<authoring_tool>` or a glTF `asset.generator`.
+    pub fn authoring_tool(&self) -> Option<String> {
+        self.source_format_hints().generator
+    }
+
+    /// The up direction the source file declared.
+    ///
+    /// FBX records it as `UpAxis`/`UpAxisSign` metadata, which is read as-is. Assimp
+    /// rotates Collada scenes to Y-up and keeps no metadata, so for Collada the declared
+    /// axis is recovered from the rotation on the root node (this reports Y-up when the
+    /// file was imported with
+    /// [`COLLADA_IGNORE_UP_DIRECTION`](crate::config::COLLADA_IGNORE_UP_DIRECTION)).
+    /// glTF is Y-up by definition. Other formats carry no convention and return `None`.
+    pub fn up_axis(&self) -> Option<AxisDirection> {
+        if let Some(axis) = self
+            .metadata_opt()
+            .ok()
+            .flatten()
+            .and_then(|metadata| fbx_axis(&metadata, "UpAxis", "UpAxisSign"))
+        {
+            return Some(axis);
+        }
+        let importer = self.source_format()?.importer.name.to_ascii_lowercase();
+        if importer.contains("collada") {
+            self.collada_up_axis()
+        } else if importer.contains("gltf") || importer.contains("fbx") {
+            // Both default to Y-up when nothing says otherwise.
+            Some(AxisDirection::PositiveY)
+        } else {
+            None
+        }
+    }
+
+    /// The forward direction from FBX `FrontAxis`/`FrontAxisSign` metadata.
+    pub fn front_axis(&self) -> Option<AxisDirection> {
+        let metadata = self.metadata_opt().ok().flatten()?;
+        fbx_axis(&metadata, "FrontAxis", "FrontAxisSign")
+    }
+
+    /// The right direction from FBX `CoordAxis`/`CoordAxisSign` metadata.
+    pub fn right_axis(&self) -> Option<AxisDirection> {
+        let metadata = self.metadata_opt().ok().flatten()?;
+        fbx_axis(&metadata, "CoordAxis", "CoordAxisSign")
+    }
+
+    /// The file axis the root node rotates onto +Y.
+    fn collada_up_axis(&self) -> Option<AxisDirection> {
+        let root = self.root_node()?.transformation();
+        AxisDirection::ALL.into_iter().find(|axis| {
+            let up = root.mul_vec4(axis.vector().extend(0.0));
+            (up.x.abs() + (up.y - 1.0).abs() + up.z.abs()) < 1e-4
+        })
+    }
+}
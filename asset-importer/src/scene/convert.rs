@@ -0,0 +1,255 @@
+//! Convention changes applied to a scene after import.
+//!
+//! These do what Assimp's `FlipUVs`, `FlipWindingOrder` and `MakeLeftHanded` steps do, so
+//! a scene converted here matches one imported with
+//! [`PostProcessSteps::FLIP_UVS`](crate::postprocess::PostProcessSteps::FLIP_UVS) and
+//! friends, without importing again. They also work on scenes assembled with
+//! [`SceneBuilder`](crate::scene::build::SceneBuilder), where no import step ran.
+//!
+//! The scene is edited in place, so it has to be an [`OwnedScene`]; an imported
+//! [`Scene`](crate::Scene) is copied first with [`OwnedScene::copy_of`]:
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! use asset_importer::scene::{OwnedScene, convert};
+//!
+//! # fn run(scene: &Scene) -> asset_importer::Result<()> {
+//! let mut owned = OwnedScene::copy_of(scene)?;
+//! convert::to_left_handed(&mut owned)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{error::Result, ffi, scene::OwnedScene, sys, types::ai_string_bytes};
+
+/// Replace every V texture coordinate with `1 - v`, as
+/// [`PostProcessSteps::FLIP_UVS`](crate::postprocess::PostProcessSteps::FLIP_UVS) does.
+///
+/// Covers all UV channels of every mesh and anim mesh, and negates the V translation and
+/// the rotation of material UV transforms (`$tex.uvtrafo`).
+pub fn flip_uvs(scene: &mut OwnedScene) -> Result<()> {
+    let raw = unsafe { &mut *scene.raw_mut()? };
+    unsafe {
+        for_each_ptr(raw.mMeshes, raw.mNumMeshes, |mesh| {
+            flip_v(&mesh.mTextureCoords, mesh.mNumVertices);
+            for_each_ptr(mesh.mAnimMeshes, mesh.mNumAnimMeshes, |anim| {
+                flip_v(&anim.mTextureCoords, anim.mNumVertices);
+            });
+        });
+        for_each_property(raw, b"$tex.uvtrafo", |prop| {
+            update_property::<sys::aiUVTransform>(prop, |uv| {
+                uv.mTranslation.y = -uv.mTranslation.y;
+                uv.mRotation = -uv.mRotation;
+            });
+        });
+    }
+    Ok(())
+}
+
+/// Reverse the index order of every face, turning counter-clockwise winding into
+/// clockwise and back, as
+/// [`PostProcessSteps::FLIP_WINDING_ORDER`](crate::postprocess::PostProcessSteps::FLIP_WINDING_ORDER)
+/// does.
+pub fn flip_winding_order(scene: &mut OwnedScene) -> Result<()> {
+    let raw = unsafe { &mut *scene.raw_mut()? };
+    unsafe {
+        for_each_ptr(raw.mMeshes, raw.mNumMeshes, |mesh| {
+            for_each_mut(mesh.mFaces, mesh.mNumFaces, |face| {
+                with_slice_mut(face.mIndices, face.mNumIndices, <[u32]>::reverse);
+            });
+        });
+    }
+    Ok(())
+}
+
+/// Mirror the scene along Z, as
+/// [`PostProcessSteps::MAKE_LEFT_HANDED`](crate::postprocess::PostProcessSteps::MAKE_LEFT_HANDED)
+/// does.
+///
+/// Node transforms and bone offset matrices are mirrored at their local Z axis. Positions,
+/// normals, tangents and bitangents of meshes and anim meshes get their Z negated, and
+/// bitangents are then negated as a whole because the handedness of the tangent frame
+/// changes. Position and rotation keys, camera look-at vectors and material mapping axes
+/// (`$tex.mapaxis`) follow. Lights are left alone, like Assimp does.
+///
+/// Winding order and UVs are unchanged; [`to_left_handed`] applies all three steps.
+pub fn make_left_handed(scene: &mut OwnedScene) -> Result<()> {
+    let raw = unsafe { &mut *scene.raw_mut_uncached()? };
+    unsafe {
+        let mut stack = vec![raw.mRootNode];
+        while let Some(node) = stack.pop() {
+            let Some(node) = node.as_mut() else {
+                continue;
+            };
+            mirror_matrix(&mut node.mTransformation);
+            for_each_mut(node.mChildren, node.mNumChildren, |child| {
+                stack.push(*child)
+            });
+        }
+
+        for_each_ptr(raw.mMeshes, raw.mNumMeshes, |mesh| {
+            let n = mesh.mNumVertices;
+            mirror_vectors(
+                n,
+                mesh.mVertices,
+                mesh.mNormals,
+                mesh.mTangents,
+                mesh.mBitangents,
+            );
+            for_each_ptr(mesh.mAnimMeshes, mesh.mNumAnimMeshes, |anim| {
+                mirror_vectors(
+                    anim.mNumVertices,
+                    anim.mVertices,
+                    anim.mNormals,
+                    anim.mTangents,
+                    anim.mBitangents,
+                );
+            });
+            for_each_ptr(mesh.mBones, mesh.mNumBones, |bone| {
+                mirror_matrix(&mut bone.mOffsetMatrix);
+            });
+            if !mesh.mTangents.is_null() && !mesh.mBitangents.is_null() {
+                for_each_mut(mesh.mBitangents, n, |b| {
+                    (b.x, b.y, b.z) = (-b.x, -b.y, -b.z);
+                });
+            }
+        });
+
+        for_each_property(raw, b"$tex.mapaxis", |prop| {
+            update_property::<sys::aiVector3D>(prop, |axis| axis.z = -axis.z);
+        });
+
+        for_each_ptr(raw.mAnimations, raw.mNumAnimations, |animation| {
+            for_each_ptr(animation.mChannels, animation.mNumChannels, |channel| {
+                for_each_mut(channel.mPositionKeys, channel.mNumPositionKeys, |key| {
+                    key.mValue.z = -key.mValue.z;
+                });
+                for_each_mut(channel.mRotationKeys, channel.mNumRotationKeys, |key| {
+                    key.mValue.x = -key.mValue.x;
+                    key.mValue.y = -key.mValue.y;
+                });
+            });
+        });
+
+        for_each_ptr(raw.mCameras, raw.mNumCameras, |camera| {
+            let (position, look_at) = (camera.mPosition, &mut camera.mLookAt);
+            look_at.x = 2.0 * position.x - look_at.x;
+            look_at.y = 2.0 * position.y - look_at.y;
+            look_at.z = 2.0 * position.z - look_at.z;
+        });
+    }
+    Ok(())
+}
+
+/// Convert a right-handed scene to Direct3D-style left-handed conventions, like Assimp's
+/// `aiProcess_ConvertToLeftHanded` (the left-handed part of
+/// [`PostProcessSteps::TARGET_REALTIME_LEFT_HANDED`](crate::postprocess::PostProcessSteps::TARGET_REALTIME_LEFT_HANDED)):
+/// [`make_left_handed`], [`flip_uvs`] and [`flip_winding_order`].
+pub fn to_left_handed(scene: &mut OwnedScene) -> Result<()> {
+    make_left_handed(scene)?;
+    flip_uvs(scene)?;
+    flip_winding_order(scene)
+}
+
+/// Negate the local Z axis of a transform, then Z again in its rows, keeping the
+/// determinant positive (the meshes are mirrored instead).
+fn mirror_matrix(m: &mut sys::aiMatrix4x4) {
+    m.c1 = -m.c1;
+    m.c2 = -m.c2;
+    m.c4 = -m.c4;
+    m.a3 = -m.a3;
+    m.b3 = -m.b3;
+    m.d3 = -m.d3;
+}
+
+/// # Safety
+/// Each non-null pointer must be valid for `n` vectors that nothing else references.
+unsafe fn mirror_vectors(
+    n: u32,
+    positions: *mut sys::aiVector3D,
+    normals: *mut sys::aiVector3D,
+    tangents: *mut sys::aiVector3D,
+    bitangents: *mut sys::aiVector3D,
+) {
+    let mut arrays = vec![positions, normals];
+    // Assimp only treats the tangent frame as present when both arrays are.
+    if !tangents.is_null() && !bitangents.is_null() {
+        arrays.extend([tangents, bitangents]);
+    }
+    for array in arrays {
+        unsafe { for_each_mut(array, n, |v| v.z = -v.z) };
+    }
+}
+
+/// `v = 1 - v` on each UV channel, stopping at the first missing one as Assimp does.
+///
+/// # Safety
+/// Each non-null channel must be valid for `n` vectors that nothing else references.
+unsafe fn flip_v(channels: &[*mut sys::aiVector3D], n: u32) {
+    for &channel in channels.iter().take_while(|channel| !channel.is_null()) {
+        unsafe { for_each_mut(channel, n, |uv| uv.y = 1.0 - uv.y) };
+    }
+}
+
+/// Call `f` on every material property named `key`.
+///
+/// # Safety
+/// The scene's materials must be valid and not referenced elsewhere.
+unsafe fn for_each_property(
+    scene: &mut sys::aiScene,
+    key: &[u8],
+    mut f: impl FnMut(&mut sys::aiMaterialProperty),
+) {
+    unsafe {
+        for_each_ptr(scene.mMaterials, scene.mNumMaterials, |material| {
+            for_each_ptr(material.mProperties, material.mNumProperties, |prop| {
+                if ai_string_bytes(&prop.mKey) == key {
+                    f(prop);
+                }
+            });
+        });
+    }
+}
+
+/// Edit the `T` at the start of a property's data; properties too short for it are
+/// skipped.
+///
+/// # Safety
+/// `prop.mData` must be null or valid for `prop.mDataLength` bytes.
+unsafe fn update_property<T: Copy>(prop: &mut sys::aiMaterialProperty, f: impl FnOnce(&mut T)) {
+    if prop.mData.is_null() || (prop.mDataLength as usize) < std::mem::size_of::<T>() {
+        return;
+    }
+    // Property data is a byte buffer, so it may not be aligned for `T`.
+    let ptr = prop.mData.cast::<T>();
+    let mut value = unsafe { ptr.read_unaligned() };
+    f(&mut value);
+    unsafe { ptr.write_unaligned(value) };
+}
+
+/// # Safety
+/// `ptr` must be null or valid for `len` elements that nothing else references.
+unsafe fn with_slice_mut<T>(ptr: *mut T, len: u32, f: impl FnOnce(&mut [T])) {
+    let mut owner = ();
+    f(unsafe { ffi::slice_from_mut_ptr_len(&mut owner, ptr, len as usize) });
+}
+
+/// # Safety
+/// As for [`with_slice_mut`].
+unsafe fn for_each_mut<T>(ptr: *mut T, len: u32, f: impl FnMut(&mut T)) {
+    unsafe { with_slice_mut(ptr, len, |items| items.iter_mut().for_each(f)) };
+}
+
+/// Call `f` on each non-null entry of a pointer array.
+///
+/// # Safety
+/// As for [`with_slice_mut`], and each non-null entry must be valid and unaliased too.
+unsafe fn for_each_ptr<T>(array: *mut *mut T, len: u32, mut f: impl FnMut(&mut T)) {
+    unsafe {
+        for_each_mut(array, len, |item| {
+            if let Some(item) = item.as_mut() {
+                f(item);
+            }
+        });
+    }
+}
@@ -0,0 +1,538 @@
+//! Structural and numeric comparison of two scenes, for regression-testing asset
+//! pipelines across Assimp upgrades or post-processing changes.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! use asset_importer::scene::diff::{CompareOptions, compare};
+//!
+//! # fn check(before: &Scene, after: &Scene) {
+//! let diff = compare(before, after, CompareOptions::default());
+//! if !diff.is_empty() {
+//!     println!("{diff}");
+//! }
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::dump::{PropertyValue, property_value};
+use crate::{material::Material, mesh::Mesh, node::Node, raw, scene::Scene, sys, types::Vector3D};
+
+/// Tolerances and limits for [`compare`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompareOptions {
+    /// Largest per-component difference between vertex positions treated as equal
+    pub position_tolerance: f32,
+    /// Largest per-component difference between normals treated as equal
+    pub normal_tolerance: f32,
+    /// Largest per-component difference between texture coordinates treated as equal
+    pub uv_tolerance: f32,
+    /// Largest difference between float material properties (and animation
+    /// durations) treated as equal
+    pub property_tolerance: f64,
+    /// Stop comparing after this many differences
+    pub max_differences: usize,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        Self {
+            position_tolerance: 1e-5,
+            normal_tolerance: 1e-4,
+            uv_tolerance: 1e-5,
+            property_tolerance: 1e-6,
+            max_differences: 100,
+        }
+    }
+}
+
+impl CompareOptions {
+    /// Options that treat any numeric difference as a change.
+    pub fn exact() -> Self {
+        Self {
+            position_tolerance: 0.0,
+            normal_tolerance: 0.0,
+            uv_tolerance: 0.0,
+            property_tolerance: 0.0,
+            ..Self::default()
+        }
+    }
+
+    /// Set [`max_differences`](Self::max_differences).
+    pub fn with_max_differences(mut self, max: usize) -> Self {
+        self.max_differences = max;
+        self
+    }
+}
+
+/// What happened to the item at a [`Difference::path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DifferenceKind {
+    /// Present only in the second scene
+    Added,
+    /// Present only in the first scene
+    Removed,
+    /// Present in both with different values
+    Changed,
+}
+
+/// One difference found by [`compare`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// Location such as `meshes[2].normals[15]`, `materials[0].$clr.diffuse` or
+    /// `nodes["Root/Arm"]`
+    pub path: String,
+    /// Whether the item was added, removed or changed
+    pub kind: DifferenceKind,
+    /// Value in the first scene (`None` when added)
+    pub before: Option<String>,
+    /// Value in the second scene (`None` when removed)
+    pub after: Option<String>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.kind, &self.before, &self.after) {
+            (DifferenceKind::Added, _, Some(after)) => write!(f, "+ {}: {}", self.path, after),
+            (DifferenceKind::Removed, Some(before), _) => {
+                write!(f, "- {}: {}", self.path, before)
+            }
+            (_, before, after) => write!(
+                f,
+                "~ {}: {} -> {}",
+                self.path,
+                before.as_deref().unwrap_or("-"),
+                after.as_deref().unwrap_or("-")
+            ),
+        }
+    }
+}
+
+/// Differences between two scenes, from [`compare`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SceneDiff {
+    /// Differences in comparison order: meshes, materials, nodes, then animations
+    pub differences: Vec<Difference>,
+    /// Whether comparison stopped at [`CompareOptions::max_differences`]
+    pub truncated: bool,
+}
+
+impl SceneDiff {
+    /// Whether the scenes compared equal within the tolerances.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Number of differences listed.
+    pub fn len(&self) -> usize {
+        self.differences.len()
+    }
+
+    /// Differences whose path starts with `prefix`, such as `"meshes[0].uvs"`.
+    pub fn under<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a Difference> + 'a {
+        self.differences
+            .iter()
+            .filter(move |d| d.path.starts_with(prefix))
+    }
+}
+
+impl fmt::Display for SceneDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("Scenes are equal");
+        }
+        write!(f, "{} differences", self.differences.len())?;
+        if self.truncated {
+            f.write_str(" (stopped at the limit)")?;
+        }
+        for difference in &self.differences {
+            write!(f, "\n  {}", difference)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare `a` (before) with `b` (after).
+///
+/// Meshes, materials and animations are matched by index and nodes by their name path
+/// from the root. Vertex buffers are compared element by element only when both
+/// meshes have the same vertex count; otherwise the count change is reported instead.
+/// Material properties are matched by key, texture type and index.
+pub fn compare(a: &Scene, b: &Scene, options: CompareOptions) -> SceneDiff {
+    let mut diff = Collector {
+        diff: SceneDiff::default(),
+        max: options.max_differences,
+    };
+    compare_meshes(a, b, &options, &mut diff);
+    compare_materials(a, b, &options, &mut diff);
+    compare_nodes(a, b, &mut diff);
+    compare_animations(a, b, &options, &mut diff);
+    diff.diff
+}
+
+struct Collector {
+    diff: SceneDiff,
+    max: usize,
+}
+
+impl Collector {
+    fn is_full(&self) -> bool {
+        self.diff.truncated
+    }
+
+    fn push(
+        &mut self,
+        path: String,
+        kind: DifferenceKind,
+        before: Option<String>,
+        after: Option<String>,
+    ) {
+        if self.diff.differences.len() >= self.max {
+            self.diff.truncated = true;
+            return;
+        }
+        self.diff.differences.push(Difference {
+            path,
+            kind,
+            before,
+            after,
+        });
+    }
+
+    fn added(&mut self, path: String, after: String) {
+        self.push(path, DifferenceKind::Added, None, Some(after));
+    }
+
+    fn removed(&mut self, path: String, before: String) {
+        self.push(path, DifferenceKind::Removed, Some(before), None);
+    }
+
+    fn changed(&mut self, path: String, before: String, after: String) {
+        self.push(path, DifferenceKind::Changed, Some(before), Some(after));
+    }
+
+    /// Report a change of a value that compares with `==`.
+    fn field<T: PartialEq + fmt::Debug>(&mut self, path: impl FnOnce() -> String, a: T, b: T) {
+        if a != b {
+            self.changed(path(), format!("{:?}", a), format!("{:?}", b));
+        }
+    }
+
+    /// Report extra elements at the end of either sequence; returns the common length.
+    fn lengths(
+        &mut self,
+        prefix: &str,
+        a: usize,
+        b: usize,
+        describe: impl Fn(bool, usize) -> String,
+    ) -> usize {
+        for index in b..a {
+            self.removed(format!("{prefix}[{index}]"), describe(true, index));
+        }
+        for index in a..b {
+            self.added(format!("{prefix}[{index}]"), describe(false, index));
+        }
+        a.min(b)
+    }
+}
+
+fn compare_meshes(a: &Scene, b: &Scene, options: &CompareOptions, diff: &mut Collector) {
+    let describe = |scene: &Scene, index| {
+        scene
+            .mesh(index)
+            .map(|m| format!("{:?} ({} vertices)", m.name(), m.num_vertices()))
+            .unwrap_or_default()
+    };
+    let common = diff.lengths("meshes", a.num_meshes(), b.num_meshes(), |first, i| {
+        describe(if first { a } else { b }, i)
+    });
+    for index in 0..common {
+        if diff.is_full() {
+            return;
+        }
+        if let (Some(ma), Some(mb)) = (a.mesh(index), b.mesh(index)) {
+            compare_mesh(&format!("meshes[{index}]"), &ma, &mb, options, diff);
+        }
+    }
+}
+
+fn compare_mesh(prefix: &str, a: &Mesh, b: &Mesh, options: &CompareOptions, diff: &mut Collector) {
+    diff.field(|| format!("{prefix}.name"), a.name(), b.name());
+    diff.field(
+        || format!("{prefix}.material_index"),
+        a.material_index(),
+        b.material_index(),
+    );
+    diff.field(
+        || format!("{prefix}.faces.len()"),
+        a.num_faces(),
+        b.num_faces(),
+    );
+    if a.num_vertices() != b.num_vertices() {
+        diff.changed(
+            format!("{prefix}.vertices.len()"),
+            a.num_vertices().to_string(),
+            b.num_vertices().to_string(),
+        );
+        return;
+    }
+
+    compare_buffer(
+        &format!("{prefix}.positions"),
+        a.vertices_raw_opt(),
+        b.vertices_raw_opt(),
+        3,
+        options.position_tolerance,
+        diff,
+    );
+    compare_buffer(
+        &format!("{prefix}.normals"),
+        a.normals_raw_opt(),
+        b.normals_raw_opt(),
+        3,
+        options.normal_tolerance,
+        diff,
+    );
+    for channel in 0..sys::AI_MAX_NUMBER_OF_TEXTURECOORDS as usize {
+        let components = a
+            .num_uv_components(channel)
+            .max(b.num_uv_components(channel))
+            .unwrap_or(2) as usize;
+        compare_buffer(
+            &format!("{prefix}.uvs[{channel}]"),
+            a.texture_coords_raw_opt(channel),
+            b.texture_coords_raw_opt(channel),
+            components,
+            options.uv_tolerance,
+            diff,
+        );
+    }
+}
+
+/// Compare the first `components` components of two vector buffers of equal length.
+fn compare_buffer(
+    path: &str,
+    a: Option<&[raw::AiVector3D]>,
+    b: Option<&[raw::AiVector3D]>,
+    components: usize,
+    tolerance: f32,
+    diff: &mut Collector,
+) {
+    let (a, b) = match (a, b) {
+        (None, None) => return,
+        (Some(a), None) => return diff.removed(path.to_string(), format!("{} values", a.len())),
+        (None, Some(b)) => return diff.added(path.to_string(), format!("{} values", b.len())),
+        (Some(a), Some(b)) => (a, b),
+    };
+    for (index, (va, vb)) in a.iter().zip(b).enumerate() {
+        if diff.is_full() {
+            return;
+        }
+        let (va, vb) = (Vector3D::from(*va), Vector3D::from(*vb));
+        let (ca, cb) = ([va.x, va.y, va.z], [vb.x, vb.y, vb.z]);
+        let same = ca[..components]
+            .iter()
+            .zip(&cb[..components])
+            .all(|(&x, &y)| within(x as f64, y as f64, tolerance as f64));
+        if !same {
+            diff.changed(
+                format!("{path}[{index}]"),
+                format_vector(&ca[..components]),
+                format_vector(&cb[..components]),
+            );
+        }
+    }
+}
+
+/// `|a - b| <= tolerance`, with NaN equal only to NaN.
+fn within(a: f64, b: f64, tolerance: f64) -> bool {
+    (a.is_nan() && b.is_nan()) || (a - b).abs() <= tolerance
+}
+
+fn format_vector(components: &[f32]) -> String {
+    let parts: Vec<String> = components.iter().map(f32::to_string).collect();
+    format!("({})", parts.join(", "))
+}
+
+fn compare_materials(a: &Scene, b: &Scene, options: &CompareOptions, diff: &mut Collector) {
+    let describe = |scene: &Scene, index| {
+        scene
+            .material(index)
+            .map(|m| format!("{:?}", m.name()))
+            .unwrap_or_default()
+    };
+    let common = diff.lengths(
+        "materials",
+        a.num_materials(),
+        b.num_materials(),
+        |first, i| describe(if first { a } else { b }, i),
+    );
+    for index in 0..common {
+        if diff.is_full() {
+            return;
+        }
+        if let (Some(ma), Some(mb)) = (a.material(index), b.material(index)) {
+            compare_material(&format!("materials[{index}]"), &ma, &mb, options, diff);
+        }
+    }
+}
+
+/// A material property's key, texture type and index, and its decoded value.
+fn material_properties(material: &Material) -> BTreeMap<String, (PropertyValue, Vec<u8>)> {
+    material
+        .properties()
+        .map(|prop| {
+            let key = match prop.semantic() {
+                Some(semantic) => format!("{}({:?}, {})", prop.key_str(), semantic, prop.index()),
+                None => prop.key_string(),
+            };
+            (key, (property_value(&prop), prop.data().to_vec()))
+        })
+        .collect()
+}
+
+fn compare_material(
+    prefix: &str,
+    a: &Material,
+    b: &Material,
+    options: &CompareOptions,
+    diff: &mut Collector,
+) {
+    let (pa, pb) = (material_properties(a), material_properties(b));
+    for (key, (va, bytes_a)) in &pa {
+        let path = || format!("{prefix}.{key}");
+        match pb.get(key) {
+            None => diff.removed(path(), describe_value(va, bytes_a)),
+            Some((vb, bytes_b)) => {
+                if !values_equal(va, bytes_a, vb, bytes_b, options.property_tolerance) {
+                    diff.changed(
+                        path(),
+                        describe_value(va, bytes_a),
+                        describe_value(vb, bytes_b),
+                    );
+                }
+            }
+        }
+    }
+    for (key, (vb, bytes_b)) in &pb {
+        if !pa.contains_key(key) {
+            diff.added(format!("{prefix}.{key}"), describe_value(vb, bytes_b));
+        }
+    }
+}
+
+fn values_equal(
+    a: &PropertyValue,
+    bytes_a: &[u8],
+    b: &PropertyValue,
+    bytes_b: &[u8],
+    tolerance: f64,
+) -> bool {
+    match (a, b) {
+        (PropertyValue::String(a), PropertyValue::String(b)) => a == b,
+        (PropertyValue::Integers(a), PropertyValue::Integers(b)) => a == b,
+        (PropertyValue::Floats(a), PropertyValue::Floats(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(&x, &y)| within(x, y, tolerance))
+        }
+        (PropertyValue::Bytes, PropertyValue::Bytes) => bytes_a == bytes_b,
+        _ => false,
+    }
+}
+
+fn describe_value(value: &PropertyValue, bytes: &[u8]) -> String {
+    match value {
+        PropertyValue::String(s) => format!("{s:?}"),
+        PropertyValue::Integers(v) if v.len() == 1 => v[0].to_string(),
+        PropertyValue::Integers(v) => format!("{v:?}"),
+        PropertyValue::Floats(v) if v.len() == 1 => v[0].to_string(),
+        PropertyValue::Floats(v) => format!("{v:?}"),
+        PropertyValue::Bytes => format!("<{} bytes>", bytes.len()),
+    }
+}
+
+/// Child and mesh counts of every node, keyed by its name path from the root.
+///
+/// Siblings sharing a name get `#2`, `#3`, ... appended so each keeps its own entry.
+fn node_shape(scene: &Scene) -> BTreeMap<String, (usize, usize)> {
+    fn visit(node: &Node, path: String, out: &mut BTreeMap<String, (usize, usize)>) {
+        out.insert(path.clone(), (node.num_children(), node.num_meshes()));
+        let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+        for child in node.children() {
+            let name = child.name();
+            let count = seen.entry(name.clone()).or_insert(0);
+            *count += 1;
+            let child_path = match *count {
+                1 => format!("{path}/{name}"),
+                n => format!("{path}/{name}#{n}"),
+            };
+            visit(&child, child_path, out);
+        }
+    }
+
+    let mut out = BTreeMap::new();
+    if let Some(root) = scene.root_node() {
+        visit(&root, root.name(), &mut out);
+    }
+    out
+}
+
+fn compare_nodes(a: &Scene, b: &Scene, diff: &mut Collector) {
+    let (na, nb) = (node_shape(a), node_shape(b));
+    let describe =
+        |(children, meshes): &(usize, usize)| format!("{children} children, {meshes} meshes");
+    for (path, shape_a) in &na {
+        if diff.is_full() {
+            return;
+        }
+        match nb.get(path) {
+            None => diff.removed(format!("nodes[{path:?}]"), describe(shape_a)),
+            Some(shape_b) if shape_a != shape_b => diff.changed(
+                format!("nodes[{path:?}]"),
+                describe(shape_a),
+                describe(shape_b),
+            ),
+            Some(_) => {}
+        }
+    }
+    for (path, shape_b) in &nb {
+        if !na.contains_key(path) {
+            diff.added(format!("nodes[{path:?}]"), describe(shape_b));
+        }
+    }
+}
+
+fn compare_animations(a: &Scene, b: &Scene, options: &CompareOptions, diff: &mut Collector) {
+    let describe = |scene: &Scene, index| {
+        scene
+            .animation(index)
+            .map(|anim| format!("{:?} ({} channels)", anim.name(), anim.num_channels()))
+            .unwrap_or_default()
+    };
+    let common = diff.lengths(
+        "animations",
+        a.num_animations(),
+        b.num_animations(),
+        |first, i| describe(if first { a } else { b }, i),
+    );
+    for index in 0..common {
+        let (Some(aa), Some(ab)) = (a.animation(index), b.animation(index)) else {
+            continue;
+        };
+        let prefix = format!("animations[{index}]");
+        diff.field(|| format!("{prefix}.name"), aa.name(), ab.name());
+        diff.field(
+            || format!("{prefix}.channels.len()"),
+            aa.num_channels(),
+            ab.num_channels(),
+        );
+        let (da, db) = (aa.duration_in_seconds(), ab.duration_in_seconds());
+        if !within(da, db, options.property_tolerance) {
+            diff.changed(
+                format!("{prefix}.duration_seconds"),
+                da.to_string(),
+                db.to_string(),
+            );
+        }
+    }
+}
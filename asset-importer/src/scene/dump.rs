@@ -0,0 +1,593 @@
+//! Deterministic text and JSON dumps of a scene, for bug reports and diffing.
+//!
+//! The dump is produced in Rust from the regular accessors, so it is available on
+//! every build (no exporter needed) and does not change with the Assimp version of
+//! the exporters. Floats are printed with six fractional digits.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! use asset_importer::scene::dump::DumpFormat;
+//!
+//! # fn report(scene: &Scene) -> asset_importer::Result<()> {
+//! let mut out = Vec::new();
+//! scene.dump(DumpFormat::Json, &mut out)?;
+//! std::fs::write("scene.json", out).ok();
+//! scene.dump(DumpFormat::Text, &mut std::io::stdout())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Write as _;
+
+use crate::{
+    animation::Animation,
+    error::{Error, Result},
+    material::{Material, MaterialPropertyRef, PropertyTypeInfo},
+    mesh::Mesh,
+    node::Node,
+    scene::Scene,
+    types::Matrix4x4,
+};
+
+/// Output format of [`Scene::dump`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DumpFormat {
+    /// Indented, human-readable tree
+    Text,
+    /// Pretty-printed JSON with a fixed key order
+    Json,
+}
+
+impl Scene {
+    /// Write a deterministic description of the scene to `writer`.
+    ///
+    /// Covers the node hierarchy with transforms, per-mesh counts and bounds, material
+    /// properties and per-channel key counts of animations. Two dumps of the same scene
+    /// are byte-identical, which makes them suitable for golden files.
+    pub fn dump<W: std::io::Write>(&self, format: DumpFormat, writer: &mut W) -> Result<()> {
+        let out = match format {
+            DumpFormat::Text => TextDump::render(self),
+            DumpFormat::Json => json_scene(self).render(),
+        };
+        writer
+            .write_all(out.as_bytes())
+            .and_then(|()| writer.flush())
+            .map_err(|e| Error::io_error_with_source("Failed to write scene dump", e))
+    }
+}
+
+/// Format with fixed precision, without a sign on values that round to zero.
+fn fixed(value: f64) -> String {
+    if value.is_nan() {
+        return "nan".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "inf" } else { "-inf" }.to_string();
+    }
+    let s = format!("{value:.6}");
+    if s == "-0.000000" {
+        s[1..].to_string()
+    } else {
+        s
+    }
+}
+
+fn matrix_rows(m: &Matrix4x4) -> [[f32; 4]; 4] {
+    let cols = m.to_cols_array_2d();
+    std::array::from_fn(|r| std::array::from_fn(|c| cols[c][r]))
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct FaceCounts {
+    faces: usize,
+    points: usize,
+    lines: usize,
+    triangles: usize,
+    polygons: usize,
+}
+
+fn face_counts(mesh: &Mesh) -> FaceCounts {
+    let mut counts = FaceCounts::default();
+    for face in mesh.faces_raw_opt().unwrap_or(&[]) {
+        match face.mNumIndices {
+            0 => continue,
+            1 => counts.points += 1,
+            2 => counts.lines += 1,
+            3 => counts.triangles += 1,
+            _ => counts.polygons += 1,
+        }
+        counts.faces += 1;
+    }
+    counts
+}
+
+/// Decoded value of a material property.
+pub(super) enum PropertyValue {
+    String(String),
+    Integers(Vec<i64>),
+    Floats(Vec<f64>),
+    Bytes,
+}
+
+pub(super) fn property_value(prop: &MaterialPropertyRef) -> PropertyValue {
+    let data = prop.data();
+    match prop.type_info() {
+        PropertyTypeInfo::String => match prop.string_ref() {
+            Some(s) => PropertyValue::String(s.to_string_lossy()),
+            None => PropertyValue::Bytes,
+        },
+        PropertyTypeInfo::Integer => PropertyValue::Integers(
+            data.chunks_exact(4)
+                .map(|b| i32::from_ne_bytes([b[0], b[1], b[2], b[3]]) as i64)
+                .collect(),
+        ),
+        PropertyTypeInfo::Float => PropertyValue::Floats(
+            data.chunks_exact(4)
+                .map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]]) as f64)
+                .collect(),
+        ),
+        PropertyTypeInfo::Double => PropertyValue::Floats(
+            data.chunks_exact(8)
+                .map(|b| f64::from_ne_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+                .collect(),
+        ),
+        PropertyTypeInfo::Buffer | PropertyTypeInfo::Unknown(_) => PropertyValue::Bytes,
+    }
+}
+
+fn type_name(info: PropertyTypeInfo) -> String {
+    match info {
+        PropertyTypeInfo::Float => "float".to_string(),
+        PropertyTypeInfo::Double => "double".to_string(),
+        PropertyTypeInfo::String => "string".to_string(),
+        PropertyTypeInfo::Integer => "integer".to_string(),
+        PropertyTypeInfo::Buffer => "buffer".to_string(),
+        PropertyTypeInfo::Unknown(raw) => format!("unknown({raw})"),
+    }
+}
+
+fn text_list<T>(values: &[T], fmt: impl Fn(&T) -> String) -> String {
+    let items: Vec<String> = values.iter().map(fmt).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// Renderer for [`DumpFormat::Text`]; writes into a `String`, which cannot fail.
+struct TextDump {
+    out: String,
+}
+
+impl TextDump {
+    fn render(scene: &Scene) -> String {
+        let mut dump = Self { out: String::new() };
+        dump.scene(scene);
+        dump.out
+    }
+
+    fn line(&mut self, depth: usize, args: std::fmt::Arguments<'_>) {
+        for _ in 0..depth {
+            self.out.push_str("  ");
+        }
+        let _ = self.out.write_fmt(args);
+        self.out.push('\n');
+    }
+
+    fn scene(&mut self, scene: &Scene) {
+        self.line(0, format_args!("Scene"));
+        self.line(1, format_args!("flags: {:#x}", scene.flags()));
+        self.line(
+            1,
+            format_args!(
+                "meshes: {}, materials: {}, animations: {}, textures: {}, cameras: {}, lights: {}",
+                scene.num_meshes(),
+                scene.num_materials(),
+                scene.num_animations(),
+                scene.num_textures(),
+                scene.num_cameras(),
+                scene.num_lights()
+            ),
+        );
+
+        self.line(0, format_args!("Nodes"));
+        if let Some(root) = scene.root_node() {
+            self.node(&root, 1);
+        }
+
+        self.line(0, format_args!("Meshes"));
+        for (index, mesh) in scene.meshes().enumerate() {
+            self.mesh(index, &mesh);
+        }
+
+        self.line(0, format_args!("Materials"));
+        for (index, material) in scene.materials().enumerate() {
+            self.material(index, &material);
+        }
+
+        self.line(0, format_args!("Animations"));
+        for (index, animation) in scene.animations().enumerate() {
+            self.animation(index, &animation);
+        }
+    }
+
+    fn node(&mut self, node: &Node, depth: usize) {
+        self.line(depth, format_args!("{:?}", node.name_str()));
+        let transform = node.transformation();
+        if transform == Matrix4x4::IDENTITY {
+            self.line(depth + 1, format_args!("transform: identity"));
+        } else {
+            self.line(depth + 1, format_args!("transform:"));
+            for row in matrix_rows(&transform) {
+                let row = text_list(&row, |v| fixed(*v as f64));
+                self.line(depth + 2, format_args!("{row}"));
+            }
+        }
+        let meshes = node.mesh_indices_raw();
+        if !meshes.is_empty() {
+            let meshes = text_list(meshes, u32::to_string);
+            self.line(depth + 1, format_args!("meshes: {meshes}"));
+        }
+        for child in node.children() {
+            self.node(&child, depth + 1);
+        }
+    }
+
+    fn mesh(&mut self, index: usize, mesh: &Mesh) {
+        let counts = face_counts(mesh);
+        self.line(1, format_args!("[{index}] {:?}", mesh.name_str()));
+        self.line(
+            2,
+            format_args!(
+                "vertices: {}, faces: {} (points: {}, lines: {}, triangles: {}, polygons: {})",
+                mesh.num_vertices(),
+                counts.faces,
+                counts.points,
+                counts.lines,
+                counts.triangles,
+                counts.polygons
+            ),
+        );
+        self.line(
+            2,
+            format_args!(
+                "normals: {}, tangents: {}, uv channels: {}, color channels: {}",
+                mesh.has_normals(),
+                mesh.has_tangents(),
+                mesh.num_uv_channels(),
+                mesh.num_color_channels()
+            ),
+        );
+        self.line(
+            2,
+            format_args!(
+                "bones: {}, morph targets: {}, material: {}",
+                mesh.num_bones(),
+                mesh.num_anim_meshes(),
+                mesh.material_index()
+            ),
+        );
+        if let Some(aabb) = mesh.compute_aabb() {
+            self.line(
+                2,
+                format_args!(
+                    "bounds: min ({}, {}, {}) max ({}, {}, {})",
+                    fixed(aabb.min.x as f64),
+                    fixed(aabb.min.y as f64),
+                    fixed(aabb.min.z as f64),
+                    fixed(aabb.max.x as f64),
+                    fixed(aabb.max.y as f64),
+                    fixed(aabb.max.z as f64)
+                ),
+            );
+        }
+    }
+
+    fn material(&mut self, index: usize, material: &Material) {
+        self.line(1, format_args!("[{index}] {:?}", material.name()));
+        for prop in material.properties() {
+            let value = match property_value(&prop) {
+                PropertyValue::String(s) => format!("{s:?}"),
+                PropertyValue::Integers(v) if v.len() == 1 => v[0].to_string(),
+                PropertyValue::Integers(v) => text_list(&v, i64::to_string),
+                PropertyValue::Floats(v) if v.len() == 1 => fixed(v[0]),
+                PropertyValue::Floats(v) => text_list(&v, |f| fixed(*f)),
+                PropertyValue::Bytes => format!("<{} bytes>", prop.data().len()),
+            };
+            let key = prop.key_str();
+            match prop.semantic() {
+                Some(semantic) => self.line(
+                    2,
+                    format_args!("{key}[{semantic:?}, {}] = {value}", prop.index()),
+                ),
+                None => self.line(2, format_args!("{key} = {value}")),
+            }
+        }
+    }
+
+    fn animation(&mut self, index: usize, animation: &Animation) {
+        self.line(
+            1,
+            format_args!(
+                "[{index}] {:?} duration: {} ticks, {} ticks/s",
+                animation.name(),
+                fixed(animation.duration()),
+                fixed(animation.ticks_per_second())
+            ),
+        );
+        for channel in animation.channels() {
+            self.line(
+                2,
+                format_args!(
+                    "node {:?}: {} position, {} rotation, {} scaling keys",
+                    channel.node_name(),
+                    channel.num_position_keys(),
+                    channel.num_rotation_keys(),
+                    channel.num_scaling_keys()
+                ),
+            );
+        }
+        for channel in animation.mesh_channels() {
+            self.line(
+                2,
+                format_args!("mesh {:?}: {} keys", channel.name(), channel.num_keys()),
+            );
+        }
+        for channel in animation.morph_mesh_channels() {
+            self.line(
+                2,
+                format_args!("morph {:?}: {} keys", channel.name(), channel.num_keys()),
+            );
+        }
+    }
+}
+
+/// Minimal JSON tree; objects keep insertion order so the key order is fixed.
+enum Json {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn render(&self) -> String {
+        let mut out = String::new();
+        self.write(&mut out, 0);
+        out.push('\n');
+        out
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Json::Int(i) => out.push_str(&i.to_string()),
+            // JSON has no representation for NaN or infinities.
+            Json::Float(f) if !f.is_finite() => out.push_str("null"),
+            Json::Float(f) => out.push_str(&fixed(*f)),
+            Json::Str(s) => write_json_string(out, s),
+            Json::Array(items) if items.is_empty() => out.push_str("[]"),
+            Json::Array(items) if items.iter().all(Json::is_scalar) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    item.write(out, depth);
+                }
+                out.push(']');
+            }
+            Json::Array(items) => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    indent(out, depth + 1);
+                    item.write(out, depth + 1);
+                    out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                indent(out, depth);
+                out.push(']');
+            }
+            Json::Object(fields) if fields.is_empty() => out.push_str("{}"),
+            Json::Object(fields) => {
+                out.push_str("{\n");
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    indent(out, depth + 1);
+                    write_json_string(out, key);
+                    out.push_str(": ");
+                    value.write(out, depth + 1);
+                    out.push_str(if i + 1 < fields.len() { ",\n" } else { "\n" });
+                }
+                indent(out, depth);
+                out.push('}');
+            }
+        }
+    }
+
+    fn is_scalar(&self) -> bool {
+        !matches!(self, Json::Array(_) | Json::Object(_))
+    }
+}
+
+fn indent(out: &mut String, depth: usize) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+pub(crate) fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn json_count(n: usize) -> Json {
+    Json::Int(n as i64)
+}
+
+fn json_floats(values: impl IntoIterator<Item = f32>) -> Json {
+    Json::Array(values.into_iter().map(|v| Json::Float(v as f64)).collect())
+}
+
+fn json_scene(scene: &Scene) -> Json {
+    Json::Object(vec![
+        ("flags", Json::Int(scene.flags() as i64)),
+        ("textures", json_count(scene.num_textures())),
+        ("cameras", json_count(scene.num_cameras())),
+        ("lights", json_count(scene.num_lights())),
+        (
+            "root",
+            scene
+                .root_node()
+                .map_or(Json::Null, |root| json_node(&root)),
+        ),
+        (
+            "meshes",
+            Json::Array(scene.meshes().map(|m| json_mesh(&m)).collect()),
+        ),
+        (
+            "materials",
+            Json::Array(scene.materials().map(|m| json_material(&m)).collect()),
+        ),
+        (
+            "animations",
+            Json::Array(scene.animations().map(|a| json_animation(&a)).collect()),
+        ),
+    ])
+}
+
+fn json_node(node: &Node) -> Json {
+    let rows = matrix_rows(&node.transformation());
+    Json::Object(vec![
+        ("name", Json::Str(node.name())),
+        (
+            "transform",
+            Json::Array(rows.into_iter().map(json_floats).collect()),
+        ),
+        (
+            "meshes",
+            Json::Array(
+                node.mesh_indices_raw()
+                    .iter()
+                    .map(|&i| Json::Int(i as i64))
+                    .collect(),
+            ),
+        ),
+        (
+            "children",
+            Json::Array(node.children().map(|c| json_node(&c)).collect()),
+        ),
+    ])
+}
+
+fn json_mesh(mesh: &Mesh) -> Json {
+    let counts = face_counts(mesh);
+    let bounds = mesh.compute_aabb().map_or(Json::Null, |aabb| {
+        Json::Object(vec![
+            ("min", json_floats([aabb.min.x, aabb.min.y, aabb.min.z])),
+            ("max", json_floats([aabb.max.x, aabb.max.y, aabb.max.z])),
+        ])
+    });
+    Json::Object(vec![
+        ("name", Json::Str(mesh.name())),
+        ("vertices", json_count(mesh.num_vertices())),
+        ("faces", json_count(counts.faces)),
+        ("points", json_count(counts.points)),
+        ("lines", json_count(counts.lines)),
+        ("triangles", json_count(counts.triangles)),
+        ("polygons", json_count(counts.polygons)),
+        ("normals", Json::Bool(mesh.has_normals())),
+        ("tangents", Json::Bool(mesh.has_tangents())),
+        ("uv_channels", json_count(mesh.num_uv_channels())),
+        ("color_channels", json_count(mesh.num_color_channels())),
+        ("bones", json_count(mesh.num_bones())),
+        ("morph_targets", json_count(mesh.num_anim_meshes())),
+        ("material", json_count(mesh.material_index())),
+        ("bounds", bounds),
+    ])
+}
+
+fn json_material(material: &Material) -> Json {
+    let properties = material
+        .properties()
+        .map(|prop| {
+            let value = match property_value(&prop) {
+                PropertyValue::String(s) => Json::Str(s),
+                PropertyValue::Integers(v) => Json::Array(v.into_iter().map(Json::Int).collect()),
+                PropertyValue::Floats(v) => Json::Array(v.into_iter().map(Json::Float).collect()),
+                PropertyValue::Bytes => Json::Null,
+            };
+            Json::Object(vec![
+                ("key", Json::Str(prop.key_string())),
+                (
+                    "semantic",
+                    prop.semantic()
+                        .map_or(Json::Null, |s| Json::Str(format!("{s:?}"))),
+                ),
+                ("index", Json::Int(prop.index() as i64)),
+                ("type", Json::Str(type_name(prop.type_info()))),
+                ("size", json_count(prop.data().len())),
+                ("value", value),
+            ])
+        })
+        .collect();
+    Json::Object(vec![
+        ("name", Json::Str(material.name())),
+        ("properties", Json::Array(properties)),
+    ])
+}
+
+fn json_animation(animation: &Animation) -> Json {
+    let channels = animation
+        .channels()
+        .map(|c| {
+            Json::Object(vec![
+                ("node", Json::Str(c.node_name())),
+                ("position_keys", json_count(c.num_position_keys())),
+                ("rotation_keys", json_count(c.num_rotation_keys())),
+                ("scaling_keys", json_count(c.num_scaling_keys())),
+            ])
+        })
+        .collect();
+    let mesh_channels = animation
+        .mesh_channels()
+        .map(|c| {
+            Json::Object(vec![
+                ("name", Json::Str(c.name())),
+                ("keys", json_count(c.num_keys())),
+            ])
+        })
+        .collect();
+    let morph_channels = animation
+        .morph_mesh_channels()
+        .map(|c| {
+            Json::Object(vec![
+                ("name", Json::Str(c.name())),
+                ("keys", json_count(c.num_keys())),
+            ])
+        })
+        .collect();
+    Json::Object(vec![
+        ("name", Json::Str(animation.name())),
+        ("duration", Json::Float(animation.duration())),
+        (
+            "ticks_per_second",
+            Json::Float(animation.ticks_per_second()),
+        ),
+        ("channels", Json::Array(channels)),
+        ("mesh_channels", Json::Array(mesh_channels)),
+        ("morph_channels", Json::Array(morph_channels)),
+    ])
+}
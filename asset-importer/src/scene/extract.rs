@@ -0,0 +1,117 @@
+//! Extract part of a scene (a node subtree and/or a mesh subset) into a new scene.
+//!
+//! ```rust,no_run
+//! # use asset_importer::{Scene, scene::extract::SubSceneExtractor};
+//! # fn run(scene: &Scene) -> asset_importer::Result<()> {
+//! let sub = SubSceneExtractor::new(scene)
+//!     .with_node_subtree("ArmatureRoot")
+//!     .with_meshes([1, 3, 5])
+//!     .build()?;
+//! println!("kept {} meshes", sub.num_meshes());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::CString;
+
+use crate::{
+    error::{Error, Result},
+    scene::{OwnedScene, Scene, copy_scene_sys},
+    sys,
+};
+
+/// Builds a pruned deep copy of a scene.
+///
+/// The source scene is copied with `aiCopyScene` and the copy is pruned in place:
+/// - [`SubSceneExtractor::with_node_subtree`] makes the named node the new root. Its
+///   ancestors' transforms are baked into it, so world-space placement is preserved, and
+///   only meshes referenced by the subtree are kept.
+/// - [`SubSceneExtractor::with_meshes`] keeps only the listed meshes (by source index).
+///
+/// Materials and embedded textures referenced by the kept meshes are preserved and
+/// reindexed (including `*N` texture paths); unreferenced ones are dropped, as are
+/// lights, cameras and animation channels whose node is no longer in the hierarchy.
+#[derive(Debug, Clone)]
+pub struct SubSceneExtractor {
+    scene: Scene,
+    node: Option<String>,
+    meshes: Option<Vec<usize>>,
+}
+
+impl SubSceneExtractor {
+    /// Start extracting from `scene`. Without filters, `build` returns a full copy.
+    pub fn new(scene: &Scene) -> Self {
+        Self {
+            scene: scene.clone(),
+            node: None,
+            meshes: None,
+        }
+    }
+
+    /// Keep only the subtree rooted at the node named `name`.
+    pub fn with_node_subtree<S: Into<String>>(mut self, name: S) -> Self {
+        self.node = Some(name.into());
+        self
+    }
+
+    /// Keep only these meshes (indices into the source scene). Calls accumulate.
+    pub fn with_meshes<I: IntoIterator<Item = usize>>(mut self, meshes: I) -> Self {
+        self.meshes.get_or_insert_with(Vec::new).extend(meshes);
+        self
+    }
+
+    /// Copy and prune the scene.
+    pub fn build(&self) -> Result<OwnedScene> {
+        let num_meshes = self.scene.num_meshes();
+        let mesh_indices = match &self.meshes {
+            Some(meshes) => {
+                if let Some(&bad) = meshes.iter().find(|&&i| i >= num_meshes) {
+                    return Err(Error::invalid_parameter(format!(
+                        "Mesh index {} out of range (scene has {} meshes)",
+                        bad, num_meshes
+                    )));
+                }
+                meshes.iter().map(|&i| i as u32).collect()
+            }
+            None => Vec::new(),
+        };
+
+        let root_name = match &self.node {
+            Some(name) => {
+                let found = self
+                    .scene
+                    .root_node()
+                    .and_then(|root| root.find_by_name(name));
+                if found.is_none() {
+                    return Err(Error::invalid_parameter(format!(
+                        "Node not found: {}",
+                        name
+                    )));
+                }
+                Some(
+                    CString::new(name.as_str())
+                        .map_err(|_| Error::invalid_parameter("Node name contains NUL byte"))?,
+                )
+            }
+            None => None,
+        };
+
+        let copied = unsafe { copy_scene_sys(self.scene.as_raw_sys()) }?;
+        let scene_ptr = copied.as_ptr() as *mut sys::aiScene;
+        let ok = unsafe {
+            sys::aiPruneSceneRust(
+                scene_ptr,
+                root_name.as_ref().map_or(std::ptr::null(), |n| n.as_ptr()),
+                mesh_indices.as_ptr(),
+                mesh_indices.len(),
+                self.meshes.is_some(),
+            )
+        };
+        // Take ownership first so the copy is freed with aiFreeScene on either path.
+        let scene = unsafe { Scene::from_raw_copied_sys(scene_ptr) }?;
+        if !ok {
+            return Err(Error::from_bridge_or_assimp());
+        }
+        Ok(OwnedScene::from_scene(scene))
+    }
+}
@@ -0,0 +1,185 @@
+//! Name lookup tables for scenes queried by name many times.
+//!
+//! [`Node::find_by_name`] and [`Mesh::find_bone_by_name`](crate::mesh::Mesh::find_bone_by_name)
+//! walk the scene on every call. Tooling that resolves thousands of names (e.g. animation
+//! retargeting) can build a [`SceneNameIndex`] once instead:
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! # fn retarget(scene: &Scene, channels: &[&str]) -> asset_importer::Result<()> {
+//! let index = scene.build_name_index();
+//! for name in channels {
+//!     if let Some(node) = index.get_unique(name)? {
+//!         println!("{name} -> {:?}", node.path());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{
+    bone::Bone,
+    error::{Error, Result},
+    node::Node,
+    scene::Scene,
+};
+
+/// Node, mesh and bone names of a scene, from [`Scene::build_name_index`].
+///
+/// Names are compared as bytes (see [`Node::name_bytes`]). Every name maps to all of
+/// its matches so duplicates are never lost: nodes in depth-first pre-order (the order
+/// [`Node::find_by_name`] searches in), meshes and bones in scene order.
+pub struct SceneNameIndex<'a> {
+    scene: &'a Scene,
+    nodes: HashMap<Vec<u8>, Vec<(Node, Vec<usize>)>>,
+    meshes: HashMap<Vec<u8>, Vec<usize>>,
+    bones: HashMap<Vec<u8>, Vec<(usize, usize)>>,
+}
+
+impl<'a> SceneNameIndex<'a> {
+    fn build(scene: &'a Scene) -> Self {
+        let mut nodes: HashMap<Vec<u8>, Vec<(Node, Vec<usize>)>> = HashMap::new();
+        if let Some(root) = scene.root_node() {
+            let mut stack = vec![(root, Vec::new())];
+            while let Some((node, path)) = stack.pop() {
+                // Reversed so the first child is visited next, as in `find_by_name`.
+                for (index, child) in node.children().enumerate().rev() {
+                    let mut child_path = path.clone();
+                    child_path.push(index);
+                    stack.push((child, child_path));
+                }
+                nodes
+                    .entry(node.name_bytes().to_vec())
+                    .or_default()
+                    .push((node, path));
+            }
+        }
+
+        let mut meshes: HashMap<Vec<u8>, Vec<usize>> = HashMap::new();
+        let mut bones: HashMap<Vec<u8>, Vec<(usize, usize)>> = HashMap::new();
+        for (mesh_index, mesh) in scene.meshes().enumerate() {
+            meshes
+                .entry(mesh.name_bytes().to_vec())
+                .or_default()
+                .push(mesh_index);
+            for (bone_index, bone) in mesh.bones().enumerate() {
+                bones
+                    .entry(bone.name_bytes().to_vec())
+                    .or_default()
+                    .push((mesh_index, bone_index));
+            }
+        }
+
+        Self {
+            scene,
+            nodes,
+            meshes,
+            bones,
+        }
+    }
+
+    /// The scene this index was built from.
+    pub fn scene(&self) -> &'a Scene {
+        self.scene
+    }
+
+    /// The first node named `name` in depth-first pre-order.
+    pub fn node(&self, name: &str) -> Option<Node> {
+        self.nodes
+            .get(name.as_bytes())
+            .and_then(|matches| matches.first())
+            .map(|(node, _)| node.clone())
+    }
+
+    /// Every node named `name`, in depth-first pre-order.
+    pub fn nodes(&self, name: &str) -> impl ExactSizeIterator<Item = Node> + '_ {
+        self.node_matches(name).iter().map(|(node, _)| node.clone())
+    }
+
+    /// The [`Node::path`] of the first node named `name`.
+    pub fn node_path(&self, name: &str) -> Option<&[usize]> {
+        self.node_matches(name)
+            .first()
+            .map(|(_, path)| path.as_slice())
+    }
+
+    /// The only node named `name`, or `None` when there is none.
+    ///
+    /// Returns an [`Error::InvalidParameter`] naming the matching paths when several
+    /// nodes share the name.
+    pub fn get_unique(&self, name: &str) -> Result<Option<Node>> {
+        match self.node_matches(name) {
+            [] => Ok(None),
+            [(node, _)] => Ok(Some(node.clone())),
+            matches => Err(Error::invalid_parameter(format!(
+                "node name {name:?} is ambiguous: {} nodes at paths {:?}",
+                matches.len(),
+                matches.iter().map(|(_, path)| path).collect::<Vec<_>>()
+            ))),
+        }
+    }
+
+    /// Indices of the meshes named `name`; empty when there are none.
+    pub fn mesh_indices(&self, name: &str) -> &[usize] {
+        self.meshes
+            .get(name.as_bytes())
+            .map_or(&[], |indices| indices.as_slice())
+    }
+
+    /// `(mesh index, bone index)` of every bone named `name`, one per mesh the bone
+    /// influences.
+    pub fn bone(&self, name: &str) -> impl ExactSizeIterator<Item = (usize, usize)> + '_ {
+        self.bones
+            .get(name.as_bytes())
+            .map_or(&[][..], |matches| matches.as_slice())
+            .iter()
+            .copied()
+    }
+
+    /// The bones named `name`, resolved against the scene.
+    pub fn bones(&self, name: &str) -> impl Iterator<Item = Bone> + '_ {
+        self.bone(name)
+            .filter_map(|(mesh_index, bone_index)| self.scene.mesh(mesh_index)?.bone(bone_index))
+    }
+
+    /// Names shared by more than one node.
+    pub fn duplicate_node_names(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.nodes
+            .iter()
+            .filter(|(_, matches)| matches.len() > 1)
+            .map(|(name, _)| name.as_slice())
+    }
+
+    /// Number of distinct node names.
+    pub fn num_node_names(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn node_matches(&self, name: &str) -> &[(Node, Vec<usize>)] {
+        self.nodes
+            .get(name.as_bytes())
+            .map_or(&[], |matches| matches.as_slice())
+    }
+}
+
+impl std::fmt::Debug for SceneNameIndex<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SceneNameIndex")
+            .field("node_names", &self.nodes.len())
+            .field("mesh_names", &self.meshes.len())
+            .field("bone_names", &self.bones.len())
+            .finish()
+    }
+}
+
+impl Scene {
+    /// Index node, mesh and bone names for repeated lookups.
+    ///
+    /// Walks the node hierarchy and the meshes once; each lookup on the result is then a
+    /// hash map probe. Nothing is indexed unless this is called.
+    pub fn build_name_index(&self) -> SceneNameIndex<'_> {
+        SceneNameIndex::build(self)
+    }
+}
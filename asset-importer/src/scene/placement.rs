@@ -0,0 +1,219 @@
+//! Meshes, lights and cameras placed in world space.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! # fn load(scene: &Scene) {
+//! for item in scene.renderables() {
+//!     println!("draw mesh {} at {:?}", item.mesh_index, item.world_transform.w_axis);
+//! }
+//! for placed in scene.placed_lights() {
+//!     println!("light {} at {:?}", placed.index, placed.world_position());
+//! }
+//! # }
+//! ```
+
+use crate::{
+    camera::Camera,
+    light::Light,
+    node::Node,
+    scene::Scene,
+    types::{Matrix4x4, Name, Vector3D},
+};
+
+/// Depth-first pre-order walk yielding each node with its world transform.
+///
+/// Children are visited in stored order, so the sequence is deterministic.
+pub(crate) struct WorldNodes {
+    stack: Vec<(Node, Matrix4x4)>,
+}
+
+impl WorldNodes {
+    pub(crate) fn new(scene: &Scene) -> Self {
+        Self {
+            stack: scene
+                .root_node()
+                .map(|root| (root, Matrix4x4::IDENTITY))
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl Iterator for WorldNodes {
+    type Item = (Node, Matrix4x4);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, parent) = self.stack.pop()?;
+        let world = parent * node.transformation();
+        // Reversed so children are visited in declaration order.
+        for child in node.children().rev() {
+            self.stack.push((child, world));
+        }
+        Some((node, world))
+    }
+}
+
+/// A mesh drawn by a node, from [`Scene::renderables`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Renderable {
+    /// Index of the mesh in the scene
+    pub mesh_index: usize,
+    /// The node's transform accumulated from the root node
+    pub world_transform: Matrix4x4,
+    /// Name of the node that references the mesh
+    pub node_name: Name,
+}
+
+/// Iterator returned by [`Scene::renderables`].
+pub struct Renderables {
+    nodes: WorldNodes,
+    current: Option<(Node, Matrix4x4, usize)>,
+}
+
+impl Iterator for Renderables {
+    type Item = Renderable;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((node, world, next)) = &mut self.current {
+                if let Some(&mesh_index) = node.mesh_indices_raw().get(*next) {
+                    *next += 1;
+                    return Some(Renderable {
+                        mesh_index: mesh_index as usize,
+                        world_transform: *world,
+                        node_name: node.name_owned(),
+                    });
+                }
+            }
+            let (node, world) = self.nodes.next()?;
+            self.current = Some((node, world, 0));
+        }
+    }
+}
+
+/// A light with the world transform of its node, from [`Scene::placed_lights`].
+#[derive(Clone)]
+pub struct PlacedLight {
+    /// Index of the light in the scene
+    pub index: usize,
+    /// The light; its position and directions are relative to the node
+    pub light: Light,
+    /// Name of the node the light is bound to
+    pub node_name: Name,
+    /// The node's transform accumulated from the root node
+    pub world_transform: Matrix4x4,
+}
+
+impl PlacedLight {
+    /// The light's position in world space.
+    pub fn world_position(&self) -> Vector3D {
+        self.world_transform.transform_point3(self.light.position())
+    }
+
+    /// The light's direction in world space (not normalized).
+    pub fn world_direction(&self) -> Vector3D {
+        transform_vector(self.world_transform, self.light.direction())
+    }
+}
+
+/// A camera with the world transform of its node, from [`Scene::placed_cameras`].
+#[derive(Clone)]
+pub struct PlacedCamera {
+    /// Index of the camera in the scene
+    pub index: usize,
+    /// The camera; its position and directions are relative to the node
+    pub camera: Camera,
+    /// Name of the node the camera is bound to
+    pub node_name: Name,
+    /// The node's transform accumulated from the root node
+    pub world_transform: Matrix4x4,
+}
+
+impl PlacedCamera {
+    /// The camera's position in world space.
+    pub fn world_position(&self) -> Vector3D {
+        self.world_transform
+            .transform_point3(self.camera.position())
+    }
+
+    /// The camera's viewing direction in world space (not normalized).
+    pub fn world_look_at(&self) -> Vector3D {
+        transform_vector(self.world_transform, self.camera.look_at())
+    }
+
+    /// The camera's up vector in world space (not normalized).
+    pub fn world_up(&self) -> Vector3D {
+        transform_vector(self.world_transform, self.camera.up())
+    }
+}
+
+fn transform_vector(matrix: Matrix4x4, v: Vector3D) -> Vector3D {
+    let out = matrix.mul_vec4(v.extend(0.0));
+    Vector3D::new(out.x, out.y, out.z)
+}
+
+impl Scene {
+    /// Every (mesh, world transform) pair in one depth-first pass over the nodes.
+    ///
+    /// Nodes are visited in pre-order with children in stored order, and each node's
+    /// meshes in the order it lists them, so the sequence is stable across calls. Nodes
+    /// without meshes (helpers, bones, light and camera anchors) produce nothing.
+    pub fn renderables(&self) -> Renderables {
+        Renderables {
+            nodes: WorldNodes::new(self),
+            current: None,
+        }
+    }
+
+    /// Lights bound to a node, with the node's world transform, in light index order.
+    ///
+    /// Nodes are resolved by [`Scene::bind_cameras_and_lights`]; lights without a node
+    /// are left out.
+    pub fn placed_lights(&self) -> Vec<PlacedLight> {
+        self.bind_cameras_and_lights()
+            .lights
+            .into_iter()
+            .filter_map(|(index, path)| {
+                let (node, world_transform) = self.world_at_path(&path)?;
+                Some(PlacedLight {
+                    index,
+                    light: self.light(index)?,
+                    node_name: node.name_owned(),
+                    world_transform,
+                })
+            })
+            .collect()
+    }
+
+    /// Cameras bound to a node, with the node's world transform, in camera index order.
+    ///
+    /// Nodes are resolved by [`Scene::bind_cameras_and_lights`]; cameras without a node
+    /// are left out.
+    pub fn placed_cameras(&self) -> Vec<PlacedCamera> {
+        self.bind_cameras_and_lights()
+            .cameras
+            .into_iter()
+            .filter_map(|(index, path)| {
+                let (node, world_transform) = self.world_at_path(&path)?;
+                Some(PlacedCamera {
+                    index,
+                    camera: self.camera(index)?,
+                    node_name: node.name_owned(),
+                    world_transform,
+                })
+            })
+            .collect()
+    }
+
+    /// The node at `path` (see [`Node::path`]) and its world transform.
+    fn world_at_path(&self, path: &[usize]) -> Option<(Node, Matrix4x4)> {
+        let root = self.root_node()?;
+        let world = root.transformation();
+        path.iter()
+            .try_fold((root, world), |(node, world), &index| {
+                let child = node.child(index)?;
+                let world = world * child.transformation();
+                Some((child, world))
+            })
+    }
+}
@@ -0,0 +1,272 @@
+//! Scene-wide skinning statistics and budget checks.
+//!
+//! Asset pipelines usually cap the bones per mesh and the influences per vertex, and
+//! expect weights to sum to one. [`Scene::skinning_statistics`] aggregates the
+//! per-mesh [`SkinningReport`]s and lists every skinned mesh that breaks the
+//! [`SkinningLimits`]:
+//!
+//! ```rust,no_run
+//! # use asset_importer::{Scene, scene::skinning::SkinningLimits};
+//! # fn check(scene: &Scene) {
+//! let limits = SkinningLimits::default()
+//!     .with_max_influences(4)
+//!     .with_max_bones_per_mesh(256)
+//!     .with_normalized_weights(true);
+//! let stats = scene.skinning_statistics(&limits);
+//! assert!(stats.passed(), "{stats}");
+//! # }
+//! ```
+
+use std::{collections::HashSet, fmt};
+
+use crate::{mesh::skinning::SkinningReport, scene::Scene};
+
+/// Budget a scene's skinning data is checked against; `None` means unlimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SkinningLimits {
+    /// Most bones a single mesh may reference
+    pub max_bones_per_mesh: Option<usize>,
+    /// Most bones that may influence a single vertex
+    pub max_influences: Option<usize>,
+    /// Whether every vertex of a skinned mesh must be weighted with weights summing to one
+    pub require_normalized: bool,
+}
+
+impl SkinningLimits {
+    /// Limit the number of bones per mesh.
+    pub fn with_max_bones_per_mesh(mut self, max: usize) -> Self {
+        self.max_bones_per_mesh = Some(max);
+        self
+    }
+
+    /// Limit the number of influences per vertex.
+    pub fn with_max_influences(mut self, max: usize) -> Self {
+        self.max_influences = Some(max);
+        self
+    }
+
+    /// Require normalized weights, see [`SkinningReport::is_normalized`].
+    pub fn with_normalized_weights(mut self, require: bool) -> Self {
+        self.require_normalized = require;
+        self
+    }
+}
+
+/// Skinning data of one skinned mesh, see [`SkinningStats::meshes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeshSkinningStats {
+    /// Index of the mesh in the scene
+    pub mesh_index: usize,
+    /// Mesh name (may be empty)
+    pub name: String,
+    /// The mesh's [`Mesh::validate_skinning`](crate::mesh::Mesh::validate_skinning) report
+    pub report: SkinningReport,
+    /// Vertices influenced by more bones than [`SkinningLimits::max_influences`]
+    pub vertices_over_limit: usize,
+}
+
+/// A limit broken by one mesh, see [`SkinningStats::violations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkinningViolationKind {
+    /// The mesh references more bones than allowed
+    TooManyBones {
+        /// Bones in the mesh
+        bones: usize,
+        /// The limit
+        limit: usize,
+    },
+    /// Some vertices are influenced by more bones than allowed
+    TooManyInfluences {
+        /// Vertices over the limit
+        vertices: usize,
+        /// Largest influence count of a vertex
+        max_influences: usize,
+        /// The limit
+        limit: usize,
+    },
+    /// Some vertices are unweighted or their weights do not sum to one
+    UnnormalizedWeights {
+        /// Vertices without any weight
+        unweighted: usize,
+        /// Vertices with a weight sum outside `[0.99, 1.01]`
+        unnormalized: usize,
+    },
+}
+
+/// One mesh breaking one of the [`SkinningLimits`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkinningViolation {
+    /// Index of the mesh in the scene
+    pub mesh_index: usize,
+    /// Mesh name (may be empty)
+    pub mesh_name: String,
+    /// Which limit was broken, and by how much
+    pub kind: SkinningViolationKind,
+}
+
+impl fmt::Display for SkinningViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mesh {} '{}': ", self.mesh_index, self.mesh_name)?;
+        match self.kind {
+            SkinningViolationKind::TooManyBones { bones, limit } => {
+                write!(f, "{bones} bones (limit {limit})")
+            }
+            SkinningViolationKind::TooManyInfluences {
+                vertices,
+                max_influences,
+                limit,
+            } => write!(
+                f,
+                "{vertices} vertices over {limit} influences (max {max_influences})"
+            ),
+            SkinningViolationKind::UnnormalizedWeights {
+                unweighted,
+                unnormalized,
+            } => write!(
+                f,
+                "{unweighted} unweighted and {unnormalized} unnormalized vertices"
+            ),
+        }
+    }
+}
+
+/// Skinning statistics for a whole scene, from [`Scene::skinning_statistics`].
+///
+/// Meshes without bones are not skinned and are left out of every count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkinningStats {
+    /// Per skinned mesh breakdown, in mesh order
+    pub meshes: Vec<MeshSkinningStats>,
+    /// Largest bone count of a single mesh
+    pub max_bones_per_mesh: usize,
+    /// Distinct bone names over all meshes (a bone shared by two meshes counts once)
+    pub unique_bones: usize,
+    /// Largest number of bones influencing a single vertex
+    pub max_influences: usize,
+    /// Vertices over [`SkinningLimits::max_influences`], over all meshes (0 without a limit)
+    pub vertices_over_limit: usize,
+    /// The limits the scene was checked against
+    pub limits: SkinningLimits,
+    /// Every broken limit, in mesh order
+    pub violations: Vec<SkinningViolation>,
+}
+
+impl SkinningStats {
+    /// Whether no mesh breaks the limits.
+    pub fn passed(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Violations of the mesh named `name`.
+    pub fn violations_for<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a SkinningViolation> + 'a {
+        self.violations.iter().filter(move |v| v.mesh_name == name)
+    }
+}
+
+impl Scene {
+    /// Aggregate skinning data over all meshes and check it against `limits`.
+    ///
+    /// Each mesh's bone weights are transposed to per-vertex influences once (see
+    /// [`Mesh::vertex_influences`](crate::mesh::Mesh::vertex_influences)); the cost is
+    /// one allocation per vertex of every skinned mesh.
+    pub fn skinning_statistics(&self, limits: &SkinningLimits) -> SkinningStats {
+        let mut stats = SkinningStats {
+            meshes: Vec::new(),
+            max_bones_per_mesh: 0,
+            unique_bones: 0,
+            max_influences: 0,
+            vertices_over_limit: 0,
+            limits: *limits,
+            violations: Vec::new(),
+        };
+        let mut bone_names = HashSet::new();
+
+        for (mesh_index, mesh) in self.meshes().enumerate() {
+            if !mesh.has_bones() {
+                continue;
+            }
+            bone_names.extend(mesh.bone_names());
+            let influences = mesh.vertex_influences();
+            let report = mesh.skinning_report(&influences);
+            let vertices_over_limit = limits.max_influences.map_or(0, |limit| {
+                influences.iter().filter(|v| v.len() > limit).count()
+            });
+            let name = mesh.name();
+
+            let mut violation = |kind| {
+                stats.violations.push(SkinningViolation {
+                    mesh_index,
+                    mesh_name: name.clone(),
+                    kind,
+                })
+            };
+            if let Some(limit) = limits
+                .max_bones_per_mesh
+                .filter(|&limit| report.num_bones > limit)
+            {
+                violation(SkinningViolationKind::TooManyBones {
+                    bones: report.num_bones,
+                    limit,
+                });
+            }
+            if let Some(limit) = limits.max_influences.filter(|_| vertices_over_limit > 0) {
+                violation(SkinningViolationKind::TooManyInfluences {
+                    vertices: vertices_over_limit,
+                    max_influences: report.max_influences,
+                    limit,
+                });
+            }
+            if limits.require_normalized && !report.is_normalized() {
+                violation(SkinningViolationKind::UnnormalizedWeights {
+                    unweighted: report.unweighted_vertices,
+                    unnormalized: report.unnormalized_vertices,
+                });
+            }
+
+            stats.max_bones_per_mesh = stats.max_bones_per_mesh.max(report.num_bones);
+            stats.max_influences = stats.max_influences.max(report.max_influences);
+            stats.vertices_over_limit += vertices_over_limit;
+            stats.meshes.push(MeshSkinningStats {
+                mesh_index,
+                name,
+                report,
+                vertices_over_limit,
+            });
+        }
+        stats.unique_bones = bone_names.len();
+        stats
+    }
+}
+
+impl fmt::Display for SkinningStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = |limit: Option<usize>| limit.map_or("-".to_string(), |l| l.to_string());
+        if self.passed() {
+            writeln!(f, "Skinning: PASS")?;
+        } else {
+            writeln!(f, "Skinning: FAIL ({} violations)", self.violations.len())?;
+        }
+        writeln!(f, "Skinned meshes:     {}", self.meshes.len())?;
+        writeln!(f, "Unique bones:       {}", self.unique_bones)?;
+        writeln!(
+            f,
+            "Max bones per mesh: {} (limit {})",
+            self.max_bones_per_mesh,
+            limit(self.limits.max_bones_per_mesh)
+        )?;
+        write!(
+            f,
+            "Max influences:     {} (limit {}, {} vertices over)",
+            self.max_influences,
+            limit(self.limits.max_influences),
+            self.vertices_over_limit
+        )?;
+        for violation in &self.violations {
+            write!(f, "\n  FAIL {violation}")?;
+        }
+        Ok(())
+    }
+}
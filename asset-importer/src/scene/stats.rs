@@ -0,0 +1,208 @@
+//! Scene statistics for validation tools and dashboards.
+//!
+//! ```rust,no_run
+//! # use asset_importer::Scene;
+//! # fn report(scene: &Scene) {
+//! let stats = scene.statistics();
+//! println!("{stats}");
+//! assert!(stats.triangles <= stats.faces);
+//! # }
+//! ```
+
+use std::fmt;
+
+use crate::scene::Scene;
+
+/// Vertex and primitive counts for one mesh, see [`SceneStatistics::meshes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeshStatistics {
+    /// Mesh name (may be empty)
+    pub name: String,
+    /// Number of vertices
+    pub vertices: usize,
+    /// Number of faces of any size
+    pub faces: usize,
+    /// Faces with one index
+    pub points: usize,
+    /// Faces with two indices
+    pub lines: usize,
+    /// Faces with three indices
+    pub triangles: usize,
+    /// Faces with more than three indices
+    pub polygons: usize,
+    /// Number of bones
+    pub bones: usize,
+    /// Index of the mesh's material
+    pub material_index: usize,
+}
+
+/// Name and length of one animation, see [`SceneStatistics::animations`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationStatistics {
+    /// Animation name (may be empty)
+    pub name: String,
+    /// Duration in seconds (ticks divided by ticks per second)
+    pub duration_seconds: f64,
+    /// Number of node animation channels
+    pub channels: usize,
+}
+
+/// Summary counts for a scene, from [`Scene::statistics`].
+///
+/// Primitive counts come from the face data, so an untriangulated scene reports its
+/// quads and n-gons under `polygons` rather than as triangles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneStatistics {
+    /// Per-mesh breakdown, in mesh order
+    pub meshes: Vec<MeshStatistics>,
+    /// Vertices over all meshes
+    pub vertices: usize,
+    /// Faces over all meshes
+    pub faces: usize,
+    /// Point faces over all meshes
+    pub points: usize,
+    /// Line faces over all meshes
+    pub lines: usize,
+    /// Triangle faces over all meshes
+    pub triangles: usize,
+    /// Polygon faces (more than three indices) over all meshes
+    pub polygons: usize,
+    /// Bones over all meshes (a bone shared by two meshes counts twice)
+    pub bones: usize,
+    /// Number of materials
+    pub materials: usize,
+    /// Number of embedded textures
+    pub textures: usize,
+    /// Payload size of all embedded textures in bytes (compressed size, or 4 bytes per
+    /// texel for uncompressed textures)
+    pub texture_bytes: usize,
+    /// Per-animation durations
+    pub animations: Vec<AnimationStatistics>,
+    /// Number of cameras
+    pub cameras: usize,
+    /// Number of lights
+    pub lights: usize,
+    /// Number of nodes in the hierarchy
+    pub nodes: usize,
+    /// Depth of the deepest node (the root node has depth 0)
+    pub max_node_depth: usize,
+}
+
+impl Scene {
+    /// Count vertices, primitives, bones, materials, textures, animations and nodes.
+    ///
+    /// Reads face sizes and texture payload sizes through the zero-copy accessors, so
+    /// the cost is one pass over the face arrays and no per-vertex allocation.
+    pub fn statistics(&self) -> SceneStatistics {
+        let meshes: Vec<MeshStatistics> = self
+            .meshes()
+            .map(|mesh| {
+                let mut stats = MeshStatistics {
+                    name: mesh.name(),
+                    vertices: mesh.num_vertices(),
+                    faces: 0,
+                    points: 0,
+                    lines: 0,
+                    triangles: 0,
+                    polygons: 0,
+                    bones: mesh.num_bones(),
+                    material_index: mesh.material_index(),
+                };
+                for face in mesh.faces_raw_opt().unwrap_or(&[]) {
+                    match face.mNumIndices {
+                        0 => continue,
+                        1 => stats.points += 1,
+                        2 => stats.lines += 1,
+                        3 => stats.triangles += 1,
+                        _ => stats.polygons += 1,
+                    }
+                    stats.faces += 1;
+                }
+                stats
+            })
+            .collect();
+
+        let (nodes, max_node_depth) = self
+            .nodes_depth_first()
+            .with_depth()
+            .fold((0, 0), |(count, depth), (_, d)| (count + 1, depth.max(d)));
+
+        SceneStatistics {
+            vertices: meshes.iter().map(|m| m.vertices).sum(),
+            faces: meshes.iter().map(|m| m.faces).sum(),
+            points: meshes.iter().map(|m| m.points).sum(),
+            lines: meshes.iter().map(|m| m.lines).sum(),
+            triangles: meshes.iter().map(|m| m.triangles).sum(),
+            polygons: meshes.iter().map(|m| m.polygons).sum(),
+            bones: meshes.iter().map(|m| m.bones).sum(),
+            meshes,
+            materials: self.num_materials(),
+            textures: self.num_textures(),
+            texture_bytes: self.textures().map(|t| t.data_size()).sum(),
+            animations: self
+                .animations()
+                .map(|a| AnimationStatistics {
+                    name: a.name(),
+                    duration_seconds: a.duration_in_seconds(),
+                    channels: a.num_channels(),
+                })
+                .collect(),
+            cameras: self.num_cameras(),
+            lights: self.num_lights(),
+            nodes,
+            max_node_depth,
+        }
+    }
+}
+
+impl fmt::Display for SceneStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Meshes:            {}", self.meshes.len())?;
+        writeln!(f, "Vertices:          {}", self.vertices)?;
+        writeln!(
+            f,
+            "Faces:             {} ({} triangles, {} polygons, {} lines, {} points)",
+            self.faces, self.triangles, self.polygons, self.lines, self.points
+        )?;
+        writeln!(f, "Bones:             {}", self.bones)?;
+        writeln!(f, "Materials:         {}", self.materials)?;
+        writeln!(
+            f,
+            "Embedded textures: {} ({} bytes)",
+            self.textures, self.texture_bytes
+        )?;
+        writeln!(f, "Animations:        {}", self.animations.len())?;
+        writeln!(f, "Cameras:           {}", self.cameras)?;
+        writeln!(f, "Lights:            {}", self.lights)?;
+        write!(
+            f,
+            "Nodes:             {} (max depth {})",
+            self.nodes, self.max_node_depth
+        )?;
+
+        for (index, mesh) in self.meshes.iter().enumerate() {
+            write!(
+                f,
+                "\n  Mesh {} '{}': {} vertices, {} faces ({} tri, {} poly, {} line, {} point), {} bones, material {}",
+                index,
+                mesh.name,
+                mesh.vertices,
+                mesh.faces,
+                mesh.triangles,
+                mesh.polygons,
+                mesh.lines,
+                mesh.points,
+                mesh.bones,
+                mesh.material_index
+            )?;
+        }
+        for (index, animation) in self.animations.iter().enumerate() {
+            write!(
+                f,
+                "\n  Animation {} '{}': {:.3} s, {} channels",
+                index, animation.name, animation.duration_seconds, animation.channels
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,441 @@
+//! Owned, serializable snapshots of scene data (requires the `serde` feature).
+//!
+//! A [`SceneSnapshot`] copies mesh buffers, materials and the node tree out of Assimp
+//! memory so it can be cached or sent elsewhere without keeping the [`Scene`] alive.
+//! Vectors and matrices serialize as plain arrays; vertex and index buffers are
+//! little-endian byte buffers serialized through `serde_bytes`.
+//!
+//! ```rust,no_run
+//! # use asset_importer::{Scene, snapshot::SnapshotOptions};
+//! # fn cache(scene: &Scene) {
+//! let snapshot = scene.to_snapshot(SnapshotOptions::positions_only());
+//! let positions = snapshot.meshes[0].positions.to_f32_vec();
+//! # let _ = positions;
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    material::{Material, TextureType},
+    mesh::Mesh,
+    node::Node,
+    raw,
+    scene::Scene,
+    types::from_real,
+};
+
+/// Controls which attributes [`Scene::to_snapshot`] copies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotOptions {
+    /// Copy per-vertex normals.
+    pub normals: bool,
+    /// Copy tangents and bitangents.
+    pub tangents: bool,
+    /// Copy every populated UV channel.
+    pub uvs: bool,
+    /// Copy every populated vertex color channel.
+    pub colors: bool,
+    /// Copy face indices.
+    pub indices: bool,
+    /// Copy materials.
+    pub materials: bool,
+    /// Copy the node hierarchy.
+    pub nodes: bool,
+}
+
+impl SnapshotOptions {
+    /// Positions only: no other vertex attributes, faces, materials or nodes.
+    pub fn positions_only() -> Self {
+        Self {
+            normals: false,
+            tangents: false,
+            uvs: false,
+            colors: false,
+            indices: false,
+            materials: false,
+            nodes: false,
+        }
+    }
+
+    /// Everything the snapshot types can hold.
+    pub fn full() -> Self {
+        Self {
+            normals: true,
+            tangents: true,
+            uvs: true,
+            colors: true,
+            indices: true,
+            materials: true,
+            nodes: true,
+        }
+    }
+}
+
+impl Default for SnapshotOptions {
+    /// Positions, normals, UVs, indices, materials and nodes (no tangents or colors).
+    fn default() -> Self {
+        Self {
+            tangents: false,
+            colors: false,
+            ..Self::full()
+        }
+    }
+}
+
+/// Tightly packed `f32` vertex attribute data (little-endian).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttributeBuffer {
+    /// Number of `f32` components per vertex.
+    pub components: u32,
+    /// `components * vertex_count` little-endian `f32` values.
+    #[serde(with = "serde_bytes")]
+    pub data: Vec<u8>,
+}
+
+impl AttributeBuffer {
+    fn from_rows<const N: usize>(
+        rows: impl ExactSizeIterator<Item = [f32; N]>,
+        width: usize,
+    ) -> Self {
+        let width = width.min(N);
+        let mut data = Vec::with_capacity(rows.len() * width * std::mem::size_of::<f32>());
+        for row in rows {
+            for value in &row[..width] {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        Self {
+            components: width as u32,
+            data,
+        }
+    }
+
+    fn from_vectors(values: &[raw::AiVector3D], components: usize) -> Self {
+        Self::from_rows(
+            values
+                .iter()
+                .map(|v| [from_real(v.x), from_real(v.y), from_real(v.z)]),
+            components,
+        )
+    }
+
+    /// Number of vertices stored.
+    pub fn len(&self) -> usize {
+        let stride = self.components as usize * std::mem::size_of::<f32>();
+        self.data.len().checked_div(stride).unwrap_or(0)
+    }
+
+    /// Whether the buffer holds no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Component `component` of vertex `index`.
+    pub fn component(&self, index: usize, component: usize) -> Option<f32> {
+        if component >= self.components as usize {
+            return None;
+        }
+        let offset = (index * self.components as usize + component) * 4;
+        let bytes = self.data.get(offset..offset + 4)?;
+        Some(f32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    /// Decode all values into a flat `f32` vector.
+    pub fn to_f32_vec(&self) -> Vec<f32> {
+        self.data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect()
+    }
+}
+
+/// Little-endian `u32` values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexBuffer {
+    /// Packed little-endian `u32` values.
+    #[serde(with = "serde_bytes")]
+    pub data: Vec<u8>,
+}
+
+impl IndexBuffer {
+    fn push(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Number of values stored.
+    pub fn len(&self) -> usize {
+        self.data.len() / 4
+    }
+
+    /// Whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.len() < 4
+    }
+
+    /// Decode all values.
+    pub fn to_u32_vec(&self) -> Vec<u32> {
+        self.data
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect()
+    }
+}
+
+/// Owned copy of a mesh.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeshSnapshot {
+    /// Mesh name.
+    pub name: String,
+    /// Index into [`SceneSnapshot::materials`] (when materials were copied).
+    pub material_index: u32,
+    /// Number of vertices.
+    pub num_vertices: u32,
+    /// Positions (3 components).
+    pub positions: AttributeBuffer,
+    /// Normals (3 components).
+    pub normals: Option<AttributeBuffer>,
+    /// Tangents (3 components).
+    pub tangents: Option<AttributeBuffer>,
+    /// Bitangents (3 components).
+    pub bitangents: Option<AttributeBuffer>,
+    /// Populated UV channels as `(channel, buffer)`; buffers have 1-3 components.
+    pub uv_channels: Vec<(u32, AttributeBuffer)>,
+    /// Populated vertex color channels as `(channel, buffer)` with RGBA components.
+    pub color_channels: Vec<(u32, AttributeBuffer)>,
+    /// Face indices, concatenated in face order.
+    pub indices: IndexBuffer,
+    /// Number of indices of each face (all 3 for triangulated meshes).
+    pub face_sizes: IndexBuffer,
+}
+
+impl MeshSnapshot {
+    fn from_mesh(mesh: &Mesh, options: &SnapshotOptions) -> Self {
+        let vectors = |values: Option<&[raw::AiVector3D]>| {
+            values.map(|values| AttributeBuffer::from_vectors(values, 3))
+        };
+
+        let mut uv_channels = Vec::new();
+        if options.uvs {
+            for channel in 0..crate::sys::AI_MAX_NUMBER_OF_TEXTURECOORDS as usize {
+                if let Some(values) = mesh.texture_coords_raw_opt(channel) {
+                    let components = mesh.num_uv_components(channel).unwrap_or(2).clamp(1, 3);
+                    uv_channels.push((
+                        channel as u32,
+                        AttributeBuffer::from_vectors(values, components as usize),
+                    ));
+                }
+            }
+        }
+
+        let mut color_channels = Vec::new();
+        if options.colors {
+            for channel in 0..crate::sys::AI_MAX_NUMBER_OF_COLOR_SETS as usize {
+                if let Some(values) = mesh.vertex_colors_raw_opt(channel) {
+                    let rgba = values.iter().map(|c| [c.r, c.g, c.b, c.a]);
+                    color_channels.push((channel as u32, AttributeBuffer::from_rows(rgba, 4)));
+                }
+            }
+        }
+
+        let mut indices = IndexBuffer::default();
+        let mut face_sizes = IndexBuffer::default();
+        if options.indices {
+            for face in mesh.faces_iter() {
+                let face_indices = face.indices_raw();
+                face_sizes.push(face_indices.len() as u32);
+                face_indices.iter().for_each(|&i| indices.push(i));
+            }
+        }
+
+        Self {
+            name: mesh.name(),
+            material_index: mesh.material_index() as u32,
+            num_vertices: mesh.num_vertices() as u32,
+            positions: vectors(mesh.vertices_raw_opt()).unwrap_or_default(),
+            normals: vectors(mesh.normals_raw_opt().filter(|_| options.normals)),
+            tangents: vectors(mesh.tangents_raw_opt().filter(|_| options.tangents)),
+            bitangents: vectors(mesh.bitangents_raw_opt().filter(|_| options.tangents)),
+            uv_channels,
+            color_channels,
+            indices,
+            face_sizes,
+        }
+    }
+
+    /// Position of vertex `index`.
+    pub fn position(&self, index: usize) -> Option<[f32; 3]> {
+        Some([
+            self.positions.component(index, 0)?,
+            self.positions.component(index, 1)?,
+            self.positions.component(index, 2)?,
+        ])
+    }
+
+    /// UV buffer for `channel`, if it was copied.
+    pub fn uv_channel(&self, channel: u32) -> Option<&AttributeBuffer> {
+        self.uv_channels
+            .iter()
+            .find(|(c, _)| *c == channel)
+            .map(|(_, buffer)| buffer)
+    }
+
+    /// Color buffer for `channel`, if it was copied.
+    pub fn color_channel(&self, channel: u32) -> Option<&AttributeBuffer> {
+        self.color_channels
+            .iter()
+            .find(|(c, _)| *c == channel)
+            .map(|(_, buffer)| buffer)
+    }
+}
+
+/// A texture reference of a material.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextureSnapshot {
+    /// Assimp `aiTextureType` value (see [`TextureType`]).
+    pub texture_type: u32,
+    /// Index within the texture type's stack.
+    pub index: u32,
+    /// Texture path (`*N` for embedded textures).
+    pub path: String,
+    /// UV channel the texture samples.
+    pub uv_index: u32,
+}
+
+impl TextureSnapshot {
+    /// The texture type, if it is known to this crate.
+    pub fn texture_type(&self) -> Option<TextureType> {
+        TextureType::from_u32(self.texture_type)
+    }
+}
+
+/// Owned copy of the commonly used material parameters.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaterialSnapshot {
+    /// Material name.
+    pub name: String,
+    /// PBR base color (RGBA).
+    pub base_color: Option<[f32; 4]>,
+    /// Diffuse color (RGB).
+    pub diffuse_color: Option<[f32; 3]>,
+    /// Specular color (RGB).
+    pub specular_color: Option<[f32; 3]>,
+    /// Emissive color (RGB).
+    pub emissive_color: Option<[f32; 3]>,
+    /// Opacity.
+    pub opacity: Option<f32>,
+    /// Shininess exponent.
+    pub shininess: Option<f32>,
+    /// PBR metallic factor.
+    pub metallic_factor: Option<f32>,
+    /// PBR roughness factor.
+    pub roughness_factor: Option<f32>,
+    /// Whether back faces should be rendered.
+    pub two_sided: bool,
+    /// Texture references in material property order.
+    pub textures: Vec<TextureSnapshot>,
+}
+
+impl MaterialSnapshot {
+    fn from_material(material: &Material) -> Self {
+        let rgb = |c: crate::types::Color3D| [c.x, c.y, c.z];
+        let textures = material
+            .properties()
+            .filter(|prop| prop.key_bytes() == b"$tex.file")
+            .filter_map(|prop| {
+                let texture_type = prop.semantic()?;
+                let info = material.texture(texture_type, prop.index() as usize)?;
+                Some(TextureSnapshot {
+                    texture_type: texture_type.to_u32(),
+                    index: prop.index(),
+                    path: info.path,
+                    uv_index: info.uv_index,
+                })
+            })
+            .collect();
+
+        Self {
+            name: material.name(),
+            base_color: material.base_color().map(|c| [c.x, c.y, c.z, c.w]),
+            diffuse_color: material.diffuse_color().map(rgb),
+            specular_color: material.specular_color().map(rgb),
+            emissive_color: material.emissive_color().map(rgb),
+            opacity: material.opacity(),
+            shininess: material.shininess(),
+            metallic_factor: material.metallic_factor(),
+            roughness_factor: material.roughness_factor(),
+            two_sided: material.is_two_sided(),
+            textures,
+        }
+    }
+}
+
+/// Owned copy of a node and its subtree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeSnapshot {
+    /// Node name.
+    pub name: String,
+    /// Local transform as four column-major columns.
+    pub transform: [[f32; 4]; 4],
+    /// Indices into [`SceneSnapshot::meshes`].
+    pub meshes: Vec<u32>,
+    /// Child nodes.
+    pub children: Vec<NodeSnapshot>,
+}
+
+impl NodeSnapshot {
+    fn from_node(node: &Node) -> Self {
+        let m = node.transformation();
+        let column = |v: crate::types::Vector4D| [v.x, v.y, v.z, v.w];
+        Self {
+            name: node.name(),
+            transform: [
+                column(m.x_axis),
+                column(m.y_axis),
+                column(m.z_axis),
+                column(m.w_axis),
+            ],
+            meshes: node.mesh_indices_raw().to_vec(),
+            children: node.children().map(|c| Self::from_node(&c)).collect(),
+        }
+    }
+
+    /// Number of nodes in this subtree, including `self`.
+    pub fn count(&self) -> usize {
+        1 + self.children.iter().map(Self::count).sum::<usize>()
+    }
+}
+
+/// Owned, serializable copy of a scene.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SceneSnapshot {
+    /// Meshes in scene order.
+    pub meshes: Vec<MeshSnapshot>,
+    /// Materials in scene order (empty unless [`SnapshotOptions::materials`]).
+    pub materials: Vec<MaterialSnapshot>,
+    /// Root node (absent unless [`SnapshotOptions::nodes`]).
+    pub root: Option<NodeSnapshot>,
+}
+
+impl Scene {
+    /// Copy scene data into an owned [`SceneSnapshot`].
+    pub fn to_snapshot(&self, options: SnapshotOptions) -> SceneSnapshot {
+        SceneSnapshot {
+            meshes: self
+                .meshes()
+                .map(|mesh| MeshSnapshot::from_mesh(&mesh, &options))
+                .collect(),
+            materials: if options.materials {
+                self.materials()
+                    .map(|material| MaterialSnapshot::from_material(&material))
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            root: self
+                .root_node()
+                .filter(|_| options.nodes)
+                .map(|root| NodeSnapshot::from_node(&root)),
+        }
+    }
+}
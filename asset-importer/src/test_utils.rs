@@ -0,0 +1,357 @@
+//! Tiny generated models for tests (requires the `test-utils` feature).
+//!
+//! Each generator builds a small, deterministic model in one of Assimp's text formats
+//! and returns its bytes together with the format hint to import it with, so tests need
+//! no fixture files:
+//!
+//! ```rust,no_run
+//! use asset_importer::{Importer, test_utils};
+//!
+//! # fn main() -> asset_importer::Result<()> {
+//! let (bytes, hint) = test_utils::gltf_rigged_triangle();
+//! let scene = Importer::new().import_from_memory(&bytes, Some(hint))?;
+//! assert_eq!(scene.mesh(0).unwrap().num_bones(), 1);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! glTF buffers are embedded as base64 data URIs, so every model is a single file.
+
+use std::fmt::Write as _;
+
+/// A unit cube centred on the origin: 8 vertices and 6 quad faces, as OBJ.
+pub fn obj_cube() -> (Vec<u8>, &'static str) {
+    let mut obj = String::from("o Cube\n");
+    for i in 0..8 {
+        let coord = |bit: u32| if i & (1 << bit) == 0 { -0.5 } else { 0.5 };
+        let _ = writeln!(obj, "v {} {} {}", coord(0), coord(1), coord(2));
+    }
+    // Corners are numbered by their x/y/z bits; each face lists its corners
+    // counter-clockwise seen from outside.
+    for face in [
+        [1, 3, 7, 5],
+        [0, 4, 6, 2],
+        [2, 6, 7, 3],
+        [0, 1, 5, 4],
+        [4, 5, 7, 6],
+        [0, 2, 3, 1],
+    ] {
+        let _ = writeln!(
+            obj,
+            "f {} {} {} {}",
+            face[0] + 1,
+            face[1] + 1,
+            face[2] + 1,
+            face[3] + 1
+        );
+    }
+    (obj.into_bytes(), "obj")
+}
+
+/// A unit plane in XZ split into `n`×`n` quads (at least one), with UVs and +Y normals,
+/// as OBJ: `(n + 1)²` vertices and `n²` faces.
+pub fn obj_plane(n: usize) -> (Vec<u8>, &'static str) {
+    let n = n.max(1);
+    let mut obj = String::from("o Plane\nvn 0 1 0\n");
+    for row in 0..=n {
+        for col in 0..=n {
+            let (u, v) = (col as f32 / n as f32, row as f32 / n as f32);
+            let _ = writeln!(obj, "v {} 0 {}", u - 0.5, 0.5 - v);
+            let _ = writeln!(obj, "vt {u} {v}");
+        }
+    }
+    let index = |row: usize, col: usize| row * (n + 1) + col + 1;
+    for row in 0..n {
+        for col in 0..n {
+            let corners = [
+                index(row, col),
+                index(row, col + 1),
+                index(row + 1, col + 1),
+                index(row + 1, col),
+            ];
+            obj.push('f');
+            for corner in corners {
+                let _ = write!(obj, " {corner}/{corner}/1");
+            }
+            obj.push('\n');
+        }
+    }
+    (obj.into_bytes(), "obj")
+}
+
+/// A glTF triangle skinned to one joint, with a one-second animation turning the joint
+/// a quarter turn about +Z.
+///
+/// The mesh is `"RiggedTriangle"`, the joint node `"Bone"` (a child of `"Armature"`)
+/// and the animation `"Turn"`; every vertex has weight 1 on the joint.
+pub fn gltf_rigged_triangle() -> (Vec<u8>, &'static str) {
+    let mut buffer = Buffer::default();
+    let positions = buffer.floats(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    let joints = buffer.shorts(&[0; 12]);
+    let weights = buffer.floats(&[1.0, 0.0, 0.0, 0.0].repeat(3));
+    let inverse_bind = buffer.floats(&IDENTITY);
+    let times = buffer.floats(&[0.0, 1.0]);
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    let rotations = buffer.floats(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, half, half]);
+
+    let json = format!(
+        r#"{{
+  "asset": {{ "version": "2.0" }},
+  "buffers": [{buffer}],
+  "bufferViews": [{views}],
+  "accessors": [
+    {{ "bufferView": {positions}, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] }},
+    {{ "bufferView": {joints}, "componentType": 5123, "count": 3, "type": "VEC4" }},
+    {{ "bufferView": {weights}, "componentType": 5126, "count": 3, "type": "VEC4" }},
+    {{ "bufferView": {inverse_bind}, "componentType": 5126, "count": 1, "type": "MAT4" }},
+    {{ "bufferView": {times}, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1] }},
+    {{ "bufferView": {rotations}, "componentType": 5126, "count": 2, "type": "VEC4" }}
+  ],
+  "meshes": [{{
+    "name": "RiggedTriangle",
+    "primitives": [{{ "attributes": {{ "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2 }} }}]
+  }}],
+  "skins": [{{ "joints": [2], "inverseBindMatrices": 3 }}],
+  "nodes": [
+    {{ "name": "Armature", "children": [1, 2] }},
+    {{ "name": "Skinned", "mesh": 0, "skin": 0 }},
+    {{ "name": "Bone" }}
+  ],
+  "animations": [{{
+    "name": "Turn",
+    "samplers": [{{ "input": 4, "output": 5 }}],
+    "channels": [{{ "sampler": 0, "target": {{ "node": 2, "path": "rotation" }} }}]
+  }}],
+  "scenes": [{{ "nodes": [0] }}],
+  "scene": 0
+}}"#,
+        buffer = buffer.json(),
+        views = buffer.views_json(),
+    );
+    (json.into_bytes(), "gltf")
+}
+
+/// A glTF unit quad (two triangles) with one morph target lifting every vertex by +Z,
+/// applied at weight 0.5.
+///
+/// The mesh is `"MorphQuad"` and the target is named `"Lift"`.
+pub fn gltf_morph_quad() -> (Vec<u8>, &'static str) {
+    let mut buffer = Buffer::default();
+    let positions = buffer.floats(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0]);
+    let lift = buffer.floats(&[0.0, 0.0, 1.0].repeat(4));
+    let indices = buffer.shorts(&[0, 1, 2, 0, 2, 3]);
+
+    let json = format!(
+        r#"{{
+  "asset": {{ "version": "2.0" }},
+  "buffers": [{buffer}],
+  "bufferViews": [{views}],
+  "accessors": [
+    {{ "bufferView": {positions}, "componentType": 5126, "count": 4, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] }},
+    {{ "bufferView": {lift}, "componentType": 5126, "count": 4, "type": "VEC3", "min": [0, 0, 1], "max": [0, 0, 1] }},
+    {{ "bufferView": {indices}, "componentType": 5123, "count": 6, "type": "SCALAR" }}
+  ],
+  "meshes": [{{
+    "name": "MorphQuad",
+    "primitives": [{{ "attributes": {{ "POSITION": 0 }}, "indices": 2, "targets": [{{ "POSITION": 1 }}] }}],
+    "weights": [0.5],
+    "extras": {{ "targetNames": ["Lift"] }}
+  }}],
+  "nodes": [{{ "name": "MorphQuad", "mesh": 0 }}],
+  "scenes": [{{ "nodes": [0] }}],
+  "scene": 0
+}}"#,
+        buffer = buffer.json(),
+        views = buffer.views_json(),
+    );
+    (json.into_bytes(), "gltf")
+}
+
+/// An ASCII PLY with four vertices carrying RGB colours and two triangles forming a
+/// unit square.
+pub fn ply_points_and_tris() -> (Vec<u8>, &'static str) {
+    let vertices = [
+        ([0.0, 0.0, 0.0], [255, 0, 0]),
+        ([1.0, 0.0, 0.0], [0, 255, 0]),
+        ([1.0, 1.0, 0.0], [0, 0, 255]),
+        ([0.0, 1.0, 0.0], [255, 255, 255]),
+    ];
+    let faces = [[0, 1, 2], [0, 2, 3]];
+    let mut ply = format!(
+        "ply\nformat ascii 1.0\nelement vertex {}\n\
+         property float x\nproperty float y\nproperty float z\n\
+         property uchar red\nproperty uchar green\nproperty uchar blue\n\
+         element face {}\nproperty list uchar int vertex_indices\nend_header\n",
+        vertices.len(),
+        faces.len()
+    );
+    for ([x, y, z], [r, g, b]) in vertices {
+        let _ = writeln!(ply, "{x} {y} {z} {r} {g} {b}");
+    }
+    for [a, b, c] in faces {
+        let _ = writeln!(ply, "3 {a} {b} {c}");
+    }
+    (ply.into_bytes(), "ply")
+}
+
+/// Little-endian bytes of `values`, as glTF buffers store them.
+pub fn f32_bytes(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+/// Little-endian bytes of `values`, as glTF buffers store them.
+pub fn u16_bytes(values: &[u16]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+/// A glTF buffer object embedding `bytes` as a base64 `data:` URI.
+pub fn gltf_buffer(bytes: &[u8]) -> String {
+    format!(
+        r#"{{ "uri": "data:application/octet-stream;base64,{}", "byteLength": {} }}"#,
+        base64(bytes),
+        bytes.len()
+    )
+}
+
+/// Standard padded base64, as used by `data:` URIs.
+pub fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+const IDENTITY: [f32; 16] = [
+    1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+];
+
+/// A glTF buffer assembled from little-endian values, one buffer view per call.
+#[derive(Default)]
+struct Buffer {
+    bytes: Vec<u8>,
+    /// `(offset, length)` of each view
+    views: Vec<(usize, usize)>,
+}
+
+impl Buffer {
+    fn floats(&mut self, values: &[f32]) -> usize {
+        self.view(f32_bytes(values))
+    }
+
+    fn shorts(&mut self, values: &[u16]) -> usize {
+        self.view(u16_bytes(values))
+    }
+
+    /// Append `data` as a new view, keeping views 4-byte aligned.
+    fn view(&mut self, data: Vec<u8>) -> usize {
+        self.bytes.resize(self.bytes.len().next_multiple_of(4), 0);
+        self.views.push((self.bytes.len(), data.len()));
+        self.bytes.extend(data);
+        self.views.len() - 1
+    }
+
+    fn json(&self) -> String {
+        gltf_buffer(&self.bytes)
+    }
+
+    fn views_json(&self) -> String {
+        self.views
+            .iter()
+            .map(|(offset, length)| {
+                format!(r#"{{ "buffer": 0, "byteOffset": {offset}, "byteLength": {length} }}"#)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Importer, postprocess::PostProcessSteps, scene::Scene};
+
+    fn import((bytes, hint): (Vec<u8>, &str)) -> Scene {
+        Importer::new()
+            .import_from_memory(&bytes, Some(hint))
+            .unwrap_or_else(|e| panic!("import generated {hint}: {e}"))
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_obj_cube() {
+        let scene = import(obj_cube());
+        let mesh = scene.mesh(0).expect("mesh");
+        assert_eq!(mesh.num_faces(), 6);
+        assert!(mesh.faces().all(|face| face.num_indices() == 4));
+        let aabb = mesh.aabb();
+        assert_eq!((aabb.min.x, aabb.max.x), (-0.5, 0.5));
+    }
+
+    #[test]
+    fn test_obj_plane() {
+        let (bytes, hint) = obj_plane(3);
+        let scene = Importer::new()
+            .read_from_memory(&bytes)
+            .with_memory_hint(hint)
+            .with_post_process(PostProcessSteps::JOIN_IDENTICAL_VERTICES)
+            .import()
+            .expect("import plane");
+        let mesh = scene.mesh(0).expect("mesh");
+        assert_eq!(mesh.num_faces(), 9);
+        assert_eq!(mesh.num_vertices(), 16);
+        assert!(mesh.has_texture_coords(0));
+    }
+
+    #[test]
+    fn test_gltf_rigged_triangle() {
+        let scene = import(gltf_rigged_triangle());
+        let mesh = scene.mesh(0).expect("mesh");
+        assert_eq!(mesh.name(), "RiggedTriangle");
+        assert_eq!(mesh.num_bones(), 1);
+        assert_eq!(mesh.bone(0).unwrap().name(), "Bone");
+        let animation = scene.animation(0).expect("animation");
+        assert_eq!(animation.name(), "Turn");
+        assert_eq!(animation.channel(0).unwrap().node_name(), "Bone");
+    }
+
+    #[test]
+    fn test_gltf_morph_quad() {
+        let scene = import(gltf_morph_quad());
+        let mesh = scene.mesh(0).expect("mesh");
+        assert_eq!(mesh.num_faces(), 2);
+        assert_eq!(mesh.num_anim_meshes(), 1);
+        let target = mesh.anim_mesh(0).unwrap();
+        assert_eq!(target.name(), "Lift");
+        assert_eq!(target.weight(), 0.5);
+    }
+
+    #[test]
+    fn test_ply_points_and_tris() {
+        let scene = import(ply_points_and_tris());
+        let mesh = scene.mesh(0).expect("mesh");
+        assert_eq!(mesh.num_faces(), 2);
+        assert!(mesh.has_vertex_colors(0));
+    }
+}
@@ -4,6 +4,12 @@
 //! including support for embedded textures that are stored directly within
 //! model files.
 
+mod dedup;
+mod usage;
+
+pub use dedup::{ExtractedTexture, TextureNaming};
+pub use usage::TextureReference;
+
 use crate::types::ai_string_to_string;
 use crate::{
     error::{Error, Result},
@@ -13,6 +19,7 @@ use crate::{
     sys,
 };
 use std::borrow::Cow;
+use std::path::Path;
 
 /// A texel (texture element) in ARGB8888 format
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -76,9 +83,15 @@ impl From<&sys::aiTexel> for Texel {
 
 #[cfg(test)]
 mod layout_tests {
-    use super::Texel;
+    use super::{Texel, texels_to_rgba8};
     use crate::sys;
 
+    #[test]
+    fn test_texels_to_rgba8_swizzles_bgra() {
+        let texels = [Texel::new(1, 2, 3, 4), Texel::new(250, 0, 128, 255)];
+        assert_eq!(texels_to_rgba8(&texels), vec![1, 2, 3, 4, 250, 0, 128, 255]);
+    }
+
     #[test]
     fn test_texel_layout_matches_sys() {
         assert_eq!(
@@ -110,11 +123,144 @@ pub enum TextureDataRef<'a> {
     Compressed(&'a [u8]),
 }
 
+/// Channel layout of an uncompressed embedded texture.
+///
+/// Assimp encodes this in the format hint as four channel letters followed by
+/// four bit counts, e.g. `"rgba8888"` or `"argb5650"`. The in-memory texels are
+/// always [`Texel`] (BGRA8); the layout only describes the original source data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TexelLayout {
+    /// Channel letters in order (`b'r'`, `b'g'`, `b'b'`, `b'a'`).
+    pub channels: [u8; 4],
+    /// Bits per channel, in the same order as `channels`.
+    pub bits: [u8; 4],
+}
+
+impl TexelLayout {
+    /// Parse a layout hint such as `"rgba8888"`.
+    ///
+    /// Returns `None` unless the hint is exactly four distinct `r`/`g`/`b`/`a`
+    /// letters followed by four decimal digits.
+    pub fn parse(hint: &str) -> Option<Self> {
+        let bytes = hint.as_bytes();
+        if bytes.len() != 8 {
+            return None;
+        }
+        let mut channels = [0u8; 4];
+        let mut bits = [0u8; 4];
+        for i in 0..4 {
+            let c = bytes[i].to_ascii_lowercase();
+            if !b"rgba".contains(&c) || channels[..i].contains(&c) {
+                return None;
+            }
+            channels[i] = c;
+            let d = bytes[i + 4];
+            if !d.is_ascii_digit() {
+                return None;
+            }
+            bits[i] = d - b'0';
+        }
+        Some(Self { channels, bits })
+    }
+
+    /// Number of bits stored for the given channel letter (`b'r'`, `b'g'`, `b'b'` or `b'a'`).
+    pub fn channel_bits(&self, channel: u8) -> u8 {
+        let channel = channel.to_ascii_lowercase();
+        self.channels
+            .iter()
+            .position(|&c| c == channel)
+            .map_or(0, |i| self.bits[i])
+    }
+
+    /// Whether the source data carried an alpha channel.
+    pub fn has_alpha(&self) -> bool {
+        self.channel_bits(b'a') > 0
+    }
+
+    /// Total bits per texel in the source data.
+    pub fn bits_per_texel(&self) -> u32 {
+        self.bits.iter().map(|&b| b as u32).sum()
+    }
+}
+
+impl std::fmt::Display for TexelLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for &c in &self.channels {
+            write!(f, "{}", c as char)?;
+        }
+        for &b in &self.bits {
+            write!(f, "{}", b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Container format of an embedded texture, parsed from Assimp's format hint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TextureFormatHint {
+    /// PNG image (`"png"`)
+    Png,
+    /// JPEG image (`"jpg"` / `"jpeg"`)
+    Jpeg,
+    /// Windows bitmap (`"bmp"`)
+    Bmp,
+    /// Targa image (`"tga"`)
+    Tga,
+    /// DirectDraw Surface (`"dds"`)
+    Dds,
+    /// WebP image (`"webp"`)
+    WebP,
+    /// Khronos texture (`"ktx"` / `"ktx2"`)
+    Ktx,
+    /// Uncompressed texel data with the given source channel layout
+    Texels(TexelLayout),
+    /// Any other (or empty) hint, stored verbatim
+    Unknown(String),
+}
+
+impl TextureFormatHint {
+    /// Parse a raw format hint string (case-insensitive).
+    pub fn parse(hint: &str) -> Self {
+        if let Some(layout) = TexelLayout::parse(hint) {
+            return Self::Texels(layout);
+        }
+        match hint.to_ascii_lowercase().as_str() {
+            "png" => Self::Png,
+            "jpg" | "jpeg" => Self::Jpeg,
+            "bmp" => Self::Bmp,
+            "tga" => Self::Tga,
+            "dds" => Self::Dds,
+            "webp" => Self::WebP,
+            "ktx" | "ktx2" => Self::Ktx,
+            _ => Self::Unknown(hint.to_string()),
+        }
+    }
+
+    /// Canonical file extension for compressed formats (without the leading dot).
+    ///
+    /// Returns `None` for [`TextureFormatHint::Texels`] and for empty unknown hints.
+    pub fn extension(&self) -> Option<&str> {
+        match self {
+            Self::Png => Some("png"),
+            Self::Jpeg => Some("jpg"),
+            Self::Bmp => Some("bmp"),
+            Self::Tga => Some("tga"),
+            Self::Dds => Some("dds"),
+            Self::WebP => Some("webp"),
+            Self::Ktx => Some("ktx"),
+            Self::Texels(_) => None,
+            Self::Unknown(hint) => (!hint.is_empty()).then_some(hint.as_str()),
+        }
+    }
+}
+
 /// An embedded texture within a 3D model file
 ///
 /// Textures can be either:
-/// 1. Uncompressed - stored as raw ARGB8888 texel data
-/// 2. Compressed - stored in a standard format like PNG, JPEG, etc.
+/// 1. Uncompressed - stored as raw [`Texel`] data (B, G, R, A byte order), with
+///    [`Texture::width`] × [`Texture::height`] texels
+/// 2. Compressed - stored in a standard format like PNG, JPEG, etc. Assimp marks these
+///    with `height == 0`, and `width` then holds the payload length in bytes.
 #[derive(Debug, Clone)]
 pub struct Texture {
     #[allow(dead_code)]
@@ -183,6 +329,9 @@ impl Texture {
             let Some(bytes) = ffi::slice_from_ptr_len_opt(self, data_ptr, size) else {
                 return Err(Error::invalid_scene("Texture data is null"));
             };
+            if bytes.len() != size {
+                return Err(Error::invalid_scene("Texture data length is out of range"));
+            }
             Ok(TextureDataRef::Compressed(bytes))
         } else {
             let width = self.width() as usize;
@@ -198,10 +347,65 @@ impl Texture {
             else {
                 return Err(Error::invalid_scene("Texture data is null"));
             };
+            if texels.len() != size {
+                return Err(Error::invalid_scene("Texture data length is out of range"));
+            }
             Ok(TextureDataRef::Texels(texels))
         }
     }
 
+    /// Get the texels of an uncompressed texture (zero-copy), row by row.
+    ///
+    /// Returns `None` for compressed textures or when the data pointer is null.
+    pub fn texels_raw(&self) -> Option<&[Texel]> {
+        match self.data_ref().ok()? {
+            TextureDataRef::Texels(texels) => Some(texels),
+            TextureDataRef::Compressed(_) => None,
+        }
+    }
+
+    /// Get the texels of an uncompressed texture as bytes (zero-copy), four per texel
+    /// in B, G, R, A order.
+    ///
+    /// Suitable for uploading as a BGRA8 texture; use [`Texture::to_rgba8`] for RGBA.
+    /// Returns `None` for compressed textures or when the data pointer is null.
+    pub fn texels_bytes(&self) -> Option<&[u8]> {
+        let texels = self.texels_raw()?;
+        // Texel is #[repr(C)] with four u8 fields, so the texels are plain bytes.
+        Some(ffi::slice_from_ptr_len(
+            self,
+            texels.as_ptr() as *const u8,
+            std::mem::size_of_val(texels),
+        ))
+    }
+
+    /// Convert an uncompressed texture to tightly packed RGBA8, returned as
+    /// `(width, height, bytes)`.
+    ///
+    /// Swizzles Assimp's BGRA texel order to RGBA. Returns `None` for compressed
+    /// textures, which have to be decoded from their container format instead.
+    pub fn to_rgba8(&self) -> Option<(u32, u32, Vec<u8>)> {
+        let texels = self.texels_raw()?;
+        Some((self.width(), self.height(), texels_to_rgba8(texels)))
+    }
+
+    /// Size of the texture payload in bytes.
+    ///
+    /// For compressed textures this is the payload length (`width`); for uncompressed
+    /// textures it is `width * height * 4`, saturating at `usize::MAX`.
+    pub fn size_bytes(&self) -> usize {
+        if self.is_compressed() {
+            self.width() as usize
+        } else {
+            let w = self.width() as u64;
+            let h = self.height() as u64;
+            let bytes = w
+                .saturating_mul(h)
+                .saturating_mul(std::mem::size_of::<Texel>() as u64);
+            usize::try_from(bytes).unwrap_or(usize::MAX)
+        }
+    }
+
     /// Get a borrowed view of the texture data as raw bytes (zero-copy).
     ///
     /// - Compressed textures return the compressed byte payload.
@@ -230,11 +434,31 @@ impl Texture {
         String::from_utf8_lossy(self.format_hint_bytes())
     }
 
-    /// Get the format hint for the texture (allocates).
+    /// Get the format hint parsed into a [`TextureFormatHint`].
+    ///
+    /// Use [`Texture::format_hint_str`] for the raw hint string.
+    pub fn format_hint(&self) -> TextureFormatHint {
+        TextureFormatHint::parse(&self.format_hint_str())
+    }
+
+    /// Get the source channel layout of an uncompressed texture.
+    ///
+    /// Returns `None` for compressed textures or when the hint is not a layout string.
+    pub fn texel_layout(&self) -> Option<TexelLayout> {
+        if self.is_compressed() {
+            return None;
+        }
+        TexelLayout::parse(&self.format_hint_str())
+    }
+
+    /// Get the compressed payload (zero-copy).
     ///
-    /// Prefer [`Texture::format_hint_str`] for a zero-allocation option.
-    pub fn format_hint(&self) -> String {
-        self.format_hint_str().into_owned()
+    /// Returns `None` for uncompressed textures or when the data pointer is null.
+    pub fn compressed_bytes(&self) -> Option<&[u8]> {
+        match self.data_ref().ok()? {
+            TextureDataRef::Compressed(bytes) => Some(bytes),
+            TextureDataRef::Texels(_) => None,
+        }
     }
 
     /// Get the original filename of the texture
@@ -273,15 +497,10 @@ impl Texture {
     }
 
     /// Get the size of the texture data in bytes
+    ///
+    /// Same as [`Texture::size_bytes`].
     pub fn data_size(&self) -> usize {
-        if self.is_compressed() {
-            self.width() as usize
-        } else {
-            let w = self.width() as u64;
-            let h = self.height() as u64;
-            let bytes = w.saturating_mul(h).saturating_mul(4); // 4 bytes per texel (ARGB)
-            usize::try_from(bytes).unwrap_or(usize::MAX)
-        }
+        self.size_bytes()
     }
 
     /// Get texture dimensions as a tuple (width, height)
@@ -291,47 +510,100 @@ impl Texture {
         (self.width(), self.height())
     }
 
-    /// Save the texture data to a file
+    /// Write the texture to a file.
     ///
-    /// For compressed textures, this saves the raw compressed data.
-    /// For uncompressed textures, this would need additional image encoding.
-    pub fn save_to_file(&self, path: &std::path::Path) -> Result<()> {
-        let data = self.data()?;
-
-        match data {
-            TextureData::Compressed(bytes) => {
-                std::fs::write(path, bytes)
-                    .map_err(|e| Error::file_error(format!("Failed to save texture: {}", e)))?;
-            }
-            TextureData::Texels(_) => {
-                return Err(Error::invalid_parameter(
-                    "Saving uncompressed textures requires image encoding library".to_string(),
-                ));
+    /// Compressed textures are written as-is, so `path` should use the extension
+    /// reported by [`TextureFormatHint::extension`]. Uncompressed textures are
+    /// encoded as PNG, which requires the `image` feature.
+    pub fn write_to_file(&self, path: &Path) -> Result<()> {
+        let written = match self.data_ref()? {
+            TextureDataRef::Compressed(bytes) => std::fs::write(path, bytes)
+                .map_err(|e| Error::io_error_with_source("Failed to save texture", e)),
+            TextureDataRef::Texels(texels) => self.write_texels_png(texels, path),
+        };
+        written.map_err(|err| {
+            err.with_context(
+                crate::error::ErrorContext::new(crate::error::Operation::Io).with_path(path),
+            )
+        })
+    }
+
+    #[cfg(feature = "image")]
+    fn write_texels_png(&self, texels: &[Texel], path: &Path) -> Result<()> {
+        image::save_buffer_with_format(
+            path,
+            &texels_to_rgba8(texels),
+            self.width(),
+            self.height(),
+            image::ColorType::Rgba8,
+            image::ImageFormat::Png,
+        )
+        .map_err(|e| match e {
+            image::ImageError::IoError(e) => {
+                Error::io_error_with_source("Failed to save texture", e)
             }
-        }
+            e => Error::io_error_with_source("Failed to save texture", std::io::Error::other(e)),
+        })
+    }
 
-        Ok(())
+    #[cfg(not(feature = "image"))]
+    fn write_texels_png(&self, _texels: &[Texel], _path: &Path) -> Result<()> {
+        Err(Error::invalid_parameter(
+            "Saving uncompressed textures requires the `image` feature",
+        ))
+    }
+
+    /// Save the texture data to a file
+    ///
+    /// Equivalent to [`Texture::write_to_file`].
+    pub fn save_to_file(&self, path: &Path) -> Result<()> {
+        self.write_to_file(path)
     }
 }
 
+/// Pack texels as R, G, B, A bytes.
+pub(crate) fn texels_to_rgba8(texels: &[Texel]) -> Vec<u8> {
+    texels.iter().flat_map(|t| [t.r, t.g, t.b, t.a]).collect()
+}
+
 /// Iterator over textures in a scene
 pub struct TextureIterator {
     scene: Scene,
     textures: Option<SharedPtr<*const sys::aiTexture>>,
     count: usize,
-    index: usize,
+    cursor: crate::ffi::PtrArrayCursor,
 }
 
 impl TextureIterator {
     /// Create a new texture iterator
     pub(crate) fn new(scene: Scene, textures: *mut *mut sys::aiTexture, count: usize) -> Self {
         let textures_ptr = SharedPtr::new(textures as *const *const sys::aiTexture);
+        let count = if textures_ptr.is_some() { count } else { 0 };
+        let cursor = crate::ffi::PtrArrayCursor::new(
+            &scene,
+            textures as *const *const sys::aiTexture,
+            count,
+        );
         Self {
             scene,
             textures: textures_ptr,
-            count: if textures_ptr.is_some() { count } else { 0 },
-            index: 0,
+            count,
+            cursor,
+        }
+    }
+
+    fn item(
+        scene: &Scene,
+        textures: Option<SharedPtr<*const sys::aiTexture>>,
+        count: usize,
+        index: usize,
+    ) -> Option<Texture> {
+        let slice = crate::ffi::slice_from_ptr_len_opt(&(), textures?.as_ptr(), count)?;
+        let ptr = *slice.get(index)?;
+        if ptr.is_null() {
+            return None;
         }
+        Texture::from_sys_ptr(scene.clone(), ptr).ok()
     }
 }
 
@@ -339,26 +611,68 @@ impl Iterator for TextureIterator {
     type Item = Texture;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let textures = self.textures?;
-        let slice = crate::ffi::slice_from_ptr_len_opt(&(), textures.as_ptr(), self.count)?;
-        while self.index < slice.len() {
-            let texture_ptr = slice[self.index];
-            self.index += 1;
-            if texture_ptr.is_null() {
-                continue;
-            }
-            // `from_sys_ptr` only fails on null pointers; keep the iterator robust anyway.
-            if let Ok(tex) = Texture::from_sys_ptr(self.scene.clone(), texture_ptr) {
-                return Some(tex);
-            }
-        }
-        None
+        let (scene, textures, count) = (&self.scene, self.textures, self.count);
+        self.cursor
+            .next(|index| Self::item(scene, textures, count, index))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.count.saturating_sub(self.index);
-        (0, Some(remaining))
+        self.cursor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (scene, textures, count) = (&self.scene, self.textures, self.count);
+        self.cursor
+            .nth(n, |index| Self::item(scene, textures, count, index))
+    }
+}
+
+impl DoubleEndedIterator for TextureIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (scene, textures, count) = (&self.scene, self.textures, self.count);
+        self.cursor
+            .next_back(|index| Self::item(scene, textures, count, index))
     }
 }
 
+impl ExactSizeIterator for TextureIterator {}
+
 // Auto-traits (Send/Sync) are derived from the contained pointers and lifetimes.
+
+#[cfg(test)]
+mod tests {
+    use super::{TexelLayout, TextureFormatHint};
+
+    #[test]
+    fn test_format_hint_parse() {
+        assert_eq!(TextureFormatHint::parse("png"), TextureFormatHint::Png);
+        assert_eq!(TextureFormatHint::parse("JPG"), TextureFormatHint::Jpeg);
+        assert_eq!(TextureFormatHint::parse("jpeg"), TextureFormatHint::Jpeg);
+        assert_eq!(TextureFormatHint::parse("ktx2"), TextureFormatHint::Ktx);
+        assert_eq!(
+            TextureFormatHint::parse("exr"),
+            TextureFormatHint::Unknown("exr".to_string())
+        );
+        assert_eq!(TextureFormatHint::parse("").extension(), None);
+        assert_eq!(TextureFormatHint::Jpeg.extension(), Some("jpg"));
+    }
+
+    #[test]
+    fn test_texel_layout_parse() {
+        let layout = TexelLayout::parse("argb5650").unwrap();
+        assert_eq!(layout.channels, *b"argb");
+        assert_eq!(layout.bits, [5, 6, 5, 0]);
+        assert_eq!(layout.channel_bits(b'r'), 6);
+        assert!(layout.has_alpha());
+        assert_eq!(layout.bits_per_texel(), 16);
+        assert_eq!(layout.to_string(), "argb5650");
+        assert_eq!(
+            TextureFormatHint::parse("rgba8888"),
+            TextureFormatHint::Texels(TexelLayout::parse("rgba8888").unwrap())
+        );
+
+        assert!(TexelLayout::parse("rrba8888").is_none());
+        assert!(TexelLayout::parse("rgba888").is_none());
+        assert!(TexelLayout::parse("rgbx8888").is_none());
+    }
+}
@@ -0,0 +1,240 @@
+//! Content hashing, deduplication and extraction of embedded textures.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::{Texture, TextureDataRef, TextureReference};
+#[cfg(not(target_family = "wasm"))]
+use crate::error::{Error, ErrorContext, Operation, Result};
+use crate::scene::Scene;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+impl Texture {
+    /// Hash of the texture's content, for deduplication and content-addressed names.
+    ///
+    /// The hash is 64-bit FNV-1a over the payload: the compressed bytes as stored, or
+    /// for uncompressed textures the width and height (little-endian `u32`) followed
+    /// by the B, G, R, A texel bytes. The algorithm is part of the API: the same
+    /// texture hashes to the same value on every platform and in every 0.x release
+    /// that does not announce a change in the changelog, so the value is safe to
+    /// persist. It is not a cryptographic hash; compare the payloads when a collision
+    /// would matter (as [`Scene::deduplicated_textures`] does).
+    pub fn content_hash(&self) -> u64 {
+        match self.data_ref() {
+            Ok(TextureDataRef::Compressed(bytes)) => fnv1a(FNV_OFFSET_BASIS, bytes),
+            Ok(TextureDataRef::Texels(_)) => {
+                let mut hash = fnv1a(FNV_OFFSET_BASIS, &self.width().to_le_bytes());
+                hash = fnv1a(hash, &self.height().to_le_bytes());
+                fnv1a(hash, self.texels_bytes().unwrap_or(&[]))
+            }
+            Err(_) => FNV_OFFSET_BASIS,
+        }
+    }
+
+    /// Whether `self` and `other` hold the same payload.
+    fn same_content(&self, other: &Texture) -> bool {
+        match (self.data_ref(), other.data_ref()) {
+            (Ok(TextureDataRef::Compressed(a)), Ok(TextureDataRef::Compressed(b))) => a == b,
+            (Ok(TextureDataRef::Texels(_)), Ok(TextureDataRef::Texels(_))) => {
+                self.dimensions() == other.dimensions()
+                    && self.texels_bytes() == other.texels_bytes()
+            }
+            _ => false,
+        }
+    }
+
+    /// File extension used when extracting: the container format, or `png` for
+    /// uncompressed textures (which are encoded on write).
+    #[cfg_attr(target_family = "wasm", allow(dead_code))]
+    fn extraction_extension(&self) -> String {
+        if self.is_uncompressed() {
+            return "png".to_string();
+        }
+        self.format_hint()
+            .extension()
+            .map(|ext| ext.to_ascii_lowercase())
+            .unwrap_or_else(|| "bin".to_string())
+    }
+}
+
+/// File naming scheme for [`Scene::extract_textures_to_dir`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextureNaming {
+    /// `texture_<N>.<ext>`, where `N` is the first embedded index with the content
+    #[default]
+    ByIndex,
+    /// The file name the texture was embedded under, falling back to
+    /// [`TextureNaming::ByIndex`] when it has none
+    ByFilename,
+    /// `<content_hash as 16 hex digits>.<ext>`, see [`Texture::content_hash`]
+    ByContentHash,
+}
+
+/// One file written by [`Scene::extract_textures_to_dir`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedTexture {
+    /// Path of the written file
+    pub path: PathBuf,
+    /// Embedded texture indices whose content was written to this file
+    pub texture_indices: Vec<usize>,
+    /// Material slots that reference any of those textures, see
+    /// [`Scene::texture_references`]
+    pub references: Vec<TextureReference>,
+}
+
+impl Scene {
+    /// Group identical embedded textures.
+    ///
+    /// Returns one entry per distinct payload, in order of first appearance: the
+    /// first texture with that content and every embedded index that holds it.
+    pub fn deduplicated_textures(&self) -> Vec<(Texture, Vec<usize>)> {
+        let mut groups: Vec<(Texture, u64, Vec<usize>)> = Vec::new();
+        for (index, texture) in self.textures().enumerate() {
+            let hash = texture.content_hash();
+            match groups
+                .iter_mut()
+                .find(|(first, h, _)| *h == hash && first.same_content(&texture))
+            {
+                Some((_, _, indices)) => indices.push(index),
+                None => groups.push((texture, hash, vec![index])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(texture, _, indices)| (texture, indices))
+            .collect()
+    }
+
+    /// Write every distinct embedded texture into `dir`, creating it if needed.
+    ///
+    /// Identical textures are written once (see [`Scene::deduplicated_textures`]).
+    /// When two different textures would get the same file name, later ones get a
+    /// `_<n>` suffix before the extension. Uncompressed textures are encoded as PNG,
+    /// which requires the `image` feature. Not available on `wasm` targets.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn extract_textures_to_dir<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        naming: TextureNaming,
+    ) -> Result<Vec<ExtractedTexture>> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(|e| {
+            Error::io_error_with_source(format!("Failed to create {}", dir.display()), e)
+                .with_context(ErrorContext::new(Operation::Io).with_path(dir))
+        })?;
+
+        let references = self.texture_references();
+        let mut used: HashSet<String> = HashSet::new();
+        let mut extracted = Vec::new();
+        for (texture, indices) in self.deduplicated_textures() {
+            let extension = texture.extraction_extension();
+            let by_index = || format!("texture_{}.{}", indices[0], extension);
+            let name = match naming {
+                TextureNaming::ByIndex => by_index(),
+                TextureNaming::ByFilename => declared_file_name(&texture)
+                    .map(|name| ensure_extension(name, &extension))
+                    .unwrap_or_else(by_index),
+                TextureNaming::ByContentHash => {
+                    format!("{:016x}.{}", texture.content_hash(), extension)
+                }
+            };
+            let name = disambiguate(name, &mut used);
+
+            let path = dir.join(&name);
+            texture.write_to_file(&path)?;
+            extracted.push(ExtractedTexture {
+                path,
+                references: references
+                    .iter()
+                    .filter(|reference| indices.contains(&reference.texture_index))
+                    .copied()
+                    .collect(),
+                texture_indices: indices,
+            });
+        }
+        Ok(extracted)
+    }
+}
+
+/// Last component of the texture's embedded file name, if it is usable as a file name.
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+fn declared_file_name(texture: &Texture) -> Option<String> {
+    let declared = texture.filename_str()?;
+    let name = declared.rsplit(['/', '\\']).next()?.trim();
+    if name.is_empty() || name == "." || name == ".." || name.starts_with('*') {
+        return None;
+    }
+    Some(name.chars().filter(|c| !matches!(c, ':' | '\0')).collect())
+}
+
+/// Append `.extension` unless `name` already ends with it (case-insensitively).
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+fn ensure_extension(name: String, extension: &str) -> String {
+    let has_extension = Path::new(&name)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(extension));
+    if has_extension {
+        name
+    } else {
+        format!("{name}.{extension}")
+    }
+}
+
+/// Return `name`, or `stem_<n>.ext` with the smallest free `n`, and mark it used.
+#[cfg_attr(target_family = "wasm", allow(dead_code))]
+fn disambiguate(name: String, used: &mut HashSet<String>) -> String {
+    let key = |name: &str| name.to_ascii_lowercase();
+    if used.insert(key(&name)) {
+        return name;
+    }
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, Some(extension)),
+        _ => (name.as_str(), None),
+    };
+    (1..)
+        .map(|n| match extension {
+            Some(extension) => format!("{stem}_{n}.{extension}"),
+            None => format!("{stem}_{n}"),
+        })
+        .find(|candidate| used.insert(key(candidate)))
+        .expect("unbounded suffix range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_reference_values() {
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_disambiguate_suffixes_collisions() {
+        let mut used = HashSet::new();
+        assert_eq!(disambiguate("a.png".into(), &mut used), "a.png");
+        assert_eq!(disambiguate("A.PNG".into(), &mut used), "A_1.PNG");
+        assert_eq!(disambiguate("a.png".into(), &mut used), "a_2.png");
+        assert_eq!(disambiguate("noext".into(), &mut used), "noext");
+        assert_eq!(disambiguate("noext".into(), &mut used), "noext_1");
+    }
+
+    #[test]
+    fn test_ensure_extension() {
+        assert_eq!(ensure_extension("a.PNG".into(), "png"), "a.PNG");
+        assert_eq!(ensure_extension("a".into(), "png"), "a.png");
+        assert_eq!(ensure_extension("a.jpg".into(), "png"), "a.jpg.png");
+    }
+}
@@ -0,0 +1,109 @@
+//! Mapping from embedded textures back to the material slots that use them.
+
+use crate::{material::TextureType, scene::Scene};
+
+/// A material texture slot that uses an embedded texture, from
+/// [`Scene::texture_references`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureReference {
+    /// Index of the embedded texture in the scene
+    pub texture_index: usize,
+    /// Index of the material in the scene
+    pub material_index: usize,
+    /// Texture type of the slot
+    pub texture_type: TextureType,
+    /// Texture index within the type
+    pub slot_index: u32,
+}
+
+impl Scene {
+    /// Every material texture slot that resolves to an embedded texture, ordered by
+    /// material, then by property order within the material.
+    ///
+    /// Each material's `$tex.file` properties are read once. A path of the form `*N`
+    /// refers to embedded texture `N`; any other path matches an embedded texture whose
+    /// file name (the part after the last `/` or `\`) is the same, which is how
+    /// `aiScene::GetEmbeddedTexture` resolves formats that embed textures under their
+    /// original names. Out-of-range `*N` references are skipped.
+    pub fn texture_references(&self) -> Vec<TextureReference> {
+        let num_textures = self.num_textures();
+        if num_textures == 0 {
+            return Vec::new();
+        }
+        let names: Vec<Option<Vec<u8>>> = self
+            .textures()
+            .map(|texture| {
+                let name = texture.filename_str()?;
+                let name = short_name(name.as_bytes());
+                (!name.is_empty()).then(|| name.to_vec())
+            })
+            .collect();
+
+        let mut references = Vec::new();
+        for (material_index, material) in self.materials().enumerate() {
+            for prop in material.properties() {
+                if prop.key_bytes() != b"$tex.file" {
+                    continue;
+                }
+                let (Some(texture_type), Some(path)) = (prop.semantic(), prop.string_ref()) else {
+                    continue;
+                };
+                let texture_index = match embedded_index(path.as_bytes()) {
+                    Some(index) if index < num_textures => index,
+                    Some(_) => continue,
+                    None => {
+                        let wanted = short_name(path.as_bytes());
+                        if wanted.is_empty() {
+                            continue;
+                        }
+                        match names
+                            .iter()
+                            .position(|name| name.as_deref() == Some(wanted))
+                        {
+                            Some(index) => index,
+                            None => continue,
+                        }
+                    }
+                };
+                references.push(TextureReference {
+                    texture_index,
+                    material_index,
+                    texture_type,
+                    slot_index: prop.index(),
+                });
+            }
+        }
+        references
+    }
+}
+
+/// `N` for a `*N` path.
+fn embedded_index(path: &[u8]) -> Option<usize> {
+    let digits = path.strip_prefix(b"*")?;
+    std::str::from_utf8(digits).ok()?.parse().ok()
+}
+
+/// The part after the last `/` or `\`.
+fn short_name(path: &[u8]) -> &[u8] {
+    let start = path
+        .iter()
+        .rposition(|&b| b == b'/' || b == b'\\')
+        .map_or(0, |i| i + 1);
+    &path[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{embedded_index, short_name};
+
+    #[test]
+    fn test_embedded_index_and_short_name() {
+        assert_eq!(embedded_index(b"*0"), Some(0));
+        assert_eq!(embedded_index(b"*12"), Some(12));
+        assert_eq!(embedded_index(b"*"), None);
+        assert_eq!(embedded_index(b"tex/*1"), None);
+        assert_eq!(short_name(b"C:\\art\\wood.png"), b"wood.png");
+        assert_eq!(short_name(b"textures/wood.png"), b"wood.png");
+        assert_eq!(short_name(b"wood.png"), b"wood.png");
+    }
+}
@@ -345,6 +345,17 @@ impl Matrix4x4 {
         ]
     }
 
+    /// Matrix product `self * rhs` (applies `rhs` first).
+    #[inline]
+    pub fn mul_mat4(self, rhs: Self) -> Self {
+        Self::from_cols(
+            self.mul_vec4(rhs.x_axis),
+            self.mul_vec4(rhs.y_axis),
+            self.mul_vec4(rhs.z_axis),
+            self.mul_vec4(rhs.w_axis),
+        )
+    }
+
     #[inline]
     pub fn mul_vec4(self, v: Vector4D) -> Vector4D {
         // Column-major: M * v = x_axis*v.x + y_axis*v.y + z_axis*v.z + w_axis*v.w
@@ -449,6 +460,50 @@ impl Matrix4x4 {
         )
     }
 
+    /// Left-handed perspective projection (camera looks down +Z, NDC z in [0, 1]).
+    ///
+    /// Matches `glam::Mat4::perspective_lh`.
+    pub fn perspective_lh(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+        let h = 1.0 / (0.5 * fov_y).tan();
+        let w = h / aspect;
+        let r = far / (far - near);
+
+        Self::from_cols(
+            Vector4D::new(w, 0.0, 0.0, 0.0),
+            Vector4D::new(0.0, h, 0.0, 0.0),
+            Vector4D::new(0.0, 0.0, r, 1.0),
+            Vector4D::new(0.0, 0.0, -r * near, 0.0),
+        )
+    }
+
+    /// Left-handed orthographic projection (camera looks down +Z, NDC z in [0, 1]).
+    ///
+    /// Matches `glam::Mat4::orthographic_lh`.
+    pub fn orthographic_lh(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    ) -> Self {
+        let rcp_width = 1.0 / (right - left);
+        let rcp_height = 1.0 / (top - bottom);
+        let r = 1.0 / (far - near);
+
+        Self::from_cols(
+            Vector4D::new(2.0 * rcp_width, 0.0, 0.0, 0.0),
+            Vector4D::new(0.0, 2.0 * rcp_height, 0.0, 0.0),
+            Vector4D::new(0.0, 0.0, r, 0.0),
+            Vector4D::new(
+                -(left + right) * rcp_width,
+                -(top + bottom) * rcp_height,
+                -r * near,
+                1.0,
+            ),
+        )
+    }
+
     /// Build from `(scale, rotation, translation)` (column-major).
     pub fn from_scale_rotation_translation(
         scale: Vector3D,
@@ -481,6 +536,14 @@ impl Matrix4x4 {
     }
 }
 
+impl std::ops::Mul for Matrix4x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_mat4(rhs)
+    }
+}
+
 /// Quaternion (x, y, z, w).
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(C)]
@@ -642,63 +705,309 @@ pub type Color4D = Vector4D;
 
 /// Convert Assimp `aiString` to a UTF-8 string (lossy).
 ///
-/// Assimp stores the length explicitly; do not assume the buffer is NUL-terminated.
+/// Reads the bytes returned by [`ai_string_bytes`], so the result never extends past
+/// the buffer or the first NUL.
 #[inline]
 pub(crate) fn ai_string_to_str(value: &sys::aiString) -> Cow<'_, str> {
-    let len = (value.length as usize).min(value.data.len());
-    if len == 0 {
+    let bytes = ai_string_bytes(value);
+    if bytes.is_empty() {
         return Cow::Borrowed("");
     }
-    let bytes = ffi::slice_from_ptr_len(value, value.data.as_ptr() as *const u8, len);
     String::from_utf8_lossy(bytes)
 }
 
+/// Borrow the bytes of an Assimp `aiString`.
+///
+/// `length` is authoritative but clamped to the buffer, and the result stops at the
+/// first NUL: a length of 0 is empty whatever the buffer holds, and anything an
+/// exporter left after an embedded NUL is dropped. Use [`ai_string_raw_bytes`] to keep
+/// it.
+#[inline]
+pub(crate) fn ai_string_bytes(value: &sys::aiString) -> &[u8] {
+    let bytes = ai_string_raw_bytes(value);
+    match bytes.iter().position(|&b| b == 0) {
+        Some(end) => &bytes[..end],
+        None => bytes,
+    }
+}
+
+/// Borrow all `length` bytes of an Assimp `aiString` (clamped to the buffer), including
+/// any embedded NULs.
+#[inline]
+pub(crate) fn ai_string_raw_bytes(value: &sys::aiString) -> &[u8] {
+    let len = (value.length as usize).min(value.data.len());
+    ffi::slice_from_ptr_len(value, value.data.as_ptr() as *const u8, len)
+}
+
 /// Convert Assimp `aiString` to an owned UTF-8 string (lossy).
 #[inline]
 pub(crate) fn ai_string_to_string(value: &sys::aiString) -> String {
     ai_string_to_str(value).into_owned()
 }
 
+/// Convert Assimp `aiString` to a [`Name`], without an intermediate `String`.
+#[inline]
+pub(crate) fn ai_string_to_name(value: &sys::aiString) -> Name {
+    Name::from_utf8_lossy(ai_string_bytes(value))
+}
+
+/// Bytes a [`Name`] stores inline before it spills to the heap.
+pub const NAME_INLINE_CAPACITY: usize = 64;
+
+/// An owned, immutable UTF-8 name that keeps up to [`NAME_INLINE_CAPACITY`] bytes on
+/// the stack.
+///
+/// Returned by the `name_owned` accessors (e.g. [`Node::name_owned`](crate::node::Node::name_owned)).
+/// Most node, mesh and bone names fit inline, so collecting them does not touch the
+/// allocator; longer names are stored in a `Box<str>`. Derefs to `str` and compares,
+/// hashes and orders exactly like its string contents.
+#[derive(Clone)]
+pub struct Name(NameRepr);
+
+#[derive(Clone)]
+enum NameRepr {
+    Inline {
+        len: u8,
+        buf: [u8; NAME_INLINE_CAPACITY],
+    },
+    Heap(Box<str>),
+}
+
+impl Name {
+    /// Create a name from a string slice.
+    pub fn new(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        if bytes.len() <= NAME_INLINE_CAPACITY {
+            let mut buf = [0u8; NAME_INLINE_CAPACITY];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            Self(NameRepr::Inline {
+                len: bytes.len() as u8,
+                buf,
+            })
+        } else {
+            Self(NameRepr::Heap(s.into()))
+        }
+    }
+
+    /// Create a name from bytes, replacing invalid UTF-8 with `U+FFFD` like
+    /// [`String::from_utf8_lossy`].
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        match String::from_utf8_lossy(bytes) {
+            Cow::Borrowed(s) => Self::new(s),
+            Cow::Owned(s) if s.len() <= NAME_INLINE_CAPACITY => Self::new(&s),
+            Cow::Owned(s) => Self(NameRepr::Heap(s.into_boxed_str())),
+        }
+    }
+
+    /// The name as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            NameRepr::Inline { len, buf } => {
+                // SAFETY: inline buffers are only filled from `&str` in `Name::new`, so
+                // the first `len` bytes are valid UTF-8.
+                unsafe { std::str::from_utf8_unchecked(&buf[..*len as usize]) }
+            }
+            NameRepr::Heap(s) => s,
+        }
+    }
+
+    /// `true` when the name is stored on the heap rather than inline.
+    pub fn spilled(&self) -> bool {
+        matches!(self.0, NameRepr::Heap(_))
+    }
+}
+
+impl Default for Name {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl std::ops::Deref for Name {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::borrow::Borrow<str> for Name {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Name {}
+
+impl PartialEq<str> for Name {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Name {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Name {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl std::hash::Hash for Name {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Name {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl From<&str> for Name {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for Name {
+    fn from(s: String) -> Self {
+        if s.len() <= NAME_INLINE_CAPACITY {
+            Self::new(&s)
+        } else {
+            Self(NameRepr::Heap(s.into_boxed_str()))
+        }
+    }
+}
+
+impl From<Name> for String {
+    fn from(name: Name) -> Self {
+        match name.0 {
+            NameRepr::Heap(s) => s.into_string(),
+            NameRepr::Inline { .. } => name.as_str().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Name {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Assimp's scalar type (`ai_real`): `f32`, or `f64` when Assimp was built with
+/// `ASSIMP_DOUBLE_PRECISION` (this crate then compiles with `cfg(f64_real)`).
+///
+/// The math types in this module stay `f32`; values are converted at the FFI boundary.
+/// Colors (`aiColor3D`/`aiColor4D`) are `f32` in Assimp regardless of this setting.
+pub type Real = sys::ai_real;
+
+// The build script derives `cfg(f64_real)` from the sys bindings; they must agree.
+const _: () = {
+    #[cfg(f64_real)]
+    assert!(std::mem::size_of::<Real>() == std::mem::size_of::<f64>());
+    #[cfg(not(f64_real))]
+    assert!(std::mem::size_of::<Real>() == std::mem::size_of::<f32>());
+};
+
+/// Widen (or pass through) an `f32` to Assimp's [`Real`].
+#[inline]
+#[allow(clippy::unnecessary_cast)]
+pub(crate) fn to_real(v: f32) -> Real {
+    v as Real
+}
+
+/// Narrow (or pass through) an Assimp [`Real`] to `f32`.
+#[inline]
+#[allow(clippy::unnecessary_cast)]
+pub(crate) fn from_real(v: Real) -> f32 {
+    v as f32
+}
+
 // ---- Assimp <-> crate math conversions (internal) ----
 
 #[inline]
 pub(crate) fn from_ai_vector3d(v: sys::aiVector3D) -> Vector3D {
-    Vector3D::new(v.x, v.y, v.z)
+    Vector3D::new(from_real(v.x), from_real(v.y), from_real(v.z))
 }
 
 #[inline]
 pub(crate) fn to_ai_vector3d(v: Vector3D) -> sys::aiVector3D {
     sys::aiVector3D {
-        x: v.x,
-        y: v.y,
-        z: v.z,
+        x: to_real(v.x),
+        y: to_real(v.y),
+        z: to_real(v.z),
     }
 }
 
 #[inline]
 pub(crate) fn from_ai_vector2d(v: sys::aiVector2D) -> Vector2D {
-    Vector2D::new(v.x, v.y)
+    Vector2D::new(from_real(v.x), from_real(v.y))
 }
 
 #[inline]
 pub(crate) fn to_ai_vector2d(v: Vector2D) -> sys::aiVector2D {
-    sys::aiVector2D { x: v.x, y: v.y }
+    sys::aiVector2D {
+        x: to_real(v.x),
+        y: to_real(v.y),
+    }
 }
 
 #[inline]
 pub(crate) fn from_ai_matrix4x4(m: sys::aiMatrix4x4) -> Matrix4x4 {
     // Assimp stores matrices row-major (a1..d4 are rows); `Matrix4x4` is column-major.
+    let col = |a: Real, b: Real, c: Real, d: Real| {
+        Vector4D::new(from_real(a), from_real(b), from_real(c), from_real(d))
+    };
     Matrix4x4::from_cols(
-        Vector4D::new(m.a1, m.b1, m.c1, m.d1),
-        Vector4D::new(m.a2, m.b2, m.c2, m.d2),
-        Vector4D::new(m.a3, m.b3, m.c3, m.d3),
-        Vector4D::new(m.a4, m.b4, m.c4, m.d4),
+        col(m.a1, m.b1, m.c1, m.d1),
+        col(m.a2, m.b2, m.c2, m.d2),
+        col(m.a3, m.b3, m.c3, m.d3),
+        col(m.a4, m.b4, m.c4, m.d4),
     )
 }
 
 #[inline]
 pub(crate) fn to_ai_matrix4x4(m: Matrix4x4) -> sys::aiMatrix4x4 {
-    let cols = m.to_cols_array_2d();
+    let cols = m.to_cols_array_2d().map(|col| col.map(to_real));
     sys::aiMatrix4x4 {
         a1: cols[0][0],
         a2: cols[1][0],
@@ -721,16 +1030,17 @@ pub(crate) fn to_ai_matrix4x4(m: Matrix4x4) -> sys::aiMatrix4x4 {
 
 #[inline]
 pub(crate) fn from_ai_matrix3x3(m: sys::aiMatrix3x3) -> Matrix3x3 {
+    let col = |a: Real, b: Real, c: Real| Vector3D::new(from_real(a), from_real(b), from_real(c));
     Matrix3x3::from_cols(
-        Vector3D::new(m.a1, m.b1, m.c1),
-        Vector3D::new(m.a2, m.b2, m.c2),
-        Vector3D::new(m.a3, m.b3, m.c3),
+        col(m.a1, m.b1, m.c1),
+        col(m.a2, m.b2, m.c2),
+        col(m.a3, m.b3, m.c3),
     )
 }
 
 #[inline]
 pub(crate) fn to_ai_matrix3x3(m: Matrix3x3) -> sys::aiMatrix3x3 {
-    let cols = m.to_cols_array_2d();
+    let cols = m.to_cols_array_2d().map(|col| col.map(to_real));
     sys::aiMatrix3x3 {
         a1: cols[0][0],
         a2: cols[1][0],
@@ -746,7 +1056,12 @@ pub(crate) fn to_ai_matrix3x3(m: Matrix3x3) -> sys::aiMatrix3x3 {
 
 #[inline]
 pub(crate) fn from_ai_quaternion(q: sys::aiQuaternion) -> Quaternion {
-    Quaternion::from_xyzw(q.x, q.y, q.z, q.w)
+    Quaternion::from_xyzw(
+        from_real(q.x),
+        from_real(q.y),
+        from_real(q.z),
+        from_real(q.w),
+    )
 }
 
 #[inline]
@@ -754,14 +1069,13 @@ pub(crate) fn from_ai_color3d(c: sys::aiColor3D) -> Color3D {
     Color3D::new(c.r, c.g, c.b)
 }
 
-#[cfg(feature = "raw-sys")]
 #[inline]
 pub(crate) fn to_ai_quaternion(q: Quaternion) -> sys::aiQuaternion {
     sys::aiQuaternion {
-        w: q.w,
-        x: q.x,
-        y: q.y,
-        z: q.z,
+        w: to_real(q.w),
+        x: to_real(q.x),
+        y: to_real(q.y),
+        z: to_real(q.z),
     }
 }
 
@@ -806,6 +1120,20 @@ pub fn ai_string_to_string_sys(value: &sys::aiString) -> String {
     ai_string_to_string(value)
 }
 
+/// Bytes of an Assimp `aiString` up to the first NUL (length clamped to the buffer), for
+/// `raw-sys` users.
+#[cfg(feature = "raw-sys")]
+pub fn ai_string_bytes_sys(value: &sys::aiString) -> &[u8] {
+    ai_string_bytes(value)
+}
+
+/// All `length` bytes of an Assimp `aiString` (clamped to the buffer), including
+/// embedded NULs, for `raw-sys` users.
+#[cfg(feature = "raw-sys")]
+pub fn ai_string_raw_bytes_sys(value: &sys::aiString) -> &[u8] {
+    ai_string_raw_bytes(value)
+}
+
 #[cfg(feature = "raw-sys")]
 pub fn from_ai_vector3d_sys(v: sys::aiVector3D) -> Vector3D {
     from_ai_vector3d(v)
@@ -910,6 +1238,40 @@ mod mint_integration {
         }
     }
 
+    // Also covers `Color4D`, which is an alias of `Vector4D`.
+    impl From<mint::Vector4<f32>> for Vector4D {
+        fn from(v: mint::Vector4<f32>) -> Self {
+            Self::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    impl From<Vector4D> for mint::Vector4<f32> {
+        fn from(v: Vector4D) -> Self {
+            Self {
+                x: v.x,
+                y: v.y,
+                z: v.z,
+                w: v.w,
+            }
+        }
+    }
+
+    impl From<mint::ColumnMatrix3<f32>> for Matrix3x3 {
+        fn from(m: mint::ColumnMatrix3<f32>) -> Self {
+            Self::from_cols(m.x.into(), m.y.into(), m.z.into())
+        }
+    }
+
+    impl From<Matrix3x3> for mint::ColumnMatrix3<f32> {
+        fn from(m: Matrix3x3) -> Self {
+            mint::ColumnMatrix3 {
+                x: m.x_axis.into(),
+                y: m.y_axis.into(),
+                z: m.z_axis.into(),
+            }
+        }
+    }
+
     impl From<mint::ColumnMatrix4<f32>> for Matrix4x4 {
         fn from(m: mint::ColumnMatrix4<f32>) -> Self {
             Self::from_cols(
@@ -1062,3 +1424,66 @@ mod glam_integration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ai_string_bytes, ai_string_raw_bytes, ai_string_to_name, ai_string_to_str};
+    use crate::sys;
+
+    fn ai_string(length: u32, data: &[u8]) -> sys::aiString {
+        let mut value = sys::aiString {
+            length,
+            data: [0; sys::AI_MAXLEN as usize],
+        };
+        for (dst, src) in value.data.iter_mut().zip(data) {
+            *dst = *src as std::os::raw::c_char;
+        }
+        value
+    }
+
+    #[test]
+    fn test_ai_string_length_past_buffer_is_clamped() {
+        let full = vec![b'a'; sys::AI_MAXLEN as usize];
+        for length in [sys::AI_MAXLEN, sys::AI_MAXLEN + 1, u32::MAX] {
+            let value = ai_string(length, &full);
+            assert_eq!(ai_string_raw_bytes(&value).len(), sys::AI_MAXLEN as usize);
+            assert_eq!(ai_string_bytes(&value), &full[..]);
+            assert_eq!(ai_string_to_str(&value).len(), sys::AI_MAXLEN as usize);
+        }
+
+        // A short string whose length overstates it stops at the terminator.
+        let value = ai_string(u32::MAX, b"abc");
+        assert_eq!(ai_string_bytes(&value), b"abc");
+        assert_eq!(ai_string_to_str(&value), "abc");
+        assert_eq!(ai_string_raw_bytes(&value).len(), sys::AI_MAXLEN as usize);
+    }
+
+    #[test]
+    fn test_ai_string_zero_length_ignores_data() {
+        let value = ai_string(0, b"leftover");
+        assert!(ai_string_bytes(&value).is_empty());
+        assert!(ai_string_raw_bytes(&value).is_empty());
+        assert_eq!(ai_string_to_str(&value), "");
+        assert_eq!(&*ai_string_to_name(&value), "");
+    }
+
+    #[test]
+    fn test_ai_string_interior_nul() {
+        let value = ai_string(9, b"name\0junk");
+        assert_eq!(ai_string_bytes(&value), b"name");
+        assert_eq!(ai_string_to_str(&value), "name");
+        assert_eq!(&*ai_string_to_name(&value), "name");
+        assert_eq!(ai_string_raw_bytes(&value), b"name\0junk");
+
+        let value = ai_string(3, b"\0ab");
+        assert!(ai_string_bytes(&value).is_empty());
+        assert_eq!(ai_string_raw_bytes(&value), b"\0ab");
+    }
+
+    #[test]
+    fn test_ai_string_invalid_utf8_is_lossy() {
+        let value = ai_string(3, &[b'a', 0xff, b'b']);
+        assert_eq!(ai_string_bytes(&value), &[b'a', 0xff, b'b']);
+        assert_eq!(ai_string_to_str(&value), "a\u{fffd}b");
+    }
+}
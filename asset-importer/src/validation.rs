@@ -0,0 +1,298 @@
+//! Structured results of Assimp's `ValidateDataStructure` step.
+//!
+//! Assimp reports validation problems only through its logger ("Validation warning: ...")
+//! or as the import error ("Validation failed: ..."). With
+//! [`ImportBuilder::with_validation`](crate::ImportBuilder::with_validation) the importer
+//! captures those lines during the import and parses them into a [`ValidationReport`],
+//! available afterwards from [`Scene::validation_report`](crate::Scene::validation_report).
+
+use std::fmt;
+
+use crate::logging::LogLevel;
+use crate::postprocess::PostProcessSteps;
+
+const WARNING_PREFIX: &str = "Validation warning: ";
+const ERROR_PREFIX: &str = "Validation failed: ";
+
+/// How an import treats `aiProcess_ValidateDataStructure` results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ValidationMode {
+    /// Do not validate; clears [`PostProcessSteps::VALIDATE_DATA_STRUCTURE`].
+    #[default]
+    Off,
+    /// Validate and attach the report to the scene; only validation errors fail the import.
+    Warn,
+    /// Validate and fail the import with [`Error::ValidationFailed`](crate::Error::ValidationFailed)
+    /// on any warning or error.
+    Strict,
+}
+
+impl ValidationMode {
+    /// `steps` with the validation flag set or cleared for this mode.
+    pub(crate) fn apply_to(self, steps: PostProcessSteps) -> PostProcessSteps {
+        match self {
+            Self::Off => steps - PostProcessSteps::VALIDATE_DATA_STRUCTURE,
+            Self::Warn | Self::Strict => steps | PostProcessSteps::VALIDATE_DATA_STRUCTURE,
+        }
+    }
+}
+
+/// Severity of a [`ValidationEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationSeverity {
+    /// Suspicious but usable data; Assimp sets `AI_SCENE_FLAGS_VALIDATION_WARNING`.
+    Warning,
+    /// Invalid data; Assimp aborts the import.
+    Error,
+}
+
+impl fmt::Display for ValidationSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// One problem reported by the validation step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationEntry {
+    /// Whether this is a warning or an error.
+    pub severity: ValidationSeverity,
+    /// What the problem refers to, such as `"mesh 3"` or `"face 12"`. Many Assimp
+    /// messages carry no index, giving just the kind (`"mesh"`) or `None`.
+    pub subject: Option<String>,
+    /// Assimp's message, without the "Validation warning:" prefix.
+    pub message: String,
+}
+
+impl ValidationEntry {
+    /// Parse a log line or error message emitted by the validation step.
+    ///
+    /// Returns `None` for lines that did not come from validation.
+    pub(crate) fn parse(line: &str) -> Option<Self> {
+        let (severity, message) = if let Some(at) = line.find(WARNING_PREFIX) {
+            (
+                ValidationSeverity::Warning,
+                &line[at + WARNING_PREFIX.len()..],
+            )
+        } else if let Some(at) = line.find(ERROR_PREFIX) {
+            (ValidationSeverity::Error, &line[at + ERROR_PREFIX.len()..])
+        } else {
+            return None;
+        };
+        let message = message.trim();
+        Some(Self {
+            severity,
+            subject: subject_of(message),
+            message: message.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for ValidationEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.subject {
+            Some(subject) => write!(f, "{} ({}): {}", self.severity, subject, self.message),
+            None => write!(f, "{}: {}", self.severity, self.message),
+        }
+    }
+}
+
+/// Validation problems found during one import, in the order Assimp reported them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// All warnings and errors.
+    pub entries: Vec<ValidationEntry>,
+}
+
+impl ValidationReport {
+    /// Collect the validation lines from captured log messages.
+    pub(crate) fn from_log(messages: &[(LogLevel, String)]) -> Self {
+        Self {
+            entries: messages
+                .iter()
+                .filter(|(level, _)| *level >= LogLevel::Warn)
+                .filter_map(|(_, line)| ValidationEntry::parse(line))
+                .collect(),
+        }
+    }
+
+    /// Whether validation found nothing to report.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Entries with [`ValidationSeverity::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.severity == ValidationSeverity::Warning)
+    }
+
+    /// Entries with [`ValidationSeverity::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.severity == ValidationSeverity::Error)
+    }
+
+    /// Whether any entry is an error.
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.entries.is_empty() {
+            return f.write_str("no validation problems");
+        }
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// Array members whose index identifies the subject, with the name used in reports.
+const INDEXED_MEMBERS: &[(&str, &str)] = &[
+    ("mMeshes[", "mesh"),
+    ("mMaterials[", "material"),
+    ("mAnimations[", "animation"),
+    ("mTextures[", "texture"),
+    ("mCameras[", "camera"),
+    ("mLights[", "light"),
+    ("mChildren[", "node"),
+    ("mBones[", "bone"),
+    ("mChannels[", "channel"),
+    ("mMeshChannels[", "mesh channel"),
+    ("mMorphMeshChannels[", "morph channel"),
+    ("mAnimMeshes[", "anim mesh"),
+    ("mProperties[", "property"),
+    ("mFaces[", "face"),
+];
+
+/// Assimp struct names at the start of a message; longer names come first.
+const STRUCT_NAMES: &[(&str, &str)] = &[
+    ("aiNodeAnim", "channel"),
+    ("aiMeshMorphAnim", "morph channel"),
+    ("aiMeshAnim", "mesh channel"),
+    ("aiAnimMesh", "anim mesh"),
+    ("aiAnimation", "animation"),
+    ("aiMaterial", "material"),
+    ("aiTexture", "texture"),
+    ("aiCamera", "camera"),
+    ("aiLight", "light"),
+    ("aiScene", "scene"),
+    ("aiMesh", "mesh"),
+    ("aiNode", "node"),
+    ("aiBone", "bone"),
+];
+
+fn subject_of(message: &str) -> Option<String> {
+    let indexed = INDEXED_MEMBERS
+        .iter()
+        .filter_map(|(member, name)| {
+            let start = message.find(member)? + member.len();
+            let digits = message[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(&message[start..], |end| &message[start..start + end]);
+            let index: usize = digits.parse().ok()?;
+            Some((start, format!("{} {}", name, index)))
+        })
+        .min_by_key(|(start, _)| *start);
+    if let Some((_, subject)) = indexed {
+        return Some(subject);
+    }
+    STRUCT_NAMES
+        .iter()
+        .find(|(prefix, _)| message.starts_with(prefix))
+        .map(|(_, name)| (*name).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_warning_and_error_lines() {
+        let warning = ValidationEntry::parse("Validation warning: There are unreferenced vertices")
+            .expect("warning line");
+        assert_eq!(warning.severity, ValidationSeverity::Warning);
+        assert_eq!(warning.subject, None);
+        assert_eq!(warning.message, "There are unreferenced vertices");
+
+        let error = ValidationEntry::parse(
+            "Validation failed: aiMesh::mFaces[3]::mIndices[1] is out of range",
+        )
+        .expect("error line");
+        assert_eq!(error.severity, ValidationSeverity::Error);
+        assert_eq!(error.subject.as_deref(), Some("face 3"));
+
+        assert!(ValidationEntry::parse("Info: Load model.obj").is_none());
+    }
+
+    #[test]
+    fn test_subject_prefers_first_index() {
+        assert_eq!(
+            subject_of("aiScene::mMeshes[2]::mFaces[7] is invalid").as_deref(),
+            Some("mesh 2")
+        );
+        assert_eq!(
+            subject_of("aiNodeAnim::mPositionKeys[0] is out of order").as_deref(),
+            Some("channel")
+        );
+        assert_eq!(subject_of("mMeshes[x] has no index"), None);
+    }
+
+    #[test]
+    fn test_report_from_log_and_display() {
+        let messages = vec![
+            (
+                LogLevel::Info,
+                "Validation warning: ignored below Warn".to_string(),
+            ),
+            (LogLevel::Warn, "Simple warning".to_string()),
+            (
+                LogLevel::Warn,
+                "Validation warning: aiMesh::mBones[1] has no weights".to_string(),
+            ),
+            (
+                LogLevel::Error,
+                "Validation failed: aiScene::mMeshes[0] is NULL".to_string(),
+            ),
+        ];
+        let report = ValidationReport::from_log(&messages);
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.warnings().count(), 1);
+        assert!(report.has_errors());
+        assert_eq!(
+            report.to_string(),
+            "warning (bone 1): aiMesh::mBones[1] has no weights; error (mesh 0): aiScene::mMeshes[0] is NULL"
+        );
+    }
+
+    #[test]
+    fn test_mode_sets_post_process_flag() {
+        let steps = PostProcessSteps::TRIANGULATE;
+        assert!(
+            ValidationMode::Warn
+                .apply_to(steps)
+                .contains(PostProcessSteps::VALIDATE_DATA_STRUCTURE)
+        );
+        assert_eq!(
+            ValidationMode::Off.apply_to(steps | PostProcessSteps::VALIDATE_DATA_STRUCTURE),
+            steps
+        );
+    }
+}
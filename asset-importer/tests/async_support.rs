@@ -6,9 +6,50 @@ use asset_importer::Importer;
 #[allow(unused_imports)]
 use std::time::Duration;
 
+// Scenes and builders must be able to cross into and out of `spawn_blocking`.
+static_assertions::assert_impl_all!(asset_importer::Scene: Send, Sync);
+static_assertions::assert_impl_all!(asset_importer::ImportBuilder: Send);
+
 #[cfg(feature = "tokio")]
 mod async_tests {
     use super::*;
+    use asset_importer::postprocess::PostProcessSteps;
+
+    #[tokio::test]
+    async fn test_import_two_models_concurrently() {
+        let importer = Importer::new();
+        let (boxed, from_memory) = tokio::join!(
+            importer.import_file_async("tests/models/box.obj", PostProcessSteps::TRIANGULATE),
+            importer.import_from_memory_async(
+                std::fs::read("tests/models/box.obj").expect("read box.obj"),
+                None,
+                PostProcessSteps::TRIANGULATE,
+            ),
+        );
+        let boxed = boxed.expect("async file import");
+        let from_memory = from_memory.expect("async memory import");
+
+        // Both imports triangulated the six quads of the cube.
+        for scene in [&boxed, &from_memory] {
+            let mesh = scene.meshes().next().expect("mesh");
+            assert_eq!(mesh.num_faces(), 12);
+        }
+
+        // The scene can move into another task after crossing back from the blocking pool.
+        let meshes = tokio::spawn(async move { boxed.num_meshes() })
+            .await
+            .expect("join");
+        assert_eq!(meshes, 1);
+    }
+
+    #[tokio::test]
+    async fn test_import_async_reports_errors() {
+        let result = Importer::new()
+            .read_file("tests/models/does_not_exist.obj")
+            .import_async()
+            .await;
+        assert!(result.is_err());
+    }
 
     // Test 1: Comprehensive compilation test for Send/Sync traits
     #[test]
@@ -0,0 +1,164 @@
+//! Fixtures shared by the integration tests
+//!
+//! Most glTF fixtures draw the same triangle, (0,0,0) (1,0,0) (0,1,0), and only differ
+//! in the nodes, meshes and materials around it. [`triangle_gltf`] supplies the buffer,
+//! buffer view 0 and the POSITION accessor 0; the test passes the remaining top-level
+//! members as a JSON object.
+//!
+//! Buffers are encoded with `asset_importer::test_utils` (`f32_bytes`, `gltf_buffer`),
+//! which the crate's dev-dependency on itself enables for every test binary.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use asset_importer::{Importer, Scene, postprocess::PostProcessSteps, test_utils};
+
+/// Positions of the shared triangle.
+pub const TRIANGLE_POSITIONS: [f32; 9] = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+
+/// [`TRIANGLE_POSITIONS`] as buffer bytes; extend it to append more attributes.
+pub fn triangle_bytes() -> Vec<u8> {
+    test_utils::f32_bytes(&TRIANGLE_POSITIONS)
+}
+
+/// A glTF document with `buffer` embedded as buffer 0, followed by the top-level
+/// `members` (a JSON object: `{ "bufferViews": ..., "scene": 0 }`).
+pub fn gltf(buffer: &[u8], members: &str) -> String {
+    format!(
+        r#"{{
+  "asset": {{ "version": "2.0" }},
+  "buffers": [{}],
+  {}
+}}"#,
+        test_utils::gltf_buffer(buffer),
+        object_body(members)
+    )
+}
+
+/// [`gltf`] with the shared triangle as buffer 0, buffer view 0 and accessor 0.
+pub fn triangle_gltf(members: &str) -> String {
+    let members = format!(
+        r#"{{
+  "bufferViews": [{{ "buffer": 0, "byteOffset": 0, "byteLength": 36 }}],
+  "accessors": [{{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] }}],
+  {}
+}}"#,
+        object_body(members)
+    );
+    gltf(&triangle_bytes(), &members)
+}
+
+/// Members of a scene with one node drawing the triangle, for [`triangle_gltf`].
+pub const TRIANGLE_SCENE: &str = r#"{
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+/// [`TRIANGLE_SCENE`] in a GLB container with no binary chunk.
+pub fn triangle_glb() -> Vec<u8> {
+    pack_glb(triangle_gltf(TRIANGLE_SCENE).into_bytes(), Vec::new())
+}
+
+/// A GLB whose binary chunk holds the triangle (buffer view 0, accessor 0) followed by
+/// `images`, each in its own buffer view starting at 1. `members` adds the remaining
+/// top-level members, e.g. `"images": [{ "bufferView": 1, "mimeType": "image/png" }]`.
+pub fn triangle_glb_with_images(images: &[&[u8]], members: &str) -> Vec<u8> {
+    let mut bin = triangle_bytes();
+    let mut views =
+        vec![r#"{ "buffer": 0, "byteOffset": 0, "byteLength": 36, "target": 34962 }"#.to_string()];
+    for image in images {
+        views.push(format!(
+            r#"{{ "buffer": 0, "byteOffset": {}, "byteLength": {} }}"#,
+            bin.len(),
+            image.len()
+        ));
+        bin.extend_from_slice(image);
+        pad4(&mut bin, 0);
+    }
+
+    let json = format!(
+        r#"{{
+  "asset": {{ "version": "2.0" }},
+  "buffers": [{{ "byteLength": {} }}],
+  "bufferViews": [
+    {}
+  ],
+  "accessors": [{{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] }}],
+  {}
+}}"#,
+        bin.len(),
+        views.join(",\n    "),
+        object_body(members)
+    );
+    pack_glb(json.into_bytes(), bin)
+}
+
+pub fn pad4(bytes: &mut Vec<u8>, fill: u8) {
+    while bytes.len() % 4 != 0 {
+        bytes.push(fill);
+    }
+}
+
+/// Wrap a JSON chunk and a (4-byte aligned) binary chunk into a GLB container. An empty
+/// `bin` leaves the binary chunk out.
+pub fn pack_glb(mut json: Vec<u8>, bin: Vec<u8>) -> Vec<u8> {
+    pad4(&mut json, b' ');
+
+    let bin_chunk = if bin.is_empty() { 0 } else { 8 + bin.len() };
+    let total = 12 + 8 + json.len() + bin_chunk;
+    let mut glb = Vec::with_capacity(total);
+    glb.extend_from_slice(b"glTF");
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&(total as u32).to_le_bytes());
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"JSON");
+    glb.extend_from_slice(&json);
+    if !bin.is_empty() {
+        glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"BIN\0");
+        glb.extend_from_slice(&bin);
+    }
+    glb
+}
+
+/// `tests/models/textured.obj`, triangulated.
+pub fn textured_scene() -> Scene {
+    Importer::new()
+        .read_file("tests/models/textured.obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import textured.obj")
+}
+
+/// An empty directory under the system temp dir, unique to this test process.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("asset_importer_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Asserts that `actual` is within 1e-5 of `expected`.
+pub fn assert_close(actual: impl Into<f64>, expected: impl Into<f64>) {
+    assert_close_within(actual, expected, 1e-5);
+}
+
+pub fn assert_close_within(actual: impl Into<f64>, expected: impl Into<f64>, tolerance: f64) {
+    let (actual, expected) = (actual.into(), expected.into());
+    assert!(
+        (actual - expected).abs() < tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+fn object_body(object: &str) -> &str {
+    object
+        .trim()
+        .strip_prefix('{')
+        .and_then(|body| body.strip_suffix('}'))
+        .expect("glTF members must be written as a JSON object")
+        .trim()
+}
@@ -0,0 +1,44 @@
+//! Assimp JSON export parsing tests
+#![cfg(feature = "assjson")]
+
+use crate::common;
+
+#[test]
+fn test_assjson_value_has_meshes() {
+    let scene = common::textured_scene();
+    let value = scene.to_assjson_value().expect("assjson export");
+
+    let meshes = value
+        .get("meshes")
+        .and_then(|meshes| meshes.as_array())
+        .expect("meshes array");
+    assert_eq!(meshes.len(), scene.num_meshes());
+    assert!(value.get("rootnode").is_some());
+}
+
+#[test]
+fn test_assjson_typed_matches_scene() {
+    let scene = common::textured_scene();
+    let json = scene.to_assjson().expect("typed assjson");
+
+    assert_eq!(json.meshes.len(), scene.num_meshes());
+    assert_eq!(json.materials.len(), scene.num_materials());
+
+    for (parsed, mesh) in json.meshes.iter().zip(scene.meshes()) {
+        let vertices = mesh.vertices();
+        let positions: Vec<[f32; 3]> = parsed.positions().collect();
+        assert_eq!(positions.len(), vertices.len());
+        for (p, v) in positions.iter().zip(&vertices) {
+            assert!(
+                (p[0] - v.x).abs() < 1e-5 && (p[1] - v.y).abs() < 1e-5 && (p[2] - v.z).abs() < 1e-5,
+                "{p:?} vs {v:?}"
+            );
+        }
+        assert_eq!(parsed.faces.len(), mesh.num_faces());
+        assert_eq!(parsed.material_index as usize, mesh.material_index());
+    }
+
+    let root = scene.root_node().expect("root node");
+    assert_eq!(json.root_node.name, root.name());
+    assert!(json.animations.is_empty());
+}
@@ -0,0 +1,100 @@
+//! One-shot conversion tests
+#![cfg(feature = "export")]
+
+use std::sync::{Arc, Mutex};
+
+use asset_importer::{
+    ConvertOptions, Error, Scene, convert,
+    error::Operation,
+    exporter::format_ids,
+    postprocess::PostProcessSteps,
+    progress::{ProgressEvent, ProgressStage},
+};
+
+use crate::common;
+
+#[test]
+fn test_convert_obj_to_gltf2_file() {
+    let dir = common::temp_dir("convert_file");
+    let output = dir.join("box.gltf");
+    let events: Arc<Mutex<Vec<ProgressEvent>>> = Arc::default();
+    let sink = events.clone();
+
+    let report = convert(
+        ConvertOptions::from_path("tests/models/box.obj")
+            .to_path(&output)
+            .with_post_process(PostProcessSteps::TRIANGULATE)
+            .with_progress_event_fn(move |event| {
+                sink.lock().unwrap().push(event.clone());
+                true
+            }),
+    )
+    .expect("convert OBJ to glTF");
+
+    assert_eq!(report.format_id, format_ids::GLTF2);
+    assert_eq!(report.output_path.as_deref(), Some(output.as_path()));
+    assert!(report.blob.is_none());
+    assert_eq!(report.statistics.triangles, 12);
+    assert_eq!(report.statistics.polygons, 0);
+
+    let back = Scene::from_file(&output).expect("converted glTF parses");
+    assert_eq!(back.num_meshes(), 1);
+    assert_eq!(back.mesh(0).unwrap().num_faces(), 12);
+
+    // Import updates stay in the first half, export updates in the second.
+    let events = events.lock().unwrap();
+    let writing: Vec<_> = events
+        .iter()
+        .filter(|e| e.stage == ProgressStage::Writing)
+        .collect();
+    assert_eq!(writing.len(), 2);
+    assert_eq!(writing[0].percent, 0.5);
+    assert!(writing[1].is_complete());
+    assert!(
+        events
+            .iter()
+            .filter(|e| e.stage != ProgressStage::Writing)
+            .all(|e| e.percent <= 0.5)
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_convert_memory_to_blob() {
+    let obj = std::fs::read("tests/models/box.obj").unwrap();
+    let report = convert(
+        ConvertOptions::from_memory(obj, Some("obj"))
+            .to_blob()
+            .with_format(format_ids::GLB2),
+    )
+    .expect("convert to GLB blob");
+
+    let blob = report.blob.expect("blob output");
+    let back = Scene::from_memory(blob.data(), Some("glb")).expect("GLB blob parses");
+    assert_eq!(back.num_meshes(), 1);
+    assert!(report.output_path.is_none());
+}
+
+#[test]
+fn test_convert_errors() {
+    // A blob has no extension to pick the format from.
+    let err = convert(ConvertOptions::from_path("tests/models/box.obj").to_blob()).unwrap_err();
+    assert!(matches!(err, Error::InvalidParameter { .. }), "{err}");
+
+    let err = convert(
+        ConvertOptions::from_path("tests/models/missing.obj")
+            .to_path(std::env::temp_dir().join("asset_importer_convert_missing.gltf")),
+    )
+    .unwrap_err();
+    assert_eq!(err.operation(), Some(Operation::Import));
+
+    let err = convert(
+        ConvertOptions::from_path("tests/models/box.obj")
+            .to_blob()
+            .with_format(format_ids::OBJ)
+            .with_progress_event_fn(|event| event.stage != ProgressStage::Writing),
+    )
+    .unwrap_err();
+    assert!(matches!(err, Error::Cancelled { .. }), "{err}");
+}
@@ -0,0 +1,144 @@
+//! Multi-file blob export tests
+#![cfg(feature = "export")]
+
+use asset_importer::{
+    Scene, config,
+    exporter::{EXPORT_BLOB_MAGIC, ExportBuilder, formats},
+};
+
+use crate::common;
+
+#[test]
+fn test_gltf_blob_parts_default_names() {
+    let scene = common::textured_scene();
+    let blob = ExportBuilder::new(formats::GLTF2)
+        .export_to_blob(&scene)
+        .expect("export glTF");
+
+    let parts: Vec<_> = blob.parts().collect();
+    assert!(
+        parts.len() >= 2,
+        "expected .gltf + .bin, got {}",
+        parts.len()
+    );
+    assert_eq!(parts.len(), blob.num_parts());
+    assert_eq!(parts[0].name, format!("{EXPORT_BLOB_MAGIC}.gltf"));
+    assert_eq!(parts[0].data, blob.data());
+    assert!(
+        parts
+            .iter()
+            .any(|p| p.name == format!("{EXPORT_BLOB_MAGIC}.bin") && !p.data.is_empty())
+    );
+}
+
+#[test]
+fn test_gltf_blob_write_all_to_dir_round_trip() {
+    let scene = common::textured_scene();
+    let blob = ExportBuilder::new(formats::GLTF2)
+        .with_config(config::EXPORT_BLOB_NAME, "scene".to_string())
+        .expect("blob name")
+        .export_to_blob(&scene)
+        .expect("export glTF");
+
+    let names: Vec<&str> = blob.parts().map(|p| p.name).collect();
+    assert_eq!(names[0], "scene.gltf");
+    assert!(names.contains(&"scene.bin"), "{names:?}");
+
+    let dir = common::temp_dir("blob");
+    let written = blob.write_all_to_dir(&dir).expect("write parts");
+    assert_eq!(written.len(), names.len());
+
+    let reimported = Scene::from_file(&written[0]);
+    let _ = std::fs::remove_dir_all(&dir);
+    let reimported = reimported.expect("re-import written glTF");
+    assert_eq!(reimported.num_meshes(), scene.num_meshes());
+}
+
+/// Part names and the bytes written for each, in write order.
+type Written = Vec<(String, Vec<u8>)>;
+
+/// Collects each part written through `ExportBlob::write_all_to`.
+#[derive(Default, Clone)]
+struct Sink(std::rc::Rc<std::cell::RefCell<Written>>);
+
+impl Sink {
+    fn writer(&self, name: &str) -> Box<dyn std::io::Write> {
+        let mut parts = self.0.borrow_mut();
+        parts.push((name.to_string(), Vec::new()));
+        Box::new(SinkWriter {
+            sink: self.clone(),
+            index: parts.len() - 1,
+        })
+    }
+}
+
+struct SinkWriter {
+    sink: Sink,
+    index: usize,
+}
+
+impl std::io::Write for SinkWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.sink.0.borrow_mut()[self.index]
+            .1
+            .extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_glb_blob_streams_to_writer() {
+    let scene = common::textured_scene();
+    let blob = ExportBuilder::new(formats::GLB2)
+        .export_to_blob(&scene)
+        .expect("export GLB");
+    assert_eq!(blob.num_parts(), 1);
+    assert_eq!(blob.total_size(), blob.size());
+
+    let part = blob.parts().next().expect("primary part");
+    // Zero-copy: the part borrows the same buffer as the blob.
+    assert_eq!(part.data().as_ptr(), blob.data().as_ptr());
+    assert_eq!(&part.data()[..4], b"glTF");
+
+    let mut streamed = Vec::new();
+    let written = blob
+        .write_part_to(part.name, &mut streamed)
+        .expect("write part");
+    assert_eq!(written, blob.size() as u64);
+    assert_eq!(streamed, blob.data());
+    assert!(blob.write_part_to("missing.bin", &mut Vec::new()).is_err());
+
+    let sink = Sink::default();
+    let total = blob
+        .write_all_to(|name| sink.writer(name))
+        .expect("stream parts");
+    assert_eq!(total, blob.total_size() as u64);
+    let parts = sink.0.borrow();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].0, part.name);
+    assert_eq!(parts[0].1, blob.data());
+}
+
+#[test]
+fn test_gltf_blob_write_all_to_matches_parts() {
+    let scene = common::textured_scene();
+    let blob = ExportBuilder::new(formats::GLTF2)
+        .export_to_blob(&scene)
+        .expect("export glTF");
+
+    let sink = Sink::default();
+    let total = blob
+        .write_all_to(|name| sink.writer(name))
+        .expect("stream parts");
+    assert_eq!(total, blob.total_size() as u64);
+
+    let expected: Written = blob
+        .parts()
+        .map(|p| (p.name.to_string(), p.data().to_vec()))
+        .collect();
+    assert_eq!(*sink.0.borrow(), expected);
+}
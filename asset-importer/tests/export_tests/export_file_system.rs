@@ -0,0 +1,145 @@
+//! Export through a custom file system
+#![cfg(feature = "export")]
+
+use std::sync::{Arc, Mutex};
+
+use asset_importer::{
+    Error, Importer,
+    exporter::{ExportBuilder, formats},
+    io::{FileStream, FileSystem, MemoryFileSystem},
+};
+
+use crate::common;
+
+#[test]
+fn test_gltf_export_to_memory_file_system() {
+    let scene = common::textured_scene();
+    let fs = Arc::new(Mutex::new(MemoryFileSystem::new()));
+
+    ExportBuilder::new(formats::GLTF2)
+        .with_file_system_shared(fs.clone())
+        .export_to_file(&scene, "scene.gltf")
+        .expect("export glTF into memory");
+
+    let written = fs.lock().unwrap();
+    let gltf = written.file("scene.gltf").expect("scene.gltf written");
+    let bin = written.file("scene.bin").expect("scene.bin written");
+    assert!(!gltf.is_empty());
+    assert!(!bin.is_empty());
+    assert!(
+        std::str::from_utf8(&gltf).unwrap().contains("scene.bin"),
+        "glTF should reference its buffer by the written name"
+    );
+    drop(written);
+
+    // The written files are a complete asset that imports back through the same file system.
+    let reimported = Importer::new()
+        .read_file("scene.gltf")
+        .with_file_system_shared(fs)
+        .import()
+        .expect("re-import exported glTF");
+    assert_eq!(reimported.num_meshes(), scene.num_meshes());
+}
+
+/// File system whose files accept `limit` bytes and then fail.
+#[derive(Debug)]
+struct LimitedFileSystem {
+    limit: usize,
+}
+
+struct LimitedStream {
+    remaining: usize,
+}
+
+impl FileSystem for LimitedFileSystem {
+    fn exists(&self, _path: &str) -> bool {
+        false
+    }
+
+    fn open(&self, path: &str) -> asset_importer::Result<Box<dyn FileStream>> {
+        Err(Error::file_error(format!("File not found: {path}")))
+    }
+
+    fn open_with_mode(
+        &self,
+        path: &str,
+        mode: &str,
+    ) -> asset_importer::Result<Box<dyn FileStream>> {
+        if mode.starts_with('r') {
+            return self.open(path);
+        }
+        Ok(Box::new(LimitedStream {
+            remaining: self.limit,
+        }))
+    }
+}
+
+impl FileStream for LimitedStream {
+    fn read(&mut self, _buffer: &mut [u8]) -> asset_importer::Result<usize> {
+        Ok(0)
+    }
+
+    fn write(&mut self, buffer: &[u8]) -> asset_importer::Result<usize> {
+        if buffer.len() > self.remaining {
+            return Err(Error::io_error("disk full"));
+        }
+        self.remaining -= buffer.len();
+        Ok(buffer.len())
+    }
+
+    fn tell(&self) -> asset_importer::Result<u64> {
+        Ok(0)
+    }
+
+    fn seek(&mut self, _position: u64) -> asset_importer::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> asset_importer::Result<u64> {
+        Ok(0)
+    }
+}
+
+#[test]
+fn test_export_write_failure_is_reported() {
+    let scene = common::textured_scene();
+    let err = ExportBuilder::new(formats::GLTF2)
+        .with_file_system(LimitedFileSystem { limit: 16 })
+        .export_to_file(&scene, "scene.gltf")
+        .expect_err("truncated write must fail the export");
+
+    match err {
+        Error::IoError { message, .. } => {
+            assert!(message.contains("scene."), "unexpected message: {message}");
+            assert!(
+                message.contains("disk full"),
+                "unexpected message: {message}"
+            );
+        }
+        other => panic!("expected an I/O error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_export_to_read_only_file_system_fails() {
+    let scene = common::textured_scene();
+    let mut fs = MemoryFileSystem::new();
+    fs.add_file("unrelated.txt", b"keep".to_vec());
+
+    // A file system without write support cannot receive an export.
+    #[derive(Debug)]
+    struct ReadOnly(MemoryFileSystem);
+    impl FileSystem for ReadOnly {
+        fn exists(&self, path: &str) -> bool {
+            self.0.exists(path)
+        }
+        fn open(&self, path: &str) -> asset_importer::Result<Box<dyn FileStream>> {
+            self.0.open(path)
+        }
+    }
+
+    let result = ExportBuilder::new(formats::OBJ)
+        .with_file_system(ReadOnly(fs))
+        .export_to_file(&scene, "scene.obj");
+    assert!(result.is_err());
+}
@@ -0,0 +1,138 @@
+//! Export format id and availability tests
+#![cfg(feature = "export")]
+
+use std::path::Path;
+
+use asset_importer::{
+    Importer, Scene,
+    exporter::{
+        ExportBuilder, export_format_desc, format_for_path, format_ids, is_format_available,
+    },
+};
+
+use crate::common;
+
+#[test]
+fn test_default_build_exports_obj_and_gltf2() {
+    assert!(is_format_available(format_ids::OBJ));
+    assert!(is_format_available(format_ids::GLTF2));
+    assert!(!is_format_available("not-a-format"));
+
+    let available: Vec<String> = asset_importer::get_export_formats()
+        .into_iter()
+        .map(|desc| desc.id)
+        .collect();
+    for id in &available {
+        assert!(
+            format_ids::ALL.contains(&id.as_str()),
+            "{id} has no format_ids constant"
+        );
+    }
+}
+
+#[test]
+fn test_export_format_extension() {
+    let obj = export_format_desc(format_ids::OBJ).expect("obj exporter");
+    assert_eq!(obj.file_extension, "obj");
+    let glb = export_format_desc(format_ids::GLB2).expect("glb2 exporter");
+    assert_eq!(glb.file_extension, "glb");
+}
+
+#[test]
+fn test_unknown_format_lists_available_ids() {
+    let scene = Importer::new()
+        .import_file("tests/models/box.obj")
+        .expect("import box.obj");
+
+    let Err(err) = ExportBuilder::new(format_ids::OBJ)
+        .format("not-a-format")
+        .export_to_blob(&scene)
+    else {
+        panic!("unknown format must fail");
+    };
+    let message = err.to_string();
+    assert!(message.contains("not-a-format"), "{message}");
+    assert!(message.contains(format_ids::GLTF2), "{message}");
+
+    let blob = ExportBuilder::new(format_ids::GLTF2)
+        .format(format_ids::OBJ_NO_MTL)
+        .export_to_blob(&scene)
+        .expect("export objnomtl");
+    assert_eq!(blob.num_parts(), 1);
+}
+
+fn format_id_for(path: &str) -> Option<String> {
+    format_for_path(Path::new(path), true).map(|desc| desc.id)
+}
+
+#[test]
+fn test_format_for_path_preferences() {
+    assert_eq!(
+        format_id_for("scene.glb").as_deref(),
+        Some(format_ids::GLB2)
+    );
+    assert_eq!(
+        format_id_for("SCENE.GLTF").as_deref(),
+        Some(format_ids::GLTF2)
+    );
+    assert_eq!(
+        format_id_for("dir/model.dae").as_deref(),
+        Some(format_ids::COLLADA)
+    );
+    assert_eq!(format_id_for("part.stl").as_deref(), Some(format_ids::STL));
+    assert_eq!(format_id_for("mesh.Obj").as_deref(), Some(format_ids::OBJ));
+    assert_eq!(format_id_for("no_extension"), None);
+    assert_eq!(format_id_for("scene.not-a-format"), None);
+
+    let desc = format_for_path(Path::new("scene.glb"), true).unwrap();
+    assert!(desc.matches_extension(".GLB"));
+    assert_eq!(desc.extensions().collect::<Vec<_>>(), ["glb"]);
+}
+
+#[test]
+fn test_format_for_path_ambiguous_versions() {
+    let latest_only = !is_format_available(format_ids::GLTF);
+    let strict = format_for_path(Path::new("scene.gltf"), false).map(|desc| desc.id);
+    if latest_only {
+        assert_eq!(strict.as_deref(), Some(format_ids::GLTF2));
+        return;
+    }
+    assert_eq!(strict, None);
+
+    let Err(err) = ExportBuilder::auto()
+        .prefer_latest(false)
+        .to_path_auto("scene.gltf")
+    else {
+        panic!("ambiguous extension must fail without prefer_latest");
+    };
+    let message = err.to_string();
+    assert!(message.contains(format_ids::GLTF2), "{message}");
+    assert!(message.contains("candidates"), "{message}");
+}
+
+#[test]
+fn test_to_path_auto_binary_flag_and_export() {
+    let ascii = ExportBuilder::auto()
+        .to_path_auto("part.STL")
+        .expect("stl exporter");
+    assert_eq!(ascii.format_id(), format_ids::STL);
+    let binary = ExportBuilder::auto()
+        .binary(true)
+        .to_path_auto("part.stl")
+        .expect("stlb exporter");
+    assert_eq!(binary.format_id(), format_ids::STL_BINARY);
+
+    let scene = Importer::new()
+        .import_file("tests/models/box.obj")
+        .expect("import box.obj");
+    let dir = common::temp_dir("auto");
+    let path = dir.join("box.GLB");
+    let exported = ExportBuilder::auto()
+        .to_path_auto(&path)
+        .and_then(|builder| builder.export(&scene));
+    let reimported = exported.and_then(|()| Scene::from_file(&path));
+    let _ = std::fs::remove_dir_all(&dir);
+    assert_eq!(reimported.expect("reimport glb").num_meshes(), 1);
+
+    assert!(ExportBuilder::auto().export(&scene).is_err());
+}
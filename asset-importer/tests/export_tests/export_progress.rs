@@ -0,0 +1,102 @@
+//! Export progress reporting and cancellation tests
+#![cfg(feature = "export")]
+
+use std::{
+    fmt::Write as _,
+    sync::{Arc, Mutex},
+};
+
+use asset_importer::{
+    Error, Scene,
+    exporter::{ExportBuilder, formats},
+};
+
+use crate::common;
+
+/// A 128x128 quad grid, large enough for the exporters to do real work.
+fn grid_scene() -> Scene {
+    let n = 128;
+    let mut obj = String::new();
+    for y in 0..=n {
+        for x in 0..=n {
+            writeln!(obj, "v {x} {y} 0").unwrap();
+        }
+    }
+    for y in 0..n {
+        for x in 0..n {
+            let a = y * (n + 1) + x + 1;
+            writeln!(obj, "f {} {} {} {}", a, a + 1, a + n + 2, a + n + 1).unwrap();
+        }
+    }
+    Scene::from_memory(obj.as_bytes(), Some("obj")).expect("import grid")
+}
+
+fn recording_builder(format: &str) -> (ExportBuilder, Arc<Mutex<Vec<f32>>>) {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = seen.clone();
+    let builder = ExportBuilder::new(format).with_progress_handler_fn(move |percent, _| {
+        sink.lock().unwrap().push(percent);
+        true
+    });
+    (builder, seen)
+}
+
+fn assert_increasing(seen: &[f32]) {
+    assert!(!seen.is_empty(), "no progress callback");
+    assert!(
+        seen.windows(2).all(|w| w[0] <= w[1]),
+        "progress went backwards: {seen:?}"
+    );
+    assert!(seen.iter().all(|p| (0.0..=1.0).contains(p)), "{seen:?}");
+    assert_eq!(seen.last(), Some(&1.0), "{seen:?}");
+}
+
+#[test]
+fn test_blob_export_reports_progress() {
+    let scene = grid_scene();
+    let (builder, seen) = recording_builder(formats::GLB2);
+    let blob = builder.export_to_blob(&scene).expect("export GLB");
+    assert!(blob.size() > 0);
+    assert_increasing(&seen.lock().unwrap());
+}
+
+#[test]
+fn test_file_export_reports_progress() {
+    let scene = grid_scene();
+    let dir = common::temp_dir("export_progress");
+    let path = dir.join("grid.stl");
+    let (builder, seen) = recording_builder(formats::STL);
+    let result = builder.export_to_file(&scene, &path);
+    let exists = path.exists();
+    let _ = std::fs::remove_dir_all(&dir);
+    result.expect("export STL");
+    assert!(exists);
+    assert_increasing(&seen.lock().unwrap());
+}
+
+#[test]
+fn test_cancelled_export_fails_and_removes_output() {
+    let scene = grid_scene();
+    let dir = common::temp_dir("export_cancel");
+    let path = dir.join("grid.stl");
+
+    // Cancel at the last stage, after the exporter has written the file.
+    let result = ExportBuilder::new(formats::STL)
+        .with_progress_handler_fn(|percent, _| percent < 1.0)
+        .export_to_file(&scene, &path);
+    let exists = path.exists();
+    let _ = std::fs::remove_dir_all(&dir);
+    let err = result.expect_err("cancelled export");
+    assert!(matches!(err, Error::Cancelled { .. }), "{err}");
+    assert_eq!(err.path(), Some(path.as_path()));
+    assert!(!exists, "partial output left behind");
+
+    let Err(err) = ExportBuilder::new(formats::GLB2)
+        .with_progress_handler_fn(|percent, _| percent < 0.5)
+        .export_to_blob(&scene)
+    else {
+        panic!("cancelled blob export succeeded");
+    };
+    assert!(matches!(err, Error::Cancelled { .. }), "{err}");
+    assert!(err.to_string().contains("cancelled"), "{err}");
+}
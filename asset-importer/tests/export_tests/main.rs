@@ -0,0 +1,11 @@
+//! Export tests
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod assjson;
+mod convert;
+mod export_blob;
+mod export_file_system;
+mod export_format;
+mod export_progress;
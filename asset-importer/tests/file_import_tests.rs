@@ -1,7 +1,10 @@
 //! File import tests using real model files
 //! These tests verify file-based import functionality
 
-use asset_importer::{Importer, postprocess::PostProcessSteps};
+use asset_importer::{
+    ImportBuilder, Importer, LogLevel, error::Operation, io::MemoryFileSystem,
+    postprocess::PostProcessSteps,
+};
 use std::path::Path;
 
 #[test]
@@ -86,6 +89,28 @@ fn test_file_import_nonexistent() {
     }
 }
 
+#[test]
+fn test_missing_file_error_names_path() {
+    let path = Path::new("assets").join("missing_model.fbx");
+    let err = Importer::new()
+        .read_file(&path)
+        .import()
+        .expect_err("missing file must fail");
+
+    assert_eq!(err.operation(), Some(Operation::Import));
+    assert_eq!(err.path(), Some(path.as_path()));
+    let message = err.to_string();
+    assert!(
+        message.starts_with(&format!("import of '{}' failed: ", path.display())),
+        "{message}"
+    );
+
+    let err = Importer::new()
+        .import_file(&path)
+        .expect_err("missing file must fail");
+    assert!(err.to_string().contains("missing_model.fbx"), "{err}");
+}
+
 #[test]
 fn test_import_builder_chaining() {
     // Test ImportBuilder method chaining
@@ -286,3 +311,305 @@ fn test_mesh_data_access() {
         }
     }
 }
+
+#[test]
+fn test_import_report_captures_missing_mtl_warning() {
+    let dir = std::env::temp_dir().join(format!("asset_importer_report_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    let obj_path = dir.join("missing_mtl.obj");
+    std::fs::write(
+        &obj_path,
+        "mtllib does_not_exist.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl Missing\nf 1 2 3\n",
+    )
+    .expect("write obj");
+
+    let result = Importer::new()
+        .read_file(&obj_path)
+        .capture_log(LogLevel::Warn)
+        .import_with_report();
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let (scene, report) = result.expect("OBJ without its MTL should still import");
+    assert_eq!(scene.num_meshes(), 1);
+    assert!(report.has_warnings());
+    assert!(
+        report
+            .messages
+            .iter()
+            .any(|(level, msg)| *level >= LogLevel::Warn && msg.contains("does_not_exist.mtl")),
+        "report should mention the missing material library: {:?}",
+        report.messages
+    );
+    assert!(
+        report
+            .messages
+            .iter()
+            .all(|(level, _)| *level >= LogLevel::Warn)
+    );
+}
+
+/// A single triangle in AMF, an importer Assimp flags as limited / experimental.
+const AMF_TRIANGLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<amf unit="millimeter">
+  <object id="0">
+    <mesh>
+      <vertices>
+        <vertex><coordinates><x>0</x><y>0</y><z>0</z></coordinates></vertex>
+        <vertex><coordinates><x>1</x><y>0</y><z>0</z></coordinates></vertex>
+        <vertex><coordinates><x>0</x><y>1</y><z>0</z></coordinates></vertex>
+      </vertices>
+      <volume>
+        <triangle><v1>0</v1><v2>1</v2><v3>2</v3></triangle>
+      </volume>
+    </mesh>
+  </object>
+</amf>"#;
+
+#[test]
+fn test_import_report_warns_on_limited_support() {
+    let flagged: Vec<_> = asset_importer::get_all_importer_descs()
+        .into_iter()
+        .filter(|desc| desc.has_limited_support() || desc.is_experimental())
+        .collect();
+    let Some(amf) = flagged
+        .iter()
+        .find(|desc| desc.file_extensions.iter().any(|ext| ext == "amf"))
+    else {
+        eprintln!(
+            "skipping: no limited/experimental importer with a fixture (flagged: {:?})",
+            flagged.iter().map(|desc| &desc.name).collect::<Vec<_>>()
+        );
+        return;
+    };
+
+    let (scene, report) = Importer::new()
+        .read_from_memory(AMF_TRIANGLE.as_bytes())
+        .with_memory_hint("amf")
+        .import_with_report()
+        .expect("import AMF");
+    assert_eq!(scene.num_meshes(), 1);
+    assert!(report.has_warnings());
+    let (level, first) = &report.messages[0];
+    assert_eq!(*level, LogLevel::Warn);
+    assert!(first.contains(&amf.name), "{:?}", report.messages);
+
+    let (_, quiet) = Importer::new()
+        .read_from_memory(AMF_TRIANGLE.as_bytes())
+        .with_memory_hint("amf")
+        .warn_on_limited_support(false)
+        .import_with_report()
+        .expect("import AMF");
+    assert!(
+        quiet
+            .messages
+            .iter()
+            .all(|(_, msg)| !msg.contains(&amf.name)),
+        "{:?}",
+        quiet.messages
+    );
+}
+
+#[test]
+fn test_importer_desc_flag_helpers() {
+    let obj = asset_importer::get_importer_desc("obj")
+        .unwrap()
+        .expect("OBJ importer");
+    assert!(obj.supports_text());
+    assert!(!obj.has_limited_support());
+    assert!(!obj.is_experimental());
+
+    let (_, report) = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import_with_report()
+        .expect("import box.obj");
+    assert!(
+        report
+            .messages
+            .iter()
+            .all(|(_, msg)| !msg.contains(&obj.name)),
+        "{:?}",
+        report.messages
+    );
+}
+
+#[test]
+fn test_all_importers_for_extension() {
+    use asset_importer::importer_desc::all_for_extension;
+
+    let names = |ext| {
+        all_for_extension(ext)
+            .into_iter()
+            .map(|desc| desc.name)
+            .collect::<Vec<_>>()
+    };
+    let gltf = names("gltf");
+    assert!(!gltf.is_empty(), "no importer for .gltf");
+    assert_eq!(gltf, names("gltf"), "order changed between calls");
+    assert_eq!(gltf, names(".GLTF"));
+
+    // The single-importer lookup returns the first candidate.
+    let first = asset_importer::get_importer_desc("gltf")
+        .unwrap()
+        .expect("glTF importer");
+    assert_eq!(first.name, gltf[0]);
+    let cstr = asset_importer::get_importer_desc_cstr(c".GLTF").expect("glTF importer");
+    assert_eq!(cstr.name, gltf[0]);
+    assert!(asset_importer::get_importer_desc("*").unwrap().is_none());
+
+    assert!(names("invalid_extension_xyz").is_empty());
+    assert!(names("*").is_empty());
+}
+
+const OBJ_WITH_MTLLIB: &str = "mtllib mem.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl m\nf 1 2 3\n";
+
+fn mtl_file_system() -> MemoryFileSystem {
+    let mut fs = MemoryFileSystem::new();
+    fs.add_file("mem.mtl", b"newmtl m\nKd 0.25 0.5 0.75\n".to_vec());
+    fs
+}
+
+fn assert_mtl_resolved(scene: &asset_importer::Scene) {
+    let material = scene
+        .materials()
+        .find(|m| m.name() == "m")
+        .expect("material from mem.mtl");
+    let kd = material.diffuse_color().expect("diffuse color");
+    assert!((kd.x - 0.25).abs() < 1e-6);
+    assert!((kd.y - 0.5).abs() < 1e-6);
+    assert!((kd.z - 0.75).abs() < 1e-6);
+}
+
+#[test]
+fn test_memory_import_resolves_mtl_through_file_system() {
+    let scene = Importer::new()
+        .read_from_memory(OBJ_WITH_MTLLIB.as_bytes())
+        .with_memory_hint("obj")
+        .with_file_system(mtl_file_system())
+        .import()
+        .expect("import OBJ from memory with MTL from file system");
+    assert_mtl_resolved(&scene);
+}
+
+#[test]
+fn test_memory_import_with_file_system_detects_missing_hint() {
+    // Without a hint the served name takes the detected format's extension.
+    let scene = Importer::new()
+        .read_from_memory(OBJ_WITH_MTLLIB.as_bytes())
+        .with_file_system(mtl_file_system())
+        .import()
+        .expect("import OBJ from memory without a hint");
+    assert_mtl_resolved(&scene);
+}
+
+/// Copy `textured.obj` + `textured.mtl` into `dir` and import the OBJ from there.
+fn import_textured_from(dir: &Path) -> asset_importer::Result<asset_importer::Scene> {
+    std::fs::create_dir_all(dir).expect("create model dir");
+    for name in ["textured.obj", "textured.mtl"] {
+        std::fs::copy(Path::new("tests/models").join(name), dir.join(name)).expect("copy model");
+    }
+    let result = Importer::new().read_file(dir.join("textured.obj")).import();
+    let _ = std::fs::remove_dir_all(dir);
+    result
+}
+
+#[cfg(windows)]
+#[test]
+fn test_import_from_non_ascii_directory() {
+    let dir = std::env::temp_dir().join(format!("模型_{}", std::process::id()));
+    let scene = import_textured_from(&dir).expect("import OBJ from non-ASCII directory");
+    assert_eq!(scene.num_meshes(), 1);
+    assert!(scene.materials().any(|m| m.name() == "mat0"));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_import_from_non_utf8_directory() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let dir_name = OsStr::from_bytes(b"asset_importer_\xff\xfe");
+    let dir = std::env::temp_dir()
+        .join(dir_name)
+        .join(std::process::id().to_string());
+    let scene = import_textured_from(&dir).expect("import OBJ from non-UTF-8 directory");
+    let _ = std::fs::remove_dir_all(std::env::temp_dir().join(dir_name));
+    assert_eq!(scene.num_meshes(), 1);
+    assert!(scene.materials().any(|m| m.name() == "mat0"));
+}
+
+/// Forwards `Read` only, so the importer cannot seek or query the length.
+struct NonSeekable<R>(R);
+
+impl<R: std::io::Read> std::io::Read for NonSeekable<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // Hand out small chunks to exercise repeated reads.
+        let len = buf.len().min(7);
+        self.0.read(&mut buf[..len])
+    }
+}
+
+#[test]
+fn test_import_from_non_seekable_reader() {
+    let obj = std::fs::read("tests/models/box.obj").expect("read box.obj");
+    let reader = NonSeekable(std::io::Cursor::new(obj.as_slice()));
+
+    let scene = ImportBuilder::new()
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import_from_reader(reader, "obj")
+        .expect("import OBJ from reader");
+    assert_eq!(scene.num_meshes(), 1);
+    assert_eq!(scene.mesh(0).unwrap().num_faces(), 12);
+
+    let reader = NonSeekable(std::io::Cursor::new(obj.as_slice()));
+    let scene = Importer::new()
+        .import_from_reader(reader, ".obj")
+        .expect("import OBJ from reader with dotted hint");
+    assert_eq!(scene.num_meshes(), 1);
+}
+
+#[test]
+fn test_import_from_reader_errors() {
+    let reader = std::io::Cursor::new(b"v 0 0 0\n".to_vec());
+    assert!(Importer::new().import_from_reader(reader, "").is_err());
+
+    struct Failing;
+    impl std::io::Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("connection reset"))
+        }
+    }
+    let err = Importer::new()
+        .import_from_reader(Failing, "obj")
+        .expect_err("read error must surface");
+    assert_eq!(err.operation(), Some(Operation::Import));
+    let source = std::error::Error::source(&err).expect("I/O source");
+    assert!(source.to_string().contains("connection reset"), "{source}");
+    assert!(!err.to_string().contains("connection reset"), "{err}");
+}
+
+#[test]
+fn test_verbose_logging_is_per_import() {
+    let import = |verbose: bool| {
+        Importer::new()
+            .read_file("tests/models/box.obj")
+            .with_post_process(PostProcessSteps::TRIANGULATE | PostProcessSteps::GEN_NORMALS)
+            .capture_log(LogLevel::Debug)
+            .with_verbose_logging(verbose)
+            .import_with_report()
+            .expect("import box.obj")
+            .1
+    };
+    let verbose = import(true);
+    let quiet = import(false);
+
+    let verbose_debug = verbose.messages_at(LogLevel::Debug).count();
+    assert!(verbose_debug >= 2, "{:?}", verbose.messages);
+    assert_eq!(
+        quiet.messages_at(LogLevel::Debug).count(),
+        0,
+        "{:?}",
+        quiet.messages
+    );
+    assert!(verbose.messages.len() >= quiet.messages.len() + verbose_debug);
+    assert!(!asset_importer::logging::is_verbose_logging_enabled());
+}
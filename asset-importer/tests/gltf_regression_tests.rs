@@ -1,3 +1,5 @@
+mod common;
+
 use asset_importer::{Scene, TextureType, animation::AnimInterpolation, version};
 
 const GLTF_PNG_1X1: &str =
@@ -7,13 +9,6 @@ const GLTF_POSITIONS_BASE64: &str = "AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8
 
 const GLTF_CUBIC_TRANSLATION_BASE64: &str = "AAAAAAAAgD8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAAAAAAAAAAAAAAAAAAAAAAAAAAA=";
 
-fn assert_close(actual: f32, expected: f32) {
-    assert!(
-        (actual - expected).abs() < 1e-5,
-        "expected {expected}, got {actual}"
-    );
-}
-
 fn gltf_605_regressions_available() -> bool {
     let runtime = (
         version::assimp_version_major(),
@@ -163,12 +158,12 @@ fn gltf_import_preserves_normal_scale_and_occlusion_strength() {
     assert!(material.occlusion_texture(0).is_some());
     assert!(material.ambient_occlusion_texture(0).is_none());
 
-    assert_close(material.normal_texture_scale(0).unwrap(), 0.42);
-    assert_close(
+    common::assert_close(material.normal_texture_scale(0).unwrap(), 0.42);
+    common::assert_close(
         material.texture_strength(TextureType::Lightmap, 0).unwrap(),
         0.73,
     );
-    assert_close(material.occlusion_texture_strength(0).unwrap(), 0.73);
+    common::assert_close(material.occlusion_texture_strength(0).unwrap(), 0.73);
 }
 
 #[test]
@@ -196,10 +191,10 @@ fn gltf_import_preserves_cubic_spline_translation_tangents() {
     assert_eq!(keys[4].time, 1000.0);
     assert_eq!(keys[5].time, 1000.0);
 
-    assert_close(keys[0].value.x, 0.0);
-    assert_close(keys[1].value.x, 0.0);
-    assert_close(keys[2].value.x, 1.0);
-    assert_close(keys[3].value.x, 0.0);
-    assert_close(keys[4].value.x, 2.0);
-    assert_close(keys[5].value.x, 0.0);
+    common::assert_close(keys[0].value.x, 0.0);
+    common::assert_close(keys[1].value.x, 0.0);
+    common::assert_close(keys[2].value.x, 1.0);
+    common::assert_close(keys[3].value.x, 0.0);
+    common::assert_close(keys[4].value.x, 2.0);
+    common::assert_close(keys[5].value.x, 0.0);
 }
@@ -0,0 +1,143 @@
+//! Typed configuration key tests
+
+use asset_importer::{
+    ImportBuilder, Importer, PropertyStore, Scene, config,
+    postprocess::{Components, PostProcessSteps},
+    types::{Matrix4x4, Vector4D},
+};
+
+use crate::common;
+
+// One triangle under a node translated by (1, 2, 3).
+const TRANSLATED_TRIANGLE_GLTF: &str = r#"{
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+  "nodes": [{ "name": "Moved", "mesh": 0, "translation": [1.0, 2.0, 3.0] }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+fn import_scaled(scale: f32) -> Scene {
+    Importer::new()
+        .read_from_memory(common::triangle_gltf(TRANSLATED_TRIANGLE_GLTF).as_bytes())
+        .with_memory_hint("gltf")
+        .with_config(config::GLOBAL_SCALE_FACTOR, scale)
+        .expect("valid scale")
+        .with_post_process(PostProcessSteps::GLOBAL_SCALE)
+        .import()
+        .expect("import glTF")
+}
+
+#[test]
+fn test_global_scale_factor_typed_key() {
+    let unscaled = import_scaled(1.0);
+    let scaled = import_scaled(2.0);
+
+    let node = |scene: &Scene| scene.root_node().unwrap().find_by_name("Moved").unwrap();
+    let t1 = node(&unscaled).transformation().w_axis;
+    let t2 = node(&scaled).transformation().w_axis;
+    assert_eq!((t1.x, t1.y, t1.z), (1.0, 2.0, 3.0));
+    assert_eq!((t2.x, t2.y, t2.z), (2.0, 4.0, 6.0));
+
+    let v1 = unscaled.mesh(0).unwrap().vertices()[1];
+    let v2 = scaled.mesh(0).unwrap().vertices()[1];
+    assert_eq!(v2.x, v1.x * 2.0);
+}
+
+#[test]
+fn test_config_rejects_invalid_values() {
+    let result = Importer::new()
+        .read_from_memory(common::triangle_gltf(TRANSLATED_TRIANGLE_GLTF).as_bytes())
+        .with_config(config::GLOBAL_SCALE_FACTOR, -1.0);
+    assert!(result.is_err());
+
+    let mut store = PropertyStore::new();
+    assert!(store.set(config::PP_LBW_MAX_WEIGHTS, 0).is_err());
+    assert!(store.is_empty());
+}
+
+#[test]
+fn test_property_store_merge_last_wins() {
+    let mut base = PropertyStore::new();
+    base.set(config::GLOBAL_SCALE_FACTOR, 1.0).unwrap();
+    base.set(config::FBX_READ_ANIMATIONS, false).unwrap();
+
+    let mut overrides = PropertyStore::new();
+    overrides.set(config::GLOBAL_SCALE_FACTOR, 3.0).unwrap();
+
+    base.merge(&overrides);
+    assert_eq!(base.len(), 2);
+    let scale: Vec<_> = base
+        .properties()
+        .iter()
+        .filter(|(name, _)| name == config::GLOBAL_SCALE_FACTOR.name())
+        .collect();
+    assert_eq!(scale.len(), 1);
+    assert!(matches!(
+        scale[0].1,
+        asset_importer::PropertyValue::Float(v) if v == 3.0
+    ));
+}
+
+const NORMAL_TRIANGLE_OBJ: &str = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 1\nf 1//1 2//1 3//1\n";
+
+fn import_triangle(builder: ImportBuilder) -> Scene {
+    builder
+        .with_memory_hint("obj")
+        .import()
+        .expect("import OBJ")
+}
+
+#[test]
+fn test_remove_components_strips_normals() {
+    let read = || Importer::new().read_from_memory(NORMAL_TRIANGLE_OBJ.as_bytes());
+
+    let kept = import_triangle(read());
+    assert!(kept.mesh(0).unwrap().has_normals());
+
+    let stripped = import_triangle(read().remove_components(Components::NORMALS));
+    assert!(!stripped.mesh(0).unwrap().has_normals());
+
+    // The step survives a later post-process override.
+    let stripped = import_triangle(
+        read()
+            .remove_components(Components::NORMALS)
+            .with_post_process(PostProcessSteps::TRIANGULATE),
+    );
+    let mesh = stripped.mesh(0).unwrap();
+    assert!(!mesh.has_normals());
+    assert_eq!(mesh.num_vertices(), 3);
+}
+
+#[test]
+fn test_matrix_properties_through_progress_bridge() {
+    let translation = |x: f32| {
+        Matrix4x4::from_cols(
+            Vector4D::new(1.0, 0.0, 0.0, 0.0),
+            Vector4D::new(0.0, 1.0, 0.0, 0.0),
+            Vector4D::new(0.0, 0.0, 1.0, 0.0),
+            Vector4D::new(x, 0.0, 0.0, 1.0),
+        )
+    };
+    // A progress handler routes the import through the Rust property bridge. Seven
+    // unused matrices come first, so the root transform is the eighth matrix stored.
+    let mut builder = Importer::new()
+        .read_from_memory(common::triangle_gltf(TRANSLATED_TRIANGLE_GLTF).as_bytes())
+        .with_memory_hint("gltf")
+        .with_progress_handler_fn(|_, _| true)
+        .with_post_process(PostProcessSteps::PRE_TRANSFORM_VERTICES);
+    for i in 0..7 {
+        builder = builder.with_property_matrix(format!("UNUSED_MATRIX_{i}"), translation(i as f32));
+    }
+    let scene = builder
+        .with_config(config::PP_PTV_ADD_ROOT_TRANSFORMATION, true)
+        .expect("valid flag")
+        .with_config(config::PP_PTV_ROOT_TRANSFORMATION, translation(10.0))
+        .expect("valid matrix")
+        .import()
+        .expect("import with matrix properties");
+
+    // The node's (1, 2, 3) translation plus the root transform's 10 along X.
+    let vertices = scene.mesh(0).expect("mesh").vertices();
+    assert!((vertices[0].x - 11.0).abs() < 1e-5, "{:?}", vertices[0]);
+    assert!((vertices[0].y - 2.0).abs() < 1e-5, "{:?}", vertices[0]);
+}
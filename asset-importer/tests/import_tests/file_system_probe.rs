@@ -0,0 +1,190 @@
+//! Existence probes through a custom FileSystem do not open files
+
+use std::sync::{Arc, Mutex};
+
+use asset_importer::{
+    Error, Importer,
+    io::{DefaultFileSystem, FileStream, FileSystem, MemoryFileSystem},
+};
+
+use crate::common;
+
+/// Forwards to a [`MemoryFileSystem`] and records every call.
+#[derive(Debug)]
+struct CountingFileSystem {
+    inner: MemoryFileSystem,
+    log: Arc<Mutex<Calls>>,
+}
+
+#[derive(Debug, Default)]
+struct Calls {
+    exists: Vec<String>,
+    sized: Vec<String>,
+    opened: Vec<String>,
+}
+
+impl CountingFileSystem {
+    fn new(files: &[(&str, &[u8])]) -> (Self, Arc<Mutex<Calls>>) {
+        let mut inner = MemoryFileSystem::new();
+        for (path, data) in files {
+            inner.add_file(*path, data.to_vec());
+        }
+        let log = Arc::new(Mutex::new(Calls::default()));
+        (
+            Self {
+                inner,
+                log: log.clone(),
+            },
+            log,
+        )
+    }
+}
+
+impl FileSystem for CountingFileSystem {
+    fn exists(&self, path: &str) -> bool {
+        self.log.lock().unwrap().exists.push(path.to_string());
+        self.inner.exists(path)
+    }
+
+    fn size_hint(&self, path: &str) -> Option<u64> {
+        self.log.lock().unwrap().sized.push(path.to_string());
+        self.inner.size_hint(path)
+    }
+
+    fn open(&self, path: &str) -> Result<Box<dyn FileStream>, Error> {
+        self.log.lock().unwrap().opened.push(path.to_string());
+        self.inner.open(path)
+    }
+}
+
+/// Implements only `open`, so the trait's default methods are used.
+#[derive(Debug)]
+struct OpenOnlyFileSystem(MemoryFileSystem);
+
+impl FileSystem for OpenOnlyFileSystem {
+    fn open(&self, path: &str) -> Result<Box<dyn FileStream>, Error> {
+        self.0.open(path)
+    }
+}
+
+/// Claims every file exists but fails to open any of them.
+#[derive(Debug)]
+struct UnopenableFileSystem;
+
+impl FileSystem for UnopenableFileSystem {
+    fn exists(&self, _path: &str) -> bool {
+        true
+    }
+
+    fn open(&self, _path: &str) -> Result<Box<dyn FileStream>, Error> {
+        Err(Error::file_error("permission denied"))
+    }
+}
+
+const TRIANGLE_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "buffers": [{ "uri": "triangle.bin", "byteLength": 36 }],
+  "bufferViews": [{ "buffer": 0, "byteOffset": 0, "byteLength": 36 }],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] }
+  ],
+  "images": [{ "uri": "missing.png" }],
+  "textures": [{ "source": 0 }],
+  "materials": [{ "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } }],
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] }],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_gltf_import_opens_only_existing_files() {
+    let bin = common::triangle_bytes();
+    let (fs, log) = CountingFileSystem::new(&[
+        ("triangle.gltf", TRIANGLE_GLTF.as_bytes()),
+        ("triangle.bin", &bin),
+    ]);
+
+    let scene = Importer::new()
+        .read_file("triangle.gltf")
+        .with_file_system(fs)
+        .import()
+        .expect("import glTF");
+    assert_eq!(scene.mesh(0).unwrap().num_vertices(), 3);
+
+    let log = log.lock().unwrap();
+    assert!(log.exists.iter().any(|p| p.ends_with("triangle.gltf")));
+    // Size queries on unread files go to `size_hint` instead of opening them.
+    assert!(log.sized.iter().any(|p| p.ends_with("triangle.gltf")));
+    assert!(log.opened.iter().any(|p| p.ends_with("triangle.bin")));
+    for path in &log.opened {
+        assert!(
+            path.ends_with("triangle.gltf") || path.ends_with("triangle.bin"),
+            "opened {path}"
+        );
+    }
+    // Every open was preceded by an existence check.
+    assert!(log.exists.len() >= log.opened.len());
+}
+
+#[test]
+fn test_missing_mtl_is_probed_without_opening() {
+    let obj = b"mtllib missing.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+    let (fs, log) = CountingFileSystem::new(&[("model.obj", obj)]);
+
+    let scene = Importer::new()
+        .read_file("model.obj")
+        .with_file_system(fs)
+        .import()
+        .expect("import OBJ");
+    assert_eq!(scene.num_meshes(), 1);
+
+    let log = log.lock().unwrap();
+    assert!(
+        log.exists.iter().any(|p| p.ends_with("missing.mtl")),
+        "{:?}",
+        log.exists
+    );
+    assert!(!log.opened.iter().any(|p| p.ends_with("missing.mtl")));
+}
+
+#[test]
+fn test_size_hint() {
+    let mut memory = MemoryFileSystem::new();
+    memory.add_file("a.bin", vec![0; 5]);
+    assert_eq!(memory.size_hint("a.bin"), Some(5));
+    assert_eq!(memory.size_hint("b.bin"), None);
+
+    // The default implementation goes through `open`.
+    let mut inner = MemoryFileSystem::new();
+    inner.add_file("a.bin", vec![0; 7]);
+    let open_only = OpenOnlyFileSystem(inner);
+    assert_eq!(open_only.size_hint("a.bin"), Some(7));
+    assert_eq!(open_only.size_hint("b.bin"), None);
+    assert_eq!(UnopenableFileSystem.size_hint("a.bin"), None);
+}
+
+#[test]
+fn test_failed_open_of_existing_file_is_reported() {
+    let err = Importer::new()
+        .read_file("model.obj")
+        .with_file_system(UnopenableFileSystem)
+        .import()
+        .expect_err("no file can be opened");
+    let message = err.to_string();
+    assert!(message.contains("Failed to open"), "{message}");
+    assert!(message.contains("permission denied"), "{message}");
+}
+
+#[test]
+fn test_default_open_error_keeps_path_and_source() {
+    let path = common::temp_dir("default_open_error").join("missing.bin");
+    let path = path.to_str().unwrap();
+    let Err(err) = DefaultFileSystem.open(path) else {
+        panic!("file is missing");
+    };
+    assert_eq!(err.path(), Some(std::path::Path::new(path)));
+    let source = std::error::Error::source(&err).expect("I/O source");
+    let io = source.downcast_ref::<std::io::Error>().expect("io::Error");
+    assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+}
@@ -0,0 +1,49 @@
+//! Format capability matrix of the linked Assimp build
+
+use asset_importer::capabilities::{Direction, capabilities};
+
+#[cfg(feature = "export")]
+#[test]
+fn test_obj_and_gltf2_read_and_write() {
+    let caps = capabilities();
+    for name in ["obj", "gltf2"] {
+        let format = caps.get(name).unwrap_or_else(|| panic!("{name} missing"));
+        assert!(format.read, "{name} not readable");
+        assert!(format.write, "{name} not writable");
+    }
+    assert_eq!(caps.get("obj").unwrap().exporter_id(), Some("obj"));
+    assert_eq!(caps.get("gltf2").unwrap().exporter_id(), Some("gltf2"));
+
+    assert!(caps.supports("scene.glb", Direction::Write));
+    assert!(caps.supports(".OBJ", Direction::Read));
+    assert!(caps.supports("ply", Direction::Write));
+}
+
+#[test]
+fn test_import_only_extension_is_read_only() {
+    let caps = capabilities();
+    let blend = caps.get("blend").expect("Blender importer");
+    assert!(blend.read);
+    assert!(!blend.write);
+    assert!(blend.exporter_id().is_none());
+    assert!(blend.has_extension("blend"));
+
+    assert!(caps.supports("model.blend", Direction::Read));
+    assert!(!caps.supports("model.blend", Direction::Write));
+    assert!(!caps.supports("model.not-a-format", Direction::Read));
+}
+
+#[test]
+fn test_every_import_extension_is_readable() {
+    let caps = capabilities();
+    for extension in asset_importer::get_import_extensions() {
+        assert!(
+            caps.supports(&extension, Direction::Read),
+            "{extension} missing from the matrix"
+        );
+    }
+
+    let table = caps.to_markdown_table();
+    assert!(table.starts_with("| Format | Read | Write |"));
+    assert_eq!(table.lines().count(), caps.formats().len() + 2);
+}
@@ -0,0 +1,103 @@
+//! Memory buffer format detection tests
+
+use asset_importer::{Importer, detect_format};
+
+use crate::common;
+
+const TRIANGLE_PLY: &str = "ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0
+1 0 0
+0 1 0
+3 0 1 2
+";
+
+const TRIANGLE_ASCII_STL: &str = "solid triangle
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 0 1 0
+    endloop
+  endfacet
+endsolid triangle
+";
+
+/// One-triangle binary STL: zeroed header, count, then normal, vertices and attribute.
+fn triangle_binary_stl() -> Vec<u8> {
+    let mut stl = vec![0u8; 80];
+    stl.extend_from_slice(&1u32.to_le_bytes());
+    let floats = [
+        0.0f32, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+    ];
+    for value in floats {
+        stl.extend_from_slice(&value.to_le_bytes());
+    }
+    stl.extend_from_slice(&0u16.to_le_bytes());
+    stl
+}
+
+fn assert_detected(data: &[u8], extension: &str) {
+    let format = detect_format(data)
+        .unwrap_or_else(|| panic!("expected the buffer to be detected as {extension}"));
+    assert_eq!(format.extension(), extension);
+    assert!(
+        format
+            .importer
+            .file_extensions
+            .iter()
+            .any(|e| e.eq_ignore_ascii_case(extension)),
+        "{} does not list {extension}",
+        format.importer.name
+    );
+}
+
+#[test]
+fn test_detects_binary_formats() {
+    assert_detected(&common::triangle_glb(), "glb");
+    assert_detected(&triangle_binary_stl(), "stl");
+}
+
+#[test]
+fn test_detects_text_formats() {
+    assert_detected(
+        common::triangle_gltf(common::TRIANGLE_SCENE).as_bytes(),
+        "gltf",
+    );
+    assert_detected(TRIANGLE_PLY.as_bytes(), "ply");
+    assert_detected(TRIANGLE_ASCII_STL.as_bytes(), "stl");
+    assert_detected(include_bytes!("../models/box.obj"), "obj");
+    assert_detected(
+        include_bytes!("../models/skinned_six_influences.dae"),
+        "dae",
+    );
+}
+
+#[test]
+fn test_import_from_memory_without_hint() {
+    let buffers: [(&str, Vec<u8>); 5] = [
+        (
+            "gltf",
+            common::triangle_gltf(common::TRIANGLE_SCENE).into_bytes(),
+        ),
+        ("glb", common::triangle_glb()),
+        ("ply", TRIANGLE_PLY.as_bytes().to_vec()),
+        ("stl", triangle_binary_stl()),
+        ("obj", include_bytes!("../models/box.obj").to_vec()),
+    ];
+
+    for (format, data) in buffers {
+        let scene = Importer::new()
+            .read_from_memory(&data)
+            .import()
+            .unwrap_or_else(|e| panic!("hint-less {format} import failed: {e}"));
+        assert_eq!(scene.num_meshes(), 1, "{format}");
+    }
+}
@@ -0,0 +1,75 @@
+//! Global scale import and unit metadata tests
+
+use asset_importer::{Importer, Scene, postprocess::PostProcessSteps};
+
+fn max_extent(scene: &Scene) -> f32 {
+    scene
+        .meshes()
+        .flat_map(|mesh| mesh.vertices_iter().collect::<Vec<_>>())
+        .map(|v| v.x.abs().max(v.y.abs()).max(v.z.abs()))
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn test_with_global_scale_shrinks_vertices() {
+    let unscaled = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj");
+    assert!((max_extent(&unscaled) - 0.5).abs() < 1e-6);
+
+    // The step is added even though the later `with_post_process` does not list it.
+    let scaled = Importer::new()
+        .read_file("tests/models/box.obj")
+        .with_global_scale(0.01)
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import scaled box.obj");
+    assert!((max_extent(&scaled) - 0.005).abs() < 1e-6);
+}
+
+#[test]
+fn test_unit_scale_factor_absent_for_obj() {
+    let scene = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj");
+    assert_eq!(scene.unit_scale_factor(), None);
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_unit_scale_factor_from_fbx() {
+    use asset_importer::exporter::{ExportBuilder, format_ids};
+
+    let source = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj");
+    // Assimp writes FBX files in centimetres.
+    let blob = ExportBuilder::new(format_ids::FBX)
+        .export_to_blob(&source)
+        .expect("export fbx");
+
+    let import = |scale: f32| {
+        Importer::new()
+            .read_from_memory(blob.data())
+            .with_memory_hint("fbx")
+            .with_global_scale(scale)
+            .import()
+            .expect("import fbx")
+    };
+
+    // Compare against an unscaled import, so the test does not depend on how the
+    // importer converts the file's centimetres.
+    let unscaled = import(1.0);
+    let scaled = import(0.01);
+    assert_eq!(scaled.unit_scale_factor(), Some(1.0));
+    let expected = max_extent(&unscaled) * 0.01;
+    assert!(expected > 0.0);
+    assert!(
+        (max_extent(&scaled) - expected).abs() <= expected * 1e-4,
+        "scaled extent {} vs {expected}",
+        max_extent(&scaled)
+    );
+}
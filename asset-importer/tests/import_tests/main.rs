@@ -0,0 +1,14 @@
+//! Import configuration, format detection, file system and progress tests
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod config;
+mod file_system_probe;
+mod format_capability;
+mod format_detection;
+mod global_scale;
+mod prelude;
+mod preset;
+mod progress;
+mod source_format;
@@ -0,0 +1,36 @@
+//! Prelude glob-import tests
+
+use asset_importer::prelude::*;
+
+fn load_box() -> Result<Scene> {
+    Importer::new()
+        .read_file("tests/models/box.obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+}
+
+#[test]
+fn test_prelude_covers_read_path() -> Result<(), Box<dyn std::error::Error>> {
+    let scene = load_box()?;
+    let meshes: Vec<Mesh> = scene.meshes().collect();
+    assert_eq!(meshes.len(), 1);
+    let vertices: Vec<Vector3D> = meshes[0].vertices();
+    assert!(!vertices.is_empty());
+
+    let material: Material = scene.materials().next().expect("material");
+    assert_eq!(material.texture_count(TextureType::Diffuse), 0);
+
+    let root: Node = scene.root_node().expect("root node");
+    let _: Matrix4x4 = root.transformation();
+    assert!(root.descendants().any(|node| node.num_meshes() > 0));
+    Ok(())
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_prelude_covers_export_path() -> Result<()> {
+    let scene = load_box()?;
+    let blob: ExportBlob = ExportBuilder::new("obj").export_to_blob(&scene)?;
+    assert!(blob.size() > 0);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! `ImportBuilder::with_preset` tests
+
+use asset_importer::{ImportPreset, Importer, postprocess::PostProcessSteps};
+
+const QUAD_OBJ: &[u8] = b"v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3 4\n";
+const POINTS_OBJ: &[u8] = b"v 0 0 0\nv 0 0 0\nv 1 0 0\np 1\np 2\np 3\n";
+
+#[test]
+fn test_cad_preset_triangulates_without_tangents() {
+    let scene = Importer::new()
+        .read_from_memory(QUAD_OBJ)
+        .with_memory_hint("obj")
+        .with_preset(ImportPreset::CadArchitecture)
+        .import()
+        .expect("import with CAD preset");
+    let mesh = scene.mesh(0).expect("mesh");
+    assert_eq!(mesh.num_faces(), 2);
+    assert!(mesh.has_normals());
+    assert!(!mesh.has_tangents());
+}
+
+#[test]
+fn test_point_cloud_preset_keeps_duplicate_points() {
+    let scene = Importer::new()
+        .read_from_memory(POINTS_OBJ)
+        .with_memory_hint("obj")
+        .with_preset(ImportPreset::PointCloudPreserve)
+        .import()
+        .expect("import with point cloud preset");
+    let vertices: usize = scene.meshes().map(|m| m.num_vertices()).sum();
+    assert_eq!(vertices, 3);
+}
+
+#[test]
+fn test_steps_after_preset_override_it() {
+    let scene = Importer::new()
+        .read_from_memory(QUAD_OBJ)
+        .with_memory_hint("obj")
+        .with_preset(ImportPreset::CadArchitecture)
+        .with_post_process(PostProcessSteps::empty())
+        .import()
+        .expect("import with preset steps replaced");
+    assert_eq!(scene.mesh(0).expect("mesh").num_faces(), 1);
+}
@@ -0,0 +1,287 @@
+//! Progress reporting tests
+
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::{Duration, Instant};
+
+use asset_importer::{
+    Error, Importer,
+    io::{FileStream, FileSystem, ReadOnlyMemoryFileStream},
+    postprocess::PostProcessSteps,
+    progress::{CancellationToken, ProgressEvent, ProgressStage},
+};
+
+/// A flat `n` x `n` grid of quads as OBJ text.
+fn grid_obj(n: usize) -> String {
+    let mut obj = String::new();
+    for y in 0..=n {
+        for x in 0..=n {
+            obj.push_str(&format!("v {x} {y} 0\n"));
+        }
+    }
+    let index = |x: usize, y: usize| y * (n + 1) + x + 1;
+    for y in 0..n {
+        for x in 0..n {
+            obj.push_str(&format!(
+                "f {} {} {} {}\n",
+                index(x, y),
+                index(x + 1, y),
+                index(x + 1, y + 1),
+                index(x, y + 1)
+            ));
+        }
+    }
+    obj
+}
+
+#[test]
+fn test_progress_events_report_stages() {
+    let obj = grid_obj(32);
+    let events: Arc<Mutex<Vec<ProgressEvent>>> = Arc::default();
+    let sink = events.clone();
+
+    let scene = Importer::new()
+        .read_from_memory(obj.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE | PostProcessSteps::GEN_NORMALS)
+        .with_progress_event_fn(move |event| {
+            sink.lock().unwrap().push(event.clone());
+            true
+        })
+        .import()
+        .expect("import grid");
+    assert_eq!(scene.mesh(0).unwrap().num_faces(), 32 * 32 * 2);
+
+    let events = events.lock().unwrap();
+    assert!(events.iter().any(|e| e.stage == ProgressStage::Reading));
+    assert!(
+        events
+            .iter()
+            .any(|e| e.stage == ProgressStage::PostProcessing)
+    );
+    let last = events.last().expect("at least one event");
+    assert!(last.is_complete());
+}
+
+#[test]
+fn test_progress_throttle_keeps_completion() {
+    let obj = grid_obj(32);
+    let events: Arc<Mutex<Vec<ProgressEvent>>> = Arc::default();
+    let sink = events.clone();
+
+    Importer::new()
+        .read_from_memory(obj.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE | PostProcessSteps::GEN_NORMALS)
+        .with_progress_throttle(Duration::from_secs(3600))
+        .with_progress_event_fn(move |event| {
+            sink.lock().unwrap().push(event.clone());
+            true
+        })
+        .import()
+        .expect("import grid");
+
+    let events = events.lock().unwrap();
+    // The first update and every completion update get through; nothing in between.
+    assert!(events.len() >= 2);
+    assert!(!events[0].is_complete());
+    assert!(events[1..].iter().all(ProgressEvent::is_complete));
+}
+
+#[test]
+fn test_progress_cancellation_aborts_large_import() {
+    let obj = grid_obj(256);
+    let calls = Arc::new(Mutex::new(0usize));
+    let counter = calls.clone();
+
+    let result = Importer::new()
+        .read_from_memory(obj.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE | PostProcessSteps::GEN_SMOOTH_NORMALS)
+        .with_progress_handler_fn(move |_percentage, _message| {
+            *counter.lock().unwrap() += 1;
+            false
+        })
+        .import();
+
+    let err = result.expect_err("cancelled import must fail");
+    assert!(err.to_string().contains("cancelled"), "{err}");
+    // Once cancelled, later updates are not forwarded to the handler.
+    assert_eq!(*calls.lock().unwrap(), 1);
+}
+
+#[test]
+fn test_cancellation_token_from_another_thread() {
+    let obj = grid_obj(1024);
+    let token = CancellationToken::new();
+    let canceller = {
+        let token = token.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(5));
+            token.cancel();
+        })
+    };
+
+    let start = Instant::now();
+    let result = Importer::new()
+        .read_from_memory(obj.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(
+            PostProcessSteps::TRIANGULATE
+                | PostProcessSteps::JOIN_IDENTICAL_VERTICES
+                | PostProcessSteps::GEN_SMOOTH_NORMALS,
+        )
+        .with_cancellation_token(token)
+        .import();
+    canceller.join().unwrap();
+
+    assert!(
+        matches!(result, Err(Error::Cancelled { .. })),
+        "expected Err(Cancelled), got {:?}",
+        result.map(|scene| scene.num_meshes())
+    );
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
+
+#[test]
+fn test_cancelled_token_fails_before_import() {
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let result = Importer::new()
+        .read_file("tests/models/box.obj")
+        .with_cancellation_token(token)
+        .import();
+    assert!(matches!(result, Err(Error::Cancelled { .. })));
+}
+
+#[test]
+fn test_uncancelled_token_imports_normally() {
+    let scene = Importer::new()
+        .read_file("tests/models/box.obj")
+        .with_cancellation_token(CancellationToken::new())
+        .import()
+        .expect("import with an idle token");
+    assert_eq!(scene.num_meshes(), 1);
+    assert!(scene.source_format().is_some());
+}
+
+fn assert_timed_out(result: asset_importer::Result<asset_importer::Scene>, limit: Duration) {
+    match result {
+        Err(Error::TimedOut { elapsed, .. }) => assert!(elapsed >= limit, "{elapsed:?}"),
+        other => panic!(
+            "expected Err(TimedOut), got {:?}",
+            other.map(|scene| scene.num_meshes())
+        ),
+    }
+}
+
+#[test]
+fn test_timeout_stops_slow_import() {
+    // Importing and post-processing a million-vertex grid takes well over 200ms.
+    let obj = grid_obj(1024);
+    let limit = Duration::from_millis(50);
+
+    let start = Instant::now();
+    let result = Importer::new()
+        .read_from_memory(obj.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(
+            PostProcessSteps::TRIANGULATE
+                | PostProcessSteps::JOIN_IDENTICAL_VERTICES
+                | PostProcessSteps::GEN_SMOOTH_NORMALS,
+        )
+        .with_timeout(limit)
+        .import();
+
+    assert_timed_out(result, limit);
+    assert!(start.elapsed() < Duration::from_secs(60));
+}
+
+/// Serves one file whose reads each take a millisecond.
+#[derive(Debug)]
+struct SlowFileSystem {
+    name: &'static str,
+    data: Arc<[u8]>,
+    bytes_read: Arc<AtomicUsize>,
+}
+
+/// Returns at most a few bytes per read, pausing before each one, so a large file takes
+/// many reads and a long time unless the read gate stops them.
+struct SlowStream {
+    inner: ReadOnlyMemoryFileStream,
+    bytes_read: Arc<AtomicUsize>,
+}
+
+impl FileStream for SlowStream {
+    fn read(&mut self, buffer: &mut [u8]) -> asset_importer::Result<usize> {
+        std::thread::sleep(Duration::from_millis(1));
+        let len = buffer.len().min(4);
+        let read = self.inner.read(&mut buffer[..len])?;
+        self.bytes_read.fetch_add(read, Ordering::Relaxed);
+        Ok(read)
+    }
+
+    fn tell(&self) -> asset_importer::Result<u64> {
+        self.inner.tell()
+    }
+
+    fn seek(&mut self, position: u64) -> asset_importer::Result<()> {
+        self.inner.seek(position)
+    }
+
+    fn size(&self) -> asset_importer::Result<u64> {
+        self.inner.size()
+    }
+}
+
+impl FileSystem for SlowFileSystem {
+    fn exists(&self, path: &str) -> bool {
+        path == self.name
+    }
+
+    fn open(&self, path: &str) -> asset_importer::Result<Box<dyn FileStream>> {
+        if path != self.name {
+            return Err(Error::invalid_parameter(format!("no such file: {path}")));
+        }
+        Ok(Box::new(SlowStream {
+            inner: ReadOnlyMemoryFileStream::new(self.data.clone()),
+            bytes_read: self.bytes_read.clone(),
+        }))
+    }
+}
+
+#[test]
+fn test_timeout_interrupts_io_bound_import() {
+    let data: Arc<[u8]> = grid_obj(512).into_bytes().into();
+    let bytes_read = Arc::new(AtomicUsize::new(0));
+    let file_system = SlowFileSystem {
+        name: "grid.obj",
+        data: data.clone(),
+        bytes_read: bytes_read.clone(),
+    };
+    let limit = Duration::from_millis(50);
+
+    let result = Importer::new()
+        .read_file("grid.obj")
+        .with_file_system(file_system)
+        .with_timeout(limit)
+        .import();
+
+    assert_timed_out(result, limit);
+    // Reading the whole file at this pace would take minutes; the read gate stopped it.
+    let read = bytes_read.load(Ordering::Relaxed);
+    assert!(read < data.len(), "read {read} of {} bytes", data.len());
+}
+
+#[test]
+fn test_generous_timeout_imports_normally() {
+    let scene = Importer::new()
+        .read_file("tests/models/box.obj")
+        .with_timeout(Duration::from_secs(600))
+        .import()
+        .expect("import well within the timeout");
+    assert_eq!(scene.num_meshes(), 1);
+}
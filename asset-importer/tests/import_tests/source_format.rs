@@ -0,0 +1,85 @@
+//! Tests for reporting which importer read a scene
+
+use asset_importer::{Importer, find_for_path};
+
+use crate::common;
+
+/// Write the GLB fixture into a directory owned by the calling test.
+fn write_glb(test: &str) -> std::path::PathBuf {
+    let path = common::temp_dir(test).join("triangle.glb");
+    std::fs::write(&path, common::triangle_glb()).expect("write glb");
+    path
+}
+
+#[test]
+fn test_glb_importer_reported_through_bridge() {
+    let path = write_glb("source_format_bridge");
+    let scene = Importer::new()
+        .read_file(&path)
+        .with_progress_handler_fn(|_, _| true)
+        .import()
+        .expect("import glb");
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+    let format = scene.source_format().expect("source format");
+    assert!(format.exact);
+    assert!(
+        format.importer.name.contains("glTF"),
+        "{}",
+        format.importer.name
+    );
+    assert_eq!(format.format_version.as_deref(), Some("2.0"));
+}
+
+#[test]
+fn test_glb_importer_falls_back_to_extension() {
+    let path = write_glb("source_format_fallback");
+    let scene = Importer::new()
+        .read_file(&path)
+        .import()
+        .expect("import glb");
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+    let format = scene.source_format().expect("source format");
+    assert!(!format.exact);
+    assert!(
+        format.importer.name.contains("glTF"),
+        "{}",
+        format.importer.name
+    );
+    assert_eq!(
+        format.importer.name,
+        find_for_path("model.GLB").expect("glb importer").name
+    );
+}
+
+#[test]
+fn test_memory_import_reports_importer() {
+    let data = common::triangle_glb();
+    let scene = Importer::new()
+        .read_from_memory(&data)
+        .with_memory_hint("glb")
+        .with_progress_handler_fn(|_, _| true)
+        .import()
+        .expect("import glb from memory");
+    let format = scene.source_format().expect("source format");
+    assert!(format.exact);
+    assert!(
+        format.importer.name.contains("glTF"),
+        "{}",
+        format.importer.name
+    );
+
+    let scene = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj");
+    let format = scene.source_format().expect("source format");
+    assert!(format.importer.file_extensions.iter().any(|e| e == "obj"));
+}
+
+#[test]
+fn test_find_for_path_without_extension() {
+    assert!(find_for_path("README").is_none());
+    assert!(find_for_path("model.not-a-format").is_none());
+}
@@ -0,0 +1,91 @@
+//! `log` crate bridge tests
+#![cfg(feature = "log")]
+
+use std::sync::Mutex;
+
+use asset_importer::{
+    Importer,
+    logging::{LogLevel, install_log_bridge},
+};
+
+static RECORDS: Mutex<Vec<(log::Level, String, String)>> = Mutex::new(Vec::new());
+
+struct TestLogger;
+
+impl log::Log for TestLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        RECORDS.lock().unwrap().push((
+            record.level(),
+            record.target().to_string(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: TestLogger = TestLogger;
+
+fn take_assimp_records() -> Vec<(log::Level, String)> {
+    RECORDS
+        .lock()
+        .unwrap()
+        .drain(..)
+        .filter(|(_, target, _)| target == "assimp")
+        .map(|(level, _, message)| (level, message))
+        .collect()
+}
+
+// Imports on a worker thread so forwarding happens off the installing thread.
+fn import_missing_mtl_obj() {
+    let dir = std::env::temp_dir().join(format!("asset_importer_log_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    let obj_path = dir.join("missing_mtl.obj");
+    std::fs::write(
+        &obj_path,
+        "mtllib does_not_exist.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl Missing\nf 1 2 3\n",
+    )
+    .expect("write obj");
+
+    let result = std::thread::spawn(move || Importer::new().import_file(&obj_path))
+        .join()
+        .expect("import thread");
+    let _ = std::fs::remove_dir_all(&dir);
+    result.expect("OBJ without its MTL should still import");
+}
+
+#[test]
+fn test_log_bridge_forwards_and_detaches() {
+    log::set_logger(&LOGGER).expect("set test logger");
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let first = install_log_bridge(LogLevel::Info);
+    // Re-installing replaces the filter; the first guard becomes a no-op.
+    let guard = install_log_bridge(LogLevel::Warn);
+    drop(first);
+
+    take_assimp_records();
+    import_missing_mtl_obj();
+    let records = take_assimp_records();
+    assert!(
+        records
+            .iter()
+            .any(|(level, message)| *level <= log::Level::Warn
+                && message.contains("does_not_exist.mtl")),
+        "missing material library should be reported: {records:?}"
+    );
+    assert!(
+        records.iter().all(|(level, _)| *level <= log::Level::Warn),
+        "only warnings and errors should pass the filter: {records:?}"
+    );
+    // Lines arrive without DefaultLogger's "Warn,  T0: " prefix.
+    assert!(records.iter().all(|(_, message)| !message.contains(",  T")));
+
+    guard.uninstall();
+    import_missing_mtl_obj();
+    assert!(take_assimp_records().is_empty());
+}
@@ -0,0 +1,196 @@
+use asset_importer::{Scene, TextureFormatHint, TextureReference, TextureType};
+
+use crate::common;
+
+// Not a decodable image: Assimp passes embedded payloads through untouched, so
+// SOI/APP0/EOI markers are enough to check that bytes survive the round trip.
+const JPEG_STUB: &[u8] = &[
+    0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, b'J', b'F', b'I', b'F', 0x00, 0x01, 0x01, 0x00, 0x00, 0x01,
+    0x00, 0x01, 0x00, 0x00, 0xFF, 0xD9,
+];
+
+/// Build a single-triangle GLB whose base color texture is an embedded JPEG.
+fn glb_with_embedded_jpeg() -> Vec<u8> {
+    common::triangle_glb_with_images(
+        &[JPEG_STUB],
+        r#"{
+  "images": [{ "bufferView": 1, "mimeType": "image/jpeg" }],
+  "textures": [{ "source": 0 }],
+  "materials": [{ "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } }],
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] }],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#,
+    )
+}
+
+/// Two triangles with different materials that both use the one embedded JPEG, the
+/// second as its normal map.
+fn glb_with_shared_jpeg() -> Vec<u8> {
+    common::triangle_glb_with_images(
+        &[JPEG_STUB],
+        r#"{
+  "images": [{ "bufferView": 1, "mimeType": "image/jpeg" }],
+  "textures": [{ "source": 0 }],
+  "materials": [
+    { "name": "A", "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } },
+    { "name": "B", "normalTexture": { "index": 0 } }
+  ],
+  "meshes": [
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 1 }] }
+  ],
+  "nodes": [{ "mesh": 0 }, { "mesh": 1 }],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#,
+    )
+}
+
+#[test]
+fn test_glb_embedded_jpeg_compressed_bytes() {
+    let glb = glb_with_embedded_jpeg();
+    let scene = Scene::from_memory(&glb, Some("glb")).expect("import GLB");
+
+    assert_eq!(scene.num_textures(), 1);
+    let texture = scene.texture(0).expect("embedded texture 0");
+
+    assert!(texture.is_compressed());
+    assert_eq!(texture.format_hint(), TextureFormatHint::Jpeg);
+    assert_eq!(texture.format_hint().extension(), Some("jpg"));
+    assert!(texture.texel_layout().is_none());
+    assert_eq!(texture.compressed_bytes(), Some(JPEG_STUB));
+}
+
+#[test]
+fn test_glb_embedded_jpeg_write_to_file() {
+    let glb = glb_with_embedded_jpeg();
+    let scene = Scene::from_memory(&glb, Some("glb")).expect("import GLB");
+    let texture = scene.texture(0).expect("embedded texture 0");
+
+    let path = std::env::temp_dir().join(format!(
+        "asset_importer_embedded_{}.{}",
+        std::process::id(),
+        texture.format_hint().extension().unwrap()
+    ));
+    texture.write_to_file(&path).expect("write texture");
+    let written = std::fs::read(&path).expect("read written texture");
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(written, JPEG_STUB);
+}
+
+#[test]
+fn test_texture_references_two_materials_share_texture() {
+    let scene = Scene::from_memory(&glb_with_shared_jpeg(), Some("glb")).expect("import GLB");
+    assert_eq!(scene.num_textures(), 1);
+
+    let references = scene.texture_references();
+    assert!(references.iter().all(|r| r.texture_index == 0));
+    let material = |name: &str| {
+        scene
+            .materials()
+            .position(|m| m.name() == name)
+            .expect("material")
+    };
+    let (a, b) = (material("A"), material("B"));
+    assert!(references.contains(&TextureReference {
+        texture_index: 0,
+        material_index: a,
+        texture_type: TextureType::BaseColor,
+        slot_index: 0,
+    }));
+    assert!(references.contains(&TextureReference {
+        texture_index: 0,
+        material_index: b,
+        texture_type: TextureType::Normals,
+        slot_index: 0,
+    }));
+    assert!(
+        references
+            .iter()
+            .all(|r| r.material_index == a || r.material_index == b)
+    );
+}
+
+#[test]
+fn test_compressed_texture_has_no_texels() {
+    let glb = glb_with_embedded_jpeg();
+    let scene = Scene::from_memory(&glb, Some("glb")).expect("import GLB");
+    let texture = scene.texture(0).expect("embedded texture 0");
+
+    assert_eq!(texture.height(), 0);
+    assert_eq!(texture.width() as usize, JPEG_STUB.len());
+    assert_eq!(texture.size_bytes(), JPEG_STUB.len());
+    assert!(texture.texels_raw().is_none());
+    assert!(texture.texels_bytes().is_none());
+    assert!(texture.to_rgba8().is_none());
+}
+
+/// Build a Quake 1 MDL (one triangle, one frame) with a single 2x2 palettized skin,
+/// which Assimp expands into an uncompressed embedded texture.
+fn mdl_with_skin() -> Vec<u8> {
+    fn i32s(out: &mut Vec<u8>, values: &[i32]) {
+        for v in values {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+    fn f32s(out: &mut Vec<u8>, values: &[f32]) {
+        for v in values {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+    }
+
+    let mut mdl = Vec::new();
+    mdl.extend_from_slice(b"IDPO");
+    i32s(&mut mdl, &[6]);
+    f32s(&mut mdl, &[1.0, 1.0, 1.0]); // scale
+    f32s(&mut mdl, &[0.0, 0.0, 0.0]); // translate
+    f32s(&mut mdl, &[1.0]); // bounding radius
+    f32s(&mut mdl, &[0.0, 0.0, 0.0]); // eye position
+    // skins, skin width, skin height, vertices, triangles, frames, sync type, flags
+    i32s(&mut mdl, &[1, 2, 2, 3, 1, 1, 0, 0]);
+    f32s(&mut mdl, &[0.0]); // size
+
+    i32s(&mut mdl, &[0]); // single skin
+    mdl.extend_from_slice(&[0, 15, 100, 254]); // palette indices
+
+    // on-seam flag and s/t texel coordinates per vertex
+    i32s(&mut mdl, &[0, 0, 0, 0, 1, 0, 0, 0, 1]);
+    // front-facing flag and vertex indices
+    i32s(&mut mdl, &[1, 0, 1, 2]);
+
+    i32s(&mut mdl, &[0]); // simple frame
+    mdl.extend_from_slice(&[0, 0, 0, 0, 1, 1, 0, 0]); // bbox min / max
+    mdl.extend_from_slice(&[0; 16]); // frame name
+    mdl.extend_from_slice(&[0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0]); // packed vertices
+    mdl
+}
+
+#[test]
+fn test_uncompressed_texture_texels_and_rgba8() {
+    let scene = Scene::from_memory(&mdl_with_skin(), Some("mdl")).expect("import MDL");
+    let texture = scene.textures().next().expect("embedded skin");
+
+    assert!(texture.is_uncompressed());
+    assert_eq!(texture.dimensions(), (2, 2));
+    assert_eq!(texture.size_bytes(), 16);
+
+    let texels = texture.texels_raw().expect("texels");
+    assert_eq!(texels.len(), 4);
+    assert!(texels.iter().all(|t| t.a == 255));
+
+    // Raw bytes keep Assimp's B, G, R, A order.
+    let bytes = texture.texels_bytes().expect("texel bytes");
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(
+        &bytes[..4],
+        &[texels[0].b, texels[0].g, texels[0].r, texels[0].a]
+    );
+
+    let (width, height, rgba) = texture.to_rgba8().expect("rgba8");
+    assert_eq!((width, height), (2, 2));
+    let expected: Vec<u8> = texels.iter().flat_map(|t| [t.r, t.g, t.b, t.a]).collect();
+    assert_eq!(rgba, expected);
+}
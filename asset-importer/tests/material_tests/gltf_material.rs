@@ -0,0 +1,172 @@
+//! glTF material convenience getter tests
+
+use asset_importer::{Scene, TextureType, material::AlphaMode, test_utils};
+
+use crate::common;
+
+// Material 0 uses MASK/0.25, is double-sided and has an emissive strength; material 1
+// relies on every glTF default.
+const ALPHA_MASK_GLTF: &str = r#"{
+  "extensionsUsed": ["KHR_materials_emissive_strength"],
+  "materials": [
+    {
+      "name": "Cutout",
+      "alphaMode": "MASK",
+      "alphaCutoff": 0.25,
+      "doubleSided": true,
+      "emissiveFactor": [1, 0.5, 0],
+      "extensions": { "KHR_materials_emissive_strength": { "emissiveStrength": 4.0 } }
+    },
+    { "name": "Plain" }
+  ],
+  "meshes": [
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 1 }] }
+  ],
+  "nodes": [{ "mesh": 0 }, { "mesh": 1 }],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_gltf_alpha_mask_and_double_sided() {
+    let scene = Scene::from_memory(
+        common::triangle_gltf(ALPHA_MASK_GLTF).as_bytes(),
+        Some("gltf"),
+    )
+    .expect("import glTF");
+    let find = |name: &str| {
+        scene
+            .materials()
+            .find(|m| m.name() == name)
+            .unwrap_or_else(|| panic!("material {name}"))
+    };
+
+    let cutout = find("Cutout");
+    assert_eq!(cutout.alpha_mode(), Some(AlphaMode::Mask));
+    assert!((cutout.alpha_cutoff().expect("alpha cutoff") - 0.25).abs() < 1e-6);
+    assert!(cutout.double_sided());
+    assert_eq!(cutout.emissive_strength(), Some(4.0));
+
+    let plain = find("Plain");
+    assert_eq!(plain.alpha_mode(), Some(AlphaMode::Opaque));
+    assert!(!plain.double_sided());
+    assert_eq!(plain.emissive_strength(), None);
+}
+
+#[test]
+fn test_non_gltf_material_has_no_gltf_keys() {
+    let scene = Scene::from_file("tests/models/textured.obj").expect("import textured.obj");
+    let material = scene.material(0).expect("material");
+    assert_eq!(material.alpha_mode(), None);
+    assert_eq!(material.alpha_cutoff(), None);
+    assert!(!material.double_sided());
+}
+
+// One material with base color, normal (scale 0.5, second UV set) and emissive textures
+// referencing external images; the sampler adds wrap modes to the base color slot.
+const TEXTURED_GLTF: &str = r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 24 },
+    { "buffer": 0, "byteOffset": 60, "byteLength": 24 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2" },
+    { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2" }
+  ],
+  "images": [{ "uri": "base.png" }, { "uri": "normal.png" }, { "uri": "glow.png" }],
+  "samplers": [{ "wrapS": 33648, "wrapT": 33071 }],
+  "textures": [{ "source": 0, "sampler": 0 }, { "source": 1 }, { "source": 2 }],
+  "materials": [{
+    "name": "Textured",
+    "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } },
+    "normalTexture": { "index": 1, "scale": 0.5, "texCoord": 1 },
+    "emissiveTexture": { "index": 2 }
+  }],
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0, "TEXCOORD_0": 1, "TEXCOORD_1": 2 }, "material": 0 }] }],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+/// [`TEXTURED_GLTF`] with both UV channels at (0,0) (1,0) (0,1).
+fn textured_gltf() -> String {
+    let uv = test_utils::f32_bytes(&[0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+    let buffer = [common::triangle_bytes(), uv.clone(), uv].concat();
+    common::gltf(&buffer, TEXTURED_GLTF)
+}
+
+#[test]
+fn test_texture_slots_group_tex_properties() {
+    let scene = Scene::from_memory(textured_gltf().as_bytes(), Some("gltf")).expect("import glTF");
+    let material = scene.material(0).expect("material");
+    let slots = material.texture_slots();
+
+    // Each (type, index) pair appears once and only holds its own `$tex.*` keys.
+    for (i, slot) in slots.iter().enumerate() {
+        assert!(
+            slots[i + 1..]
+                .iter()
+                .all(|other| (other.texture_type, other.index) != (slot.texture_type, slot.index))
+        );
+        for prop in &slot.properties {
+            assert!(prop.key.starts_with("$tex."), "{}", prop.key);
+            assert_eq!(prop.semantic, Some(slot.texture_type));
+            assert_eq!(prop.index, slot.index);
+        }
+    }
+
+    let slot = |texture_type| {
+        slots
+            .iter()
+            .find(|s| s.texture_type == texture_type && s.index == 0)
+            .unwrap_or_else(|| panic!("{texture_type:?} slot"))
+    };
+    let base = slot(TextureType::BaseColor);
+    assert!(base.property("$tex.file").is_some());
+    assert!(base.property("$tex.mapmodeu").is_some());
+    assert!(base.property("$tex.mapmodev").is_some());
+    let normal = slot(TextureType::Normals);
+    assert!(normal.property("$tex.file").is_some());
+    assert!(normal.property("$tex.scale").is_some());
+    assert!(slot(TextureType::Emissive).property("$tex.file").is_some());
+
+    // No texture properties end up outside a slot.
+    let tex_properties = material
+        .properties()
+        .filter(|p| p.key_str().starts_with("$tex.") && p.semantic().is_some())
+        .count();
+    let grouped: usize = slots.iter().map(|s| s.properties.len()).sum();
+    assert_eq!(grouped, tex_properties);
+}
+
+#[test]
+fn test_material_property_as_string() {
+    let scene = Scene::from_memory(textured_gltf().as_bytes(), Some("gltf")).expect("import glTF");
+    let material = scene.material(0).expect("material");
+
+    let file = |texture_type| {
+        material
+            .properties()
+            .find(|p| p.key_str() == "$tex.file" && p.semantic() == Some(texture_type))
+            .and_then(|p| p.as_string())
+    };
+    assert_eq!(file(TextureType::BaseColor).as_deref(), Some("base.png"));
+    assert_eq!(file(TextureType::Normals).as_deref(), Some("normal.png"));
+    assert_eq!(file(TextureType::Emissive).as_deref(), Some("glow.png"));
+
+    let name = material
+        .properties()
+        .find(|p| p.key_str() == "?mat.name")
+        .expect("name property");
+    assert_eq!(name.as_string().as_deref(), Some("Textured"));
+    // Non-string properties do not decode as strings.
+    assert!(
+        material
+            .properties()
+            .filter(|p| p.key_str() == "$tex.scale")
+            .all(|p| p.as_string().is_none())
+    );
+}
@@ -0,0 +1,14 @@
+//! Material and texture tests
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod embedded_texture;
+mod gltf_material;
+mod material_builder;
+mod material_dedup;
+mod material_summary;
+mod texture_dedup;
+mod texture_image;
+mod texture_path;
+mod uv_transform;
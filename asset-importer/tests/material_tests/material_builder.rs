@@ -0,0 +1,161 @@
+//! Material authoring and editing tests
+
+use asset_importer::{
+    material::{MaterialBuilder, MaterialTexture, TextureMapMode, TextureType, material_keys},
+    scene::OwnedScene,
+};
+
+use crate::common;
+
+#[test]
+fn test_builder_duplicate_keys_replace() {
+    let material = MaterialBuilder::new()
+        .with_float(material_keys::METALLIC_FACTOR, 0.1)
+        .with_float(material_keys::METALLIC_FACTOR, 0.7)
+        .with_texture(TextureType::Diffuse, 0, MaterialTexture::new("a.png"))
+        .with_texture(
+            TextureType::Diffuse,
+            0,
+            MaterialTexture::new("b.png")
+                .with_map_modes(TextureMapMode::Clamp, TextureMapMode::Mirror),
+        )
+        .build()
+        .expect("build material");
+    // metallic + $tex.file + two map modes
+    assert_eq!(material.len(), 4);
+
+    assert!(
+        MaterialBuilder::new()
+            .with_name("x".repeat(2000))
+            .build()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_material_mut_edits_copy_only() {
+    let scene = common::textured_scene();
+    let mut owned = OwnedScene::copy_of(&scene).expect("copy scene");
+    let before = owned.material(0).expect("material view");
+
+    owned
+        .material_mut(0)
+        .expect("material_mut")
+        .set_float(material_keys::ROUGHNESS_FACTOR, 0.3)
+        .and_then(|m| m.set_float(material_keys::ROUGHNESS_FACTOR, 0.6))
+        .and_then(|m| m.set_name("edited"))
+        .expect("set properties");
+
+    let after = owned.material(0).unwrap();
+    assert_eq!(after.roughness_factor(), Some(0.6));
+    assert_eq!(after.name(), "edited");
+    let count = |m: &asset_importer::Material| {
+        m.properties()
+            .filter(|p| p.key_str() == "$mat.roughnessFactor")
+            .count()
+    };
+    assert_eq!(count(&after), 1);
+
+    // The earlier view and the source scene keep the original data.
+    assert_eq!(before.name(), "mat0");
+    assert_eq!(scene.material(0).unwrap().name(), "mat0");
+
+    let mut material = owned.material_mut(0).unwrap();
+    assert!(material.remove(material_keys::ROUGHNESS_FACTOR).unwrap());
+    assert!(!material.remove(material_keys::ROUGHNESS_FACTOR).unwrap());
+    assert!(owned.material_mut(99).is_err());
+}
+
+#[test]
+fn test_unknown_texture_semantic_survives() {
+    // Simulate a semantic added by a newer Assimp.
+    const RAW: u32 = 99;
+    let other = TextureType::from_u32_lossy(RAW);
+    assert_eq!(other, TextureType::Other(RAW));
+    assert_eq!(other.to_u32(), RAW);
+    assert_eq!(TextureType::from_u32(RAW), None);
+    for texture_type in TextureType::ALL {
+        assert_eq!(
+            TextureType::from_u32_lossy(texture_type.to_u32()),
+            texture_type
+        );
+    }
+
+    let scene = common::textured_scene();
+    let mut owned = OwnedScene::copy_of(&scene).expect("copy scene");
+    owned
+        .material_mut(0)
+        .expect("material_mut")
+        .set_texture(
+            other,
+            1,
+            &MaterialTexture::new("future.png").with_uv_index(2),
+        )
+        .expect("set texture");
+    let material = owned.material(0).unwrap();
+
+    let semantics: Vec<_> = material
+        .properties()
+        .filter(|p| p.key_str() == "$tex.file")
+        .filter_map(|p| p.semantic())
+        .collect();
+    assert!(semantics.contains(&other));
+
+    let slot = material
+        .texture_slots()
+        .into_iter()
+        .find(|slot| slot.texture_type == other)
+        .expect("slot with unknown semantic");
+    assert_eq!(slot.index, 1);
+    assert!(slot.property("$tex.file").is_some());
+
+    assert_eq!(material.texture_count(other), 2);
+    let texture = material.texture(other, 1).expect("texture");
+    assert_eq!(texture.path, "future.png");
+    assert_eq!(texture.uv_index, 2);
+    assert!(material.texture(other, 0).is_none());
+
+    assert!(material.texture_coverage().contains(&(other, 2)));
+    assert_eq!(material.texture_counts().other(), 2);
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_pbr_factors_survive_gltf_export() {
+    use asset_importer::{
+        Scene,
+        exporter::{ExportBuilder, formats},
+        types::Color4D,
+    };
+
+    let material = MaterialBuilder::new()
+        .with_name("painted")
+        .with_color(material_keys::BASE_COLOR, Color4D::new(0.2, 0.4, 0.6, 1.0))
+        .with_float(material_keys::METALLIC_FACTOR, 0.25)
+        .build()
+        .expect("build material");
+
+    let scene = common::textured_scene();
+    let mut owned = OwnedScene::copy_of(&scene).expect("copy scene");
+    let mesh_material = scene.mesh(0).unwrap().material_index();
+    owned
+        .material_mut(mesh_material)
+        .and_then(|mut m| m.apply(&material).map(|_| ()))
+        .expect("apply material");
+    let added = owned.add_material(&material).expect("add material");
+    assert_eq!(added, scene.num_materials());
+    assert_eq!(owned.num_materials(), scene.num_materials() + 1);
+
+    let blob = ExportBuilder::new(formats::GLB2)
+        .export_to_blob(&owned)
+        .expect("export GLB");
+    let reimported = Scene::from_memory(blob.data(), Some("glb")).expect("re-import GLB");
+
+    let material = reimported
+        .material(reimported.mesh(0).unwrap().material_index())
+        .expect("material");
+    let base = material.base_color().expect("base color");
+    assert!((base.x - 0.2).abs() < 1e-5 && (base.y - 0.4).abs() < 1e-5);
+    assert!((base.z - 0.6).abs() < 1e-5 && (base.w - 1.0).abs() < 1e-5);
+    assert_eq!(material.metallic_factor(), Some(0.25));
+}
@@ -0,0 +1,67 @@
+//! Mesh material lookup and near-duplicate material grouping tests
+
+use asset_importer::Scene;
+
+use crate::common;
+
+// Two materials whose base colors differ by 1e-4, plus a rough one.
+const NEAR_DUPLICATE_GLTF: &str = r#"{
+  "materials": [
+    { "name": "A", "pbrMetallicRoughness": { "baseColorFactor": [0.5, 0.5, 0.5, 1] } },
+    { "name": "B", "pbrMetallicRoughness": { "baseColorFactor": [0.5001, 0.5, 0.5, 1] } },
+    { "name": "C", "pbrMetallicRoughness": { "baseColorFactor": [0.5, 0.5, 0.5, 1], "roughnessFactor": 0.2 } }
+  ],
+  "meshes": [
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 1 }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 2 }] }
+  ],
+  "nodes": [{ "mesh": 0 }, { "mesh": 1 }, { "mesh": 2 }],
+  "scenes": [{ "nodes": [0, 1, 2] }],
+  "scene": 0
+}"#;
+
+fn material_index(scene: &Scene, name: &str) -> usize {
+    scene
+        .materials()
+        .position(|m| m.name() == name)
+        .unwrap_or_else(|| panic!("material {name}"))
+}
+
+#[test]
+fn test_mesh_material_resolves_through_scene() {
+    let scene = Scene::from_file("tests/models/duplicate_materials.obj").expect("import OBJ");
+    assert!(scene.num_meshes() > 0);
+    for mesh in scene.meshes() {
+        let material = mesh.material().expect("mesh material");
+        let by_index = scene
+            .material(mesh.material_index())
+            .expect("indexed material");
+        assert_eq!(material.name(), by_index.name());
+    }
+}
+
+#[test]
+fn test_renamed_mtl_entries_form_one_group() {
+    let scene = Scene::from_file("tests/models/duplicate_materials.obj").expect("import OBJ");
+    let red = material_index(&scene, "red");
+    let copy = material_index(&scene, "red_copy");
+    material_index(&scene, "red_other_texture");
+
+    // The third entry only differs in its diffuse texture path.
+    assert_eq!(scene.material_duplicates(0.0), [vec![red, copy]]);
+}
+
+#[test]
+fn test_float_tolerance() {
+    let scene = Scene::from_memory(
+        common::triangle_gltf(NEAR_DUPLICATE_GLTF).as_bytes(),
+        Some("gltf"),
+    )
+    .expect("import glTF");
+    let a = material_index(&scene, "A");
+    let b = material_index(&scene, "B");
+
+    assert!(scene.material_duplicates(0.0).is_empty());
+    assert_eq!(scene.material_duplicates(1e-3), [vec![a, b]]);
+}
@@ -0,0 +1,107 @@
+//! Material texture counts and summary tests
+
+use asset_importer::{Scene, TextureType, material::PbrWorkflow};
+
+use crate::common;
+
+// One PBR material using every core glTF texture slot, plus an untextured one.
+const PBR_GLTF: &str = r#"{
+  "images": [
+    { "uri": "base.png" }, { "uri": "mr.png" }, { "uri": "normal.png" },
+    { "uri": "ao.png" }, { "uri": "emissive.png" }
+  ],
+  "textures": [
+    { "source": 0 }, { "source": 1 }, { "source": 2 }, { "source": 3 }, { "source": 4 }
+  ],
+  "materials": [
+    {
+      "name": "Textured",
+      "pbrMetallicRoughness": {
+        "baseColorFactor": [0.5, 0.5, 0.5, 1],
+        "baseColorTexture": { "index": 0 },
+        "metallicFactor": 0.25,
+        "roughnessFactor": 0.75,
+        "metallicRoughnessTexture": { "index": 1 }
+      },
+      "normalTexture": { "index": 2 },
+      "occlusionTexture": { "index": 3 },
+      "emissiveTexture": { "index": 4 },
+      "doubleSided": true
+    },
+    { "name": "Plain" }
+  ],
+  "meshes": [
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 1 }] }
+  ],
+  "nodes": [{ "mesh": 0 }, { "mesh": 1 }],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#;
+
+fn assert_counts_match(scene: &Scene, label: &str) {
+    assert!(scene.num_materials() > 0, "{label}: no materials");
+    for (i, material) in scene.materials().enumerate() {
+        let counts = material.texture_counts();
+        for texture_type in TextureType::ALL {
+            assert_eq!(
+                counts.get(texture_type),
+                material.texture_count(texture_type),
+                "{label}: material {i} {texture_type:?}"
+            );
+        }
+        assert_eq!(material.summary().texture_counts, counts, "{label}: {i}");
+    }
+}
+
+#[test]
+fn test_texture_counts_match_assimp_on_fixtures() {
+    for path in [
+        "tests/models/textured.obj",
+        "tests/models/texture_options.obj",
+        "tests/models/box.obj",
+        "tests/models/pivots.fbx",
+        "tests/models/skinned_six_influences.dae",
+    ] {
+        let scene = Scene::from_file(path).unwrap_or_else(|e| panic!("import {path}: {e}"));
+        assert_counts_match(&scene, path);
+    }
+
+    let scene = Scene::from_memory(common::triangle_gltf(PBR_GLTF).as_bytes(), Some("gltf"))
+        .expect("import glTF");
+    assert_counts_match(&scene, "PBR glTF");
+}
+
+#[test]
+fn test_material_summary_pbr_gltf() {
+    let scene = Scene::from_memory(common::triangle_gltf(PBR_GLTF).as_bytes(), Some("gltf"))
+        .expect("import glTF");
+    let find = |name: &str| {
+        scene
+            .materials()
+            .find(|m| m.name() == name)
+            .unwrap_or_else(|| panic!("material {name}"))
+    };
+
+    let textured = find("Textured");
+    let summary = textured.summary();
+    assert_eq!(summary.name, "Textured");
+    assert_eq!(summary.pbr_workflow, PbrWorkflow::MetallicRoughness);
+    assert_eq!(summary.metallic_factor, Some(0.25));
+    assert_eq!(summary.roughness_factor, Some(0.75));
+    assert!(summary.two_sided);
+    assert_eq!(summary.texture_counts.get(TextureType::BaseColor), 1);
+    assert_eq!(summary.texture_counts.get(TextureType::Normals), 1);
+    assert!(summary.texture_counts.total() >= 5);
+    assert!(
+        summary
+            .texture_counts
+            .iter()
+            .all(|(t, count)| count == textured.texture_count(t))
+    );
+
+    let plain = find("Plain").summary();
+    assert_eq!(plain.name, "Plain");
+    assert!(plain.texture_counts.is_empty());
+    assert!(!plain.two_sided);
+}
@@ -0,0 +1,151 @@
+//! Embedded texture hashing, deduplication and extraction tests
+
+use asset_importer::Scene;
+#[cfg(not(target_family = "wasm"))]
+use asset_importer::{TextureNaming, TextureType};
+
+use crate::common;
+
+// Signature plus an empty IEND chunk: enough for the payload to be passed through.
+const PNG_STUB: &[u8] = &[
+    0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x00, b'I', b'E', b'N', b'D',
+    0xAE, 0x42, 0x60, 0x82,
+];
+
+// A different payload declared under the same image name as `PNG_STUB`.
+const OTHER_PNG_STUB: &[u8] = &[
+    0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x00, b'I', b'E', b'N', b'D',
+    0xAE, 0x42, 0x60, 0x82, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// A triangle GLB with three embedded images: the same PNG twice and a different PNG
+/// under the same name. Material 0 uses image 0 as base color and emissive, material 1
+/// uses the duplicate (image 1) and material 2 the different image.
+fn glb_with_duplicate_png() -> Vec<u8> {
+    common::triangle_glb_with_images(
+        &[PNG_STUB, PNG_STUB, OTHER_PNG_STUB],
+        r#"{
+  "images": [
+    { "bufferView": 1, "mimeType": "image/png", "name": "albedo" },
+    { "bufferView": 2, "mimeType": "image/png", "name": "albedo" },
+    { "bufferView": 3, "mimeType": "image/png", "name": "albedo" }
+  ],
+  "textures": [{ "source": 0 }, { "source": 1 }, { "source": 2 }],
+  "materials": [
+    { "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } }, "emissiveTexture": { "index": 0 } },
+    { "pbrMetallicRoughness": { "baseColorTexture": { "index": 1 } } },
+    { "pbrMetallicRoughness": { "baseColorTexture": { "index": 2 } } }
+  ],
+  "meshes": [
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 1 }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 }, "material": 2 }] }
+  ],
+  "nodes": [{ "mesh": 0 }, { "mesh": 1 }, { "mesh": 2 }],
+  "scenes": [{ "nodes": [0, 1, 2] }],
+  "scene": 0
+}"#,
+    )
+}
+
+fn import() -> Scene {
+    Scene::from_memory(&glb_with_duplicate_png(), Some("glb")).expect("import GLB")
+}
+
+#[test]
+fn test_content_hash_matches_identical_payloads() {
+    let scene = import();
+    assert_eq!(scene.num_textures(), 3);
+    let hashes: Vec<u64> = scene.textures().map(|t| t.content_hash()).collect();
+    assert_eq!(hashes[0], hashes[1]);
+    assert_ne!(hashes[0], hashes[2]);
+    // Stable FNV-1a over the compressed payload.
+    let expected = PNG_STUB.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    assert_eq!(hashes[0], expected);
+}
+
+#[test]
+fn test_deduplicated_textures_groups_indices() {
+    let scene = import();
+    let groups = scene.deduplicated_textures();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].1, vec![0, 1]);
+    assert_eq!(groups[0].0.compressed_bytes(), Some(PNG_STUB));
+    assert_eq!(groups[1].1, vec![2]);
+}
+
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_extract_textures_by_content_hash() {
+    let scene = import();
+    let root = common::temp_dir("extract_hash");
+    let dir = root.join("textures");
+    let extracted = scene
+        .extract_textures_to_dir(&dir, TextureNaming::ByContentHash)
+        .expect("extract textures");
+
+    assert_eq!(extracted.len(), 2);
+    let first = &extracted[0];
+    assert_eq!(
+        first.path.file_name().unwrap().to_str().unwrap(),
+        format!("{:016x}.png", scene.texture(0).unwrap().content_hash())
+    );
+    assert_eq!(std::fs::read(&first.path).unwrap(), PNG_STUB);
+    assert_eq!(first.texture_indices, vec![0, 1]);
+
+    // Material 0 references the PNG twice; material 1 references the duplicate.
+    let base_color: Vec<_> = first
+        .references
+        .iter()
+        .filter(|r| r.texture_type == TextureType::BaseColor)
+        .map(|r| r.material_index)
+        .collect();
+    assert!(base_color.contains(&0) && base_color.contains(&1));
+    assert!(
+        first
+            .references
+            .iter()
+            .any(|r| r.material_index == 0 && r.texture_type == TextureType::Emissive)
+    );
+    assert!(
+        extracted[1]
+            .references
+            .iter()
+            .all(|r| r.material_index == 2)
+    );
+
+    let _ = std::fs::remove_dir_all(&root);
+}
+
+#[test]
+#[cfg(not(target_family = "wasm"))]
+fn test_extract_textures_disambiguates_names() {
+    let scene = import();
+    // Both distinct textures are declared as "albedo".
+    assert_eq!(
+        scene.texture(2).unwrap().filename().as_deref(),
+        Some("albedo")
+    );
+    for naming in [TextureNaming::ByIndex, TextureNaming::ByFilename] {
+        let root = common::temp_dir("extract_names");
+        let dir = root.join("textures");
+        let extracted = scene
+            .extract_textures_to_dir(&dir, naming)
+            .expect("extract textures");
+
+        assert_eq!(extracted.len(), 2);
+        assert_ne!(extracted[0].path, extracted[1].path);
+        assert_eq!(std::fs::read(&extracted[0].path).unwrap(), PNG_STUB);
+        assert_eq!(std::fs::read(&extracted[1].path).unwrap(), OTHER_PNG_STUB);
+        if naming == TextureNaming::ByIndex {
+            assert!(extracted[1].path.ends_with("texture_2.png"));
+        } else {
+            assert!(extracted[0].path.ends_with("albedo.png"));
+            assert!(extracted[1].path.ends_with("albedo_1.png"));
+        }
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
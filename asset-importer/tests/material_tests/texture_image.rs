@@ -0,0 +1,97 @@
+//! Decoding material textures through `Material::load_texture_image`
+
+#![cfg(feature = "image")]
+
+use std::path::Path;
+
+use asset_importer::{
+    Importer, Scene, TextureType,
+    material::{ImageSource, TextureLoadContext},
+};
+
+use crate::common;
+
+// 2x2 RGBA PNG: red, green / blue, white.
+const CHECKER_PNG: &[u8] = include_bytes!("../models/textures/checker.png");
+const CHECKER_RGBA: [u8; 16] = [
+    255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255, 255, 255, 255,
+];
+
+/// A single-triangle GLB whose base color texture is the embedded checker PNG.
+fn glb_with_embedded_png() -> Vec<u8> {
+    common::triangle_glb_with_images(
+        &[CHECKER_PNG],
+        r#"{
+  "images": [{ "bufferView": 1, "mimeType": "image/png" }],
+  "textures": [{ "source": 0 }],
+  "materials": [{ "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } }],
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] }],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#,
+    )
+}
+
+#[test]
+fn test_external_and_embedded_load_the_same_way() {
+    // checker.mtl references `C:\Users\artist\textures\checker.png`; the file only
+    // exists in the `textures` search path.
+    let model = Path::new("tests/models/checker.obj");
+    let obj = Importer::new().import_file(model).expect("import OBJ");
+    let ctx = TextureLoadContext::for_model_file(model).with_search_path("tests/models/textures");
+    let external = obj
+        .material(0)
+        .expect("material")
+        .load_texture_image(TextureType::Diffuse, 0, &ctx)
+        .expect("load external PNG");
+
+    let glb = Scene::from_memory(&glb_with_embedded_png(), Some("glb")).expect("import GLB");
+    let ctx = TextureLoadContext::default();
+    let embedded = glb
+        .material(0)
+        .expect("material")
+        .load_texture_image(TextureType::Diffuse, 0, &ctx)
+        .expect("load embedded PNG");
+
+    assert_eq!(
+        external.source,
+        ImageSource::External(Path::new("tests/models/textures/checker.png").to_path_buf())
+    );
+    assert_eq!(embedded.source, ImageSource::Embedded(0));
+    for image in [&external, &embedded] {
+        assert_eq!((image.width, image.height), (2, 2));
+        assert_eq!(image.rgba, CHECKER_RGBA);
+        assert_eq!(image.texture_type, TextureType::Diffuse);
+    }
+}
+
+#[test]
+fn test_missing_texture_fallback() {
+    let model = Path::new("tests/models/checker.obj");
+    let scene = Importer::new().import_file(model).expect("import OBJ");
+    let material = scene.material(0).expect("material");
+
+    // Without the search path the file is not found.
+    let ctx = TextureLoadContext::for_model_file(model);
+    assert!(
+        material
+            .load_texture_image(TextureType::Diffuse, 0, &ctx)
+            .is_err()
+    );
+
+    let ctx = ctx.with_fallback(true);
+    let diffuse = material
+        .load_texture_image(TextureType::Diffuse, 0, &ctx)
+        .expect("fallback");
+    assert_eq!(diffuse.source, ImageSource::Fallback);
+    assert_eq!((diffuse.width, diffuse.height), (1, 1));
+    assert_eq!(diffuse.rgba, [255, 255, 255, 255]);
+
+    // Slots the material does not have fall back as well; normal maps stay flat.
+    let normals = material
+        .load_texture_image(TextureType::Normals, 0, &ctx)
+        .expect("fallback");
+    assert_eq!(normals.source, ImageSource::Fallback);
+    assert_eq!(normals.rgba, [128, 128, 255, 255]);
+}
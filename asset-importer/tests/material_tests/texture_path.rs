@@ -0,0 +1,156 @@
+//! Texture path resolution tests
+
+use asset_importer::{
+    Importer, PathResolveOptions, ResolvedPath, TextureType, postprocess::PostProcessSteps,
+};
+use std::path::{Path, PathBuf};
+
+use crate::common;
+
+const OBJ: &str = "mtllib scene.mtl\n\
+v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvt 0 1\n\
+usemtl mat0\nf 1/1 2/2 3/3\n";
+
+const MTL: &str = "newmtl mat0\n\
+Kd 1 1 1\n\
+map_Kd textures\\wood.png\n\
+map_Ks C:\\Users\\artist\\Desktop\\metal.png\n\
+map_Ka my%20tex.png\n\
+map_bump ../outside/normal.png\n";
+
+struct Fixture {
+    dir: PathBuf,
+}
+
+impl Fixture {
+    fn new(tag: &str) -> Self {
+        let dir = common::temp_dir(&format!("texture_paths_{tag}"));
+        std::fs::create_dir(dir.join("textures")).unwrap();
+        std::fs::write(dir.join("scene.obj"), OBJ).unwrap();
+        std::fs::write(dir.join("scene.mtl"), MTL).unwrap();
+        for file in ["textures/wood.png", "metal.png", "my tex.png", "normal.png"] {
+            std::fs::write(dir.join(file), b"png").unwrap();
+        }
+        Self { dir }
+    }
+
+    fn path(&self, file: &str) -> PathBuf {
+        self.dir.join(file)
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn texture_with_path(fixture: &Fixture, path: &str) -> asset_importer::TextureInfo {
+    let scene = Importer::new()
+        .read_file(fixture.path("scene.obj"))
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import scene.obj");
+    let mut info = scene
+        .material(0)
+        .and_then(|m| m.texture(TextureType::Diffuse, 0))
+        .expect("diffuse texture");
+    info.path = path.to_string();
+    info
+}
+
+fn dir(fixture: &Fixture) -> &Path {
+    &fixture.dir
+}
+
+#[test]
+fn test_resolve_path_cleanups() {
+    let fixture = Fixture::new("cleanups");
+    let options = PathResolveOptions::default();
+    let resolve =
+        |path: &str| texture_with_path(&fixture, path).resolve_path(dir(&fixture), options);
+
+    assert_eq!(
+        resolve("textures\\wood.png"),
+        ResolvedPath::Found(fixture.path("textures").join("wood.png"))
+    );
+    assert_eq!(
+        resolve("C:\\Users\\artist\\Desktop\\metal.png"),
+        ResolvedPath::Found(fixture.path("metal.png"))
+    );
+    assert_eq!(
+        resolve("/home/artist/metal.png"),
+        ResolvedPath::Found(fixture.path("metal.png"))
+    );
+    assert_eq!(
+        resolve("my%20tex.png"),
+        ResolvedPath::Found(fixture.path("my tex.png"))
+    );
+    assert_eq!(
+        resolve("file://my%20tex.png"),
+        ResolvedPath::Found(fixture.path("my tex.png"))
+    );
+    // Escaping `..` is reduced to the file name inside the model directory.
+    assert_eq!(
+        resolve("../outside/normal.png"),
+        ResolvedPath::Found(fixture.path("normal.png"))
+    );
+    assert_eq!(resolve("*2"), ResolvedPath::Embedded(2));
+    assert_eq!(
+        resolve("missing/rock.png"),
+        ResolvedPath::Candidate(fixture.path("missing").join("rock.png"))
+    );
+}
+
+#[test]
+fn test_resolve_path_verbatim_options() {
+    let fixture = Fixture::new("verbatim");
+    let options = PathResolveOptions::verbatim();
+    let resolve =
+        |path: &str| texture_with_path(&fixture, path).resolve_path(dir(&fixture), options);
+
+    assert_eq!(
+        resolve("my%20tex.png"),
+        ResolvedPath::Candidate(fixture.path("my%20tex.png"))
+    );
+    // Allowed `..` is kept; the missing target falls back to the file name.
+    assert_eq!(
+        resolve("../outside/normal.png"),
+        ResolvedPath::Found(fixture.path("normal.png"))
+    );
+    assert_eq!(
+        resolve("../outside/rock.png"),
+        ResolvedPath::Candidate(fixture.dir.join("..").join("outside").join("rock.png"))
+    );
+    assert_eq!(
+        resolve("/abs/rock.png"),
+        ResolvedPath::Candidate(PathBuf::from("/abs/rock.png"))
+    );
+}
+
+#[test]
+fn test_material_resolved_texture_paths() {
+    let fixture = Fixture::new("batch");
+    let scene = Importer::new()
+        .read_file(fixture.path("scene.obj"))
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import scene.obj");
+    let material = scene.material(0).expect("material");
+
+    let resolved = material.resolved_texture_paths(dir(&fixture), PathResolveOptions::default());
+    assert_eq!(resolved.len(), 4, "{resolved:?}");
+    assert!(
+        resolved.iter().all(|t| t.resolved.is_available()),
+        "{resolved:?}"
+    );
+    let diffuse = resolved
+        .iter()
+        .find(|t| t.texture_type == TextureType::Diffuse)
+        .expect("diffuse entry");
+    assert_eq!(diffuse.index, 0);
+    assert_eq!(
+        diffuse.resolved,
+        ResolvedPath::Found(fixture.path("textures").join("wood.png"))
+    );
+}
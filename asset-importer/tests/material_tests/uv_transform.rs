@@ -0,0 +1,226 @@
+//! UV transform convention tests
+
+use std::f32::consts::FRAC_PI_2;
+
+use asset_importer::{
+    Importer, Scene,
+    material::{TextureType, UVTransform},
+    test_utils,
+    types::{Vector2D, Vector3D},
+};
+
+use crate::common;
+
+const EPSILON: f32 = 1e-5;
+
+fn assert_uv(actual: Vector2D, expected: (f32, f32)) {
+    assert!(
+        (actual.x - expected.0).abs() < EPSILON && (actual.y - expected.1).abs() < EPSILON,
+        "expected {expected:?}, got ({}, {})",
+        actual.x,
+        actual.y
+    );
+}
+
+/// Scale (2, 0.5), translate by (0.5, 0), then rotate 90° clockwise about (0.5, 0.5).
+fn rotated_scaled() -> UVTransform {
+    UVTransform {
+        translation: Vector2D::new(0.5, 0.0),
+        scaling: Vector2D::new(2.0, 0.5),
+        rotation: FRAC_PI_2,
+    }
+}
+
+#[test]
+fn test_apply_maps_corners() {
+    let transform = rotated_scaled();
+    // (u, v) -> (0.5 * v, 0.5 - 2 * u)
+    let corners = [
+        ((0.0, 0.0), (0.0, 0.5)),
+        ((1.0, 0.0), (0.0, -1.5)),
+        ((0.0, 1.0), (0.5, 0.5)),
+        ((1.0, 1.0), (0.5, -1.5)),
+    ];
+    for ((u, v), expected) in corners {
+        assert_uv(transform.apply(Vector2D::new(u, v)), expected);
+    }
+}
+
+#[test]
+fn test_matrix_matches_apply_for_row_vectors() {
+    let transform = rotated_scaled();
+    let m = transform.to_matrix3().to_cols_array_2d();
+    for (u, v) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 1.0), (0.25, 0.75)] {
+        let row = Vector3D::new(u, v, 1.0);
+        // [u, v, 1] * m: each output component is the row dotted with a column.
+        let column = |c: [f32; 3]| row.x * c[0] + row.y * c[1] + row.z * c[2];
+        let out = Vector2D::new(column(m[0]), column(m[1]));
+        assert_uv(out, {
+            let expected = transform.apply(Vector2D::new(u, v));
+            (expected.x, expected.y)
+        });
+        assert!((column(m[2]) - 1.0).abs() < EPSILON);
+    }
+}
+
+#[test]
+fn test_identity_is_default() {
+    assert_eq!(UVTransform::default(), UVTransform::IDENTITY);
+    assert_uv(
+        UVTransform::IDENTITY.apply(Vector2D::new(0.3, 0.7)),
+        (0.3, 0.7),
+    );
+}
+
+#[test]
+fn test_mesh_texture_coords_transformed() {
+    let scene = Importer::new()
+        .import_file("tests/models/textured.obj")
+        .expect("import textured.obj");
+    let mesh = scene.meshes().next().expect("mesh");
+
+    let baked = mesh
+        .texture_coords_transformed(0, &rotated_scaled())
+        .expect("UV channel 0");
+    let expected = [(0.0, 0.5), (0.0, -1.5), (0.5, 0.5)];
+    assert_eq!(baked.len(), expected.len());
+    for (uv, expected) in baked.into_iter().zip(expected) {
+        assert_uv(uv, expected);
+    }
+    assert!(
+        mesh.texture_coords_transformed(1, &UVTransform::IDENTITY)
+            .is_none()
+    );
+}
+
+const TRANSFORMED_GLTF: &str = r#"{
+  "extensionsUsed": ["KHR_texture_transform"],
+  "images": [{ "uri": "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mP8/x8AAwMCAO+/p9sAAAAASUVORK5CYII=" }],
+  "textures": [{ "source": 0 }],
+  "materials": [{
+    "name": "Transformed",
+    "pbrMetallicRoughness": {
+      "baseColorTexture": {
+        "index": 0,
+        "extensions": { "KHR_texture_transform": { "offset": [0.25, 0.0], "scale": [2.0, 3.0] } }
+      }
+    }
+  }],
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] }],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_material_uv_transform_from_gltf() {
+    let scene = Scene::from_memory(
+        common::triangle_gltf(TRANSFORMED_GLTF).as_bytes(),
+        Some("gltf"),
+    )
+    .expect("import");
+    let material = scene
+        .materials()
+        .find(|m| m.name() == "Transformed")
+        .expect("material");
+
+    let transform = material
+        .uv_transform(TextureType::Diffuse, 0)
+        .expect("KHR_texture_transform becomes $tex.uvtrafo");
+    assert_uv(transform.scaling, (2.0, 3.0));
+    assert!(transform.rotation.abs() < EPSILON);
+    assert!((transform.translation.x - 0.25).abs() < EPSILON);
+
+    let texture = material
+        .texture_ref(TextureType::Diffuse, 0)
+        .expect("diffuse texture");
+    assert_eq!(texture.uv_transform, Some(transform));
+    assert!(material.uv_transform(TextureType::Diffuse, 1).is_none());
+}
+
+// Positions as in `common::triangle_bytes`, then UVs (0, 0) (1, 0) (0.25, 1).
+const ROTATED_GLTF: &str = r#"{
+  "extensionsUsed": ["KHR_texture_transform"],
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 24 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2" }
+  ],
+  "images": [{ "uri": "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mP8/x8AAwMCAO+/p9sAAAAASUVORK5CYII=" }],
+  "textures": [{ "source": 0 }],
+  "materials": [{
+    "name": "Rotated",
+    "pbrMetallicRoughness": {
+      "baseColorTexture": {
+        "index": 0,
+        "extensions": { "KHR_texture_transform": { "rotation": 0.5 } }
+      }
+    }
+  }],
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0, "TEXCOORD_0": 1 }, "material": 0 }] }],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_gltf_rotation_baked_into_texture_coords() {
+    let file_uvs = [(0.0f32, 0.0f32), (1.0, 0.0), (0.25, 1.0)];
+    let uvs = file_uvs
+        .iter()
+        .flat_map(|&(u, v)| [u, v])
+        .collect::<Vec<_>>();
+    let buffer = [common::triangle_bytes(), test_utils::f32_bytes(&uvs)].concat();
+    let scene = Scene::from_memory(common::gltf(&buffer, ROTATED_GLTF).as_bytes(), Some("gltf"))
+        .expect("import");
+    let material = scene
+        .materials()
+        .find(|m| m.name() == "Rotated")
+        .expect("material");
+    let transform = material
+        .uv_transform(TextureType::Diffuse, 0)
+        .expect("KHR_texture_transform becomes $tex.uvtrafo");
+    // Assimp stores the negated glTF angle.
+    assert!((transform.rotation + 0.5).abs() < EPSILON);
+
+    let baked = scene
+        .mesh(0)
+        .expect("mesh")
+        .texture_coords_transformed(0, &transform)
+        .expect("UV channel 0");
+    let (sin, cos) = 0.5f32.sin_cos();
+    assert_eq!(baked.len(), file_uvs.len());
+    for (uv, (u, v)) in baked.into_iter().zip(file_uvs) {
+        // KHR_texture_transform in glTF's V-down space, then Assimp's V flip.
+        let (u2, v2) = (cos * u + sin * v, -sin * u + cos * v);
+        assert_uv(uv, (u2, 1.0 - v2));
+    }
+}
+
+#[test]
+fn test_mtl_texture_options_keep_path_and_helpers_agree() {
+    let scene = Importer::new()
+        .import_file("tests/models/texture_options.obj")
+        .expect("import texture_options.obj");
+    let material = scene
+        .materials()
+        .find(|m| m.name() == "offset_scaled")
+        .expect("offset_scaled material");
+
+    let texture = material
+        .texture_ref(TextureType::Diffuse, 0)
+        .expect("map_Kd texture");
+    // The -o/-s options must not leak into the path.
+    assert_eq!(texture.path_str(), "checker.png");
+    assert_eq!(
+        texture.uv_transform,
+        material.uv_transform(TextureType::Diffuse, 0)
+    );
+    assert_eq!(
+        texture.axis,
+        material.texture_map_axis(TextureType::Diffuse, 0)
+    );
+}
@@ -0,0 +1,12 @@
+//! Mesh attribute, geometry, morph and skinning tests
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod mesh_attribute;
+mod mesh_measure;
+mod mint_interop;
+mod morph;
+mod primitive_type;
+mod skinning;
+mod topology;
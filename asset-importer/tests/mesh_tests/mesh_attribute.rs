@@ -0,0 +1,584 @@
+//! Mesh vertex attribute tests
+
+use asset_importer::{
+    Importer, Scene, Vector2D, Vector3D,
+    mesh::{
+        bake::{VertexAttribute, VertexLayout},
+        compute::{NormalMode, WeldAttributes, generate_normals, generate_tangents, weld_vertices},
+    },
+    postprocess::PostProcessSteps,
+    test_utils,
+};
+
+use crate::common;
+
+// One triangle with two UV channels: (0,0) (1,0) (0,1) and (0.5,0.5) (0.25,0.75) (1,1).
+fn two_uv_gltf() -> String {
+    let uv0 = test_utils::f32_bytes(&[0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+    let uv1 = test_utils::f32_bytes(&[0.5, 0.5, 0.25, 0.75, 1.0, 1.0]);
+    common::gltf(
+        &[common::triangle_bytes(), uv0, uv1].concat(),
+        r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 24 },
+    { "buffer": 0, "byteOffset": 60, "byteLength": 24 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2" },
+    { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2" }
+  ],
+  "meshes": [
+    { "primitives": [{ "attributes": { "POSITION": 0, "TEXCOORD_0": 1, "TEXCOORD_1": 2 } }] }
+  ],
+  "nodes": [{ "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#,
+    )
+}
+
+#[test]
+fn test_gltf_two_uv_channels() {
+    let gltf = two_uv_gltf();
+    let scene = Scene::from_memory(gltf.as_bytes(), Some("gltf")).expect("import glTF");
+    let mesh = scene.mesh(0).expect("mesh 0");
+
+    assert_eq!(mesh.num_uv_channels(), 2);
+    assert_eq!(mesh.num_color_channels(), 0);
+    assert_eq!(mesh.num_uv_components(0), Some(2));
+    assert_eq!(mesh.num_uv_components(1), Some(2));
+    assert_eq!(mesh.num_uv_components(2), None);
+    assert_eq!(mesh.num_uv_components(64), None);
+    assert!(mesh.uv_channel_name(64).is_none());
+
+    let uv1 = mesh.texture_coords_2d(1).expect("UV channel 1");
+    assert_eq!(uv1.len(), 3);
+    // Assimp's glTF importer flips V (v' = 1 - v).
+    common::assert_close(uv1[1].x, 0.25);
+    common::assert_close(uv1[1].y, 0.25);
+    assert!(mesh.texture_coords_2d(2).is_none());
+}
+
+#[test]
+fn test_bake_vertices_matches_manual_assembly() {
+    let scene = Importer::new()
+        .read_file("tests/models/textured.obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import textured.obj");
+    let mesh = scene.mesh(0).expect("mesh 0");
+
+    let layout = VertexLayout::new()
+        .with_position()
+        .with_normal()
+        .with_uv(0)
+        .with_color(0);
+    let baked = mesh.bake_vertices(&layout).expect("bake");
+
+    assert_eq!(baked.stride(), (3 + 3 + 2 + 4) * 4);
+    assert_eq!(baked.offset_of(VertexAttribute::Normal), Some(12));
+    assert_eq!(
+        baked.offset_of(VertexAttribute::Color { channel: 0 }),
+        Some(32)
+    );
+    assert!(!baked.attributes()[3].present);
+    assert_eq!(baked.vertex_count(), mesh.num_vertices());
+    assert_eq!(
+        baked.interleaved_bytes().len(),
+        baked.stride() * mesh.num_vertices()
+    );
+
+    let mut manual = Vec::new();
+    let normals = mesh.normals().expect("normals");
+    let uvs = mesh.texture_coords(0).expect("uvs");
+    for (i, p) in mesh.vertices().iter().enumerate() {
+        manual.extend_from_slice(&[p.x, p.y, p.z]);
+        manual.extend_from_slice(&[normals[i].x, normals[i].y, normals[i].z]);
+        manual.extend_from_slice(&[uvs[i].x, uvs[i].y]);
+        manual.extend_from_slice(&[1.0, 1.0, 1.0, 1.0]);
+    }
+    assert_eq!(baked.interleaved(), manual.as_slice());
+
+    let manual_indices: Vec<u32> = mesh.triangle_indices_iter().collect();
+    assert_eq!(baked.indices, manual_indices);
+
+    assert!(mesh.bake_vertices(&layout.strict(true)).is_err());
+}
+
+fn import_box(extra: PostProcessSteps) -> Scene {
+    Importer::new()
+        .read_file("tests/models/box.obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE | extra)
+        .import()
+        .expect("import box.obj")
+}
+
+fn assert_normals_close(actual: &[Vector3D], expected: &[Vector3D]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((*a - *e).length() < 1e-4, "expected {e:?}, got {a:?}");
+    }
+}
+
+#[test]
+fn test_computed_normals_match_assimp_smooth_normals() {
+    let plain = import_box(PostProcessSteps::empty());
+    let mesh = plain.mesh(0).expect("mesh 0");
+    assert!(mesh.normals().is_none());
+
+    let computed = mesh
+        .computed_normals(NormalMode::SmoothAngle(175.0))
+        .expect("computed normals");
+
+    let reference = import_box(PostProcessSteps::GEN_SMOOTH_NORMALS);
+    let expected = reference
+        .mesh(0)
+        .unwrap()
+        .normals()
+        .expect("assimp normals");
+    assert_normals_close(&computed, &expected);
+}
+
+#[test]
+fn test_computed_flat_normals_match_assimp_face_normals() {
+    let plain = import_box(PostProcessSteps::empty());
+    let computed = plain
+        .mesh(0)
+        .unwrap()
+        .computed_normals(NormalMode::Flat)
+        .expect("computed normals");
+
+    let reference = import_box(PostProcessSteps::GEN_NORMALS);
+    let expected = reference
+        .mesh(0)
+        .unwrap()
+        .normals()
+        .expect("assimp normals");
+    assert_normals_close(&computed, &expected);
+}
+
+#[test]
+fn test_generate_normals_degenerate_and_invalid_input() {
+    let positions = [
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(1.0, 0.0, 0.0),
+        Vector3D::new(0.0, 1.0, 0.0),
+        Vector3D::new(2.0, 0.0, 0.0),
+    ];
+    // Second triangle is collinear; vertex 3 is only used by it.
+    let indices = [0, 1, 2, 0, 1, 3];
+    for mode in [NormalMode::Flat, NormalMode::SmoothAngle(60.0)] {
+        let normals = generate_normals(&positions, &indices, mode).expect("normals");
+        assert!(
+            normals
+                .iter()
+                .all(|n| n.x.is_finite() && n.y.is_finite() && n.z.is_finite())
+        );
+        assert_eq!(normals[0], Vector3D::new(0.0, 0.0, 1.0));
+        assert_eq!(normals[3], Vector3D::ZERO);
+    }
+
+    assert!(generate_normals(&positions, &[0, 1], NormalMode::Flat).is_err());
+    assert!(generate_normals(&positions, &[0, 1, 4], NormalMode::Flat).is_err());
+}
+
+#[test]
+fn test_computed_normals_rejects_quads() {
+    let scene = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj");
+    let mesh = scene.mesh(0).expect("mesh 0");
+    assert!(mesh.computed_normals(NormalMode::Flat).is_err());
+}
+
+fn assert_valid_tangents(tangents: &[[f32; 4]], normals: &[Vector3D]) {
+    assert_eq!(tangents.len(), normals.len());
+    for (t, n) in tangents.iter().zip(normals) {
+        let tangent = Vector3D::new(t[0], t[1], t[2]);
+        assert!(
+            (tangent.length() - 1.0).abs() < 1e-4,
+            "{t:?} is not unit length"
+        );
+        assert!(
+            tangent.dot(n.normalize()).abs() < 1e-4,
+            "{t:?} not orthogonal to {n:?}"
+        );
+        assert!(t[3] == 1.0 || t[3] == -1.0, "w = {}", t[3]);
+    }
+}
+
+#[test]
+fn test_tangents_with_sign_and_computed_tangents() {
+    let scene = Importer::new()
+        .read_file("tests/models/textured.obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE | PostProcessSteps::CALC_TANGENT_SPACE)
+        .import()
+        .expect("import textured.obj");
+    let mesh = scene.mesh(0).expect("mesh 0");
+    let normals = mesh.normals().expect("normals");
+
+    let assimp = mesh.tangents_with_sign().expect("tangents");
+    assert_valid_tangents(&assimp, &normals);
+    common::assert_close(assimp[0][0], 1.0);
+    assert_eq!(assimp[0][3], 1.0);
+
+    let computed = mesh.computed_tangents(0).expect("computed tangents");
+    assert_valid_tangents(&computed, &normals);
+    for (a, c) in assimp.iter().zip(&computed) {
+        assert!(
+            a.iter().zip(c).all(|(a, c)| (a - c).abs() < 1e-4),
+            "{a:?} vs {c:?}"
+        );
+    }
+    assert!(mesh.computed_tangents(1).is_err());
+}
+
+#[test]
+fn test_generate_tangents_mirrored_and_degenerate_uvs() {
+    let positions = [
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(1.0, 0.0, 0.0),
+        Vector3D::new(0.0, 1.0, 0.0),
+    ];
+    let normals = [Vector3D::new(0.0, 0.0, 1.0); 3];
+    let indices = [0, 1, 2];
+
+    let mirrored = [
+        Vector2D::new(1.0, 0.0),
+        Vector2D::new(0.0, 0.0),
+        Vector2D::new(1.0, 1.0),
+    ];
+    let tangents = generate_tangents(&positions, &normals, &mirrored, &indices).expect("tangents");
+    assert_valid_tangents(&tangents, &normals);
+    assert!(
+        tangents
+            .iter()
+            .all(|t| t[3] == -1.0 && (t[0] + 1.0).abs() < 1e-5)
+    );
+
+    let degenerate = [Vector2D::new(0.5, 0.5); 3];
+    let tangents =
+        generate_tangents(&positions, &normals, &degenerate, &indices).expect("tangents");
+    assert_valid_tangents(&tangents, &normals);
+
+    assert!(generate_tangents(&positions, &normals[..2], &degenerate, &indices).is_err());
+}
+
+/// A `cols` x `rows` grid of unit quads on z = 0 where every quad owns its four corners,
+/// so interior corners are duplicated up to four times. `jitter` offsets each copy.
+fn unshared_grid(cols: u32, rows: u32, jitter: f32) -> (Vec<Vector3D>, Vec<Vector2D>, Vec<u32>) {
+    let (mut positions, mut uvs, mut indices) = (Vec::new(), Vec::new(), Vec::new());
+    for row in 0..rows {
+        for col in 0..cols {
+            let base = positions.len() as u32;
+            for (dx, dy) in [(0, 0), (1, 0), (1, 1), (0, 1)] {
+                let offset = jitter * (positions.len() % 3) as f32;
+                positions.push(Vector3D::new(
+                    (col + dx) as f32 + offset,
+                    (row + dy) as f32,
+                    0.0,
+                ));
+                // Each quad maps the full texture, so shared corners disagree on UVs.
+                uvs.push(Vector2D::new(dx as f32, dy as f32));
+            }
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+    }
+    (positions, uvs, indices)
+}
+
+#[test]
+fn test_weld_vertices_grid_seams() {
+    let (positions, _, indices) = unshared_grid(3, 2, 1e-5);
+    assert_eq!(positions.len(), 24);
+
+    let welded = weld_vertices(&positions, &indices, 1e-3, None).expect("weld");
+    assert_eq!(welded.num_vertices(), 4 * 3);
+    assert_eq!(welded.remap.len(), positions.len());
+    assert_eq!(welded.indices.len(), indices.len());
+    for (&old, &new) in indices.iter().zip(&welded.indices) {
+        assert_eq!(welded.remap[old as usize], new);
+        assert!((positions[old as usize] - welded.positions[new as usize]).length() < 1e-3);
+    }
+
+    // Too tight a tolerance keeps the jittered copies apart.
+    let exact = weld_vertices(&positions, &indices, 0.0, None).expect("weld");
+    assert!(exact.num_vertices() > welded.num_vertices());
+
+    // Same output on every run.
+    assert_eq!(
+        weld_vertices(&positions, &indices, 1e-3, None).unwrap(),
+        welded
+    );
+}
+
+#[test]
+fn test_weld_vertices_attributes_keep_uv_seams() {
+    let (positions, uvs, indices) = unshared_grid(2, 1, 0.0);
+    let normals = vec![Vector3D::new(0.0, 0.0, 1.0); positions.len()];
+
+    let with_uvs = WeldAttributes {
+        normals: Some((&normals, 1.0)),
+        uvs: Some((&uvs, 1e-4)),
+    };
+    // The shared edge has UV u = 1 on the left quad and u = 0 on the right one.
+    let welded = weld_vertices(&positions, &indices, 0.0, Some(&with_uvs)).expect("weld");
+    assert_eq!(welded.num_vertices(), 8);
+
+    let normals_only = WeldAttributes {
+        normals: Some((&normals, 1.0)),
+        uvs: None,
+    };
+    let welded = weld_vertices(&positions, &indices, 0.0, Some(&normals_only)).expect("weld");
+    assert_eq!(welded.num_vertices(), 6);
+    let welded_uvs = welded.remap_attribute(&uvs).expect("remap UVs");
+    assert_eq!(welded_uvs.len(), 6);
+    assert_eq!(welded_uvs[0], uvs[0]);
+
+    let short = &uvs[..3];
+    let bad = WeldAttributes {
+        normals: None,
+        uvs: Some((short, 0.0)),
+    };
+    assert!(weld_vertices(&positions, &indices, 0.0, Some(&bad)).is_err());
+    assert!(weld_vertices(&positions, &[0, 99, 1], 0.0, None).is_err());
+    assert!(welded.remap_attribute(short).is_err());
+}
+
+#[test]
+fn test_mesh_welded_box() {
+    let scene = import_box(PostProcessSteps::empty());
+    let welded = scene.mesh(0).unwrap().welded(0.0).expect("weld box");
+    assert_eq!(welded.num_vertices(), 8);
+    assert_eq!(welded.indices.len(), 12 * 3);
+}
+
+static_assertions::assert_impl_all!(asset_importer::mesh::Mesh: Send, Sync);
+
+/// An OBJ with `objects` separate grids; each becomes its own mesh.
+fn multi_object_obj(objects: usize, size: usize) -> String {
+    let mut obj = String::new();
+    let mut base = 1;
+    for o in 0..objects {
+        obj.push_str(&format!("o grid_{o}\n"));
+        for y in 0..=size {
+            for x in 0..=size {
+                obj.push_str(&format!("v {} {} {}\nvt {} {}\n", x, y, o, x, y));
+            }
+        }
+        for y in 0..size {
+            for x in 0..size {
+                let a = base + y * (size + 1) + x;
+                let (b, c, d) = (a + 1, a + size + 1, a + size + 2);
+                obj.push_str(&format!("f {a}/{a} {b}/{b} {d}/{d} {c}/{c}\n"));
+            }
+        }
+        base += (size + 1) * (size + 1);
+    }
+    obj
+}
+
+#[test]
+fn test_bake_all_meshes_matches_sequential() {
+    let scene = Importer::new()
+        .read_from_memory(multi_object_obj(12, 8).as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE | PostProcessSteps::GEN_NORMALS)
+        .import()
+        .expect("import generated OBJ");
+    assert_eq!(scene.num_meshes(), 12);
+
+    let layout = VertexLayout::new().with_position().with_normal().with_uv(0);
+    let all = scene.bake_all_meshes(&layout).expect("bake all");
+    let sequential: Vec<_> = scene
+        .meshes()
+        .map(|mesh| mesh.bake_vertices(&layout).expect("bake"))
+        .collect();
+    assert_eq!(all.len(), sequential.len());
+    for (parallel, sequential) in all.iter().zip(&sequential) {
+        assert_eq!(parallel.interleaved_bytes(), sequential.interleaved_bytes());
+        assert_eq!(parallel.indices, sequential.indices);
+    }
+
+    let strict = layout.with_tangent().strict(true);
+    assert!(scene.bake_all_meshes(&strict).is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_meshes_keeps_mesh_order() {
+    use rayon::iter::ParallelIterator;
+
+    let scene = Scene::from_memory(multi_object_obj(6, 2).as_bytes(), Some("obj"))
+        .expect("import generated OBJ");
+    let names: Vec<String> = scene.par_meshes().map(|mesh| mesh.name()).collect();
+    let expected: Vec<String> = scene.meshes().map(|mesh| mesh.name()).collect();
+    assert_eq!(names, expected);
+}
+
+/// A UV sphere built from quads (triangle fans at the poles).
+fn quad_sphere_obj(segments: usize, rings: usize) -> String {
+    let mut obj = String::from("v 0 1 0\n");
+    for r in 1..rings {
+        let phi = std::f32::consts::PI * r as f32 / rings as f32;
+        for s in 0..segments {
+            let theta = std::f32::consts::TAU * s as f32 / segments as f32;
+            let (x, z) = (phi.sin() * theta.cos(), phi.sin() * theta.sin());
+            obj.push_str(&format!("v {x} {} {z}\n", phi.cos()));
+        }
+    }
+    obj.push_str("v 0 -1 0\n");
+    let ring = |r: usize, s: usize| 2 + (r - 1) * segments + s % segments;
+    let bottom = 2 + (rings - 1) * segments;
+    for s in 0..segments {
+        obj.push_str(&format!("f 1 {} {}\n", ring(1, s + 1), ring(1, s)));
+        obj.push_str(&format!(
+            "f {bottom} {} {}\n",
+            ring(rings - 1, s),
+            ring(rings - 1, s + 1)
+        ));
+    }
+    for r in 1..rings - 1 {
+        for s in 0..segments {
+            obj.push_str(&format!(
+                "f {} {} {} {}\n",
+                ring(r, s),
+                ring(r, s + 1),
+                ring(r + 1, s + 1),
+                ring(r + 1, s)
+            ));
+        }
+    }
+    obj
+}
+
+fn import_obj(obj: &str, steps: PostProcessSteps) -> Scene {
+    Importer::new()
+        .read_from_memory(obj.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(steps)
+        .import()
+        .expect("import generated OBJ")
+}
+
+#[test]
+fn test_triangles_fast_and_u16_indices_on_sphere() {
+    let scene = import_obj(
+        &quad_sphere_obj(16, 8),
+        PostProcessSteps::TRIANGULATE | PostProcessSteps::JOIN_IDENTICAL_VERTICES,
+    );
+    let mesh = scene.mesh(0).unwrap();
+    assert!(mesh.num_vertices() < u16::MAX as usize);
+
+    let fast: Vec<[u32; 3]> = mesh.triangles_fast().expect("pure triangles").collect();
+    assert_eq!(fast, mesh.triangles());
+    assert_eq!(fast.len(), 16 * 2 + 16 * 6 * 2);
+
+    let max = mesh.max_index().expect("faces");
+    assert_eq!(max as usize, mesh.num_vertices() - 1);
+    let flat: Vec<u32> = mesh.triangle_indices_iter().collect();
+    let u16s = mesh.triangle_indices_u16().expect("fits in u16");
+    assert_eq!(u16s.len(), flat.len());
+    assert!(u16s.iter().zip(&flat).all(|(&a, &b)| a as u32 == b));
+}
+
+#[test]
+fn test_triangles_fast_rejects_quads() {
+    let scene = import_obj(&quad_sphere_obj(8, 4), PostProcessSteps::empty());
+    let mesh = scene.mesh(0).unwrap();
+    assert!(mesh.has_polygons());
+    assert!(mesh.triangles_fast().is_none());
+    assert!(mesh.triangle_indices_u16().is_none());
+    assert!(mesh.max_index().is_some());
+}
+
+#[test]
+fn test_triangle_indices_u16_rejects_large_mesh() {
+    let scene = import_obj(&multi_object_obj(1, 260), PostProcessSteps::TRIANGULATE);
+    let mesh = scene.mesh(0).unwrap();
+    assert!(mesh.num_vertices() > u16::MAX as usize + 1);
+    assert!(mesh.max_index().unwrap() > u16::MAX as u32);
+    assert_eq!(
+        mesh.triangles_fast().expect("pure triangles").count(),
+        mesh.num_faces()
+    );
+    assert!(mesh.triangle_indices_u16().is_none());
+}
+
+const COLORED_PLY: &str = "ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0 255 0 0
+1 0 0 0 255 0
+0 1 0 0 0 255
+3 0 1 2
+";
+
+#[test]
+fn test_copy_attributes_match_accessors() {
+    let scene = import_box(PostProcessSteps::GEN_NORMALS);
+    let mesh = scene.mesh(0).expect("mesh 0");
+
+    // Stale contents are replaced, not appended to.
+    let mut positions = vec![[9.0; 3]];
+    assert!(mesh.copy_positions(&mut positions));
+    let expected: Vec<[f32; 3]> = mesh.vertices().iter().map(|v| [v.x, v.y, v.z]).collect();
+    assert_eq!(positions, expected);
+
+    let mut normals = Vec::new();
+    assert!(mesh.copy_normals(&mut normals));
+    let expected: Vec<[f32; 3]> = mesh
+        .normals()
+        .expect("normals")
+        .iter()
+        .map(|v| [v.x, v.y, v.z])
+        .collect();
+    assert_eq!(normals, expected);
+
+    let plain = import_box(PostProcessSteps::empty());
+    assert!(!plain.mesh(0).unwrap().copy_normals(&mut normals));
+    assert!(normals.is_empty());
+
+    let gltf = two_uv_gltf();
+    let scene = Scene::from_memory(gltf.as_bytes(), Some("gltf")).expect("import glTF");
+    let mesh = scene.mesh(0).expect("mesh 0");
+    let mut uvs = Vec::new();
+    for channel in 0..2 {
+        assert!(mesh.copy_texture_coords_2d(channel, &mut uvs));
+        let expected: Vec<[f32; 2]> = mesh
+            .texture_coords_2d(channel)
+            .expect("uvs")
+            .iter()
+            .map(|v| [v.x, v.y])
+            .collect();
+        assert_eq!(uvs, expected);
+    }
+    assert!(!mesh.copy_texture_coords_2d(2, &mut uvs));
+    assert!(uvs.is_empty());
+
+    let mut colors = Vec::new();
+    assert!(!mesh.copy_vertex_colors(0, &mut colors));
+    let scene = Scene::from_memory(COLORED_PLY.as_bytes(), Some("ply")).expect("import PLY");
+    let mesh = scene.mesh(0).expect("mesh 0");
+    assert!(mesh.copy_vertex_colors(0, &mut colors));
+    let expected: Vec<[f32; 4]> = mesh
+        .vertex_colors(0)
+        .expect("colors")
+        .iter()
+        .map(|c| [c.x, c.y, c.z, c.w])
+        .collect();
+    assert_eq!(colors, expected);
+    assert_eq!(colors[1], [0.0, 1.0, 0.0, 1.0]);
+}
@@ -0,0 +1,138 @@
+//! Mesh surface area and volume tests
+
+use asset_importer::{
+    Importer,
+    postprocess::PostProcessSteps,
+    scene::build::{MeshData, SceneBuilder, node},
+    types::{Matrix4x4, Quaternion, Vector3D},
+};
+
+use crate::common;
+
+const EPSILON: f64 = 1e-6;
+
+/// Unit cube from the origin to (1, 1, 1), vertex `i` at `(i & 1, i >> 1 & 1, i >> 2)`,
+/// wound counter-clockwise seen from outside.
+fn unit_cube() -> MeshData {
+    MeshData {
+        name: "cube".into(),
+        positions: (0..8)
+            .map(|i| Vector3D::new((i & 1) as f32, (i >> 1 & 1) as f32, (i >> 2) as f32))
+            .collect(),
+        indices: vec![
+            0, 2, 3, 0, 3, 1, // -z
+            4, 5, 7, 4, 7, 6, // +z
+            0, 1, 5, 0, 5, 4, // -y
+            2, 6, 7, 2, 7, 3, // +y
+            0, 4, 6, 0, 6, 2, // -x
+            1, 3, 7, 1, 7, 5, // +x
+        ],
+        ..Default::default()
+    }
+}
+
+fn scale_translate(scale: Vector3D, translation: Vector3D) -> Matrix4x4 {
+    Matrix4x4::from_scale_rotation_translation(scale, Quaternion::IDENTITY, translation)
+}
+
+#[test]
+fn test_unit_cube_area_and_volume() {
+    let mut builder = SceneBuilder::new();
+    let cube = builder.add_mesh(unit_cube());
+    builder.add_node(node("cube").with_meshes([cube]));
+    let scene = builder.build().expect("build cube");
+    let mesh = scene.mesh(0).unwrap();
+
+    assert!(mesh.is_closed());
+    common::assert_close_within(mesh.surface_area(), 6.0, EPSILON);
+    common::assert_close_within(mesh.signed_volume(), 1.0, EPSILON);
+
+    let measures = mesh.measures();
+    assert_eq!(measures.triangles, 12);
+    assert_eq!(measures.skipped_faces, 0);
+    assert_eq!(measures.skipped_non_finite, 0);
+
+    // Mirroring flips the winding, and with it the sign.
+    let mirrored = mesh.measures_transformed(&scale_translate(
+        Vector3D::new(-1.0, 1.0, 1.0),
+        Vector3D::ZERO,
+    ));
+    common::assert_close_within(mirrored.surface_area, 6.0, EPSILON);
+    common::assert_close_within(mirrored.signed_volume, -1.0, EPSILON);
+    common::assert_close_within(mirrored.volume(), 1.0, EPSILON);
+}
+
+#[test]
+fn test_world_measures_apply_node_transforms() {
+    let mut builder = SceneBuilder::new();
+    let cube = builder.add_mesh(unit_cube());
+    builder.add_node(node("original").with_meshes([cube]));
+    builder.add_node(
+        node("stretched")
+            .with_transform(scale_translate(
+                Vector3D::new(2.0, 1.0, 1.0),
+                Vector3D::new(5.0, 0.0, 0.0),
+            ))
+            .with_meshes([cube]),
+    );
+    let scene = builder.build().expect("build cubes");
+
+    // 6 for the unit cube plus 2 * (2 + 2 + 1) for the 2x1x1 box.
+    common::assert_close_within(scene.world_surface_area(), 16.0, EPSILON);
+    let measures = scene.world_measures();
+    common::assert_close_within(measures.signed_volume, 3.0, EPSILON);
+    assert_eq!(measures.triangles, 24);
+
+    // The translation moves the box but does not change its measures.
+    let stretched = scene
+        .mesh(0)
+        .unwrap()
+        .measures_transformed(&scale_translate(
+            Vector3D::new(2.0, 1.0, 1.0),
+            Vector3D::new(5.0, 0.0, 0.0),
+        ));
+    common::assert_close_within(stretched.surface_area, 10.0, EPSILON);
+    common::assert_close_within(stretched.signed_volume, 2.0, EPSILON);
+}
+
+#[test]
+fn test_degenerate_and_non_finite_triangles() {
+    let mut cube = unit_cube();
+    cube.positions.push(Vector3D::new(f32::NAN, 0.0, 0.0));
+    // A collinear triangle and one with a NaN corner.
+    cube.indices.extend([0, 1, 1, 0, 1, 8]);
+    let mut builder = SceneBuilder::new();
+    let cube = builder.add_mesh(cube);
+    builder.add_node(node("cube").with_meshes([cube]));
+    let scene = builder.build().expect("build cube");
+
+    let measures = scene.mesh(0).unwrap().measures();
+    common::assert_close_within(measures.surface_area, 6.0, EPSILON);
+    common::assert_close_within(measures.signed_volume, 1.0, EPSILON);
+    assert_eq!(measures.triangles, 13);
+    assert_eq!(measures.skipped_non_finite, 1);
+}
+
+#[test]
+fn test_polygons_are_skipped_until_triangulated() {
+    let import = |steps| {
+        Importer::new()
+            .read_file("tests/models/box.obj")
+            .with_post_process(steps)
+            .import()
+            .expect("import box.obj")
+    };
+
+    let quads = import(PostProcessSteps::JOIN_IDENTICAL_VERTICES);
+    let measures = quads.mesh(0).unwrap().measures();
+    assert_eq!(measures.triangles, 0);
+    assert_eq!(measures.skipped_faces, 6);
+    common::assert_close_within(measures.surface_area, 0.0, EPSILON);
+
+    let triangles =
+        import(PostProcessSteps::JOIN_IDENTICAL_VERTICES | PostProcessSteps::TRIANGULATE);
+    let mesh = triangles.mesh(0).unwrap();
+    assert!(mesh.is_closed());
+    common::assert_close_within(mesh.surface_area(), 6.0, EPSILON);
+    common::assert_close_within(mesh.signed_volume(), 1.0, EPSILON);
+}
@@ -0,0 +1,86 @@
+//! `mint` interop tests
+#![cfg(feature = "mint")]
+
+use asset_importer::{Color4D, Matrix3x3, Matrix4x4, Quaternion, Vector2D, Vector3D, Vector4D};
+use std::mem::{align_of, size_of};
+
+#[test]
+fn test_layouts_match_mint() {
+    assert_eq!(size_of::<Vector2D>(), size_of::<mint::Vector2<f32>>());
+    assert_eq!(align_of::<Vector2D>(), align_of::<mint::Vector2<f32>>());
+    assert_eq!(size_of::<Vector3D>(), size_of::<mint::Vector3<f32>>());
+    assert_eq!(align_of::<Vector3D>(), align_of::<mint::Vector3<f32>>());
+    assert_eq!(size_of::<Color4D>(), size_of::<mint::Vector4<f32>>());
+    assert_eq!(align_of::<Color4D>(), align_of::<mint::Vector4<f32>>());
+    assert_eq!(
+        size_of::<Matrix3x3>(),
+        size_of::<mint::ColumnMatrix3<f32>>()
+    );
+    assert_eq!(
+        align_of::<Matrix3x3>(),
+        align_of::<mint::ColumnMatrix3<f32>>()
+    );
+    assert_eq!(
+        size_of::<Matrix4x4>(),
+        size_of::<mint::ColumnMatrix4<f32>>()
+    );
+    assert_eq!(
+        align_of::<Matrix4x4>(),
+        align_of::<mint::ColumnMatrix4<f32>>()
+    );
+
+    // The float-array mesh views hand out the same layout as the mint vectors.
+    assert_eq!(size_of::<[f32; 3]>(), size_of::<mint::Vector3<f32>>());
+    assert_eq!(size_of::<[f32; 4]>(), size_of::<mint::Vector4<f32>>());
+}
+
+#[test]
+fn test_vector_conversions() {
+    let v = Vector3D::new(1.0, -2.0, 3.5);
+    let m: mint::Vector3<f32> = v.into();
+    assert_eq!((m.x, m.y, m.z), (1.0, -2.0, 3.5));
+    assert_eq!(Vector3D::from(m), v);
+
+    let uv = Vector2D::new(0.25, 0.75);
+    let m: mint::Vector2<f32> = uv.into();
+    assert_eq!(Vector2D::from(m), uv);
+
+    let color = Color4D::new(0.1, 0.2, 0.3, 1.0);
+    let m: mint::Vector4<f32> = color.into();
+    assert_eq!((m.x, m.y, m.z, m.w), (0.1, 0.2, 0.3, 1.0));
+    assert_eq!(Vector4D::from(m), color);
+}
+
+#[test]
+fn test_matrix_conversions_are_column_major() {
+    let translation = Matrix4x4::from_cols(
+        Vector4D::new(1.0, 0.0, 0.0, 0.0),
+        Vector4D::new(0.0, 1.0, 0.0, 0.0),
+        Vector4D::new(0.0, 0.0, 1.0, 0.0),
+        Vector4D::new(4.0, 5.0, 6.0, 1.0),
+    );
+    let m: mint::ColumnMatrix4<f32> = translation.into();
+    assert_eq!((m.w.x, m.w.y, m.w.z, m.w.w), (4.0, 5.0, 6.0, 1.0));
+    assert_eq!(m.x.x, 1.0);
+    assert_eq!(m.x.w, 0.0);
+    assert_eq!(Matrix4x4::from(m), translation);
+
+    let rotation = Matrix3x3::from_cols(
+        Vector3D::new(0.0, 1.0, 0.0),
+        Vector3D::new(-1.0, 0.0, 0.0),
+        Vector3D::new(0.0, 0.0, 1.0),
+    );
+    let m: mint::ColumnMatrix3<f32> = rotation.into();
+    assert_eq!((m.x.x, m.x.y, m.x.z), (0.0, 1.0, 0.0));
+    assert_eq!((m.y.x, m.y.y, m.y.z), (-1.0, 0.0, 0.0));
+    assert_eq!(Matrix3x3::from(m), rotation);
+}
+
+#[test]
+fn test_quaternion_conversion() {
+    let q = Quaternion::from_xyzw(0.0, 0.0, 0.70710677, 0.70710677);
+    let m: mint::Quaternion<f32> = q.into();
+    assert_eq!(m.s, 0.70710677);
+    assert_eq!((m.v.x, m.v.y, m.v.z), (0.0, 0.0, 0.70710677));
+    assert_eq!(Quaternion::from(m), q);
+}
@@ -0,0 +1,159 @@
+//! Morph target delta, blending and weight sampling tests
+
+use asset_importer::{Scene, Vector3D, test_utils};
+
+use crate::common;
+
+// One triangle with two morph targets, laid out like the Khronos AnimatedMorphCube:
+//   base      (0,0,0) (1,0,0) (0,1,0), normals +Z
+//   target 0  moves every vertex by +Z and tilts the normals towards +Y
+//   target 1  moves the third vertex by +X
+// and a LINEAR weights animation with keys [0,0] -> [1,0] -> [0,1] at t = 0, 1, 2 s.
+fn morph_gltf() -> String {
+    let normals = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+    let target0_positions = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+    let target0_normals = [0.0, 1.0, -1.0, 0.0, 1.0, -1.0, 0.0, 1.0, -1.0];
+    let target1_positions = [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+    let times = [0.0, 1.0, 2.0];
+    let weights = [0.0, 0.0, 1.0, 0.0, 0.0, 1.0];
+    let buffer = [
+        common::triangle_bytes(),
+        test_utils::f32_bytes(&normals),
+        test_utils::f32_bytes(&target0_positions),
+        test_utils::f32_bytes(&target0_normals),
+        test_utils::f32_bytes(&target1_positions),
+        test_utils::f32_bytes(&times),
+        test_utils::f32_bytes(&weights),
+    ]
+    .concat();
+    common::gltf(
+        &buffer,
+        r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 180 },
+    { "buffer": 0, "byteOffset": 180, "byteLength": 36 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "byteOffset": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 0, "byteOffset": 36, "componentType": 5126, "count": 3, "type": "VEC3" },
+    { "bufferView": 0, "byteOffset": 72, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 1], "max": [0, 0, 1] },
+    { "bufferView": 0, "byteOffset": 108, "componentType": 5126, "count": 3, "type": "VEC3" },
+    { "bufferView": 0, "byteOffset": 144, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 0, 0] },
+    { "bufferView": 1, "byteOffset": 0, "componentType": 5126, "count": 3, "type": "SCALAR", "min": [0], "max": [2] },
+    { "bufferView": 1, "byteOffset": 12, "componentType": 5126, "count": 6, "type": "SCALAR" }
+  ],
+  "meshes": [{
+    "name": "MorphTriangle",
+    "primitives": [{
+      "attributes": { "POSITION": 0, "NORMAL": 1 },
+      "targets": [{ "POSITION": 2, "NORMAL": 3 }, { "POSITION": 4 }]
+    }],
+    "weights": [0.25, 0]
+  }],
+  "nodes": [{ "name": "MorphNode", "mesh": 0 }],
+  "animations": [{
+    "channels": [{ "sampler": 0, "target": { "node": 0, "path": "weights" } }],
+    "samplers": [{ "input": 5, "output": 6, "interpolation": "LINEAR" }]
+  }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#,
+    )
+}
+
+fn import() -> Scene {
+    Scene::from_memory(morph_gltf().as_bytes(), Some("gltf")).expect("import morph glTF")
+}
+
+fn assert_vec_close(actual: Vector3D, expected: Vector3D) {
+    assert!(
+        (actual - expected).length() < 1e-5,
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_position_and_normal_deltas() {
+    let scene = import();
+    let mesh = scene.mesh(0).expect("mesh");
+    assert_eq!(mesh.num_anim_meshes(), 2);
+
+    let target0 = mesh.anim_mesh(0).expect("target 0");
+    let deltas = target0.position_deltas(&mesh);
+    assert_eq!(deltas.len(), mesh.num_vertices());
+    for delta in &deltas {
+        assert_vec_close(*delta, Vector3D::new(0.0, 0.0, 1.0));
+    }
+    for delta in target0.normal_deltas(&mesh) {
+        assert_vec_close(delta, Vector3D::new(0.0, 1.0, -1.0));
+    }
+    assert!((target0.weight() - 0.25).abs() < 1e-6);
+
+    let target1 = mesh.anim_mesh(1).expect("target 1");
+    let deltas = target1.position_deltas(&mesh);
+    assert_vec_close(deltas[0], Vector3D::ZERO);
+    assert_vec_close(deltas[1], Vector3D::ZERO);
+    assert_vec_close(deltas[2], Vector3D::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_evaluate_morphs() {
+    let scene = import();
+    let mesh = scene.mesh(0).expect("mesh");
+
+    // No weights: the base mesh.
+    assert_eq!(mesh.evaluate_morphs(&[]), mesh.vertices());
+
+    let positions = mesh.evaluate_morphs(&[(0, 0.5), (1, 1.0)]);
+    assert_vec_close(positions[0], Vector3D::new(0.0, 0.0, 0.5));
+    assert_vec_close(positions[1], Vector3D::new(1.0, 0.0, 0.5));
+    assert_vec_close(positions[2], Vector3D::new(1.0, 1.0, 0.5));
+
+    // Unknown targets are skipped instead of panicking.
+    let positions = mesh.evaluate_morphs(&[(7, 1.0), (0, 1.0)]);
+    assert_vec_close(positions[0], Vector3D::new(0.0, 0.0, 1.0));
+
+    let normals = mesh
+        .evaluate_morph_normals(&[(0, 1.0)])
+        .expect("mesh has normals");
+    assert_vec_close(normals[0], Vector3D::new(0.0, 1.0, 0.0));
+    let normals = mesh.evaluate_morph_normals(&[(0, 0.5)]).unwrap();
+    let half = std::f32::consts::FRAC_1_SQRT_2;
+    assert_vec_close(normals[1], Vector3D::new(0.0, half, half));
+}
+
+#[test]
+fn test_sample_morph_weights() {
+    let scene = import();
+    let animation = scene.animation(0).expect("animation");
+    let channel = animation
+        .morph_mesh_channels()
+        .next()
+        .expect("morph channel");
+    assert_eq!(channel.num_keys(), 3);
+
+    let times: Vec<f64> = (0..3).map(|i| channel.key(i).unwrap().time()).collect();
+    let weight = |ticks: f64, target: u32| {
+        channel
+            .sample(ticks)
+            .into_iter()
+            .find(|&(t, _)| t == target)
+            .map_or(0.0, |(_, w)| w)
+    };
+
+    // On the keys themselves.
+    assert!((weight(times[1], 0) - 1.0).abs() < 1e-9);
+    assert!(weight(times[1], 1).abs() < 1e-9);
+
+    // Halfway between the first two keys, and between the last two.
+    let mid = (times[0] + times[1]) / 2.0;
+    assert!((weight(mid, 0) - 0.5).abs() < 1e-6);
+    assert!(weight(mid, 1).abs() < 1e-6);
+    let mid = (times[1] + times[2]) / 2.0;
+    assert!((weight(mid, 0) - 0.5).abs() < 1e-6);
+    assert!((weight(mid, 1) - 0.5).abs() < 1e-6);
+
+    // Clamped outside the key range.
+    assert!(weight(times[0] - 100.0, 0).abs() < 1e-9);
+    assert!((weight(times[2] + 100.0, 1) - 1.0).abs() < 1e-9);
+}
@@ -0,0 +1,80 @@
+//! Primitive type filtering tests
+
+use asset_importer::{Importer, Scene, mesh::PrimitiveTypes, postprocess::PostProcessSteps};
+
+// One triangle plus two single-index (point) faces.
+const MIXED_PLY: &str = "ply
+format ascii 1.0
+element vertex 5
+property float x
+property float y
+property float z
+element face 3
+property list uchar int vertex_indices
+end_header
+0 0 0
+1 0 0
+0 1 0
+5 5 5
+6 6 6
+3 0 1 2
+1 3
+1 4
+";
+
+fn import_mixed(remove: Option<PrimitiveTypes>) -> Scene {
+    let builder = Importer::new()
+        .read_from_memory(MIXED_PLY.as_bytes())
+        .with_memory_hint("ply");
+    let builder = match remove {
+        Some(types) => builder.remove_primitives(types),
+        None => builder.with_post_process(PostProcessSteps::SORT_BY_PTYPE),
+    };
+    builder.import().expect("import PLY")
+}
+
+#[test]
+fn test_sort_by_ptype_splits_points_and_triangles() {
+    let scene = import_mixed(None);
+    assert_eq!(scene.num_meshes(), 2);
+    for mesh in scene.meshes() {
+        let types = mesh.primitive_types_typed();
+        assert!(types == PrimitiveTypes::POINT || types == PrimitiveTypes::TRIANGLE);
+    }
+
+    let points: Vec<_> = scene
+        .meshes_with_primitives(PrimitiveTypes::POINT)
+        .collect();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].num_faces(), 2);
+    assert_eq!(
+        scene
+            .meshes_with_primitives(PrimitiveTypes::TRIANGLE | PrimitiveTypes::POLYGON)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_remove_primitives_drops_points() {
+    let scene = import_mixed(Some(PrimitiveTypes::POINT | PrimitiveTypes::LINE));
+    assert!(scene.num_meshes() > 0);
+    assert!(scene.meshes().all(|mesh| !mesh.has_points()));
+    assert_eq!(
+        scene.meshes_with_primitives(PrimitiveTypes::POINT).count(),
+        0
+    );
+}
+
+#[test]
+fn test_remove_primitives_survives_post_process_override() {
+    let scene = Importer::new()
+        .read_from_memory(MIXED_PLY.as_bytes())
+        .with_memory_hint("ply")
+        .remove_primitives(PrimitiveTypes::POINT)
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import PLY");
+    assert!(scene.meshes().all(|mesh| !mesh.has_points()));
+    assert!(scene.meshes().any(|mesh| mesh.has_triangles()));
+}
@@ -0,0 +1,255 @@
+//! Skinning validation and weight normalization tests
+
+use asset_importer::{
+    Scene, mesh::Mesh, postprocess::PostProcessSteps, test_utils, types::Vector3D,
+};
+
+use crate::common;
+
+const SKINNED_DAE: &str = "tests/models/skinned_six_influences.dae";
+
+fn skinned_mesh(flags: PostProcessSteps) -> Mesh {
+    let scene = Scene::from_file_with_flags(SKINNED_DAE, flags).expect("import skinned .dae");
+    scene
+        .meshes()
+        .find(|m| m.has_bones())
+        .expect("skinned mesh")
+}
+
+#[test]
+fn test_validate_skinning_reports_problems() {
+    let mesh = skinned_mesh(PostProcessSteps::empty());
+    let report = mesh.validate_skinning();
+
+    assert_eq!(report.num_vertices, 3);
+    assert_eq!(report.num_bones, 6);
+    assert_eq!(report.max_influences, 6);
+    assert_eq!(report.unweighted_vertices, 1);
+    assert_eq!(report.unnormalized_vertices, 1);
+    assert!(!report.is_normalized());
+    assert_eq!(
+        report.to_string(),
+        "3 vertices, 6 bones: 1 unweighted, 1 unnormalized, max 6 influences"
+    );
+}
+
+#[test]
+fn test_normalized_vertex_weights_clamps_and_breaks_ties() {
+    let mesh = skinned_mesh(PostProcessSteps::empty());
+    let bone_names = mesh.bone_names();
+    let weights = mesh.normalized_vertex_weights(4);
+    assert_eq!(weights.len(), 3);
+
+    // J3 and J4 tie at 0.1; the lower bone index wins.
+    let names: Vec<&str> = weights[0]
+        .iter()
+        .map(|i| bone_names[i.bone_index as usize].as_str())
+        .collect();
+    assert_eq!(names, ["J0", "J1", "J2", "J3"]);
+    let expected = [0.3, 0.25, 0.2, 0.1].map(|w: f32| w / 0.85);
+    for (influence, expected) in weights[0].iter().zip(expected) {
+        assert!((influence.weight - expected).abs() < 1e-5);
+    }
+
+    assert_eq!(weights[1].len(), 1);
+    assert!((weights[1][0].weight - 1.0).abs() < 1e-6);
+    assert!(weights[2].is_empty());
+    assert_eq!(mesh.normalized_vertex_weights(4), weights);
+}
+
+#[test]
+fn test_limit_bone_weights_only_renormalizes_truncated_vertices() {
+    let mesh = skinned_mesh(PostProcessSteps::LIMIT_BONE_WEIGHTS);
+    let report = mesh.validate_skinning();
+
+    assert_eq!(report.max_influences, 4);
+    assert_eq!(report.unweighted_vertices, 1);
+    // Vertex 1 had a single 0.5 weight, so Assimp left it alone.
+    assert_eq!(report.unnormalized_vertices, 1);
+}
+
+// One triangle skinned to a two-joint chain: Armature -> Root -> Tip.
+const RIGGED_GLTF: &str = r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 12 },
+    { "buffer": 0, "byteOffset": 48, "byteLength": 48 },
+    { "buffer": 0, "byteOffset": 96, "byteLength": 128 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5121, "count": 3, "type": "VEC4" },
+    { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC4" },
+    { "bufferView": 3, "componentType": 5126, "count": 2, "type": "MAT4" }
+  ],
+  "meshes": [{ "name": "Skin", "primitives": [{ "attributes": { "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2 } }] }],
+  "skins": [{ "joints": [2, 3], "inverseBindMatrices": 3, "skeleton": 2 }],
+  "nodes": [
+    { "name": "Armature", "children": [1, 2] },
+    { "name": "Body", "mesh": 0, "skin": 0 },
+    { "name": "Root", "children": [3] },
+    { "name": "Tip", "translation": [0, 1, 0] }
+  ],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+/// [`RIGGED_GLTF`] with its joints, weights and inverse bind matrices: vertex 0 is split
+/// evenly, vertex 1 leans towards `Root` and vertex 2 follows `Tip` alone.
+fn rigged_gltf() -> String {
+    let joints: [u8; 12] = [0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0];
+    let weights =
+        test_utils::f32_bytes(&[0.5, 0.5, 0.0, 0.0, 0.75, 0.25, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    let root_inverse_bind: [f32; 16] = [
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ];
+    let tip_inverse_bind: [f32; 16] = [
+        1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 1.0,
+    ];
+    let inverse_binds = test_utils::f32_bytes(&[root_inverse_bind, tip_inverse_bind].concat());
+    let buffer = [
+        common::triangle_bytes(),
+        joints.to_vec(),
+        weights,
+        inverse_binds,
+    ]
+    .concat();
+    common::gltf(&buffer, RIGGED_GLTF)
+}
+
+#[test]
+fn test_armature_population_resolves_bone_nodes() {
+    let scene = asset_importer::Importer::new()
+        .read_from_memory(rigged_gltf().as_bytes())
+        .with_memory_hint("gltf")
+        .with_armature_population(true)
+        .import()
+        .expect("import rigged glTF");
+    let mesh = scene
+        .meshes()
+        .find(|m| m.has_bones())
+        .expect("skinned mesh");
+
+    let mut resolved = 0;
+    for bone in mesh.bones() {
+        let (Some(node), Some(armature)) = (bone.node(), bone.armature_node()) else {
+            continue;
+        };
+        assert_eq!(node.name(), bone.name());
+        assert!(
+            node.path().starts_with(&armature.path()),
+            "{} is not inside its armature {}",
+            node.name(),
+            armature.name()
+        );
+        resolved += 1;
+    }
+    assert!(resolved >= 1, "no bone resolved its node and armature");
+}
+
+#[test]
+fn test_bone_nodes_absent_without_armature_population() {
+    let scene = asset_importer::Importer::new()
+        .read_from_memory(rigged_gltf().as_bytes())
+        .with_memory_hint("gltf")
+        .import()
+        .expect("import rigged glTF");
+    let mesh = scene
+        .meshes()
+        .find(|m| m.has_bones())
+        .expect("skinned mesh");
+    assert!(mesh.bones().all(|b| b.node().is_none()));
+}
+
+#[test]
+fn test_bone_offset_matrix_and_aabb() {
+    let scene =
+        Scene::from_memory(rigged_gltf().as_bytes(), Some("gltf")).expect("import rigged glTF");
+    let mesh = scene
+        .meshes()
+        .find(|m| m.has_bones())
+        .expect("skinned mesh");
+    let bone = |name: &str| mesh.bones().find(|b| b.name() == name).expect(name);
+    let (root, tip) = (bone("Root"), bone("Tip"));
+    let mesh_aabb = mesh.compute_aabb().expect("mesh has vertices");
+
+    // The root influences every vertex, so its bind-pose box is the mesh's.
+    let root_aabb = root.compute_aabb(&mesh, 0.01).expect("root bounds");
+    assert!(root_aabb.contains_point(mesh_aabb.center()));
+    assert_eq!(root_aabb, mesh_aabb);
+
+    // The tip weighs 0.5 on vertex 0 and 0.25 on vertex 1.
+    let tip_aabb = tip.compute_aabb(&mesh, 0.3).expect("tip bounds");
+    assert_eq!(tip_aabb.min, tip_aabb.max);
+    assert_eq!(tip_aabb.min, Vector3D::new(0.0, 0.0, 0.0));
+    assert!(tip.compute_aabb(&mesh, 0.6).is_none());
+    assert_eq!(tip.weights_raw().len(), 2);
+
+    // The tip sits at y = 1, so its inverse bind matrix moves that point to the origin.
+    let moved = tip
+        .offset_matrix()
+        .transform_point3(Vector3D::new(0.0, 1.0, 0.0));
+    assert!(moved.length() < 1e-6, "{moved:?}");
+}
+
+#[test]
+fn test_skinning_statistics_checks_limits() {
+    use asset_importer::scene::skinning::{SkinningLimits, SkinningViolationKind};
+
+    let budget = SkinningLimits::default()
+        .with_max_influences(4)
+        .with_max_bones_per_mesh(256)
+        .with_normalized_weights(true);
+
+    let rigged =
+        Scene::from_memory(rigged_gltf().as_bytes(), Some("gltf")).expect("import rigged glTF");
+    let stats = rigged.skinning_statistics(&budget);
+    assert!(stats.passed(), "{stats}");
+    assert_eq!(stats.meshes.len(), 1);
+    assert_eq!((stats.max_bones_per_mesh, stats.unique_bones), (2, 2));
+    assert_eq!(stats.max_influences, 2);
+    assert!(stats.to_string().starts_with("Skinning: PASS"));
+
+    // Tight limits: one influence per vertex.
+    let stats = rigged.skinning_statistics(&SkinningLimits::default().with_max_influences(1));
+    assert!(!stats.passed());
+    assert_eq!(stats.vertices_over_limit, 2);
+    let violation = stats.violations_for("Skin").next().expect("Skin violates");
+    assert_eq!(
+        violation.kind,
+        SkinningViolationKind::TooManyInfluences {
+            vertices: 2,
+            max_influences: 2,
+            limit: 1,
+        }
+    );
+
+    let scene = Scene::from_file(SKINNED_DAE).expect("import skinned .dae");
+    let stats = scene.skinning_statistics(&budget.with_max_bones_per_mesh(4));
+    let name = skinned_mesh(PostProcessSteps::empty()).name();
+    let kinds: Vec<_> = stats.violations_for(&name).map(|v| v.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            SkinningViolationKind::TooManyBones { bones: 6, limit: 4 },
+            SkinningViolationKind::TooManyInfluences {
+                vertices: 1,
+                max_influences: 6,
+                limit: 4,
+            },
+            SkinningViolationKind::UnnormalizedWeights {
+                unweighted: 1,
+                unnormalized: 1,
+            },
+        ]
+    );
+    let summary = stats.to_string();
+    assert!(
+        summary.starts_with("Skinning: FAIL (3 violations)"),
+        "{summary}"
+    );
+    assert!(
+        summary.contains(&format!("'{name}': 6 bones (limit 4)")),
+        "{summary}"
+    );
+}
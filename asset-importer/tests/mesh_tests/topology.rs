@@ -0,0 +1,67 @@
+//! Mesh edge and adjacency tests
+
+use asset_importer::{Importer, Scene, mesh::topology::EdgeList, postprocess::PostProcessSteps};
+
+fn import_box(steps: PostProcessSteps) -> Scene {
+    Importer::new()
+        .read_file("tests/models/box.obj")
+        .with_post_process(steps)
+        .import()
+        .expect("import box.obj")
+}
+
+// Two quads side by side: 2x1 grid of 6 vertices.
+const PLANE_OBJ: &str =
+    "v 0 0 0\nv 1 0 0\nv 2 0 0\nv 0 1 0\nv 1 1 0\nv 2 1 0\nf 1 2 5 4\nf 2 3 6 5\n";
+
+#[test]
+fn test_cube_quads_have_twelve_closed_edges() {
+    let scene = import_box(PostProcessSteps::JOIN_IDENTICAL_VERTICES);
+    let mesh = scene.meshes().next().expect("mesh");
+    assert_eq!(mesh.num_vertices(), 8);
+
+    let edges = EdgeList::build(&mesh);
+    assert_eq!(edges.len(), 12);
+    assert!(edges.iter().all(|e| e.faces.len() == 2));
+    assert!(mesh.is_closed());
+
+    // Each cube corner touches three quads.
+    let adjacency = mesh.adjacency();
+    assert_eq!(adjacency.num_vertices(), 8);
+    for vertex in 0..8 {
+        assert_eq!(adjacency.faces(vertex).len(), 3);
+    }
+}
+
+#[test]
+fn test_triangulated_cube_adds_diagonals() {
+    let scene =
+        import_box(PostProcessSteps::JOIN_IDENTICAL_VERTICES | PostProcessSteps::TRIANGULATE);
+    let mesh = scene.meshes().next().expect("mesh");
+    let edges = EdgeList::build(&mesh);
+    // 12 cube edges plus one diagonal per side.
+    assert_eq!(edges.len(), 18);
+    assert_eq!(edges.boundary_edges().count(), 0);
+    assert!(mesh.is_closed());
+}
+
+#[test]
+fn test_open_plane_boundary() {
+    let scene = Scene::from_memory_with_flags(
+        PLANE_OBJ.as_bytes(),
+        Some("obj"),
+        PostProcessSteps::JOIN_IDENTICAL_VERTICES,
+    )
+    .expect("import plane");
+    let mesh = scene.meshes().next().expect("mesh");
+    assert_eq!(mesh.num_vertices(), 6);
+
+    let edges = EdgeList::build(&mesh);
+    assert_eq!(edges.len(), 7);
+    assert_eq!(edges.boundary_edges().count(), 6);
+    let shared: Vec<_> = edges.iter().filter(|e| e.faces.len() == 2).collect();
+    assert_eq!(shared.len(), 1);
+    assert_eq!(shared[0].faces, &[0, 1]);
+    assert!(!mesh.is_closed());
+    assert_eq!(edges.non_manifold_edges().count(), 0);
+}
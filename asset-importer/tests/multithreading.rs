@@ -271,3 +271,25 @@ fn test_send_sync_compilation() {
 
     println!("✅ Compilation test passed: All types implement Send + Sync");
 }
+
+// Test 5: A deep copy outlives the original scene and can move to another thread
+#[test]
+fn test_deep_copy_outlives_original() {
+    let original = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj");
+    let expected = original.mesh(0).expect("mesh 0").vertices();
+
+    let copy = original.deep_copy().expect("deep copy");
+    drop(original);
+
+    // A copy of a copy goes through the FreeScene path on both ends.
+    let second = copy.deep_copy().expect("deep copy of copy");
+    drop(copy);
+
+    let vertices = thread::spawn(move || second.mesh(0).expect("mesh 0").vertices())
+        .join()
+        .expect("worker thread");
+    assert_eq!(vertices, expected);
+}
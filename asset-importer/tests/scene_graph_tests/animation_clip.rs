@@ -0,0 +1,81 @@
+//! Listing animation clips and selecting them by cleaned name
+
+use asset_importer::Scene;
+
+// Buffer: times [0, 1] s and translations (0,0,0) (1,0,0), then times [0, 1, 2] s and
+// translations (0,0,0) (0,1,0) (0,2,0). The clip names are what the FBX importer and a
+// Blender export produce for a take and an armature action.
+const TAKES_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "buffers": [{ "uri": "data:application/octet-stream;base64,AAAAAAAAgD8AAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAAAAAACAPwAAAEAAAAAAAAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAQAAAAAA=", "byteLength": 80 }],
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 8 },
+    { "buffer": 0, "byteOffset": 8, "byteLength": 24 },
+    { "buffer": 0, "byteOffset": 32, "byteLength": 12 },
+    { "buffer": 0, "byteOffset": 44, "byteLength": 36 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1] },
+    { "bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3" },
+    { "bufferView": 2, "componentType": 5126, "count": 3, "type": "SCALAR", "min": [0], "max": [2] },
+    { "bufferView": 3, "componentType": 5126, "count": 3, "type": "VEC3" }
+  ],
+  "nodes": [{ "name": "Root", "children": [1] }, { "name": "Hips" }],
+  "animations": [
+    {
+      "name": "AnimStack::Take 001",
+      "samplers": [{ "input": 0, "output": 1 }],
+      "channels": [{ "sampler": 0, "target": { "node": 0, "path": "translation" } }]
+    },
+    {
+      "name": "Armature|Walk",
+      "samplers": [{ "input": 2, "output": 3 }, { "input": 0, "output": 1 }],
+      "channels": [
+        { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+        { "sampler": 1, "target": { "node": 1, "path": "translation" } }
+      ]
+    }
+  ],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_animation_clips_clean_names() {
+    let scene = Scene::from_memory(TAKES_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let clips = scene.animation_clips();
+    assert_eq!(clips.len(), 2);
+
+    assert_eq!(clips[0].index, 0);
+    assert_eq!(clips[0].raw_name, "AnimStack::Take 001");
+    assert_eq!(clips[0].clean_name, "Take 001");
+    assert_eq!(clips[0].channel_count, 1);
+    assert!((clips[0].duration_seconds - 1.0).abs() < 1e-6);
+
+    assert_eq!(clips[1].index, 1);
+    assert_eq!(clips[1].raw_name, "Armature|Walk");
+    assert_eq!(clips[1].clean_name, "Walk");
+    assert_eq!(clips[1].channel_count, 2);
+    assert!((clips[1].duration_seconds - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_animation_by_clean_name() {
+    let scene = Scene::from_memory(TAKES_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+
+    for query in [
+        "walk",
+        " Walk ",
+        "WALK",
+        "Armature|Walk",
+        "AnimStack::Armature|Walk",
+    ] {
+        let animation = scene.animation_by_clean_name(query).expect(query);
+        assert_eq!(animation.name(), "Armature|Walk", "{query:?}");
+    }
+    let take = scene.animation_by_clean_name("take 001").expect("take");
+    assert_eq!(take.name(), "AnimStack::Take 001");
+
+    assert!(scene.animation_by_clean_name("Run").is_none());
+    assert!(scene.animation_by_clean_name("Armature").is_none());
+}
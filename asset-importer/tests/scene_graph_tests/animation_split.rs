@@ -0,0 +1,103 @@
+//! Animation time ranges, sampling and clip extraction
+
+use asset_importer::{Scene, animation::split::extract_clip};
+
+// Buffer: translation times [0, 1, 2, 3] s (16 bytes) and values (0,0,0) (1,0,0) (1,2,0)
+// (4,2,0) (48 bytes), then rotation times [0, 1.5, 3] s (12 bytes) and rotations of 0, 90
+// and 180 degrees about +Y (48 bytes).
+const TIMELINE_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "buffers": [{ "uri": "data:application/octet-stream;base64,AAAAAAAAgD8AAABAAABAQAAAAAAAAAAAAAAAAAAAgD8AAAAAAAAAAAAAgD8AAABAAAAAAAAAgEAAAABAAAAAAAAAAAAAAMA/AABAQAAAAAAAAAAAAAAAAAAAgD8AAAAA8wQ1PwAAAADzBDU/AAAAAAAAgD8AAAAAMjGNJA==", "byteLength": 124 }],
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 16 },
+    { "buffer": 0, "byteOffset": 16, "byteLength": 48 },
+    { "buffer": 0, "byteOffset": 64, "byteLength": 12 },
+    { "buffer": 0, "byteOffset": 76, "byteLength": 48 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 4, "type": "SCALAR", "min": [0], "max": [3] },
+    { "bufferView": 1, "componentType": 5126, "count": 4, "type": "VEC3" },
+    { "bufferView": 2, "componentType": 5126, "count": 3, "type": "SCALAR", "min": [0], "max": [3] },
+    { "bufferView": 3, "componentType": 5126, "count": 3, "type": "VEC4" }
+  ],
+  "nodes": [{ "name": "Cube" }],
+  "animations": [{
+    "name": "Timeline",
+    "samplers": [{ "input": 0, "output": 1 }, { "input": 2, "output": 3 }],
+    "channels": [
+      { "sampler": 0, "target": { "node": 0, "path": "translation" } },
+      { "sampler": 1, "target": { "node": 0, "path": "rotation" } }
+    ]
+  }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_channel_time_range() {
+    let scene = Scene::from_memory(TIMELINE_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let animation = scene.animation(0).expect("animation");
+    let tps = animation.ticks_per_second();
+
+    assert_eq!(animation.channel_time_range(), (0.0, 3.0 * tps));
+    assert!(!animation.is_empty());
+
+    let channel = animation.channel(0).expect("channel");
+    let halfway = channel.sample_position(1.5 * tps).expect("position");
+    assert!((halfway.x - 1.0).abs() < 1e-5 && (halfway.y - 1.0).abs() < 1e-5);
+    // Held at the last key past the end.
+    let after = channel.sample_position(10.0 * tps).expect("position");
+    assert!((after.x - 4.0).abs() < 1e-5, "{after:?}");
+}
+
+#[test]
+fn test_extract_clip_matches_source() {
+    let scene = Scene::from_memory(TIMELINE_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let animation = scene.animation(0).expect("animation");
+    let tps = animation.ticks_per_second();
+    let source = animation.channel(0).expect("channel");
+    let (start, end) = (0.5 * tps, 2.0 * tps);
+
+    let clip = extract_clip(&animation, start, end);
+    assert_eq!(clip.duration, end - start);
+    assert_eq!(clip.duration_in_seconds(), 1.5);
+    let channel = clip.channel("Cube").expect("Cube channel");
+
+    // A key is synthesized at the start cut; the key at 2 s already sits on the end cut.
+    let position_times: Vec<f64> = channel.position_keys.iter().map(|k| k.time).collect();
+    assert_eq!(position_times, [0.0, 0.5 * tps, 1.5 * tps]);
+    // Rotation keys at 0 and 3 s are outside; 1.5 s is kept between two sampled keys.
+    let rotation_times: Vec<f64> = channel.rotation_keys.iter().map(|k| k.time).collect();
+    assert_eq!(rotation_times, [0.0, 1.0 * tps, 1.5 * tps]);
+
+    for step in 0..=15 {
+        let t = clip.duration * step as f64 / 15.0;
+        let expected = source.sample_position(start + t).unwrap();
+        let actual = channel.sample_position(t).unwrap();
+        assert!(
+            actual.distance_squared(expected) < 1e-10,
+            "{t}: {actual:?} vs {expected:?}"
+        );
+
+        let expected = source.sample_rotation(start + t).unwrap();
+        let actual = channel.sample_rotation(t).unwrap();
+        assert!(
+            actual.dot(expected).abs() > 1.0 - 1e-5,
+            "{t}: {actual:?} vs {expected:?}"
+        );
+    }
+}
+
+#[test]
+fn test_extract_zero_length_clip() {
+    let scene = Scene::from_memory(TIMELINE_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let animation = scene.animation(0).expect("animation");
+    let tps = animation.ticks_per_second();
+
+    let clip = extract_clip(&animation, 1.0 * tps, 0.0);
+    assert_eq!(clip.duration, 0.0);
+    let channel = clip.channel("Cube").expect("Cube channel");
+    assert_eq!(channel.position_keys.len(), 1);
+    assert_eq!(channel.position_keys[0].time, 0.0);
+    assert_eq!(channel.rotation_keys.len(), 1);
+}
@@ -0,0 +1,83 @@
+//! Camera matrix tests
+
+use asset_importer::{Scene, types::Matrix4x4};
+use glam::{Mat4, Vec3};
+
+const CAMERAS_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "cameras": [
+    { "type": "perspective", "perspective": { "yfov": 0.8, "aspectRatio": 1.5, "znear": 0.1, "zfar": 100.0 } },
+    { "type": "orthographic", "orthographic": { "xmag": 4.0, "ymag": 2.0, "znear": 0.5, "zfar": 50.0 } }
+  ],
+  "nodes": [
+    { "name": "PerspectiveCam", "camera": 0, "translation": [1.0, 2.0, 3.0] },
+    { "name": "OrthoCam", "camera": 1 }
+  ],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#;
+
+fn to_vec3(v: asset_importer::Vector3D) -> Vec3 {
+    Vec3::new(v.x, v.y, v.z)
+}
+
+fn assert_matrix_close(actual: Matrix4x4, expected: Mat4) {
+    let actual = actual.to_cols_array_2d();
+    let expected = expected.to_cols_array_2d();
+    for (a, e) in actual.iter().flatten().zip(expected.iter().flatten()) {
+        assert!(
+            (a - e).abs() < 1e-4,
+            "matrix mismatch:\n{actual:?}\n{expected:?}"
+        );
+    }
+}
+
+#[test]
+fn test_perspective_camera_matrices() {
+    let scene = Scene::from_memory(CAMERAS_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let camera = scene
+        .cameras()
+        .find(|c| c.name() == "PerspectiveCam")
+        .expect("perspective camera");
+
+    assert!(!camera.is_orthographic());
+    assert!((camera.vertical_fov(1.5) - 0.8).abs() < 1e-4);
+
+    let view = Mat4::look_to_lh(
+        to_vec3(camera.position()),
+        to_vec3(camera.look_at()),
+        to_vec3(camera.up()),
+    );
+    assert_matrix_close(camera.view_matrix(), view);
+
+    assert_matrix_close(
+        camera.projection_matrix(None),
+        Mat4::perspective_lh(0.8, 1.5, 0.1, 100.0),
+    );
+    let fov_y = camera.vertical_fov(2.0);
+    assert_matrix_close(
+        camera.projection_matrix(Some(2.0)),
+        Mat4::perspective_lh(fov_y, 2.0, 0.1, 100.0),
+    );
+
+    let node = scene.camera_node(&camera).expect("camera node");
+    assert_eq!(node.name(), "PerspectiveCam");
+    let t = node.transformation();
+    assert_eq!((t.w_axis.x, t.w_axis.y, t.w_axis.z), (1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_orthographic_camera_projection() {
+    let scene = Scene::from_memory(CAMERAS_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let camera = scene
+        .cameras()
+        .find(|c| c.name() == "OrthoCam")
+        .expect("orthographic camera");
+
+    assert!(camera.is_orthographic());
+    assert_eq!(camera.orthographic_width(), 4.0);
+    assert_matrix_close(
+        camera.projection_matrix(None),
+        Mat4::orthographic_lh(-4.0, 4.0, -2.0, 2.0, 0.5, 50.0),
+    );
+}
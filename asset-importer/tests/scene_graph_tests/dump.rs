@@ -0,0 +1,178 @@
+//! Scene dump golden tests
+//!
+//! The golden files in `tests/golden/` hold the dumps of [`built_scene`], which is
+//! assembled in Rust so its dump does not depend on how an Assimp importer fills in
+//! names, flags or material defaults. A missing or outdated golden file fails the
+//! test; run with `UPDATE_GOLDEN=1` to rewrite them after an intentional format
+//! change, and review the diff before committing.
+
+use std::path::Path;
+
+use asset_importer::{
+    Importer, Scene,
+    material::{MaterialBuilder, MaterialTexture, TextureType, material_keys},
+    scene::{
+        OwnedScene,
+        build::{MeshData, SceneBuilder, node},
+        dump::DumpFormat,
+    },
+    types::{Color4D, Matrix4x4, Vector2D, Vector3D, Vector4D},
+};
+
+fn box_scene() -> Scene {
+    Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj")
+}
+
+/// A textured quad drawn by a translated node and its child.
+fn built_scene() -> OwnedScene {
+    let mut builder = SceneBuilder::new();
+    let material = builder.add_material(
+        MaterialBuilder::new()
+            .with_name("Painted")
+            .with_color(
+                material_keys::COLOR_DIFFUSE,
+                Color4D::new(0.5, 0.25, 0.75, 1.0),
+            )
+            .with_float(material_keys::OPACITY, 0.5)
+            .with_int(material_keys::TWOSIDED, 1)
+            .with_texture(TextureType::Diffuse, 0, MaterialTexture::new("albedo.png")),
+    );
+    let quad = builder.add_mesh(MeshData {
+        name: "Quad".into(),
+        positions: vec![
+            Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+        ],
+        normals: Some(vec![Vector3D::new(0.0, 0.0, 1.0); 4]),
+        uvs: Some(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(0.0, 1.0),
+        ]),
+        indices: vec![0, 1, 2, 0, 2, 3],
+        material_index: material,
+    });
+    let translation = Matrix4x4::from_cols(
+        Vector4D::new(1.0, 0.0, 0.0, 0.0),
+        Vector4D::new(0.0, 1.0, 0.0, 0.0),
+        Vector4D::new(0.0, 0.0, 1.0, 0.0),
+        Vector4D::new(1.0, 2.0, 3.0, 1.0),
+    );
+    builder.add_node(
+        node("Body")
+            .with_transform(translation)
+            .with_meshes([quad])
+            .with_child(node("Tip").with_meshes([quad])),
+    );
+    builder.build().expect("build scene")
+}
+
+fn dump_string(scene: &Scene, format: DumpFormat) -> String {
+    let mut out = Vec::new();
+    scene.dump(format, &mut out).expect("dump scene");
+    String::from_utf8(out).expect("dump is UTF-8")
+}
+
+fn check_golden(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| {
+            panic!(
+                "{}: {e}; run with UPDATE_GOLDEN=1 to create it",
+                path.display()
+            )
+        })
+        .replace("\r\n", "\n");
+    assert_eq!(
+        actual,
+        expected,
+        "{} is out of date; rerun with UPDATE_GOLDEN=1 if the change is intended",
+        path.display()
+    );
+}
+
+#[test]
+fn test_text_dump_matches_golden() {
+    check_golden(
+        "built_scene.txt",
+        &dump_string(&built_scene(), DumpFormat::Text),
+    );
+}
+
+#[test]
+fn test_json_dump_matches_golden() {
+    check_golden(
+        "built_scene.json",
+        &dump_string(&built_scene(), DumpFormat::Json),
+    );
+}
+
+#[test]
+fn test_text_dump_of_imported_box() {
+    let text = dump_string(&box_scene(), DumpFormat::Text);
+
+    assert!(text.starts_with("Scene\n"));
+    for section in ["\nNodes\n", "\nMeshes\n", "\nMaterials\n", "\nAnimations\n"] {
+        assert!(text.contains(section), "missing section {section:?}");
+    }
+    assert!(
+        text.contains("vertices: 8, faces: 6 (points: 0, lines: 0, triangles: 0, polygons: 6)")
+    );
+    assert!(text.contains(
+        "bounds: min (-0.500000, -0.500000, -0.500000) max (0.500000, 0.500000, 0.500000)"
+    ));
+}
+
+#[test]
+fn test_json_dump_of_imported_box() {
+    let json = dump_string(&box_scene(), DumpFormat::Json);
+
+    assert!(json.starts_with("{\n  \"flags\": "));
+    assert!(json.ends_with("}\n"));
+    assert!(json.contains("\"vertices\": 8,"));
+    assert!(json.contains("\"polygons\": 6,"));
+    assert!(json.contains("\"min\": [-0.500000, -0.500000, -0.500000]"));
+    assert!(json.contains("\"key\": \"?mat.name\""));
+}
+
+#[test]
+fn test_dump_is_deterministic() {
+    // Two imports of the same file produce byte-identical dumps.
+    for format in [DumpFormat::Text, DumpFormat::Json] {
+        assert_eq!(
+            dump_string(&box_scene(), format),
+            dump_string(&box_scene(), format)
+        );
+    }
+}
+
+#[test]
+fn test_dump_reports_write_errors() {
+    struct Broken;
+    impl std::io::Write for Broken {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("pipe closed"))
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = box_scene()
+        .dump(DumpFormat::Text, &mut Broken)
+        .expect_err("write failure must surface");
+    assert!(err.to_string().contains("pipe closed"), "{err}");
+}
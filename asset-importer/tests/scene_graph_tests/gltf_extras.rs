@@ -0,0 +1,103 @@
+//! glTF `extras` on nodes and materials
+
+use asset_importer::{
+    Scene,
+    material::{MaterialBuilder, material_keys},
+    scene::build::{MeshData, SceneBuilder, node},
+    types::Vector3D,
+};
+
+use crate::common;
+
+// Buffer: one triangle (0,0,0) (1,0,0) (0,1,0). Its node carries extras with every JSON
+// value kind; its material has extras the importer drops.
+const EXTRAS_GLTF: &str = r#"{
+  "meshes": [{ "name": "Tri", "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] }],
+  "materials": [{
+    "name": "Tagged",
+    "pbrMetallicRoughness": { "baseColorFactor": [1, 0, 0, 1] },
+    "extras": { "surface": "metal" }
+  }],
+  "nodes": [
+    {
+      "name": "Crate",
+      "mesh": 0,
+      "extras": {
+        "lod": 2,
+        "label": "crate \"A\"",
+        "tags": ["static", "collider"],
+        "physics": { "mass": 1.5, "kinematic": false }
+      }
+    },
+    { "name": "Plain" }
+  ],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_node_extras_json() {
+    let scene = Scene::from_memory(common::triangle_gltf(EXTRAS_GLTF).as_bytes(), Some("gltf"))
+        .expect("import glTF");
+
+    let crate_node = scene
+        .root_node()
+        .unwrap()
+        .find_node("Crate")
+        .expect("Crate");
+    assert_eq!(
+        crate_node.extras_json().as_deref(),
+        Some(
+            r#"{"lod":2,"label":"crate \"A\"","tags":["static","collider"],"physics":{"mass":1.5,"kinematic":false}}"#
+        )
+    );
+
+    let plain = scene
+        .root_node()
+        .unwrap()
+        .find_node("Plain")
+        .expect("Plain");
+    assert_eq!(plain.extras_json(), None);
+}
+
+#[test]
+fn test_material_extras_json() {
+    // Assimp does not carry material extras over, so imported materials have none.
+    let scene = Scene::from_memory(common::triangle_gltf(EXTRAS_GLTF).as_bytes(), Some("gltf"))
+        .expect("import glTF");
+    let material = scene
+        .materials()
+        .find(|m| m.name() == "Tagged")
+        .expect("Tagged");
+    assert_eq!(material.extras_json(), None);
+
+    // Materials annotated with the property report it.
+    let mut builder = SceneBuilder::new();
+    let material = builder.add_material(
+        MaterialBuilder::new()
+            .with_name("annotated")
+            .with_string(material_keys::GLTF_EXTRAS, r#"{"surface":"metal"}"#),
+    );
+    let triangle = builder.add_mesh(MeshData {
+        name: "triangle".into(),
+        positions: vec![
+            Vector3D::ZERO,
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+        ],
+        normals: None,
+        uvs: None,
+        indices: vec![0, 1, 2],
+        material_index: material,
+    });
+    builder.add_node(node("triangle").with_meshes([triangle]));
+    let scene = builder.build().expect("build scene");
+
+    let material = scene.material(0).expect("material");
+    assert_eq!(
+        material.extras_json().as_deref(),
+        Some(r#"{"surface":"metal"}"#)
+    );
+    // Built scenes have no importer, so node metadata is not read as extras.
+    assert_eq!(scene.root_node().unwrap().extras_json(), None);
+}
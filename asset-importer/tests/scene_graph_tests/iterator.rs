@@ -0,0 +1,100 @@
+//! Scene iterator length, reverse and `nth` tests
+
+use asset_importer::{Importer, Scene};
+
+use crate::common;
+
+const THREE_MESH_GLTF: &str = r#"{
+  "materials": [{ "name": "red" }, { "name": "green" }, { "name": "blue" }],
+  "meshes": [
+    { "name": "first", "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] },
+    { "name": "second", "primitives": [{ "attributes": { "POSITION": 0 }, "material": 1 }] },
+    { "name": "third", "primitives": [{ "attributes": { "POSITION": 0 }, "material": 2 }] }
+  ],
+  "nodes": [
+    { "name": "root", "children": [1, 2, 3] },
+    { "name": "a", "mesh": 0 },
+    { "name": "b", "mesh": 1 },
+    { "name": "c", "mesh": 2 }
+  ],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+fn scene() -> Scene {
+    Scene::from_memory(
+        common::triangle_gltf(THREE_MESH_GLTF).as_bytes(),
+        Some("gltf"),
+    )
+    .expect("import glTF")
+}
+
+#[test]
+fn test_scene_iterators_report_exact_len() {
+    let scene = scene();
+    assert_eq!(scene.meshes().len(), scene.num_meshes());
+    assert_eq!(scene.materials().len(), scene.num_materials());
+    assert_eq!(scene.animations().len(), scene.num_animations());
+    assert_eq!(scene.cameras().len(), scene.num_cameras());
+    assert_eq!(scene.lights().len(), scene.num_lights());
+
+    let mut meshes = scene.meshes();
+    assert_eq!(meshes.size_hint(), (3, Some(3)));
+    meshes.next();
+    assert_eq!(meshes.len(), 2);
+    meshes.next_back();
+    assert_eq!(meshes.len(), 1);
+    meshes.next();
+    assert_eq!(meshes.len(), 0);
+    assert!(meshes.next().is_none());
+
+    let material = scene.material(0).unwrap();
+    assert_eq!(material.properties().len(), material.properties().count());
+}
+
+#[test]
+fn test_scene_iterators_reverse() {
+    let scene = scene();
+
+    let forward: Vec<String> = scene.meshes().map(|m| m.name()).collect();
+    let mut backward: Vec<String> = scene.meshes().rev().map(|m| m.name()).collect();
+    assert_eq!(forward, ["first", "second", "third"]);
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    let forward: Vec<String> = scene.materials().map(|m| m.name()).collect();
+    let mut backward: Vec<String> = scene.materials().rev().map(|m| m.name()).collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    let root = scene.root_node().unwrap();
+    let children: Vec<String> = root.children().rev().map(|n| n.name()).collect();
+    assert_eq!(children, ["c", "b", "a"]);
+    assert_eq!(root.children().len(), root.num_children());
+}
+
+#[test]
+fn test_scene_iterators_nth() {
+    let scene = scene();
+    assert_eq!(
+        scene.meshes().nth(2).map(|m| m.name()).as_deref(),
+        Some("third")
+    );
+    assert!(scene.meshes().nth(3).is_none());
+
+    let mut meshes = scene.meshes();
+    assert_eq!(meshes.nth(1).map(|m| m.name()).as_deref(), Some("second"));
+    assert_eq!(meshes.len(), 1);
+
+    let box_scene = Importer::new()
+        .read_file("tests/models/box.obj")
+        .import()
+        .expect("import box.obj");
+    let mesh = box_scene.mesh(0).unwrap();
+    let last = mesh.faces().nth(mesh.num_faces() - 1).expect("last face");
+    assert_eq!(
+        last.indices().len(),
+        mesh.faces().last().unwrap().indices().len()
+    );
+    assert!(mesh.faces().nth(mesh.num_faces()).is_none());
+}
@@ -0,0 +1,77 @@
+//! Light source tests
+
+use asset_importer::{
+    Scene,
+    light::{LightKind, LightType},
+};
+
+const SPOT_LIGHT_DAE: &str = r##"<?xml version="1.0" encoding="utf-8"?>
+<COLLADA xmlns="http://www.collada.org/2005/11/COLLADASchema" version="1.4.1">
+  <asset>
+    <unit name="meter" meter="1"/>
+    <up_axis>Y_UP</up_axis>
+  </asset>
+  <library_lights>
+    <light id="Spot-light" name="Spot">
+      <technique_common>
+        <spot>
+          <color>1 0.5 0.25</color>
+          <constant_attenuation>1</constant_attenuation>
+          <linear_attenuation>0</linear_attenuation>
+          <quadratic_attenuation>0.01</quadratic_attenuation>
+          <falloff_angle>40</falloff_angle>
+          <falloff_exponent>0</falloff_exponent>
+        </spot>
+      </technique_common>
+    </light>
+  </library_lights>
+  <library_visual_scenes>
+    <visual_scene id="Scene" name="Scene">
+      <node id="SpotNode" name="SpotNode" type="NODE">
+        <translate>0 5 0</translate>
+        <instance_light url="#Spot-light"/>
+      </node>
+    </visual_scene>
+  </library_visual_scenes>
+  <scene>
+    <instance_visual_scene url="#Scene"/>
+  </scene>
+</COLLADA>"##;
+
+#[test]
+fn test_collada_spot_light() {
+    let scene = Scene::from_memory(SPOT_LIGHT_DAE.as_bytes(), Some("dae")).expect("import DAE");
+    assert_eq!(scene.num_lights(), 1);
+    let light = scene.light(0).expect("light 0");
+
+    assert_eq!(light.light_type(), LightType::Spot);
+    assert!((light.angle_inner_cone() - 40f32.to_radians()).abs() < 1e-4);
+    assert!(light.angle_outer_cone() >= light.angle_inner_cone());
+    assert!(light.angle_outer_cone() <= std::f32::consts::TAU);
+
+    let diffuse = light.color_diffuse();
+    assert_eq!((diffuse.x, diffuse.y, diffuse.z), (1.0, 0.5, 0.25));
+
+    assert_eq!(light.attenuation_constant(), 1.0);
+    assert_eq!(light.attenuation_linear(), 0.0);
+    assert!((light.attenuation_quadratic() - 0.01).abs() < 1e-6);
+    assert!((light.intensity_at(10.0) - 0.5).abs() < 1e-5);
+
+    match light.kind() {
+        Some(LightKind::Spot {
+            attenuation,
+            inner_cone,
+            outer_cone,
+            ..
+        }) => {
+            assert_eq!(attenuation, light.attenuation());
+            assert_eq!(inner_cone, light.angle_inner_cone());
+            assert_eq!(outer_cone, light.angle_outer_cone());
+        }
+        other => panic!("expected a spot light, got {other:?}"),
+    }
+
+    let node = scene.light_node(&light).expect("light node");
+    assert_eq!(node.name(), light.name());
+    assert_eq!(node.transformation().w_axis.y, 5.0);
+}
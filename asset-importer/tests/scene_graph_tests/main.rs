@@ -0,0 +1,26 @@
+//! Scene graph, node, camera, light, animation and whole-scene tests
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod animation_clip;
+mod animation_split;
+mod camera;
+mod dump;
+mod gltf_extras;
+mod iterator;
+mod light;
+mod name;
+mod node_transform;
+mod postprocess_ownership;
+mod scene_build;
+mod scene_conventions;
+mod scene_convert;
+mod scene_diff;
+mod scene_extract;
+mod scene_graph;
+mod scene_name_index;
+mod scene_stats;
+mod snapshot;
+mod validation;
+mod view_ownership;
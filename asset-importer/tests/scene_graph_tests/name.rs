@@ -0,0 +1,86 @@
+//! `Name` and the `name_owned` accessors
+
+use std::collections::{BTreeSet, HashSet};
+
+use asset_importer::{NAME_INLINE_CAPACITY, Name, Scene, test_utils};
+
+use crate::common;
+
+#[test]
+fn test_name_inline_and_spilled() {
+    let short = Name::new("Hips");
+    assert_eq!(short, "Hips");
+    assert!(!short.spilled());
+
+    let exact = "x".repeat(NAME_INLINE_CAPACITY);
+    assert!(!Name::new(&exact).spilled());
+
+    let long = "y".repeat(NAME_INLINE_CAPACITY + 1);
+    let name = Name::from(long.clone());
+    assert!(name.spilled());
+    assert_eq!(name, long);
+    assert_eq!(String::from(name), long);
+
+    assert_eq!(Name::default().len(), 0);
+}
+
+#[test]
+fn test_name_lossy_utf8() {
+    let name = Name::from_utf8_lossy(b"bone\xff");
+    assert_eq!(&*name, "bone\u{fffd}");
+    assert!(!name.spilled());
+}
+
+#[test]
+fn test_name_compares_like_str() {
+    let names: Vec<Name> = ["b", "a", "c", "a"].into_iter().map(Name::from).collect();
+    let ordered: BTreeSet<&str> = names.iter().map(|n| n.as_str()).collect();
+    let sorted: BTreeSet<Name> = names.iter().cloned().collect();
+    assert!(sorted.iter().map(Name::as_str).eq(ordered));
+
+    let set: HashSet<Name> = names.into_iter().collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains("a"));
+    assert_eq!(
+        format!("{} {:?}", Name::new("a"), Name::new("a")),
+        "a \"a\""
+    );
+}
+
+const NAMED_GLTF: &str = r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 6 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR" }
+  ],
+  "materials": [{ "name": "Skin" }],
+  "meshes": [{ "name": "Body", "primitives": [{ "attributes": { "POSITION": 0 }, "indices": 1, "material": 0 }] }],
+  "nodes": [{ "name": "Character", "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+fn named_gltf() -> String {
+    let buffer = [common::triangle_bytes(), test_utils::u16_bytes(&[0, 1, 2])].concat();
+    common::gltf(&buffer, NAMED_GLTF)
+}
+
+#[test]
+fn test_name_owned_accessors_match_strings() {
+    let scene = Scene::from_memory(named_gltf().as_bytes(), Some("gltf")).expect("import glTF");
+
+    let node = scene.root_node().expect("root").find_by_name("Character");
+    let node = node.expect("Character node");
+    assert_eq!(node.name_owned(), node.name());
+
+    let mesh = scene.mesh(0).expect("mesh");
+    assert_eq!(mesh.name_owned(), mesh.name());
+    assert_eq!(mesh.name_owned(), "Body");
+
+    let material = scene.material(0).expect("material");
+    assert_eq!(material.name_owned(), material.name());
+    assert_eq!(material.name_owned(), "Skin");
+}
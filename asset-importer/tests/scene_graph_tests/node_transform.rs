@@ -0,0 +1,103 @@
+//! Node transform decomposition and FBX pivot chain tests
+
+use asset_importer::{Importer, Scene, Vector3D, config, node::Node};
+
+use crate::common;
+
+const EPSILON: f64 = 1e-4;
+
+const TRS_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "nodes": [
+    {
+      "name": "Posed",
+      "translation": [1, 2, 3],
+      "rotation": [0, 0.70710677, 0, 0.70710677],
+      "scale": [2, 3, 4]
+    }
+  ],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+const PIVOTS_FBX: &str = "tests/models/pivots.fbx";
+
+fn import_pivots(preserve: bool) -> Scene {
+    Importer::new()
+        .read_file(PIVOTS_FBX)
+        .with_config(config::FBX_PRESERVE_PIVOTS, preserve)
+        .expect("config")
+        .import()
+        .expect("import pivots.fbx")
+}
+
+fn find(scene: &Scene, name: &str) -> Node {
+    scene
+        .root_node()
+        .and_then(|root| root.find_by_name(name))
+        .unwrap_or_else(|| panic!("node {name} not found"))
+}
+
+#[test]
+fn test_decomposed_transform() {
+    let scene = Scene::from_memory(TRS_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let node = find(&scene, "Posed");
+
+    let (translation, rotation, scaling) = node.decomposed_transform();
+    assert_eq!(translation, Vector3D::new(1.0, 2.0, 3.0));
+    common::assert_close_within(scaling.x, 2.0, EPSILON);
+    common::assert_close_within(scaling.y, 3.0, EPSILON);
+    common::assert_close_within(scaling.z, 4.0, EPSILON);
+    // The quaternion sign is not unique.
+    let sign = rotation.w.signum();
+    common::assert_close_within(rotation.x * sign, 0.0, EPSILON);
+    common::assert_close_within(rotation.y * sign, 0.70710677, EPSILON);
+    common::assert_close_within(rotation.z * sign, 0.0, EPSILON);
+    common::assert_close_within(rotation.w * sign, 0.70710677, EPSILON);
+    assert!(!node.is_fbx_pivot_helper());
+    assert_eq!(
+        node.collapse_pivot_chain(),
+        ("Posed".to_string(), node.transformation())
+    );
+}
+
+#[test]
+fn test_collapse_pivot_chain_matches_unpreserved_import() {
+    let flat = import_pivots(false);
+    let expected = find(&flat, "Arm").transformation();
+    assert!(
+        flat.nodes_depth_first().all(|n| !n.is_fbx_pivot_helper()),
+        "no helpers without FBX_PRESERVE_PIVOTS"
+    );
+
+    let scene = import_pivots(true);
+    let helpers: Vec<Node> = scene
+        .nodes_depth_first()
+        .filter(Node::is_fbx_pivot_helper)
+        .collect();
+    assert!(!helpers.is_empty(), "pivots should produce helper nodes");
+    assert!(
+        helpers
+            .iter()
+            .all(|n| n.name().starts_with("Arm_$AssimpFbx$_"))
+    );
+
+    // Same result from the first helper, a helper in the middle and the real node.
+    let arm = find(&scene, "Arm");
+    for node in [&helpers[0], &helpers[helpers.len() / 2], &arm] {
+        let (name, transform) = node.collapse_pivot_chain();
+        assert_eq!(name, "Arm");
+        let (actual, wanted) = (transform.to_cols_array_2d(), expected.to_cols_array_2d());
+        for (actual, wanted) in actual.iter().flatten().zip(wanted.iter().flatten()) {
+            common::assert_close_within(*actual, *wanted, EPSILON);
+        }
+    }
+
+    // The child keeps its own transform; it is not part of the chain.
+    let hand = find(&scene, "Hand");
+    assert_eq!(hand.parent().map(|p| p.name()).as_deref(), Some("Arm"));
+    assert_eq!(
+        hand.collapse_pivot_chain(),
+        ("Hand".to_string(), hand.transformation())
+    );
+}
@@ -0,0 +1,76 @@
+//! Post-processing after import is limited to importer-owned scenes
+
+use asset_importer::{Error, Importer, Scene, SceneFlags, postprocess::PostProcessSteps};
+
+const QUAD_OBJ: &str = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1 2 3 4\n";
+
+fn assert_unsupported(result: asset_importer::Result<Scene>) {
+    match result {
+        Err(error @ Error::UnsupportedOperation { .. }) => {
+            assert_eq!(
+                error.operation(),
+                Some(asset_importer::error::Operation::PostProcess)
+            );
+        }
+        Err(other) => panic!("expected UnsupportedOperation, got {other}"),
+        Ok(_) => panic!("post-processing unexpectedly succeeded"),
+    }
+}
+
+#[test]
+fn test_bridge_import_is_not_post_processed() {
+    // A progress handler routes the import through the C++ bridge, which hands over a
+    // scene detached from its importer.
+    let scene = Importer::new()
+        .read_from_memory(QUAD_OBJ.as_bytes())
+        .with_memory_hint("obj")
+        .with_progress_event_fn(|_| true)
+        .import()
+        .expect("import quad");
+    assert!(!scene.is_importer_owned());
+    assert_unsupported(scene.apply_postprocess(PostProcessSteps::TRIANGULATE));
+}
+
+#[test]
+fn test_importer_owned_scene_is_post_processed() {
+    let scene = Scene::from_memory(QUAD_OBJ.as_bytes(), Some("obj")).expect("import quad");
+    assert!(scene.is_importer_owned());
+    assert_eq!(scene.mesh(0).unwrap().num_faces(), 1);
+
+    let scene = scene
+        .apply_postprocess(PostProcessSteps::TRIANGULATE)
+        .expect("triangulate");
+    assert!(scene.is_importer_owned());
+    assert_eq!(scene.mesh(0).unwrap().num_faces(), 2);
+}
+
+#[test]
+fn test_copies_and_shared_scenes_are_rejected() {
+    let scene = Scene::from_memory(QUAD_OBJ.as_bytes(), Some("obj")).expect("import quad");
+
+    let copy = scene.deep_copy().expect("deep copy");
+    assert!(!copy.is_importer_owned());
+    assert_unsupported(copy.apply_postprocess(PostProcessSteps::TRIANGULATE));
+
+    let mesh = scene.mesh(0).expect("mesh");
+    assert_unsupported(scene.apply_postprocess(PostProcessSteps::TRIANGULATE));
+    assert_eq!(mesh.num_faces(), 1);
+}
+
+#[test]
+fn test_scene_flags() {
+    let scene = Importer::new()
+        .read_from_memory(QUAD_OBJ.as_bytes())
+        .with_memory_hint("obj")
+        .with_post_process(PostProcessSteps::VALIDATE_DATA_STRUCTURE)
+        .import()
+        .expect("import quad");
+    let flags = scene.scene_flags();
+    assert_eq!(flags.bits(), scene.flags());
+    assert!(flags.contains(SceneFlags::VALIDATED));
+    assert!(!flags.contains(SceneFlags::INCOMPLETE));
+    assert_eq!(
+        scene.allows_shared_data(),
+        flags.contains(SceneFlags::ALLOW_SHARED)
+    );
+}
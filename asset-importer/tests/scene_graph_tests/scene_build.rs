@@ -0,0 +1,147 @@
+//! Programmatic scene construction tests
+
+use asset_importer::{
+    material::{MaterialBuilder, MaterialTexture, TextureType},
+    scene::build::{MeshData, SceneBuilder, node},
+    types::{Matrix4x4, Quaternion, Vector2D, Vector3D},
+};
+
+fn offset() -> Matrix4x4 {
+    Matrix4x4::from_scale_rotation_translation(
+        Vector3D::splat(1.0),
+        Quaternion::IDENTITY,
+        Vector3D::new(0.0, 0.0, -1.0),
+    )
+}
+
+fn quad_mesh(material_index: usize) -> MeshData {
+    MeshData {
+        name: "quad".into(),
+        positions: vec![
+            Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+        ],
+        normals: Some(vec![Vector3D::new(0.0, 0.0, 1.0); 4]),
+        uvs: Some(vec![
+            Vector2D::new(0.0, 0.0),
+            Vector2D::new(1.0, 0.0),
+            Vector2D::new(1.0, 1.0),
+            Vector2D::new(0.0, 1.0),
+        ]),
+        indices: vec![0, 1, 2, 0, 2, 3],
+        material_index,
+    }
+}
+
+fn textured_quad() -> SceneBuilder {
+    let mut builder = SceneBuilder::new();
+    let material = builder.add_material(MaterialBuilder::new().with_name("checker").with_texture(
+        TextureType::Diffuse,
+        0,
+        MaterialTexture::new("checker.png"),
+    ));
+    let quad = builder.add_mesh(quad_mesh(material));
+    builder.add_node(node("quad").with_transform(offset()).with_meshes([quad]));
+    builder
+}
+
+#[test]
+fn test_build_textured_quad() {
+    let scene = textured_quad().build().expect("build scene");
+    assert_eq!(scene.num_meshes(), 1);
+    assert_eq!(scene.num_materials(), 1);
+
+    let mesh = scene.mesh(0).unwrap();
+    assert_eq!(mesh.name(), "quad");
+    assert_eq!(mesh.num_vertices(), 4);
+    assert_eq!(mesh.num_faces(), 2);
+    assert!(mesh.has_texture_coords(0));
+    let material = scene.material(0).unwrap();
+    assert_eq!(material.name(), "checker");
+    assert_eq!(
+        material.texture(TextureType::Diffuse, 0).unwrap().path,
+        "checker.png"
+    );
+
+    let root = scene.root_node().unwrap();
+    assert_eq!(root.num_children(), 1);
+    let child = root.child(0).unwrap();
+    assert_eq!(child.name(), "quad");
+    assert_eq!(child.mesh_indices_raw(), &[0]);
+    assert_eq!(child.transformation(), offset());
+}
+
+#[test]
+fn test_build_adds_default_material() {
+    let mut builder = SceneBuilder::new();
+    builder.add_mesh(quad_mesh(0));
+    let scene = builder.build().expect("build scene");
+    assert_eq!(scene.num_materials(), 1);
+}
+
+#[test]
+fn test_build_rejects_invalid_indices() {
+    let mut builder = SceneBuilder::new();
+    let mut mesh = quad_mesh(0);
+    mesh.indices[4] = 4;
+    builder.add_mesh(mesh);
+    let err = builder.build().unwrap_err().to_string();
+    assert!(err.contains("index 4 at position 4"), "{err}");
+
+    let mut builder = SceneBuilder::new();
+    builder.add_mesh(quad_mesh(1));
+    let err = builder.build().unwrap_err().to_string();
+    assert!(err.contains("material index 1"), "{err}");
+
+    let mut builder = SceneBuilder::new();
+    let mut mesh = quad_mesh(0);
+    mesh.uvs.as_mut().unwrap().pop();
+    builder.add_mesh(mesh);
+    assert!(builder.build().is_err());
+
+    let mut builder = SceneBuilder::new();
+    builder.add_mesh(quad_mesh(0));
+    builder.add_node(node("parent").with_child(node("child").with_meshes([3])));
+    let err = builder.build().unwrap_err().to_string();
+    assert!(err.contains("mesh index 3"), "{err}");
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_built_scene_exports_and_reimports() {
+    use asset_importer::{
+        Importer,
+        exporter::{ExportBuilder, format_ids},
+    };
+
+    let scene = textured_quad().build().expect("build scene");
+    // GLB rather than glTF: a single blob has no room for the separate .bin buffer.
+    for (format, hint) in [(format_ids::OBJ, "obj"), (format_ids::GLB2, "glb")] {
+        let blob = ExportBuilder::new(format)
+            .export_to_blob(&scene)
+            .unwrap_or_else(|e| panic!("export {format}: {e}"));
+        let reimported = Importer::new()
+            .read_from_memory(blob.data())
+            .with_memory_hint(hint)
+            .import()
+            .unwrap_or_else(|e| panic!("reimport {format}: {e}"));
+
+        assert_eq!(reimported.num_meshes(), 1, "{format}");
+        let mesh = reimported.mesh(0).unwrap();
+        assert_eq!(mesh.num_faces(), 2, "{format}");
+        assert!(mesh.has_texture_coords(0), "{format}");
+        let mut positions: Vec<_> = mesh
+            .vertices_iter()
+            .map(|v| ((v.x * 10.0).round() as i32, (v.y * 10.0).round() as i32))
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+        assert_eq!(
+            positions,
+            vec![(0, 0), (0, 10), (10, 0), (10, 10)],
+            "{format}"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Scene metadata convenience getters: axes and provenance
+
+use asset_importer::{Scene, scene::conventions::AxisDirection};
+
+const Z_UP_DAE: &str = r##"<?xml version="1.0" encoding="utf-8"?>
+<COLLADA xmlns="http://www.collada.org/2005/11/COLLADASchema" version="1.4.1">
+  <asset>
+    <contributor>
+      <authoring_tool>Blender 4.2.0</authoring_tool>
+    </contributor>
+    <unit name="meter" meter="1"/>
+    <up_axis>Z_UP</up_axis>
+  </asset>
+  <library_visual_scenes>
+    <visual_scene id="Scene" name="Scene">
+      <node id="Empty" name="Empty" type="NODE">
+        <translate>0 0 1</translate>
+      </node>
+    </visual_scene>
+  </library_visual_scenes>
+  <scene>
+    <instance_visual_scene url="#Scene"/>
+  </scene>
+</COLLADA>"##;
+
+#[test]
+fn test_collada_z_up() {
+    let scene = Scene::from_memory(Z_UP_DAE.as_bytes(), Some("dae")).expect("import DAE");
+    assert_eq!(scene.up_axis(), Some(AxisDirection::PositiveZ));
+    assert_eq!(scene.authoring_tool().as_deref(), Some("Blender 4.2.0"));
+    assert_eq!(
+        scene.source_format_hints().generator.as_deref(),
+        Some("Blender 4.2.0")
+    );
+}
+
+#[test]
+fn test_collada_y_up_default() {
+    let dae = Z_UP_DAE.replace("<up_axis>Z_UP</up_axis>", "<up_axis>Y_UP</up_axis>");
+    let scene = Scene::from_memory(dae.as_bytes(), Some("dae")).expect("import DAE");
+    assert_eq!(scene.up_axis(), Some(AxisDirection::PositiveY));
+}
+
+#[test]
+fn test_fbx_y_up() {
+    let scene = Scene::from_file("tests/models/pivots.fbx").expect("import FBX");
+    assert!(scene.metadata_opt().expect("metadata").is_some());
+    assert_eq!(scene.up_axis(), Some(AxisDirection::PositiveY));
+    assert_eq!(scene.front_axis(), Some(AxisDirection::PositiveZ));
+    assert_eq!(scene.right_axis(), Some(AxisDirection::PositiveX));
+
+    let hints = scene.source_format_hints();
+    assert!(!hints.is_empty());
+    assert!(hints.format_version.is_some());
+}
+
+#[test]
+fn test_obj_has_no_conventions() {
+    let scene = Scene::from_file("tests/models/box.obj").expect("import OBJ");
+    let metadata = scene.metadata().expect("metadata");
+    match scene.metadata_opt().expect("metadata") {
+        Some(found) => assert_eq!(found.len(), metadata.len()),
+        None => assert!(metadata.is_empty()),
+    }
+    assert_eq!(scene.up_axis(), None);
+    assert_eq!(scene.front_axis(), None);
+    assert_eq!(scene.authoring_tool(), None);
+}
+
+#[test]
+fn test_axis_direction_from_index() {
+    assert_eq!(
+        AxisDirection::from_index(1, -1),
+        Some(AxisDirection::NegativeY)
+    );
+    assert_eq!(
+        AxisDirection::from_index(2, 1),
+        Some(AxisDirection::PositiveZ)
+    );
+    assert_eq!(AxisDirection::from_index(3, 1), None);
+    for axis in AxisDirection::ALL {
+        let v = axis.vector();
+        assert_eq!(v.x.abs() + v.y.abs() + v.z.abs(), 1.0);
+    }
+}
@@ -0,0 +1,143 @@
+//! Post-import convention conversions compared with the matching post-process steps
+
+use asset_importer::{
+    Importer, Scene,
+    postprocess::PostProcessSteps,
+    scene::{OwnedScene, convert},
+    test_utils,
+    types::Vector3D,
+};
+
+use crate::common;
+
+// Buffer: positions (0,0,0) (1,0,0) (0,1,0), normals +Z and UVs (0,0) (1,0) (0.25,1).
+// The triangle hangs under a rotated, translated parent next to a camera.
+const TRIANGLE_GLTF: &str = r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 72, "byteLength": 24 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3" },
+    { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2" }
+  ],
+  "meshes": [{ "name": "Tri", "primitives": [{ "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2 } }] }],
+  "cameras": [{ "type": "perspective", "perspective": { "yfov": 0.8, "znear": 0.1 } }],
+  "nodes": [
+    { "name": "Parent", "translation": [1, 2, 3], "rotation": [0, 0.3826834, 0, 0.9238795], "children": [1, 2] },
+    { "name": "Child", "mesh": 0, "translation": [0, 0, -2] },
+    { "name": "Eye", "camera": 0, "translation": [0, 1, 5] }
+  ],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+fn triangle_gltf() -> String {
+    let normals = test_utils::f32_bytes(&[0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+    let uvs = test_utils::f32_bytes(&[0.0, 0.0, 1.0, 0.0, 0.25, 1.0]);
+    common::gltf(
+        &[common::triangle_bytes(), normals, uvs].concat(),
+        TRIANGLE_GLTF,
+    )
+}
+
+fn import(steps: PostProcessSteps) -> Scene {
+    Importer::new()
+        .read_from_memory(triangle_gltf().as_bytes())
+        .with_memory_hint("gltf")
+        .with_post_process(PostProcessSteps::CALC_TANGENT_SPACE | steps)
+        .import()
+        .expect("import glTF")
+}
+
+fn assert_vectors_eq(what: &str, actual: &[Vector3D], expected: &[Vector3D]) {
+    assert_eq!(actual.len(), expected.len(), "{what}");
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((*a - *e).length() < 1e-5, "{what}: {a:?} vs {e:?}");
+    }
+}
+
+fn assert_scenes_match(actual: &Scene, expected: &Scene) {
+    let nodes = |scene: &Scene| -> Vec<_> {
+        scene
+            .nodes_depth_first()
+            .map(|node| (node.name(), node.transformation().to_cols_array_2d()))
+            .collect()
+    };
+    for ((name, a), (expected_name, e)) in nodes(actual).into_iter().zip(nodes(expected)) {
+        assert_eq!(name, expected_name);
+        let flat = |m: [[f32; 4]; 4]| m.into_iter().flatten().collect::<Vec<_>>();
+        for (a, e) in flat(a).into_iter().zip(flat(e)) {
+            assert!((a - e).abs() < 1e-5, "{name}: {a} vs {e}");
+        }
+    }
+
+    assert_eq!(actual.num_meshes(), expected.num_meshes());
+    for (a, e) in actual.meshes().zip(expected.meshes()) {
+        assert_vectors_eq("positions", &a.vertices(), &e.vertices());
+        assert_vectors_eq("normals", &a.normals().unwrap(), &e.normals().unwrap());
+        assert_vectors_eq("tangents", &a.tangents().unwrap(), &e.tangents().unwrap());
+        assert_vectors_eq(
+            "bitangents",
+            &a.bitangents().unwrap(),
+            &e.bitangents().unwrap(),
+        );
+        assert_vectors_eq(
+            "uvs",
+            &a.texture_coords(0).unwrap(),
+            &e.texture_coords(0).unwrap(),
+        );
+        let faces = |mesh: &asset_importer::mesh::Mesh| -> Vec<Vec<u32>> {
+            mesh.faces().map(|face| face.indices().to_vec()).collect()
+        };
+        assert_eq!(faces(&a), faces(&e));
+    }
+
+    for (a, e) in actual.cameras().zip(expected.cameras()) {
+        assert_vectors_eq("camera look-at", &[a.look_at()], &[e.look_at()]);
+    }
+}
+
+#[test]
+fn test_flip_uvs_matches_post_process() {
+    let mut converted = OwnedScene::copy_of(&import(PostProcessSteps::empty())).expect("copy");
+    convert::flip_uvs(&mut converted).expect("flip UVs");
+    assert_scenes_match(&converted, &import(PostProcessSteps::FLIP_UVS));
+
+    // The glTF importer already stores V as 1 - v, so flipping restores the file's 1.0.
+    let uvs = converted.mesh(0).unwrap().texture_coords(0).unwrap();
+    assert_eq!((uvs[2].x, uvs[2].y), (0.25, 1.0));
+}
+
+#[test]
+fn test_flip_winding_order_matches_post_process() {
+    let mut converted = OwnedScene::copy_of(&import(PostProcessSteps::empty())).expect("copy");
+    convert::flip_winding_order(&mut converted).expect("flip winding");
+    assert_scenes_match(&converted, &import(PostProcessSteps::FLIP_WINDING_ORDER));
+}
+
+#[test]
+fn test_to_left_handed_matches_post_process() {
+    let original = import(PostProcessSteps::empty());
+    let mut converted = OwnedScene::copy_of(&original).expect("copy");
+    convert::make_left_handed(&mut converted).expect("make left-handed");
+    assert_scenes_match(&converted, &import(PostProcessSteps::MAKE_LEFT_HANDED));
+
+    let mut converted = OwnedScene::copy_of(&original).expect("copy");
+    // Cached instances are computed before the conversion and must not survive it.
+    let before = converted.mesh_instances();
+    convert::to_left_handed(&mut converted).expect("convert to left-handed");
+    let expected = import(
+        PostProcessSteps::MAKE_LEFT_HANDED
+            | PostProcessSteps::FLIP_UVS
+            | PostProcessSteps::FLIP_WINDING_ORDER,
+    );
+    assert_scenes_match(&converted, &expected);
+    assert_eq!(converted.mesh_instances(), expected.mesh_instances());
+    assert_ne!(converted.mesh_instances(), before);
+
+    // The source scene is untouched.
+    assert_scenes_match(&original, &import(PostProcessSteps::empty()));
+}
@@ -0,0 +1,96 @@
+//! Scene comparison tests
+
+use asset_importer::{
+    Importer, Scene,
+    postprocess::PostProcessSteps,
+    scene::diff::{CompareOptions, DifferenceKind, compare},
+};
+
+fn import(flags: PostProcessSteps) -> Scene {
+    Importer::new()
+        .read_file("tests/models/textured.obj")
+        .with_post_process(flags)
+        .import()
+        .expect("import textured.obj")
+}
+
+#[test]
+fn test_scene_equals_itself() {
+    let scene = import(PostProcessSteps::TRIANGULATE);
+    let again = import(PostProcessSteps::TRIANGULATE);
+
+    let diff = compare(&scene, &scene, CompareOptions::exact());
+    assert!(diff.is_empty(), "{diff}");
+    let diff = compare(&scene, &again, CompareOptions::exact());
+    assert!(diff.is_empty(), "{diff}");
+    assert_eq!(diff.to_string(), "Scenes are equal");
+}
+
+#[test]
+fn test_flip_uvs_reports_uv_changes() {
+    let before = import(PostProcessSteps::TRIANGULATE);
+    let after = import(PostProcessSteps::TRIANGULATE | PostProcessSteps::FLIP_UVS);
+
+    let diff = compare(&before, &after, CompareOptions::default());
+    assert!(!diff.is_empty());
+    assert!(!diff.truncated);
+
+    // v becomes 1 - v for all three corners; nothing else about the mesh moves.
+    let uvs: Vec<_> = diff.under("meshes[0].uvs[0]").collect();
+    assert_eq!(uvs.len(), 3, "{diff}");
+    assert!(uvs.iter().all(|d| d.kind == DifferenceKind::Changed));
+    assert!(diff.under("meshes[0].positions").next().is_none());
+    assert!(diff.under("meshes[0].normals").next().is_none());
+
+    let first = uvs
+        .iter()
+        .find(|d| d.path == "meshes[0].uvs[0][0]")
+        .expect("first corner changed");
+    assert_eq!(first.before.as_deref(), Some("(0, 0)"));
+    assert_eq!(first.after.as_deref(), Some("(0, 1)"));
+    assert!(
+        diff.to_string()
+            .contains("~ meshes[0].uvs[0][0]: (0, 0) -> (0, 1)"),
+        "{diff}"
+    );
+}
+
+#[test]
+fn test_max_differences_truncates() {
+    let before = import(PostProcessSteps::TRIANGULATE);
+    let after = import(PostProcessSteps::TRIANGULATE | PostProcessSteps::FLIP_UVS);
+
+    let diff = compare(
+        &before,
+        &after,
+        CompareOptions::default().with_max_differences(1),
+    );
+    assert_eq!(diff.len(), 1);
+    assert!(diff.truncated);
+}
+
+#[test]
+fn test_structural_differences() {
+    let triangle = import(PostProcessSteps::TRIANGULATE);
+    let cube = Scene::from_file("tests/models/box.obj").expect("import box.obj");
+
+    let diff = compare(&triangle, &cube, CompareOptions::default());
+    assert!(
+        diff.differences
+            .iter()
+            .any(|d| d.path == "meshes[0].vertices.len()"),
+        "{diff}"
+    );
+    // The triangle has UVs, the box does not.
+    assert!(
+        diff.differences
+            .iter()
+            .all(|d| !d.path.starts_with("meshes[0].uvs"))
+    );
+    assert!(
+        diff.differences
+            .iter()
+            .any(|d| d.path.starts_with("nodes[") && d.kind != DifferenceKind::Changed),
+        "{diff}"
+    );
+}
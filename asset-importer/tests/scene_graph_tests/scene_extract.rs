@@ -0,0 +1,106 @@
+//! Sub-scene extraction tests
+
+use asset_importer::{Scene, SubSceneExtractor};
+
+const THREE_OBJECTS_OBJ: &str = "\
+o First
+v 0 0 0
+v 1 0 0
+v 0 1 0
+f 1 2 3
+o Second
+v 0 0 1
+v 1 0 1
+v 1 1 1
+v 0 1 1
+f 4 5 6 7
+o Third
+v 0 0 2
+v 1 0 2
+v 0 1 2
+f 8 9 10
+";
+
+fn three_objects() -> Scene {
+    Scene::from_memory(THREE_OBJECTS_OBJ.as_bytes(), Some("obj")).expect("import OBJ")
+}
+
+#[test]
+fn test_extract_single_mesh() {
+    let scene = three_objects();
+    assert_eq!(scene.num_meshes(), 3);
+
+    let sub = SubSceneExtractor::new(&scene)
+        .with_meshes([1])
+        .build()
+        .expect("extract mesh 1");
+
+    assert_eq!(sub.num_meshes(), 1);
+    assert_eq!(sub.mesh(0).unwrap().num_vertices(), 4);
+    assert_eq!(sub.num_materials(), 1);
+
+    let referencing: Vec<String> = sub
+        .nodes_depth_first()
+        .filter(|n| n.num_meshes() > 0)
+        .map(|n| n.name())
+        .collect();
+    assert_eq!(referencing, ["Second"]);
+    assert!(
+        sub.nodes_depth_first()
+            .all(|n| n.mesh_indices_raw().iter().all(|&i| i == 0))
+    );
+
+    // The source scene is untouched.
+    assert_eq!(scene.num_meshes(), 3);
+}
+
+#[test]
+fn test_extract_node_subtree() {
+    let scene = three_objects();
+    let sub = SubSceneExtractor::new(&scene)
+        .with_node_subtree("Third")
+        .build()
+        .expect("extract subtree");
+
+    assert_eq!(sub.root_node().unwrap().name(), "Third");
+    assert_eq!(sub.num_meshes(), 1);
+    assert_eq!(sub.mesh(0).unwrap().num_vertices(), 3);
+}
+
+#[test]
+fn test_extract_rejects_bad_input() {
+    let scene = three_objects();
+    assert!(
+        SubSceneExtractor::new(&scene)
+            .with_meshes([7])
+            .build()
+            .is_err()
+    );
+    assert!(
+        SubSceneExtractor::new(&scene)
+            .with_node_subtree("Missing")
+            .build()
+            .is_err()
+    );
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_extract_export_gltf_round_trip() {
+    use asset_importer::exporter::{ExportBuilder, formats};
+
+    let scene = three_objects();
+    let sub = SubSceneExtractor::new(&scene)
+        .with_meshes([1])
+        .build()
+        .expect("extract mesh 1");
+
+    let blob = ExportBuilder::new(formats::GLB2)
+        .export_to_blob(&sub)
+        .expect("export GLB");
+    let reimported = Scene::from_memory(blob.data(), Some("glb")).expect("re-import GLB");
+
+    assert_eq!(reimported.num_meshes(), 1);
+    assert_eq!(reimported.mesh(0).unwrap().num_vertices(), 4);
+    assert_eq!(reimported.num_materials(), 1);
+}
@@ -0,0 +1,305 @@
+//! Scene graph traversal tests
+
+use asset_importer::Scene;
+
+use crate::common;
+
+// Two scene roots force Assimp to synthesize a common parent:
+// <root> -> { A -> A1, B -> B1 }
+const BRANCHING_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "nodes": [
+    { "name": "A", "children": [2] },
+    { "name": "B", "children": [3] },
+    { "name": "A1" },
+    { "name": "B1" }
+  ],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#;
+
+fn names(nodes: impl Iterator<Item = asset_importer::node::Node>) -> Vec<String> {
+    nodes.skip(1).map(|n| n.name()).collect()
+}
+
+#[test]
+fn test_node_traversal_orders() {
+    let scene = Scene::from_memory(BRANCHING_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+
+    assert_eq!(scene.node_count(), 5);
+    assert_eq!(names(scene.nodes_depth_first()), ["A", "A1", "B", "B1"]);
+    assert_eq!(names(scene.nodes_breadth_first()), ["A", "B", "A1", "B1"]);
+
+    let depths: Vec<usize> = scene
+        .nodes_breadth_first()
+        .with_depth()
+        .map(|(_, depth)| depth)
+        .collect();
+    assert_eq!(depths, [0, 1, 1, 2, 2]);
+}
+
+#[test]
+fn test_node_descendants_and_find_by_name() {
+    let scene = Scene::from_memory(BRANCHING_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let root = scene.root_node().expect("root node");
+
+    assert_eq!(root.descendants().count(), 4);
+
+    let a = root.find_by_name("A").expect("node A");
+    let below_a: Vec<String> = a.descendants().map(|n| n.name()).collect();
+    assert_eq!(below_a, ["A1"]);
+
+    assert_eq!(
+        root.find_by_name("B1").map(|n| n.parent().unwrap().name()),
+        Some("B".to_string())
+    );
+    assert!(a.find_by_name("B1").is_none());
+    assert!(root.find_by_name("missing").is_none());
+}
+
+// Two meshes sharing one triangle (0,0,0) (1,0,0) (0,1,0): one translated by +10 on X,
+// the other scaled by 2 and moved to z = -5.
+const TWO_MESH_GLTF: &str = r#"{
+  "meshes": [
+    { "primitives": [{ "attributes": { "POSITION": 0 } }] },
+    { "primitives": [{ "attributes": { "POSITION": 0 } }] }
+  ],
+  "nodes": [
+    { "name": "Shifted", "mesh": 0, "translation": [10.0, 0.0, 0.0] },
+    { "name": "Scaled", "mesh": 1, "translation": [0.0, 0.0, -5.0], "scale": [2.0, 2.0, 2.0] }
+  ],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_compute_world_aabb() {
+    let scene = Scene::from_memory(
+        common::triangle_gltf(TWO_MESH_GLTF).as_bytes(),
+        Some("gltf"),
+    )
+    .expect("import glTF");
+    assert_eq!(scene.num_meshes(), 2);
+
+    let bounds = scene.compute_world_aabb().expect("scene bounds");
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+    assert!(close(bounds.min.x, 0.0) && close(bounds.min.y, 0.0) && close(bounds.min.z, -5.0));
+    assert!(close(bounds.max.x, 11.0) && close(bounds.max.y, 2.0) && close(bounds.max.z, 0.0));
+
+    let mesh = scene.mesh(0).unwrap();
+    assert_eq!(mesh.bounds(), mesh.compute_aabb());
+}
+
+// One mesh placed by three nodes; the third is nested under a translated parent.
+const INSTANCED_GLTF: &str = r#"{
+  "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+  "nodes": [
+    { "name": "First", "mesh": 0 },
+    { "name": "Second", "mesh": 0, "translation": [5.0, 0.0, 0.0] },
+    { "name": "Group", "translation": [0.0, 3.0, 0.0], "children": [3] },
+    { "name": "Third", "mesh": 0, "translation": [0.0, 0.0, 2.0] }
+  ],
+  "scenes": [{ "nodes": [0, 1, 2] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_mesh_instances_for_shared_mesh() {
+    let scene = Scene::from_memory(
+        common::triangle_gltf(INSTANCED_GLTF).as_bytes(),
+        Some("gltf"),
+    )
+    .expect("import glTF");
+    assert_eq!(scene.num_meshes(), 1);
+
+    let instances = scene.mesh_instances();
+    assert_eq!(instances.len(), 1);
+    let names: Vec<&str> = instances[0].iter().map(|i| i.node_name.as_str()).collect();
+    assert_eq!(names, ["First", "Second", "Third"]);
+
+    let translations: Vec<[f32; 3]> = instances[0]
+        .iter()
+        .map(|i| {
+            let t = i.world_transform.w_axis;
+            [t.x, t.y, t.z]
+        })
+        .collect();
+    assert_eq!(
+        translations,
+        [[0.0, 0.0, 0.0], [5.0, 0.0, 0.0], [0.0, 3.0, 2.0]]
+    );
+
+    let pairs: Vec<_> = scene.instanced_meshes().collect();
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].1, instances[0].as_slice());
+
+    let first = scene.root_node().unwrap().find_by_name("First").unwrap();
+    assert!(first.metadata().expect("node metadata").is_empty());
+}
+
+#[test]
+fn test_node_path_round_trip() {
+    let scene = Scene::from_memory(BRANCHING_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+
+    let root = scene.root_node().expect("root node");
+    assert!(root.path().is_empty());
+    let b1 = root.find_by_name("B1").expect("B1");
+    assert_eq!(b1.path(), [1, 0]);
+
+    for node in scene.nodes_depth_first() {
+        let found = scene.node_by_path(&node.path()).expect("path resolves");
+        assert_eq!(found.name(), node.name());
+    }
+    assert!(scene.node_by_path(&[2]).is_none());
+    assert!(scene.node_by_path(&[0, 0, 0]).is_none());
+}
+
+// The camera node and an unrelated node share the name "Cam":
+// <root> -> { Rig -> Cam (camera 0), Cam }
+const DUPLICATE_NAME_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "cameras": [
+    { "type": "perspective", "perspective": { "yfov": 0.8, "znear": 0.1, "zfar": 100.0 } }
+  ],
+  "nodes": [
+    { "name": "Rig", "children": [1] },
+    { "name": "Cam", "camera": 0 },
+    { "name": "Cam" }
+  ],
+  "scenes": [{ "nodes": [0, 2] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_bind_cameras_reports_name_collisions() {
+    use asset_importer::scene::bindings::BindingKind;
+
+    let scene =
+        Scene::from_memory(DUPLICATE_NAME_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    assert_eq!(scene.num_cameras(), 1);
+
+    let bindings = scene.bind_cameras_and_lights();
+    assert_eq!(bindings.camera_path(0), Some(&[0, 0][..]));
+    assert!(bindings.unbound.is_empty());
+    assert!(bindings.has_collisions());
+
+    let collision = &bindings.collisions[0];
+    assert_eq!(collision.kind, BindingKind::Camera);
+    assert_eq!(collision.index, 0);
+    assert_eq!(collision.name, "Cam");
+    assert_eq!(collision.candidates, [vec![0, 0], vec![1]]);
+
+    let node = scene.node_by_path(&[0, 0]).expect("bound node");
+    assert_eq!(node.parent().expect("parent").name(), "Rig");
+}
+
+#[test]
+fn test_bind_without_cameras_or_lights_is_empty() {
+    let scene = Scene::from_memory(BRANCHING_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let bindings = scene.bind_cameras_and_lights();
+    assert_eq!(bindings, Default::default());
+}
+
+// Two meshes and a point light under a translated, scaled parent:
+// Parent(t=10,0,0 s=2) -> { Left(t=0,1,0, mesh 0), Right(t=0,0,3, mesh 1) -> Lamp(t=1,0,0) }
+const NESTED_GLTF: &str = r#"{
+  "extensionsUsed": ["KHR_lights_punctual"],
+  "extensions": { "KHR_lights_punctual": { "lights": [{ "type": "point", "color": [1, 1, 1], "intensity": 1 }] } },
+  "meshes": [
+    { "name": "LeftMesh", "primitives": [{ "attributes": { "POSITION": 0 } }] },
+    { "name": "RightMesh", "primitives": [{ "attributes": { "POSITION": 0 } }] }
+  ],
+  "nodes": [
+    { "name": "Parent", "translation": [10.0, 0.0, 0.0], "scale": [2.0, 2.0, 2.0], "children": [1, 2] },
+    { "name": "Left", "mesh": 0, "translation": [0.0, 1.0, 0.0] },
+    { "name": "Right", "mesh": 1, "translation": [0.0, 0.0, 3.0], "children": [3] },
+    { "name": "Lamp", "translation": [1.0, 0.0, 0.0], "extensions": { "KHR_lights_punctual": { "light": 0 } } }
+  ],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_renderables_and_placed_lights() {
+    let scene = Scene::from_memory(common::triangle_gltf(NESTED_GLTF).as_bytes(), Some("gltf"))
+        .expect("import glTF");
+    assert_eq!(scene.num_meshes(), 2);
+
+    let renderables: Vec<_> = scene.renderables().collect();
+    let placed: Vec<(&str, usize, [f32; 3])> = renderables
+        .iter()
+        .map(|r| {
+            let t = r.world_transform.w_axis;
+            (r.node_name.as_str(), r.mesh_index, [t.x, t.y, t.z])
+        })
+        .collect();
+    assert_eq!(
+        placed,
+        [
+            ("Left", 0, [10.0, 2.0, 0.0]),
+            ("Right", 1, [10.0, 0.0, 6.0]),
+        ]
+    );
+    // Same traversal as the cached instance lists.
+    let instances = scene.mesh_instances();
+    assert_eq!(
+        instances[1][0].world_transform,
+        renderables[1].world_transform
+    );
+
+    let lights = scene.placed_lights();
+    assert_eq!(lights.len(), 1);
+    assert_eq!(lights[0].index, 0);
+    assert_eq!(lights[0].node_name, "Lamp");
+    let p = lights[0].world_position();
+    assert!(
+        (p.x - 12.0).abs() < 1e-5 && p.y.abs() < 1e-5 && (p.z - 6.0).abs() < 1e-5,
+        "{p:?}"
+    );
+    assert!(scene.placed_cameras().is_empty());
+}
+
+// <root> -> { Arm -> { Upper, Lower, Hand }, Leg }
+const WIDE_GLTF: &str = r#"{
+  "asset": { "version": "2.0" },
+  "nodes": [
+    { "name": "Arm", "children": [2, 3, 4] },
+    { "name": "Leg" },
+    { "name": "Upper" },
+    { "name": "Lower" },
+    { "name": "Hand" }
+  ],
+  "scenes": [{ "nodes": [0, 1] }],
+  "scene": 0
+}"#;
+
+#[test]
+fn test_indexed_children() {
+    let scene = Scene::from_memory(WIDE_GLTF.as_bytes(), Some("gltf")).expect("import glTF");
+    let root = scene.root_node().expect("root node");
+
+    for node in scene.nodes_depth_first() {
+        let children: Vec<_> = node.children().collect();
+        assert_eq!(node.num_children(), children.len());
+        for (index, child) in children.iter().enumerate() {
+            let by_index = node.child(index).expect("child by index");
+            assert_eq!(by_index.name(), child.name());
+            assert_eq!(child.index_in_parent(), Some(index));
+            assert_eq!(child.parent().unwrap().path(), node.path());
+        }
+        assert!(node.child(children.len()).is_none());
+    }
+
+    assert_eq!(root.num_children(), 2);
+    assert_eq!(root.index_in_parent(), None);
+    assert_eq!(root.siblings().count(), 0);
+
+    let arm = root.child(0).expect("Arm");
+    assert_eq!(arm.name(), "Arm");
+    assert_eq!(arm.num_children(), 3);
+    let lower = arm.child(1).expect("Lower");
+    assert_eq!(lower.name(), "Lower");
+    assert_eq!(lower.path(), [0, 1]);
+    let siblings: Vec<String> = lower.siblings().map(|n| n.name()).collect();
+    assert_eq!(siblings, ["Upper", "Hand"]);
+}
@@ -0,0 +1,128 @@
+//! Scene name index tests
+
+use asset_importer::{
+    Scene,
+    scene::build::{MeshData, ROOT_NODE_NAME, SceneBuilder, node},
+    types::Vector3D,
+};
+
+fn triangle(name: &str) -> MeshData {
+    MeshData {
+        name: name.into(),
+        positions: vec![
+            Vector3D::new(0.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+        ],
+        indices: vec![0, 1, 2],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_duplicate_node_names() {
+    let mut builder = SceneBuilder::new();
+    let shared = builder.add_mesh(triangle("Shared"));
+    let other = builder.add_mesh(triangle("Shared"));
+    let unique = builder.add_mesh(triangle("Unique"));
+    builder
+        .add_node(node("Arm").with_child(node("Hand").with_meshes([shared])))
+        .add_node(node("Leg").with_child(node("Hand").with_meshes([other, unique])));
+    let scene = builder.build().expect("build scene");
+
+    let index = scene.build_name_index();
+    assert_eq!(
+        index.node(ROOT_NODE_NAME).expect("root").path(),
+        Vec::<usize>::new()
+    );
+    assert_eq!(index.get_unique("Arm").unwrap().unwrap().name(), "Arm");
+    assert!(index.get_unique("Missing").unwrap().is_none());
+    assert!(index.node("Missing").is_none());
+
+    // Both matches are kept, first in depth-first pre-order.
+    let hands: Vec<_> = index.nodes("Hand").map(|n| n.path()).collect();
+    assert_eq!(hands, vec![vec![0, 0], vec![1, 0]]);
+    assert_eq!(index.node_path("Hand"), Some(&[0usize, 0][..]));
+    assert_eq!(
+        index.node("Hand").unwrap().path(),
+        scene
+            .root_node()
+            .unwrap()
+            .find_by_name("Hand")
+            .unwrap()
+            .path()
+    );
+    let err = index.get_unique("Hand").err().expect("Hand is ambiguous");
+    assert!(err.to_string().contains("ambiguous"), "{err}");
+    let duplicates: Vec<_> = index.duplicate_node_names().collect();
+    assert_eq!(duplicates, vec![b"Hand".as_slice()]);
+
+    assert_eq!(index.mesh_indices("Shared"), &[shared, other]);
+    assert_eq!(index.mesh_indices("Unique"), &[unique]);
+    assert!(index.mesh_indices("Missing").is_empty());
+}
+
+#[test]
+fn test_bone_lookup() {
+    let scene =
+        Scene::from_file("tests/models/skinned_six_influences.dae").expect("import skinned DAE");
+    let index = scene.build_name_index();
+
+    let mut found = 0;
+    for (mesh_index, mesh) in scene.meshes().enumerate() {
+        for (bone_index, bone) in mesh.bones().enumerate() {
+            let name = bone.name();
+            assert!(
+                index.bone(&name).any(|hit| hit == (mesh_index, bone_index)),
+                "{name}"
+            );
+            assert!(index.bones(&name).all(|b| b.name() == name));
+            found += 1;
+        }
+    }
+    assert!(found > 0);
+    assert_eq!(index.bone("no such bone").len(), 0);
+}
+
+#[test]
+fn test_generated_hierarchy() {
+    const BRANCHES: usize = 100;
+    const DEPTH: usize = 10;
+
+    let mut builder = SceneBuilder::new();
+    for branch in 0..BRANCHES {
+        let mut chain = node(format!("joint_{branch}_{}", DEPTH - 1));
+        for level in (0..DEPTH - 1).rev() {
+            chain = node(format!("joint_{branch}_{level}")).with_child(chain);
+        }
+        builder.add_node(chain);
+    }
+    let scene = builder.build().expect("build scene");
+    assert_eq!(scene.node_count(), BRANCHES * DEPTH + 1);
+
+    let start = std::time::Instant::now();
+    let index = scene.build_name_index();
+    assert_eq!(index.num_node_names(), BRANCHES * DEPTH + 1);
+    for _ in 0..10 {
+        for branch in 0..BRANCHES {
+            for level in 0..DEPTH {
+                let path = index
+                    .node_path(&format!("joint_{branch}_{level}"))
+                    .expect("indexed");
+                assert_eq!(path.len(), level + 1);
+                assert_eq!(path[0], branch);
+            }
+        }
+    }
+    // 10k lookups plus the build; a linear search per lookup would be ~10M node visits.
+    assert!(
+        start.elapsed() < std::time::Duration::from_secs(5),
+        "{:?}",
+        start.elapsed()
+    );
+
+    let deepest = index.node(&format!("joint_42_{}", DEPTH - 1)).unwrap();
+    let path = index.node_path(&format!("joint_42_{}", DEPTH - 1)).unwrap();
+    assert_eq!(deepest.path(), path);
+    assert_eq!(scene.node_by_path(path).unwrap().name(), deepest.name());
+}
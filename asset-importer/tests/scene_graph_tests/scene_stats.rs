@@ -0,0 +1,130 @@
+//! Scene statistics golden tests
+
+use asset_importer::{
+    Scene,
+    scene::stats::{AnimationStatistics, MeshStatistics},
+    test_utils,
+};
+
+use crate::common;
+
+// Buffer: triangle positions (36 bytes), animation times [0, 2] (8 bytes) and two
+// translations (24 bytes). The same three vertices are drawn as triangles, points and a
+// line strip. Root -> Child -> Leaf, with one embedded 1x1 PNG (68 bytes).
+const STATS_GLTF: &str = r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+    { "buffer": 0, "byteOffset": 36, "byteLength": 8 },
+    { "buffer": 0, "byteOffset": 44, "byteLength": 24 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0] },
+    { "bufferView": 1, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [2] },
+    { "bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC3" }
+  ],
+  "images": [{ "uri": "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mP8/x8AAwMCAO+/p9sAAAAASUVORK5CYII=" }],
+  "textures": [{ "source": 0 }],
+  "materials": [{ "name": "Textured", "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } }],
+  "meshes": [
+    { "name": "Tri", "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }] },
+    { "name": "Dots", "primitives": [{ "attributes": { "POSITION": 0 }, "mode": 0 }] },
+    { "name": "Strip", "primitives": [{ "attributes": { "POSITION": 0 }, "mode": 3 }] }
+  ],
+  "nodes": [
+    { "name": "Root", "mesh": 2, "children": [1] },
+    { "name": "Child", "mesh": 0, "children": [2] },
+    { "name": "Leaf", "mesh": 1 }
+  ],
+  "animations": [{
+    "name": "Move",
+    "samplers": [{ "input": 1, "output": 2 }],
+    "channels": [{ "sampler": 0, "target": { "node": 2, "path": "translation" } }]
+  }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+fn stats_gltf() -> String {
+    let times = test_utils::f32_bytes(&[0.0, 2.0]);
+    let translations = test_utils::f32_bytes(&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    common::gltf(
+        &[common::triangle_bytes(), times, translations].concat(),
+        STATS_GLTF,
+    )
+}
+
+fn mesh(name: &str, faces: [usize; 3], material_index: usize) -> MeshStatistics {
+    let [points, lines, triangles] = faces;
+    MeshStatistics {
+        name: name.to_string(),
+        vertices: 3,
+        faces: points + lines + triangles,
+        points,
+        lines,
+        triangles,
+        polygons: 0,
+        bones: 0,
+        material_index,
+    }
+}
+
+#[test]
+fn test_statistics_golden_gltf() {
+    let scene = Scene::from_memory(stats_gltf().as_bytes(), Some("gltf")).expect("import glTF");
+    let stats = scene.statistics();
+
+    // glTF import appends a default material for primitives without one.
+    assert_eq!(
+        stats.meshes,
+        [
+            mesh("Tri", [0, 0, 1], 0),
+            mesh("Dots", [3, 0, 0], 1),
+            mesh("Strip", [0, 2, 0], 1),
+        ]
+    );
+    assert_eq!(stats.vertices, 9);
+    assert_eq!(
+        (
+            stats.faces,
+            stats.points,
+            stats.lines,
+            stats.triangles,
+            stats.polygons
+        ),
+        (6, 3, 2, 1, 0)
+    );
+    assert_eq!(stats.bones, 0);
+    assert_eq!(stats.materials, 2);
+    assert_eq!((stats.textures, stats.texture_bytes), (1, 68));
+    assert_eq!(
+        stats.animations,
+        [AnimationStatistics {
+            name: "Move".to_string(),
+            duration_seconds: 2.0,
+            channels: 1,
+        }]
+    );
+    assert_eq!((stats.cameras, stats.lights), (0, 0));
+    assert_eq!((stats.nodes, stats.max_node_depth), (3, 2));
+
+    let text = stats.to_string();
+    assert!(text.contains("Faces:             6 (1 triangles, 0 polygons, 2 lines, 3 points)"));
+    assert!(text.contains("Embedded textures: 1 (68 bytes)"));
+    assert!(text.contains("Animation 0 'Move': 2.000 s, 1 channels"));
+}
+
+#[test]
+fn test_statistics_counts_quads_as_polygons() {
+    let scene = Scene::from_file("tests/models/box.obj").expect("import box.obj");
+    let stats = scene.statistics();
+    assert_eq!((stats.faces, stats.polygons, stats.triangles), (6, 6, 0));
+
+    let triangulated = Scene::from_file_with_flags(
+        "tests/models/box.obj",
+        asset_importer::postprocess::PostProcessSteps::TRIANGULATE,
+    )
+    .expect("import box.obj")
+    .statistics();
+    assert_eq!((triangulated.faces, triangulated.triangles), (12, 12));
+    assert_eq!(triangulated.vertices, stats.vertices);
+}
@@ -0,0 +1,58 @@
+//! Serde scene snapshot tests
+#![cfg(feature = "serde")]
+
+use asset_importer::{
+    Importer,
+    postprocess::PostProcessSteps,
+    snapshot::{SceneSnapshot, SnapshotOptions},
+};
+
+#[test]
+fn test_snapshot_bincode_round_trip() {
+    let scene = Importer::new()
+        .read_file("tests/models/textured.obj")
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import textured.obj");
+
+    let snapshot = scene.to_snapshot(SnapshotOptions::full());
+    let bytes = bincode::serialize(&snapshot).expect("serialize snapshot");
+    drop(scene);
+    let restored: SceneSnapshot = bincode::deserialize(&bytes).expect("deserialize snapshot");
+    assert_eq!(restored, snapshot);
+
+    let mesh = &restored.meshes[0];
+    assert_eq!(mesh.positions.len(), mesh.num_vertices as usize);
+    assert_eq!(
+        mesh.face_sizes.to_u32_vec().iter().sum::<u32>() as usize,
+        mesh.indices.len()
+    );
+    assert!(mesh.uv_channel(0).is_some());
+    for i in [0, mesh.num_vertices as usize - 1] {
+        let p = mesh.position(i).expect("position");
+        assert_eq!(p, snapshot.meshes[0].position(i).unwrap());
+        assert!(p.iter().all(|c| c.is_finite()));
+    }
+    assert!(restored.root.as_ref().is_some_and(|root| root.count() >= 1));
+    assert!(!restored.materials.is_empty());
+}
+
+#[test]
+fn test_snapshot_positions_only() {
+    let scene = Importer::new()
+        .read_file("tests/models/textured.obj")
+        .import()
+        .expect("import textured.obj");
+
+    let full = scene.to_snapshot(SnapshotOptions::full());
+    let minimal = scene.to_snapshot(SnapshotOptions::positions_only());
+
+    let mesh = &minimal.meshes[0];
+    assert_eq!(mesh.positions, full.meshes[0].positions);
+    assert!(mesh.normals.is_none());
+    assert!(mesh.uv_channels.is_empty());
+    assert!(mesh.indices.is_empty());
+    assert!(minimal.materials.is_empty());
+    assert!(minimal.root.is_none());
+    assert!(bincode::serialize(&minimal).unwrap().len() < bincode::serialize(&full).unwrap().len());
+}
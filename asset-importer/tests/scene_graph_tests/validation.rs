@@ -0,0 +1,122 @@
+//! `ImportBuilder::with_validation` tests
+
+use asset_importer::{
+    Error, Importer, ValidationMode, ValidationSeverity, postprocess::PostProcessSteps, test_utils,
+};
+
+use crate::common;
+
+/// A triangle whose position accessor has a fourth vertex no face uses, which
+/// `ValidateDataStructure` reports as "There are unreferenced vertices".
+const UNREFERENCED_VERTEX_GLTF: &str = r#"{
+  "bufferViews": [
+    { "buffer": 0, "byteOffset": 0, "byteLength": 48 },
+    { "buffer": 0, "byteOffset": 48, "byteLength": 6 }
+  ],
+  "accessors": [
+    { "bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3", "min": [0, 0, 0], "max": [5, 5, 5] },
+    { "bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR" }
+  ],
+  "meshes": [{ "name": "tri", "primitives": [{ "attributes": { "POSITION": 0 }, "indices": 1 }] }],
+  "nodes": [{ "name": "root", "mesh": 0 }],
+  "scenes": [{ "nodes": [0] }],
+  "scene": 0
+}"#;
+
+fn unreferenced_vertex_gltf() -> String {
+    let buffer = [
+        common::triangle_bytes(),
+        test_utils::f32_bytes(&[5.0, 5.0, 5.0]),
+        test_utils::u16_bytes(&[0, 1, 2, 0]),
+    ]
+    .concat();
+    common::gltf(&buffer, UNREFERENCED_VERTEX_GLTF)
+}
+
+const CLEAN_OBJ: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+
+fn import_broken(mode: ValidationMode) -> asset_importer::Result<asset_importer::Scene> {
+    Importer::new()
+        .read_from_memory(unreferenced_vertex_gltf().as_bytes())
+        .with_memory_hint("gltf")
+        .with_validation(mode)
+        .import()
+}
+
+#[test]
+fn test_warn_mode_attaches_report() {
+    let scene = import_broken(ValidationMode::Warn).expect("warnings do not fail Warn mode");
+    let report = scene.validation_report().expect("report is attached");
+    assert!(!report.is_empty(), "expected validation warnings");
+    assert!(!report.has_errors());
+    assert!(
+        report
+            .warnings()
+            .any(|w| w.message.contains("unreferenced vertices")),
+        "{report}"
+    );
+    assert!(
+        report
+            .entries
+            .iter()
+            .all(|e| e.severity == ValidationSeverity::Warning)
+    );
+}
+
+#[test]
+fn test_strict_mode_fails_on_warnings() {
+    match import_broken(ValidationMode::Strict) {
+        Err(Error::ValidationFailed { report, .. }) => assert!(!report.is_empty()),
+        Err(other) => panic!("unexpected error: {other}"),
+        Ok(_) => panic!("Strict mode must reject validation warnings"),
+    }
+}
+
+#[test]
+fn test_clean_import_has_empty_report() {
+    let scene = Importer::new()
+        .read_from_memory(CLEAN_OBJ)
+        .with_memory_hint("obj")
+        .with_validation(ValidationMode::Strict)
+        .import()
+        .expect("clean file passes Strict validation");
+    assert_eq!(scene.validation_report(), Some(Default::default()));
+}
+
+#[test]
+fn test_off_mode_clears_flag_and_report() {
+    let scene = Importer::new()
+        .read_from_memory(unreferenced_vertex_gltf().as_bytes())
+        .with_memory_hint("gltf")
+        .with_post_process(PostProcessSteps::VALIDATE_DATA_STRUCTURE)
+        .with_validation(ValidationMode::Off)
+        .import()
+        .expect("import without validation");
+    assert!(scene.validation_report().is_none());
+
+    let plain = Importer::new()
+        .read_from_memory(CLEAN_OBJ)
+        .with_memory_hint("obj")
+        .import()
+        .expect("import without validation");
+    assert!(plain.validation_report().is_none());
+}
+
+#[test]
+fn test_validation_combines_with_import_report() {
+    let (scene, report) = Importer::new()
+        .read_from_memory(unreferenced_vertex_gltf().as_bytes())
+        .with_memory_hint("gltf")
+        .with_validation(ValidationMode::Warn)
+        .capture_log(asset_importer::LogLevel::Error)
+        .import_with_report()
+        .expect("import with report");
+    assert!(!scene.validation_report().unwrap().is_empty());
+    // The report keeps its own level even though validation captured warnings.
+    assert!(
+        report
+            .messages_at(asset_importer::LogLevel::Warn)
+            .next()
+            .is_none()
+    );
+}
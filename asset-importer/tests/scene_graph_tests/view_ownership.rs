@@ -0,0 +1,82 @@
+//! Scene-backed views own a scene handle and outlive the `Scene` they came from
+
+use asset_importer::{
+    Importer, Scene,
+    animation::{Animation, NodeAnimation},
+    bone::Bone,
+    camera::Camera,
+    light::Light,
+    material::{Material, MaterialPropertyRef},
+    mesh::{AnimMesh, Mesh},
+    node::Node,
+    scene::{MaterialIterator, MeshIterator},
+    texture::Texture,
+};
+
+fn assert_owned_view<T: Clone + Send + Sync + 'static>() {}
+fn assert_owned_iter<T: Iterator + Send + Sync + 'static>() {}
+
+#[test]
+fn test_view_types_are_owned() {
+    assert_owned_view::<Scene>();
+    assert_owned_view::<Mesh>();
+    assert_owned_view::<AnimMesh>();
+    assert_owned_view::<Material>();
+    assert_owned_view::<MaterialPropertyRef>();
+    assert_owned_view::<Node>();
+    assert_owned_view::<Animation>();
+    assert_owned_view::<NodeAnimation>();
+    assert_owned_view::<Bone>();
+    assert_owned_view::<Camera>();
+    assert_owned_view::<Light>();
+    assert_owned_view::<Texture>();
+    assert_owned_iter::<MeshIterator>();
+    assert_owned_iter::<MaterialIterator>();
+}
+
+struct Stored {
+    mesh: Mesh,
+    material: Material,
+    root: Node,
+    meshes: MeshIterator,
+}
+
+fn load_views() -> Stored {
+    let scene = Importer::new()
+        .import_file("tests/models/textured.obj")
+        .expect("import textured.obj");
+    Stored {
+        mesh: scene.meshes().next().expect("mesh"),
+        material: scene.material(0).expect("material"),
+        root: scene.root_node().expect("root node"),
+        meshes: scene.meshes(),
+    }
+    // `scene` is dropped here; the views keep the Assimp data alive.
+}
+
+#[test]
+fn test_views_outlive_scene() {
+    let stored = load_views();
+    assert_eq!(stored.mesh.num_vertices(), 3);
+    assert_eq!(stored.mesh.vertices_raw().len(), 3);
+    assert!(!stored.material.name().is_empty());
+    assert!(stored.root.num_children() > 0 || stored.root.num_meshes() > 0);
+    assert_eq!(stored.meshes.count(), 1);
+
+    let handle = std::thread::spawn(move || stored.mesh.vertices());
+    assert_eq!(handle.join().expect("join").len(), 3);
+}
+
+#[test]
+fn test_scene_clone_shares_data() {
+    let scene = Importer::new()
+        .import_file("tests/models/textured.obj")
+        .expect("import textured.obj");
+    let clone = scene.clone();
+    let original = scene.meshes().next().expect("mesh");
+    let cloned = clone.meshes().next().expect("mesh");
+    assert_eq!(
+        original.vertices_raw().as_ptr(),
+        cloned.vertices_raw().as_ptr()
+    );
+}
@@ -0,0 +1,47 @@
+//! Smoke test for `wasm32-unknown-emscripten` builds (`--features wasm`)
+//!
+//! Run under Node with
+//! `CARGO_TARGET_WASM32_UNKNOWN_EMSCRIPTEN_RUNNER=node cargo test --target wasm32-unknown-emscripten --features wasm --test wasm_smoke_tests`.
+//! The import path is the one browsers use: bytes in, companion files from a
+//! `MemoryFileSystem`, no native file system.
+#![cfg(target_family = "wasm")]
+
+use asset_importer::{Importer, io::MemoryFileSystem, postprocess::PostProcessSteps};
+
+const OBJ: &str = "mtllib tri.mtl
+v 0 0 0
+v 1 0 0
+v 0 1 0
+vn 0 0 1
+usemtl red
+f 1//1 2//1 3//1
+";
+
+const MTL: &str = "newmtl red
+Kd 1 0 0
+";
+
+#[test]
+fn test_import_obj_from_bytes() {
+    let mut files = MemoryFileSystem::new();
+    files.add_file("tri.mtl", MTL.as_bytes().to_vec());
+
+    let scene = Importer::new()
+        .read_from_memory(OBJ.as_bytes())
+        .with_memory_hint("obj")
+        .with_file_system(files)
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("import OBJ from bytes");
+
+    assert_eq!(scene.num_meshes(), 1);
+    let mesh = scene.mesh(0).expect("mesh");
+    assert_eq!(mesh.vertices().len(), 3);
+    assert_eq!(mesh.num_faces(), 1);
+    assert!(mesh.normals().is_some());
+
+    let material = mesh.material().expect("material");
+    assert_eq!(material.name(), "red");
+    let diffuse = material.diffuse_color().expect("diffuse");
+    assert_eq!((diffuse.x, diffuse.y, diffuse.z), (1.0, 0.0, 0.0));
+}
@@ -57,7 +57,48 @@ fn test_mesh_vertices_raw_type_is_sys_free() {
     assert!(!raw.is_empty(), "mesh has no vertices");
 }
 
-#[cfg(feature = "bytemuck")]
+// The `[f32; 3]` views only exist when Assimp stores `f32` vectors.
+#[cfg(not(f64_real))]
+#[test]
+fn test_mesh_float_array_views() {
+    let model_path = Path::new("tests/models/textured.obj");
+    if !model_path.exists() {
+        println!("Skipping test - model file not found: {:?}", model_path);
+        return;
+    }
+
+    let scene = Importer::new()
+        .read_file(model_path)
+        .with_post_process(PostProcessSteps::TRIANGULATE)
+        .import()
+        .expect("failed to import textured.obj");
+
+    let mesh = scene.meshes().next().expect("scene has no meshes");
+    let positions = mesh.vertices_f32x3();
+    assert_eq!(positions.len(), mesh.num_vertices());
+    for (array, v) in positions.iter().zip(mesh.vertices_raw()) {
+        assert_eq!(*array, [v.x, v.y, v.z]);
+    }
+
+    let normals = mesh.normals_f32x3();
+    assert_eq!(normals.len(), mesh.normals_raw().len());
+    for (array, n) in normals.iter().zip(mesh.normals_raw()) {
+        assert_eq!(*array, [n.x, n.y, n.z]);
+    }
+
+    let uvs = mesh.texture_coords_f32x3(0);
+    assert_eq!(uvs.len(), mesh.texture_coords_raw(0).len());
+    for (array, uv) in uvs.iter().zip(mesh.texture_coords_raw(0)) {
+        assert_eq!(*array, [uv.x, uv.y, uv.z]);
+    }
+
+    // Absent or out-of-range channels are empty rather than dangling.
+    assert!(mesh.texture_coords_f32x3(64).is_empty());
+    assert!(mesh.vertex_colors_f32x4(0).is_empty());
+    assert!(mesh.tangents_f32x3().is_empty());
+}
+
+#[cfg(all(feature = "bytemuck", not(f64_real)))]
 #[test]
 fn test_bytemuck_mesh_bytes_views() {
     let model_path = Path::new("tests/models/textured.obj");